@@ -35,6 +35,18 @@ use crate::{
 
 pub type ClickhouseResult<T> = error_stack::Result<T, ClickhouseError>;
 
+/// Tables of the analytics store whose records hold the customer id
+const CUSTOMER_DATA_TABLES: [&str; 3] = ["payment_intents", "payout", "api_events"];
+
+/// The outcome of erasing the records of a customer from the analytics store
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CustomerDataErasure {
+    /// The number of records of the customer found before the erasure
+    pub records_found: u64,
+    /// The number of records of the customer found after the erasure
+    pub records_remaining: u64,
+}
+
 #[derive(Clone, Debug)]
 pub struct ClickhouseClient {
     pub config: Arc<ClickhouseConfig>,
@@ -93,6 +105,80 @@ impl ClickhouseClient {
                 .data)
         }
     }
+
+    /// Executes a statement which does not return any rows. Mutations are run synchronously, so
+    /// that the statement returns once the mutation is applied on all the replicas.
+    async fn execute_statement(&self, statement: &str) -> ClickhouseResult<()> {
+        logger::debug!("Executing statement: {statement}");
+        let client = reqwest::Client::new();
+        let params = CkhStatement {
+            database: self.database.clone(),
+            mutations_sync: 2,
+        };
+        let response = client
+            .post(&self.config.host)
+            .query(&params)
+            .basic_auth(self.config.username.clone(), self.config.password.clone())
+            .body(statement.to_string())
+            .send()
+            .await
+            .change_context(ClickhouseError::ConnectionError)?;
+
+        logger::debug!(clickhouse_response=?response, statement=?statement, "Clickhouse response");
+        if response.status() != StatusCode::OK {
+            response.text().await.map_or_else(
+                |er| {
+                    Err(ClickhouseError::ResponseError)
+                        .attach_printable_lazy(|| format!("Error: {er:?}"))
+                },
+                |t| Err(report!(ClickhouseError::ResponseNotOK(t))),
+            )
+        } else {
+            Ok(())
+        }
+    }
+
+    async fn count_customer_records(&self, table: &str, condition: &str) -> ClickhouseResult<u64> {
+        self.execute_query(&format!(
+            "SELECT count() AS count FROM {table} WHERE {condition}"
+        ))
+        .await?
+        .first()
+        .and_then(|row| row.get("count"))
+        .and_then(serde_json::Value::as_u64)
+        .ok_or(report!(ClickhouseError::ResponseError))
+        .attach_printable_lazy(|| format!("Failed to read the count of records in {table}"))
+    }
+
+    /// Deletes the records of the customer from the tables of the analytics store holding the
+    /// customer id, and counts the records of the customer left behind by the deletion
+    pub async fn erase_customer_data(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> ClickhouseResult<CustomerDataErasure> {
+        let condition = format!(
+            "merchant_id = {} AND customer_id = {}",
+            quote_string(merchant_id.get_string_repr()),
+            quote_string(customer_id.get_string_repr())
+        );
+        let mut erasure = CustomerDataErasure::default();
+        for table in CUSTOMER_DATA_TABLES {
+            erasure.records_found += self.count_customer_records(table, &condition).await?;
+            self.execute_statement(&format!("ALTER TABLE {table} DELETE WHERE {condition}"))
+                .await
+                .attach_printable_lazy(|| {
+                    format!("Failed to delete customer records in {table}")
+                })?;
+            erasure.records_remaining += self.count_customer_records(table, &condition).await?;
+        }
+        Ok(erasure)
+    }
+}
+
+/// Quotes a value as a ClickHouse string literal
+fn quote_string(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
 }
 
 #[async_trait::async_trait]
@@ -189,6 +275,12 @@ struct CkhQuery {
     database: String,
 }
 
+#[derive(Debug, serde::Serialize)]
+struct CkhStatement {
+    database: String,
+    mutations_sync: u8,
+}
+
 #[derive(Debug, serde::Deserialize)]
 struct CkhOutput<T> {
     data: Vec<T>,
@@ -55,8 +55,8 @@ use api_models::analytics::{
     Distribution, Granularity, TimeRange,
 };
 use clickhouse::ClickhouseClient;
-pub use clickhouse::ClickhouseConfig;
-use error_stack::report;
+pub use clickhouse::{ClickhouseConfig, CustomerDataErasure};
+use error_stack::{report, ResultExt};
 use router_env::{
     logger,
     tracing::{self, instrument},
@@ -77,7 +77,7 @@ use self::{
     refunds::metrics::{RefundMetric, RefundMetricRow},
     sdk_events::metrics::{SdkEventMetric, SdkEventMetricRow},
     sqlx::SqlxClient,
-    types::MetricsError,
+    types::{MetricsError, QueryExecutionError},
 };
 
 #[derive(Clone, Debug)]
@@ -846,6 +846,24 @@ impl AnalyticsProvider {
         }
     }
 
+    /// Erases the records of the customer from the analytics store. The Postgres analytics store
+    /// reads the application tables, whose records are erased along with the application data.
+    pub async fn erase_customer_data(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<CustomerDataErasure, QueryExecutionError> {
+        match self {
+            Self::Sqlx(_pool) => Ok(CustomerDataErasure::default()),
+            Self::Clickhouse(ckh_pool)
+            | Self::CombinedCkh(_, ckh_pool)
+            | Self::CombinedSqlx(_, ckh_pool) => ckh_pool
+                .erase_customer_data(merchant_id, customer_id)
+                .await
+                .change_context(QueryExecutionError::DatabaseError),
+        }
+    }
+
     pub async fn from_conf(
         config: &AnalyticsConfig,
         tenant: &dyn storage_impl::config::TenantConfig,
@@ -236,6 +236,38 @@ pub struct AuthenticationConnectorDetails {
     pub three_ds_requestor_url: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RefundPolicy {
+    /// Maximum number of days after the payment was captured within which a refund can be created
+    #[schema(example = 90)]
+    pub max_refund_age_in_days: Option<i64>,
+    /// Maximum total amount that can be refunded under the profile in a day, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 500000)]
+    pub max_refund_amount_per_day: Option<common_utils::types::MinorUnit>,
+    /// Maximum total amount that a single user can refund in a day, in the lowest denomination of the currency
+    #[schema(value_type = Option<i64>, example = 100000)]
+    pub max_refund_amount_per_user_per_day: Option<common_utils::types::MinorUnit>,
+    /// Refunds with an amount above this threshold are held in `pending_approval` until they are approved
+    #[schema(value_type = Option<i64>, example = 50000)]
+    pub approval_threshold: Option<common_utils::types::MinorUnit>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AutoCapturePolicy {
+    /// Number of hours after authorization at which the payment is captured
+    #[schema(example = 24)]
+    pub capture_after_hours: Option<u16>,
+    /// Cron expression evaluated in UTC, the payment is captured at the first occurrence after authorization
+    #[schema(example = "0 18 * * MON-FRI")]
+    pub capture_schedule: Option<String>,
+    /// Action taken when the authorization would expire at the issuer before the scheduled capture
+    #[serde(default)]
+    #[schema(value_type = AuthorizationExpiryAction)]
+    pub authorization_expiry_action: api_enums::AuthorizationExpiryAction,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct MerchantAccountMetadata {
     pub compatible_connector: Option<api_enums::Connector>,
@@ -1917,6 +1949,22 @@ pub struct BusinessProfileCreate {
     /// If set to `true` tax_connector_id will be checked.
     #[serde(default)]
     pub is_tax_connector_enabled: bool,
+
+    /// Refund policy applied to the refunds created under this profile
+    pub refund_policy: Option<RefundPolicy>,
+
+    /// Policy for automatically capturing payments authorized with manual capture under this profile
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+
+    /// Action taken on payments authorized with manual capture under this profile, before their authorization lapses at the issuer
+    #[schema(value_type = Option<AuthorizationExpiryAction>)]
+    pub authorization_expiry_action: Option<api_enums::AuthorizationExpiryAction>,
+
+    /// Indicates if the available balance of a bank account verified through a payment method auth connector is checked in real time before it is debited via ACH or SEPA
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+
+    /// Indicates if the holder name of a bank account verified through a payment method auth connector is matched against the customer name before it is debited
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[nutype::nutype(
@@ -2020,6 +2068,22 @@ pub struct BusinessProfileCreate {
     /// If set to `true` tax_connector_id will be checked.
     #[serde(default)]
     pub is_tax_connector_enabled: bool,
+
+    /// Refund policy applied to the refunds created under this profile
+    pub refund_policy: Option<RefundPolicy>,
+
+    /// Policy for automatically capturing payments authorized with manual capture under this profile
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+
+    /// Action taken on payments authorized with manual capture under this profile, before their authorization lapses at the issuer
+    #[schema(value_type = Option<AuthorizationExpiryAction>)]
+    pub authorization_expiry_action: Option<api_enums::AuthorizationExpiryAction>,
+
+    /// Indicates if the available balance of a bank account verified through a payment method auth connector is checked in real time before it is debited via ACH or SEPA
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+
+    /// Indicates if the holder name of a bank account verified through a payment method auth connector is matched against the customer name before it is debited
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -2136,6 +2200,22 @@ pub struct BusinessProfileResponse {
     /// Indicates if tax_calculator connector is enabled or not.
     /// If set to `true` tax_connector_id will be checked.
     pub is_tax_connector_enabled: bool,
+
+    /// Refund policy applied to the refunds created under this profile
+    pub refund_policy: Option<RefundPolicy>,
+
+    /// Policy for automatically capturing payments authorized with manual capture under this profile
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+
+    /// Action taken on payments authorized with manual capture under this profile, before their authorization lapses at the issuer
+    #[schema(value_type = Option<AuthorizationExpiryAction>)]
+    pub authorization_expiry_action: Option<api_enums::AuthorizationExpiryAction>,
+
+    /// Indicates if the available balance of a bank account verified through a payment method auth connector is checked in real time before it is debited via ACH or SEPA
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+
+    /// Indicates if the holder name of a bank account verified through a payment method auth connector is matched against the customer name before it is debited
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -2243,6 +2323,22 @@ pub struct BusinessProfileResponse {
     /// Indicates if tax_calculator connector is enabled or not.
     /// If set to `true` tax_connector_id will be checked.
     pub is_tax_connector_enabled: bool,
+
+    /// Refund policy applied to the refunds created under this profile
+    pub refund_policy: Option<RefundPolicy>,
+
+    /// Policy for automatically capturing payments authorized with manual capture under this profile
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+
+    /// Action taken on payments authorized with manual capture under this profile, before their authorization lapses at the issuer
+    #[schema(value_type = Option<AuthorizationExpiryAction>)]
+    pub authorization_expiry_action: Option<api_enums::AuthorizationExpiryAction>,
+
+    /// Indicates if the available balance of a bank account verified through a payment method auth connector is checked in real time before it is debited via ACH or SEPA
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+
+    /// Indicates if the holder name of a bank account verified through a payment method auth connector is matched against the customer name before it is debited
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -2351,6 +2447,22 @@ pub struct BusinessProfileUpdate {
     /// Indicates if tax_calculator connector is enabled or not.
     /// If set to `true` tax_connector_id will be checked.
     pub is_tax_connector_enabled: Option<bool>,
+
+    /// Refund policy applied to the refunds created under this profile
+    pub refund_policy: Option<RefundPolicy>,
+
+    /// Policy for automatically capturing payments authorized with manual capture under this profile
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+
+    /// Action taken on payments authorized with manual capture under this profile, before their authorization lapses at the issuer
+    #[schema(value_type = Option<AuthorizationExpiryAction>)]
+    pub authorization_expiry_action: Option<api_enums::AuthorizationExpiryAction>,
+
+    /// Indicates if the available balance of a bank account verified through a payment method auth connector is checked in real time before it is debited via ACH or SEPA
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+
+    /// Indicates if the holder name of a bank account verified through a payment method auth connector is matched against the customer name before it is debited
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -2450,6 +2562,22 @@ pub struct BusinessProfileUpdate {
     /// Indicates if tax_calculator connector is enabled or not.
     /// If set to `true` tax_connector_id will be checked.
     pub is_tax_connector_enabled: Option<bool>,
+
+    /// Refund policy applied to the refunds created under this profile
+    pub refund_policy: Option<RefundPolicy>,
+
+    /// Policy for automatically capturing payments authorized with manual capture under this profile
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+
+    /// Action taken on payments authorized with manual capture under this profile, before their authorization lapses at the issuer
+    #[schema(value_type = Option<AuthorizationExpiryAction>)]
+    pub authorization_expiry_action: Option<api_enums::AuthorizationExpiryAction>,
+
+    /// Indicates if the available balance of a bank account verified through a payment method auth connector is checked in real time before it is debited via ACH or SEPA
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+
+    /// Indicates if the holder name of a bank account verified through a payment method auth connector is matched against the customer name before it is debited
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, ToSchema)]
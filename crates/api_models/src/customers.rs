@@ -46,6 +46,12 @@ pub struct CustomerRequest {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The tax identifiers of the customer, such as VAT or GST registration numbers
+    pub tax_ids: Option<Vec<CustomerTaxId>>,
+    /// Tags grouping the customer into merchant defined segments, usable in routing and surcharge
+    /// rules as `customer_segment.<segment>` metadata keys
+    #[schema(max_items = 20, example = json!(["wholesale", "tier_1"]))]
+    pub segments: Option<Vec<String>>,
 }
 
 #[derive(Debug, Default, Clone, Deserialize, Serialize, ToSchema)]
@@ -244,6 +250,11 @@ pub struct CustomerResponse {
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The tax identifiers of the customer
+    pub tax_ids: Option<Vec<CustomerTaxId>>,
+    /// The segments the customer belongs to
+    #[schema(example = json!(["wholesale", "tier_1"]))]
+    pub segments: Option<Vec<String>>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -401,6 +412,11 @@ pub struct CustomerUpdateRequest {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The tax identifiers of the customer, replacing the ones saved earlier
+    pub tax_ids: Option<Vec<CustomerTaxId>>,
+    /// The segments of the customer, replacing the ones saved earlier
+    #[schema(max_items = 20, example = json!(["wholesale", "tier_1"]))]
+    pub segments: Option<Vec<String>>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -469,6 +485,91 @@ impl CustomerUpdateRequest {
     }
 }
 
+/// A tax identifier of a customer, such as a VAT or GST registration number
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerTaxId {
+    #[schema(value_type = CustomerTaxIdType, example = "eu_vat")]
+    pub tax_id_type: common_enums::CustomerTaxIdType,
+    /// The identifier as issued by the tax authority. EU VAT numbers are prefixed with the code of
+    /// the member state, such as `DE123456789`
+    #[schema(value_type = String, example = "DE123456789")]
+    pub value: Secret<String>,
+}
+
+/// A labeled billing or shipping address of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerAddressRequest {
+    #[schema(value_type = CustomerAddressType, example = "shipping")]
+    pub address_type: common_enums::CustomerAddressType,
+    /// A name for the address chosen by the merchant, such as `Head office` or `Warehouse 2`
+    #[schema(max_length = 64, example = "Head office")]
+    pub label: Option<String>,
+    #[schema(value_type = AddressDetails)]
+    pub address: payments::AddressDetails,
+    /// The phone number of the contact at the address
+    #[schema(value_type = Option<String>, max_length = 255, example = "9123456789")]
+    pub phone: Option<Secret<String>>,
+    /// The country code for the phone number
+    #[schema(max_length = 255, example = "+65")]
+    pub phone_country_code: Option<String>,
+    /// The email address of the contact at the address
+    #[schema(value_type = Option<String>, max_length = 255, example = "JonTest@test.com")]
+    pub email: Option<pii::Email>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressResponse {
+    /// The identifier for the address
+    #[schema(example = "add_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub address_id: String,
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    #[schema(value_type = CustomerAddressType, example = "shipping")]
+    pub address_type: common_enums::CustomerAddressType,
+    #[schema(max_length = 64, example = "Head office")]
+    pub label: Option<String>,
+    #[serde(flatten)]
+    pub address: payments::Address,
+    /// A timestamp (ISO 8601 code) that determines when the address was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// A timestamp (ISO 8601 code) that determines when the address was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressListResponse {
+    pub addresses: Vec<CustomerAddressResponse>,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CustomerAddressId {
+    pub customer_id: id_type::CustomerId,
+    pub address_id: String,
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerAddressDeleteResponse {
+    /// The identifier for the address
+    #[schema(example = "add_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub address_id: String,
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    /// Whether the address was deleted or not
+    #[schema(example = true)]
+    pub deleted: bool,
+}
+
 #[derive(Default, Debug, serde::Deserialize, serde::Serialize, Clone)]
 pub struct UpdateCustomerId(String);
 
@@ -481,3 +582,150 @@ impl UpdateCustomerId {
         Self(id)
     }
 }
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DataSubjectRequestRetrieveRequest {
+    pub customer_id: id_type::CustomerId,
+    pub request_id: String,
+}
+
+/// A request of a customer (the data subject) to export or erase the personal data held about them
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct DataSubjectRequestResponse {
+    /// The identifier for the data subject request
+    #[schema(example = "dsr_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub request_id: String,
+    /// The identifier for the customer object
+    #[schema(value_type = String, max_length = 64, min_length = 1, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: id_type::CustomerId,
+    #[schema(value_type = DataSubjectRequestType)]
+    pub request_type: common_enums::DataSubjectRequestType,
+    #[schema(value_type = DataSubjectRequestStatus)]
+    pub status: common_enums::DataSubjectRequestStatus,
+    /// The progress of the erasure in each of the stores holding data of the customer
+    pub report: Option<DataErasureReport>,
+    /// A timestamp (ISO 8601 code) that determines when the request was created
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// A timestamp (ISO 8601 code) that determines when the data of the customer was processed in
+    /// all the stores
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub completed_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The completion report of the erasure of the personal data of a customer
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct DataErasureReport {
+    /// The outcome of the erasure in each of the stores, in the order they are erased
+    pub stores: Vec<DataErasureStoreReport>,
+    /// Hex encoded HMAC-SHA256 of the request id, the customer id and the store reports, keyed
+    /// with a secret held by the server and recorded once the erasure in all the stores is done
+    pub digest: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DataErasureStoreReport {
+    pub store: DataErasureStore,
+    pub status: DataErasureStoreStatus,
+    /// The number of records of the customer found in the store
+    pub records_found: u32,
+    /// The number of records deleted or redacted
+    pub records_erased: u32,
+    /// The number of records that had to be kept, such as the evidence of disputes that are still
+    /// open or files that are stored with a connector
+    pub records_retained: u32,
+    /// Whether the store was read back after the erasure and no personal data of the customer was
+    /// found in the erased records
+    pub verified: bool,
+    /// The reason the erasure failed in this store
+    pub error_message: Option<String>,
+}
+
+/// The stores holding personal data of a customer
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, strum::Display, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DataErasureStore {
+    /// Saved payment methods and the cards stored in the vault
+    Vault,
+    /// Addresses of the customer and the billing and shipping addresses of their payments
+    Addresses,
+    /// Payments of the customer, whose updated records also replace the copies sent to the
+    /// analytics pipeline
+    Payments,
+    /// Evidence files of the disputes raised on payments of the customer
+    Files,
+    /// Records of the customer in the analytics store, such as the payments, payouts and API events
+    Analytics,
+    /// The customer record
+    Customer,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum DataErasureStoreStatus {
+    Pending,
+    Completed,
+    Failed,
+}
+
+/// All the data tied to a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerDataExportResponse {
+    /// The identifier for the data subject request recording the export
+    pub request_id: String,
+    pub customer: CustomerResponse,
+    /// The addresses of the customer and the billing and shipping addresses of their payments
+    pub addresses: Vec<CustomerDataExportAddress>,
+    pub payment_methods: Vec<CustomerDataExportPaymentMethod>,
+    pub payments: Vec<payments::PaymentsResponse>,
+    pub refunds: Vec<crate::refunds::RefundResponse>,
+    pub disputes: Vec<crate::disputes::DisputeResponse>,
+    pub mandates: Vec<CustomerDataExportMandate>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub exported_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerDataExportAddress {
+    pub address_id: String,
+    /// The payment the address was provided for, absent for addresses of the customer
+    pub payment_id: Option<id_type::PaymentId>,
+    #[serde(flatten)]
+    pub address: payments::Address,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerDataExportPaymentMethod {
+    pub payment_method_id: String,
+    pub payment_method: Option<common_enums::PaymentMethod>,
+    pub payment_method_type: Option<common_enums::PaymentMethodType>,
+    pub payment_method_issuer: Option<String>,
+    pub status: common_enums::PaymentMethodStatus,
+    pub payment_method_data: Option<crate::payment_methods::PaymentMethodsData>,
+    pub billing: Option<payments::Address>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    #[serde(with = "custom_serde::iso8601")]
+    pub last_used_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CustomerDataExportMandate {
+    pub mandate_id: String,
+    pub payment_method_id: String,
+    pub status: common_enums::MandateStatus,
+    pub mandate_type: common_enums::MandateType,
+    #[serde(with = "custom_serde::iso8601::option")]
+    pub customer_accepted_at: Option<time::PrimitiveDateTime>,
+    pub customer_ip_address: Option<Secret<String, pii::IpAddress>>,
+    pub customer_user_agent: Option<String>,
+    #[serde(with = "custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
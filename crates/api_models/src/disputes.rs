@@ -208,3 +208,95 @@ pub struct DeleteEvidenceRequest {
     /// Evidence Type to be deleted
     pub evidence_type: EvidenceType,
 }
+
+/// Network or issuer programme through which a pre-dispute alert was raised
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PreDisputeAlertProvider {
+    /// Visa Rapid Dispute Resolution
+    Rdr,
+    /// Mastercard Ethoca alerts
+    Ethoca,
+    /// Verifi Cardholder Dispute Resolution Network
+    Cdrn,
+}
+
+/// Action taken on a pre-dispute alert
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, ToSchema, strum::Display, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PreDisputeAlertOutcome {
+    /// The payment was refunded automatically to prevent the chargeback
+    Refunded,
+    /// A refund was created to prevent the chargeback and is awaiting approval
+    RefundPendingApproval,
+    /// The alert was recorded and the merchant was notified to take action
+    MerchantNotified,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PreDisputeAlertRequest {
+    /// Identifier of the alert assigned by the alert provider
+    pub alert_id: String,
+    /// Programme through which the alert was raised
+    pub alert_provider: PreDisputeAlertProvider,
+    /// Acquirer reference number of the disputed transaction
+    pub arn: String,
+    /// The identifier for the payment, if the alert provider was able to resolve it
+    #[schema(value_type = Option<String>)]
+    pub payment_id: Option<common_utils::id_type::PaymentId>,
+    /// Transaction reference of the disputed transaction at the connector
+    pub connector_transaction_id: Option<String>,
+    /// Disputed amount in the lowest denomination of the currency
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: common_utils::types::MinorUnit,
+    /// The three-letter ISO currency code
+    #[schema(value_type = Currency)]
+    pub currency: common_enums::Currency,
+    /// Date on which the disputed transaction was made
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub transaction_date: PrimitiveDateTime,
+    /// Last 4 digits of the card used for the disputed transaction
+    pub card_last4: Option<String>,
+    /// Reason for the alert as sent by the alert provider
+    pub reason: Option<String>,
+    /// Reason code for the alert as sent by the alert provider
+    pub reason_code: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct PreDisputeAlertResponse {
+    /// The identifier for the dispute recorded against the alert
+    pub dispute_id: String,
+    /// Identifier of the alert assigned by the alert provider
+    pub alert_id: String,
+    /// The identifier for the payment the alert was matched to
+    #[schema(value_type = String)]
+    pub payment_id: common_utils::id_type::PaymentId,
+    /// Action taken on the alert
+    pub outcome: PreDisputeAlertOutcome,
+    /// The identifier for the refund created to resolve the alert
+    pub refund_id: Option<String>,
+    /// Status of the refund created to resolve the alert
+    pub refund_status: Option<crate::refunds::RefundStatus>,
+    /// Status of the dispute recorded against the alert
+    pub dispute_status: DisputeStatus,
+}
+
+/// Merchant rule applied to incoming pre-dispute alerts
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct PreDisputeAlertRule {
+    /// Alerts with an amount less than or equal to this are refunded automatically. If not set, the merchant is only notified.
+    #[schema(value_type = Option<i64>, example = 5000)]
+    pub auto_refund_threshold: Option<common_utils::types::MinorUnit>,
+}
+
+impl PreDisputeAlertRule {
+    pub fn should_auto_refund(&self, amount: common_utils::types::MinorUnit) -> bool {
+        self.auto_refund_threshold
+            .is_some_and(|threshold| amount <= threshold)
+    }
+}
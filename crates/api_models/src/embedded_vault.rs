@@ -0,0 +1,98 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums;
+
+/// The details of a data key of the embedded card vault. The key itself is never exposed.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DataKeyResponse {
+    /// The identifier for the data key.
+    pub key_id: String,
+
+    /// The version of the data key, incremented on every rotation.
+    pub version: i32,
+
+    /// The status of the data key.
+    pub status: enums::VaultDataKeyStatus,
+
+    /// The time at which the data key was created.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// The time at which all data encrypted with the key was re-encrypted with a newer key.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub retired_at: Option<PrimitiveDateTime>,
+}
+
+/// The data keys of a merchant, latest version first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DataKeyListResponse {
+    /// The identifier for the merchant.
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The data keys of the merchant.
+    pub keys: Vec<DataKeyResponse>,
+}
+
+/// The result of rotating the data key of a merchant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DataKeyRotationResponse {
+    /// The identifier for the merchant.
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The data key which encrypts newly stored data.
+    pub active_key: DataKeyResponse,
+
+    /// The previously active data key, whose data is being re-encrypted in the background.
+    pub rotating_key: Option<DataKeyResponse>,
+}
+
+/// The constraints to apply when listing the access logs of the embedded card vault.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct AccessLogListConstraints {
+    /// Include only the operations performed on the specified entry.
+    pub entry_id: Option<String>,
+
+    /// Include at most the specified number of access logs.
+    pub limit: Option<u16>,
+
+    /// Include access logs after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// A record of an operation performed on the embedded card vault.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AccessLogResponse {
+    /// The identifier for the access log.
+    pub log_id: String,
+
+    /// The entry the operation was performed on.
+    pub entry_id: Option<String>,
+
+    /// The data key used by the operation.
+    pub key_id: Option<String>,
+
+    /// The operation performed.
+    pub action: enums::VaultAccessAction,
+
+    /// The identifier for the request which performed the operation.
+    pub request_id: Option<String>,
+
+    /// The time at which the operation was performed.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The access logs of a merchant, latest first.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AccessLogListResponse {
+    /// The identifier for the merchant.
+    #[schema(value_type = String)]
+    pub merchant_id: common_utils::id_type::MerchantId,
+
+    /// The access logs of the merchant.
+    pub access_logs: Vec<AccessLogResponse>,
+}
@@ -77,6 +77,8 @@ pub enum Connector {
     #[serde(rename = "paypal_test")]
     #[strum(serialize = "paypal_test")]
     DummyConnector7,
+    #[cfg(feature = "dummy_connector")]
+    DummyBank,
     Aci,
     Adyen,
     Airwallex,
@@ -137,6 +139,7 @@ pub enum Connector {
     Stripe,
     // Taxjar,
     Threedsecureio,
+    Truelayer,
     Trustpay,
     Tsys,
     Volt,
@@ -210,7 +213,8 @@ impl Connector {
             | Self::DummyConnector4
             | Self::DummyConnector5
             | Self::DummyConnector6
-            | Self::DummyConnector7 => false,
+            | Self::DummyConnector7
+            | Self::DummyBank => false,
             Self::Aci
             // Add Separate authentication support for connectors
 			// | Self::Novalnet
@@ -264,6 +268,7 @@ impl Connector {
             | Self::Shift4
             | Self::Square
             | Self::Stax
+            | Self::Truelayer
             | Self::Trustpay
             | Self::Tsys
             | Self::Volt
@@ -304,6 +309,7 @@ impl Connector {
                     | Self::DummyConnector5
                     | Self::DummyConnector6
                     | Self::DummyConnector7
+                    | Self::DummyBank
             )
         {
             Err(errors::ValidationError::InvalidValue {
@@ -646,6 +652,9 @@ pub enum LockerChoice {
 #[strum(serialize_all = "snake_case")]
 pub enum PmAuthConnectors {
     Plaid,
+    Truelayer,
+    #[cfg(feature = "dummy_connector")]
+    DummyBank,
 }
 
 pub fn convert_pm_auth_connector(connector_name: &str) -> Option<PmAuthConnectors> {
@@ -2,11 +2,13 @@ pub mod apple_pay_certificates_migration;
 pub mod connector_onboarding;
 pub mod customer;
 pub mod dispute;
+pub mod embedded_vault;
 pub mod gsm;
 mod locker_migration;
 pub mod payment;
 #[cfg(feature = "payouts")]
 pub mod payouts;
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refund;
@@ -3,7 +3,12 @@ use common_utils::events::{ApiEventMetric, ApiEventsType};
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use crate::customers::GlobalId;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-use crate::customers::{CustomerDeleteResponse, CustomerId};
+use crate::customers::{
+    CustomerAddressDeleteResponse, CustomerAddressId, CustomerAddressListResponse,
+    CustomerAddressRequest, CustomerAddressResponse, CustomerDataExportResponse,
+    CustomerDeleteResponse, CustomerId, DataSubjectRequestResponse,
+    DataSubjectRequestRetrieveRequest,
+};
 use crate::customers::{CustomerRequest, CustomerResponse, CustomerUpdateRequest};
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -87,3 +92,63 @@ impl ApiEventMetric for CustomerUpdateRequest {
     }
 }
 // These needs to be fixed for v2
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerDataExportResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for DataSubjectRequestRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for DataSubjectRequestResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerAddressRequest {}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerAddressId {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerAddressResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerAddressListResponse {}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl ApiEventMetric for CustomerAddressDeleteResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
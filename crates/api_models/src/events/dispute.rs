@@ -1,7 +1,8 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use super::{
-    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve, SubmitEvidenceRequest,
+    DeleteEvidenceRequest, DisputeResponse, DisputeResponsePaymentsRetrieve,
+    PreDisputeAlertRequest, PreDisputeAlertResponse, SubmitEvidenceRequest,
 };
 
 impl ApiEventMetric for SubmitEvidenceRequest {
@@ -32,3 +33,17 @@ impl ApiEventMetric for DeleteEvidenceRequest {
         })
     }
 }
+impl ApiEventMetric for PreDisputeAlertRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        self.payment_id
+            .clone()
+            .map(|payment_id| ApiEventsType::Payment { payment_id })
+    }
+}
+impl ApiEventMetric for PreDisputeAlertResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Dispute {
+            dispute_id: self.dispute_id.clone(),
+        })
+    }
+}
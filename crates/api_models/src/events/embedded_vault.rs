@@ -0,0 +1,27 @@
+use common_utils::events::{ApiEventMetric, ApiEventsType};
+
+use crate::embedded_vault;
+
+impl ApiEventMetric for embedded_vault::DataKeyListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::EmbeddedVault)
+    }
+}
+
+impl ApiEventMetric for embedded_vault::DataKeyRotationResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::EmbeddedVault)
+    }
+}
+
+impl ApiEventMetric for embedded_vault::AccessLogListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::EmbeddedVault)
+    }
+}
+
+impl ApiEventMetric for embedded_vault::AccessLogListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::EmbeddedVault)
+    }
+}
@@ -9,13 +9,20 @@ use crate::payment_methods::CustomerPaymentMethodsListResponse;
 use crate::payment_methods::CustomerPaymentMethodsListResponse;
 use crate::{
     payment_methods::{
-        CustomerDefaultPaymentMethodResponse, DefaultPaymentMethod, ListCountriesCurrenciesRequest,
-        ListCountriesCurrenciesResponse, PaymentMethodCollectLinkRenderRequest,
-        PaymentMethodCollectLinkRequest, PaymentMethodCollectLinkResponse,
-        PaymentMethodDeleteResponse, PaymentMethodListRequest, PaymentMethodListResponse,
+        AccountUpdaterEnrollmentResponse, CustomerDefaultPaymentMethodResponse,
+        CustomerPaymentMethodUpdateRequest, CustomerPaymentMethodUpdateResponse,
+        DefaultPaymentMethod, ListCountriesCurrenciesRequest, ListCountriesCurrenciesResponse,
+        PaymentMethodCollectLinkRenderRequest, PaymentMethodCollectLinkRequest,
+        PaymentMethodCollectLinkResponse, PaymentMethodDeleteResponse,
+        PaymentMethodFingerprintLinksRequest, PaymentMethodFingerprintLinksResponse,
+        PaymentMethodHealthEnrollmentResponse, PaymentMethodListRequest, PaymentMethodListResponse,
+        PaymentMethodManageLinkRenderRequest, PaymentMethodManageLinkRequest,
+        PaymentMethodManageLinkResponse, PaymentMethodMigrationCreateRequest,
+        PaymentMethodMigrationJobResponse, PaymentMethodMigrationRetrieveRequest,
         PaymentMethodResponse, PaymentMethodUpdate,
     },
     payments::{
+        ExpiringAuthorizationsListConstraints, ExpiringAuthorizationsListResponse,
         ExtendedCardInfoResponse, PaymentIdType, PaymentListConstraints,
         PaymentListFilterConstraints, PaymentListFilters, PaymentListFiltersV2,
         PaymentListResponse, PaymentListResponseV2, PaymentsAggregateResponse,
@@ -192,6 +199,78 @@ impl ApiEventMetric for PaymentMethodCollectLinkResponse {
     }
 }
 
+impl ApiEventMetric for PaymentMethodManageLinkRequest {}
+
+impl ApiEventMetric for PaymentMethodManageLinkRenderRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodManageLink {
+            link_id: self.pm_manage_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentMethodManageLinkResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodManageLink {
+            link_id: self.pm_manage_link_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerPaymentMethodUpdateRequest {}
+
+impl ApiEventMetric for CustomerPaymentMethodUpdateResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethod {
+            payment_method_id: self.payment_method_id.clone(),
+            payment_method: self.payment_method,
+            payment_method_type: self.payment_method_type,
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentMethodMigrationCreateRequest {}
+
+impl ApiEventMetric for PaymentMethodMigrationRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodMigration {
+            migration_id: self.migration_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PaymentMethodMigrationJobResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PaymentMethodMigration {
+            migration_id: self.migration_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for AccountUpdaterEnrollmentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PaymentMethodHealthEnrollmentResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PaymentMethodFingerprintLinksRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
+impl ApiEventMetric for PaymentMethodFingerprintLinksResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Miscellaneous)
+    }
+}
+
 impl ApiEventMetric for PaymentListFilterConstraints {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::ResourceListAPI)
@@ -232,6 +311,18 @@ impl ApiEventMetric for PaymentsAggregateResponse {
     }
 }
 
+impl ApiEventMetric for ExpiringAuthorizationsListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl ApiEventMetric for ExpiringAuthorizationsListResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
+
 impl ApiEventMetric for RedirectionResponse {}
 
 impl ApiEventMetric for PaymentsIncrementalAuthorizationRequest {
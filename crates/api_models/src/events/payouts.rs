@@ -1,9 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
 use crate::payouts::{
-    PayoutActionRequest, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest,
-    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutRetrieveRequest,
+    PayoutActionRequest, PayoutBatchCreateRequest, PayoutBatchResponse, PayoutBatchRetrieveRequest,
+    PayoutCreateRequest, PayoutCreateResponse, PayoutLinkInitiateRequest, PayoutListConstraints,
+    PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse, PayoutRetrieveRequest,
 };
 
 impl ApiEventMetric for PayoutRetrieveRequest {
@@ -69,3 +69,21 @@ impl ApiEventMetric for PayoutLinkInitiateRequest {
         })
     }
 }
+
+impl ApiEventMetric for PayoutBatchCreateRequest {}
+
+impl ApiEventMetric for PayoutBatchRetrieveRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for PayoutBatchResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::PayoutBatch {
+            batch_id: self.batch_id.clone(),
+        })
+    }
+}
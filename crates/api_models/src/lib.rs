@@ -10,6 +10,7 @@ pub mod consts;
 pub mod currency;
 pub mod customers;
 pub mod disputes;
+pub mod embedded_vault;
 pub mod enums;
 pub mod ephemeral_key;
 #[cfg(feature = "errors")]
@@ -27,6 +28,7 @@ pub mod payments;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
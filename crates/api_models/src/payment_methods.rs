@@ -645,6 +645,10 @@ pub struct PaymentMethodResponse {
 
     /// For Client based calls
     pub client_secret: Option<String>,
+
+    /// The health of the payment method, as evaluated by the payment method health sweep
+    #[schema(value_type = Option<PaymentMethodHealthStatus>, example = "healthy")]
+    pub health_status: Option<api_enums::PaymentMethodHealthStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -691,6 +695,10 @@ pub struct PaymentMethodResponse {
     pub client_secret: Option<String>,
 
     pub payment_method_data: Option<PaymentMethodResponseData>,
+
+    /// The health of the payment method, as evaluated by the payment method health sweep
+    #[schema(value_type = Option<PaymentMethodHealthStatus>, example = "healthy")]
+    pub health_status: Option<api_enums::PaymentMethodHealthStatus>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize)]
@@ -1632,6 +1640,11 @@ pub struct CustomerPaymentMethod {
     /// The billing details of the payment method
     #[schema(value_type = Option<Address>)]
     pub billing: Option<payments::Address>,
+
+    /// The health of the payment method. Unhealthy payment methods are listed after the healthy
+    /// ones
+    #[schema(value_type = Option<PaymentMethodHealthStatus>, example = "healthy")]
+    pub health_status: Option<api_enums::PaymentMethodHealthStatus>,
 }
 
 #[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
@@ -1729,6 +1742,11 @@ pub struct CustomerPaymentMethod {
     /// The billing details of the payment method
     #[schema(value_type = Option<Address>)]
     pub billing: Option<payments::Address>,
+
+    /// The health of the payment method. Unhealthy payment methods are listed after the healthy
+    /// ones
+    #[schema(value_type = Option<PaymentMethodHealthStatus>, example = "healthy")]
+    pub health_status: Option<api_enums::PaymentMethodHealthStatus>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
@@ -1829,6 +1847,113 @@ pub struct PaymentMethodCollectLinkStatusDetails {
     pub ui_config: link_utils::GenericLinkUiConfigFormData,
 }
 
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodManageLinkRequest {
+    /// The unique identifier of the customer whose saved payment methods are managed.
+    #[schema(value_type = String, example = "cus_92dnwed8s32bV9D8Snbiasd8v")]
+    pub customer_id: id_type::CustomerId,
+
+    #[serde(flatten)]
+    #[schema(value_type = Option<GenericLinkUiConfig>)]
+    pub ui_config: Option<link_utils::GenericLinkUiConfig>,
+
+    /// Will be used to expire the link after certain amount of time to be supplied in seconds
+    /// (900) for 15 mins
+    #[schema(value_type = Option<u32>, example = 900)]
+    pub session_expiry: Option<u32>,
+
+    /// Redirect to this URL once the customer is done managing their payment methods
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io/account")]
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodManageLinkResponse {
+    /// The unique identifier for the manage link.
+    #[schema(value_type = String, example = "pm_manage_link_2bdacf398vwzq5n422S1")]
+    pub pm_manage_link_id: String,
+
+    /// The unique identifier of the customer.
+    #[schema(value_type = String, example = "cus_92dnwed8s32bV9D8Snbiasd8v")]
+    pub customer_id: id_type::CustomerId,
+
+    /// Time when this link will be expired in ISO8601 format
+    #[schema(value_type = PrimitiveDateTime, example = "2025-01-18T11:04:09.922Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expiry: time::PrimitiveDateTime,
+
+    /// URL to the page generated for managing the saved payment methods.
+    #[schema(value_type = String, example = "https://sandbox.hyperswitch.io/payment_methods/manage/merchant_1671528864/pm_manage_link_2bdacf398vwzq5n422S1")]
+    pub link: masking::Secret<url::Url>,
+
+    /// Redirect to this URL once the customer is done managing their payment methods
+    #[schema(value_type = Option<String>, example = "https://hyperswitch.io/account")]
+    pub return_url: Option<String>,
+
+    /// Manage link config used
+    #[serde(flatten)]
+    #[schema(value_type = GenericLinkUiConfig)]
+    pub ui_config: link_utils::GenericLinkUiConfig,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+pub struct PaymentMethodManageLinkRenderRequest {
+    /// Unique identifier for a merchant.
+    #[schema(example = "merchant_1671528864", value_type = String)]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The unique identifier for the manage link.
+    #[schema(value_type = String, example = "pm_manage_link_2bdacf398vwzq5n422S1")]
+    pub pm_manage_link_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PaymentMethodManageLinkDetails {
+    pub ephemeral_key: masking::Secret<String>,
+    pub pm_manage_link_id: String,
+    pub customer_id: id_type::CustomerId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub session_expiry: time::PrimitiveDateTime,
+    pub return_url: Option<String>,
+    #[serde(flatten)]
+    pub ui_config: link_utils::GenericLinkUiConfigFormData,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerPaymentMethodUpdateRequest {
+    /// The name shown to the customer for the saved card
+    #[schema(value_type = Option<String>, example = "John's card")]
+    pub nick_name: Option<masking::Secret<String>>,
+
+    /// The billing address of the saved payment method
+    pub billing: Option<payments::Address>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct CustomerPaymentMethodUpdateResponse {
+    /// The unique identifier of the saved payment method
+    #[schema(example = "pm_iouuy468iyuowqs")]
+    pub payment_method_id: String,
+
+    /// The unique identifier of the customer.
+    #[schema(value_type = String, example = "cus_92dnwed8s32bV9D8Snbiasd8v")]
+    pub customer_id: id_type::CustomerId,
+
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<api_enums::PaymentMethod>,
+
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<api_enums::PaymentMethodType>,
+
+    /// The name shown to the customer for the saved card
+    #[schema(value_type = Option<String>, example = "John's card")]
+    pub nick_name: Option<masking::Secret<String>>,
+
+    /// The billing address of the saved payment method
+    pub billing: Option<payments::Address>,
+}
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
 pub struct MaskedBankDetails {
     pub mask: String,
@@ -2155,10 +2280,172 @@ impl From<PaymentMethodRecord> for customers::CustomerRequest {
                 last_name: Some(record.billing_address_last_name),
             }),
             metadata: None,
+            tax_ids: None,
+            segments: None,
         }
     }
 }
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+impl From<PaymentMethodMigrate> for customers::CustomerRequest {
+    fn from(payment_method_migrate: PaymentMethodMigrate) -> Self {
+        let billing = payment_method_migrate.billing.unwrap_or_default();
+        let phone = billing.phone.unwrap_or_default();
+        Self {
+            customer_id: payment_method_migrate.customer_id,
+            merchant_id: payment_method_migrate.merchant_id,
+            name: payment_method_migrate
+                .card
+                .and_then(|card| card.card_holder_name),
+            email: billing.email,
+            phone: phone.number,
+            description: None,
+            phone_country_code: phone.country_code,
+            address: billing.address,
+            metadata: None,
+            tax_ids: None,
+            segments: None,
+        }
+    }
+}
+
+/// Request to migrate the payment methods in a file uploaded with the `payment_method_migration`
+/// purpose. The file is either a CSV file with the columns of the batch migration API, or a JSONL
+/// file with one payment method migrate request per line.
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PaymentMethodMigrationCreateRequest {
+    /// The identifier of the merchant whose payment methods are migrated
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier of the uploaded migration file
+    pub file_id: String,
+
+    /// The number of records migrated concurrently, defaults to 10
+    pub parallelism: Option<u8>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct PaymentMethodMigrationRetrieveRequest {
+    pub merchant_id: id_type::MerchantId,
+    pub migration_id: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentMethodMigrationJobResponse {
+    /// The identifier of the migration
+    pub migration_id: String,
+
+    pub merchant_id: id_type::MerchantId,
+
+    /// The identifier of the migration file
+    pub file_id: String,
+
+    pub file_format: api_enums::PaymentMethodMigrationFileFormat,
+
+    pub status: api_enums::PaymentMethodMigrationStatus,
+
+    /// The number of records migrated concurrently
+    pub parallelism: i32,
+
+    /// The number of records in the migration file
+    pub total_count: i32,
+
+    /// The number of records processed so far
+    pub processed_count: i32,
+
+    /// The number of records which could not be migrated
+    pub failed_count: i32,
+
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
+/// A record of the result report of a payment method migration
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentMethodMigrationReportRecord {
+    pub line_number: i32,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payment_method_id: Option<String>,
+    pub status: api_enums::PaymentMethodMigrationItemStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+/// The enrollment of a merchant in the card account updater
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct AccountUpdaterEnrollmentResponse {
+    pub merchant_id: id_type::MerchantId,
+
+    /// Whether the saved cards of the merchant are periodically submitted to the account updater
+    pub enrolled: bool,
+
+    /// The time at which the saved cards of the merchant are submitted next
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<time::PrimitiveDateTime>,
+}
+
+/// The enrollment of a merchant in the payment method health sweep
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentMethodHealthEnrollmentResponse {
+    pub merchant_id: id_type::MerchantId,
+
+    /// Whether the health of the saved payment methods of the merchant is periodically evaluated
+    pub enrolled: bool,
+
+    /// The time at which the health of the saved payment methods of the merchant is evaluated next
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub next_run_at: Option<time::PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct PaymentMethodFingerprintLinksRequest {
+    /// The maximum number of payments made with the card to be listed
+    pub limit: Option<u32>,
+}
+
+/// The customers and payments of the merchant linked by the fingerprint of a card
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentMethodFingerprintLinksResponse {
+    pub fingerprint_id: String,
+
+    /// The number of customers who have saved the card
+    pub customer_count: usize,
+
+    /// The customers who have saved the card, the most recently used first
+    pub customers: Vec<FingerprintLinkedCustomer>,
+
+    /// The payments made with the card, the most recent first
+    pub payments: Vec<FingerprintLinkedPayment>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FingerprintLinkedCustomer {
+    pub customer_id: id_type::CustomerId,
+
+    /// The payment methods of the customer saved with the card
+    pub payment_method_ids: Vec<String>,
+
+    /// The time at which the card was last used by the customer
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_used_at: time::PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct FingerprintLinkedPayment {
+    pub payment_id: id_type::PaymentId,
+    pub attempt_id: String,
+    pub status: api_enums::AttemptStatus,
+    pub amount: MinorUnit,
+    pub currency: Option<api_enums::Currency>,
+    pub payment_method_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
 // #[cfg(feature = "v2")]
 // impl From<PaymentMethodRecord> for customers::CustomerRequest {
 //     fn from(record: PaymentMethodRecord) -> Self {
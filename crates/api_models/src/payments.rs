@@ -3890,6 +3890,11 @@ pub struct PaymentsResponse {
     #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
     pub updated: Option<PrimitiveDateTime>,
 
+    /// Date time at which the authorization of the payment lapses at the issuer, if the payment is authorized and yet to be captured
+    #[schema(example = "2022-09-17T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+
     /// Fee information to be charged on the payment being collected
     pub charges: Option<PaymentChargeResponse>,
 
@@ -4119,6 +4124,63 @@ pub struct PaymentsAggregateResponse {
     pub status_with_count: HashMap<enums::IntentStatus, i64>,
 }
 
+/// The constraints to apply when listing payments whose authorization is about to lapse
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ExpiringAuthorizationsListConstraints {
+    /// List payments whose authorization lapses within the specified number of hours, defaults to 24 hours
+    pub expiring_within_hours: Option<u32>,
+
+    /// List payments created under the specified profile
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// Include at most the specified number of payments, defaults to 100
+    pub limit: Option<u32>,
+}
+
+/// The details of a payment whose authorization is about to lapse
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ExpiringAuthorizationResponse {
+    /// The identifier for the payment
+    #[schema(value_type = String)]
+    pub payment_id: id_type::PaymentId,
+
+    /// The identifier for the profile the payment was created under
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// The status of the payment
+    #[schema(value_type = IntentStatus)]
+    pub status: enums::IntentStatus,
+
+    /// The authorized amount of the payment
+    #[schema(value_type = i64, example = 6540)]
+    pub amount: MinorUnit,
+
+    /// The currency of the payment
+    #[schema(value_type = Option<Currency>)]
+    pub currency: Option<enums::Currency>,
+
+    /// Time at which the authorization of the payment lapses at the issuer
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub authorization_expires_at: PrimitiveDateTime,
+
+    /// Time at which the payment was created
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+}
+
+/// The payments whose authorization is about to lapse, the earliest expiring first
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct ExpiringAuthorizationsListResponse {
+    /// The number of payments included in the list
+    pub count: usize,
+
+    /// The list of payments
+    pub data: Vec<ExpiringAuthorizationResponse>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
 pub struct AmountFilter {
     /// The start amount to filter list of transactions which are greater than or equal to the start amount
@@ -5034,6 +5096,10 @@ pub struct PaymentsIncrementalAuthorizationRequest {
     pub amount: MinorUnit,
     /// Reason for incremental authorization
     pub reason: Option<String>,
+    /// Whether the authorized amount is re-authorized to extend the validity of the
+    /// authorization, rather than incremented
+    #[serde(skip)]
+    pub is_reauthorization: bool,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize, Clone, ToSchema)]
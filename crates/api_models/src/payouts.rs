@@ -1,4 +1,7 @@
+use std::collections::HashMap;
+
 use cards::CardNumber;
+pub use common_utils::types::PayoutRecurrence;
 use common_utils::{
     consts::default_payouts_list_limit,
     crypto, id_type, link_utils,
@@ -151,6 +154,17 @@ pub struct PayoutCreateRequest {
     #[schema(value_type = Option<PayoutSendPriority>, example = "instant")]
     pub priority: Option<api_enums::PayoutSendPriority>,
 
+    /// Time at which the payout is to be executed, in ISO 8601 format. A scheduled payout is executed by the scheduler instead of on confirmation, and the connector for it is chosen at the time of execution. Can only be passed during the Payout _Create_ along with `confirm` set to true.
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    #[remove_in(PayoutUpdateRequest, PayoutConfirmRequest)]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// Recurrence of the payout, for executing it repeatedly on a schedule. Every execution schedules the next payout of the recurrence, which can be cancelled before it is executed to stop the recurrence.
+    #[schema(value_type = Option<PayoutRecurrence>)]
+    #[remove_in(PayoutUpdateRequest, PayoutConfirmRequest)]
+    pub recurrence: Option<PayoutRecurrence>,
+
     /// Whether to get the payout link (if applicable). Merchant need to specify this during the Payout _Create_, this field can not be updated during Payout _Update_.
     #[schema(default = false, example = true, value_type = Option<bool>)]
     pub payout_link: Option<bool>,
@@ -504,6 +518,15 @@ pub struct PayoutCreateResponse {
     #[schema(value_type = Option<PayoutSendPriority>, example = "instant")]
     pub priority: Option<api_enums::PayoutSendPriority>,
 
+    /// Time at which the payout is scheduled to be executed (if applicable)
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+
+    /// Recurrence of the payout (if applicable)
+    #[schema(value_type = Option<PayoutRecurrence>)]
+    pub recurrence: Option<PayoutRecurrence>,
+
     /// List of attempts
     #[schema(value_type = Option<Vec<PayoutAttemptResponse>>)]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -799,3 +822,125 @@ pub struct PayoutLinkStatusDetails {
     pub ui_config: link_utils::GenericLinkUiConfigFormData,
     pub test_mode: bool,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct PayoutBatchCreateRequest {
+    /// The identifier of the file uploaded with the purpose `payout_batch`, containing one payout per row
+    #[schema(example = "file_4tqnmgkdZrVD3kGWFYpD")]
+    pub file_id: String,
+
+    /// The business profile under which the payouts of this batch are to be created. If not provided, the default business profile of the merchant account is used.
+    #[schema(value_type = Option<String>)]
+    pub profile_id: Option<id_type::ProfileId>,
+
+    /// Whether the payouts created from this batch are to be confirmed
+    #[schema(value_type = Option<bool>, default = true, example = true)]
+    pub confirm: Option<bool>,
+
+    /// Whether the payouts created from this batch are to be fulfilled without review
+    #[schema(value_type = Option<bool>, default = true, example = true)]
+    pub auto_fulfill: Option<bool>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+pub struct PayoutBatchRetrieveRequest {
+    /// The identifier for the payout batch
+    #[schema(example = "batch_ghAZsbf4nSwI8QwcP5Hj")]
+    pub batch_id: String,
+}
+
+/// A single row of a payout batch file in CSV format. Rows of a JSON batch file accept the complete payout create request instead.
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct PayoutBatchRecord {
+    pub payout_id: Option<String>,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub customer_id: Option<id_type::CustomerId>,
+    pub payout_token: Option<String>,
+    pub payout_type: Option<api_enums::PayoutType>,
+    pub connector: Option<api_enums::PayoutConnectors>,
+    pub priority: Option<api_enums::PayoutSendPriority>,
+    pub entity_type: Option<api_enums::PayoutEntityType>,
+    pub description: Option<String>,
+}
+
+impl From<PayoutBatchRecord> for PayoutCreateRequest {
+    fn from(record: PayoutBatchRecord) -> Self {
+        Self {
+            payout_id: record.payout_id,
+            amount: Some(payments::Amount::from(common_utils::types::MinorUnit::new(
+                record.amount,
+            ))),
+            currency: Some(record.currency),
+            customer_id: record.customer_id,
+            payout_token: record.payout_token,
+            payout_type: record.payout_type,
+            connector: record.connector.map(|connector| vec![connector]),
+            priority: record.priority,
+            entity_type: record.entity_type,
+            description: record.description,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct PayoutBatchResponse {
+    /// The identifier for the payout batch
+    #[schema(example = "batch_ghAZsbf4nSwI8QwcP5Hj")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant account
+    #[schema(value_type = String, example = "merchant_1668273825")]
+    pub merchant_id: id_type::MerchantId,
+
+    /// The business profile under which the payouts of this batch are created
+    #[schema(value_type = String)]
+    pub profile_id: id_type::ProfileId,
+
+    /// The identifier of the uploaded batch file
+    #[schema(example = "file_4tqnmgkdZrVD3kGWFYpD")]
+    pub file_id: String,
+
+    /// The format of the uploaded batch file
+    #[schema(value_type = PayoutBatchFileFormat, example = "csv")]
+    pub file_format: api_enums::PayoutBatchFileFormat,
+
+    /// The processing status of the batch
+    #[schema(value_type = PayoutBatchStatus, example = "processing")]
+    pub status: api_enums::PayoutBatchStatus,
+
+    /// The number of rows in the batch file
+    pub total_count: i32,
+
+    /// The number of rows processed so far
+    pub processed_count: i32,
+
+    /// The number of rows for which a payout could not be created
+    pub failed_count: i32,
+
+    /// The number of payouts created from this batch, grouped by their current status
+    #[schema(value_type = Object, example = json!({"success": 10, "pending": 2}))]
+    pub status_with_count: HashMap<api_enums::PayoutStatus, i64>,
+
+    /// Time when the batch was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time when the batch was last modified
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+/// A single row of the downloadable result report of a payout batch
+#[derive(Clone, Debug, serde::Serialize)]
+pub struct PayoutBatchReportRecord {
+    pub row_number: i32,
+    pub payout_id: Option<String>,
+    pub status: Option<api_enums::PayoutStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
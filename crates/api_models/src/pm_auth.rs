@@ -1,7 +1,8 @@
-use common_enums::{PaymentMethod, PaymentMethodType};
+use common_enums::{Currency, IntentStatus, PaymentMethod, PaymentMethodType, RefundStatus};
 use common_utils::{
     events::{ApiEventMetric, ApiEventsType},
     id_type, impl_api_event_type,
+    types::MinorUnit,
 };
 
 #[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
@@ -49,12 +50,61 @@ pub struct PaymentMethodAuthConnectorChoice {
     pub mca_id: id_type::MerchantConnectorAccountId,
 }
 
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PaymentInitiationCreateRequest {
+    pub client_secret: Option<String>,
+    pub payment_id: id_type::PaymentId,
+    pub payment_token: String, // token of the bank account verified through the exchange token flow
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PaymentInitiationSyncRequest {
+    pub client_secret: Option<String>,
+    pub payment_id: id_type::PaymentId,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentInitiationResponse {
+    pub payment_id: id_type::PaymentId,
+    pub status: IntentStatus,
+    pub connector: String, // pm_auth connector the payment was initiated through
+    pub connector_payment_id: Option<String>, // payment id at the pm_auth connector
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct PaymentInitiationReversalRequest {
+    #[serde(skip_deserializing)]
+    pub payment_id: id_type::PaymentId, // payment_id is taken from the path
+    pub amount: Option<MinorUnit>, // defaults to the full payment amount
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct PaymentInitiationReversalResponse {
+    pub refund_id: String,
+    pub payment_id: id_type::PaymentId,
+    pub amount: MinorUnit,
+    pub currency: Currency,
+    pub status: RefundStatus,
+    pub connector: String,
+}
+
 impl_api_event_type!(
     Miscellaneous,
     (
         LinkTokenCreateRequest,
         LinkTokenCreateResponse,
         ExchangeTokenCreateRequest,
-        ExchangeTokenCreateResponse
+        ExchangeTokenCreateResponse,
+        PaymentInitiationCreateRequest,
+        PaymentInitiationSyncRequest,
+        PaymentInitiationResponse,
+        PaymentInitiationReversalRequest,
+        PaymentInitiationReversalResponse
     )
 );
@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when searching scheduled tasks.
+#[derive(Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct ProcessTrackerListConstraints {
+    /// Filter tasks executed by the specified runner, such as `PAYMENTS_SYNC_WORKFLOW`.
+    pub runner: Option<String>,
+
+    /// Filter tasks in the specified status, such as `new`, `pending` or `finish`.
+    pub status: Option<String>,
+
+    /// Filter tasks with the specified business status.
+    pub business_status: Option<String>,
+
+    /// Filter tasks created on behalf of the specified merchant.
+    #[schema(value_type = Option<String>)]
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+
+    /// Filter tasks scheduled at or after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_after: Option<PrimitiveDateTime>,
+
+    /// Filter tasks scheduled at or before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_before: Option<PrimitiveDateTime>,
+
+    /// Include at most the specified number of tasks.
+    pub limit: Option<u16>,
+
+    /// Include tasks after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// The details of a scheduled task.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProcessTrackerResponse {
+    /// The identifier for the task.
+    pub id: String,
+
+    /// The name of the task.
+    pub name: Option<String>,
+
+    /// The runner that executes the task.
+    pub runner: Option<String>,
+
+    /// The status of the task in the scheduler.
+    pub status: String,
+
+    /// The status of the task as recorded by its workflow.
+    pub business_status: String,
+
+    /// The data the workflow operates on.
+    #[schema(value_type = Object)]
+    pub tracking_data: serde_json::Value,
+
+    /// The number of times the task has been retried.
+    pub retry_count: i32,
+
+    /// Time at which the task is scheduled to run next.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub schedule_time: Option<PrimitiveDateTime>,
+
+    /// The priority of the task, higher priority tasks are scheduled first.
+    pub priority: i16,
+
+    /// The cron expression the task recurs on, if it is a recurring task.
+    pub cron_expression: Option<String>,
+
+    /// The tags attached to the task.
+    pub tag: Vec<String>,
+
+    /// Time at which the task was created.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+
+    /// Time at which the task was last updated.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub updated_at: PrimitiveDateTime,
+}
+
+/// The request body for rescheduling a task.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ProcessTrackerRescheduleRequest {
+    /// Time at which the task should be run.
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub schedule_time: PrimitiveDateTime,
+}
+
+/// The pause state of a runner.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ProcessTrackerRunnerResponse {
+    /// The name of the runner.
+    pub runner: String,
+
+    /// Whether the scheduler has stopped scheduling tasks of the runner.
+    pub is_paused: bool,
+
+    /// The user who paused the runner.
+    pub paused_by: Option<String>,
+
+    /// The number of overdue tasks that were made available to the scheduler again when the
+    /// runner was resumed.
+    pub rescheduled_tasks: Option<usize>,
+}
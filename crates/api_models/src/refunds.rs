@@ -11,6 +11,8 @@ use crate::{
     admin::{self, MerchantConnectorInfo},
     enums,
 };
+#[cfg(feature = "payouts")]
+use crate::{payments, payouts};
 
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -59,6 +61,77 @@ pub struct RefundRequest {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+
+    /// Details of the payout used to send the refund amount to the customer, when the original payment method cannot receive the refund
+    #[cfg(feature = "payouts")]
+    #[schema(value_type = Option<RefundPayoutDetails>)]
+    pub payout_details: Option<RefundPayoutDetails>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundPayoutDetails {
+    /// Indicates when the refund amount has to be sent as a payout
+    #[serde(default)]
+    #[schema(value_type = RefundPayoutTrigger, default = "on_refund_failure")]
+    pub trigger: RefundPayoutTrigger,
+
+    /// The payout method to which the refund amount is sent
+    #[schema(value_type = RefundPayoutMethod)]
+    pub payout_method: RefundPayoutMethod,
+}
+
+/// Indicates when the refund amount has to be sent as a payout
+#[cfg(feature = "payouts")]
+#[derive(
+    Default, Debug, Clone, Copy, ToSchema, Deserialize, Serialize, Eq, PartialEq, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundPayoutTrigger {
+    /// The refund is sent as a payout only if the connector fails to refund the original payment method
+    #[default]
+    OnRefundFailure,
+    /// The refund is always sent as a payout, without refunding the original payment method
+    Always,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundPayoutMethod {
+    /// The payout method information provided by the customer
+    #[schema(value_type = Option<PayoutMethodData>)]
+    pub payout_method_data: Option<payouts::PayoutMethodData>,
+
+    /// Reference to a payout method saved for the customer
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_token: Option<String>,
+
+    /// The type of the payout method
+    #[schema(value_type = Option<PayoutType>, example = "card")]
+    pub payout_type: Option<enums::PayoutType>,
+
+    /// The payout connectors which can be used for sending the refund amount
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<enums::PayoutConnectors>>,
+
+    /// The billing address of the customer receiving the payout
+    #[schema(value_type = Option<Address>)]
+    pub billing: Option<payments::Address>,
+}
+
+#[cfg(feature = "payouts")]
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundPayoutRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+
+    /// The payout method to which the refund amount is sent
+    #[schema(value_type = RefundPayoutMethod)]
+    pub payout_method: RefundPayoutMethod,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -98,6 +171,19 @@ pub struct RefundUpdateRequest {
     pub metadata: Option<pii::SecretSerdeValue>,
 }
 
+#[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RefundApprovalRequest {
+    #[serde(skip)]
+    pub refund_id: String,
+    /// The reason for approving or rejecting the refund. When the refund is rejected, this is stored as the refund error message
+    #[schema(
+        max_length = 255,
+        example = "Refund amount verified against the returned order"
+    )]
+    pub reason: Option<String>,
+}
+
 #[derive(Default, Debug, ToSchema, Clone, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct RefundManualUpdateRequest {
@@ -166,6 +252,9 @@ pub struct RefundResponse {
     /// Charge specific fields for controlling the revert of funds from either platform or connected account
     #[schema(value_type = Option<ChargeRefunds>)]
     pub charges: Option<ChargeRefunds>,
+    /// The identifier of the payout through which the refund amount was sent, when the refund was routed as a payout
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -255,6 +344,7 @@ pub enum RefundStatus {
     #[default]
     Pending,
     Review,
+    PendingApproval,
 }
 
 impl From<enums::RefundStatus> for RefundStatus {
@@ -264,6 +354,7 @@ impl From<enums::RefundStatus> for RefundStatus {
             enums::RefundStatus::ManualReview => Self::Review,
             enums::RefundStatus::Pending => Self::Pending,
             enums::RefundStatus::Success => Self::Succeeded,
+            enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
@@ -275,6 +366,7 @@ impl From<RefundStatus> for enums::RefundStatus {
             RefundStatus::Review => Self::ManualReview,
             RefundStatus::Pending => Self::Pending,
             RefundStatus::Succeeded => Self::Success,
+            RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
@@ -115,6 +115,7 @@ impl EuclidDirFilter for ConnectorSelection {
         DirKeyKind::CryptoType,
         DirKeyKind::MetaData,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::CardFingerprintCustomerCount,
         DirKeyKind::PaymentCurrency,
         DirKeyKind::AuthenticationType,
         DirKeyKind::MandateAcceptanceType,
@@ -37,6 +37,8 @@ pub enum Permission {
     PayoutRead,
     WebhookEventWrite,
     GenerateReport,
+    ProcessTrackerRead,
+    ProcessTrackerWrite,
 }
 
 #[derive(Clone, Debug, serde::Serialize, PartialEq, Eq, Hash)]
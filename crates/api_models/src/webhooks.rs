@@ -5,7 +5,7 @@ use utoipa::ToSchema;
 
 #[cfg(feature = "payouts")]
 use crate::payouts;
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{disputes, enums as api_enums, mandates, payment_methods, payments, refunds};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -239,6 +239,8 @@ pub enum OutgoingWebhookContent {
     #[cfg(feature = "payouts")]
     #[schema(value_type = PayoutCreateResponse, title = "PayoutCreateResponse")]
     PayoutDetails(payouts::PayoutCreateResponse),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -1208,6 +1208,7 @@ pub enum EventClass {
     Mandates,
     #[cfg(feature = "payouts")]
     Payouts,
+    PaymentMethods,
 }
 
 #[derive(
@@ -1253,6 +1254,11 @@ pub enum EventType {
     PayoutCancelled,
     PayoutExpired,
     PayoutReversed,
+    /// The card details of a saved payment method were updated, or its account was closed
+    PaymentMethodUpdated,
+    /// The health status of a saved payment method changed, for instance when its card is about
+    /// to expire
+    PaymentMethodHealthUpdated,
 }
 
 #[derive(
@@ -1598,6 +1604,7 @@ pub enum PaymentMethodType {
     Mifinity,
     #[serde(rename = "open_banking_pis")]
     OpenBankingPIS,
+    PayByBank,
 }
 
 /// Indicates the type of payment method. Eg: 'card', 'wallet', etc.
@@ -1692,6 +1699,8 @@ pub enum RefundStatus {
     Success,
     #[serde(alias = "TransactionFailure")]
     TransactionFailure,
+    /// The refund exceeds the approval threshold of the profile's refund policy and is awaiting approval
+    PendingApproval,
 }
 
 #[derive(
@@ -2378,6 +2387,473 @@ pub enum PayoutEntityType {
     Personal,
 }
 
+/// The status of a payout batch
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchStatus {
+    /// The batch has been created and is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// Payouts are being created for the rows of the batch file
+    Processing,
+    /// All the rows of the batch file have been processed
+    Completed,
+    /// The batch file could not be processed
+    Failed,
+}
+
+/// The format of the file from which a payout batch is created
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutBatchFileFormat {
+    Csv,
+    Json,
+}
+
+/// The status of a data key of the embedded card vault
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultDataKeyStatus {
+    /// The key encrypts the data stored in the vault
+    Active,
+    /// The data encrypted with the key is being re-encrypted with the active key
+    Rotating,
+    /// The re-encryption of the data encrypted with the key failed, rotating the data key of the
+    /// merchant again restarts it
+    RotationFailed,
+    /// No data is encrypted with the key anymore
+    Retired,
+}
+
+/// An operation performed on the embedded card vault, recorded in its access logs
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VaultAccessAction {
+    Store,
+    Retrieve,
+    Delete,
+    ReEncrypt,
+    RotateKey,
+}
+
+/// The status of a payment method migration job
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodMigrationStatus {
+    /// The migration has been created and is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// The records of the migration file are being migrated
+    Processing,
+    /// All the records of the migration file have been processed
+    Completed,
+    /// The migration file could not be processed
+    Failed,
+}
+
+/// The format of the file from which payment methods are migrated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodMigrationFileFormat {
+    Csv,
+    /// One JSON object per line
+    Jsonl,
+}
+
+/// The result of migrating a single record of a payment method migration file
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodMigrationItemStatus {
+    /// The record is being migrated
+    #[default]
+    Pending,
+    Succeeded,
+    Failed,
+}
+
+/// The kind of request a customer (the data subject) made about the personal data held about them
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DataSubjectRequestType {
+    /// Export of all the data tied to the customer
+    Export,
+    /// Erasure of all the personal data tied to the customer
+    Erasure,
+}
+
+/// The status of a data subject request
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DataSubjectRequestStatus {
+    /// The request has been created and is waiting to be picked up by the scheduler
+    #[default]
+    Pending,
+    /// The data of the customer is being processed
+    Processing,
+    /// The data of the customer has been processed in all the stores
+    Completed,
+    /// The data of the customer could not be processed in all the stores
+    Failed,
+}
+
+/// The purpose of an address saved against a customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerAddressType {
+    /// Address used for invoicing the customer
+    Billing,
+    /// Address the goods are delivered to
+    Shipping,
+}
+
+/// The kind of tax identifier held by a customer
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CustomerTaxIdType {
+    /// European Union VAT number
+    EuVat,
+    /// United Kingdom VAT number
+    GbVat,
+    /// Indian Goods and Services Tax Identification Number
+    InGst,
+    /// Australian Business Number
+    AuAbn,
+    /// Canadian GST/HST registration number
+    CaGst,
+    /// Singaporean GST registration number
+    SgGst,
+}
+
+/// The reason a saved card is submitted to the card account updater
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterReason {
+    /// The card expires within the configured window
+    CardExpiring,
+    /// A payment made with the card was declined
+    PaymentFailed,
+}
+
+/// The status of a saved card submitted to the card account updater
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterItemStatus {
+    /// The card is waiting to be submitted in the next batch
+    #[default]
+    Pending,
+    /// The card was submitted and the result is awaited
+    Submitted,
+    /// The result of the card was received and applied
+    Completed,
+    /// The card could not be submitted, or its result could not be applied
+    Failed,
+}
+
+/// The update reported by the card account updater for a saved card
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AccountUpdaterOutcome {
+    NoChange,
+    NewAccountNumber,
+    NewExpiry,
+    AccountClosed,
+    ContactCardholder,
+}
+
+/// The health of a saved payment method, as evaluated by the payment method health sweep
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodHealthStatus {
+    /// The payment method can be used for payments
+    #[default]
+    Healthy,
+    /// The card expires within the configured window
+    ExpiringSoon,
+    /// The card has expired
+    Expired,
+    /// Merchant initiated payments made with the payment method were declined repeatedly
+    RepeatedDeclines,
+}
+
+impl PaymentMethodHealthStatus {
+    /// Payments made with an unhealthy payment method are likely to be declined, so such payment
+    /// methods are not preferred when listing the saved payment methods of a customer
+    pub fn is_unhealthy(self) -> bool {
+        match self {
+            Self::Healthy | Self::ExpiringSoon => false,
+            Self::Expired | Self::RepeatedDeclines => true,
+        }
+    }
+}
+
+/// The interval at which a recurring payout is executed
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PayoutRecurrenceInterval {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// The action taken on an authorized payment whose authorization would lapse at the issuer before
+/// it is captured
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthorizationExpiryAction {
+    /// Capture the payment before the authorization expires
+    #[default]
+    Capture,
+    /// Void the payment before the authorization expires, releasing the hold on the funds
+    Void,
+    /// Extend the hold on the funds by re-authorizing the payment for the same amount through an
+    /// incremental authorization. Payments which do not allow incremental authorization are voided
+    /// instead.
+    Reauthorize,
+}
+
 /// The send method which will be required for processing payouts, check options for better understanding.
 #[derive(
     Clone,
@@ -3000,6 +3476,7 @@ pub enum GenericLinkType {
     #[default]
     PaymentMethodCollect,
     PayoutLink,
+    PaymentMethodManage,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, strum::Display, serde::Deserialize, serde::Serialize)]
@@ -1883,6 +1883,7 @@ impl From<PaymentMethodType> for PaymentMethod {
             PaymentMethodType::Seicomart => Self::Voucher,
             PaymentMethodType::PayEasy => Self::Voucher,
             PaymentMethodType::OpenBankingPIS => Self::OpenBanking,
+            PaymentMethodType::PayByBank => Self::OpenBanking,
         }
     }
 }
@@ -0,0 +1,278 @@
+//! Parsing and evaluation of cron expressions, used for scheduling recurring tasks.
+
+use error_stack::report;
+use time::{Date, PrimitiveDateTime, Time};
+
+use crate::errors::{CustomResult, ValidationError};
+
+/// Maximum number of days looked ahead while searching for the next occurrence of a schedule.
+/// Eight years covers every valid expression, including ones that only match on February 29th
+/// across a non-leap century year.
+const MAX_LOOKAHEAD_DAYS: u16 = 8 * 366;
+
+const MONTH_NAMES: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+
+const DAY_OF_WEEK_NAMES: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// A parsed five field cron expression (`minute hour day-of-month month day-of-week`).
+///
+/// Each field accepts `*`, single values, ranges (`1-5`), lists (`1,15`) and steps (`*/15`,
+/// `0-30/10`). Months and days of week additionally accept three letter names (`JAN`, `MON`),
+/// and both `0` and `7` denote Sunday. As with the classic cron implementations, when both the
+/// day-of-month and the day-of-week fields are restricted, a day matches if either of them
+/// matches. All times are evaluated in UTC.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CronSchedule {
+    expression: String,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    day_of_month_restricted: bool,
+    day_of_week_restricted: bool,
+}
+
+impl CronSchedule {
+    /// Parses the provided cron expression
+    ///
+    /// It returns a [ValidationError::InvalidValue] in case the expression is malformed
+    pub fn parse(expression: &str) -> CustomResult<Self, ValidationError> {
+        let fields = expression.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day_of_month, month, day_of_week] = fields.as_slice() else {
+            return Err(report!(ValidationError::InvalidValue {
+                message: format!(
+                    "Cron expression `{expression}` must have exactly 5 fields, found {}",
+                    fields.len()
+                ),
+            }));
+        };
+
+        let invalid_field = |field_name: &str, field: &str| {
+            report!(ValidationError::InvalidValue {
+                message: format!(
+                    "Invalid {field_name} field `{field}` in cron expression `{expression}`"
+                ),
+            })
+        };
+
+        let minutes =
+            parse_field(minute, 0, 59, &[]).ok_or_else(|| invalid_field("minute", minute))?;
+        let hours = parse_field(hour, 0, 23, &[]).ok_or_else(|| invalid_field("hour", hour))?;
+        let days_of_month = parse_field(day_of_month, 1, 31, &[])
+            .ok_or_else(|| invalid_field("day of month", day_of_month))?;
+        let months =
+            parse_field(month, 1, 12, &MONTH_NAMES).ok_or_else(|| invalid_field("month", month))?;
+        let days_of_week = parse_field(day_of_week, 0, 7, &DAY_OF_WEEK_NAMES)
+            .ok_or_else(|| invalid_field("day of week", day_of_week))?;
+
+        // Sunday can be written as either 0 or 7
+        let days_of_week = if days_of_week & (1 << 7) != 0 {
+            (days_of_week & !(1 << 7)) | 1
+        } else {
+            days_of_week
+        };
+
+        Ok(Self {
+            expression: fields.join(" "),
+            minutes,
+            hours,
+            days_of_month,
+            months,
+            days_of_week,
+            day_of_month_restricted: !day_of_month.starts_with('*'),
+            day_of_week_restricted: !day_of_week.starts_with('*'),
+        })
+    }
+
+    /// Returns the normalized cron expression this schedule was parsed from
+    pub fn expression(&self) -> &str {
+        &self.expression
+    }
+
+    /// Returns the first time strictly after `after` at which the schedule fires, truncated to
+    /// the minute. Returns `None` if the schedule never fires again, which can only happen for
+    /// expressions that match impossible dates (such as `0 0 31 2 *`).
+    pub fn next_occurrence_after(&self, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        let start = after
+            .replace_second(0)
+            .ok()?
+            .replace_nanosecond(0)
+            .ok()?
+            .checked_add(time::Duration::minutes(1))?;
+
+        let mut date = start.date();
+        let (mut from_hour, mut from_minute) = (start.hour(), start.minute());
+
+        for _ in 0..MAX_LOOKAHEAD_DAYS {
+            if self.matches_date(date) {
+                if let Some(time) = self.first_time_from(from_hour, from_minute) {
+                    return Some(date.with_time(time));
+                }
+            }
+            date = date.next_day()?;
+            (from_hour, from_minute) = (0, 0);
+        }
+
+        None
+    }
+
+    fn matches_date(&self, date: Date) -> bool {
+        if !is_set(self.months, u8::from(date.month())) {
+            return false;
+        }
+
+        let day_of_month_matches = is_set(self.days_of_month, date.day());
+        let day_of_week_matches =
+            is_set(self.days_of_week, date.weekday().number_days_from_sunday());
+
+        match (self.day_of_month_restricted, self.day_of_week_restricted) {
+            (true, true) => day_of_month_matches || day_of_week_matches,
+            _ => day_of_month_matches && day_of_week_matches,
+        }
+    }
+
+    fn first_time_from(&self, from_hour: u8, from_minute: u8) -> Option<Time> {
+        (from_hour..24)
+            .filter(|hour| is_set(self.hours, *hour))
+            .find_map(|hour| {
+                let from_minute = if hour == from_hour { from_minute } else { 0 };
+                (from_minute..60)
+                    .find(|minute| is_set(self.minutes, *minute))
+                    .and_then(|minute| Time::from_hms(hour, minute, 0).ok())
+            })
+    }
+}
+
+impl std::fmt::Display for CronSchedule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.expression)
+    }
+}
+
+fn is_set(bits: u64, value: u8) -> bool {
+    bits & (1 << value) != 0
+}
+
+/// Parses a single cron field into a bitset of the values it matches
+fn parse_field(field: &str, min: u8, max: u8, names: &[&str]) -> Option<u64> {
+    field.split(',').try_fold(0u64, |bits, item| {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u8>().ok().filter(|step| *step > 0)?),
+            None => (item, 1),
+        };
+
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (
+                    parse_value(start, min, names)?,
+                    parse_value(end, min, names)?,
+                ),
+                // A single value with a step, such as `5/15`, runs from the value till the maximum
+                None if item.contains('/') => (parse_value(range, min, names)?, max),
+                None => {
+                    let value = parse_value(range, min, names)?;
+                    (value, value)
+                }
+            },
+        };
+
+        if start < min || end > max || start > end {
+            return None;
+        }
+
+        Some(
+            (start..=end)
+                .step_by(usize::from(step))
+                .fold(bits, |bits, value| bits | (1 << value)),
+        )
+    })
+}
+
+fn parse_value(value: &str, min: u8, names: &[&str]) -> Option<u8> {
+    value.parse::<u8>().ok().or_else(|| {
+        names
+            .iter()
+            .position(|name| name.eq_ignore_ascii_case(value))
+            .and_then(|position| u8::try_from(position).ok())
+            .map(|position| position + min)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_occurrence_for_step_and_range_fields() {
+        let schedule = CronSchedule::parse("*/15 9-17 * * MON-FRI").unwrap();
+
+        // Friday evening rolls over to Monday morning
+        assert_eq!(
+            schedule.next_occurrence_after(datetime!(2024-09-06 17:50)),
+            Some(datetime!(2024-09-09 09:00))
+        );
+        assert_eq!(
+            schedule.next_occurrence_after(datetime!(2024-09-09 09:00)),
+            Some(datetime!(2024-09-09 09:15))
+        );
+        assert_eq!(
+            schedule.next_occurrence_after(datetime!(2024-09-09 10:07:42)),
+            Some(datetime!(2024-09-09 10:15))
+        );
+    }
+
+    #[test]
+    fn test_day_of_month_and_day_of_week_are_combined() {
+        // Fires on the first of every month as well as on every Sunday
+        let schedule = CronSchedule::parse("0 0 1 * 7").unwrap();
+
+        assert_eq!(
+            schedule.next_occurrence_after(datetime!(2024-09-02 00:00)),
+            Some(datetime!(2024-09-08 00:00))
+        );
+        assert_eq!(
+            schedule.next_occurrence_after(datetime!(2024-09-29 00:00)),
+            Some(datetime!(2024-10-01 00:00))
+        );
+    }
+
+    #[test]
+    fn test_leap_day_schedule() {
+        let schedule = CronSchedule::parse("30 12 29 FEB *").unwrap();
+
+        assert_eq!(
+            schedule.next_occurrence_after(datetime!(2024-03-01 00:00)),
+            Some(datetime!(2028-02-29 12:30))
+        );
+        assert!(CronSchedule::parse("0 0 31 2 *")
+            .unwrap()
+            .next_occurrence_after(datetime!(2024-01-01 00:00))
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_expressions() {
+        for expression in [
+            "* * * *",
+            "60 * * * *",
+            "* 24 * * *",
+            "* * 0 * *",
+            "* * * 13 *",
+            "*/0 * * * *",
+            "5-1 * * * *",
+            "* * * FOO *",
+        ] {
+            assert!(
+                CronSchedule::parse(expression).is_err(),
+                "expected `{expression}` to be rejected"
+            );
+        }
+    }
+}
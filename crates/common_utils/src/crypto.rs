@@ -437,6 +437,13 @@ pub fn generate_cryptographically_secure_random_bytes<const N: usize>() -> [u8;
     bytes
 }
 
+/// Compare two secrets (e.g. API keys) without leaking their contents through the time taken.
+/// The secrets are hashed first, as comparing BLAKE3 hashes is done in constant time.
+#[inline]
+pub fn is_equal_in_constant_time(secret: &[u8], other: &[u8]) -> bool {
+    blake3::hash(secret) == blake3::hash(other)
+}
+
 ///
 /// A wrapper type to store the encrypted data for sensitive pii domain data types
 ///
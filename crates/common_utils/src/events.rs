@@ -15,6 +15,9 @@ pub enum ApiEventsType {
     Payout {
         payout_id: String,
     },
+    PayoutBatch {
+        batch_id: String,
+    },
     Payment {
         payment_id: id_type::PaymentId,
     },
@@ -55,10 +58,12 @@ pub enum ApiEventsType {
         payment_id: Option<id_type::PaymentId>,
     },
     Gsm,
+    ProcessTracker,
     // TODO: This has to be removed once the corresponding apiEventTypes are created
     Miscellaneous,
     Keymanager,
     RustLocker,
+    EmbeddedVault,
     ApplePayCertificatesMigration,
     FraudCheck,
     Recon,
@@ -71,6 +76,12 @@ pub enum ApiEventsType {
     PaymentMethodCollectLink {
         link_id: String,
     },
+    PaymentMethodManageLink {
+        link_id: String,
+    },
+    PaymentMethodMigration {
+        migration_id: String,
+    },
     Poll {
         poll_id: String,
     },
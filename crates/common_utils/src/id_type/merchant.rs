@@ -204,6 +204,11 @@ impl MerchantId {
         format!("should_call_gsm_{}", self.get_string_repr())
     }
 
+    /// get_pre_dispute_alert_rule_key
+    pub fn get_pre_dispute_alert_rule_key(&self) -> String {
+        format!("pre_dispute_alert_rule_{}", self.get_string_repr())
+    }
+
     /// get_max_auto_single_connector_payout_retries_enabled_
     pub fn get_max_auto_single_connector_payout_retries_enabled(
         &self,
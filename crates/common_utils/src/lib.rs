@@ -5,6 +5,7 @@ use masking::{PeekInterface, Secret};
 
 pub mod access_token;
 pub mod consts;
+pub mod cron;
 pub mod crypto;
 pub mod custom_serde;
 #[allow(missing_docs)] // Todo: add docs
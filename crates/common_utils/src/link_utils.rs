@@ -32,6 +32,8 @@ pub enum GenericLinkStatus {
     PaymentMethodCollect(PaymentMethodCollectStatus),
     /// Status variants for payout link
     PayoutLink(PayoutLinkStatus),
+    /// Status variants for payment method manage link
+    PaymentMethodManage(PaymentMethodManageStatus),
 }
 
 impl Default for GenericLinkStatus {
@@ -66,9 +68,9 @@ where
         let generic_status: GenericLinkStatus = serde_json::from_value(value)?;
         match generic_status {
             GenericLinkStatus::PaymentMethodCollect(status) => Ok(status),
-            GenericLinkStatus::PayoutLink(_) => Err(report!(ParsingError::EnumParseFailure(
-                "PaymentMethodCollectStatus"
-            )))
+            GenericLinkStatus::PayoutLink(_) | GenericLinkStatus::PaymentMethodManage(_) => Err(
+                report!(ParsingError::EnumParseFailure("PaymentMethodCollectStatus")),
+            )
             .attach_printable("Invalid status for PaymentMethodCollect")?,
         }
     }
@@ -115,7 +117,8 @@ where
         let generic_status: GenericLinkStatus = serde_json::from_value(value)?;
         match generic_status {
             GenericLinkStatus::PayoutLink(status) => Ok(status),
-            GenericLinkStatus::PaymentMethodCollect(_) => {
+            GenericLinkStatus::PaymentMethodCollect(_)
+            | GenericLinkStatus::PaymentMethodManage(_) => {
                 Err(report!(ParsingError::EnumParseFailure("PayoutLinkStatus")))
                     .attach_printable("Invalid status for PayoutLink")?
             }
@@ -140,6 +143,16 @@ where
     }
 }
 
+#[derive(Serialize, serde::Deserialize, Debug, Clone, Eq, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// Status variants for payment method manage links
+pub enum PaymentMethodManageStatus {
+    /// Link was initialized
+    Initiated,
+    /// Link was expired or invalidated
+    Invalidated,
+}
+
 #[derive(Serialize, serde::Deserialize, Debug, Clone, FromSqlRow, AsExpression, ToSchema)]
 #[diesel(sql_type = Jsonb)]
 /// Payout link object
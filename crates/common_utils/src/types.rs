@@ -709,6 +709,117 @@ pub struct ChargeRefunds {
 
 crate::impl_to_sql_from_sql_json!(ChargeRefunds);
 
+/// Recurrence of a payout, for executing the same payout repeatedly on a schedule (e.g. weekly seller settlements)
+#[derive(
+    Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq, FromSqlRow, AsExpression, ToSchema,
+)]
+#[diesel(sql_type = Jsonb)]
+pub struct PayoutRecurrence {
+    /// The interval at which the payout is executed
+    #[schema(value_type = PayoutRecurrenceInterval, example = "weekly")]
+    pub interval: enums::PayoutRecurrenceInterval,
+
+    /// The number of intervals between two executions of the payout, defaults to 1
+    #[schema(example = 1)]
+    pub interval_count: Option<u16>,
+
+    /// The number of executions left in the schedule, including the upcoming one.
+    /// If not passed, the payout recurs until it is cancelled.
+    #[schema(example = 12)]
+    pub remaining_occurrences: Option<u16>,
+}
+
+crate::impl_to_sql_from_sql_json!(PayoutRecurrence);
+
+impl PayoutRecurrence {
+    /// Returns the time of the execution following the one at `scheduled_at`, along with the
+    /// recurrence to be carried over to it. Returns `None` once the schedule is exhausted.
+    pub fn get_next_occurrence(
+        &self,
+        scheduled_at: time::PrimitiveDateTime,
+    ) -> Option<(time::PrimitiveDateTime, Self)> {
+        let remaining_occurrences = match self.remaining_occurrences {
+            Some(remaining) if remaining <= 1 => return None,
+            remaining => remaining.map(|remaining| remaining - 1),
+        };
+        let interval_count = self.interval_count.unwrap_or(1).max(1);
+        let next_scheduled_at = match self.interval {
+            enums::PayoutRecurrenceInterval::Daily => {
+                scheduled_at.checked_add(time::Duration::days(i64::from(interval_count)))?
+            }
+            enums::PayoutRecurrenceInterval::Weekly => {
+                scheduled_at.checked_add(time::Duration::weeks(i64::from(interval_count)))?
+            }
+            enums::PayoutRecurrenceInterval::Monthly => add_months(scheduled_at, interval_count)?,
+        };
+
+        Some((
+            next_scheduled_at,
+            Self {
+                interval: self.interval,
+                interval_count: self.interval_count,
+                remaining_occurrences,
+            },
+        ))
+    }
+}
+
+/// Adds calendar months to the given time, clamping the day to the last day of the resulting month
+fn add_months(date_time: time::PrimitiveDateTime, months: u16) -> Option<time::PrimitiveDateTime> {
+    let date = date_time.date();
+    let total_months = i32::from(u8::from(date.month())) - 1 + i32::from(months);
+    let year = date.year().checked_add(total_months.div_euclid(12))?;
+    let month = u8::try_from(total_months.rem_euclid(12) + 1)
+        .ok()
+        .and_then(|month| time::Month::try_from(month).ok())?;
+    let day = date.day().min(time::util::days_in_year_month(year, month));
+
+    time::Date::from_calendar_date(year, month, day)
+        .ok()
+        .map(|date| date.with_time(date_time.time()))
+}
+
+#[cfg(test)]
+mod payout_recurrence_tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn monthly_recurrence_clamps_to_end_of_month() {
+        let recurrence = PayoutRecurrence {
+            interval: enums::PayoutRecurrenceInterval::Monthly,
+            interval_count: None,
+            remaining_occurrences: Some(3),
+        };
+
+        let (next_scheduled_at, next_recurrence) = recurrence
+            .get_next_occurrence(datetime!(2024-01-31 10:00))
+            .unwrap();
+        assert_eq!(next_scheduled_at, datetime!(2024-02-29 10:00));
+        assert_eq!(next_recurrence.remaining_occurrences, Some(2));
+
+        let (next_scheduled_at, _) = recurrence
+            .get_next_occurrence(datetime!(2024-12-15 10:00))
+            .unwrap();
+        assert_eq!(next_scheduled_at, datetime!(2025-01-15 10:00));
+    }
+
+    #[test]
+    fn recurrence_ends_after_last_occurrence() {
+        let recurrence = PayoutRecurrence {
+            interval: enums::PayoutRecurrenceInterval::Weekly,
+            interval_count: Some(2),
+            remaining_occurrences: Some(1),
+        };
+
+        assert!(recurrence
+            .get_next_occurrence(datetime!(2024-01-01 00:00))
+            .is_none());
+    }
+}
+
 /// Domain type for description
 #[derive(
     Debug, Clone, PartialEq, Eq, Queryable, serde::Deserialize, serde::Serialize, AsExpression,
@@ -60,6 +60,79 @@ pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     Ok(())
 }
 
+/// Validates a tax identifier against the format of the issuing country and returns it in its
+/// canonical form, which is uppercase and without the separators (spaces, dots and hyphens) it is
+/// often written with.
+///
+/// Australian Business Numbers are additionally verified against their check digits.
+pub fn validate_tax_id(
+    tax_id_type: common_enums::CustomerTaxIdType,
+    tax_id: &str,
+) -> CustomResult<String, ValidationError> {
+    let canonical_tax_id = tax_id
+        .chars()
+        .filter(|character| !matches!(character, ' ' | '.' | '-'))
+        .collect::<String>()
+        .to_uppercase();
+
+    let pattern = match tax_id_type {
+        common_enums::CustomerTaxIdType::EuVat => {
+            r"^(AT|BE|BG|CY|CZ|DE|DK|EE|EL|ES|FI|FR|HR|HU|IE|IT|LT|LU|LV|MT|NL|PL|PT|RO|SE|SI|SK)[0-9A-Z]{2,12}$"
+        }
+        common_enums::CustomerTaxIdType::GbVat => {
+            r"^GB([0-9]{9}|[0-9]{12}|GD[0-4][0-9]{2}|HA[5-9][0-9]{2})$"
+        }
+        common_enums::CustomerTaxIdType::InGst => {
+            r"^[0-9]{2}[A-Z]{5}[0-9]{4}[A-Z][1-9A-Z]Z[0-9A-Z]$"
+        }
+        common_enums::CustomerTaxIdType::AuAbn => r"^[1-9][0-9]{10}$",
+        common_enums::CustomerTaxIdType::CaGst => r"^[0-9]{9}RT[0-9]{4}$",
+        common_enums::CustomerTaxIdType::SgGst => {
+            r"^(M[0-9A-Z][0-9]{7}[0-9A-Z]|[0-9]{8,9}[A-Z]|[RST][0-9]{2}[A-Z]{2}[0-9]{4}[A-Z])$"
+        }
+    };
+    let tax_id_regex = Regex::new(pattern).map_err(|_| {
+        report!(ValidationError::InvalidValue {
+            message: "Invalid regex expression".into()
+        })
+    })?;
+
+    let is_valid = tax_id_regex.is_match(&canonical_tax_id)
+        && (tax_id_type != common_enums::CustomerTaxIdType::AuAbn
+            || is_abn_checksum_valid(&canonical_tax_id));
+
+    if !is_valid {
+        return Err(report!(ValidationError::InvalidValue {
+            message: format!("Invalid tax id format for tax id type {tax_id_type}")
+        }));
+    }
+
+    Ok(canonical_tax_id)
+}
+
+/// Subtracts one from the first digit of the ABN and checks that the weighted sum of the digits is
+/// divisible by 89
+fn is_abn_checksum_valid(abn: &str) -> bool {
+    const ABN_WEIGHTS: [u32; 11] = [10, 1, 3, 5, 7, 9, 11, 13, 15, 17, 19];
+
+    let weighted_sum = abn
+        .chars()
+        .filter_map(|digit| digit.to_digit(10))
+        .zip(ABN_WEIGHTS)
+        .enumerate()
+        .map(|(position, (digit, weight))| {
+            let digit = if position == 0 {
+                digit.saturating_sub(1)
+            } else {
+                digit
+            };
+            digit * weight
+        })
+        .sum::<u32>();
+
+    weighted_sum % 89 == 0
+}
+
 /// Checks whether a given domain matches against a list of valid domain glob patterns
 pub fn validate_domain_against_allowed_domains(
     domain: &str,
@@ -131,6 +204,31 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test_case(common_enums::CustomerTaxIdType::EuVat, "DE123456789", "DE123456789" ; "German VAT number")]
+    #[test_case(common_enums::CustomerTaxIdType::EuVat, "fr 12 345678901", "FR12345678901" ; "French VAT number with spaces")]
+    #[test_case(common_enums::CustomerTaxIdType::GbVat, "GB 123 4567 89", "GB123456789" ; "UK VAT number")]
+    #[test_case(common_enums::CustomerTaxIdType::InGst, "27AAPFU0939F1ZV", "27AAPFU0939F1ZV" ; "Indian GSTIN")]
+    #[test_case(common_enums::CustomerTaxIdType::AuAbn, "51 824 753 556", "51824753556" ; "Australian Business Number")]
+    #[test_case(common_enums::CustomerTaxIdType::CaGst, "123456789RT0001", "123456789RT0001" ; "Canadian GST number")]
+    #[test_case(common_enums::CustomerTaxIdType::SgGst, "M2-1234567-8", "M212345678" ; "Singaporean GST number")]
+    fn test_validate_tax_id(
+        tax_id_type: common_enums::CustomerTaxIdType,
+        tax_id: &str,
+        canonical_tax_id: &str,
+    ) {
+        let result = validate_tax_id(tax_id_type, tax_id);
+        assert_eq!(result.ok().as_deref(), Some(canonical_tax_id));
+    }
+
+    #[test_case(common_enums::CustomerTaxIdType::EuVat, "US123456789" ; "VAT number of a country outside the EU")]
+    #[test_case(common_enums::CustomerTaxIdType::GbVat, "GB1234567" ; "UK VAT number that is too short")]
+    #[test_case(common_enums::CustomerTaxIdType::InGst, "27AAPFU0939F1AV" ; "Indian GSTIN without the Z character")]
+    #[test_case(common_enums::CustomerTaxIdType::AuAbn, "51824753557" ; "Australian Business Number with invalid check digits")]
+    #[test_case(common_enums::CustomerTaxIdType::CaGst, "123456789" ; "Canadian business number without the program account")]
+    fn test_invalid_tax_id(tax_id_type: common_enums::CustomerTaxIdType, tax_id: &str) {
+        assert!(validate_tax_id(tax_id_type, tax_id).is_err());
+    }
+
     proptest::proptest! {
         /// Example of unit test
         #[test]
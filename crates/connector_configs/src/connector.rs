@@ -211,6 +211,7 @@ pub struct ConnectorConfig {
     pub signifyd: Option<ConnectorTomlConfig>,
     pub trustpay: Option<ConnectorTomlConfig>,
     pub threedsecureio: Option<ConnectorTomlConfig>,
+    pub truelayer: Option<ConnectorTomlConfig>,
     pub netcetera: Option<ConnectorTomlConfig>,
     pub tsys: Option<ConnectorTomlConfig>,
     pub volt: Option<ConnectorTomlConfig>,
@@ -222,6 +223,8 @@ pub struct ConnectorConfig {
     pub square: Option<ConnectorTomlConfig>,
     pub stax: Option<ConnectorTomlConfig>,
     pub dummy_connector: Option<ConnectorTomlConfig>,
+    #[cfg(feature = "dummy_connector")]
+    pub dummy_bank: Option<ConnectorTomlConfig>,
     pub stripe_test: Option<ConnectorTomlConfig>,
     pub paypal_test: Option<ConnectorTomlConfig>,
     pub zen: Option<ConnectorTomlConfig>,
@@ -289,6 +292,9 @@ impl ConnectorConfig {
         let connector_data = Self::new()?;
         match connector {
             PmAuthConnectors::Plaid => Ok(connector_data.plaid),
+            PmAuthConnectors::Truelayer => Ok(connector_data.truelayer),
+            #[cfg(feature = "dummy_connector")]
+            PmAuthConnectors::DummyBank => Ok(connector_data.dummy_bank),
         }
     }
 
@@ -357,6 +363,7 @@ impl ConnectorConfig {
             Connector::Stripe => Ok(connector_data.stripe),
             Connector::Trustpay => Ok(connector_data.trustpay),
             Connector::Threedsecureio => Ok(connector_data.threedsecureio),
+            Connector::Truelayer => Ok(connector_data.truelayer),
             Connector::Tsys => Ok(connector_data.tsys),
             Connector::Volt => Ok(connector_data.volt),
             Connector::Wellsfargo => Ok(connector_data.wellsfargo),
@@ -379,6 +386,8 @@ impl ConnectorConfig {
             Connector::DummyConnector6 => Ok(connector_data.dummy_connector),
             #[cfg(feature = "dummy_connector")]
             Connector::DummyConnector7 => Ok(connector_data.paypal_test),
+            #[cfg(feature = "dummy_connector")]
+            Connector::DummyBank => Ok(connector_data.dummy_bank),
             Connector::Netcetera => Ok(connector_data.netcetera),
         }
     }
@@ -0,0 +1,116 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::account_updater_item};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = account_updater_item, primary_key(merchant_id, item_id), check_for_backend(diesel::pg::Pg))]
+pub struct AccountUpdaterItem {
+    pub item_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub reason: storage_enums::AccountUpdaterReason,
+    pub status: storage_enums::AccountUpdaterItemStatus,
+    pub batch_id: Option<String>,
+    pub outcome: Option<storage_enums::AccountUpdaterOutcome>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = account_updater_item)]
+pub struct AccountUpdaterItemNew {
+    pub item_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub payment_method_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub reason: storage_enums::AccountUpdaterReason,
+    pub status: storage_enums::AccountUpdaterItemStatus,
+    pub batch_id: Option<String>,
+    pub outcome: Option<storage_enums::AccountUpdaterOutcome>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum AccountUpdaterItemUpdate {
+    SubmissionUpdate {
+        batch_id: String,
+    },
+    ResultUpdate {
+        outcome: storage_enums::AccountUpdaterOutcome,
+    },
+    ErrorUpdate {
+        error_message: String,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = account_updater_item)]
+pub struct AccountUpdaterItemUpdateInternal {
+    pub status: Option<storage_enums::AccountUpdaterItemStatus>,
+    pub batch_id: Option<String>,
+    pub outcome: Option<storage_enums::AccountUpdaterOutcome>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<AccountUpdaterItemUpdate> for AccountUpdaterItemUpdateInternal {
+    fn from(account_updater_item_update: AccountUpdaterItemUpdate) -> Self {
+        match account_updater_item_update {
+            AccountUpdaterItemUpdate::SubmissionUpdate { batch_id } => Self {
+                status: Some(storage_enums::AccountUpdaterItemStatus::Submitted),
+                batch_id: Some(batch_id),
+                outcome: None,
+                error_message: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            AccountUpdaterItemUpdate::ResultUpdate { outcome } => Self {
+                status: Some(storage_enums::AccountUpdaterItemStatus::Completed),
+                batch_id: None,
+                outcome: Some(outcome),
+                error_message: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            AccountUpdaterItemUpdate::ErrorUpdate { error_message } => Self {
+                status: Some(storage_enums::AccountUpdaterItemStatus::Failed),
+                batch_id: None,
+                outcome: None,
+                error_message: Some(error_message),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+/// The data the recurring account updater workflow of a merchant operates on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdaterTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
+
+/// The data the workflow retrieving the results of a submitted batch operates on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AccountUpdaterBatchTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+}
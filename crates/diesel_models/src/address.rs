@@ -136,6 +136,27 @@ pub struct AddressUpdateInternal {
 }
 
 impl AddressUpdateInternal {
+    /// Combines `later`, issued after `self` for the same address, into a single changeset
+    /// that has the same effect as applying both in order.
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            city: later.city.or(self.city),
+            country: later.country.or(self.country),
+            line1: later.line1.or(self.line1),
+            line2: later.line2.or(self.line2),
+            line3: later.line3.or(self.line3),
+            state: later.state.or(self.state),
+            zip: later.zip.or(self.zip),
+            first_name: later.first_name.or(self.first_name),
+            last_name: later.last_name.or(self.last_name),
+            phone_number: later.phone_number.or(self.phone_number),
+            country_code: later.country_code.or(self.country_code),
+            modified_at: later.modified_at,
+            updated_by: later.updated_by,
+            email: later.email.or(self.email),
+        }
+    }
+
     pub fn create_address(self, source: Address) -> Address {
         Address {
             city: self.city,
@@ -53,6 +53,11 @@ pub struct BusinessProfile {
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: Option<bool>,
     pub version: common_enums::ApiVersion,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -93,6 +98,11 @@ pub struct BusinessProfileNew {
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: Option<bool>,
     pub version: common_enums::ApiVersion,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -129,6 +139,11 @@ pub struct BusinessProfileUpdateInternal {
     pub always_collect_shipping_details_from_wallet_connector: Option<bool>,
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: Option<bool>,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -164,6 +179,11 @@ impl BusinessProfileUpdateInternal {
             always_collect_shipping_details_from_wallet_connector,
             tax_connector_id,
             is_tax_connector_enabled,
+            refund_policy,
+            auto_capture_policy,
+            authorization_expiry_action,
+            is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled,
         } = self;
         BusinessProfile {
             profile_id: source.profile_id,
@@ -217,6 +237,14 @@ impl BusinessProfileUpdateInternal {
             tax_connector_id: tax_connector_id.or(source.tax_connector_id),
             is_tax_connector_enabled: is_tax_connector_enabled.or(source.is_tax_connector_enabled),
             version: source.version,
+            refund_policy: refund_policy.or(source.refund_policy),
+            auto_capture_policy: auto_capture_policy.or(source.auto_capture_policy),
+            authorization_expiry_action: authorization_expiry_action
+                .or(source.authorization_expiry_action),
+            is_pre_debit_balance_check_enabled: is_pre_debit_balance_check_enabled
+                .or(source.is_pre_debit_balance_check_enabled),
+            is_account_ownership_check_enabled: is_account_ownership_check_enabled
+                .or(source.is_account_ownership_check_enabled),
         }
     }
 }
@@ -266,6 +294,11 @@ pub struct BusinessProfile {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub id: common_utils::id_type::ProfileId,
     pub version: common_enums::ApiVersion,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 impl BusinessProfile {
@@ -320,6 +353,11 @@ pub struct BusinessProfileNew {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub id: common_utils::id_type::ProfileId,
     pub version: common_enums::ApiVersion,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -358,6 +396,11 @@ pub struct BusinessProfileUpdateInternal {
     pub frm_routing_algorithm_id: Option<String>,
     pub payout_routing_algorithm_id: Option<common_utils::id_type::RoutingId>,
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -395,6 +438,11 @@ impl BusinessProfileUpdateInternal {
             frm_routing_algorithm_id,
             payout_routing_algorithm_id,
             default_fallback_routing,
+            refund_policy,
+            auto_capture_policy,
+            authorization_expiry_action,
+            is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled,
         } = self;
         BusinessProfile {
             id: source.id,
@@ -452,6 +500,14 @@ impl BusinessProfileUpdateInternal {
                 .or(source.payout_routing_algorithm_id),
             default_fallback_routing: default_fallback_routing.or(source.default_fallback_routing),
             version: source.version,
+            refund_policy: refund_policy.or(source.refund_policy),
+            auto_capture_policy: auto_capture_policy.or(source.auto_capture_policy),
+            authorization_expiry_action: authorization_expiry_action
+                .or(source.authorization_expiry_action),
+            is_pre_debit_balance_check_enabled: is_pre_debit_balance_check_enabled
+                .or(source.is_pre_debit_balance_check_enabled),
+            is_account_ownership_check_enabled: is_account_ownership_check_enabled
+                .or(source.is_account_ownership_check_enabled),
         }
     }
 }
@@ -502,6 +558,11 @@ impl From<BusinessProfileNew> for BusinessProfile {
             payout_routing_algorithm_id: new.payout_routing_algorithm_id,
             default_fallback_routing: new.default_fallback_routing,
             version: new.version,
+            refund_policy: new.refund_policy,
+            auto_capture_policy: new.auto_capture_policy,
+            authorization_expiry_action: new.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: new.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: new.is_account_ownership_check_enabled,
         }
     }
 }
@@ -515,6 +576,27 @@ pub struct AuthenticationConnectorDetails {
 
 common_utils::impl_to_sql_from_sql_json!(AuthenticationConnectorDetails);
 
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct RefundPolicy {
+    pub max_refund_age_in_days: Option<i64>,
+    pub max_refund_amount_per_day: Option<common_utils::types::MinorUnit>,
+    pub max_refund_amount_per_user_per_day: Option<common_utils::types::MinorUnit>,
+    pub approval_threshold: Option<common_utils::types::MinorUnit>,
+}
+
+common_utils::impl_to_sql_from_sql_json!(RefundPolicy);
+
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct AutoCapturePolicy {
+    pub capture_after_hours: Option<u16>,
+    pub capture_schedule: Option<String>,
+    pub authorization_expiry_action: common_enums::AuthorizationExpiryAction,
+}
+
+common_utils::impl_to_sql_from_sql_json!(AutoCapturePolicy);
+
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, diesel::AsExpression)]
 #[diesel(sql_type = diesel::sql_types::Json)]
 pub struct WebhookDetails {
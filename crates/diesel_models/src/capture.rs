@@ -139,3 +139,27 @@ impl From<CaptureUpdate> for CaptureUpdateInternal {
         }
     }
 }
+
+impl From<&CaptureNew> for Capture {
+    fn from(capture_new: &CaptureNew) -> Self {
+        Self {
+            capture_id: capture_new.capture_id.clone(),
+            payment_id: capture_new.payment_id.clone(),
+            merchant_id: capture_new.merchant_id.clone(),
+            status: capture_new.status,
+            amount: capture_new.amount,
+            currency: capture_new.currency,
+            connector: capture_new.connector.clone(),
+            error_message: capture_new.error_message.clone(),
+            error_code: capture_new.error_code.clone(),
+            error_reason: capture_new.error_reason.clone(),
+            tax_amount: capture_new.tax_amount,
+            created_at: capture_new.created_at,
+            modified_at: capture_new.modified_at,
+            authorized_attempt_id: capture_new.authorized_attempt_id.clone(),
+            connector_capture_id: capture_new.connector_capture_id.clone(),
+            capture_sequence: capture_new.capture_sequence,
+            connector_response_reference_id: capture_new.connector_response_reference_id.clone(),
+        }
+    }
+}
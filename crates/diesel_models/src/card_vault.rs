@@ -0,0 +1,165 @@
+use common_utils::{encryption::Encryption, id_type};
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema_card_vault::{access_logs, data_keys, entries},
+};
+
+/// A data key of the embedded card vault, encrypted by the encryption management service
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = data_keys, primary_key(key_id), check_for_backend(diesel::pg::Pg))]
+pub struct VaultDataKey {
+    pub key_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub version: i32,
+    pub encrypted_key: Encryption,
+    pub status: storage_enums::VaultDataKeyStatus,
+    pub created_at: PrimitiveDateTime,
+    pub retired_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_keys)]
+pub struct VaultDataKeyNew {
+    pub key_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub version: i32,
+    pub encrypted_key: Encryption,
+    pub status: storage_enums::VaultDataKeyStatus,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum VaultDataKeyUpdate {
+    StatusUpdate {
+        status: storage_enums::VaultDataKeyStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_keys)]
+pub struct VaultDataKeyUpdateInternal {
+    pub status: Option<storage_enums::VaultDataKeyStatus>,
+    pub retired_at: Option<PrimitiveDateTime>,
+}
+
+impl From<VaultDataKeyUpdate> for VaultDataKeyUpdateInternal {
+    fn from(vault_data_key_update: VaultDataKeyUpdate) -> Self {
+        match vault_data_key_update {
+            VaultDataKeyUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                retired_at: (status == storage_enums::VaultDataKeyStatus::Retired)
+                    .then(common_utils::date_time::now),
+            },
+        }
+    }
+}
+
+/// Data stored in the embedded card vault, encrypted with a data key of the merchant
+#[derive(Clone, Debug, Identifiable, Queryable, Selectable)]
+#[diesel(table_name = entries, primary_key(entry_id), check_for_backend(diesel::pg::Pg))]
+pub struct VaultEntry {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub key_id: String,
+    pub encrypted_data: Encryption,
+    pub fingerprint: Option<String>,
+    pub requestor_reference: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = entries)]
+pub struct VaultEntryNew {
+    pub entry_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub customer_id: id_type::CustomerId,
+    pub key_id: String,
+    pub encrypted_data: Encryption,
+    pub fingerprint: Option<String>,
+    pub requestor_reference: Option<String>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum VaultEntryUpdate {
+    /// The data is encrypted again, either with changed contents or with another data key
+    DataUpdate {
+        key_id: String,
+        encrypted_data: Encryption,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = entries)]
+pub struct VaultEntryUpdateInternal {
+    pub key_id: Option<String>,
+    pub encrypted_data: Option<Encryption>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<VaultEntryUpdate> for VaultEntryUpdateInternal {
+    fn from(vault_entry_update: VaultEntryUpdate) -> Self {
+        match vault_entry_update {
+            VaultEntryUpdate::DataUpdate {
+                key_id,
+                encrypted_data,
+            } => Self {
+                key_id: Some(key_id),
+                encrypted_data: Some(encrypted_data),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+/// A record of an operation performed on the embedded card vault
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = access_logs, primary_key(log_id), check_for_backend(diesel::pg::Pg))]
+pub struct VaultAccessLog {
+    pub log_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub entry_id: Option<String>,
+    pub key_id: Option<String>,
+    pub action: storage_enums::VaultAccessAction,
+    pub request_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = access_logs)]
+pub struct VaultAccessLogNew {
+    pub log_id: String,
+    pub merchant_id: id_type::MerchantId,
+    pub entry_id: Option<String>,
+    pub key_id: Option<String>,
+    pub action: storage_enums::VaultAccessAction,
+    pub request_id: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VaultKeyRotationTrackingData {
+    pub merchant_id: id_type::MerchantId,
+    /// The key whose data is re-encrypted with the active key of the merchant
+    pub key_id: String,
+}
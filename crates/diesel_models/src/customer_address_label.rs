@@ -0,0 +1,77 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::customer_address_label};
+
+/// Purpose and label of an address saved against a customer. The address itself is stored in the
+/// `address` table under the same `address_id`.
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = customer_address_label, primary_key(merchant_id, address_id), check_for_backend(diesel::pg::Pg))]
+pub struct CustomerAddressLabel {
+    pub address_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub address_type: storage_enums::CustomerAddressType,
+    pub label: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = customer_address_label)]
+pub struct CustomerAddressLabelNew {
+    pub address_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub address_type: storage_enums::CustomerAddressType,
+    pub label: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum CustomerAddressLabelUpdate {
+    Update {
+        address_type: storage_enums::CustomerAddressType,
+        label: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = customer_address_label)]
+pub struct CustomerAddressLabelUpdateInternal {
+    pub address_type: Option<storage_enums::CustomerAddressType>,
+    pub label: Option<Option<String>>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<CustomerAddressLabelUpdate> for CustomerAddressLabelUpdateInternal {
+    fn from(customer_address_label_update: CustomerAddressLabelUpdate) -> Self {
+        match customer_address_label_update {
+            CustomerAddressLabelUpdate::Update {
+                address_type,
+                label,
+            } => Self {
+                address_type: Some(address_type),
+                label: Some(label),
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
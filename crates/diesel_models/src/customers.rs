@@ -30,6 +30,8 @@ pub struct CustomerNew {
     pub address_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: ApiVersion,
+    pub tax_ids: Option<Encryption>,
+    pub segments: Option<Vec<String>>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -73,6 +75,8 @@ impl From<CustomerNew> for Customer {
             default_payment_method_id: None,
             updated_by: customer_new.updated_by,
             version: customer_new.version,
+            tax_ids: customer_new.tax_ids,
+            segments: customer_new.segments,
         }
     }
 }
@@ -165,6 +169,8 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: ApiVersion,
+    pub tax_ids: Option<Encryption>,
+    pub segments: Option<Vec<String>>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -210,10 +216,34 @@ pub struct CustomerUpdateInternal {
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
     pub updated_by: Option<String>,
+    pub tax_ids: Option<Encryption>,
+    pub segments: Option<Vec<String>>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 impl CustomerUpdateInternal {
+    /// Combines `later`, issued after `self` for the same customer, into a single changeset
+    /// that has the same effect as applying both in order.
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            name: later.name.or(self.name),
+            email: later.email.or(self.email),
+            phone: later.phone.or(self.phone),
+            description: later.description.or(self.description),
+            phone_country_code: later.phone_country_code.or(self.phone_country_code),
+            metadata: later.metadata.or(self.metadata),
+            modified_at: later.modified_at,
+            connector_customer: later.connector_customer.or(self.connector_customer),
+            address_id: later.address_id.or(self.address_id),
+            default_payment_method_id: later
+                .default_payment_method_id
+                .or(self.default_payment_method_id),
+            updated_by: later.updated_by.or(self.updated_by),
+            tax_ids: later.tax_ids.or(self.tax_ids),
+            segments: later.segments.or(self.segments),
+        }
+    }
+
     pub fn apply_changeset(self, source: Customer) -> Customer {
         let Self {
             name,
@@ -225,6 +255,8 @@ impl CustomerUpdateInternal {
             connector_customer,
             address_id,
             default_payment_method_id,
+            tax_ids,
+            segments,
             ..
         } = self;
 
@@ -241,6 +273,8 @@ impl CustomerUpdateInternal {
             default_payment_method_id: default_payment_method_id
                 .flatten()
                 .map_or(source.default_payment_method_id, Some),
+            tax_ids: tax_ids.map_or(source.tax_ids, Some),
+            segments: segments.map_or(source.segments, Some),
             ..source
         }
     }
@@ -268,6 +302,31 @@ pub struct CustomerUpdateInternal {
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 impl CustomerUpdateInternal {
+    /// Combines `later`, issued after `self` for the same customer, into a single changeset
+    /// that has the same effect as applying both in order.
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            name: later.name.or(self.name),
+            email: later.email.or(self.email),
+            phone: later.phone.or(self.phone),
+            description: later.description.or(self.description),
+            phone_country_code: later.phone_country_code.or(self.phone_country_code),
+            metadata: later.metadata.or(self.metadata),
+            modified_at: later.modified_at,
+            connector_customer: later.connector_customer.or(self.connector_customer),
+            default_payment_method_id: later
+                .default_payment_method_id
+                .or(self.default_payment_method_id),
+            updated_by: later.updated_by.or(self.updated_by),
+            default_billing_address: later
+                .default_billing_address
+                .or(self.default_billing_address),
+            default_shipping_address: later
+                .default_shipping_address
+                .or(self.default_shipping_address),
+        }
+    }
+
     pub fn apply_changeset(self, source: Customer) -> Customer {
         let Self {
             name,
@@ -0,0 +1,100 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::data_subject_request};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = data_subject_request, primary_key(merchant_id, request_id), check_for_backend(diesel::pg::Pg))]
+pub struct DataSubjectRequest {
+    pub request_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub request_type: storage_enums::DataSubjectRequestType,
+    pub status: storage_enums::DataSubjectRequestStatus,
+    pub report: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = data_subject_request)]
+pub struct DataSubjectRequestNew {
+    pub request_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub request_type: storage_enums::DataSubjectRequestType,
+    pub status: storage_enums::DataSubjectRequestStatus,
+    pub report: Option<serde_json::Value>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug)]
+pub enum DataSubjectRequestUpdate {
+    StatusUpdate {
+        status: storage_enums::DataSubjectRequestStatus,
+    },
+    ReportUpdate {
+        status: storage_enums::DataSubjectRequestStatus,
+        report: serde_json::Value,
+        completed_at: Option<PrimitiveDateTime>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = data_subject_request)]
+pub struct DataSubjectRequestUpdateInternal {
+    pub status: Option<storage_enums::DataSubjectRequestStatus>,
+    pub report: Option<serde_json::Value>,
+    pub modified_at: PrimitiveDateTime,
+    pub completed_at: Option<PrimitiveDateTime>,
+}
+
+impl From<DataSubjectRequestUpdate> for DataSubjectRequestUpdateInternal {
+    fn from(data_subject_request_update: DataSubjectRequestUpdate) -> Self {
+        match data_subject_request_update {
+            DataSubjectRequestUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                report: None,
+                modified_at: common_utils::date_time::now(),
+                completed_at: None,
+            },
+            DataSubjectRequestUpdate::ReportUpdate {
+                status,
+                report,
+                completed_at,
+            } => Self {
+                status: Some(status),
+                report: Some(report),
+                modified_at: common_utils::date_time::now(),
+                completed_at,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataSubjectErasureTrackingData {
+    pub request_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
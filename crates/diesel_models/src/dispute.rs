@@ -1,12 +1,12 @@
 use common_utils::custom_serde;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use masking::Secret;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{enums as storage_enums, schema::dispute};
 
-#[derive(Clone, Debug, Insertable, Serialize, router_derive::DebugAsDisplay)]
+#[derive(Clone, Debug, Insertable, Serialize, Deserialize, router_derive::DebugAsDisplay)]
 #[diesel(table_name = dispute)]
 #[serde(deny_unknown_fields)]
 pub struct DisputeNew {
@@ -33,7 +33,7 @@ pub struct DisputeNew {
     pub organization_id: common_utils::id_type::OrganizationId,
 }
 
-#[derive(Clone, Debug, PartialEq, Serialize, Identifiable, Queryable, Selectable)]
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Identifiable, Queryable, Selectable)]
 #[diesel(table_name = dispute, primary_key(dispute_id), check_for_backend(diesel::pg::Pg))]
 pub struct Dispute {
     pub dispute_id: String,
@@ -63,7 +63,7 @@ pub struct Dispute {
     pub organization_id: common_utils::id_type::OrganizationId,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DisputeUpdate {
     Update {
         dispute_stage: storage_enums::DisputeStage,
@@ -97,6 +97,34 @@ pub struct DisputeUpdateInternal {
     evidence: Option<Secret<serde_json::Value>>,
 }
 
+impl DisputeUpdate {
+    pub fn apply_changeset(self, source: Dispute) -> Dispute {
+        let DisputeUpdateInternal {
+            dispute_stage,
+            dispute_status,
+            connector_status,
+            connector_reason,
+            connector_reason_code,
+            challenge_required_by,
+            connector_updated_at,
+            modified_at,
+            evidence,
+        } = self.into();
+        Dispute {
+            dispute_stage: dispute_stage.unwrap_or(source.dispute_stage),
+            dispute_status: dispute_status.unwrap_or(source.dispute_status),
+            connector_status: connector_status.unwrap_or(source.connector_status),
+            connector_reason: connector_reason.or(source.connector_reason),
+            connector_reason_code: connector_reason_code.or(source.connector_reason_code),
+            challenge_required_by: challenge_required_by.or(source.challenge_required_by),
+            connector_updated_at: connector_updated_at.or(source.connector_updated_at),
+            modified_at,
+            evidence: evidence.unwrap_or(source.evidence),
+            ..source
+        }
+    }
+}
+
 impl From<DisputeUpdate> for DisputeUpdateInternal {
     fn from(merchant_account_update: DisputeUpdate) -> Self {
         match merchant_account_update {
@@ -147,3 +175,153 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
         }
     }
 }
+
+impl From<&DisputeNew> for Dispute {
+    fn from(dispute_new: &DisputeNew) -> Self {
+        let now = common_utils::date_time::now();
+        Self {
+            dispute_id: dispute_new.dispute_id.clone(),
+            amount: dispute_new.amount.clone(),
+            currency: dispute_new.currency.clone(),
+            dispute_stage: dispute_new.dispute_stage,
+            dispute_status: dispute_new.dispute_status,
+            payment_id: dispute_new.payment_id.clone(),
+            attempt_id: dispute_new.attempt_id.clone(),
+            merchant_id: dispute_new.merchant_id.clone(),
+            connector_status: dispute_new.connector_status.clone(),
+            connector_dispute_id: dispute_new.connector_dispute_id.clone(),
+            connector_reason: dispute_new.connector_reason.clone(),
+            connector_reason_code: dispute_new.connector_reason_code.clone(),
+            challenge_required_by: dispute_new.challenge_required_by,
+            connector_created_at: dispute_new.connector_created_at,
+            connector_updated_at: dispute_new.connector_updated_at,
+            created_at: now,
+            modified_at: now,
+            connector: dispute_new.connector.clone(),
+            evidence: dispute_new
+                .evidence
+                .clone()
+                .unwrap_or_else(|| Secret::new(serde_json::Value::Object(Default::default()))),
+            profile_id: dispute_new.profile_id.clone(),
+            merchant_connector_id: dispute_new.merchant_connector_id.clone(),
+            dispute_amount: dispute_new.dispute_amount,
+            organization_id: dispute_new.organization_id.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use masking::{ExposeInterface, Secret};
+
+    use super::{Dispute, DisputeNew, DisputeUpdate};
+    use crate::enums as storage_enums;
+
+    fn create_dispute_new(evidence: Option<Secret<serde_json::Value>>) -> DisputeNew {
+        DisputeNew {
+            dispute_id: "dispute_1".into(),
+            amount: "1040".into(),
+            currency: "USD".into(),
+            dispute_stage: storage_enums::DisputeStage::Dispute,
+            dispute_status: storage_enums::DisputeStatus::DisputeOpened,
+            payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed("payment_1"))
+                .unwrap(),
+            attempt_id: "attempt_1".into(),
+            merchant_id: common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1"))
+                .unwrap(),
+            connector_status: "needs_response".into(),
+            connector_dispute_id: "connector_dispute_1".into(),
+            connector_reason: Some("fraudulent".into()),
+            connector_reason_code: Some("4837".into()),
+            challenge_required_by: None,
+            connector_created_at: None,
+            connector_updated_at: None,
+            connector: "connector".into(),
+            evidence,
+            profile_id: None,
+            merchant_connector_id: None,
+            dispute_amount: 1040,
+            organization_id: common_utils::id_type::OrganizationId::default(),
+        }
+    }
+
+    #[test]
+    fn test_dispute_from_dispute_new_defaults_evidence_to_empty_object() {
+        let dispute = Dispute::from(&create_dispute_new(None));
+
+        assert_eq!(
+            dispute.evidence.expose(),
+            serde_json::Value::Object(Default::default())
+        );
+        assert_eq!(dispute.created_at, dispute.modified_at);
+    }
+
+    #[test]
+    fn test_status_update_apply_changeset_keeps_unset_fields() {
+        let evidence = Secret::new(serde_json::json!({ "receipt": "file_1" }));
+        let dispute = Dispute::from(&create_dispute_new(Some(evidence.clone())));
+
+        let updated_dispute = DisputeUpdate::StatusUpdate {
+            dispute_status: storage_enums::DisputeStatus::DisputeWon,
+            connector_status: None,
+        }
+        .apply_changeset(dispute.clone());
+
+        assert_eq!(
+            updated_dispute.dispute_status,
+            storage_enums::DisputeStatus::DisputeWon
+        );
+        assert_eq!(updated_dispute.connector_status, dispute.connector_status);
+        assert_eq!(updated_dispute.connector_reason, dispute.connector_reason);
+        assert_eq!(updated_dispute.evidence.expose(), evidence.expose());
+        assert!(updated_dispute.modified_at >= dispute.modified_at);
+    }
+
+    #[test]
+    fn test_evidence_update_apply_changeset_replaces_only_evidence() {
+        let dispute = Dispute::from(&create_dispute_new(None));
+        let evidence = serde_json::json!({ "receipt": "file_2" });
+
+        let updated_dispute = DisputeUpdate::EvidenceUpdate {
+            evidence: Secret::new(evidence.clone()),
+        }
+        .apply_changeset(dispute.clone());
+
+        assert_eq!(updated_dispute.evidence.clone().expose(), evidence);
+        assert_eq!(updated_dispute.dispute_status, dispute.dispute_status);
+        assert_eq!(updated_dispute.dispute_stage, dispute.dispute_stage);
+        assert_eq!(updated_dispute.connector_status, dispute.connector_status);
+    }
+
+    #[test]
+    fn test_dispute_update_survives_serialization_round_trip() {
+        let dispute_update = DisputeUpdate::Update {
+            dispute_stage: storage_enums::DisputeStage::PreArbitration,
+            dispute_status: storage_enums::DisputeStatus::DisputeChallenged,
+            connector_status: "under_review".into(),
+            connector_reason: None,
+            connector_reason_code: None,
+            challenge_required_by: None,
+            connector_updated_at: None,
+        };
+        let dispute = Dispute::from(&create_dispute_new(None));
+
+        let serialized = serde_json::to_string(&dispute_update).unwrap();
+        let deserialized: DisputeUpdate = serde_json::from_str(&serialized).unwrap();
+        let updated_dispute = deserialized.apply_changeset(dispute);
+
+        assert_eq!(
+            updated_dispute.dispute_stage,
+            storage_enums::DisputeStage::PreArbitration
+        );
+        assert_eq!(
+            updated_dispute.dispute_status,
+            storage_enums::DisputeStatus::DisputeChallenged
+        );
+        assert_eq!(updated_dispute.connector_status, "under_review");
+        assert_eq!(updated_dispute.connector_reason, Some("fraudulent".into()));
+    }
+}
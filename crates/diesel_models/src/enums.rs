@@ -72,6 +72,7 @@ pub enum EventObjectType {
     DisputeDetails,
     MandateDetails,
     PayoutDetails,
+    PaymentMethodDetails,
 }
 
 #[derive(
@@ -117,6 +117,9 @@ pub enum EventMetadata {
         payment_method_id: String,
         mandate_id: String,
     },
+    PaymentMethod {
+        payment_method_id: String,
+    },
 }
 
 common_utils::impl_to_sql_from_sql_json!(EventMetadata);
@@ -29,7 +29,15 @@ pub struct FraudCheck {
     pub payment_capture_method: Option<storage_enums::CaptureMethod>, // In postFrm, we are updating capture method from automatic to manual. To store the merchant actual capture method, we are storing the actual capture method in payment_capture_method. It will be useful while approving the FRM decision.
 }
 
-#[derive(router_derive::Setter, Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[derive(
+    router_derive::Setter,
+    Clone,
+    Debug,
+    Insertable,
+    router_derive::DebugAsDisplay,
+    Serialize,
+    Deserialize,
+)]
 #[diesel(table_name = fraud_check)]
 pub struct FraudCheckNew {
     pub frm_id: String,
@@ -83,6 +91,32 @@ pub struct FraudCheckUpdateInternal {
     payment_capture_method: Option<storage_enums::CaptureMethod>,
 }
 
+impl FraudCheckUpdate {
+    pub fn apply_changeset(self, source: FraudCheck) -> FraudCheck {
+        let FraudCheckUpdateInternal {
+            frm_status,
+            frm_transaction_id,
+            frm_reason,
+            frm_score,
+            frm_error,
+            metadata,
+            last_step,
+            payment_capture_method,
+        } = self.into();
+        FraudCheck {
+            frm_status: frm_status.unwrap_or(source.frm_status),
+            frm_transaction_id: frm_transaction_id.or(source.frm_transaction_id),
+            frm_reason: frm_reason.or(source.frm_reason),
+            frm_score: frm_score.or(source.frm_score),
+            frm_error: frm_error.unwrap_or(source.frm_error),
+            metadata: metadata.or(source.metadata),
+            last_step,
+            payment_capture_method: payment_capture_method.or(source.payment_capture_method),
+            ..source
+        }
+    }
+}
+
 impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
     fn from(fraud_check_update: FraudCheckUpdate) -> Self {
         match fraud_check_update {
@@ -116,3 +150,27 @@ impl From<FraudCheckUpdate> for FraudCheckUpdateInternal {
         }
     }
 }
+
+impl From<&FraudCheckNew> for FraudCheck {
+    fn from(fraud_check_new: &FraudCheckNew) -> Self {
+        Self {
+            frm_id: fraud_check_new.frm_id.clone(),
+            payment_id: fraud_check_new.payment_id.clone(),
+            merchant_id: fraud_check_new.merchant_id.clone(),
+            attempt_id: fraud_check_new.attempt_id.clone(),
+            created_at: fraud_check_new.created_at,
+            frm_name: fraud_check_new.frm_name.clone(),
+            frm_transaction_id: fraud_check_new.frm_transaction_id.clone(),
+            frm_transaction_type: fraud_check_new.frm_transaction_type,
+            frm_status: fraud_check_new.frm_status,
+            frm_score: fraud_check_new.frm_score,
+            frm_reason: fraud_check_new.frm_reason.clone(),
+            frm_error: fraud_check_new.frm_error.clone(),
+            payment_details: fraud_check_new.payment_details.clone(),
+            metadata: fraud_check_new.metadata.clone(),
+            modified_at: fraud_check_new.modified_at,
+            last_step: fraud_check_new.last_step,
+            payment_capture_method: fraud_check_new.payment_capture_method,
+        }
+    }
+}
@@ -2,7 +2,7 @@ use common_utils::{
     consts,
     link_utils::{
         EnabledPaymentMethod, GenericLinkStatus, GenericLinkUiConfig, PaymentMethodCollectStatus,
-        PayoutLinkData, PayoutLinkStatus,
+        PaymentMethodManageStatus, PayoutLinkData, PayoutLinkStatus,
     },
 };
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
@@ -104,6 +104,7 @@ impl Default for GenericLinkNew {
 pub enum GenericLinkData {
     PaymentMethodCollect(PaymentMethodCollectLinkData),
     PayoutLink(PayoutLinkData),
+    PaymentMethodManage(PaymentMethodManageLinkData),
 }
 
 impl GenericLinkData {
@@ -119,6 +120,12 @@ impl GenericLinkData {
             _ => Err("Invalid link type for fetching payout link data".to_string()),
         }
     }
+    pub fn get_payment_method_manage_data(&self) -> Result<&PaymentMethodManageLinkData, String> {
+        match self {
+            Self::PaymentMethodManage(pm) => Ok(pm),
+            _ => Err("Invalid link type for fetching payment method manage data".to_string()),
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -151,6 +158,34 @@ pub struct PaymentMethodCollectLinkData {
     pub enabled_payment_methods: Option<Vec<EnabledPaymentMethod>>,
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PaymentMethodManageLink {
+    pub link_id: String,
+    pub primary_reference: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub last_modified_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expiry: PrimitiveDateTime,
+    pub link_data: PaymentMethodManageLinkData,
+    pub link_status: PaymentMethodManageStatus,
+    pub link_type: storage_enums::GenericLinkType,
+    pub url: Secret<String>,
+    pub return_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMethodManageLinkData {
+    pub pm_manage_link_id: String,
+    pub customer_id: common_utils::id_type::CustomerId,
+    pub link: Secret<String>,
+    pub session_expiry: u32,
+    #[serde(flatten)]
+    pub ui_config: GenericLinkUiConfig,
+}
+
 #[derive(Clone, Debug, Identifiable, Queryable, Serialize, Deserialize)]
 #[diesel(table_name = generic_link)]
 #[diesel(primary_key(link_id))]
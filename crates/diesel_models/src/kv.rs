@@ -1,15 +1,25 @@
-use error_stack::ResultExt;
+pub mod partition;
+
+use diesel::associations::HasTable;
+use error_stack::{report, ResultExt};
 use serde::{Deserialize, Serialize};
 
 use crate::{
     address::{Address, AddressNew, AddressUpdateInternal},
+    authentication::{Authentication, AuthenticationNew, AuthenticationUpdateInternal},
+    capture::{Capture, CaptureNew, CaptureUpdate},
     customers::{Customer, CustomerNew, CustomerUpdateInternal},
+    dispute::{Dispute, DisputeNew, DisputeUpdate},
     errors,
-    payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
-    payment_intent::{PaymentIntentNew, PaymentIntentUpdate},
+    fraud_check::{FraudCheck, FraudCheckNew, FraudCheckUpdate},
+    payment_attempt::{
+        PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate, PaymentAttemptUpdateInternal,
+    },
+    payment_intent::{PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal},
     payout_attempt::{PayoutAttempt, PayoutAttemptNew, PayoutAttemptUpdate},
-    payouts::{Payouts, PayoutsNew, PayoutsUpdate},
-    refund::{Refund, RefundNew, RefundUpdate},
+    payouts::{Payouts, PayoutsNew, PayoutsUpdate, PayoutsUpdateInternal},
+    query::generics,
+    refund::{Refund, RefundNew, RefundUpdate, RefundUpdateInternal},
     reverse_lookup::{ReverseLookup, ReverseLookupNew},
     Mandate, MandateNew, MandateUpdateInternal, PaymentIntent, PaymentMethod, PaymentMethodNew,
     PaymentMethodUpdateInternal, PgPooledConn,
@@ -31,18 +41,7 @@ impl DBOperation {
     }
     pub fn table<'a>(&self) -> &'a str {
         match self {
-            Self::Insert { insertable } => match insertable {
-                Insertable::PaymentIntent(_) => "payment_intent",
-                Insertable::PaymentAttempt(_) => "payment_attempt",
-                Insertable::Refund(_) => "refund",
-                Insertable::Address(_) => "address",
-                Insertable::Payouts(_) => "payouts",
-                Insertable::PayoutAttempt(_) => "payout_attempt",
-                Insertable::Customer(_) => "customer",
-                Insertable::ReverseLookUp(_) => "reverse_lookup",
-                Insertable::PaymentMethod(_) => "payment_method",
-                Insertable::Mandate(_) => "mandate",
-            },
+            Self::Insert { insertable } => insertable.table(),
             Self::Update { updatable } => match updatable {
                 Updateable::PaymentIntentUpdate(_) => "payment_intent",
                 Updateable::PaymentAttemptUpdate(_) => "payment_attempt",
@@ -53,6 +52,10 @@ impl DBOperation {
                 Updateable::PayoutAttemptUpdate(_) => "payout_attempt",
                 Updateable::PaymentMethodUpdate(_) => "payment_method",
                 Updateable::MandateUpdate(_) => " mandate",
+                Updateable::DisputeUpdate(_) => "dispute",
+                Updateable::CaptureUpdate(_) => "captures",
+                Updateable::AuthenticationUpdate(_) => "authentication",
+                Updateable::FraudCheckUpdate(_) => "fraud_check",
             },
         }
     }
@@ -70,6 +73,10 @@ pub enum DBResult {
     PayoutAttempt(Box<PayoutAttempt>),
     PaymentMethod(Box<PaymentMethod>),
     Mandate(Box<Mandate>),
+    Dispute(Box<Dispute>),
+    Capture(Box<Capture>),
+    Authentication(Box<Authentication>),
+    FraudCheck(Box<FraudCheck>),
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -104,23 +111,65 @@ impl DBOperation {
                     DBResult::PaymentMethod(Box::new(rev.insert(conn).await?))
                 }
                 Insertable::Mandate(m) => DBResult::Mandate(Box::new(m.insert(conn).await?)),
+                Insertable::Dispute(d) => DBResult::Dispute(Box::new(d.insert(conn).await?)),
+                Insertable::Capture(c) => DBResult::Capture(Box::new(c.insert(conn).await?)),
+                Insertable::Authentication(a) => {
+                    DBResult::Authentication(Box::new(a.insert(conn).await?))
+                }
+                Insertable::FraudCheck(f) => DBResult::FraudCheck(Box::new(f.insert(conn).await?)),
             },
             Self::Update { updatable } => match updatable {
-                Updateable::PaymentIntentUpdate(a) => {
-                    DBResult::PaymentIntent(Box::new(a.orig.update(conn, a.update_data).await?))
-                }
+                Updateable::PaymentIntentUpdate(a) => DBResult::PaymentIntent(Box::new(
+                    a.orig
+                        .update_with_changeset(
+                            conn,
+                            merge_changesets(
+                                a.update_data,
+                                a.coalesced_updates,
+                                PaymentIntentUpdateInternal::merge,
+                            ),
+                        )
+                        .await?,
+                )),
                 Updateable::PaymentAttemptUpdate(a) => DBResult::PaymentAttempt(Box::new(
-                    a.orig.update_with_attempt_id(conn, a.update_data).await?,
+                    a.orig
+                        .update_changeset_with_attempt_id(
+                            conn,
+                            merge_changesets(
+                                a.update_data,
+                                a.coalesced_updates,
+                                PaymentAttemptUpdateInternal::merge,
+                            ),
+                        )
+                        .await?,
+                )),
+                Updateable::RefundUpdate(a) => DBResult::Refund(Box::new(
+                    a.orig
+                        .update_with_changeset(
+                            conn,
+                            merge_changesets(
+                                a.update_data,
+                                a.coalesced_updates,
+                                RefundUpdateInternal::merge,
+                            ),
+                        )
+                        .await?,
                 )),
-                Updateable::RefundUpdate(a) => {
-                    DBResult::Refund(Box::new(a.orig.update(conn, a.update_data).await?))
-                }
                 Updateable::AddressUpdate(a) => {
                     DBResult::Address(Box::new(a.orig.update(conn, a.update_data).await?))
                 }
-                Updateable::PayoutsUpdate(a) => {
-                    DBResult::Payouts(Box::new(a.orig.update(conn, a.update_data).await?))
-                }
+                Updateable::PayoutsUpdate(a) => DBResult::Payouts(Box::new(
+                    a.orig
+                        .update_with_changeset(
+                            conn,
+                            merge_changesets(
+                                a.update_data,
+                                a.coalesced_updates,
+                                PayoutsUpdateInternal::merge,
+                            ),
+                        )
+                        .await?,
+                )),
                 Updateable::PayoutAttemptUpdate(a) => DBResult::PayoutAttempt(Box::new(
                     a.orig.update_with_attempt_id(conn, a.update_data).await?,
                 )),
@@ -138,6 +187,24 @@ impl DBOperation {
                     )
                     .await?,
                 )),
+                Updateable::DisputeUpdate(d) => {
+                    DBResult::Dispute(Box::new(d.orig.update(conn, d.update_data).await?))
+                }
+                Updateable::CaptureUpdate(c) => DBResult::Capture(Box::new(
+                    c.orig.update_with_capture_id(conn, c.update_data).await?,
+                )),
+                Updateable::AuthenticationUpdate(a) => DBResult::Authentication(Box::new(
+                    Authentication::update_by_merchant_id_authentication_id(
+                        conn,
+                        a.orig.merchant_id,
+                        a.orig.authentication_id,
+                        a.update_data,
+                    )
+                    .await?,
+                )),
+                Updateable::FraudCheckUpdate(f) => DBResult::FraudCheck(Box::new(
+                    f.orig.update_with_attempt_id(conn, f.update_data).await?,
+                )),
                 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
                 Updateable::CustomerUpdate(cust) => DBResult::Customer(Box::new(
                     Customer::update_by_customer_id_merchant_id(
@@ -157,11 +224,28 @@ impl DBOperation {
     }
 }
 
+/// Merges the changeset of an update with the changesets of the updates coalesced into it, see
+/// [`Updateable::coalesce`]
+pub fn merge_changesets<U, C: From<U>>(
+    update_data: U,
+    coalesced_updates: Vec<U>,
+    merge: impl Fn(C, C) -> C,
+) -> C {
+    coalesced_updates
+        .into_iter()
+        .fold(C::from(update_data), |merged, later| {
+            merge(merged, C::from(later))
+        })
+}
+
 impl TypedSql {
+    /// The fields of the drainer stream entry of the operation, stamped with the version of the
+    /// partition layout the entry is pushed to
     pub fn to_field_value_pairs(
         &self,
         request_id: String,
         global_id: String,
+        partition_map_version: u32,
     ) -> crate::StorageResult<Vec<(&str, String)>> {
         let pushed_at = common_utils::date_time::now_unix_timestamp();
 
@@ -174,6 +258,7 @@ impl TypedSql {
             ("global_id", global_id),
             ("request_id", request_id),
             ("pushed_at", pushed_at.to_string()),
+            ("partition_map_version", partition_map_version.to_string()),
         ])
     }
 }
@@ -191,6 +276,105 @@ pub enum Insertable {
     PayoutAttempt(PayoutAttemptNew),
     PaymentMethod(PaymentMethodNew),
     Mandate(MandateNew),
+    Dispute(DisputeNew),
+    Capture(CaptureNew),
+    Authentication(Box<AuthenticationNew>),
+    FraudCheck(FraudCheckNew),
+}
+
+/// Collects the inner values of `insertables`, all of which are expected to be of the given
+/// variant, and inserts them as a single multi-row statement.
+macro_rules! batch_insert_variant {
+    ($conn:expr, $insertables:expr, $variant:ident, $row:ty, |$value:ident| $convert:expr) => {{
+        let values = $insertables
+            .into_iter()
+            .map(|insertable| match insertable {
+                Self::$variant($value) => Ok($convert),
+                other => Err(report!(errors::DatabaseError::QueryGenerationFailed))
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Cannot batch {} with {} inserts",
+                            other.table(),
+                            stringify!($variant)
+                        )
+                    }),
+            })
+            .collect::<crate::StorageResult<Vec<_>>>()?;
+        generics::generic_batch_insert::<<$row as HasTable>::Table, _>($conn, values).await
+    }};
+}
+
+impl Insertable {
+    pub fn table<'a>(&self) -> &'a str {
+        match self {
+            Self::PaymentIntent(_) => "payment_intent",
+            Self::PaymentAttempt(_) => "payment_attempt",
+            Self::Refund(_) => "refund",
+            Self::Address(_) => "address",
+            Self::Payouts(_) => "payouts",
+            Self::PayoutAttempt(_) => "payout_attempt",
+            Self::Customer(_) => "customer",
+            Self::ReverseLookUp(_) => "reverse_lookup",
+            Self::PaymentMethod(_) => "payment_method",
+            Self::Mandate(_) => "mandate",
+            Self::Dispute(_) => "dispute",
+            Self::Capture(_) => "captures",
+            Self::Authentication(_) => "authentication",
+            Self::FraudCheck(_) => "fraud_check",
+        }
+    }
+
+    /// Whether `other` targets the same table and can be part of the same multi-row insert
+    pub fn is_batchable_with(&self, other: &Self) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    /// Inserts all the rows in a single statement. Every element must target the same table,
+    /// see [`Self::is_batchable_with`].
+    pub async fn batch_insert(
+        conn: &PgPooledConn,
+        insertables: Vec<Self>,
+    ) -> crate::StorageResult<usize> {
+        let Some(first) = insertables.first() else {
+            return Ok(0);
+        };
+
+        match first {
+            Self::PaymentIntent(_) => {
+                batch_insert_variant!(conn, insertables, PaymentIntent, PaymentIntent, |a| a)
+            }
+            Self::PaymentAttempt(_) => {
+                batch_insert_variant!(conn, insertables, PaymentAttempt, PaymentAttempt, |a| a
+                    .populate_derived_fields())
+            }
+            Self::Refund(_) => batch_insert_variant!(conn, insertables, Refund, Refund, |a| a),
+            Self::Address(_) => {
+                batch_insert_variant!(conn, insertables, Address, Address, |addr| *addr)
+            }
+            Self::Customer(_) => {
+                batch_insert_variant!(conn, insertables, Customer, Customer, |cust| cust)
+            }
+            Self::ReverseLookUp(_) => {
+                batch_insert_variant!(conn, insertables, ReverseLookUp, ReverseLookup, |rev| rev)
+            }
+            Self::Payouts(_) => batch_insert_variant!(conn, insertables, Payouts, Payouts, |p| p),
+            Self::PayoutAttempt(_) => {
+                batch_insert_variant!(conn, insertables, PayoutAttempt, PayoutAttempt, |p| p)
+            }
+            Self::PaymentMethod(_) => {
+                batch_insert_variant!(conn, insertables, PaymentMethod, PaymentMethod, |pm| pm)
+            }
+            Self::Mandate(_) => batch_insert_variant!(conn, insertables, Mandate, Mandate, |m| m),
+            Self::Dispute(_) => batch_insert_variant!(conn, insertables, Dispute, Dispute, |d| d),
+            Self::Capture(_) => batch_insert_variant!(conn, insertables, Capture, Capture, |c| c),
+            Self::Authentication(_) => {
+                batch_insert_variant!(conn, insertables, Authentication, Authentication, |a| *a)
+            }
+            Self::FraudCheck(_) => {
+                batch_insert_variant!(conn, insertables, FraudCheck, FraudCheck, |f| f)
+            }
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -205,6 +389,120 @@ pub enum Updateable {
     PayoutAttemptUpdate(PayoutAttemptUpdateMems),
     PaymentMethodUpdate(PaymentMethodUpdateMems),
     MandateUpdate(MandateUpdateMems),
+    DisputeUpdate(DisputeUpdateMems),
+    CaptureUpdate(CaptureUpdateMems),
+    AuthenticationUpdate(Box<AuthenticationUpdateMems>),
+    FraudCheckUpdate(FraudCheckUpdateMems),
+}
+
+impl Updateable {
+    /// Identifies the row targeted by an update whose changeset can be merged with later updates
+    /// to the same row, see [`Self::coalesce`]. Returns `None` for updates that must be executed
+    /// individually.
+    pub fn coalescing_key(&self) -> Option<String> {
+        match self {
+            #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+            Self::CustomerUpdate(cust) => Some(format!(
+                "customer_{}_{}",
+                cust.orig.merchant_id.get_string_repr(),
+                cust.orig.customer_id.get_string_repr()
+            )),
+            #[cfg(all(feature = "v2", feature = "customer_v2"))]
+            Self::CustomerUpdate(cust) => Some(format!("customer_{}", cust.orig.id)),
+            Self::AddressUpdate(a) => Some(format!("address_{}", a.orig.address_id)),
+            Self::PaymentMethodUpdate(v) => {
+                Some(format!("payment_method_{}", v.orig.payment_method_id))
+            }
+            Self::MandateUpdate(m) => Some(format!(
+                "mandate_{}_{}",
+                m.orig.merchant_id.get_string_repr(),
+                m.orig.mandate_id
+            )),
+            Self::PaymentIntentUpdate(a) => Some(format!(
+                "payment_intent_{}_{}",
+                a.orig.merchant_id.get_string_repr(),
+                a.orig.payment_id.get_string_repr()
+            )),
+            Self::PaymentAttemptUpdate(a) => Some(format!(
+                "payment_attempt_{}_{}",
+                a.orig.merchant_id.get_string_repr(),
+                a.orig.attempt_id
+            )),
+            Self::RefundUpdate(a) => Some(format!(
+                "refund_{}_{}",
+                a.orig.merchant_id.get_string_repr(),
+                a.orig.refund_id
+            )),
+            Self::PayoutsUpdate(a) => Some(format!(
+                "payouts_{}_{}",
+                a.orig.merchant_id.get_string_repr(),
+                a.orig.payout_id
+            )),
+            Self::PayoutAttemptUpdate(_)
+            | Self::DisputeUpdate(_)
+            | Self::CaptureUpdate(_)
+            | Self::AuthenticationUpdate(_)
+            | Self::FraudCheckUpdate(_) => None,
+        }
+    }
+
+    /// Merges `later`, an update to the same row issued after `self`, into a single update which
+    /// leaves the row in the same final state. Both updates are handed back unchanged when their
+    /// changesets can't be merged.
+    pub fn coalesce(self, later: Self) -> Result<Self, (Self, Self)> {
+        let key = self.coalescing_key();
+        if key.is_none() || key != later.coalescing_key() {
+            return Err((self, later));
+        }
+
+        match (self, later) {
+            (Self::CustomerUpdate(earlier), Self::CustomerUpdate(later)) => {
+                Ok(Self::CustomerUpdate(CustomerUpdateMems {
+                    orig: earlier.orig,
+                    update_data: earlier.update_data.merge(later.update_data),
+                }))
+            }
+            (Self::AddressUpdate(earlier), Self::AddressUpdate(later)) => {
+                Ok(Self::AddressUpdate(Box::new(AddressUpdateMems {
+                    orig: earlier.orig,
+                    update_data: earlier.update_data.merge(later.update_data),
+                })))
+            }
+            (Self::PaymentMethodUpdate(earlier), Self::PaymentMethodUpdate(later)) => {
+                Ok(Self::PaymentMethodUpdate(PaymentMethodUpdateMems {
+                    orig: earlier.orig,
+                    update_data: earlier.update_data.merge(later.update_data),
+                }))
+            }
+            (Self::MandateUpdate(earlier), Self::MandateUpdate(later)) => {
+                Ok(Self::MandateUpdate(MandateUpdateMems {
+                    orig: earlier.orig,
+                    update_data: earlier.update_data.merge(later.update_data),
+                }))
+            }
+            (Self::PaymentIntentUpdate(mut earlier), Self::PaymentIntentUpdate(later)) => {
+                earlier.coalesced_updates.push(later.update_data);
+                earlier.coalesced_updates.extend(later.coalesced_updates);
+                Ok(Self::PaymentIntentUpdate(earlier))
+            }
+            (Self::PaymentAttemptUpdate(mut earlier), Self::PaymentAttemptUpdate(later)) => {
+                earlier.coalesced_updates.push(later.update_data);
+                earlier.coalesced_updates.extend(later.coalesced_updates);
+                Ok(Self::PaymentAttemptUpdate(earlier))
+            }
+            (Self::RefundUpdate(mut earlier), Self::RefundUpdate(later)) => {
+                earlier.coalesced_updates.push(later.update_data);
+                earlier.coalesced_updates.extend(later.coalesced_updates);
+                Ok(Self::RefundUpdate(earlier))
+            }
+            (Self::PayoutsUpdate(mut earlier), Self::PayoutsUpdate(later)) => {
+                earlier.coalesced_updates.push(later.update_data);
+                earlier.coalesced_updates.extend(later.coalesced_updates);
+                Ok(Self::PayoutsUpdate(earlier))
+            }
+            (earlier, later) => Err((earlier, later)),
+        }
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -223,24 +521,40 @@ pub struct AddressUpdateMems {
 pub struct PaymentIntentUpdateMems {
     pub orig: PaymentIntent,
     pub update_data: PaymentIntentUpdate,
+    /// Updates to the same row issued after `update_data`, applied along with it by a single
+    /// statement. Only set by the drainer, see [`Updateable::coalesce`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coalesced_updates: Vec<PaymentIntentUpdate>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PaymentAttemptUpdateMems {
     pub orig: PaymentAttempt,
     pub update_data: PaymentAttemptUpdate,
+    /// Updates to the same row issued after `update_data`, applied along with it by a single
+    /// statement. Only set by the drainer, see [`Updateable::coalesce`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coalesced_updates: Vec<PaymentAttemptUpdate>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RefundUpdateMems {
     pub orig: Refund,
     pub update_data: RefundUpdate,
+    /// Updates to the same row issued after `update_data`, applied along with it by a single
+    /// statement. Only set by the drainer, see [`Updateable::coalesce`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coalesced_updates: Vec<RefundUpdate>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PayoutsUpdateMems {
     pub orig: Payouts,
     pub update_data: PayoutsUpdate,
+    /// Updates to the same row issued after `update_data`, applied along with it by a single
+    /// statement. Only set by the drainer, see [`Updateable::coalesce`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub coalesced_updates: Vec<PayoutsUpdate>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -260,3 +574,27 @@ pub struct MandateUpdateMems {
     pub orig: Mandate,
     pub update_data: MandateUpdateInternal,
 }
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DisputeUpdateMems {
+    pub orig: Dispute,
+    pub update_data: DisputeUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CaptureUpdateMems {
+    pub orig: Capture,
+    pub update_data: CaptureUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuthenticationUpdateMems {
+    pub orig: Authentication,
+    pub update_data: AuthenticationUpdateInternal,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FraudCheckUpdateMems {
+    pub orig: FraudCheck,
+    pub update_data: FraudCheckUpdate,
+}
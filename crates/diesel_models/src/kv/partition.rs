@@ -0,0 +1,149 @@
+use serde::{Deserialize, Serialize};
+
+/// Redis key under which the partition map of the drainer streams is stored
+pub const PARTITION_MAP_KEY: &str = "drainer_partition_map";
+
+/// Redis key holding the version of the current layout of the partition map, which routers check
+/// before pushing an entry to find out whether the partition map they cached is stale
+pub const PARTITION_MAP_VERSION_KEY: &str = "drainer_partition_map_version";
+
+/// Redis key held by the drainer while it updates the partition map
+pub const PARTITION_MAP_LOCK_KEY: &str = "drainer_partition_map_lock";
+
+/// Time in seconds for which the router caches the partition map in memory. The cached map is
+/// read again earlier if the version of the current layout changes.
+pub const PARTITION_MAP_CACHE_TTL_IN_SECS: u64 = 10;
+
+/// A layout of the drainer streams, that KV entries are partitioned into
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartitionLayout {
+    /// Version of the layout, incremented on every reshard
+    pub version: u32,
+    /// Number of streams the entries are partitioned into
+    pub num_partitions: u8,
+}
+
+impl PartitionLayout {
+    /// The shard key of the partition. Shards of the initial layout retain the names used before
+    /// the partition map was introduced, so that entries pushed by then are drained as well.
+    pub fn shard_key(&self, partition_number: u32) -> String {
+        match self.version {
+            0 => format!("shard_{partition_number}"),
+            version => format!("shard_v{version}_{partition_number}"),
+        }
+    }
+}
+
+/// The versioned partition map of the drainer streams, shared by the router and the drainer
+/// through Redis.
+///
+/// Entries are always pushed to the streams of the current layout. When the number of partitions
+/// is changed, the current layout becomes the previous layout, which the drainer drains to
+/// completion before draining the streams of the new layout, so that the operations on any key
+/// are applied in the order they were pushed.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartitionMap {
+    /// The layout entries are pushed to
+    pub current: PartitionLayout,
+    /// The layout being drained after a reshard
+    pub previous: Option<PartitionLayout>,
+    /// Unix timestamp at which the last reshard was started
+    pub resharded_at: Option<i64>,
+    /// Layouts which were drained completely, whose quarantine streams may still hold entries
+    #[serde(default)]
+    pub retired: Vec<PartitionLayout>,
+}
+
+impl PartitionMap {
+    pub fn new(num_partitions: u8) -> Self {
+        Self {
+            current: PartitionLayout {
+                version: 0,
+                num_partitions,
+            },
+            previous: None,
+            resharded_at: None,
+            retired: Vec::new(),
+        }
+    }
+
+    /// Whether the map is older than the map whose current layout is of `live_version`. Routers
+    /// holding a stale map read the map again before pushing entries, so that no entry is pushed
+    /// to a layout which is being drained or was retired.
+    pub fn is_stale(&self, live_version: Option<u32>) -> bool {
+        live_version.is_some_and(|live_version| live_version != self.current.version)
+    }
+
+    pub fn is_resharding(&self) -> bool {
+        self.previous.is_some()
+    }
+
+    /// The layout whose streams the drainer must drain
+    pub fn draining_layout(&self) -> &PartitionLayout {
+        self.previous.as_ref().unwrap_or(&self.current)
+    }
+
+    /// Finds the layout of the specified version, including retired layouts
+    pub fn find_layout(&self, version: u32) -> Option<&PartitionLayout> {
+        std::iter::once(&self.current)
+            .chain(self.previous.as_ref())
+            .chain(self.retired.iter())
+            .find(|layout| layout.version == version)
+    }
+
+    /// Switches entries to a new layout of `num_partitions` streams. Returns `None` if a reshard is
+    /// already in progress, or if the current layout already has as many streams.
+    pub fn reshard(&self, num_partitions: u8, resharded_at: i64) -> Option<Self> {
+        if self.is_resharding() || self.current.num_partitions == num_partitions {
+            return None;
+        }
+
+        Some(Self {
+            current: PartitionLayout {
+                version: self.current.version + 1,
+                num_partitions,
+            },
+            previous: Some(self.current),
+            resharded_at: Some(resharded_at),
+            retired: self.retired.clone(),
+        })
+    }
+
+    /// Whether the previous layout may be retired once its streams are empty. Routers which read
+    /// the partition map just before the reshard may push entries to the previous layout until
+    /// the grace period has elapsed.
+    pub fn is_grace_period_elapsed(&self, now: i64, grace_period_in_secs: i64) -> bool {
+        self.resharded_at.map_or(true, |resharded_at| {
+            resharded_at + grace_period_in_secs <= now
+        })
+    }
+
+    /// Retires `drained_layout` if it is still the previous layout, the grace period of the
+    /// reshard has elapsed and its streams hold no `pending_entries`. Returns `None` if the
+    /// reshard cannot be completed yet.
+    pub fn complete_reshard_if_drained(
+        &self,
+        drained_layout: &PartitionLayout,
+        pending_entries: usize,
+        now: i64,
+        grace_period_in_secs: i64,
+    ) -> Option<Self> {
+        (self.previous.as_ref() == Some(drained_layout)
+            && pending_entries == 0
+            && self.is_grace_period_elapsed(now, grace_period_in_secs))
+        .then(|| self.complete_reshard())
+    }
+
+    /// Retires the previous layout, once all of its streams have been drained
+    pub fn complete_reshard(&self) -> Self {
+        let mut retired = self.retired.clone();
+        retired.extend(self.previous);
+
+        Self {
+            current: self.current,
+            previous: None,
+            resharded_at: self.resharded_at,
+            retired,
+        }
+    }
+}
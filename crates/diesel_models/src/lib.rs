@@ -1,8 +1,10 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_vault;
 pub mod cards_info;
 pub mod configs;
 
@@ -10,7 +12,9 @@ pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod customer_address_label;
 pub mod customers;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
@@ -33,7 +37,9 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod query;
@@ -46,6 +52,8 @@ pub mod unified_translations;
 #[allow(unused_qualifications)]
 pub mod schema;
 #[allow(unused_qualifications)]
+pub mod schema_card_vault;
+#[allow(unused_qualifications)]
 pub mod schema_v2;
 
 pub mod user;
@@ -181,6 +181,20 @@ impl From<MandateUpdate> for MandateUpdateInternal {
 }
 
 impl MandateUpdateInternal {
+    /// Combines `later`, issued after `self` for the same mandate, into a single changeset
+    /// that has the same effect as applying both in order.
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            mandate_status: later.mandate_status.or(self.mandate_status),
+            amount_captured: later.amount_captured.or(self.amount_captured),
+            connector_mandate_ids: later.connector_mandate_ids.or(self.connector_mandate_ids),
+            connector_mandate_id: later.connector_mandate_id.or(self.connector_mandate_id),
+            payment_method_id: later.payment_method_id.or(self.payment_method_id),
+            original_payment_id: later.original_payment_id.or(self.original_payment_id),
+            updated_by: later.updated_by.or(self.updated_by),
+        }
+    }
+
     pub fn apply_changeset(self, source: Mandate) -> Mandate {
         let Self {
             mandate_status,
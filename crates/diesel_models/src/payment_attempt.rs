@@ -447,6 +447,11 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<String>,
         connector_transaction_id: Option<String>,
     },
+    CustomerDataRedactionUpdate {
+        payment_method_data: Option<serde_json::Value>,
+        browser_info: Option<serde_json::Value>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
@@ -502,6 +507,72 @@ pub struct PaymentAttemptUpdateInternal {
 }
 
 impl PaymentAttemptUpdateInternal {
+    /// Merges `later`, a changeset for the same row issued after `self`, into a single changeset
+    /// which leaves the row in the same final state
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            amount: later.amount.or(self.amount),
+            net_amount: later.net_amount.or(self.net_amount),
+            currency: later.currency.or(self.currency),
+            status: later.status.or(self.status),
+            connector_transaction_id: later
+                .connector_transaction_id
+                .or(self.connector_transaction_id),
+            amount_to_capture: later.amount_to_capture.or(self.amount_to_capture),
+            connector: later.connector.or(self.connector),
+            authentication_type: later.authentication_type.or(self.authentication_type),
+            payment_method: later.payment_method.or(self.payment_method),
+            error_message: later.error_message.or(self.error_message),
+            payment_method_id: later.payment_method_id.or(self.payment_method_id),
+            cancellation_reason: later.cancellation_reason.or(self.cancellation_reason),
+            modified_at: later.modified_at,
+            mandate_id: later.mandate_id.or(self.mandate_id),
+            browser_info: later.browser_info.or(self.browser_info),
+            payment_token: later.payment_token.or(self.payment_token),
+            error_code: later.error_code.or(self.error_code),
+            connector_metadata: later.connector_metadata.or(self.connector_metadata),
+            payment_method_data: later.payment_method_data.or(self.payment_method_data),
+            payment_method_type: later.payment_method_type.or(self.payment_method_type),
+            payment_experience: later.payment_experience.or(self.payment_experience),
+            business_sub_label: later.business_sub_label.or(self.business_sub_label),
+            straight_through_algorithm: later
+                .straight_through_algorithm
+                .or(self.straight_through_algorithm),
+            preprocessing_step_id: later.preprocessing_step_id.or(self.preprocessing_step_id),
+            error_reason: later.error_reason.or(self.error_reason),
+            capture_method: later.capture_method.or(self.capture_method),
+            connector_response_reference_id: later
+                .connector_response_reference_id
+                .or(self.connector_response_reference_id),
+            multiple_capture_count: later.multiple_capture_count.or(self.multiple_capture_count),
+            surcharge_amount: later.surcharge_amount.or(self.surcharge_amount),
+            tax_amount: later.tax_amount.or(self.tax_amount),
+            amount_capturable: later.amount_capturable.or(self.amount_capturable),
+            updated_by: later.updated_by,
+            merchant_connector_id: later.merchant_connector_id.or(self.merchant_connector_id),
+            authentication_data: later.authentication_data.or(self.authentication_data),
+            encoded_data: later.encoded_data.or(self.encoded_data),
+            unified_code: later.unified_code.or(self.unified_code),
+            unified_message: later.unified_message.or(self.unified_message),
+            external_three_ds_authentication_attempted: later
+                .external_three_ds_authentication_attempted
+                .or(self.external_three_ds_authentication_attempted),
+            authentication_connector: later
+                .authentication_connector
+                .or(self.authentication_connector),
+            authentication_id: later.authentication_id.or(self.authentication_id),
+            fingerprint_id: later.fingerprint_id.or(self.fingerprint_id),
+            payment_method_billing_address_id: later
+                .payment_method_billing_address_id
+                .or(self.payment_method_billing_address_id),
+            charge_id: later.charge_id.or(self.charge_id),
+            client_source: later.client_source.or(self.client_source),
+            client_version: later.client_version.or(self.client_version),
+            customer_acceptance: later.customer_acceptance.or(self.customer_acceptance),
+            card_network: later.card_network.or(self.card_network),
+        }
+    }
+
     pub fn populate_derived_fields(self, source: &PaymentAttempt) -> Self {
         let mut update_internal = self;
         update_internal.net_amount = Some(
@@ -530,7 +601,13 @@ impl PaymentAttemptUpdateInternal {
 
 impl PaymentAttemptUpdate {
     pub fn apply_changeset(self, source: PaymentAttempt) -> PaymentAttempt {
-        let PaymentAttemptUpdateInternal {
+        PaymentAttemptUpdateInternal::from(self).apply_changeset(source)
+    }
+}
+
+impl PaymentAttemptUpdateInternal {
+    pub fn apply_changeset(self, source: PaymentAttempt) -> PaymentAttempt {
+        let Self {
             amount,
             net_amount,
             currency,
@@ -578,7 +655,7 @@ impl PaymentAttemptUpdate {
             client_version,
             customer_acceptance,
             card_network,
-        } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
+        } = self.populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
             net_amount: net_amount.or(source.net_amount),
@@ -1735,6 +1812,59 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 customer_acceptance: None,
                 card_network: None,
             },
+            PaymentAttemptUpdate::CustomerDataRedactionUpdate {
+                payment_method_data,
+                browser_info,
+                updated_by,
+            } => Self {
+                payment_method_data,
+                browser_info,
+                updated_by,
+                modified_at: common_utils::date_time::now(),
+                amount: None,
+                net_amount: None,
+                currency: None,
+                status: None,
+                connector_transaction_id: None,
+                amount_to_capture: None,
+                connector: None,
+                authentication_type: None,
+                payment_method: None,
+                error_message: None,
+                payment_method_id: None,
+                cancellation_reason: None,
+                mandate_id: None,
+                payment_token: None,
+                error_code: None,
+                connector_metadata: None,
+                payment_method_type: None,
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                preprocessing_step_id: None,
+                error_reason: None,
+                capture_method: None,
+                connector_response_reference_id: None,
+                multiple_capture_count: None,
+                surcharge_amount: None,
+                tax_amount: None,
+                amount_capturable: None,
+                merchant_connector_id: None,
+                authentication_data: None,
+                encoded_data: None,
+                unified_code: None,
+                unified_message: None,
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                fingerprint_id: None,
+                payment_method_billing_address_id: None,
+                charge_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+                card_network: None,
+            },
         }
     }
 }
@@ -70,6 +70,10 @@ pub struct PaymentIntent {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub auto_capture_policy: Option<crate::business_profile::AutoCapturePolicy>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "payment_v2")))]
@@ -132,6 +136,10 @@ pub struct PaymentIntent {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub auto_capture_policy: Option<crate::business_profile::AutoCapturePolicy>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -193,6 +201,10 @@ pub struct PaymentIntentNew {
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub auto_capture_policy: Option<crate::business_profile::AutoCapturePolicy>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -204,6 +216,8 @@ pub enum PaymentIntentUpdate {
         return_url: Option<String>,
         updated_by: String,
         incremental_authorization_allowed: Option<bool>,
+        authorized_at: Option<PrimitiveDateTime>,
+        captured_at: Option<PrimitiveDateTime>,
     },
     MetadataUpdate {
         metadata: serde_json::Value,
@@ -268,6 +282,17 @@ pub enum PaymentIntentUpdate {
         status: Option<storage_enums::IntentStatus>,
         updated_by: String,
     },
+    AuthorizationExpiryUpdate {
+        authorization_expires_at: PrimitiveDateTime,
+        updated_by: String,
+    },
+    CustomerDataRedactionUpdate {
+        description: Option<String>,
+        customer_details: Option<Encryption>,
+        billing_details: Option<Encryption>,
+        shipping_details: Option<Encryption>,
+        updated_by: String,
+    },
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -339,6 +364,70 @@ pub struct PaymentIntentUpdateInternal {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryption>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
+}
+
+impl PaymentIntentUpdateInternal {
+    /// Merges `later`, a changeset for the same row issued after `self`, into a single changeset
+    /// which leaves the row in the same final state
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            amount: later.amount.or(self.amount),
+            currency: later.currency.or(self.currency),
+            status: later.status.or(self.status),
+            amount_captured: later.amount_captured.or(self.amount_captured),
+            customer_id: later.customer_id.or(self.customer_id),
+            return_url: later.return_url.or(self.return_url),
+            setup_future_usage: later.setup_future_usage.or(self.setup_future_usage),
+            off_session: later.off_session.or(self.off_session),
+            metadata: later.metadata.or(self.metadata),
+            billing_address_id: later.billing_address_id.or(self.billing_address_id),
+            shipping_address_id: later.shipping_address_id.or(self.shipping_address_id),
+            modified_at: later.modified_at,
+            active_attempt_id: later.active_attempt_id.or(self.active_attempt_id),
+            business_country: later.business_country.or(self.business_country),
+            business_label: later.business_label.or(self.business_label),
+            description: later.description.or(self.description),
+            statement_descriptor_name: later
+                .statement_descriptor_name
+                .or(self.statement_descriptor_name),
+            statement_descriptor_suffix: later
+                .statement_descriptor_suffix
+                .or(self.statement_descriptor_suffix),
+            order_details: later.order_details.or(self.order_details),
+            attempt_count: later.attempt_count.or(self.attempt_count),
+            merchant_decision: later.merchant_decision.or(self.merchant_decision),
+            payment_confirm_source: later.payment_confirm_source.or(self.payment_confirm_source),
+            updated_by: later.updated_by,
+            surcharge_applicable: later.surcharge_applicable.or(self.surcharge_applicable),
+            incremental_authorization_allowed: later
+                .incremental_authorization_allowed
+                .or(self.incremental_authorization_allowed),
+            authorization_count: later.authorization_count.or(self.authorization_count),
+            session_expiry: later.session_expiry.or(self.session_expiry),
+            fingerprint_id: later.fingerprint_id.or(self.fingerprint_id),
+            request_external_three_ds_authentication: later
+                .request_external_three_ds_authentication
+                .or(self.request_external_three_ds_authentication),
+            frm_metadata: later.frm_metadata.or(self.frm_metadata),
+            customer_details: later.customer_details.or(self.customer_details),
+            billing_details: later.billing_details.or(self.billing_details),
+            merchant_order_reference_id: later
+                .merchant_order_reference_id
+                .or(self.merchant_order_reference_id),
+            shipping_details: later.shipping_details.or(self.shipping_details),
+            is_payment_processor_token_flow: later
+                .is_payment_processor_token_flow
+                .or(self.is_payment_processor_token_flow),
+            authorization_expires_at: later
+                .authorization_expires_at
+                .or(self.authorization_expires_at),
+            authorized_at: later.authorized_at.or(self.authorized_at),
+            captured_at: later.captured_at.or(self.captured_at),
+        }
+    }
 }
 
 impl PaymentIntentUpdate {
@@ -379,6 +468,9 @@ impl PaymentIntentUpdate {
             merchant_order_reference_id,
             shipping_details,
             is_payment_processor_token_flow,
+            authorization_expires_at,
+            authorized_at,
+            captured_at,
         } = self.into();
         PaymentIntent {
             amount: amount.unwrap_or(source.amount),
@@ -423,6 +515,9 @@ impl PaymentIntentUpdate {
             shipping_details: shipping_details.or(source.shipping_details),
             is_payment_processor_token_flow: is_payment_processor_token_flow
                 .or(source.is_payment_processor_token_flow),
+            authorization_expires_at: authorization_expires_at.or(source.authorization_expires_at),
+            authorized_at: authorized_at.or(source.authorized_at),
+            captured_at: captured_at.or(source.captured_at),
             ..source
         }
     }
@@ -470,6 +565,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::Update(value) => Self {
                 amount: Some(value.amount),
@@ -508,6 +606,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 incremental_authorization_allowed: None,
                 authorization_count: None,
                 is_payment_processor_token_flow: value.is_payment_processor_token_flow,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::PaymentCreateUpdate {
                 return_url,
@@ -553,6 +654,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::PGStatusUpdate {
                 status,
@@ -594,6 +698,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::MerchantStatusUpdate {
                 status,
@@ -636,6 +743,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::ResponseUpdate {
                 // amount,
@@ -647,6 +757,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 return_url,
                 updated_by,
                 incremental_authorization_allowed,
+                authorized_at,
+                captured_at,
             } => Self {
                 // amount,
                 // currency: Some(currency),
@@ -686,6 +798,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at,
+                captured_at,
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
                 active_attempt_id,
@@ -727,6 +842,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::StatusAndAttemptUpdate {
                 status,
@@ -769,6 +887,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::ApproveUpdate {
                 status,
@@ -810,6 +931,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::RejectUpdate {
                 status,
@@ -851,6 +975,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::SurchargeApplicableUpdate {
                 surcharge_applicable,
@@ -891,6 +1018,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::IncrementalAuthorizationAmountUpdate { amount } => Self {
                 amount: Some(amount),
@@ -928,6 +1058,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::AuthorizationCountUpdate {
                 authorization_count,
@@ -967,6 +1100,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::CompleteAuthorizeUpdate {
                 shipping_address_id,
@@ -1006,6 +1142,9 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => Self {
                 status,
@@ -1043,6 +1182,98 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 merchant_order_reference_id: None,
                 shipping_details: None,
                 is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
+            },
+            PaymentIntentUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            } => Self {
+                status: None,
+                updated_by,
+                amount: None,
+                currency: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details: None,
+                billing_details: None,
+                merchant_order_reference_id: None,
+                shipping_details: None,
+                is_payment_processor_token_flow: None,
+                authorization_expires_at: Some(authorization_expires_at),
+                authorized_at: None,
+                captured_at: None,
+            },
+            PaymentIntentUpdate::CustomerDataRedactionUpdate {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                status: None,
+                updated_by,
+                amount: None,
+                currency: None,
+                amount_captured: None,
+                customer_id: None,
+                return_url: None,
+                setup_future_usage: None,
+                off_session: None,
+                metadata: None,
+                billing_address_id: None,
+                shipping_address_id: None,
+                modified_at: common_utils::date_time::now(),
+                active_attempt_id: None,
+                business_country: None,
+                business_label: None,
+                description,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                order_details: None,
+                attempt_count: None,
+                merchant_decision: None,
+                payment_confirm_source: None,
+                surcharge_applicable: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                session_expiry: None,
+                fingerprint_id: None,
+                request_external_three_ds_authentication: None,
+                frm_metadata: None,
+                customer_details,
+                billing_details,
+                merchant_order_reference_id: None,
+                shipping_details,
+                is_payment_processor_token_flow: None,
+                authorization_expires_at: None,
+                authorized_at: None,
+                captured_at: None,
             },
         }
     }
@@ -44,6 +44,9 @@ pub struct PaymentMethod {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub health_status: Option<storage_enums::PaymentMethodHealthStatus>,
+    pub consecutive_mit_declines: Option<i32>,
+    pub fingerprint_id: Option<String>,
 }
 
 #[derive(
@@ -81,6 +84,9 @@ pub struct PaymentMethodNew {
     pub client_secret: Option<String>,
     pub payment_method_billing_address: Option<Encryption>,
     pub updated_by: Option<String>,
+    pub health_status: Option<storage_enums::PaymentMethodHealthStatus>,
+    pub consecutive_mit_declines: Option<i32>,
+    pub fingerprint_id: Option<String>,
 }
 
 impl PaymentMethodNew {
@@ -129,6 +135,16 @@ pub enum PaymentMethodUpdate {
     ConnectorMandateDetailsUpdate {
         connector_mandate_details: Option<serde_json::Value>,
     },
+    HealthStatusUpdate {
+        health_status: Option<storage_enums::PaymentMethodHealthStatus>,
+    },
+    ConsecutiveMitDeclinesUpdate {
+        consecutive_mit_declines: Option<i32>,
+    },
+    CustomerDetailsUpdate {
+        payment_method_data: Option<Encryption>,
+        payment_method_billing_address: Option<Encryption>,
+    },
 }
 
 impl PaymentMethodUpdate {
@@ -158,9 +174,39 @@ pub struct PaymentMethodUpdateInternal {
     updated_by: Option<String>,
     payment_method_type: Option<storage_enums::PaymentMethodType>,
     payment_method_issuer: Option<String>,
+    health_status: Option<storage_enums::PaymentMethodHealthStatus>,
+    consecutive_mit_declines: Option<i32>,
+    payment_method_billing_address: Option<Encryption>,
 }
 
 impl PaymentMethodUpdateInternal {
+    /// Combines `later`, issued after `self` for the same payment method, into a single
+    /// changeset that has the same effect as applying both in order.
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            metadata: later.metadata.or(self.metadata),
+            payment_method_data: later.payment_method_data.or(self.payment_method_data),
+            last_used_at: later.last_used_at.or(self.last_used_at),
+            network_transaction_id: later.network_transaction_id.or(self.network_transaction_id),
+            status: later.status.or(self.status),
+            locker_id: later.locker_id.or(self.locker_id),
+            payment_method: later.payment_method.or(self.payment_method),
+            connector_mandate_details: later
+                .connector_mandate_details
+                .or(self.connector_mandate_details),
+            updated_by: later.updated_by.or(self.updated_by),
+            payment_method_type: later.payment_method_type.or(self.payment_method_type),
+            payment_method_issuer: later.payment_method_issuer.or(self.payment_method_issuer),
+            health_status: later.health_status.or(self.health_status),
+            consecutive_mit_declines: later
+                .consecutive_mit_declines
+                .or(self.consecutive_mit_declines),
+            payment_method_billing_address: later
+                .payment_method_billing_address
+                .or(self.payment_method_billing_address),
+        }
+    }
+
     pub fn create_payment_method(self, source: PaymentMethod) -> PaymentMethod {
         let metadata = self.metadata.map(Secret::new);
 
@@ -176,6 +222,9 @@ impl PaymentMethodUpdateInternal {
             status,
             connector_mandate_details,
             updated_by,
+            health_status,
+            consecutive_mit_declines,
+            payment_method_billing_address,
             ..
         } = self;
 
@@ -189,6 +238,11 @@ impl PaymentMethodUpdateInternal {
             connector_mandate_details: connector_mandate_details
                 .map_or(source.connector_mandate_details, Some),
             updated_by: updated_by.map_or(source.updated_by, Some),
+            health_status: health_status.map_or(source.health_status, Some),
+            consecutive_mit_declines: consecutive_mit_declines
+                .map_or(source.consecutive_mit_declines, Some),
+            payment_method_billing_address: payment_method_billing_address
+                .map_or(source.payment_method_billing_address, Some),
             ..source
         }
     }
@@ -212,6 +266,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -227,6 +284,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
@@ -240,6 +300,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::UpdatePaymentMethodDataAndLastUsed {
                 payment_method_data,
@@ -256,6 +319,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::NetworkTransactionIdAndStatusUpdate {
                 network_transaction_id,
@@ -272,6 +338,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
@@ -285,6 +354,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::AdditionalDataUpdate {
                 payment_method_data,
@@ -305,6 +377,9 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer,
                 payment_method_type,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
             },
             PaymentMethodUpdate::ConnectorMandateDetailsUpdate {
                 connector_mandate_details,
@@ -320,6 +395,62 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 updated_by: None,
                 payment_method_issuer: None,
                 payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::HealthStatusUpdate { health_status } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                health_status,
+                consecutive_mit_declines: None,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::ConsecutiveMitDeclinesUpdate {
+                consecutive_mit_declines,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines,
+                payment_method_billing_address: None,
+            },
+            PaymentMethodUpdate::CustomerDetailsUpdate {
+                payment_method_data,
+                payment_method_billing_address,
+            } => Self {
+                metadata: None,
+                payment_method_data,
+                last_used_at: None,
+                network_transaction_id: None,
+                status: None,
+                locker_id: None,
+                payment_method: None,
+                connector_mandate_details: None,
+                updated_by: None,
+                payment_method_issuer: None,
+                payment_method_type: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                payment_method_billing_address,
             },
         }
     }
@@ -360,6 +491,9 @@ impl From<&PaymentMethodNew> for PaymentMethod {
             payment_method_billing_address: payment_method_new
                 .payment_method_billing_address
                 .clone(),
+            health_status: payment_method_new.health_status,
+            consecutive_mit_declines: payment_method_new.consecutive_mit_declines,
+            fingerprint_id: payment_method_new.fingerprint_id.clone(),
         }
     }
 }
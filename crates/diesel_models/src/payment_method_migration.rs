@@ -0,0 +1,202 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payment_method_migration, payment_method_migration_item},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payment_method_migration, primary_key(merchant_id, migration_id), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentMethodMigration {
+    pub migration_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub file_format: storage_enums::PaymentMethodMigrationFileFormat,
+    pub status: storage_enums::PaymentMethodMigrationStatus,
+    pub parallelism: i32,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    pub processed_offset: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = payment_method_migration)]
+pub struct PaymentMethodMigrationNew {
+    pub migration_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub file_id: String,
+    pub file_format: storage_enums::PaymentMethodMigrationFileFormat,
+    pub status: storage_enums::PaymentMethodMigrationStatus,
+    pub parallelism: i32,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    pub processed_offset: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodMigrationUpdate {
+    ProgressUpdate {
+        status: storage_enums::PaymentMethodMigrationStatus,
+        processed_count: i32,
+        failed_count: i32,
+        processed_offset: i64,
+    },
+    StatusUpdate {
+        status: storage_enums::PaymentMethodMigrationStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_migration)]
+pub struct PaymentMethodMigrationUpdateInternal {
+    pub status: Option<storage_enums::PaymentMethodMigrationStatus>,
+    pub processed_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub processed_offset: Option<i64>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentMethodMigrationUpdate> for PaymentMethodMigrationUpdateInternal {
+    fn from(payment_method_migration_update: PaymentMethodMigrationUpdate) -> Self {
+        match payment_method_migration_update {
+            PaymentMethodMigrationUpdate::ProgressUpdate {
+                status,
+                processed_count,
+                failed_count,
+                processed_offset,
+            } => Self {
+                status: Some(status),
+                processed_count: Some(processed_count),
+                failed_count: Some(failed_count),
+                processed_offset: Some(processed_offset),
+                modified_at: common_utils::date_time::now(),
+            },
+            PaymentMethodMigrationUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                processed_count: None,
+                failed_count: None,
+                processed_offset: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payment_method_migration_item, primary_key(merchant_id, migration_id, line_number), check_for_backend(diesel::pg::Pg))]
+pub struct PaymentMethodMigrationItem {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub migration_id: String,
+    pub line_number: i32,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub payment_method_id: Option<String>,
+    pub status: storage_enums::PaymentMethodMigrationItemStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = payment_method_migration_item)]
+pub struct PaymentMethodMigrationItemNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub migration_id: String,
+    pub line_number: i32,
+    pub customer_id: Option<common_utils::id_type::CustomerId>,
+    pub payment_method_id: Option<String>,
+    pub status: storage_enums::PaymentMethodMigrationItemStatus,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PaymentMethodMigrationItemUpdate {
+    SuccessUpdate {
+        payment_method_id: String,
+    },
+    ErrorUpdate {
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payment_method_migration_item)]
+pub struct PaymentMethodMigrationItemUpdateInternal {
+    pub payment_method_id: Option<String>,
+    pub status: Option<storage_enums::PaymentMethodMigrationItemStatus>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PaymentMethodMigrationItemUpdate> for PaymentMethodMigrationItemUpdateInternal {
+    fn from(payment_method_migration_item_update: PaymentMethodMigrationItemUpdate) -> Self {
+        match payment_method_migration_item_update {
+            PaymentMethodMigrationItemUpdate::SuccessUpdate { payment_method_id } => Self {
+                payment_method_id: Some(payment_method_id),
+                status: Some(storage_enums::PaymentMethodMigrationItemStatus::Succeeded),
+                error_code: None,
+                error_message: None,
+                modified_at: common_utils::date_time::now(),
+            },
+            PaymentMethodMigrationItemUpdate::ErrorUpdate {
+                error_code,
+                error_message,
+            } => Self {
+                payment_method_id: None,
+                status: Some(storage_enums::PaymentMethodMigrationItemStatus::Failed),
+                error_code,
+                error_message,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PaymentMethodMigrationTrackingData {
+    pub migration_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -0,0 +1,179 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    enums as storage_enums,
+    schema::{payout_batch, payout_batch_item},
+};
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payout_batch, primary_key(merchant_id, batch_id), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatch {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub file_id: String,
+    pub file_format: storage_enums::PayoutBatchFileFormat,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub confirm: bool,
+    pub auto_fulfill: bool,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchNew {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub profile_id: common_utils::id_type::ProfileId,
+    pub file_id: String,
+    pub file_format: storage_enums::PayoutBatchFileFormat,
+    pub status: storage_enums::PayoutBatchStatus,
+    pub confirm: bool,
+    pub auto_fulfill: bool,
+    pub total_count: i32,
+    pub processed_count: i32,
+    pub failed_count: i32,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchUpdate {
+    ProgressUpdate {
+        status: storage_enums::PayoutBatchStatus,
+        processed_count: i32,
+        failed_count: i32,
+    },
+    StatusUpdate {
+        status: storage_enums::PayoutBatchStatus,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch)]
+pub struct PayoutBatchUpdateInternal {
+    pub status: Option<storage_enums::PayoutBatchStatus>,
+    pub processed_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl From<PayoutBatchUpdate> for PayoutBatchUpdateInternal {
+    fn from(payout_batch_update: PayoutBatchUpdate) -> Self {
+        match payout_batch_update {
+            PayoutBatchUpdate::ProgressUpdate {
+                status,
+                processed_count,
+                failed_count,
+            } => Self {
+                status: Some(status),
+                processed_count: Some(processed_count),
+                failed_count: Some(failed_count),
+                modified_at: common_utils::date_time::now(),
+            },
+            PayoutBatchUpdate::StatusUpdate { status } => Self {
+                status: Some(status),
+                processed_count: None,
+                failed_count: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, Eq, PartialEq, Identifiable, Queryable, Selectable, Serialize, Deserialize,
+)]
+#[diesel(table_name = payout_batch_item, primary_key(merchant_id, batch_id, row_number), check_for_backend(diesel::pg::Pg))]
+pub struct PayoutBatchItem {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+    pub row_number: i32,
+    pub payout_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(
+    Clone,
+    Debug,
+    Eq,
+    PartialEq,
+    Insertable,
+    serde::Serialize,
+    serde::Deserialize,
+    router_derive::DebugAsDisplay,
+)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemNew {
+    pub merchant_id: common_utils::id_type::MerchantId,
+    pub batch_id: String,
+    pub row_number: i32,
+    pub payout_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug)]
+pub enum PayoutBatchItemUpdate {
+    ResultUpdate {
+        payout_id: Option<String>,
+        error_code: Option<String>,
+        error_message: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = payout_batch_item)]
+pub struct PayoutBatchItemUpdateInternal {
+    pub payout_id: Option<String>,
+    pub error_code: Option<String>,
+    pub error_message: Option<String>,
+}
+
+impl From<PayoutBatchItemUpdate> for PayoutBatchItemUpdateInternal {
+    fn from(payout_batch_item_update: PayoutBatchItemUpdate) -> Self {
+        match payout_batch_item_update {
+            PayoutBatchItemUpdate::ResultUpdate {
+                payout_id,
+                error_code,
+                error_message,
+            } => Self {
+                payout_id,
+                error_code,
+                error_message,
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayoutBatchTrackingData {
+    pub batch_id: String,
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
@@ -1,4 +1,7 @@
-use common_utils::{pii, types::MinorUnit};
+use common_utils::{
+    pii,
+    types::{MinorUnit, PayoutRecurrence},
+};
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable, Selectable};
 use serde::{self, Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -37,6 +40,9 @@ pub struct Payouts {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub recurrence: Option<PayoutRecurrence>,
 }
 
 #[derive(
@@ -78,6 +84,9 @@ pub struct PayoutsNew {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub recurrence: Option<PayoutRecurrence>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -136,6 +145,33 @@ pub struct PayoutsUpdateInternal {
     pub customer_id: Option<common_utils::id_type::CustomerId>,
 }
 
+impl PayoutsUpdateInternal {
+    /// Merges `later`, a changeset for the same row issued after `self`, into a single changeset
+    /// which leaves the row in the same final state
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            amount: later.amount.or(self.amount),
+            destination_currency: later.destination_currency.or(self.destination_currency),
+            source_currency: later.source_currency.or(self.source_currency),
+            description: later.description.or(self.description),
+            recurring: later.recurring.or(self.recurring),
+            auto_fulfill: later.auto_fulfill.or(self.auto_fulfill),
+            return_url: later.return_url.or(self.return_url),
+            entity_type: later.entity_type.or(self.entity_type),
+            metadata: later.metadata.or(self.metadata),
+            payout_method_id: later.payout_method_id.or(self.payout_method_id),
+            profile_id: later.profile_id.or(self.profile_id),
+            status: later.status.or(self.status),
+            last_modified_at: later.last_modified_at,
+            attempt_count: later.attempt_count.or(self.attempt_count),
+            confirm: later.confirm.or(self.confirm),
+            payout_type: later.payout_type.or(self.payout_type),
+            address_id: later.address_id.or(self.address_id),
+            customer_id: later.customer_id.or(self.customer_id),
+        }
+    }
+}
+
 impl Default for PayoutsUpdateInternal {
     fn default() -> Self {
         Self {
@@ -38,6 +38,9 @@ pub struct ProcessTracker {
     pub created_at: PrimitiveDateTime,
     #[serde(with = "common_utils::custom_serde::iso8601")]
     pub updated_at: PrimitiveDateTime,
+    #[serde(default)]
+    pub priority: i16,
+    pub cron_expression: Option<String>,
 }
 
 impl ProcessTracker {
@@ -45,6 +48,15 @@ impl ProcessTracker {
     pub fn is_valid_business_status(&self, valid_statuses: &[&str]) -> bool {
         valid_statuses.iter().any(|&x| x == self.business_status)
     }
+
+    /// Returns the time at which a recurring task should run next, if the task has a cron
+    /// expression and the schedule fires again after `after`.
+    pub fn get_next_cron_occurrence(&self, after: PrimitiveDateTime) -> Option<PrimitiveDateTime> {
+        self.cron_expression
+            .as_deref()
+            .and_then(|expression| common_utils::cron::CronSchedule::parse(expression).ok())
+            .and_then(|schedule| schedule.next_occurrence_after(after))
+    }
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -63,6 +75,8 @@ pub struct ProcessTrackerNew {
     pub event: Vec<String>,
     pub created_at: PrimitiveDateTime,
     pub updated_at: PrimitiveDateTime,
+    pub priority: i16,
+    pub cron_expression: Option<String>,
 }
 
 impl ProcessTrackerNew {
@@ -95,8 +109,41 @@ impl ProcessTrackerNew {
             event: vec![],
             created_at: current_time,
             updated_at: current_time,
+            priority: priority::DEFAULT,
+            cron_expression: None,
         })
     }
+
+    /// Sets the priority of the task, tasks with a higher priority are picked up by the producer
+    /// ahead of other tasks due in the same window.
+    pub fn with_priority(mut self, priority: i16) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Makes the task recurring: once the task finishes, it is rescheduled for the next
+    /// occurrence of the provided cron expression.
+    pub fn with_cron_expression(mut self, cron_expression: &str) -> StorageResult<Self> {
+        let schedule = common_utils::cron::CronSchedule::parse(cron_expression)
+            .change_context(errors::DatabaseError::Others)
+            .attach_printable("Invalid cron expression for process tracker task")?;
+        self.cron_expression = Some(schedule.to_string());
+        Ok(self)
+    }
+}
+
+/// Constraints for searching process tracker tasks, unset constraints match all tasks.
+#[derive(Clone, Debug, Default)]
+pub struct ProcessTrackerFilters {
+    pub runner: Option<String>,
+    pub status: Option<storage_enums::ProcessTrackerStatus>,
+    pub business_status: Option<String>,
+    /// Matched against the `merchant_id` recorded in the tracking data of the task
+    pub merchant_id: Option<common_utils::id_type::MerchantId>,
+    pub scheduled_after: Option<PrimitiveDateTime>,
+    pub scheduled_before: Option<PrimitiveDateTime>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -119,6 +166,9 @@ pub enum ProcessTrackerUpdate {
         retry_count: i32,
         schedule_time: PrimitiveDateTime,
     },
+    RecurrenceUpdate {
+        schedule_time: PrimitiveDateTime,
+    },
 }
 
 #[derive(Debug, Clone, AsChangeset, router_derive::DebugAsDisplay)]
@@ -147,6 +197,30 @@ impl Default for ProcessTrackerUpdateInternal {
     }
 }
 
+impl ProcessTrackerUpdateInternal {
+    pub fn apply_changeset(self, source: ProcessTracker) -> ProcessTracker {
+        let Self {
+            name,
+            retry_count,
+            schedule_time,
+            tracking_data,
+            business_status,
+            status,
+            updated_at,
+        } = self;
+        ProcessTracker {
+            name: name.or(source.name),
+            retry_count: retry_count.unwrap_or(source.retry_count),
+            schedule_time: schedule_time.or(source.schedule_time),
+            tracking_data: tracking_data.unwrap_or(source.tracking_data),
+            business_status: business_status.unwrap_or(source.business_status),
+            status: status.unwrap_or(source.status),
+            updated_at: updated_at.unwrap_or(source.updated_at),
+            ..source
+        }
+    }
+}
+
 impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
     fn from(process_tracker_update: ProcessTrackerUpdate) -> Self {
         match process_tracker_update {
@@ -185,6 +259,13 @@ impl From<ProcessTrackerUpdate> for ProcessTrackerUpdateInternal {
                 schedule_time: Some(schedule_time),
                 ..Default::default()
             },
+            ProcessTrackerUpdate::RecurrenceUpdate { schedule_time } => Self {
+                status: Some(storage_enums::ProcessTrackerStatus::New),
+                business_status: Some(String::from(business_status::PENDING)),
+                retry_count: Some(0),
+                schedule_time: Some(schedule_time),
+                ..Default::default()
+            },
         }
     }
 }
@@ -210,6 +291,18 @@ pub enum ProcessTrackerRunner {
     OutgoingWebhookRetryWorkflow,
     AttachPayoutAccountWorkflow,
     PaymentMethodStatusUpdateWorkflow,
+    PayoutBatchWorkflow,
+    PayoutScheduleWorkflow,
+    AutoCaptureWorkflow,
+    AuthorizationExpiryWorkflow,
+    VaultKeyRotationWorkflow,
+    PaymentMethodMigrationWorkflow,
+    AccountUpdaterWorkflow,
+    AccountUpdaterResultsWorkflow,
+    PaymentMethodHealthSweepWorkflow,
+    DataSubjectErasureWorkflow,
+    PayByBankReversalSyncWorkflow,
+    PayByBankPaymentSyncWorkflow,
 }
 
 #[cfg(test)]
@@ -265,4 +358,17 @@ pub mod business_status {
 
     /// Business status set for newly created tasks.
     pub const PENDING: &str = "Pending";
+
+    /// The task was cancelled by an operator before it could complete.
+    /// A task that reaches this status should not be retried (rescheduled for execution) later.
+    pub const CANCELLED: &str = "CANCELLED";
+}
+
+pub mod priority {
+    /// Priority assigned to tasks unless specified otherwise.
+    pub const DEFAULT: i16 = 0;
+
+    /// Priority for tasks whose delay is directly visible to merchants, such as webhook
+    /// deliveries, so that they are not starved by bulk background work.
+    pub const HIGH: i16 = 10;
 }
@@ -1,8 +1,10 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
+pub mod card_vault;
 pub mod cards_info;
 pub mod configs;
 
@@ -10,8 +12,10 @@ pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
 pub mod blocklist_fingerprint;
+pub mod customer_address_label;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod events;
 pub mod file;
@@ -29,7 +33,9 @@ pub mod payment_attempt;
 pub mod payment_intent;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
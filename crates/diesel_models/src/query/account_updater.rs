@@ -0,0 +1,99 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    account_updater::{
+        AccountUpdaterItem, AccountUpdaterItemNew, AccountUpdaterItemUpdate,
+        AccountUpdaterItemUpdateInternal,
+    },
+    enums as storage_enums, errors,
+    schema::account_updater_item::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl AccountUpdaterItemNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<AccountUpdaterItem> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl AccountUpdaterItem {
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        account_updater_item_update: AccountUpdaterItemUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::item_id.eq(self.item_id.to_owned())),
+            AccountUpdaterItemUpdateInternal::from(account_updater_item_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn find_by_merchant_id_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: storage_enums::AccountUpdaterItemStatus,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::status.eq(status)),
+            Some(limit),
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_payment_method_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method_id.eq(payment_method_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+}
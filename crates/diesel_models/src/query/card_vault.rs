@@ -0,0 +1,217 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    card_vault::{
+        VaultAccessLog, VaultAccessLogNew, VaultDataKey, VaultDataKeyNew, VaultDataKeyUpdate,
+        VaultDataKeyUpdateInternal, VaultEntry, VaultEntryNew, VaultEntryUpdate,
+        VaultEntryUpdateInternal,
+    },
+    enums, errors,
+    schema_card_vault::{access_logs, data_keys, entries},
+    PgPooledConn, StorageResult,
+};
+
+impl VaultDataKeyNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultDataKey> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultDataKey {
+    pub async fn find_by_key_id(conn: &PgPooledConn, key_id: &str) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            data_keys::dsl::key_id.eq(key_id.to_owned()),
+        )
+        .await
+    }
+
+    pub async fn find_optional_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            data_keys::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(data_keys::dsl::status.eq(enums::VaultDataKeyStatus::Active)),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            data_keys::dsl::merchant_id.eq(merchant_id.to_owned()),
+            None,
+            None,
+            Some(data_keys::dsl::version.desc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        vault_data_key_update: VaultDataKeyUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            data_keys::dsl::key_id.eq(self.key_id.to_owned()),
+            VaultDataKeyUpdateInternal::from(vault_data_key_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl VaultEntryNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultEntry> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultEntry {
+    pub async fn find_by_merchant_id_entry_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            entries::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(entries::dsl::entry_id.eq(entry_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_optional_by_fingerprint(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_find_one_optional::<<Self as HasTable>::Table, _, _>(
+            conn,
+            entries::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(entries::dsl::customer_id.eq(customer_id.to_owned()))
+                .and(entries::dsl::fingerprint.eq(fingerprint.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_key_id(
+        conn: &PgPooledConn,
+        key_id: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            entries::dsl::key_id.eq(key_id.to_owned()),
+            Some(limit),
+            None,
+            Some(entries::dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        vault_entry_update: VaultEntryUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            // The entry is not updated if it was re-encrypted with another data key meanwhile
+            entries::dsl::entry_id
+                .eq(self.entry_id.to_owned())
+                .and(entries::dsl::key_id.eq(self.key_id.to_owned())),
+            VaultEntryUpdateInternal::from(vault_entry_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn delete_by_merchant_id_entry_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> StorageResult<bool> {
+        generics::generic_delete::<<Self as HasTable>::Table, _>(
+            conn,
+            entries::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(entries::dsl::entry_id.eq(entry_id.to_owned())),
+        )
+        .await
+    }
+}
+
+impl VaultAccessLogNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<VaultAccessLog> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl VaultAccessLog {
+    pub async fn list_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> StorageResult<Vec<Self>> {
+        match entry_id {
+            Some(entry_id) => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    access_logs::dsl::merchant_id
+                        .eq(merchant_id.to_owned())
+                        .and(access_logs::dsl::entry_id.eq(entry_id.to_owned())),
+                    Some(limit),
+                    Some(offset),
+                    Some(access_logs::dsl::created_at.desc()),
+                )
+                .await
+            }
+            None => {
+                generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+                    conn,
+                    access_logs::dsl::merchant_id.eq(merchant_id.to_owned()),
+                    Some(limit),
+                    Some(offset),
+                    Some(access_logs::dsl::created_at.desc()),
+                )
+                .await
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    customer_address_label::{
+        CustomerAddressLabel, CustomerAddressLabelNew, CustomerAddressLabelUpdate,
+        CustomerAddressLabelUpdateInternal,
+    },
+    errors,
+    schema::customer_address_label::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl CustomerAddressLabelNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<CustomerAddressLabel> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl CustomerAddressLabel {
+    pub async fn find_by_merchant_id_customer_id_address_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::address_id.eq(address_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_customer_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned())),
+            None,
+            None,
+            Some(dsl::created_at.asc()),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        customer_address_label_update: CustomerAddressLabelUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::address_id.eq(self.address_id.to_owned())),
+            CustomerAddressLabelUpdateInternal::from(customer_address_label_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn delete_by_merchant_id_customer_id_address_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_delete_one_with_result::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::customer_id.eq(customer_id.to_owned()))
+                .and(dsl::address_id.eq(address_id.to_owned())),
+        )
+        .await
+    }
+}
@@ -0,0 +1,61 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    data_subject_request::{
+        DataSubjectRequest, DataSubjectRequestNew, DataSubjectRequestUpdate,
+        DataSubjectRequestUpdateInternal,
+    },
+    errors,
+    schema::data_subject_request::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl DataSubjectRequestNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<DataSubjectRequest> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl DataSubjectRequest {
+    pub async fn find_by_merchant_id_request_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::request_id.eq(request_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        data_subject_request_update: DataSubjectRequestUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::request_id.eq(self.request_id.to_owned())),
+            DataSubjectRequestUpdateInternal::from(data_subject_request_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
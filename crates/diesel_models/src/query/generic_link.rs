@@ -7,7 +7,7 @@ use crate::{
     errors as db_errors,
     generic_link::{
         GenericLink, GenericLinkData, GenericLinkNew, GenericLinkState, GenericLinkUpdateInternal,
-        PaymentMethodCollectLink, PayoutLink, PayoutLinkUpdate,
+        PaymentMethodCollectLink, PaymentMethodManageLink, PayoutLink, PayoutLinkUpdate,
     },
     schema::generic_link::dsl,
     PgPooledConn, StorageResult,
@@ -46,6 +46,19 @@ impl GenericLinkNew {
                     .attach_printable("failed to parse payout link data from DB")
             })
     }
+
+    pub async fn insert_pm_manage_link(
+        self,
+        conn: &PgPooledConn,
+    ) -> StorageResult<PaymentMethodManageLink> {
+        generics::generic_insert(conn, self)
+            .await
+            .and_then(|res: GenericLink| {
+                PaymentMethodManageLink::try_from(res)
+                    .change_context(db_errors::DatabaseError::Others)
+                    .attach_printable("failed to parse payment method manage link data from DB")
+            })
+    }
 }
 
 impl GenericLink {
@@ -96,6 +109,22 @@ impl GenericLink {
                 .attach_printable("failed to parse payout link data from DB")
         })
     }
+
+    pub async fn find_pm_manage_link_by_link_id(
+        conn: &PgPooledConn,
+        link_id: &str,
+    ) -> StorageResult<PaymentMethodManageLink> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::link_id.eq(link_id.to_owned()),
+        )
+        .await
+        .and_then(|res: Self| {
+            PaymentMethodManageLink::try_from(res)
+                .change_context(db_errors::DatabaseError::Others)
+                .attach_printable("failed to parse payment method manage link data from DB")
+        })
+    }
 }
 
 impl PayoutLink {
@@ -136,6 +165,12 @@ impl TryFrom<GenericLink> for GenericLinkState {
                 let link_data = db_val.link_data.parse_value("PayoutLinkData")?;
                 GenericLinkData::PayoutLink(link_data)
             }
+            common_enums::GenericLinkType::PaymentMethodManage => {
+                let link_data = db_val
+                    .link_data
+                    .parse_value("PaymentMethodManageLinkData")?;
+                GenericLinkData::PaymentMethodManage(link_data)
+            }
         };
 
         Ok(Self {
@@ -237,3 +272,49 @@ impl TryFrom<GenericLink> for PayoutLink {
         })
     }
 }
+
+impl TryFrom<GenericLink> for PaymentMethodManageLink {
+    type Error = Report<errors::ParsingError>;
+    fn try_from(db_val: GenericLink) -> Result<Self, Self::Error> {
+        let (link_data, link_status) = match db_val.link_type {
+            common_enums::GenericLinkType::PaymentMethodManage => {
+                let link_data = db_val
+                    .link_data
+                    .parse_value("PaymentMethodManageLinkData")?;
+                let link_status = match db_val.link_status {
+                    GenericLinkStatus::PaymentMethodManage(status) => Ok(status),
+                    _ => Err(report!(errors::ParsingError::EnumParseFailure(
+                        "GenericLinkStatus"
+                    )))
+                    .attach_printable_lazy(|| {
+                        format!(
+                            "Invalid status for PaymentMethodManageLink - {:?}",
+                            db_val.link_status
+                        )
+                    }),
+                }?;
+                (link_data, link_status)
+            }
+            _ => Err(report!(errors::ParsingError::UnknownError)).attach_printable_lazy(|| {
+                format!(
+                    "Invalid link_type for PaymentMethodManageLink - {}",
+                    db_val.link_type
+                )
+            })?,
+        };
+
+        Ok(Self {
+            link_id: db_val.link_id,
+            primary_reference: db_val.primary_reference,
+            merchant_id: db_val.merchant_id,
+            created_at: db_val.created_at,
+            last_modified_at: db_val.last_modified_at,
+            expiry: db_val.expiry,
+            link_data,
+            link_status,
+            link_type: db_val.link_type,
+            url: db_val.url,
+            return_url: db_val.return_url,
+        })
+    }
+}
@@ -98,6 +98,34 @@ where
     .attach_printable_lazy(|| format!("Error while inserting {debug_values}"))
 }
 
+pub async fn generic_batch_insert<T, V>(conn: &PgPooledConn, values: Vec<V>) -> StorageResult<usize>
+where
+    T: HasTable<Table = T> + Table + 'static + Debug,
+    V: Debug,
+    Vec<V>: Insertable<T>,
+    <T as QuerySource>::FromClause: QueryFragment<Pg> + Debug,
+    <Vec<V> as Insertable<T>>::Values: CanInsertInSingleQuery<Pg> + QueryFragment<Pg> + 'static,
+    InsertStatement<T, <Vec<V> as Insertable<T>>::Values>:
+        AsQuery + QueryFragment<Pg> + QueryId + Send + 'static,
+{
+    let debug_values = format!("{values:?}");
+
+    let query = diesel::insert_into(<T as HasTable>::table()).values(values);
+    logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+    match track_database_call::<T, _, _>(query.execute_async(conn), DatabaseOperation::Insert).await
+    {
+        Ok(value) => Ok(value),
+        Err(err) => match err {
+            DieselError::DatabaseError(diesel::result::DatabaseErrorKind::UniqueViolation, _) => {
+                Err(report!(err)).change_context(errors::DatabaseError::UniqueViolation)
+            }
+            _ => Err(report!(err)).change_context(errors::DatabaseError::Others),
+        },
+    }
+    .attach_printable_lazy(|| format!("Error while batch inserting {debug_values}"))
+}
+
 pub async fn generic_update<T, V, P>(
     conn: &PgPooledConn,
     predicate: P,
@@ -33,6 +33,18 @@ impl PaymentAttempt {
         self,
         conn: &PgPooledConn,
         payment_attempt: PaymentAttemptUpdate,
+    ) -> StorageResult<Self> {
+        self.update_changeset_with_attempt_id(
+            conn,
+            PaymentAttemptUpdateInternal::from(payment_attempt),
+        )
+        .await
+    }
+
+    pub async fn update_changeset_with_attempt_id(
+        self,
+        conn: &PgPooledConn,
+        changeset: PaymentAttemptUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
@@ -44,7 +56,7 @@ impl PaymentAttempt {
             dsl::attempt_id
                 .eq(self.attempt_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentAttemptUpdateInternal::from(payment_attempt).populate_derived_fields(&self),
+            changeset.populate_derived_fields(&self),
         )
         .await
         {
@@ -215,6 +227,24 @@ impl PaymentAttempt {
         .await
     }
 
+    pub async fn find_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+            limit,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
     pub async fn get_filters_for_payments(
         conn: &PgPooledConn,
         pi: &[PaymentIntent],
@@ -24,13 +24,22 @@ impl PaymentIntent {
         self,
         conn: &PgPooledConn,
         payment_intent: PaymentIntentUpdate,
+    ) -> StorageResult<Self> {
+        self.update_with_changeset(conn, PaymentIntentUpdateInternal::from(payment_intent))
+            .await
+    }
+
+    pub async fn update_with_changeset(
+        self,
+        conn: &PgPooledConn,
+        changeset: PaymentIntentUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::payment_id
                 .eq(self.payment_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PaymentIntentUpdateInternal::from(payment_intent),
+            changeset,
         )
         .await
         {
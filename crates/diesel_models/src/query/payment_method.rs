@@ -148,6 +148,116 @@ impl PaymentMethod {
         .await
     }
 
+    /// Lists the payment methods of the merchant with the provided status, in the order of their
+    /// identifiers, starting after the identifier provided
+    pub async fn find_by_merchant_id_payment_method_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method: storage_enums::PaymentMethod,
+        status: storage_enums::PaymentMethodStatus,
+        starting_after: &str,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::payment_method.eq(payment_method))
+                .and(dsl::status.eq(status))
+                .and(dsl::payment_method_id.gt(starting_after.to_owned())),
+            Some(limit),
+            None,
+            Some(dsl::payment_method_id.asc()),
+        )
+        .await
+    }
+
+    /// Lists the payment methods of the merchant with the provided fingerprint, across all the
+    /// customers of the merchant
+    pub async fn find_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+            limit,
+            None,
+            Some(dsl::last_used_at.desc()),
+        )
+        .await
+    }
+
+    /// Counts the distinct customers of the merchant who have saved a payment method with the
+    /// provided fingerprint
+    pub async fn get_customer_count_by_merchant_id_fingerprint_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> StorageResult<i64> {
+        let filter = <Self as HasTable>::table()
+            .select(diesel::dsl::count_distinct(dsl::customer_id))
+            .filter(
+                dsl::merchant_id
+                    .eq(merchant_id.to_owned())
+                    .and(dsl::fingerprint_id.eq(fingerprint_id.to_owned())),
+            )
+            .into_boxed();
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&filter).to_string());
+
+        generics::db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            filter.get_result_async::<i64>(conn),
+            generics::db_metrics::DatabaseOperation::Count,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Failed to get a count of customers by fingerprint")
+    }
+
+    /// Increments the count of consecutive declined merchant initiated payments of the payment
+    /// method in the database, so that concurrent declines are all counted
+    pub async fn increment_consecutive_mit_declines(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::payment_method_id.eq(payment_method_id.to_owned()),
+            dsl::consecutive_mit_declines.eq(diesel::dsl::sql::<
+                diesel::sql_types::Nullable<diesel::sql_types::Integer>,
+            >(
+                "COALESCE(consecutive_mit_declines, 0) + 1"
+            )),
+        )
+        .await
+    }
+
+    /// Resets the count of consecutive declined merchant initiated payments of the payment
+    /// method, returns the number of payment methods whose count was reset
+    pub async fn reset_consecutive_mit_declines(
+        conn: &PgPooledConn,
+        payment_method_id: &str,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::payment_method_id
+                .eq(payment_method_id.to_owned())
+                .and(dsl::consecutive_mit_declines.gt(0)),
+            dsl::consecutive_mit_declines.eq(Some(0)),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
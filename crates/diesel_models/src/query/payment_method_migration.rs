@@ -0,0 +1,140 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    errors,
+    payment_method_migration::{
+        PaymentMethodMigration, PaymentMethodMigrationItem, PaymentMethodMigrationItemNew,
+        PaymentMethodMigrationItemUpdate, PaymentMethodMigrationItemUpdateInternal,
+        PaymentMethodMigrationNew, PaymentMethodMigrationUpdate,
+        PaymentMethodMigrationUpdateInternal,
+    },
+    schema::{payment_method_migration::dsl, payment_method_migration_item},
+    PgPooledConn, StorageResult,
+};
+
+impl PaymentMethodMigrationNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodMigration> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodMigration {
+    pub async fn find_by_merchant_id_migration_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::migration_id.eq(migration_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_method_migration_update: PaymentMethodMigrationUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::migration_id.eq(self.migration_id.to_owned())),
+            PaymentMethodMigrationUpdateInternal::from(payment_method_migration_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl PaymentMethodMigrationItemNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PaymentMethodMigrationItem> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PaymentMethodMigrationItem {
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payment_method_migration_item_update: PaymentMethodMigrationItemUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            payment_method_migration_item::dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(
+                    payment_method_migration_item::dsl::migration_id
+                        .eq(self.migration_id.to_owned()),
+                )
+                .and(payment_method_migration_item::dsl::line_number.eq(self.line_number)),
+            PaymentMethodMigrationItemUpdateInternal::from(payment_method_migration_item_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn find_by_merchant_id_migration_id_line_number_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+        start_line_number: i32,
+        end_line_number: i32,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            payment_method_migration_item::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_method_migration_item::dsl::migration_id.eq(migration_id.to_owned()))
+                .and(payment_method_migration_item::dsl::line_number.ge(start_line_number))
+                .and(payment_method_migration_item::dsl::line_number.lt(end_line_number)),
+            None,
+            None,
+            Some(payment_method_migration_item::dsl::line_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_migration_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            payment_method_migration_item::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payment_method_migration_item::dsl::migration_id.eq(migration_id.to_owned())),
+            None,
+            None,
+            Some(payment_method_migration_item::dsl::line_number.asc()),
+        )
+        .await
+    }
+}
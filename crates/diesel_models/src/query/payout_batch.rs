@@ -0,0 +1,181 @@
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, pg::Pg, BoolExpressionMethods, ExpressionMethods,
+    JoinOnDsl, NullableExpressionMethods, QueryDsl, SelectableHelper,
+};
+use error_stack::ResultExt;
+
+use super::generics;
+use crate::{
+    enums, errors,
+    payout_batch::{
+        PayoutBatch, PayoutBatchItem, PayoutBatchItemNew, PayoutBatchItemUpdate,
+        PayoutBatchItemUpdateInternal, PayoutBatchNew, PayoutBatchUpdate,
+        PayoutBatchUpdateInternal,
+    },
+    query::generics::db_metrics,
+    schema::{payout_batch::dsl, payout_batch_item, payouts},
+    PgPooledConn, StorageResult,
+};
+
+impl PayoutBatchNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatch> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatch {
+    pub async fn find_by_merchant_id_batch_id(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::batch_id.eq(batch_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_update: PayoutBatchUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(dsl::batch_id.eq(self.batch_id.to_owned())),
+            PayoutBatchUpdateInternal::from(payout_batch_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+}
+
+impl PayoutBatchItemNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<PayoutBatchItem> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl PayoutBatchItem {
+    pub async fn update(
+        self,
+        conn: &PgPooledConn,
+        payout_batch_item_update: PayoutBatchItemUpdate,
+    ) -> StorageResult<Self> {
+        match generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            payout_batch_item::dsl::merchant_id
+                .eq(self.merchant_id.to_owned())
+                .and(payout_batch_item::dsl::batch_id.eq(self.batch_id.to_owned()))
+                .and(payout_batch_item::dsl::row_number.eq(self.row_number)),
+            PayoutBatchItemUpdateInternal::from(payout_batch_item_update),
+        )
+        .await
+        {
+            Err(error) => match error.current_context() {
+                errors::DatabaseError::NoFieldsToUpdate => Ok(self),
+                _ => Err(error),
+            },
+            result => result,
+        }
+    }
+
+    pub async fn find_by_merchant_id_batch_id_row_number_range(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            payout_batch_item::dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(payout_batch_item::dsl::batch_id.eq(batch_id.to_owned()))
+                .and(payout_batch_item::dsl::row_number.ge(start_row_number))
+                .and(payout_batch_item::dsl::row_number.lt(end_row_number)),
+            None,
+            None,
+            Some(payout_batch_item::dsl::row_number.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_batch_id_with_payout_status(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<(Self, Option<enums::PayoutStatus>)>> {
+        let query = <Self as HasTable>::table()
+            .left_join(
+                payouts::table.on(payouts::dsl::payout_id
+                    .nullable()
+                    .eq(payout_batch_item::dsl::payout_id)
+                    .and(payouts::dsl::merchant_id.eq(payout_batch_item::dsl::merchant_id))),
+            )
+            .filter(payout_batch_item::dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payout_batch_item::dsl::batch_id.eq(batch_id.to_owned()))
+            .order(payout_batch_item::dsl::row_number.asc())
+            .select((Self::as_select(), payouts::dsl::status.nullable()));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(Self, Option<enums::PayoutStatus>)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error filtering payout batch items")
+    }
+
+    pub async fn get_payout_status_with_count(
+        conn: &PgPooledConn,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> StorageResult<Vec<(enums::PayoutStatus, i64)>> {
+        let query = <Self as HasTable>::table()
+            .inner_join(
+                payouts::table.on(payouts::dsl::payout_id
+                    .nullable()
+                    .eq(payout_batch_item::dsl::payout_id)
+                    .and(payouts::dsl::merchant_id.eq(payout_batch_item::dsl::merchant_id))),
+            )
+            .filter(payout_batch_item::dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(payout_batch_item::dsl::batch_id.eq(batch_id.to_owned()))
+            .group_by(payouts::dsl::status)
+            .select((payouts::dsl::status, diesel::dsl::count_star()));
+
+        router_env::logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        db_metrics::track_database_call::<<Self as HasTable>::Table, _, _>(
+            query.get_results_async::<(enums::PayoutStatus, i64)>(conn),
+            db_metrics::DatabaseOperation::Filter,
+        )
+        .await
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error counting payouts of payout batch")
+    }
+}
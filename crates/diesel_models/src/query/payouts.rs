@@ -24,13 +24,22 @@ impl Payouts {
         self,
         conn: &PgPooledConn,
         payout_update: PayoutsUpdate,
+    ) -> StorageResult<Self> {
+        self.update_with_changeset(conn, PayoutsUpdateInternal::from(payout_update))
+            .await
+    }
+
+    pub async fn update_with_changeset(
+        self,
+        conn: &PgPooledConn,
+        changeset: PayoutsUpdateInternal,
     ) -> StorageResult<Self> {
         match generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
             conn,
             dsl::payout_id
                 .eq(self.payout_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            PayoutsUpdateInternal::from(payout_update),
+            changeset,
         )
         .await
         {
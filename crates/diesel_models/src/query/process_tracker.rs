@@ -6,7 +6,8 @@ use super::generics;
 use crate::{
     enums, errors,
     process_tracker::{
-        ProcessTracker, ProcessTrackerNew, ProcessTrackerUpdate, ProcessTrackerUpdateInternal,
+        ProcessTracker, ProcessTrackerFilters, ProcessTrackerNew, ProcessTrackerUpdate,
+        ProcessTrackerUpdateInternal,
     },
     schema::process_tracker::dsl,
     PgPooledConn, StorageResult,
@@ -41,6 +42,24 @@ impl ProcessTracker {
         }
     }
 
+    /// Updates the task only if its status is one of `statuses`, returns `None` if the status of
+    /// the task has changed since it was read.
+    #[instrument(skip(conn))]
+    pub async fn update_if_status_in(
+        self,
+        conn: &PgPooledConn,
+        statuses: Vec<enums::ProcessTrackerStatus>,
+        process: ProcessTrackerUpdate,
+    ) -> StorageResult<Option<Self>> {
+        generics::generic_update_with_results::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::id.eq(self.id).and(dsl::status.eq_any(statuses)),
+            ProcessTrackerUpdateInternal::from(process),
+        )
+        .await
+        .map(|mut processes| processes.pop())
+    }
+
     #[instrument(skip(conn))]
     pub async fn update_process_status_by_ids(
         conn: &PgPooledConn,
@@ -136,4 +155,86 @@ impl ProcessTracker {
         )
         .await
     }
+
+    #[instrument(skip(conn))]
+    pub async fn find_processes_by_filters(
+        conn: &PgPooledConn,
+        filters: &ProcessTrackerFilters,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::ResultExt;
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+
+        let mut query = <Self as HasTable>::table()
+            .order(dsl::schedule_time.desc())
+            .into_boxed();
+
+        if let Some(runner) = &filters.runner {
+            query = query.filter(dsl::runner.eq(runner.to_owned()));
+        }
+
+        if let Some(status) = filters.status {
+            query = query.filter(dsl::status.eq(status));
+        }
+
+        if let Some(business_status) = &filters.business_status {
+            query = query.filter(dsl::business_status.eq(business_status.to_owned()));
+        }
+
+        if let Some(merchant_id) = &filters.merchant_id {
+            // Tasks do not have a merchant column, the merchant is a part of the tracking data
+            query = query.filter(
+                diesel::dsl::sql::<diesel::sql_types::Bool>("tracking_data ->> 'merchant_id' = ")
+                    .bind::<diesel::sql_types::Text, _>(merchant_id.get_string_repr().to_owned()),
+            );
+        }
+
+        if let Some(scheduled_after) = filters.scheduled_after {
+            query = query.filter(dsl::schedule_time.ge(scheduled_after));
+        }
+
+        if let Some(scheduled_before) = filters.scheduled_before {
+            query = query.filter(dsl::schedule_time.le(scheduled_before));
+        }
+
+        if let Some(limit) = filters.limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = filters.offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .change_context(errors::DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering process tracker tasks by constraints")
+    }
+
+    /// Moves the tasks of a runner that are waiting to be picked up and are due before
+    /// `schedule_time` to `schedule_time`, returning the number of tasks moved.
+    #[instrument(skip(conn))]
+    pub async fn reschedule_due_processes_by_runner(
+        conn: &PgPooledConn,
+        runner: &str,
+        schedule_time: PrimitiveDateTime,
+    ) -> StorageResult<usize> {
+        generics::generic_update::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::runner
+                .eq(runner.to_owned())
+                .and(dsl::status.eq_any([
+                    enums::ProcessTrackerStatus::New,
+                    enums::ProcessTrackerStatus::Pending,
+                ]))
+                .and(dsl::schedule_time.lt(schedule_time)),
+            dsl::schedule_time.eq(schedule_time),
+        )
+        .await
+    }
 }
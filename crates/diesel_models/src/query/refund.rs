@@ -16,6 +16,15 @@ impl RefundNew {
 
 impl Refund {
     pub async fn update(self, conn: &PgPooledConn, refund: RefundUpdate) -> StorageResult<Self> {
+        self.update_with_changeset(conn, RefundUpdateInternal::from(refund))
+            .await
+    }
+
+    pub async fn update_with_changeset(
+        self,
+        conn: &PgPooledConn,
+        changeset: RefundUpdateInternal,
+    ) -> StorageResult<Self> {
         match generics::generic_update_with_unique_predicate_get_result::<
             <Self as HasTable>::Table,
             _,
@@ -26,7 +35,7 @@ impl Refund {
             dsl::refund_id
                 .eq(self.refund_id.to_owned())
                 .and(dsl::merchant_id.eq(self.merchant_id.to_owned())),
-            RefundUpdateInternal::from(refund),
+            changeset,
         )
         .await
         {
@@ -51,6 +51,9 @@ pub struct Refund {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub charges: Option<ChargeRefunds>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub payout_id: Option<String>,
+    pub created_by: Option<String>,
+    pub daily_refund_limit_reservation: Option<DailyRefundLimitReservation>,
 }
 
 #[derive(
@@ -94,6 +97,9 @@ pub struct RefundNew {
     pub merchant_connector_id: Option<common_utils::id_type::MerchantConnectorAccountId>,
     pub charges: Option<ChargeRefunds>,
     pub organization_id: common_utils::id_type::OrganizationId,
+    pub payout_id: Option<String>,
+    pub created_by: Option<String>,
+    pub daily_refund_limit_reservation: Option<DailyRefundLimitReservation>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -130,8 +136,33 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    PayoutUpdate {
+        payout_id: String,
+        refund_status: storage_enums::RefundStatus,
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        updated_by: String,
+    },
+    DailyRefundLimitReservationUpdate {
+        daily_refund_limit_reservation: Option<DailyRefundLimitReservation>,
+        updated_by: String,
+    },
+}
+
+/// Amount of a refund added to the daily refund counters of its profile, which is removed from
+/// the same counters if the refund fails
+#[derive(
+    Clone, Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, diesel::AsExpression,
+)]
+#[diesel(sql_type = diesel::sql_types::Jsonb)]
+pub struct DailyRefundLimitReservation {
+    pub key: String,
+    pub fields: Vec<String>,
+    pub refund_amount: MinorUnit,
 }
 
+common_utils::impl_to_sql_from_sql_json!(DailyRefundLimitReservation);
+
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
 #[diesel(table_name = refund)]
 pub struct RefundUpdateInternal {
@@ -145,9 +176,32 @@ pub struct RefundUpdateInternal {
     refund_error_code: Option<String>,
     updated_by: String,
     modified_at: PrimitiveDateTime,
+    payout_id: Option<String>,
+    daily_refund_limit_reservation: Option<Option<DailyRefundLimitReservation>>,
 }
 
 impl RefundUpdateInternal {
+    /// Merges `later`, a changeset for the same row issued after `self`, into a single changeset
+    /// which leaves the row in the same final state
+    pub fn merge(self, later: Self) -> Self {
+        Self {
+            connector_refund_id: later.connector_refund_id.or(self.connector_refund_id),
+            refund_status: later.refund_status.or(self.refund_status),
+            sent_to_gateway: later.sent_to_gateway.or(self.sent_to_gateway),
+            refund_error_message: later.refund_error_message.or(self.refund_error_message),
+            refund_arn: later.refund_arn.or(self.refund_arn),
+            metadata: later.metadata.or(self.metadata),
+            refund_reason: later.refund_reason.or(self.refund_reason),
+            refund_error_code: later.refund_error_code.or(self.refund_error_code),
+            updated_by: later.updated_by,
+            modified_at: later.modified_at,
+            payout_id: later.payout_id.or(self.payout_id),
+            daily_refund_limit_reservation: later
+                .daily_refund_limit_reservation
+                .or(self.daily_refund_limit_reservation),
+        }
+    }
+
     pub fn create_refund(self, source: Refund) -> Refund {
         Refund {
             connector_refund_id: self.connector_refund_id,
@@ -160,6 +214,10 @@ impl RefundUpdateInternal {
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
             modified_at: self.modified_at,
+            payout_id: self.payout_id.or(source.payout_id),
+            daily_refund_limit_reservation: self
+                .daily_refund_limit_reservation
+                .unwrap_or(source.daily_refund_limit_reservation),
             ..source
         }
     }
@@ -186,6 +244,8 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                payout_id: None,
+                daily_refund_limit_reservation: None,
             },
             RefundUpdate::MetadataAndReasonUpdate {
                 metadata,
@@ -202,6 +262,8 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_arn: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                payout_id: None,
+                daily_refund_limit_reservation: None,
             },
             RefundUpdate::StatusUpdate {
                 connector_refund_id,
@@ -219,6 +281,8 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 refund_reason: None,
                 refund_error_code: None,
                 modified_at: common_utils::date_time::now(),
+                payout_id: None,
+                daily_refund_limit_reservation: None,
             },
             RefundUpdate::ErrorUpdate {
                 refund_status,
@@ -237,6 +301,8 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                payout_id: None,
+                daily_refund_limit_reservation: None,
             },
             RefundUpdate::ManualUpdate {
                 refund_status,
@@ -254,6 +320,45 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 metadata: None,
                 refund_reason: None,
                 modified_at: common_utils::date_time::now(),
+                payout_id: None,
+                daily_refund_limit_reservation: None,
+            },
+            RefundUpdate::PayoutUpdate {
+                payout_id,
+                refund_status,
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+            } => Self {
+                payout_id: Some(payout_id),
+                refund_status: Some(refund_status),
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+                connector_refund_id: None,
+                sent_to_gateway: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                modified_at: common_utils::date_time::now(),
+                daily_refund_limit_reservation: None,
+            },
+            RefundUpdate::DailyRefundLimitReservationUpdate {
+                daily_refund_limit_reservation,
+                updated_by,
+            } => Self {
+                daily_refund_limit_reservation: Some(daily_refund_limit_reservation),
+                updated_by,
+                connector_refund_id: None,
+                refund_status: None,
+                sent_to_gateway: None,
+                refund_error_message: None,
+                refund_arn: None,
+                metadata: None,
+                refund_reason: None,
+                refund_error_code: None,
+                modified_at: common_utils::date_time::now(),
+                payout_id: None,
             },
         }
     }
@@ -272,6 +377,8 @@ impl RefundUpdate {
             refund_error_code,
             updated_by,
             modified_at: _,
+            payout_id,
+            daily_refund_limit_reservation,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -284,6 +391,9 @@ impl RefundUpdate {
             refund_reason: refund_reason.or(source.refund_reason),
             updated_by,
             modified_at: common_utils::date_time::now(),
+            payout_id: payout_id.or(source.payout_id),
+            daily_refund_limit_reservation: daily_refund_limit_reservation
+                .unwrap_or(source.daily_refund_limit_reservation),
             ..source
         }
     }
@@ -1,5 +1,32 @@
 // @generated automatically by Diesel CLI.
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    account_updater_item (merchant_id, item_id) {
+        #[max_length = 64]
+        item_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        payment_method_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 32]
+        reason -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        batch_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        outcome -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -209,6 +236,12 @@ diesel::table! {
         tax_connector_id -> Nullable<Varchar>,
         is_tax_connector_enabled -> Nullable<Bool>,
         version -> ApiVersion,
+        refund_policy -> Nullable<Jsonb>,
+        auto_capture_policy -> Nullable<Jsonb>,
+        #[max_length = 32]
+        authorization_expiry_action -> Nullable<Varchar>,
+        is_pre_debit_balance_check_enabled -> Nullable<Bool>,
+        is_account_ownership_check_enabled -> Nullable<Bool>,
     }
 }
 
@@ -283,6 +316,26 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    customer_address_label (merchant_id, address_id) {
+        #[max_length = 64]
+        address_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 16]
+        address_type -> Varchar,
+        #[max_length = 64]
+        label -> Nullable<Varchar>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -310,6 +363,8 @@ diesel::table! {
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
         version -> ApiVersion,
+        tax_ids -> Nullable<Bytea>,
+        segments -> Nullable<Array<Nullable<Text>>>,
     }
 }
 
@@ -336,6 +391,28 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    data_subject_request (merchant_id, request_id) {
+        #[max_length = 64]
+        request_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        customer_id -> Varchar,
+        #[max_length = 16]
+        request_type -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        report -> Nullable<Jsonb>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+        completed_at -> Nullable<Timestamp>,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -917,6 +994,10 @@ diesel::table! {
         is_payment_processor_token_flow -> Nullable<Bool>,
         #[max_length = 32]
         organization_id -> Varchar,
+        auto_capture_policy -> Nullable<Jsonb>,
+        authorization_expires_at -> Nullable<Timestamp>,
+        authorized_at -> Nullable<Timestamp>,
+        captured_at -> Nullable<Timestamp>,
     }
 }
 
@@ -950,6 +1031,55 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_migration (merchant_id, migration_id) {
+        #[max_length = 64]
+        migration_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 16]
+        file_format -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        parallelism -> Int4,
+        total_count -> Int4,
+        processed_count -> Int4,
+        failed_count -> Int4,
+        processed_offset -> Int8,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payment_method_migration_item (merchant_id, migration_id, line_number) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        migration_id -> Varchar,
+        line_number -> Int4,
+        #[max_length = 64]
+        customer_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        payment_method_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        status -> Varchar,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1002,6 +1132,11 @@ diesel::table! {
         payment_method_billing_address -> Nullable<Bytea>,
         #[max_length = 64]
         updated_by -> Nullable<Varchar>,
+        #[max_length = 32]
+        health_status -> Nullable<Varchar>,
+        consecutive_mit_declines -> Nullable<Int4>,
+        #[max_length = 64]
+        fingerprint_id -> Nullable<Varchar>,
     }
 }
 
@@ -1044,6 +1179,52 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch (merchant_id, batch_id) {
+        #[max_length = 64]
+        batch_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        profile_id -> Varchar,
+        #[max_length = 64]
+        file_id -> Varchar,
+        #[max_length = 16]
+        file_format -> Varchar,
+        #[max_length = 32]
+        status -> Varchar,
+        confirm -> Bool,
+        auto_fulfill -> Bool,
+        total_count -> Int4,
+        processed_count -> Int4,
+        failed_count -> Int4,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    payout_batch_item (merchant_id, batch_id, row_number) {
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        batch_id -> Varchar,
+        row_number -> Int4,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        error_code -> Nullable<Varchar>,
+        error_message -> Nullable<Text>,
+        created_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1085,6 +1266,8 @@ diesel::table! {
         client_secret -> Nullable<Varchar>,
         #[max_length = 32]
         priority -> Nullable<Varchar>,
+        scheduled_at -> Nullable<Timestamp>,
+        recurrence -> Nullable<Jsonb>,
     }
 }
 
@@ -1111,6 +1294,9 @@ diesel::table! {
         event -> Array<Nullable<Text>>,
         created_at -> Timestamp,
         updated_at -> Timestamp,
+        priority -> Int2,
+        #[max_length = 255]
+        cron_expression -> Nullable<Varchar>,
     }
 }
 
@@ -1163,6 +1349,11 @@ diesel::table! {
         charges -> Nullable<Jsonb>,
         #[max_length = 32]
         organization_id -> Varchar,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        created_by -> Nullable<Varchar>,
+        daily_refund_limit_reservation -> Nullable<Jsonb>,
     }
 }
 
@@ -1343,6 +1534,7 @@ diesel::table! {
 }
 
 diesel::allow_tables_to_appear_in_same_query!(
+    account_updater_item,
     address,
     api_keys,
     authentication,
@@ -1353,8 +1545,10 @@ diesel::allow_tables_to_appear_in_same_query!(
     captures,
     cards_info,
     configs,
+    customer_address_label,
     customers,
     dashboard_metadata,
+    data_subject_request,
     dispute,
     events,
     file_metadata,
@@ -1371,8 +1565,12 @@ diesel::allow_tables_to_appear_in_same_query!(
     payment_attempt,
     payment_intent,
     payment_link,
+    payment_method_migration,
+    payment_method_migration_item,
     payment_methods,
     payout_attempt,
+    payout_batch,
+    payout_batch_item,
     payouts,
     process_tracker,
     refund,
@@ -0,0 +1,456 @@
+use std::{collections::HashMap, sync::Arc};
+
+use async_bb8_diesel::AsyncConnection;
+use common_utils::errors::CustomResult;
+use diesel_models::errors::DatabaseError;
+use error_stack::{report, ResultExt};
+
+use crate::{kv, logger, metrics, pg_connection, services::Store};
+
+/// A single statement produced from one or more consecutive stream entries
+#[derive(Debug)]
+pub(crate) enum BatchedQuery {
+    /// Multi-row insert, every element targets the same table
+    Insert(Vec<kv::Insertable>),
+    /// Update of a single row, possibly merged from several entries for the same row
+    Update(kv::Updateable),
+}
+
+impl BatchedQuery {
+    async fn execute(self, conn: &diesel_models::PgPooledConn) -> diesel_models::StorageResult<()> {
+        match self {
+            Self::Insert(insertables) => {
+                kv::Insertable::batch_insert(conn, insertables).await?;
+            }
+            Self::Update(updatable) => {
+                kv::DBOperation::Update { updatable }.execute(conn).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Groups of updates for the same row, kept in the order of the first update of each row
+#[derive(Default)]
+struct UpdateRun {
+    groups: Vec<Vec<kv::Updateable>>,
+    positions: HashMap<String, usize>,
+}
+
+impl UpdateRun {
+    fn push(&mut self, updatable: kv::Updateable) {
+        match updatable.coalescing_key() {
+            Some(key) => match self
+                .positions
+                .get(&key)
+                .and_then(|&position| self.groups.get_mut(position))
+            {
+                Some(group) => group.push(updatable),
+                None => {
+                    self.positions.insert(key, self.groups.len());
+                    self.groups.push(vec![updatable]);
+                }
+            },
+            None => self.groups.push(vec![updatable]),
+        }
+    }
+
+    fn flush_into(&mut self, queries: &mut Vec<BatchedQuery>) -> usize {
+        let mut coalesced = 0;
+        self.positions.clear();
+
+        for group in self.groups.drain(..) {
+            let mut current = None;
+            for updatable in group {
+                current = Some(match current.take() {
+                    None => updatable,
+                    Some(earlier) => match kv::Updateable::coalesce(earlier, updatable) {
+                        Ok(merged) => {
+                            coalesced += 1;
+                            merged
+                        }
+                        Err((earlier, later)) => {
+                            queries.push(BatchedQuery::Update(earlier));
+                            later
+                        }
+                    },
+                });
+            }
+            queries.extend(current.map(BatchedQuery::Update));
+        }
+
+        coalesced
+    }
+}
+
+/// Plans the statements needed to apply `operations`, which are expected in stream order.
+///
+/// Consecutive inserts into the same table are grouped into a multi-row insert. Within a run of
+/// consecutive updates, updates to the same row are coalesced into a single update, keeping the
+/// relative order of the updates to every row. Updates are never moved across inserts, so a row
+/// is always inserted before it's updated.
+///
+/// Returns the planned statements along with the number of updates that were coalesced away.
+pub(crate) fn plan(operations: Vec<kv::DBOperation>) -> (Vec<BatchedQuery>, usize) {
+    let mut queries = Vec::new();
+    let mut update_run = UpdateRun::default();
+    let mut coalesced = 0;
+
+    for operation in operations {
+        match operation {
+            kv::DBOperation::Insert { insertable } => {
+                coalesced += update_run.flush_into(&mut queries);
+                match queries.last_mut() {
+                    Some(BatchedQuery::Insert(insertables))
+                        if insertables
+                            .last()
+                            .is_some_and(|last| last.is_batchable_with(&insertable)) =>
+                    {
+                        insertables.push(insertable)
+                    }
+                    _ => queries.push(BatchedQuery::Insert(vec![insertable])),
+                }
+            }
+            kv::DBOperation::Update { updatable } => update_run.push(updatable),
+        }
+    }
+    coalesced += update_run.flush_into(&mut queries);
+
+    (queries, coalesced)
+}
+
+/// Executes all the `operations` read from a stream inside a single transaction, either all of
+/// them are applied or none of them are.
+pub(crate) async fn execute_batch(
+    store: &Arc<Store>,
+    operations: Vec<(kv::DBOperation, i64)>,
+) -> CustomResult<(), DatabaseError> {
+    let batch_size = u64::try_from(operations.len()).unwrap_or(u64::MAX);
+    let drained_at = common_utils::date_time::now_unix_timestamp();
+
+    if let Some(oldest_pushed_at) = operations.iter().map(|(_, pushed_at)| *pushed_at).min() {
+        metrics::DRAINER_LAG_SECONDS.record(&metrics::CONTEXT, drained_at - oldest_pushed_at, &[]);
+    }
+
+    // The delays are recorded once the batch is committed, the entries of a failed batch are
+    // recorded when they are executed one at a time
+    let (operations, delays): (Vec<_>, Vec<_>) = operations
+        .into_iter()
+        .map(|(operation, pushed_at)| {
+            let delay = (pushed_at, operation.operation(), operation.table());
+            (operation, delay)
+        })
+        .unzip();
+
+    let (queries, coalesced) = plan(operations);
+    let statements = u64::try_from(queries.len()).unwrap_or(u64::MAX);
+
+    metrics::BATCH_SIZE.record(&metrics::CONTEXT, batch_size, &[]);
+    metrics::BATCH_STATEMENTS.record(&metrics::CONTEXT, statements, &[]);
+    metrics::COALESCED_UPDATES.add(
+        &metrics::CONTEXT,
+        u64::try_from(coalesced).unwrap_or(u64::MAX),
+        &[],
+    );
+
+    let conn = pg_connection(&store.master_pool).await;
+
+    let (result, execution_time) = common_utils::date_time::time_it(|| {
+        conn.transaction_async(|conn| {
+            Box::pin(async move {
+                for query in queries {
+                    query.execute(&conn).await.map_err(|err| {
+                        logger::error!(operation = "batch_execution", ?err);
+                        *err.current_context()
+                    })?;
+                }
+                Ok::<_, DatabaseError>(())
+            })
+        })
+    })
+    .await;
+
+    metrics::BATCH_EXECUTION_TIME.record(&metrics::CONTEXT, execution_time, &[]);
+
+    match result {
+        Ok(()) => {
+            logger::info!(
+                batch_size = batch_size,
+                statements = statements,
+                coalesced = coalesced,
+                "Batch executed successfully"
+            );
+            metrics::SUCCESSFUL_QUERY_EXECUTION.add(&metrics::CONTEXT, statements, &[]);
+            for (pushed_at, operation, table) in delays {
+                crate::query::push_drainer_delay(
+                    pushed_at,
+                    operation,
+                    table,
+                    &[
+                        metrics::KeyValue::new("operation", operation),
+                        metrics::KeyValue::new("table", table),
+                    ],
+                );
+            }
+            Ok(())
+        }
+        Err(err) => {
+            metrics::ERRORS_WHILE_QUERY_EXECUTION.add(&metrics::CONTEXT, 1, &[]);
+            Err(report!(err)).attach_printable("Failed to execute the batch of stream entries")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use diesel_models::{
+        enums::{AttemptStatus, MandateStatus},
+        kv::{
+            self, DBOperation, Insertable, MandateUpdateMems, PaymentAttemptUpdateMems, Updateable,
+        },
+        payment_attempt::{PaymentAttempt, PaymentAttemptUpdate, PaymentAttemptUpdateInternal},
+        reverse_lookup::ReverseLookupNew,
+        Mandate, MandateNew, MandateUpdate, MandateUpdateInternal,
+    };
+
+    use super::{plan, BatchedQuery};
+
+    fn reverse_lookup(lookup_id: &str) -> DBOperation {
+        DBOperation::Insert {
+            insertable: Insertable::ReverseLookUp(ReverseLookupNew {
+                lookup_id: lookup_id.to_string(),
+                pk_id: "pk".to_string(),
+                sk_id: "sk".to_string(),
+                source: "payment_attempt".to_string(),
+                updated_by: "redis_kv".to_string(),
+            }),
+        }
+    }
+
+    fn mandate(mandate_id: &str) -> Mandate {
+        Mandate::from(&MandateNew {
+            mandate_id: mandate_id.to_string(),
+            ..Default::default()
+        })
+    }
+
+    fn mandate_update(orig: &Mandate, update: MandateUpdate) -> DBOperation {
+        DBOperation::Update {
+            updatable: Updateable::MandateUpdate(MandateUpdateMems {
+                orig: orig.clone(),
+                update_data: MandateUpdateInternal::from(update),
+            }),
+        }
+    }
+
+    fn apply_sequentially(source: Mandate, operations: Vec<DBOperation>) -> Mandate {
+        operations
+            .into_iter()
+            .fold(source, |current, operation| match operation {
+                DBOperation::Update {
+                    updatable: Updateable::MandateUpdate(m),
+                } => m.update_data.apply_changeset(current),
+                _ => current,
+            })
+    }
+
+    #[test]
+    fn test_consecutive_inserts_into_same_table_are_grouped() {
+        let (queries, coalesced) = plan(vec![
+            reverse_lookup("lookup_1"),
+            reverse_lookup("lookup_2"),
+            reverse_lookup("lookup_3"),
+        ]);
+
+        assert_eq!(coalesced, 0);
+        assert!(matches!(queries.as_slice(), [BatchedQuery::Insert(rows)] if rows.len() == 3));
+    }
+
+    #[test]
+    fn test_updates_are_not_moved_across_inserts() {
+        let orig = mandate("mandate_1");
+        let (queries, coalesced) = plan(vec![
+            reverse_lookup("lookup_1"),
+            mandate_update(
+                &orig,
+                MandateUpdate::StatusUpdate {
+                    mandate_status: MandateStatus::Inactive,
+                },
+            ),
+            reverse_lookup("lookup_2"),
+            mandate_update(
+                &orig,
+                MandateUpdate::StatusUpdate {
+                    mandate_status: MandateStatus::Revoked,
+                },
+            ),
+        ]);
+
+        assert_eq!(coalesced, 0);
+        assert!(matches!(
+            queries.as_slice(),
+            [
+                BatchedQuery::Insert(_),
+                BatchedQuery::Update(_),
+                BatchedQuery::Insert(_),
+                BatchedQuery::Update(_)
+            ]
+        ));
+    }
+
+    #[test]
+    fn test_coalesced_updates_leave_same_final_state() {
+        let first = mandate("mandate_1");
+        let second = mandate("mandate_2");
+
+        let operations = || {
+            vec![
+                mandate_update(
+                    &first,
+                    MandateUpdate::CaptureAmountUpdate {
+                        amount_captured: Some(100),
+                    },
+                ),
+                mandate_update(
+                    &second,
+                    MandateUpdate::StatusUpdate {
+                        mandate_status: MandateStatus::Revoked,
+                    },
+                ),
+                mandate_update(
+                    &first,
+                    MandateUpdate::StatusUpdate {
+                        mandate_status: MandateStatus::Inactive,
+                    },
+                ),
+                mandate_update(
+                    &first,
+                    MandateUpdate::CaptureAmountUpdate {
+                        amount_captured: Some(250),
+                    },
+                ),
+            ]
+        };
+
+        let (queries, coalesced) = plan(operations());
+
+        assert_eq!(coalesced, 2);
+        assert_eq!(queries.len(), 2);
+
+        let mut coalesced_first = first.clone();
+        let mut coalesced_second = second.clone();
+        for m in queries.into_iter().filter_map(|query| match query {
+            BatchedQuery::Update(Updateable::MandateUpdate(m)) => Some(m),
+            _ => None,
+        }) {
+            if m.orig.mandate_id == first.mandate_id {
+                coalesced_first = m.update_data.apply_changeset(coalesced_first);
+            } else {
+                coalesced_second = m.update_data.apply_changeset(coalesced_second);
+            }
+        }
+
+        let (first_operations, second_operations): (Vec<_>, Vec<_>) =
+            operations().into_iter().partition(|operation| {
+                matches!(
+                    operation,
+                    DBOperation::Update {
+                        updatable: Updateable::MandateUpdate(m),
+                    } if m.orig.mandate_id == first.mandate_id
+                )
+            });
+
+        assert_eq!(
+            serde_json::to_value(apply_sequentially(first, first_operations)).unwrap(),
+            serde_json::to_value(coalesced_first).unwrap()
+        );
+        assert_eq!(
+            serde_json::to_value(apply_sequentially(second, second_operations)).unwrap(),
+            serde_json::to_value(coalesced_second).unwrap()
+        );
+    }
+
+    fn payment_attempt(attempt_id: &str) -> PaymentAttempt {
+        serde_json::from_value(serde_json::json!({
+            "payment_id": "pay_1",
+            "merchant_id": "merchant_1",
+            "attempt_id": attempt_id,
+            "status": "started",
+            "amount": 1000,
+            "confirm": true,
+            "created_at": "2024-01-01T00:00:00.000Z",
+            "modified_at": "2024-01-01T00:00:00.000Z",
+            "amount_capturable": 1000,
+            "updated_by": "redis_kv",
+            "profile_id": "pro_1",
+            "organization_id": "org_1",
+        }))
+        .unwrap()
+    }
+
+    fn payment_attempt_update(orig: &PaymentAttempt, update: PaymentAttemptUpdate) -> DBOperation {
+        DBOperation::Update {
+            updatable: Updateable::PaymentAttemptUpdate(PaymentAttemptUpdateMems {
+                orig: orig.clone(),
+                update_data: update,
+                coalesced_updates: Vec::new(),
+            }),
+        }
+    }
+
+    #[test]
+    fn test_consecutive_payment_attempt_updates_are_coalesced() {
+        let orig = payment_attempt("attempt_1");
+        let updates = || {
+            vec![
+                PaymentAttemptUpdate::PreprocessingUpdate {
+                    status: AttemptStatus::Pending,
+                    payment_method_id: None,
+                    connector_metadata: None,
+                    preprocessing_step_id: Some("pre_1".to_string()),
+                    connector_transaction_id: Some("txn_1".to_string()),
+                    connector_response_reference_id: None,
+                    updated_by: "redis_kv".to_string(),
+                },
+                PaymentAttemptUpdate::StatusUpdate {
+                    status: AttemptStatus::Authorized,
+                    updated_by: "redis_kv".to_string(),
+                },
+                PaymentAttemptUpdate::AmountToCaptureUpdate {
+                    status: AttemptStatus::Charged,
+                    amount_capturable: 0,
+                    updated_by: "redis_kv".to_string(),
+                },
+            ]
+        };
+
+        let (queries, coalesced) = plan(
+            updates()
+                .into_iter()
+                .map(|update| payment_attempt_update(&orig, update))
+                .collect(),
+        );
+
+        assert_eq!(coalesced, 2);
+        let [BatchedQuery::Update(Updateable::PaymentAttemptUpdate(merged))] = queries.as_slice()
+        else {
+            panic!("Expected a single payment attempt update, got {queries:?}");
+        };
+        assert_eq!(merged.coalesced_updates.len(), 2);
+
+        let mut coalesced_attempt = kv::merge_changesets(
+            merged.update_data.clone(),
+            merged.coalesced_updates.clone(),
+            PaymentAttemptUpdateInternal::merge,
+        )
+        .apply_changeset(orig.clone());
+        let sequential_attempt = updates()
+            .into_iter()
+            .fold(orig, |current, update| update.apply_changeset(current));
+
+        assert_eq!(coalesced_attempt.status, AttemptStatus::Charged);
+        coalesced_attempt.modified_at = sequential_attempt.modified_at;
+        assert_eq!(coalesced_attempt, sequential_attempt);
+    }
+}
@@ -27,6 +27,36 @@ pub enum HealthCheckError {
     RedisError { message: String },
 }
 
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum QuarantineError {
+    #[error("Invalid or missing admin API key")]
+    Unauthorized,
+    #[error("Tenant `{tenant}` was not found")]
+    TenantNotFound { tenant: String },
+    #[error("Shard {shard} does not exist, the layout has {num_partitions} shards")]
+    ShardNotFound { shard: u8, num_partitions: u8 },
+    #[error("Layout version {version} does not exist")]
+    LayoutNotFound { version: u32 },
+    #[error("Quarantined entry `{entry_id}` was not found")]
+    EntryNotFound { entry_id: String },
+    #[error("Quarantined entry is not a valid database operation: {message}")]
+    InvalidEntry { message: String },
+    #[error("Failed to execute the quarantined entry: {message}")]
+    RetryFailed { message: String },
+    #[error("Error during redis operation")]
+    RedisError,
+}
+
+#[derive(Debug, Error, Clone, serde::Serialize)]
+pub enum PartitionMapError {
+    #[error("Invalid or missing admin API key")]
+    Unauthorized,
+    #[error("Tenant `{tenant}` was not found")]
+    TenantNotFound { tenant: String },
+    #[error("Error during redis operation")]
+    RedisError,
+}
+
 impl From<std::io::Error> for DrainerError {
     fn from(err: std::io::Error) -> Self {
         Self::IoError(err)
@@ -56,3 +86,32 @@ impl actix_web::ResponseError for HealthCheckError {
         }
     }
 }
+
+impl actix_web::ResponseError for QuarantineError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. }
+            | Self::ShardNotFound { .. }
+            | Self::LayoutNotFound { .. }
+            | Self::EntryNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::InvalidEntry { .. } => StatusCode::BAD_REQUEST,
+            Self::RetryFailed { .. } => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl actix_web::ResponseError for PartitionMapError {
+    fn status_code(&self) -> reqwest::StatusCode {
+        use reqwest::StatusCode;
+
+        match self {
+            Self::Unauthorized => StatusCode::UNAUTHORIZED,
+            Self::TenantNotFound { .. } => StatusCode::NOT_FOUND,
+            Self::RedisError => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::{
     sync::{atomic, Arc},
 };
 
+use diesel_models::{errors::DatabaseError, kv::partition::PartitionLayout};
 use router_env::tracing::Instrument;
 use tokio::{
     sync::{mpsc, oneshot},
@@ -10,8 +11,8 @@ use tokio::{
 };
 
 use crate::{
-    errors, instrument, logger, metrics, query::ExecuteQuery, tracing, utils, DrainerSettings,
-    Store, StreamData,
+    batch, errors, instrument, logger, metrics, quarantine::QuarantineReason, query::ExecuteQuery,
+    stream::StreamEntries, tracing, utils, DrainerSettings, Store, StreamData,
 };
 
 /// Handler handles the spawning and closing of drainer
@@ -69,13 +70,33 @@ impl Handler {
         let mut stream_index: u8 = 0;
         let jobs_picked = Arc::new(atomic::AtomicU8::new(0));
 
+        for store in self.stores.values() {
+            store.init_partition_map().await?;
+        }
+
         while self.running.load(atomic::Ordering::SeqCst) {
             metrics::DRAINER_HEALTH.add(&metrics::CONTEXT, 1, &[]);
+            // The streams of the layout with the most partitions bound the cycle
+            let mut num_partitions = 1;
             for store in self.stores.values() {
-                if store.is_stream_available(stream_index).await {
+                // The partition map is read on every iteration, since it is updated by any of the
+                // running drainers
+                let layout = match store.get_partition_map().await {
+                    Ok(partition_map) => *partition_map.draining_layout(),
+                    Err(error) => {
+                        logger::error!(operation = "get_partition_map", ?error);
+                        continue;
+                    }
+                };
+                num_partitions = num_partitions.max(layout.num_partitions);
+
+                if stream_index < layout.num_partitions
+                    && store.is_stream_available(&layout, stream_index).await
+                {
                     let _task_handle = tokio::spawn(
                         drainer_handler(
                             store.clone(),
+                            layout,
                             stream_index,
                             self.conf.max_read_count,
                             self.active_tasks.clone(),
@@ -85,11 +106,19 @@ impl Handler {
                     );
                 }
             }
-            stream_index = utils::increment_stream_index(
-                (stream_index, jobs_picked.clone()),
-                self.conf.num_partitions,
-            )
-            .await;
+            stream_index =
+                utils::increment_stream_index((stream_index, jobs_picked.clone()), num_partitions)
+                    .await;
+
+            if stream_index == 0 {
+                for store in self.stores.values() {
+                    store
+                        .complete_reshard_if_drained(&self.conf)
+                        .await
+                        .map_err(|error| logger::error!(operation = "complete_reshard", ?error))
+                        .ok();
+                }
+            }
             time::sleep(self.loop_interval).await;
         }
 
@@ -168,6 +197,7 @@ pub async fn redis_error_receiver(rx: oneshot::Receiver<()>, shutdown_channel: m
 #[router_env::instrument(skip_all)]
 async fn drainer_handler(
     store: Arc<Store>,
+    layout: PartitionLayout,
     stream_index: u8,
     max_read_count: u64,
     active_tasks: Arc<atomic::AtomicU64>,
@@ -175,7 +205,7 @@ async fn drainer_handler(
 ) -> errors::DrainerResult<()> {
     active_tasks.fetch_add(1, atomic::Ordering::Release);
 
-    let stream_name = store.get_drainer_stream_name(stream_index);
+    let stream_name = store.get_drainer_stream_name(&layout, stream_index);
 
     let drainer_result = Box::pin(drainer(
         store.clone(),
@@ -189,7 +219,7 @@ async fn drainer_handler(
         logger::error!(?error)
     }
 
-    let flag_stream_name = store.get_stream_key_flag(stream_index);
+    let flag_stream_name = store.get_stream_key_flag(&layout, stream_index);
 
     let output = store.make_stream_available(flag_stream_name.as_str()).await;
     active_tasks.fetch_sub(1, atomic::Ordering::Release);
@@ -241,12 +271,20 @@ async fn drainer(
     );
 
     let session_id = common_utils::generate_id_with_default_len("drainer_session");
+    tracing::Span::current().record("session_id", &session_id);
 
-    let mut last_processed_id = String::new();
+    let mut operations = Vec::with_capacity(read_count);
+    let mut parsed_entries = Vec::with_capacity(read_count);
+    let mut last_parsed_id = None;
+    let mut quarantined_ids = Vec::new();
 
     for (entry_id, entry) in entries.clone() {
-        let data = match StreamData::from_hashmap(entry) {
-            Ok(data) => data,
+        match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => {
+                operations.push((data.typed_sql, data.pushed_at));
+                parsed_entries.push((entry_id.clone(), entry));
+                last_parsed_id = Some(entry_id);
+            }
             Err(err) => {
                 logger::error!(operation = "deserialization", err=?err);
                 metrics::STREAM_PARSE_FAIL.add(
@@ -258,31 +296,66 @@ async fn drainer(
                     }],
                 );
 
-                // break from the loop in case of a deser error
-                break;
-            }
-        };
-
-        tracing::Span::current().record("request_id", data.request_id);
-        tracing::Span::current().record("global_id", data.global_id);
-        tracing::Span::current().record("session_id", &session_id);
-
-        match data.typed_sql.execute_query(&store, data.pushed_at).await {
-            Ok(_) => {
-                last_processed_id = entry_id;
-            }
-            Err(err) => match err.current_context() {
-                // In case of Uniqueviolation we can't really do anything to fix it so just clear
-                // it from the stream
-                diesel_models::errors::DatabaseError::UniqueViolation => {
-                    last_processed_id = entry_id;
+                match store
+                    .quarantine_entry(
+                        stream_name,
+                        &entry_id,
+                        entry,
+                        QuarantineReason::Deserialization,
+                        format!("{err:?}"),
+                    )
+                    .await
+                {
+                    Ok(()) => {
+                        quarantined_ids.push(entry_id.clone());
+                        last_parsed_id = Some(entry_id);
+                    }
+                    Err(error) => {
+                        logger::error!(operation = "quarantine", ?error);
+                        // break from the loop in case the entry could not be quarantined, the
+                        // remaining entries are left in the stream
+                        break;
+                    }
                 }
-                // break from the loop in case of an error in query
-                _ => break,
-            },
+            }
         }
     }
 
+    let last_processed_id = match last_parsed_id {
+        Some(last_parsed_id) if operations.is_empty() => last_parsed_id,
+        Some(last_parsed_id) => match batch::execute_batch(&store, operations).await {
+            Ok(()) => last_parsed_id,
+            Err(err) => {
+                logger::error!(
+                    operation = "batch_execution",
+                    ?err,
+                    "Falling back to executing the entries one at a time"
+                );
+                metrics::BATCH_FALLBACK_TO_SEQUENTIAL.add(&metrics::CONTEXT, 1, &[]);
+
+                let last_entry_id = parsed_entries.last().map(|(entry_id, _)| entry_id.clone());
+                match drain_sequentially(
+                    &store,
+                    stream_name,
+                    parsed_entries,
+                    &session_id,
+                    &mut quarantined_ids,
+                )
+                .await
+                {
+                    // every entry was drained or quarantined, the quarantined entries following
+                    // the last parsed entry can be cleared as well
+                    Some(last_drained_id) if Some(&last_drained_id) == last_entry_id.as_ref() => {
+                        last_parsed_id
+                    }
+                    Some(last_drained_id) => last_drained_id,
+                    None => String::new(),
+                }
+            }
+        },
+        None => String::new(),
+    };
+
     if !last_processed_id.is_empty() {
         let entries_trimmed = store
             .trim_from_stream(stream_name, &last_processed_id)
@@ -299,5 +372,151 @@ async fn drainer(
         logger::error!(read_entries = %read_count,?entries,"No streams were processed in this session");
     }
 
+    // Quarantined entries after the last processed entry are left in the stream by the trim and
+    // would be quarantined again when the remaining entries are retried
+    let untrimmed_quarantined_ids =
+        get_untrimmed_quarantined_ids(&entries, &last_processed_id, quarantined_ids);
+    if !untrimmed_quarantined_ids.is_empty() {
+        store
+            .redis_conn
+            .stream_delete_entries(stream_name, untrimmed_quarantined_ids)
+            .await
+            .map_err(|error| logger::error!(operation = "delete_quarantined_entries", ?error))
+            .ok();
+    }
+
     Ok(())
 }
+
+/// Quarantined entries which are not removed from the stream by trimming it up to
+/// `last_processed_id`
+fn get_untrimmed_quarantined_ids(
+    entries: &StreamEntries,
+    last_processed_id: &str,
+    quarantined_ids: Vec<String>,
+) -> Vec<String> {
+    let trimmed_count = entries
+        .iter()
+        .position(|(entry_id, _)| entry_id == last_processed_id)
+        .map_or(0, |position| position + 1);
+    let trimmed_ids = entries
+        .iter()
+        .take(trimmed_count)
+        .map(|(entry_id, _)| entry_id.as_str())
+        .collect::<std::collections::HashSet<_>>();
+
+    quarantined_ids
+        .into_iter()
+        .filter(|entry_id| !trimmed_ids.contains(entry_id.as_str()))
+        .collect()
+}
+
+/// Executes the entries one by one, quarantining entries that can never succeed and stopping at
+/// the first entry that fails otherwise. Returns the id of the last drained or quarantined entry,
+/// the ids of the quarantined entries are added to `quarantined_ids`.
+async fn drain_sequentially(
+    store: &Arc<Store>,
+    stream_name: &str,
+    entries: StreamEntries,
+    session_id: &str,
+    quarantined_ids: &mut Vec<String>,
+) -> Option<String> {
+    let mut last_processed_id = None;
+
+    for (entry_id, entry) in entries {
+        let data = match StreamData::from_hashmap(entry.clone()) {
+            Ok(data) => data,
+            Err(err) => {
+                logger::error!(operation = "deserialization", err=?err);
+                // break from the loop in case of a deser error
+                break;
+            }
+        };
+
+        tracing::Span::current().record("request_id", data.request_id);
+        tracing::Span::current().record("global_id", data.global_id);
+        tracing::Span::current().record("session_id", session_id);
+
+        match data.typed_sql.execute_query(store, data.pushed_at).await {
+            Ok(_) => {
+                last_processed_id = Some(entry_id);
+            }
+            Err(err) => {
+                let reason = match err.current_context() {
+                    DatabaseError::UniqueViolation => QuarantineReason::UniqueViolation,
+                    DatabaseError::NotFound => QuarantineReason::NotFound,
+                    DatabaseError::QueryGenerationFailed => QuarantineReason::QueryGeneration,
+                    // break from the loop in case of an error in query which may succeed on a
+                    // later attempt
+                    DatabaseError::DatabaseConnectionError
+                    | DatabaseError::NoFieldsToUpdate
+                    | DatabaseError::Others => break,
+                };
+
+                // Retrying these entries would fail the same way, so they are moved to the
+                // quarantine stream for being inspected and retried manually
+                match store
+                    .quarantine_entry(stream_name, &entry_id, entry, reason, format!("{err:?}"))
+                    .await
+                {
+                    Ok(()) => {
+                        quarantined_ids.push(entry_id.clone());
+                        last_processed_id = Some(entry_id);
+                    }
+                    Err(error) => {
+                        logger::error!(operation = "quarantine", ?error);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    last_processed_id
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::get_untrimmed_quarantined_ids;
+
+    fn entries(entry_ids: &[&str]) -> super::StreamEntries {
+        entry_ids
+            .iter()
+            .map(|entry_id| (entry_id.to_string(), HashMap::new()))
+            .collect()
+    }
+
+    #[test]
+    fn test_quarantined_entries_after_failure_stopping_fallback_are_deleted() {
+        // 1-0 and 4-0 are poison entries, 2-0 is drained and 3-0 fails with a connection error
+        // which stops the sequential fallback
+        let entries = entries(&["1-0", "2-0", "3-0", "4-0"]);
+        let quarantined_ids = vec!["1-0".to_string(), "4-0".to_string()];
+
+        assert_eq!(
+            get_untrimmed_quarantined_ids(&entries, "2-0", quarantined_ids),
+            vec!["4-0".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_quarantined_entries_are_deleted_when_nothing_is_drained() {
+        let entries = entries(&["1-0", "2-0", "3-0"]);
+        let quarantined_ids = vec!["1-0".to_string(), "3-0".to_string()];
+
+        assert_eq!(
+            get_untrimmed_quarantined_ids(&entries, "", quarantined_ids),
+            vec!["1-0".to_string(), "3-0".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_trimmed_quarantined_entries_are_not_deleted() {
+        let entries = entries(&["1-0", "2-0", "3-0"]);
+        let quarantined_ids = vec!["1-0".to_string(), "3-0".to_string()];
+
+        assert!(get_untrimmed_quarantined_ids(&entries, "3-0", quarantined_ids).is_empty());
+    }
+}
@@ -1,9 +1,12 @@
+mod batch;
 mod connection;
 pub mod errors;
 mod handler;
 mod health_check;
 pub mod logger;
 pub(crate) mod metrics;
+mod partition;
+mod quarantine;
 mod query;
 pub mod services;
 pub mod settings;
@@ -66,7 +69,10 @@ pub async fn start_web_server(
 ) -> Result<Server, errors::DrainerError> {
     let server = conf.server.clone();
     let web_server = actix_web::HttpServer::new(move || {
-        actix_web::App::new().service(health_check::Health::server(conf.clone(), stores.clone()))
+        actix_web::App::new()
+            .service(health_check::Health::server(conf.clone(), stores.clone()))
+            .service(quarantine::Quarantine::server(conf.clone(), stores.clone()))
+            .service(partition::Partitions::server(conf.clone(), stores.clone()))
     })
     .bind((server.host.as_str(), server.port))?
     .run();
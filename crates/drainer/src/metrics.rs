@@ -1,6 +1,7 @@
 pub use router_env::opentelemetry::KeyValue;
 use router_env::{
-    counter_metric, global_meter, histogram_metric, histogram_metric_i64, metrics_context,
+    counter_metric, global_meter, histogram_metric, histogram_metric_i64, histogram_metric_u64,
+    metrics_context,
 };
 
 metrics_context!(CONTEXT);
@@ -16,9 +17,21 @@ counter_metric!(SUCCESSFUL_SHUTDOWN, DRAINER_METER);
 counter_metric!(STREAM_EMPTY, DRAINER_METER);
 counter_metric!(STREAM_PARSE_FAIL, DRAINER_METER);
 counter_metric!(DRAINER_HEALTH, DRAINER_METER);
+counter_metric!(COALESCED_UPDATES, DRAINER_METER);
+counter_metric!(BATCH_FALLBACK_TO_SEQUENTIAL, DRAINER_METER);
+counter_metric!(QUARANTINED_ENTRIES, DRAINER_METER);
+counter_metric!(QUARANTINE_FAILURES, DRAINER_METER);
+counter_metric!(QUARANTINE_ENTRIES_RETRIED, DRAINER_METER);
+counter_metric!(QUARANTINE_ENTRIES_DISCARDED, DRAINER_METER);
+counter_metric!(RESHARDS_STARTED, DRAINER_METER);
+counter_metric!(RESHARDS_COMPLETED, DRAINER_METER);
 
 histogram_metric!(QUERY_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_READ_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(REDIS_STREAM_TRIM_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric!(CLEANUP_TIME, DRAINER_METER); // Time in (ms) milliseconds
+histogram_metric!(BATCH_EXECUTION_TIME, DRAINER_METER); // Time in (ms) milliseconds
 histogram_metric_i64!(DRAINER_DELAY_SECONDS, DRAINER_METER); // Time in (s) seconds
+histogram_metric_i64!(DRAINER_LAG_SECONDS, DRAINER_METER); // Time in (s) seconds
+histogram_metric_u64!(BATCH_SIZE, DRAINER_METER); // Stream entries per batch
+histogram_metric_u64!(BATCH_STATEMENTS, DRAINER_METER); // Statements executed per batch
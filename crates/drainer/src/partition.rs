@@ -0,0 +1,337 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use common_utils::ext_traits::Encode;
+use diesel_models::kv::partition::{
+    PartitionLayout, PartitionMap, PARTITION_MAP_KEY, PARTITION_MAP_LOCK_KEY,
+    PARTITION_MAP_VERSION_KEY,
+};
+use error_stack::{report, ResultExt};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+
+use crate::{
+    errors::{self, PartitionMapError},
+    metrics,
+    services::{self, log_and_return_error_response, Store},
+    settings::DrainerSettings,
+    Settings,
+};
+
+/// Time after which the lock on the partition map is released, if the drainer holding it dies
+const PARTITION_MAP_LOCK_TTL: i64 = 10;
+
+impl Store {
+    /// Fetches the partition map of the drainer streams. The configured number of partitions
+    /// applies until the map is stored.
+    pub(crate) async fn get_partition_map(&self) -> errors::DrainerResult<PartitionMap> {
+        match self
+            .redis_conn
+            .get_and_deserialize_key::<PartitionMap>(PARTITION_MAP_KEY, "PartitionMap")
+            .await
+        {
+            Ok(partition_map) => Ok(partition_map),
+            Err(error)
+                if matches!(error.current_context(), redis::errors::RedisError::NotFound) =>
+            {
+                Ok(PartitionMap::new(self.config.drainer_num_partitions))
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Stores the partition map, followed by the version of its current layout. Routers read the
+    /// map again once they see the new version, by when the new map is already stored.
+    async fn set_partition_map(&self, partition_map: &PartitionMap) -> errors::DrainerResult<()> {
+        let serialized =
+            partition_map
+                .encode_to_vec()
+                .change_context(errors::DrainerError::UnexpectedError(
+                    "Failed to serialize the partition map".to_string(),
+                ))?;
+
+        self.redis_conn
+            .set_key_without_expiry(PARTITION_MAP_KEY, serialized.as_slice())
+            .await?;
+
+        Ok(self
+            .redis_conn
+            .set_key_without_expiry(
+                PARTITION_MAP_VERSION_KEY,
+                i64::from(partition_map.current.version),
+            )
+            .await?)
+    }
+
+    /// Runs `update` on the partition map while holding the partition map lock, so that
+    /// concurrently running drainers do not overwrite each other's changes. The update is skipped
+    /// if another drainer holds the lock.
+    async fn update_partition_map<F>(&self, update: F) -> errors::DrainerResult<()>
+    where
+        F: FnOnce(PartitionMap) -> Option<PartitionMap> + Send,
+    {
+        // The lock holds a value unique to this update, so that a lock which expired while the
+        // map was being updated and was taken by another drainer is not released
+        let lock_value = common_utils::generate_id_with_default_len("partition_map_lock");
+        let lock = self
+            .redis_conn
+            .set_key_if_not_exists_with_expiry(
+                PARTITION_MAP_LOCK_KEY,
+                lock_value.as_str(),
+                Some(PARTITION_MAP_LOCK_TTL),
+            )
+            .await?;
+        if lock != redis::SetnxReply::KeySet {
+            logger::debug!("Partition map is being updated by another drainer");
+            return Ok(());
+        }
+
+        let result = async {
+            let partition_map = self.get_partition_map().await?;
+            match update(partition_map) {
+                Some(updated_partition_map) => self.set_partition_map(&updated_partition_map).await,
+                None => Ok(()),
+            }
+        }
+        .await;
+
+        self.redis_conn
+            .delete_key_if_value_equals(PARTITION_MAP_LOCK_KEY, &lock_value)
+            .await
+            .map_err(|error| logger::error!(operation = "unlock_partition_map", ?error))
+            .ok();
+
+        result
+    }
+
+    /// Stores the partition map if it does not exist yet, and starts a reshard if the configured
+    /// number of partitions differs from that of the current layout. Resharding is only started
+    /// when the drainer starts, so that drainers still running with the previous configuration
+    /// during a deployment do not revert the reshard.
+    #[instrument(skip_all)]
+    pub(crate) async fn init_partition_map(&self) -> errors::DrainerResult<()> {
+        let num_partitions = self.config.drainer_num_partitions;
+
+        self.update_partition_map(|partition_map| {
+            if partition_map.current.num_partitions == num_partitions {
+                // Stores the initial map, if it was not stored already
+                return Some(partition_map);
+            }
+
+            let resharded = partition_map.reshard(
+                num_partitions,
+                common_utils::date_time::now_unix_timestamp(),
+            );
+            match resharded.as_ref() {
+                Some(resharded) => {
+                    logger::info!(
+                        previous_layout = ?partition_map.current,
+                        current_layout = ?resharded.current,
+                        "Started resharding the drainer streams"
+                    );
+                    metrics::RESHARDS_STARTED.add(&metrics::CONTEXT, 1, &[]);
+                }
+                None => logger::warn!(
+                    ?partition_map,
+                    num_partitions,
+                    "A reshard is already in progress, restart the drainer once it completes to \
+                     apply the configured number of partitions"
+                ),
+            }
+            resharded
+        })
+        .await
+    }
+
+    /// Retires the previous layout once all of its streams have been drained, after which the
+    /// streams of the current layout are drained.
+    #[instrument(skip_all)]
+    pub(crate) async fn complete_reshard_if_drained(
+        &self,
+        conf: &DrainerSettings,
+    ) -> errors::DrainerResult<()> {
+        let partition_map = self.get_partition_map().await?;
+        let Some(previous_layout) = partition_map.previous else {
+            return Ok(());
+        };
+
+        let now = common_utils::date_time::now_unix_timestamp();
+        let grace_period_in_secs = i64::from(conf.reshard_grace_period);
+        if !partition_map.is_grace_period_elapsed(now, grace_period_in_secs) {
+            return Ok(());
+        }
+
+        let pending_entries = self.get_pending_entries(&previous_layout).await?;
+        if pending_entries != 0 {
+            logger::debug!(
+                ?previous_layout,
+                pending_entries,
+                "Waiting for the previous layout to be drained"
+            );
+            return Ok(());
+        }
+
+        self.update_partition_map(|partition_map| {
+            // The map may have been updated by another drainer since it was read
+            let completed = partition_map.complete_reshard_if_drained(
+                &previous_layout,
+                pending_entries,
+                now,
+                grace_period_in_secs,
+            );
+            if completed.is_some() {
+                logger::info!(?previous_layout, "Completed resharding the drainer streams");
+                metrics::RESHARDS_COMPLETED.add(&metrics::CONTEXT, 1, &[]);
+            }
+            completed
+        })
+        .await
+    }
+
+    /// The number of entries yet to be drained from the streams of the layout
+    pub(crate) async fn get_pending_entries(
+        &self,
+        layout: &PartitionLayout,
+    ) -> errors::DrainerResult<usize> {
+        let mut pending_entries = 0;
+        for stream_index in 0..layout.num_partitions {
+            let stream_name = self.get_drainer_stream_name(layout, stream_index);
+            pending_entries += self
+                .redis_conn
+                .stream_get_length(stream_name.as_str())
+                .await?;
+        }
+        Ok(pending_entries)
+    }
+
+    /// The number of entries in the quarantine streams of the layout
+    pub(crate) async fn get_quarantined_entries(
+        &self,
+        layout: &PartitionLayout,
+    ) -> errors::DrainerResult<usize> {
+        let mut quarantined_entries = 0;
+        for stream_index in 0..layout.num_partitions {
+            let stream_name = self.get_drainer_stream_name(layout, stream_index);
+            quarantined_entries += self
+                .redis_conn
+                .stream_get_length(self.get_quarantine_stream_name(&stream_name).as_str())
+                .await?;
+        }
+        Ok(quarantined_entries)
+    }
+}
+
+pub struct Partitions;
+
+impl Partitions {
+    pub fn server(conf: Settings, stores: HashMap<String, Arc<Store>>) -> Scope {
+        web::scope("partitions")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("/{tenant}").route(web::get().to(retrieve_partition_map)))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReshardStatus {
+    /// Entries are pushed to and drained from the streams of the current layout
+    Completed,
+    /// The streams of the previous layout are being drained, entries pushed to the current
+    /// layout are drained afterwards
+    InProgress,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartitionLayoutStatus {
+    pub version: u32,
+    pub num_partitions: u8,
+    pub pending_entries: usize,
+    pub quarantined_entries: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartitionMapResponse {
+    pub status: ReshardStatus,
+    pub current: PartitionLayoutStatus,
+    pub previous: Option<PartitionLayoutStatus>,
+    pub resharded_at: Option<i64>,
+    pub retired: Vec<PartitionLayoutStatus>,
+}
+
+async fn get_layout_status(
+    store: &Store,
+    layout: &PartitionLayout,
+) -> errors::DrainerResult<PartitionLayoutStatus> {
+    Ok(PartitionLayoutStatus {
+        version: layout.version,
+        num_partitions: layout.num_partitions,
+        pending_entries: store.get_pending_entries(layout).await?,
+        quarantined_entries: store.get_quarantined_entries(layout).await?,
+    })
+}
+
+/// Reports the layouts of the drainer streams of the tenant and the progress of the reshard
+#[instrument(skip_all)]
+pub async fn retrieve_partition_map(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let tenant = path.into_inner();
+
+    let result = async {
+        if !services::is_admin_authenticated(&req, &conf) {
+            return Err(report!(PartitionMapError::Unauthorized))
+                .attach_printable("Admin Authentication Failure");
+        }
+        let store = stores.get(&tenant).ok_or_else(|| {
+            report!(PartitionMapError::TenantNotFound {
+                tenant: tenant.clone(),
+            })
+        })?;
+
+        let response = async {
+            let partition_map = store.get_partition_map().await?;
+
+            let mut retired = Vec::with_capacity(partition_map.retired.len());
+            for layout in &partition_map.retired {
+                retired.push(get_layout_status(store, layout).await?);
+            }
+            let previous = match partition_map.previous.as_ref() {
+                Some(layout) => Some(get_layout_status(store, layout).await?),
+                None => None,
+            };
+
+            Ok::<_, error_stack::Report<errors::DrainerError>>(PartitionMapResponse {
+                status: if partition_map.is_resharding() {
+                    ReshardStatus::InProgress
+                } else {
+                    ReshardStatus::Completed
+                },
+                current: get_layout_status(store, &partition_map.current).await?,
+                previous,
+                resharded_at: partition_map.resharded_at,
+                retired,
+            })
+        }
+        .await
+        .change_context(PartitionMapError::RedisError)?;
+
+        Ok::<_, error_stack::Report<PartitionMapError>>(response)
+    }
+    .await;
+
+    match result {
+        Ok(response) => match serde_json::to_string(&response) {
+            Ok(body) => services::http_response_json(body),
+            Err(err) => {
+                logger::error!(serialization_error=?err);
+                HttpResponse::InternalServerError().finish()
+            }
+        },
+        Err(err) => log_and_return_error_response(err),
+    }
+}
@@ -0,0 +1,414 @@
+use std::{collections::HashMap, sync::Arc};
+
+use actix_web::{web, HttpRequest, HttpResponse, Scope};
+use error_stack::{report, ResultExt};
+use redis_interface as redis;
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    errors::{self, QuarantineError},
+    kv, metrics,
+    query::ExecuteQuery,
+    services::{self, log_and_return_error_response, Store},
+    utils::deserialize_db_op,
+    Settings,
+};
+
+pub const QUARANTINE_ERROR_FIELD: &str = "quarantine_error";
+pub const QUARANTINE_REASON_FIELD: &str = "quarantine_reason";
+pub const QUARANTINE_SOURCE_ENTRY_ID_FIELD: &str = "quarantine_source_entry_id";
+pub const QUARANTINED_AT_FIELD: &str = "quarantined_at";
+
+const DEFAULT_LIST_LIMIT: u64 = 50;
+
+/// Reason for which a stream entry could not be drained and was moved to the quarantine stream
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum QuarantineReason {
+    Deserialization,
+    UniqueViolation,
+    NotFound,
+    QueryGeneration,
+}
+
+impl QuarantineReason {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Deserialization => "deserialization",
+            Self::UniqueViolation => "unique_violation",
+            Self::NotFound => "not_found",
+            Self::QueryGeneration => "query_generation",
+        }
+    }
+}
+
+impl Store {
+    #[inline(always)]
+    pub(crate) fn get_quarantine_stream_name(&self, stream_name: &str) -> String {
+        // Example: {shard_5}_drainer_stream_quarantine
+        format!("{stream_name}_quarantine")
+    }
+
+    /// Copies a stream entry that could not be drained to the quarantine stream of its shard,
+    /// along with the reason and the error, so that it can be inspected and retried later.
+    #[instrument(skip_all)]
+    pub(crate) async fn quarantine_entry(
+        &self,
+        stream_name: &str,
+        entry_id: &str,
+        entry: HashMap<String, String>,
+        reason: QuarantineReason,
+        error: String,
+    ) -> errors::DrainerResult<()> {
+        let quarantine_stream = self.get_quarantine_stream_name(stream_name);
+
+        let mut fields = entry.into_iter().collect::<Vec<_>>();
+        fields.extend([
+            (
+                QUARANTINE_REASON_FIELD.to_string(),
+                reason.as_str().to_string(),
+            ),
+            (QUARANTINE_ERROR_FIELD.to_string(), error),
+            (
+                QUARANTINE_SOURCE_ENTRY_ID_FIELD.to_string(),
+                entry_id.to_string(),
+            ),
+            (
+                QUARANTINED_AT_FIELD.to_string(),
+                common_utils::date_time::now_unix_timestamp().to_string(),
+            ),
+        ]);
+
+        let result = self
+            .redis_conn
+            .stream_append_entry(
+                quarantine_stream.as_str(),
+                &redis::RedisEntryId::AutoGeneratedID,
+                fields,
+            )
+            .await
+            .map_err(errors::DrainerError::from);
+
+        match result {
+            Ok(()) => {
+                logger::error!(
+                    operation = "quarantine",
+                    stream = stream_name,
+                    entry_id = entry_id,
+                    reason = reason.as_str(),
+                    "Stream entry could not be drained and was moved to the quarantine stream"
+                );
+                metrics::QUARANTINED_ENTRIES.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[
+                        metrics::KeyValue::new("stream", stream_name.to_owned()),
+                        metrics::KeyValue::new("reason", reason.as_str()),
+                    ],
+                );
+                Ok(())
+            }
+            Err(err) => {
+                metrics::QUARANTINE_FAILURES.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::KeyValue::new("stream", stream_name.to_owned())],
+                );
+                Err(err.into())
+            }
+        }
+    }
+}
+
+pub struct Quarantine;
+
+impl Quarantine {
+    pub fn server(conf: Settings, stores: HashMap<String, Arc<Store>>) -> Scope {
+        web::scope("quarantine")
+            .app_data(web::Data::new(conf))
+            .app_data(web::Data::new(stores))
+            .service(web::resource("/{tenant}/{shard}").route(web::get().to(list_entries)))
+            .service(
+                web::resource("/{tenant}/{shard}/{entry_id}")
+                    .route(web::get().to(retrieve_entry))
+                    .route(web::delete().to(discard_entry)),
+            )
+            .service(
+                web::resource("/{tenant}/{shard}/{entry_id}/retry")
+                    .route(web::post().to(retry_entry)),
+            )
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuarantineListConstraints {
+    pub limit: Option<u64>,
+    /// Version of the layout the shard belongs to, defaults to the current layout
+    pub version: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuarantineLayoutQuery {
+    /// Version of the layout the shard belongs to, defaults to the current layout
+    pub version: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuarantineRetryRequest {
+    /// Replacement for the `typed_sql` of the quarantined entry, used for editing the entry before
+    /// retrying it. The original operation is retried when not provided.
+    pub typed_sql: Option<kv::DBOperation>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuarantinedEntry {
+    pub entry_id: String,
+    pub fields: HashMap<String, String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct QuarantineActionResponse {
+    pub entry_id: String,
+    pub status: &'static str,
+}
+
+#[instrument(skip_all)]
+pub async fn list_entries(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8)>,
+    query: web::Query<QuarantineListConstraints>,
+) -> HttpResponse {
+    let (tenant, shard) = path.into_inner();
+    let constraints = query.into_inner();
+    let limit = constraints.limit.unwrap_or(DEFAULT_LIST_LIMIT);
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let (store, quarantine_stream) =
+            get_quarantine_stream(&stores, &tenant, shard, constraints.version).await?;
+
+        let entries = store
+            .redis_conn
+            .stream_read_range(quarantine_stream.as_str(), "-", "+", Some(limit))
+            .await
+            .change_context(QuarantineError::RedisError)?;
+
+        Ok::<_, error_stack::Report<QuarantineError>>(
+            entries
+                .into_iter()
+                .map(|(entry_id, fields)| QuarantinedEntry { entry_id, fields })
+                .collect::<Vec<_>>(),
+        )
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8, String)>,
+    query: web::Query<QuarantineLayoutQuery>,
+) -> HttpResponse {
+    let (tenant, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let (store, quarantine_stream) =
+            get_quarantine_stream(&stores, &tenant, shard, query.version).await?;
+
+        find_entry(store, &quarantine_stream, entry_id).await
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+#[instrument(skip_all)]
+pub async fn discard_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8, String)>,
+    query: web::Query<QuarantineLayoutQuery>,
+) -> HttpResponse {
+    let (tenant, shard, entry_id) = path.into_inner();
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let (store, quarantine_stream) =
+            get_quarantine_stream(&stores, &tenant, shard, query.version).await?;
+
+        let entry = find_entry(store, &quarantine_stream, entry_id).await?;
+        remove_entry(store, &quarantine_stream, &entry.entry_id).await?;
+
+        logger::info!(operation = "quarantine_discard", entry_id = %entry.entry_id, ?entry.fields);
+        metrics::QUARANTINE_ENTRIES_DISCARDED.add(&metrics::CONTEXT, 1, &[]);
+
+        Ok::<_, error_stack::Report<QuarantineError>>(QuarantineActionResponse {
+            entry_id: entry.entry_id,
+            status: "discarded",
+        })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+#[instrument(skip_all)]
+pub async fn retry_entry(
+    req: HttpRequest,
+    conf: web::Data<Settings>,
+    stores: web::Data<HashMap<String, Arc<Store>>>,
+    path: web::Path<(String, u8, String)>,
+    query: web::Query<QuarantineLayoutQuery>,
+    payload: Option<web::Json<QuarantineRetryRequest>>,
+) -> HttpResponse {
+    let (tenant, shard, entry_id) = path.into_inner();
+    let edited_operation = payload.and_then(|payload| payload.into_inner().typed_sql);
+
+    let result = async {
+        authenticate(&req, &conf)?;
+        let (store, quarantine_stream) =
+            get_quarantine_stream(&stores, &tenant, shard, query.version).await?;
+
+        let entry = find_entry(store, &quarantine_stream, entry_id).await?;
+
+        let operation = match edited_operation {
+            Some(operation) => operation,
+            None => parse_operation(&entry.fields)?,
+        };
+        let pushed_at = entry
+            .fields
+            .get("pushed_at")
+            .and_then(|pushed_at| pushed_at.parse().ok())
+            .unwrap_or_else(common_utils::date_time::now_unix_timestamp);
+
+        operation
+            .execute_query(store, pushed_at)
+            .await
+            .map_err(|err| {
+                report!(QuarantineError::RetryFailed {
+                    message: err.to_string(),
+                })
+            })?;
+
+        remove_entry(store, &quarantine_stream, &entry.entry_id).await?;
+        metrics::QUARANTINE_ENTRIES_RETRIED.add(&metrics::CONTEXT, 1, &[]);
+
+        Ok::<_, error_stack::Report<QuarantineError>>(QuarantineActionResponse {
+            entry_id: entry.entry_id,
+            status: "retried",
+        })
+    }
+    .await;
+
+    to_http_response(result)
+}
+
+fn authenticate(req: &HttpRequest, conf: &Settings) -> error_stack::Result<(), QuarantineError> {
+    if !services::is_admin_authenticated(req, conf) {
+        return Err(report!(QuarantineError::Unauthorized))
+            .attach_printable("Admin Authentication Failure");
+    }
+
+    Ok(())
+}
+
+/// Resolves the quarantine stream of the shard in the layout of the specified version, or in the
+/// current layout if no version is specified
+async fn get_quarantine_stream<'a>(
+    stores: &'a HashMap<String, Arc<Store>>,
+    tenant: &str,
+    shard: u8,
+    version: Option<u32>,
+) -> error_stack::Result<(&'a Arc<Store>, String), QuarantineError> {
+    let store = stores.get(tenant).ok_or_else(|| {
+        report!(QuarantineError::TenantNotFound {
+            tenant: tenant.to_owned(),
+        })
+    })?;
+
+    let partition_map = store
+        .get_partition_map()
+        .await
+        .change_context(QuarantineError::RedisError)?;
+    let layout = match version {
+        Some(version) => *partition_map
+            .find_layout(version)
+            .ok_or(report!(QuarantineError::LayoutNotFound { version }))?,
+        None => partition_map.current,
+    };
+
+    if shard >= layout.num_partitions {
+        return Err(report!(QuarantineError::ShardNotFound {
+            shard,
+            num_partitions: layout.num_partitions,
+        }));
+    }
+
+    let stream_name = store.get_drainer_stream_name(&layout, shard);
+    Ok((store, store.get_quarantine_stream_name(&stream_name)))
+}
+
+async fn find_entry(
+    store: &Store,
+    quarantine_stream: &str,
+    entry_id: String,
+) -> error_stack::Result<QuarantinedEntry, QuarantineError> {
+    store
+        .redis_conn
+        .stream_read_range(quarantine_stream, &entry_id, &entry_id, Some(1))
+        .await
+        .change_context(QuarantineError::RedisError)?
+        .pop()
+        .map(|(entry_id, fields)| QuarantinedEntry { entry_id, fields })
+        .ok_or(report!(QuarantineError::EntryNotFound { entry_id }))
+}
+
+async fn remove_entry(
+    store: &Store,
+    quarantine_stream: &str,
+    entry_id: &str,
+) -> error_stack::Result<(), QuarantineError> {
+    store
+        .redis_conn
+        .stream_delete_entries(quarantine_stream, entry_id)
+        .await
+        .change_context(QuarantineError::RedisError)?;
+    Ok(())
+}
+
+fn parse_operation(
+    fields: &HashMap<String, String>,
+) -> error_stack::Result<kv::DBOperation, QuarantineError> {
+    let typed_sql = fields.get("typed_sql").ok_or_else(|| {
+        report!(QuarantineError::InvalidEntry {
+            message: "`typed_sql` is missing, provide it in the request to retry".to_string(),
+        })
+    })?;
+
+    deserialize_db_op(serde_json::Value::String(typed_sql.to_owned())).map_err(|err| {
+        report!(QuarantineError::InvalidEntry {
+            message: err.to_string(),
+        })
+    })
+}
+
+fn to_http_response<T: Serialize>(result: error_stack::Result<T, QuarantineError>) -> HttpResponse {
+    match result {
+        Ok(response) => match serde_json::to_string(&response) {
+            Ok(body) => services::http_response_json(body),
+            Err(err) => {
+                logger::error!(serialization_error=?err);
+                HttpResponse::InternalServerError().finish()
+            }
+        },
+        Err(err) => log_and_return_error_response(err),
+    }
+}
@@ -58,7 +58,12 @@ impl ExecuteQuery for kv::DBOperation {
 }
 
 #[inline(always)]
-fn push_drainer_delay(pushed_at: i64, operation: &str, table: &str, tags: &[metrics::KeyValue]) {
+pub(crate) fn push_drainer_delay(
+    pushed_at: i64,
+    operation: &str,
+    table: &str,
+    tags: &[metrics::KeyValue],
+) {
     let drained_at = common_utils::date_time::now_unix_timestamp();
     let delay = drained_at - pushed_at;
 
@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use actix_web::{body, HttpResponse, ResponseError};
 use error_stack::Report;
+use masking::PeekInterface;
 use redis_interface::RedisConnectionPool;
 
 use crate::{
@@ -52,6 +53,19 @@ impl Store {
     }
 }
 
+/// Whether the request carries the admin API key
+pub fn is_admin_authenticated(req: &actix_web::HttpRequest, conf: &crate::Settings) -> bool {
+    req.headers()
+        .get("api-key")
+        .and_then(|api_key| api_key.to_str().ok())
+        .is_some_and(|api_key| {
+            common_utils::crypto::is_equal_in_constant_time(
+                api_key.as_bytes(),
+                conf.secrets.get_inner().admin_api_key.peek().as_bytes(),
+            )
+        })
+}
+
 pub fn log_and_return_error_response<T>(error: Report<T>) -> HttpResponse
 where
     T: error_stack::Context + ResponseError + Clone,
@@ -61,7 +75,7 @@ where
         "message": error.to_string()
     })
     .to_string();
-    HttpResponse::InternalServerError()
+    HttpResponse::build(error.current_context().status_code())
         .content_type(mime::APPLICATION_JSON)
         .body(body)
 }
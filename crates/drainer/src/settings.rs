@@ -68,6 +68,7 @@ impl AppState {
 pub struct Settings<S: SecretState> {
     pub server: Server,
     pub master_database: SecretStateContainer<Database, S>,
+    pub secrets: SecretStateContainer<Secrets, S>,
     pub redis: redis::RedisSettings,
     pub log: Log,
     pub drainer: DrainerSettings,
@@ -88,6 +89,13 @@ pub struct Database {
     pub connection_timeout: u64,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct Secrets {
+    /// API key required for accessing the drainer admin APIs, like the quarantine APIs
+    pub admin_api_key: Secret<String>,
+}
+
 impl DbConnectionParams for Database {
     fn get_username(&self) -> &str {
         &self.username
@@ -112,8 +120,9 @@ pub struct DrainerSettings {
     pub stream_name: String,
     pub num_partitions: u8,
     pub max_read_count: u64,
-    pub shutdown_interval: u32, // in milliseconds
-    pub loop_interval: u32,     // in milliseconds
+    pub shutdown_interval: u32,    // in milliseconds
+    pub loop_interval: u32,        // in milliseconds
+    pub reshard_grace_period: u32, // in seconds
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -184,8 +193,9 @@ impl Default for DrainerSettings {
             stream_name: "DRAINER_STREAM".into(),
             num_partitions: 64,
             max_read_count: 100,
-            shutdown_interval: 1000, // in milliseconds
-            loop_interval: 100,      // in milliseconds
+            shutdown_interval: 1000,  // in milliseconds
+            loop_interval: 100,       // in milliseconds
+            reshard_grace_period: 30, // in seconds
         }
     }
 }
@@ -230,13 +240,41 @@ impl Database {
     }
 }
 
+impl Secrets {
+    fn validate(&self) -> Result<(), errors::DrainerError> {
+        common_utils::fp_utils::when(self.admin_api_key.is_default_or_empty(), || {
+            Err(errors::DrainerError::ConfigParsingError(
+                "admin api key must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl DrainerSettings {
     fn validate(&self) -> Result<(), errors::DrainerError> {
-        common_utils::fp_utils::when(self.stream_name.is_default_or_empty(), || {
+        use common_utils::fp_utils::when;
+
+        when(self.stream_name.is_default_or_empty(), || {
             Err(errors::DrainerError::ConfigParsingError(
                 "drainer stream name must not be empty".into(),
             ))
-        })
+        })?;
+
+        // Routers cache the partition map, and may push entries to the previous layout of a
+        // reshard until their cached map expires
+        when(
+            u64::from(self.reshard_grace_period)
+                <= diesel_models::kv::partition::PARTITION_MAP_CACHE_TTL_IN_SECS,
+            || {
+                Err(errors::DrainerError::ConfigParsingError(
+                    format!(
+                        "drainer reshard grace period must be longer than {} seconds",
+                        diesel_models::kv::partition::PARTITION_MAP_CACHE_TTL_IN_SECS
+                    )
+                    .into(),
+                ))
+            },
+        )
     }
 }
 
@@ -285,6 +323,7 @@ impl Settings<SecuredSecret> {
     pub fn validate(&self) -> Result<(), errors::DrainerError> {
         self.server.validate()?;
         self.master_database.get_inner().validate()?;
+        self.secrets.get_inner().validate()?;
 
         // The logger may not yet be initialized when validating the application configuration
         #[allow(clippy::print_stderr)]
@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use diesel_models::kv::partition::PartitionLayout;
 use redis_interface as redis;
 use router_env::{logger, tracing};
 
@@ -16,18 +17,25 @@ impl Store {
     }
 
     #[inline(always)]
-    pub(crate) fn get_stream_key_flag(&self, stream_index: u8) -> String {
-        format!("{}_in_use", self.get_drainer_stream_name(stream_index))
+    pub(crate) fn get_stream_key_flag(&self, layout: &PartitionLayout, stream_index: u8) -> String {
+        format!(
+            "{}_in_use",
+            self.get_drainer_stream_name(layout, stream_index)
+        )
     }
 
     #[inline(always)]
-    pub(crate) fn get_drainer_stream_name(&self, stream_index: u8) -> String {
-        self.drainer_stream(format!("shard_{stream_index}").as_str())
+    pub(crate) fn get_drainer_stream_name(
+        &self,
+        layout: &PartitionLayout,
+        stream_index: u8,
+    ) -> String {
+        self.drainer_stream(layout.shard_key(u32::from(stream_index)).as_str())
     }
 
     #[router_env::instrument(skip_all)]
-    pub async fn is_stream_available(&self, stream_index: u8) -> bool {
-        let stream_key_flag = self.get_stream_key_flag(stream_index);
+    pub async fn is_stream_available(&self, layout: &PartitionLayout, stream_index: u8) -> bool {
+        let stream_key_flag = self.get_stream_key_flag(layout, stream_index);
 
         match self
             .redis_conn
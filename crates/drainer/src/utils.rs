@@ -61,7 +61,8 @@ pub async fn increment_stream_index(
     (index, jobs_picked): (u8, Arc<atomic::AtomicU8>),
     total_streams: u8,
 ) -> u8 {
-    if index == total_streams - 1 {
+    // The index may exceed the number of streams when the layout being drained has fewer streams
+    if index.saturating_add(1) >= total_streams {
         match jobs_picked.load(atomic::Ordering::SeqCst) {
             0 => metrics::CYCLES_COMPLETED_UNSUCCESSFULLY.add(&metrics::CONTEXT, 1, &[]),
             _ => metrics::CYCLES_COMPLETED_SUCCESSFULLY.add(&metrics::CONTEXT, 1, &[]),
@@ -28,6 +28,7 @@ pub struct PaymentInput {
     pub billing_country: Option<enums::Country>,
     pub business_label: Option<String>,
     pub setup_future_usage: Option<enums::SetupFutureUsage>,
+    pub card_fingerprint_customer_count: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -70,6 +70,13 @@ impl From<inputs::BackendInput> for Context {
                 EuclidKey::PaymentAmount.to_string(),
                 Some(ValueType::Number(input.payment.amount)),
             ),
+            (
+                EuclidKey::CardFingerprintCustomerCount.to_string(),
+                input
+                    .payment
+                    .card_fingerprint_customer_count
+                    .map(|count| ValueType::Number(common_utils::types::MinorUnit::new(count))),
+            ),
             (
                 EuclidKey::PaymentCurrency.to_string(),
                 Some(ValueType::EnumVariant(input.payment.currency.to_string())),
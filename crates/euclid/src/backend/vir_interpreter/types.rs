@@ -1,3 +1,4 @@
+use common_utils::types::MinorUnit;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
@@ -114,13 +115,22 @@ impl Context {
             enum_values.insert(EuclidValue::MandateAcceptanceType(mandate_acceptance_type));
         }
 
-        let numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
+        let mut numeric_values: FxHashMap<EuclidKey, EuclidValue> = FxHashMap::from_iter([(
             EuclidKey::PaymentAmount,
             EuclidValue::PaymentAmount(types::NumValue {
                 number: payment.amount,
                 refinement: None,
             }),
         )]);
+        if let Some(customer_count) = payment.card_fingerprint_customer_count {
+            numeric_values.insert(
+                EuclidKey::CardFingerprintCustomerCount,
+                EuclidValue::CardFingerprintCustomerCount(types::NumValue {
+                    number: MinorUnit::new(customer_count),
+                    refinement: None,
+                }),
+            );
+        }
 
         Self {
             atomic_values: enum_values,
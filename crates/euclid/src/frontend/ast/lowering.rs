@@ -68,8 +68,8 @@ macro_rules! lower_enum {
 /// payment_amount = 17052001
 /// ```notrust
 /// This is for the cases in which there are numerical values involved and they are lowered
-/// accordingly on basis of the supplied key, currently payment_amount is the only key having this
-/// use case
+/// accordingly on basis of the supplied key, currently payment_amount and
+/// card_fingerprint_customer_count are the keys having this use case
 
 macro_rules! lower_number {
     ($key:ident, $value:ident, $comp:ident) => {
@@ -297,6 +297,10 @@ fn lower_comparison_inner<O: EuclidDirFilter>(
 
         dir::DirKeyKind::PaymentAmount => lower_number!(PaymentAmount, value, comparison),
 
+        dir::DirKeyKind::CardFingerprintCustomerCount => {
+            lower_number!(CardFingerprintCustomerCount, value, comparison)
+        }
+
         dir::DirKeyKind::Connector => Err(AnalysisErrorType::InvalidKey(
             dir::DirKeyKind::Connector.to_string(),
         )),
@@ -205,6 +205,13 @@ pub enum DirKeyKind {
     )]
     #[serde(rename = "amount")]
     PaymentAmount,
+    #[strum(
+        serialize = "card_fingerprint_customer_count",
+        detailed_message = "Number of customers who have saved the card used for the payment",
+        props(Category = "Customer")
+    )]
+    #[serde(rename = "card_fingerprint_customer_count")]
+    CardFingerprintCustomerCount,
     #[strum(
         serialize = "currency",
         detailed_message = "Currency used for the payment",
@@ -315,6 +322,7 @@ impl DirKeyKind {
             Self::CryptoType => types::DataType::EnumVariant,
             Self::RewardType => types::DataType::EnumVariant,
             Self::PaymentAmount => types::DataType::Number,
+            Self::CardFingerprintCustomerCount => types::DataType::Number,
             Self::PaymentCurrency => types::DataType::EnumVariant,
             Self::AuthenticationType => types::DataType::EnumVariant,
             Self::CaptureMethod => types::DataType::EnumVariant,
@@ -401,6 +409,7 @@ impl DirKeyKind {
                     .collect(),
             ),
             Self::PaymentAmount => None,
+            Self::CardFingerprintCustomerCount => None,
             Self::PaymentCurrency => Some(
                 enums::PaymentCurrency::iter()
                     .map(DirValue::PaymentCurrency)
@@ -506,6 +515,8 @@ pub enum DirValue {
     GiftCardType(enums::GiftCardType),
     #[serde(rename = "amount")]
     PaymentAmount(types::NumValue),
+    #[serde(rename = "card_fingerprint_customer_count")]
+    CardFingerprintCustomerCount(types::NumValue),
     #[serde(rename = "currency")]
     PaymentCurrency(enums::PaymentCurrency),
     #[serde(rename = "authentication_type")]
@@ -550,6 +561,9 @@ impl DirValue {
             Self::AuthenticationType(_) => (DirKeyKind::AuthenticationType, None),
             Self::CaptureMethod(_) => (DirKeyKind::CaptureMethod, None),
             Self::PaymentAmount(_) => (DirKeyKind::PaymentAmount, None),
+            Self::CardFingerprintCustomerCount(_) => {
+                (DirKeyKind::CardFingerprintCustomerCount, None)
+            }
             Self::PaymentCurrency(_) => (DirKeyKind::PaymentCurrency, None),
             Self::Connector(_) => (DirKeyKind::Connector, None),
             Self::BankDebitType(_) => (DirKeyKind::BankDebitType, None),
@@ -582,6 +596,7 @@ impl DirValue {
             Self::CaptureMethod(_) => None,
             Self::GiftCardType(_) => None,
             Self::PaymentAmount(_) => None,
+            Self::CardFingerprintCustomerCount(_) => None,
             Self::PaymentCurrency(_) => None,
             Self::BusinessCountry(_) => None,
             Self::BillingCountry(_) => None,
@@ -611,7 +626,7 @@ impl DirValue {
 
     pub fn get_num_value(&self) -> Option<types::NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::CardFingerprintCustomerCount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -178,6 +178,7 @@ pub enum BankRedirectType {
 #[strum(serialize_all = "snake_case")]
 pub enum OpenBankingType {
     OpenBankingPIS,
+    PayByBank,
 }
 
 #[derive(
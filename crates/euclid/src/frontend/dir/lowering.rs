@@ -172,6 +172,7 @@ impl From<enums::OpenBankingType> for global_enums::PaymentMethodType {
     fn from(value: enums::OpenBankingType) -> Self {
         match value {
             enums::OpenBankingType::OpenBankingPIS => Self::OpenBankingPIS,
+            enums::OpenBankingType::PayByBank => Self::PayByBank,
         }
     }
 }
@@ -233,6 +234,9 @@ fn lower_value(dir_value: dir::DirValue) -> Result<EuclidValue, AnalysisErrorTyp
         dir::DirValue::AuthenticationType(at) => EuclidValue::AuthenticationType(at),
         dir::DirValue::CaptureMethod(cm) => EuclidValue::CaptureMethod(cm),
         dir::DirValue::PaymentAmount(pa) => EuclidValue::PaymentAmount(pa),
+        dir::DirValue::CardFingerprintCustomerCount(count) => {
+            EuclidValue::CardFingerprintCustomerCount(count)
+        }
         dir::DirValue::PaymentCurrency(pc) => EuclidValue::PaymentCurrency(pc),
         dir::DirValue::BusinessCountry(buc) => EuclidValue::BusinessCountry(buc),
         dir::DirValue::BillingCountry(bic) => EuclidValue::BillingCountry(bic),
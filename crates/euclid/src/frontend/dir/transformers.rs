@@ -188,6 +188,7 @@ impl IntoDirValue for (global_enums::PaymentMethodType, global_enums::PaymentMet
             global_enums::PaymentMethodType::OpenBankingPIS => {
                 Ok(dirval!(OpenBankingType = OpenBankingPIS))
             }
+            global_enums::PaymentMethodType::PayByBank => Ok(dirval!(OpenBankingType = PayByBank)),
         }
     }
 }
@@ -50,6 +50,8 @@ pub enum EuclidKey {
     CaptureMethod,
     #[strum(serialize = "amount")]
     PaymentAmount,
+    #[strum(serialize = "card_fingerprint_customer_count")]
+    CardFingerprintCustomerCount,
     #[strum(serialize = "currency")]
     PaymentCurrency,
     #[strum(serialize = "country", to_string = "business_country")]
@@ -72,6 +74,7 @@ impl EuclidDirFilter for DummyOutput {
         DirKeyKind::CardBin,
         DirKeyKind::PayLaterType,
         DirKeyKind::PaymentAmount,
+        DirKeyKind::CardFingerprintCustomerCount,
         DirKeyKind::MetaData,
         DirKeyKind::MandateAcceptanceType,
         DirKeyKind::MandateType,
@@ -130,6 +133,7 @@ impl EuclidKey {
             Self::AuthenticationType => DataType::EnumVariant,
             Self::CaptureMethod => DataType::EnumVariant,
             Self::PaymentAmount => DataType::Number,
+            Self::CardFingerprintCustomerCount => DataType::Number,
             Self::PaymentCurrency => DataType::EnumVariant,
             Self::BusinessCountry => DataType::EnumVariant,
             Self::BillingCountry => DataType::EnumVariant,
@@ -248,6 +252,7 @@ pub enum EuclidValue {
     MandateAcceptanceType(enums::MandateAcceptanceType),
     MandateType(enums::MandateType),
     PaymentAmount(NumValue),
+    CardFingerprintCustomerCount(NumValue),
     PaymentCurrency(enums::Currency),
     BusinessCountry(enums::Country),
     BillingCountry(enums::Country),
@@ -258,7 +263,7 @@ pub enum EuclidValue {
 impl EuclidValue {
     pub fn get_num_value(&self) -> Option<NumValue> {
         match self {
-            Self::PaymentAmount(val) => Some(val.clone()),
+            Self::PaymentAmount(val) | Self::CardFingerprintCustomerCount(val) => Some(val.clone()),
             _ => None,
         }
     }
@@ -276,6 +281,7 @@ impl EuclidValue {
             Self::AuthenticationType(_) => EuclidKey::AuthenticationType,
             Self::CaptureMethod(_) => EuclidKey::CaptureMethod,
             Self::PaymentAmount(_) => EuclidKey::PaymentAmount,
+            Self::CardFingerprintCustomerCount(_) => EuclidKey::CardFingerprintCustomerCount,
             Self::PaymentCurrency(_) => EuclidKey::PaymentCurrency,
             Self::BusinessCountry(_) => EuclidKey::BusinessCountry,
             Self::BillingCountry(_) => EuclidKey::BillingCountry,
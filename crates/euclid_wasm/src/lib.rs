@@ -264,6 +264,7 @@ pub fn get_variant_values(key: &str) -> Result<JsValue, JsValue> {
         dir::DirKeyKind::OpenBankingType => dir_enums::OpenBankingType::VARIANTS,
 
         dir::DirKeyKind::PaymentAmount
+        | dir::DirKeyKind::CardFingerprintCustomerCount
         | dir::DirKeyKind::Connector
         | dir::DirKeyKind::CardBin
         | dir::DirKeyKind::BusinessLabel
@@ -0,0 +1,147 @@
+//!
+//! Module for submitting saved cards to a card account updater service and retrieving the
+//! updates reported by the card networks.
+//!
+
+use std::{
+    fmt::{Display, Formatter},
+    sync::Arc,
+};
+
+use common_utils::errors::CustomResult;
+use masking::Secret;
+
+mod mock;
+
+/// Enum representing the account updater service the saved cards are submitted to.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(tag = "account_updater_backend")]
+#[serde(rename_all = "snake_case")]
+pub enum AccountUpdaterConfig {
+    /// File based account updater, intended for local development and tests.
+    Mock {
+        /// Configuration for the file based account updater.
+        mock: mock::MockAccountUpdaterConfig,
+    },
+}
+
+impl Default for AccountUpdaterConfig {
+    fn default() -> Self {
+        Self::Mock {
+            mock: mock::MockAccountUpdaterConfig::default(),
+        }
+    }
+}
+
+impl AccountUpdaterConfig {
+    /// Validates the account updater configuration.
+    pub fn validate(&self) -> Result<(), InvalidAccountUpdaterConfig> {
+        match self {
+            Self::Mock { mock } => mock.validate(),
+        }
+    }
+
+    /// Retrieves the account updater client based on the account updater configuration.
+    pub async fn get_account_updater_client(&self) -> Arc<dyn AccountUpdaterInterface> {
+        match self {
+            Self::Mock { mock } => Arc::new(mock::MockAccountUpdater::new(mock)),
+        }
+    }
+}
+
+/// A saved card submitted to the account updater service.
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterInquiry {
+    /// Reference used to correlate the result of the inquiry with the saved card.
+    pub reference_id: String,
+    /// The card number of the saved card.
+    pub card_number: Secret<String>,
+    /// The expiry month of the saved card.
+    pub card_exp_month: Secret<String>,
+    /// The expiry year of the saved card.
+    pub card_exp_year: Secret<String>,
+}
+
+/// The update reported for a saved card by the account updater service.
+#[derive(Debug, Clone)]
+pub struct AccountUpdaterResult {
+    /// Reference of the inquiry the result corresponds to.
+    pub reference_id: String,
+    /// The update to be applied to the saved card.
+    pub update: AccountUpdate,
+}
+
+/// The updates the card networks report for a card.
+#[derive(Debug, Clone)]
+pub enum AccountUpdate {
+    /// The card is still valid as saved.
+    NoChange,
+    /// The card was reissued with a new card number.
+    NewAccountNumber {
+        /// The new card number.
+        card_number: Secret<String>,
+        /// The expiry month of the reissued card.
+        card_exp_month: Secret<String>,
+        /// The expiry year of the reissued card.
+        card_exp_year: Secret<String>,
+    },
+    /// The card was reissued with a new expiry date.
+    NewExpiry {
+        /// The new expiry month.
+        card_exp_month: Secret<String>,
+        /// The new expiry year.
+        card_exp_year: Secret<String>,
+    },
+    /// The account of the card was closed.
+    AccountClosed,
+    /// The issuer asks the cardholder to be contacted for new card details.
+    ContactCardholder,
+}
+
+/// Trait for interacting with a card account updater service.
+#[async_trait::async_trait]
+pub trait AccountUpdaterInterface: dyn_clone::DynClone + Sync + Send {
+    /// Submits a batch of saved cards for which updates are to be retrieved.
+    async fn submit_inquiries(
+        &self,
+        batch_id: &str,
+        inquiries: Vec<AccountUpdaterInquiry>,
+    ) -> CustomResult<(), AccountUpdaterError>;
+
+    /// Retrieves the results of a submitted batch, returns `None` if the results are not
+    /// available yet.
+    async fn retrieve_results(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Option<Vec<AccountUpdaterResult>>, AccountUpdaterError>;
+}
+
+dyn_clone::clone_trait_object!(AccountUpdaterInterface);
+
+/// Error thrown when the account updater config is invalid
+#[derive(Debug, Clone)]
+pub struct InvalidAccountUpdaterConfig(&'static str);
+
+impl std::error::Error for InvalidAccountUpdaterConfig {}
+
+impl Display for InvalidAccountUpdaterConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "account_updater: {}", self.0)
+    }
+}
+
+/// Represents errors that can occur while interacting with the account updater service.
+#[derive(Debug, thiserror::Error, PartialEq)]
+pub enum AccountUpdaterError {
+    /// Indicates that the inquiries could not be submitted.
+    #[error("Failed to submit account updater inquiries")]
+    SubmissionFailed,
+
+    /// Indicates that the results could not be retrieved.
+    #[error("Failed to retrieve account updater results")]
+    RetrievalFailed,
+
+    /// Indicates that the results received could not be parsed.
+    #[error("Failed to parse account updater results")]
+    ResultsParsingFailed,
+}
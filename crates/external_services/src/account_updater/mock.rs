@@ -0,0 +1,281 @@
+//!
+//! Module for the file based account updater, which exchanges batches with the card networks
+//! through files in a local directory.
+//!
+
+use std::path::PathBuf;
+
+use common_utils::errors::CustomResult;
+use error_stack::ResultExt;
+use masking::{ExposeInterface, Secret};
+
+use crate::account_updater::{
+    AccountUpdate, AccountUpdaterError, AccountUpdaterInquiry, AccountUpdaterInterface,
+    AccountUpdaterResult, InvalidAccountUpdaterConfig,
+};
+
+const INQUIRIES_FILE_NAME: &str = "inquiries.json";
+const RESULTS_FILE_NAME: &str = "results.json";
+
+/// Configuration for the file based account updater.
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct MockAccountUpdaterConfig {
+    /// The directory under which a directory is created for every submitted batch. The inquiries
+    /// of the batch are written to `inquiries.json`, and the results are read from
+    /// `results.json` once it is placed alongside.
+    pub directory: String,
+}
+
+impl Default for MockAccountUpdaterConfig {
+    fn default() -> Self {
+        Self {
+            directory: String::from("account_updater"),
+        }
+    }
+}
+
+impl MockAccountUpdaterConfig {
+    /// Validates the file based account updater configuration.
+    pub(super) fn validate(&self) -> Result<(), InvalidAccountUpdaterConfig> {
+        use common_utils::fp_utils::when;
+
+        when(self.directory.trim().is_empty(), || {
+            Err(InvalidAccountUpdaterConfig(
+                "mock account updater directory must not be empty",
+            ))
+        })
+    }
+}
+
+/// An inquiry as written to the inquiries file of a batch.
+#[derive(Debug, serde::Serialize)]
+struct InquiryRecord {
+    reference_id: String,
+    card_number: String,
+    card_exp_month: String,
+    card_exp_year: String,
+}
+
+impl From<AccountUpdaterInquiry> for InquiryRecord {
+    fn from(inquiry: AccountUpdaterInquiry) -> Self {
+        Self {
+            reference_id: inquiry.reference_id,
+            card_number: inquiry.card_number.expose(),
+            card_exp_month: inquiry.card_exp_month.expose(),
+            card_exp_year: inquiry.card_exp_year.expose(),
+        }
+    }
+}
+
+/// A result as read from the results file of a batch.
+#[derive(Debug, serde::Deserialize)]
+struct ResultRecord {
+    reference_id: String,
+    update: UpdateType,
+    card_number: Option<Secret<String>>,
+    card_exp_month: Option<Secret<String>>,
+    card_exp_year: Option<Secret<String>>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum UpdateType {
+    NoChange,
+    NewAccountNumber,
+    NewExpiry,
+    AccountClosed,
+    ContactCardholder,
+}
+
+impl TryFrom<ResultRecord> for AccountUpdaterResult {
+    type Error = error_stack::Report<AccountUpdaterError>;
+
+    fn try_from(record: ResultRecord) -> Result<Self, Self::Error> {
+        let get_field = |field: Option<Secret<String>>, field_name: &'static str| {
+            field
+                .ok_or(AccountUpdaterError::ResultsParsingFailed)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Missing {field_name} in the result of {}",
+                        record.reference_id
+                    )
+                })
+        };
+
+        let update = match record.update {
+            UpdateType::NoChange => AccountUpdate::NoChange,
+            UpdateType::NewAccountNumber => AccountUpdate::NewAccountNumber {
+                card_number: get_field(record.card_number, "card_number")?,
+                card_exp_month: get_field(record.card_exp_month, "card_exp_month")?,
+                card_exp_year: get_field(record.card_exp_year, "card_exp_year")?,
+            },
+            UpdateType::NewExpiry => AccountUpdate::NewExpiry {
+                card_exp_month: get_field(record.card_exp_month, "card_exp_month")?,
+                card_exp_year: get_field(record.card_exp_year, "card_exp_year")?,
+            },
+            UpdateType::AccountClosed => AccountUpdate::AccountClosed,
+            UpdateType::ContactCardholder => AccountUpdate::ContactCardholder,
+        };
+
+        Ok(Self {
+            reference_id: record.reference_id,
+            update,
+        })
+    }
+}
+
+/// Represents the file based account updater.
+#[derive(Debug, Clone)]
+pub(super) struct MockAccountUpdater {
+    directory: PathBuf,
+}
+
+impl MockAccountUpdater {
+    /// Constructs the file based account updater from its configuration.
+    pub(super) fn new(config: &MockAccountUpdaterConfig) -> Self {
+        Self {
+            directory: PathBuf::from(&config.directory),
+        }
+    }
+
+    fn get_batch_directory(&self, batch_id: &str) -> PathBuf {
+        self.directory.join(batch_id)
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterInterface for MockAccountUpdater {
+    /// Writes the inquiries of the batch to the inquiries file of the batch.
+    async fn submit_inquiries(
+        &self,
+        batch_id: &str,
+        inquiries: Vec<AccountUpdaterInquiry>,
+    ) -> CustomResult<(), AccountUpdaterError> {
+        let batch_directory = self.get_batch_directory(batch_id);
+        std::fs::create_dir_all(&batch_directory)
+            .change_context(AccountUpdaterError::SubmissionFailed)
+            .attach_printable("Failed to create the batch directory")?;
+
+        let records = inquiries
+            .into_iter()
+            .map(InquiryRecord::from)
+            .collect::<Vec<_>>();
+        let contents = serde_json::to_vec_pretty(&records)
+            .change_context(AccountUpdaterError::SubmissionFailed)
+            .attach_printable("Failed to serialize the inquiries")?;
+
+        std::fs::write(batch_directory.join(INQUIRIES_FILE_NAME), contents)
+            .change_context(AccountUpdaterError::SubmissionFailed)
+            .attach_printable("Failed to write the inquiries file")
+    }
+
+    /// Reads the results of the batch from the results file of the batch, if it was placed.
+    async fn retrieve_results(
+        &self,
+        batch_id: &str,
+    ) -> CustomResult<Option<Vec<AccountUpdaterResult>>, AccountUpdaterError> {
+        let results_file = self.get_batch_directory(batch_id).join(RESULTS_FILE_NAME);
+        if !results_file.exists() {
+            return Ok(None);
+        }
+
+        let contents = std::fs::read(results_file)
+            .change_context(AccountUpdaterError::RetrievalFailed)
+            .attach_printable("Failed to read the results file")?;
+        let records: Vec<ResultRecord> = serde_json::from_slice(&contents)
+            .change_context(AccountUpdaterError::ResultsParsingFailed)?;
+
+        records
+            .into_iter()
+            .map(AccountUpdaterResult::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use masking::{PeekInterface, Secret};
+
+    use super::{MockAccountUpdater, MockAccountUpdaterConfig, INQUIRIES_FILE_NAME};
+    use crate::account_updater::{AccountUpdate, AccountUpdaterInquiry, AccountUpdaterInterface};
+
+    fn get_mock_account_updater(test_name: &str) -> (MockAccountUpdater, std::path::PathBuf) {
+        let directory = std::env::temp_dir().join(format!(
+            "account_updater_{test_name}_{}",
+            std::process::id()
+        ));
+        let account_updater = MockAccountUpdater::new(&MockAccountUpdaterConfig {
+            directory: directory.to_string_lossy().into_owned(),
+        });
+        (account_updater, directory)
+    }
+
+    #[tokio::test]
+    async fn test_submit_inquiries_and_retrieve_results() {
+        let (account_updater, directory) = get_mock_account_updater("roundtrip");
+        let inquiry = AccountUpdaterInquiry {
+            reference_id: "item_1".to_string(),
+            card_number: Secret::new("4242424242424242".to_string()),
+            card_exp_month: Secret::new("10".to_string()),
+            card_exp_year: Secret::new("2024".to_string()),
+        };
+
+        account_updater
+            .submit_inquiries("batch_1", vec![inquiry])
+            .await
+            .unwrap();
+        let inquiries: serde_json::Value = serde_json::from_slice(
+            &std::fs::read(directory.join("batch_1").join(INQUIRIES_FILE_NAME)).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(inquiries[0]["card_number"], "4242424242424242");
+        assert!(account_updater
+            .retrieve_results("batch_1")
+            .await
+            .unwrap()
+            .is_none());
+
+        std::fs::write(
+            directory.join("batch_1").join("results.json"),
+            r#"[
+                {"reference_id": "item_1", "update": "new_expiry", "card_exp_month": "10", "card_exp_year": "2028"},
+                {"reference_id": "item_2", "update": "account_closed"}
+            ]"#,
+        )
+        .unwrap();
+        let results = account_updater
+            .retrieve_results("batch_1")
+            .await
+            .unwrap()
+            .expect("results should be available");
+
+        assert_eq!(results.len(), 2);
+        assert!(matches!(
+            &results[0].update,
+            AccountUpdate::NewExpiry { card_exp_year, .. } if card_exp_year.peek() == "2028"
+        ));
+        assert!(matches!(results[1].update, AccountUpdate::AccountClosed));
+
+        std::fs::remove_dir_all(directory).ok();
+    }
+
+    #[tokio::test]
+    async fn test_result_without_new_card_details_is_rejected() {
+        let (account_updater, directory) = get_mock_account_updater("invalid_result");
+        std::fs::create_dir_all(directory.join("batch_1")).unwrap();
+        std::fs::write(
+            directory.join("batch_1").join("results.json"),
+            r#"[{"reference_id": "item_1", "update": "new_account_number"}]"#,
+        )
+        .unwrap();
+
+        let result = account_updater.retrieve_results("batch_1").await;
+
+        assert!(result.is_err());
+        std::fs::remove_dir_all(directory).ok();
+    }
+}
@@ -75,6 +75,7 @@ pub enum GenericLinksData {
     PayoutLinkStatus(GenericLinkStatusData),
     PaymentMethodCollectStatus(GenericLinkStatusData),
     SecurePaymentLink(PaymentLinkFormData),
+    PaymentMethodManage(GenericLinkStatusData),
 }
 
 impl Display for GenericLinksData {
@@ -89,6 +90,7 @@ impl Display for GenericLinksData {
                 Self::PayoutLinkStatus(_) => "PayoutLinkStatus",
                 Self::PaymentMethodCollectStatus(_) => "PaymentMethodCollectStatus",
                 Self::SecurePaymentLink(_) => "SecurePaymentLink",
+                Self::PaymentMethodManage(_) => "PaymentMethodManage",
             }
         )
     }
@@ -7,8 +7,8 @@ use common_utils::{
     types::keymanager,
 };
 use diesel_models::business_profile::{
-    AuthenticationConnectorDetails, BusinessPaymentLinkConfig, BusinessPayoutLinkConfig,
-    BusinessProfileUpdateInternal, WebhookDetails,
+    AuthenticationConnectorDetails, AutoCapturePolicy, BusinessPaymentLinkConfig,
+    BusinessPayoutLinkConfig, BusinessProfileUpdateInternal, RefundPolicy, WebhookDetails,
 };
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
@@ -54,6 +54,11 @@ pub struct BusinessProfile {
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: bool,
     pub version: common_enums::ApiVersion,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -90,6 +95,11 @@ pub struct BusinessProfileSetter {
     pub always_collect_shipping_details_from_wallet_connector: Option<bool>,
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: bool,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -133,6 +143,11 @@ impl From<BusinessProfileSetter> for BusinessProfile {
             tax_connector_id: value.tax_connector_id,
             is_tax_connector_enabled: value.is_tax_connector_enabled,
             version: consts::API_VERSION,
+            refund_policy: value.refund_policy,
+            auto_capture_policy: value.auto_capture_policy,
+            authorization_expiry_action: value.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: value.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: value.is_account_ownership_check_enabled,
         }
     }
 }
@@ -178,6 +193,11 @@ pub struct BusinessProfileGeneralUpdate {
     pub always_collect_shipping_details_from_wallet_connector: Option<bool>,
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: Option<bool>,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v1")]
@@ -230,6 +250,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     always_collect_shipping_details_from_wallet_connector,
                     tax_connector_id,
                     is_tax_connector_enabled,
+                    refund_policy,
+                    auto_capture_policy,
+                    authorization_expiry_action,
+                    is_pre_debit_balance_check_enabled,
+                    is_account_ownership_check_enabled,
                 } = *update;
 
                 Self {
@@ -263,6 +288,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     always_collect_shipping_details_from_wallet_connector,
                     tax_connector_id,
                     is_tax_connector_enabled,
+                    refund_policy,
+                    auto_capture_policy,
+                    authorization_expiry_action,
+                    is_pre_debit_balance_check_enabled,
+                    is_account_ownership_check_enabled,
                 }
             }
             BusinessProfileUpdate::RoutingAlgorithmUpdate {
@@ -298,6 +328,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 always_collect_shipping_details_from_wallet_connector: None,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -331,6 +366,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 always_collect_shipping_details_from_wallet_connector: None,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
             BusinessProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -364,6 +404,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 always_collect_shipping_details_from_wallet_connector: None,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
         }
     }
@@ -416,6 +461,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             tax_connector_id: self.tax_connector_id,
             is_tax_connector_enabled: Some(self.is_tax_connector_enabled),
             version: self.version,
+            refund_policy: self.refund_policy,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expiry_action: self.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
         })
     }
 
@@ -480,6 +530,11 @@ impl super::behaviour::Conversion for BusinessProfile {
                 tax_connector_id: item.tax_connector_id,
                 is_tax_connector_enabled: item.is_tax_connector_enabled.unwrap_or(false),
                 version: item.version,
+                refund_policy: item.refund_policy,
+                auto_capture_policy: item.auto_capture_policy,
+                authorization_expiry_action: item.authorization_expiry_action,
+                is_pre_debit_balance_check_enabled: item.is_pre_debit_balance_check_enabled,
+                is_account_ownership_check_enabled: item.is_account_ownership_check_enabled,
             })
         }
         .await
@@ -529,6 +584,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             tax_connector_id: self.tax_connector_id,
             is_tax_connector_enabled: Some(self.is_tax_connector_enabled),
             version: self.version,
+            refund_policy: self.refund_policy,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expiry_action: self.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
         })
     }
 }
@@ -571,6 +631,11 @@ pub struct BusinessProfile {
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: bool,
     pub version: common_enums::ApiVersion,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -609,6 +674,11 @@ pub struct BusinessProfileSetter {
     pub default_fallback_routing: Option<pii::SecretSerdeValue>,
     pub tax_connector_id: Option<String>,
     pub is_tax_connector_enabled: bool,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -654,6 +724,11 @@ impl From<BusinessProfileSetter> for BusinessProfile {
             tax_connector_id: value.tax_connector_id,
             is_tax_connector_enabled: value.is_tax_connector_enabled,
             version: consts::API_VERSION,
+            refund_policy: value.refund_policy,
+            auto_capture_policy: value.auto_capture_policy,
+            authorization_expiry_action: value.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: value.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: value.is_account_ownership_check_enabled,
         }
     }
 }
@@ -695,6 +770,11 @@ pub struct BusinessProfileGeneralUpdate {
     pub always_collect_shipping_details_from_wallet_connector: Option<bool>,
     pub order_fulfillment_time: Option<i64>,
     pub order_fulfillment_time_origin: Option<common_enums::OrderFulfillmentTimeOrigin>,
+    pub refund_policy: Option<RefundPolicy>,
+    pub auto_capture_policy: Option<AutoCapturePolicy>,
+    pub authorization_expiry_action: Option<common_enums::AuthorizationExpiryAction>,
+    pub is_pre_debit_balance_check_enabled: Option<bool>,
+    pub is_account_ownership_check_enabled: Option<bool>,
 }
 
 #[cfg(feature = "v2")]
@@ -746,6 +826,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     always_collect_shipping_details_from_wallet_connector,
                     order_fulfillment_time,
                     order_fulfillment_time_origin,
+                    refund_policy,
+                    auto_capture_policy,
+                    authorization_expiry_action,
+                    is_pre_debit_balance_check_enabled,
+                    is_account_ownership_check_enabled,
                 } = *update;
                 Self {
                     profile_name,
@@ -780,6 +865,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                     default_fallback_routing: None,
                     tax_connector_id: None,
                     is_tax_connector_enabled: None,
+                    refund_policy,
+                    auto_capture_policy,
+                    authorization_expiry_action,
+                    is_pre_debit_balance_check_enabled,
+                    is_account_ownership_check_enabled,
                 }
             }
             BusinessProfileUpdate::RoutingAlgorithmUpdate {
@@ -817,6 +907,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 default_fallback_routing: None,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
             BusinessProfileUpdate::ExtendedCardInfoUpdate {
                 is_extended_card_info_enabled,
@@ -852,6 +947,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 default_fallback_routing: None,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
             BusinessProfileUpdate::ConnectorAgnosticMitUpdate {
                 is_connector_agnostic_mit_enabled,
@@ -887,6 +987,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 default_fallback_routing: None,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
             BusinessProfileUpdate::DefaultRoutingFallbackUpdate {
                 default_fallback_routing,
@@ -922,6 +1027,11 @@ impl From<BusinessProfileUpdate> for BusinessProfileUpdateInternal {
                 default_fallback_routing,
                 tax_connector_id: None,
                 is_tax_connector_enabled: None,
+                refund_policy: None,
+                auto_capture_policy: None,
+                authorization_expiry_action: None,
+                is_pre_debit_balance_check_enabled: None,
+                is_account_ownership_check_enabled: None,
             },
         }
     }
@@ -976,6 +1086,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             tax_connector_id: self.tax_connector_id,
             is_tax_connector_enabled: Some(self.is_tax_connector_enabled),
             version: self.version,
+            refund_policy: self.refund_policy,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expiry_action: self.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
         })
     }
 
@@ -1042,6 +1157,11 @@ impl super::behaviour::Conversion for BusinessProfile {
                 tax_connector_id: item.tax_connector_id,
                 is_tax_connector_enabled: item.is_tax_connector_enabled.unwrap_or(false),
                 version: item.version,
+                refund_policy: item.refund_policy,
+                auto_capture_policy: item.auto_capture_policy,
+                authorization_expiry_action: item.authorization_expiry_action,
+                is_pre_debit_balance_check_enabled: item.is_pre_debit_balance_check_enabled,
+                is_account_ownership_check_enabled: item.is_account_ownership_check_enabled,
             })
         }
         .await
@@ -1093,6 +1213,11 @@ impl super::behaviour::Conversion for BusinessProfile {
             tax_connector_id: self.tax_connector_id,
             is_tax_connector_enabled: Some(self.is_tax_connector_enabled),
             version: self.version,
+            refund_policy: self.refund_policy,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expiry_action: self.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
         })
     }
 }
@@ -17,6 +17,8 @@ use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
 
 use crate::type_encryption as types;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::type_encryption::AsyncLift;
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 #[derive(Clone, Debug)]
@@ -36,6 +38,8 @@ pub struct Customer {
     pub default_payment_method_id: Option<String>,
     pub updated_by: Option<String>,
     pub version: common_enums::ApiVersion,
+    pub tax_ids: crypto::OptionalEncryptableValue,
+    pub segments: Option<Vec<String>>,
 }
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
@@ -83,6 +87,8 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: self.default_payment_method_id,
             updated_by: self.updated_by,
             version: self.version,
+            tax_ids: self.tax_ids.map(Encryption::from),
+            segments: self.segments,
         })
     }
 
@@ -117,6 +123,23 @@ impl super::behaviour::Conversion for Customer {
             .change_context(ValidationError::InvalidValue {
                 message: "Failed while decrypting customer data".to_string(),
             })?;
+        let tax_ids = item
+            .tax_ids
+            .async_lift(|inner| async {
+                types::crypto_operation(
+                    state,
+                    common_utils::type_name!(Self::DstType),
+                    types::CryptoOperation::DecryptOptional(inner),
+                    keymanager::Identifier::Merchant(item.merchant_id.clone()),
+                    key.peek(),
+                )
+                .await
+                .and_then(|val| val.try_into_optionaloperation())
+            })
+            .await
+            .change_context(ValidationError::InvalidValue {
+                message: "Failed while decrypting customer tax ids".to_string(),
+            })?;
 
         Ok(Self {
             customer_id: item.customer_id,
@@ -134,6 +157,8 @@ impl super::behaviour::Conversion for Customer {
             default_payment_method_id: item.default_payment_method_id,
             updated_by: item.updated_by,
             version: item.version,
+            tax_ids,
+            segments: item.segments,
         })
     }
 
@@ -154,6 +179,8 @@ impl super::behaviour::Conversion for Customer {
             address_id: self.address_id,
             updated_by: self.updated_by,
             version: self.version,
+            tax_ids: self.tax_ids.map(Encryption::from),
+            segments: self.segments,
         })
     }
 }
@@ -363,6 +390,8 @@ pub enum CustomerUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         connector_customer: Option<pii::SecretSerdeValue>,
         address_id: Option<String>,
+        tax_ids: crypto::OptionalEncryptableValue,
+        segments: Option<Vec<String>>,
     },
     ConnectorCustomer {
         connector_customer: Option<pii::SecretSerdeValue>,
@@ -385,6 +414,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 address_id,
+                tax_ids,
+                segments,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -397,6 +428,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 address_id,
                 default_payment_method_id: None,
                 updated_by: None,
+                tax_ids: tax_ids.map(Encryption::from),
+                segments,
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
                 connector_customer,
@@ -410,6 +443,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 default_payment_method_id: None,
                 updated_by: None,
                 address_id: None,
+                tax_ids: None,
+                segments: None,
             },
             CustomerUpdate::UpdateDefaultPaymentMethod {
                 default_payment_method_id,
@@ -425,6 +460,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer: None,
                 updated_by: None,
                 address_id: None,
+                tax_ids: None,
+                segments: None,
             },
         }
     }
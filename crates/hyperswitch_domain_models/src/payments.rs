@@ -68,4 +68,8 @@ pub struct PaymentIntent {
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub organization_id: id_type::OrganizationId,
+    pub auto_capture_policy: Option<diesel_models::business_profile::AutoCapturePolicy>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
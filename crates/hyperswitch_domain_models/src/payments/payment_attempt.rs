@@ -95,6 +95,14 @@ pub trait PaymentAttemptInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
 
+    async fn find_attempts_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError>;
+
     async fn get_filters_for_payments(
         &self,
         pi: &[PaymentIntent],
@@ -479,6 +487,11 @@ pub enum PaymentAttemptUpdate {
         unified_message: Option<String>,
         connector_transaction_id: Option<String>,
     },
+    CustomerDataRedactionUpdate {
+        payment_method_data: Option<serde_json::Value>,
+        browser_info: Option<serde_json::Value>,
+        updated_by: String,
+    },
 }
 
 impl ForeignIDRef for PaymentAttempt {
@@ -548,6 +561,10 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
             organization_id: self.organization_id,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expires_at: self.authorization_expires_at,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         })
     }
     async fn convert_back(
@@ -631,6 +648,10 @@ impl behaviour::Conversion for PaymentIntent {
                     .await?,
                 is_payment_processor_token_flow: storage_model.is_payment_processor_token_flow,
                 organization_id: storage_model.organization_id,
+                auto_capture_policy: storage_model.auto_capture_policy,
+                authorization_expires_at: storage_model.authorization_expires_at,
+                authorized_at: storage_model.authorized_at,
+                captured_at: storage_model.captured_at,
             })
         }
         .await
@@ -690,6 +711,10 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
             organization_id: self.organization_id,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expires_at: self.authorization_expires_at,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         })
     }
 }
@@ -751,6 +776,10 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
             organization_id: self.organization_id,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expires_at: self.authorization_expires_at,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         })
     }
 
@@ -835,6 +864,10 @@ impl behaviour::Conversion for PaymentIntent {
                     .await?,
                 is_payment_processor_token_flow: storage_model.is_payment_processor_token_flow,
                 organization_id: storage_model.organization_id,
+                auto_capture_policy: storage_model.auto_capture_policy,
+                authorization_expires_at: storage_model.authorization_expires_at,
+                authorized_at: storage_model.authorized_at,
+                captured_at: storage_model.captured_at,
             })
         }
         .await
@@ -894,6 +927,10 @@ impl behaviour::Conversion for PaymentIntent {
             shipping_details: self.shipping_details.map(Encryption::from),
             is_payment_processor_token_flow: self.is_payment_processor_token_flow,
             organization_id: self.organization_id,
+            auto_capture_policy: self.auto_capture_policy,
+            authorization_expires_at: self.authorization_expires_at,
+            authorized_at: self.authorized_at,
+            captured_at: self.captured_at,
         })
     }
 }
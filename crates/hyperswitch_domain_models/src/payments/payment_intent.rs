@@ -91,6 +91,19 @@ pub trait PaymentIntentInterface {
         constraints: &PaymentIntentFetchConstraints,
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, errors::StorageError>;
+
+    /// Fetches the capturable payments whose authorization lapses at or before
+    /// `expiring_before`, the earliest expiring first
+    #[cfg(feature = "olap")]
+    async fn find_payment_intents_with_expiring_authorization(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<&id_type::ProfileId>,
+        expiring_before: PrimitiveDateTime,
+        limit: u32,
+        merchant_key_store: &MerchantKeyStore,
+    ) -> error_stack::Result<Vec<PaymentIntent>, errors::StorageError>;
 }
 
 #[derive(Clone, Debug, PartialEq, router_derive::DebugAsDisplay, Serialize, Deserialize)]
@@ -152,6 +165,10 @@ pub struct PaymentIntentNew {
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
     pub organization_id: id_type::OrganizationId,
+    pub auto_capture_policy: Option<diesel_models::business_profile::AutoCapturePolicy>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -193,6 +210,8 @@ pub enum PaymentIntentUpdate {
         updated_by: String,
         fingerprint_id: Option<String>,
         incremental_authorization_allowed: Option<bool>,
+        authorized_at: Option<PrimitiveDateTime>,
+        captured_at: Option<PrimitiveDateTime>,
     },
     MetadataUpdate {
         metadata: serde_json::Value,
@@ -257,6 +276,17 @@ pub enum PaymentIntentUpdate {
         status: Option<storage_enums::IntentStatus>,
         updated_by: String,
     },
+    AuthorizationExpiryUpdate {
+        authorization_expires_at: PrimitiveDateTime,
+        updated_by: String,
+    },
+    CustomerDataRedactionUpdate {
+        description: Option<String>,
+        customer_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        billing_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default)]
@@ -299,6 +329,9 @@ pub struct PaymentIntentUpdateInternal {
     pub merchant_order_reference_id: Option<String>,
     pub shipping_details: Option<Encryptable<Secret<serde_json::Value>>>,
     pub is_payment_processor_token_flow: Option<bool>,
+    pub authorization_expires_at: Option<PrimitiveDateTime>,
+    pub authorized_at: Option<PrimitiveDateTime>,
+    pub captured_at: Option<PrimitiveDateTime>,
 }
 
 impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
@@ -396,6 +429,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 return_url,
                 updated_by,
                 incremental_authorization_allowed,
+                authorized_at,
+                captured_at,
             } => Self {
                 // amount,
                 // currency: Some(currency),
@@ -407,6 +442,8 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 modified_at: Some(common_utils::date_time::now()),
                 updated_by,
                 incremental_authorization_allowed,
+                authorized_at,
+                captured_at,
                 ..Default::default()
             },
             PaymentIntentUpdate::PaymentAttemptAndAttemptCountUpdate {
@@ -481,6 +518,30 @@ impl From<PaymentIntentUpdate> for PaymentIntentUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            PaymentIntentUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            } => Self {
+                authorization_expires_at: Some(authorization_expires_at),
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
+            PaymentIntentUpdate::CustomerDataRedactionUpdate {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                modified_at: Some(common_utils::date_time::now()),
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -500,6 +561,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 return_url,
                 updated_by,
                 incremental_authorization_allowed,
+                authorized_at,
+                captured_at,
             } => Self::ResponseUpdate {
                 status,
                 amount_captured,
@@ -507,6 +570,8 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
                 return_url,
                 updated_by,
                 incremental_authorization_allowed,
+                authorized_at,
+                captured_at,
             },
             PaymentIntentUpdate::MetadataUpdate {
                 metadata,
@@ -644,6 +709,26 @@ impl From<PaymentIntentUpdate> for DieselPaymentIntentUpdate {
             PaymentIntentUpdate::ManualUpdate { status, updated_by } => {
                 Self::ManualUpdate { status, updated_by }
             }
+            PaymentIntentUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            } => Self::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by,
+            },
+            PaymentIntentUpdate::CustomerDataRedactionUpdate {
+                description,
+                customer_details,
+                billing_details,
+                shipping_details,
+                updated_by,
+            } => Self::CustomerDataRedactionUpdate {
+                description,
+                customer_details: customer_details.map(Encryption::from),
+                billing_details: billing_details.map(Encryption::from),
+                shipping_details: shipping_details.map(Encryption::from),
+                updated_by,
+            },
         }
     }
 }
@@ -688,6 +773,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             merchant_order_reference_id,
             shipping_details,
             is_payment_processor_token_flow,
+            authorization_expires_at,
+            authorized_at,
+            captured_at,
         } = value;
 
         Self {
@@ -726,6 +814,9 @@ impl From<PaymentIntentUpdateInternal> for diesel_models::PaymentIntentUpdateInt
             merchant_order_reference_id,
             shipping_details: shipping_details.map(Encryption::from),
             is_payment_processor_token_flow,
+            authorization_expires_at,
+            authorized_at,
+            captured_at,
         }
     }
 }
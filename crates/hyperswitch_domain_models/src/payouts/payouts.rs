@@ -1,5 +1,8 @@
 use common_enums as storage_enums;
-use common_utils::{id_type, pii, types::MinorUnit};
+use common_utils::{
+    id_type, pii,
+    types::{MinorUnit, PayoutRecurrence},
+};
 use serde::{Deserialize, Serialize};
 use storage_enums::MerchantStorageScheme;
 use time::PrimitiveDateTime;
@@ -112,6 +115,8 @@ pub struct Payouts {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub recurrence: Option<PayoutRecurrence>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -140,6 +145,8 @@ pub struct PayoutsNew {
     pub payout_link_id: Option<String>,
     pub client_secret: Option<String>,
     pub priority: Option<storage_enums::PayoutSendPriority>,
+    pub scheduled_at: Option<PrimitiveDateTime>,
+    pub recurrence: Option<PayoutRecurrence>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -126,6 +126,7 @@ pub struct PaymentsIncrementalAuthorizationData {
     pub currency: storage_enums::Currency,
     pub reason: Option<String>,
     pub connector_transaction_id: String,
+    pub is_reauthorization: bool,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -15,6 +15,8 @@ pub struct FraudCheckSaleData {
     pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
     pub currency: Option<common_enums::Currency>,
     pub email: Option<Email>,
+    /// Number of customers of the merchant who have saved the card used for the payment
+    pub card_fingerprint_customer_count: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -26,6 +28,8 @@ pub struct FraudCheckCheckoutData {
     pub payment_method_data: Option<api_models::payments::AdditionalPaymentData>,
     pub email: Option<Email>,
     pub gateway: Option<String>,
+    /// Number of customers of the merchant who have saved the card used for the payment
+    pub card_fingerprint_customer_count: Option<i64>,
 }
 
 #[derive(Debug, Clone)]
@@ -78,6 +78,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
         routes::payments::payments_list,
+        routes::payments::payments_expiring_authorizations_list,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
@@ -87,6 +88,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::refunds::refunds_create,
         routes::refunds::refunds_retrieve,
         routes::refunds::refunds_update,
+        routes::refunds::refunds_approve,
+        routes::refunds::refunds_reject,
+        routes::refunds::refunds_payout,
         routes::refunds::refunds_list,
 
         // Routes for Organization
@@ -125,6 +129,11 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customers_address_create,
+        routes::customers::customers_address_list,
+        routes::customers::customers_address_retrieve,
+        routes::customers::customers_address_update,
+        routes::customers::customers_address_delete,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -146,6 +155,7 @@ Never share your secret api keys. Keep them guarded and secure.
         // Routes for disputes
         routes::disputes::retrieve_dispute,
         routes::disputes::retrieve_disputes_list,
+        routes::disputes::ingest_pre_dispute_alert,
 
         // Routes for routing
         routes::routing::routing_create_config,
@@ -175,6 +185,9 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payouts::payouts_confirm,
         routes::payouts::payouts_list_filters,
         routes::payouts::payouts_list_by_filter,
+        routes::payouts::payouts_batch_create,
+        routes::payouts::payouts_batch_retrieve,
+        routes::payouts::payouts_batch_report,
 
         // Routes for api keys
         routes::api_keys::api_key_create,
@@ -199,6 +212,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::RefundApprovalRequest,
+        api_models::refunds::RefundPayoutDetails,
+        api_models::refunds::RefundPayoutTrigger,
+        api_models::refunds::RefundPayoutMethod,
+        api_models::refunds::RefundPayoutRequest,
         api_models::organization::OrganizationRequest,
         api_models::organization::OrganizationResponse,
         api_models::admin::MerchantAccountCreate,
@@ -208,12 +226,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::RefundPolicy,
+        api_models::admin::AutoCapturePolicy,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
         api_models::admin::BusinessCollectLinkConfig,
         api_models::admin::BusinessPayoutLinkConfig,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerTaxId,
+        api_models::customers::CustomerAddressRequest,
+        api_models::customers::CustomerAddressResponse,
+        api_models::customers::CustomerAddressListResponse,
+        api_models::customers::CustomerAddressDeleteResponse,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::PaymentMethodList,
@@ -254,6 +279,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::DisputeStage,
         api_models::enums::DisputeStatus,
         api_models::enums::CountryAlpha2,
+        api_models::enums::CustomerAddressType,
+        api_models::enums::CustomerTaxIdType,
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
         api_models::enums::FrmPreferredFlowTypes,
@@ -264,6 +291,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
         api_models::enums::PaymentMethodStatus,
+        api_models::enums::PaymentMethodHealthStatus,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::AdditionalMerchantData,
         api_models::admin::MerchantRecipientData,
@@ -284,6 +312,11 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::PaymentLinkConfig,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::PreDisputeAlertRequest,
+        api_models::disputes::PreDisputeAlertResponse,
+        api_models::disputes::PreDisputeAlertProvider,
+        api_models::disputes::PreDisputeAlertOutcome,
+        api_models::disputes::PreDisputeAlertRule,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -405,6 +438,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsCancelRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
+        api_models::payments::ExpiringAuthorizationResponse,
+        api_models::payments::ExpiringAuthorizationsListResponse,
         api_models::payments::CashappQr,
         api_models::payments::BankTransferData,
         api_models::payments::BankTransferNextStepsData,
@@ -495,9 +530,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchFileFormat,
+        api_models::enums::PayoutRecurrenceInterval,
+        api_models::enums::AuthorizationExpiryAction,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -567,6 +608,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentChargeRequest,
         api_models::payments::PaymentChargeResponse,
         api_models::refunds::ChargeRefunds,
+        api_models::payouts::PayoutRecurrence,
         api_models::payments::CustomerDetailsResponse,
         api_models::payments::OpenBankingData,
         api_models::payments::OpenBankingSessionToken,
@@ -116,6 +116,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
+        api_models::refunds::RefundPayoutDetails,
+        api_models::refunds::RefundPayoutTrigger,
+        api_models::refunds::RefundPayoutMethod,
         api_models::organization::OrganizationRequest,
         api_models::organization::OrganizationResponse,
         api_models::admin::MerchantAccountCreate,
@@ -125,6 +128,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorResponse,
         api_models::admin::MerchantConnectorListResponse,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::RefundPolicy,
+        api_models::admin::AutoCapturePolicy,
         api_models::admin::ExtendedCardInfoConfig,
         api_models::admin::BusinessGenericLinkConfig,
         api_models::admin::BusinessCollectLinkConfig,
@@ -181,6 +186,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
         api_models::enums::PaymentMethodStatus,
+        api_models::enums::PaymentMethodHealthStatus,
         api_models::enums::OrderFulfillmentTimeOrigin,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::AdditionalMerchantData,
@@ -410,9 +416,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payouts::PayoutLinkResponse,
         api_models::payouts::Bank,
         api_models::payouts::PayoutCreatePayoutLinkConfig,
+        api_models::payouts::PayoutBatchCreateRequest,
+        api_models::payouts::PayoutBatchResponse,
         api_models::enums::PayoutEntityType,
         api_models::enums::PayoutSendPriority,
         api_models::enums::PayoutStatus,
+        api_models::enums::PayoutBatchStatus,
+        api_models::enums::PayoutBatchFileFormat,
+        api_models::enums::PayoutRecurrenceInterval,
+        api_models::enums::AuthorizationExpiryAction,
         api_models::enums::PayoutType,
         api_models::enums::TransactionType,
         api_models::payments::FrmMessage,
@@ -483,6 +495,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentChargeRequest,
         api_models::payments::PaymentChargeResponse,
         api_models::refunds::ChargeRefunds,
+        api_models::payouts::PayoutRecurrence,
         api_models::payments::CustomerDetailsResponse,
         api_models::payments::OpenBankingData,
         api_models::payments::OpenBankingSessionToken,
@@ -100,3 +100,113 @@ pub async fn customers_delete() {}
     security(("api_key" = []))
 )]
 pub async fn customers_list() {}
+
+/// Customers - Create Address
+///
+/// Saves a labeled billing or shipping address against a customer.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/addresses",
+    request_body (
+        content = CustomerAddressRequest,
+        examples (( "Save the head office of a customer as its billing address" = (
+        value = json!( {
+            "address_type": "billing",
+            "label": "Head office",
+            "address": {
+                "line1": "1467 Harrison Street",
+                "city": "San Francisco",
+                "zip": "94122",
+                "country": "US"
+            }
+        })
+        )))
+    ),
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Address Created", body = CustomerAddressResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Create an Address for a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_address_create() {}
+
+/// Customers - List Addresses
+///
+/// Lists the labeled addresses saved against a customer.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/addresses",
+    params (("customer_id" = String, Path, description = "The unique identifier for the Customer")),
+    responses(
+        (status = 200, description = "Addresses Retrieved", body = CustomerAddressListResponse),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "List all Addresses for a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_address_list() {}
+
+/// Customers - Retrieve Address
+///
+/// Retrieves a labeled address of a customer.
+#[utoipa::path(
+    get,
+    path = "/customers/{customer_id}/addresses/{address_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("address_id" = String, Path, description = "The unique identifier for the Address"),
+    ),
+    responses(
+        (status = 200, description = "Address Retrieved", body = CustomerAddressResponse),
+        (status = 404, description = "Address was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve an Address of a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_address_retrieve() {}
+
+/// Customers - Update Address
+///
+/// Replaces a labeled address of a customer.
+#[utoipa::path(
+    post,
+    path = "/customers/{customer_id}/addresses/{address_id}",
+    request_body = CustomerAddressRequest,
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("address_id" = String, Path, description = "The unique identifier for the Address"),
+    ),
+    responses(
+        (status = 200, description = "Address was Updated", body = CustomerAddressResponse),
+        (status = 404, description = "Address was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Update an Address of a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_address_update() {}
+
+/// Customers - Delete Address
+///
+/// Deletes a labeled address of a customer.
+#[utoipa::path(
+    delete,
+    path = "/customers/{customer_id}/addresses/{address_id}",
+    params (
+        ("customer_id" = String, Path, description = "The unique identifier for the Customer"),
+        ("address_id" = String, Path, description = "The unique identifier for the Address"),
+    ),
+    responses(
+        (status = 200, description = "Address was Deleted", body = CustomerAddressDeleteResponse),
+        (status = 404, description = "Address was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Delete an Address of a Customer",
+    security(("api_key" = []))
+)]
+pub async fn customers_address_delete() {}
@@ -69,3 +69,19 @@ pub async fn retrieve_disputes_list() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_disputes_list_profile() {}
+
+/// Disputes - Ingest Pre-Dispute Alert
+/// Records a chargeback prevention alert against a payment and resolves it as per the merchant's pre-dispute alert rule
+#[utoipa::path(
+    post,
+    path = "/disputes/alerts",
+    request_body=PreDisputeAlertRequest,
+    responses(
+        (status = 200, description = "The pre-dispute alert was recorded successfully", body = PreDisputeAlertResponse),
+        (status = 404, description = "Payment referenced by the alert does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Ingest a Pre-Dispute Alert",
+    security(("api_key" = []))
+)]
+pub async fn ingest_pre_dispute_alert() {}
@@ -459,6 +459,26 @@ pub fn payments_cancel() {}
 )]
 pub fn payments_list() {}
 
+/// Payments - List Expiring Authorizations
+///
+/// To list the payments authorized with manual capture whose authorization is about to lapse
+#[utoipa::path(
+    get,
+    path = "/payments/authorizations/expiring",
+    params(
+        ("expiring_within_hours" = Option<u32>, Query, description = "List payments whose authorization lapses within the specified number of hours, defaults to 24 hours"),
+        ("profile_id" = Option<String>, Query, description = "List payments created under the specified profile"),
+        ("limit" = Option<u32>, Query, description = "Include at most the specified number of payments, defaults to 100")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the payments with expiring authorizations", body = ExpiringAuthorizationsListResponse),
+    ),
+    tag = "Payments",
+    operation_id = "List Payments with Expiring Authorizations",
+    security(("api_key" = []))
+)]
+pub fn payments_expiring_authorizations_list() {}
+
 /// Business Profile level Payments - List
 ///
 /// To list the payments
@@ -190,3 +190,56 @@ pub async fn payouts_list_by_filter_profile() {}
     security(("api_key" = []))
 )]
 pub async fn payouts_confirm() {}
+
+/// Payouts - Batch Create
+///
+/// Creates payouts in bulk from a CSV or JSON file uploaded with the purpose `payout_batch`. The rows of the file are validated and processed asynchronously.
+#[utoipa::path(
+    post,
+    path = "/payouts/batch",
+    request_body=PayoutBatchCreateRequest,
+    responses(
+        (status = 200, description = "Payout batch created", body = PayoutBatchResponse),
+        (status = 400, description = "Invalid payout batch file")
+    ),
+    tag = "Payouts",
+    operation_id = "Create a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_create() {}
+
+/// Payouts - Batch Retrieve
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch retrieved", body = PayoutBatchResponse),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_retrieve() {}
+
+/// Payouts - Batch Report
+///
+/// Downloads a CSV report containing the payout created for every row of the batch file, along with its status or the reason the row failed.
+#[utoipa::path(
+    get,
+    path = "/payouts/batch/{batch_id}/report",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for payout batch")
+    ),
+    responses(
+        (status = 200, description = "Payout batch report retrieved", body = String, content_type = "text/csv"),
+        (status = 404, description = "Payout batch does not exist in our records")
+    ),
+    tag = "Payouts",
+    operation_id = "Retrieve a Payout Batch Report",
+    security(("api_key" = []))
+)]
+pub async fn payouts_batch_report() {}
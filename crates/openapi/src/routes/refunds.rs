@@ -112,6 +112,102 @@ pub async fn refunds_retrieve_with_body() {}
 )]
 pub async fn refunds_update() {}
 
+/// Refunds - Approve
+///
+/// Approves a refund that is pending approval as per the refund policy of the business profile. The refund is then processed with the connector
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/approve",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body(
+        content = RefundApprovalRequest,
+        examples(
+            (
+                "Approve a refund" = (
+                    value = json!({
+                        "reason": "Refund amount verified against the returned order"
+                      })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Refund approved", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval or was created by the approving user")
+    ),
+    tag = "Refunds",
+    operation_id = "Approve a Refund",
+    security(("jwt_key" = []))
+)]
+pub async fn refunds_approve() {}
+
+/// Refunds - Reject
+///
+/// Rejects a refund that is pending approval as per the refund policy of the business profile. The refund is marked as failed
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/reject",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body(
+        content = RefundApprovalRequest,
+        examples(
+            (
+                "Reject a refund" = (
+                    value = json!({
+                        "reason": "Product was not returned"
+                      })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Refund rejected", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval")
+    ),
+    tag = "Refunds",
+    operation_id = "Reject a Refund",
+    security(("jwt_key" = []))
+)]
+pub async fn refunds_reject() {}
+
+/// Refunds - Payout
+///
+/// Sends the amount of a failed refund to the customer as a payout, using the payout method details provided by the customer or a payout method saved for the customer. The payout is linked to the refund and the refund status follows the payout status
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/payout",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body(
+        content = RefundPayoutRequest,
+        examples(
+            (
+                "Send a refund to a saved payout method" = (
+                    value = json!({
+                        "payout_method": {
+                            "payout_token": "187282ab-40ef-47a9-9206-5099ba31e432",
+                            "payout_type": "bank"
+                        }
+                      })
+                )
+            ),
+        )
+    ),
+    responses(
+        (status = 200, description = "Refund sent as a payout", body = RefundResponse),
+        (status = 400, description = "Refund cannot be sent as a payout")
+    ),
+    tag = "Refunds",
+    operation_id = "Send a Refund as a Payout",
+    security(("api_key" = []))
+)]
+pub async fn refunds_payout() {}
+
 /// Refunds - List
 ///
 /// Lists all the refunds associated with the merchant or a payment_id if payment_id is not provided
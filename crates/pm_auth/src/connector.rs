@@ -1,3 +1,8 @@
+#[cfg(feature = "dummy_connector")]
+pub mod dummy_bank;
 pub mod plaid;
+pub mod truelayer;
 
-pub use self::plaid::Plaid;
+#[cfg(feature = "dummy_connector")]
+pub use self::dummy_bank::DummyBank;
+pub use self::{plaid::Plaid, truelayer::Truelayer};
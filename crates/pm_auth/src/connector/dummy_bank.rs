@@ -0,0 +1,600 @@
+use common_enums::{Currency, PaymentMethod, PaymentMethodType};
+use common_utils::types::{AmountConvertor, FloatMajorUnitForConnector, MinorUnit};
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+
+use crate::{
+    core::errors,
+    types::{
+        self as auth_types,
+        api::{
+            auth_service::{
+                self, AccountOwnership, BankAccountBalance, BankAccountCredentials, ExchangeToken,
+                LinkToken, PaymentInitiationCreate, PaymentInitiationLookup,
+                PaymentInitiationReversal, PaymentInitiationReversalSync, PaymentInitiationSync,
+                RecipientCreate,
+            },
+            ConnectorCommon, ConnectorIntegration,
+        },
+    },
+};
+
+/// Local stand-in for an open banking provider. It serves every flow in-process without any
+/// outbound request, so that payment method auth and payment initiation can be exercised in
+/// tests and local setups.
+///
+/// Payments are `initiated` on creation and `executed` once synced or looked up by their
+/// reference, reversals are executed immediately.
+#[derive(Debug, Clone)]
+pub struct DummyBank;
+
+/// Balance reported for every dummy bank account, in minor units of the account currency
+const DUMMY_BANK_ACCOUNT_BALANCE: i64 = 1_000_000;
+
+/// Holder name of every dummy bank account, account ownership checks are matched against it
+const DUMMY_BANK_ACCOUNT_HOLDER_NAME: &str = "John Doe";
+
+struct DummyBankAccount {
+    account_id: &'static str,
+    account_name: &'static str,
+    currency: Currency,
+    account_details: auth_types::PaymentMethodTypeDetails,
+    payment_method_type: PaymentMethodType,
+}
+
+fn get_dummy_bank_accounts() -> Vec<DummyBankAccount> {
+    vec![
+        DummyBankAccount {
+            account_id: "dummy_bank_ach",
+            account_name: "Dummy Bank Checking",
+            currency: Currency::USD,
+            account_details: auth_types::PaymentMethodTypeDetails::Ach(
+                auth_types::BankAccountDetailsAch {
+                    account_number: Secret::new("000123456789".to_string()),
+                    routing_number: Secret::new("110000000".to_string()),
+                },
+            ),
+            payment_method_type: PaymentMethodType::Ach,
+        },
+        DummyBankAccount {
+            account_id: "dummy_bank_bacs",
+            account_name: "Dummy Bank Current",
+            currency: Currency::GBP,
+            account_details: auth_types::PaymentMethodTypeDetails::Bacs(
+                auth_types::BankAccountDetailsBacs {
+                    account_number: Secret::new("31926819".to_string()),
+                    sort_code: Secret::new("601613".to_string()),
+                },
+            ),
+            payment_method_type: PaymentMethodType::Bacs,
+        },
+        DummyBankAccount {
+            account_id: "dummy_bank_sepa",
+            account_name: "Dummy Bank Girokonto",
+            currency: Currency::EUR,
+            account_details: auth_types::PaymentMethodTypeDetails::Sepa(
+                auth_types::BankAccountDetailsSepa {
+                    iban: Secret::new("DE89370400440532013000".to_string()),
+                    bic: Secret::new("COBADEFFXXX".to_string()),
+                },
+            ),
+            payment_method_type: PaymentMethodType::Sepa,
+        },
+    ]
+}
+
+fn get_dummy_bank_account(account_id: &str) -> Option<DummyBankAccount> {
+    get_dummy_bank_accounts()
+        .into_iter()
+        .find(|account| account.account_id == account_id)
+}
+
+fn get_account_not_found_error() -> auth_types::ErrorResponse {
+    auth_types::ErrorResponse {
+        code: "ACCOUNT_NOT_FOUND".to_string(),
+        message: "The requested account does not exist".to_string(),
+        reason: None,
+        status_code: http::StatusCode::NOT_FOUND.as_u16(),
+    }
+}
+
+impl ConnectorCommon for DummyBank {
+    fn id(&self) -> &'static str {
+        "dummy_bank"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str {
+        ""
+    }
+}
+
+impl auth_service::AuthService for DummyBank {}
+impl auth_service::PaymentInitiation for DummyBank {}
+impl auth_service::AuthServiceLinkToken for DummyBank {}
+
+impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
+    for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::LinkTokenRouterData,
+    ) -> errors::CustomResult<auth_types::LinkTokenRouterData, errors::ConnectorError> {
+        let customer_id = data.request.user_info.as_ref().ok_or(
+            errors::ConnectorError::MissingRequiredField {
+                field_name: "user_info",
+            },
+        )?;
+
+        Ok(auth_types::LinkTokenRouterData {
+            response: Ok(auth_types::LinkTokenResponse {
+                link_token: format!("link-dummy_bank-{}", customer_id.get_string_repr()),
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceExchangeToken for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        ExchangeToken,
+        auth_types::ExchangeTokenRequest,
+        auth_types::ExchangeTokenResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::ExchangeTokenRouterData,
+    ) -> errors::CustomResult<auth_types::ExchangeTokenRouterData, errors::ConnectorError> {
+        Ok(auth_types::ExchangeTokenRouterData {
+            response: Ok(auth_types::ExchangeTokenResponse {
+                access_token: format!("access-dummy_bank-{}", data.request.public_token),
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceBankAccountCredentials for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        BankAccountCredentials,
+        auth_types::BankAccountCredentialsRequest,
+        auth_types::BankAccountCredentialsResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::BankDetailsRouterData,
+    ) -> errors::CustomResult<auth_types::BankDetailsRouterData, errors::ConnectorError> {
+        let requested_ids = data.request.optional_ids.as_ref().map(|optional_ids| {
+            optional_ids
+                .ids
+                .iter()
+                .map(|id| id.peek().as_str())
+                .collect::<Vec<_>>()
+        });
+
+        let credentials = get_dummy_bank_accounts()
+            .into_iter()
+            .filter(|account| {
+                requested_ids
+                    .as_ref()
+                    .map_or(true, |ids| ids.contains(&account.account_id))
+            })
+            .map(
+                |account| -> errors::CustomResult<_, errors::ConnectorError> {
+                    let balance = FloatMajorUnitForConnector
+                        .convert(MinorUnit::new(DUMMY_BANK_ACCOUNT_BALANCE), account.currency)
+                        .change_context(errors::ConnectorError::ProcessingStepFailed(None))?;
+
+                    Ok(auth_types::BankAccountDetails {
+                        account_name: Some(account.account_name.to_string()),
+                        account_details: account.account_details,
+                        payment_method_type: account.payment_method_type,
+                        payment_method: PaymentMethod::BankDebit,
+                        account_id: Secret::new(account.account_id.to_string()),
+                        account_type: Some("checking".to_string()),
+                        balance: Some(balance),
+                    })
+                },
+            )
+            .collect::<errors::CustomResult<Vec<_>, _>>()?;
+
+        Ok(auth_types::BankDetailsRouterData {
+            response: Ok(auth_types::BankAccountCredentialsResponse { credentials }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceBankAccountBalance for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        BankAccountBalance,
+        auth_types::BankAccountBalanceRequest,
+        auth_types::BankAccountBalanceResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::BankAccountBalanceRouterData,
+    ) -> errors::CustomResult<auth_types::BankAccountBalanceRouterData, errors::ConnectorError>
+    {
+        let response = match get_dummy_bank_account(data.request.account_id.peek()) {
+            Some(account) => {
+                let balance = FloatMajorUnitForConnector
+                    .convert(MinorUnit::new(DUMMY_BANK_ACCOUNT_BALANCE), account.currency)
+                    .change_context(errors::ConnectorError::ProcessingStepFailed(None))?;
+
+                Ok(auth_types::BankAccountBalanceResponse {
+                    account_id: Secret::new(account.account_id.to_string()),
+                    available_balance: Some(balance),
+                    current_balance: Some(balance),
+                    currency: Some(account.currency),
+                })
+            }
+            None => Err(get_account_not_found_error()),
+        };
+
+        Ok(auth_types::BankAccountBalanceRouterData {
+            response,
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceAccountOwnership for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        AccountOwnership,
+        auth_types::AccountOwnershipRequest,
+        auth_types::AccountOwnershipResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::AccountOwnershipRouterData,
+    ) -> errors::CustomResult<auth_types::AccountOwnershipRouterData, errors::ConnectorError> {
+        let response = match get_dummy_bank_account(data.request.account_id.peek()) {
+            Some(account) => {
+                let name_match_score = crate::utils::get_name_match_score(
+                    data.request.legal_name.peek(),
+                    DUMMY_BANK_ACCOUNT_HOLDER_NAME,
+                );
+
+                Ok(auth_types::AccountOwnershipResponse {
+                    account_id: Secret::new(account.account_id.to_string()),
+                    name_match_score: Some(name_match_score),
+                    is_name_matched: name_match_score >= crate::consts::NAME_MATCH_THRESHOLD,
+                })
+            }
+            None => Err(get_account_not_found_error()),
+        };
+
+        Ok(auth_types::AccountOwnershipRouterData {
+            response,
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationRecipientCreate for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        RecipientCreate,
+        auth_types::RecipientCreateRequest,
+        auth_types::RecipientCreateResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::RecipientCreateRouterData,
+    ) -> errors::CustomResult<auth_types::RecipientCreateRouterData, errors::ConnectorError> {
+        Ok(auth_types::RecipientCreateRouterData {
+            response: Ok(auth_types::RecipientCreateResponse {
+                recipient_id: common_utils::generate_id_with_default_len("dummy_bank_recipient"),
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentCreate for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationCreate,
+        auth_types::PaymentInitiationCreateRequest,
+        auth_types::PaymentInitiationResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationCreateRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationCreateRouterData, errors::ConnectorError>
+    {
+        Ok(auth_types::PaymentInitiationCreateRouterData {
+            response: Ok(auth_types::PaymentInitiationResponse {
+                payment_id: get_dummy_bank_payment_id(&data.request.reference),
+                status: auth_types::PaymentInitiationStatus::Initiated,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentSync for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationSync,
+        auth_types::PaymentInitiationSyncRequest,
+        auth_types::PaymentInitiationResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationSyncRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationSyncRouterData, errors::ConnectorError>
+    {
+        Ok(auth_types::PaymentInitiationSyncRouterData {
+            response: Ok(auth_types::PaymentInitiationResponse {
+                payment_id: data.request.payment_id.clone(),
+                status: auth_types::PaymentInitiationStatus::Executed,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentLookup for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationLookup,
+        auth_types::PaymentInitiationLookupRequest,
+        auth_types::PaymentInitiationLookupResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationLookupRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationLookupRouterData, errors::ConnectorError>
+    {
+        Ok(auth_types::PaymentInitiationLookupRouterData {
+            response: Ok(auth_types::PaymentInitiationLookupResponse {
+                payment: Some(auth_types::PaymentInitiationResponse {
+                    payment_id: get_dummy_bank_payment_id(&data.request.reference),
+                    status: auth_types::PaymentInitiationStatus::Executed,
+                }),
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentReversal for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationReversal,
+        auth_types::PaymentInitiationReversalRequest,
+        auth_types::PaymentInitiationReversalResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationReversalRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationReversalRouterData, errors::ConnectorError>
+    {
+        Ok(auth_types::PaymentInitiationReversalRouterData {
+            response: Ok(auth_types::PaymentInitiationReversalResponse {
+                reversal_id: common_utils::generate_id_with_default_len("dummy_bank_reversal"),
+                status: auth_types::PaymentInitiationReversalStatus::Executed,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentReversalSync for DummyBank {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationReversalSync,
+        auth_types::PaymentInitiationReversalSyncRequest,
+        auth_types::PaymentInitiationReversalResponse,
+    > for DummyBank
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationReversalSyncRouterData,
+    ) -> errors::CustomResult<
+        auth_types::PaymentInitiationReversalSyncRouterData,
+        errors::ConnectorError,
+    > {
+        Ok(auth_types::PaymentInitiationReversalSyncRouterData {
+            response: Ok(auth_types::PaymentInitiationReversalResponse {
+                reversal_id: data.request.reversal_id.clone(),
+                status: auth_types::PaymentInitiationReversalStatus::Executed,
+            }),
+            ..data.clone()
+        })
+    }
+}
+
+/// Payments are identified by their reference, so that a payment can be looked up without any
+/// state being kept
+fn get_dummy_bank_payment_id(reference: &str) -> String {
+    format!("dummy_bank_payment_{reference}")
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+
+    use std::marker::PhantomData;
+
+    use super::*;
+
+    fn get_router_data<F, Req, Resp>(
+        request: Req,
+    ) -> auth_types::PaymentAuthRouterData<F, Req, Resp> {
+        auth_types::PaymentAuthRouterData {
+            flow: PhantomData,
+            merchant_id: None,
+            connector: Some(DummyBank.id().to_string()),
+            request,
+            response: Err(auth_types::ErrorResponse {
+                code: crate::consts::NO_ERROR_CODE.to_string(),
+                message: crate::consts::NO_ERROR_MESSAGE.to_string(),
+                reason: None,
+                status_code: 500,
+            }),
+            connector_auth_type: auth_types::ConnectorAuthType::NoKey,
+            connector_http_status_code: None,
+        }
+    }
+
+    #[test]
+    fn test_bank_account_credentials_filtered_by_account_id() {
+        let router_data: auth_types::BankDetailsRouterData =
+            get_router_data(auth_types::BankAccountCredentialsRequest {
+                access_token: Secret::new("access-dummy_bank-public".to_string()),
+                optional_ids: Some(auth_types::BankAccountOptionalIDs {
+                    ids: vec![Secret::new("dummy_bank_sepa".to_string())],
+                }),
+            });
+
+        let credentials = DummyBank
+            .handle_local_response(&router_data)
+            .unwrap()
+            .response
+            .unwrap()
+            .credentials;
+
+        assert_eq!(credentials.len(), 1);
+        assert_eq!(credentials[0].payment_method_type, PaymentMethodType::Sepa);
+        assert!(credentials[0].balance.is_some());
+    }
+
+    #[test]
+    fn test_balance_and_account_ownership() {
+        let balance_data: auth_types::BankAccountBalanceRouterData =
+            get_router_data(auth_types::BankAccountBalanceRequest {
+                access_token: Secret::new("access-dummy_bank-public".to_string()),
+                account_id: Secret::new("dummy_bank_ach".to_string()),
+            });
+        let balance = DummyBank
+            .handle_local_response(&balance_data)
+            .unwrap()
+            .response
+            .unwrap();
+        assert_eq!(balance.currency, Some(Currency::USD));
+        assert!(balance.available_balance.is_some());
+
+        let ownership_data: auth_types::AccountOwnershipRouterData =
+            get_router_data(auth_types::AccountOwnershipRequest {
+                access_token: Secret::new("access-dummy_bank-public".to_string()),
+                account_id: Secret::new("dummy_bank_ach".to_string()),
+                legal_name: Secret::new("Richard Roe".to_string()),
+            });
+        let ownership = DummyBank
+            .handle_local_response(&ownership_data)
+            .unwrap()
+            .response
+            .unwrap();
+        assert!(!ownership.is_name_matched);
+
+        let unknown_account_data: auth_types::BankAccountBalanceRouterData =
+            get_router_data(auth_types::BankAccountBalanceRequest {
+                access_token: Secret::new("access-dummy_bank-public".to_string()),
+                account_id: Secret::new("unknown".to_string()),
+            });
+        assert!(DummyBank
+            .handle_local_response(&unknown_account_data)
+            .unwrap()
+            .response
+            .is_err());
+    }
+
+    #[test]
+    fn test_payment_initiation_lifecycle() {
+        let create_data: auth_types::PaymentInitiationCreateRouterData =
+            get_router_data(auth_types::PaymentInitiationCreateRequest {
+                recipient_id: "dummy_bank_recipient".to_string(),
+                reference: "pay_reference".to_string(),
+                amount: MinorUnit::new(1000),
+                currency: Currency::GBP,
+                payer_account: None,
+            });
+        let created = DummyBank
+            .handle_local_response(&create_data)
+            .unwrap()
+            .response
+            .unwrap();
+        assert_eq!(
+            created.status,
+            auth_types::PaymentInitiationStatus::Initiated
+        );
+
+        let sync_data: auth_types::PaymentInitiationSyncRouterData =
+            get_router_data(auth_types::PaymentInitiationSyncRequest {
+                payment_id: created.payment_id.clone(),
+            });
+        let synced = DummyBank
+            .handle_local_response(&sync_data)
+            .unwrap()
+            .response
+            .unwrap();
+        assert_eq!(synced.payment_id, created.payment_id);
+        assert_eq!(synced.status, auth_types::PaymentInitiationStatus::Executed);
+
+        let lookup_data: auth_types::PaymentInitiationLookupRouterData =
+            get_router_data(auth_types::PaymentInitiationLookupRequest {
+                reference: "pay_reference".to_string(),
+            });
+        let looked_up = DummyBank
+            .handle_local_response(&lookup_data)
+            .unwrap()
+            .response
+            .unwrap()
+            .payment
+            .unwrap();
+        assert_eq!(looked_up.payment_id, created.payment_id);
+
+        let reversal_data: auth_types::PaymentInitiationReversalRouterData =
+            get_router_data(auth_types::PaymentInitiationReversalRequest {
+                payment_id: created.payment_id,
+                reversal_reference: "ref_reference".to_string(),
+                amount: MinorUnit::new(1000),
+                currency: Currency::GBP,
+            });
+        let reversed = DummyBank
+            .handle_local_response(&reversal_data)
+            .unwrap()
+            .response
+            .unwrap();
+        assert_eq!(
+            reversed.status,
+            auth_types::PaymentInitiationReversalStatus::Executed
+        );
+
+        let reversal_sync_data: auth_types::PaymentInitiationReversalSyncRouterData =
+            get_router_data(auth_types::PaymentInitiationReversalSyncRequest {
+                reversal_id: reversed.reversal_id.clone(),
+            });
+        let reversal_synced = DummyBank
+            .handle_local_response(&reversal_sync_data)
+            .unwrap()
+            .response
+            .unwrap();
+        assert_eq!(reversal_synced.reversal_id, reversed.reversal_id);
+    }
+}
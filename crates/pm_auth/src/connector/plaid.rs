@@ -16,7 +16,10 @@ use crate::{
         self as auth_types,
         api::{
             auth_service::{
-                self, BankAccountCredentials, ExchangeToken, LinkToken, RecipientCreate,
+                self, AccountOwnership, BankAccountBalance, BankAccountCredentials, ExchangeToken,
+                LinkToken, PaymentInitiationCreate, PaymentInitiationLookup,
+                PaymentInitiationReversal, PaymentInitiationReversalSync, PaymentInitiationSync,
+                RecipientCreate,
             },
             ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
         },
@@ -343,6 +346,177 @@ impl
     }
 }
 
+impl auth_service::AuthServiceBankAccountBalance for Plaid {}
+
+impl
+    ConnectorIntegration<
+        BankAccountBalance,
+        auth_types::BankAccountBalanceRequest,
+        auth_types::BankAccountBalanceResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::BankAccountBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::BankAccountBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/accounts/balance/get"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::BankAccountBalanceRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidBankAccountBalanceRequest::from(req);
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankAccountBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthBankAccountBalanceType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthBankAccountBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(
+                    auth_types::PaymentAuthBankAccountBalanceType::get_request_body(self, req)?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankAccountBalanceRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankAccountBalanceRouterData, errors::ConnectorError>
+    {
+        let response: plaid::PlaidBankAccountBalanceResponse = res
+            .response
+            .parse_struct("PlaidBankAccountBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::BankAccountBalanceRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceAccountOwnership for Plaid {}
+
+impl
+    ConnectorIntegration<
+        AccountOwnership,
+        auth_types::AccountOwnershipRequest,
+        auth_types::AccountOwnershipResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::AccountOwnershipRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::AccountOwnershipRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/identity/match"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::AccountOwnershipRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidIdentityMatchRequest::from(req);
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::AccountOwnershipRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthAccountOwnershipType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthAccountOwnershipType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(auth_types::PaymentAuthAccountOwnershipType::get_request_body(self, req)?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::AccountOwnershipRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::AccountOwnershipRouterData, errors::ConnectorError> {
+        let response: plaid::PlaidIdentityMatchResponse = res
+            .response
+            .parse_struct("PlaidIdentityMatchResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::AccountOwnershipRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
 impl
     ConnectorIntegration<
         RecipientCreate,
@@ -430,3 +604,460 @@ impl
         self.build_error_response(res)
     }
 }
+
+impl auth_service::PaymentInitiationPaymentCreate for Plaid {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationCreate,
+        auth_types::PaymentInitiationCreateRequest,
+        auth_types::PaymentInitiationResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::PaymentInitiationCreateRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::PaymentInitiationCreateRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/payment_initiation/payment/create"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::PaymentInitiationCreateRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidPaymentInitiationCreateRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::PaymentInitiationCreateRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentInitiationPaymentCreateType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentInitiationPaymentCreateType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(
+                    auth_types::PaymentInitiationPaymentCreateType::get_request_body(self, req)?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::PaymentInitiationCreateRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationCreateRouterData, errors::ConnectorError>
+    {
+        let response: plaid::PlaidPaymentInitiationResponse = res
+            .response
+            .parse_struct("PlaidPaymentInitiationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::PaymentInitiationCreateRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentSync for Plaid {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationSync,
+        auth_types::PaymentInitiationSyncRequest,
+        auth_types::PaymentInitiationResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::PaymentInitiationSyncRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::PaymentInitiationSyncRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/payment_initiation/payment/get"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::PaymentInitiationSyncRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidPaymentInitiationSyncRequest::from(req);
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::PaymentInitiationSyncRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentInitiationPaymentSyncType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentInitiationPaymentSyncType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(
+                    auth_types::PaymentInitiationPaymentSyncType::get_request_body(self, req)?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::PaymentInitiationSyncRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationSyncRouterData, errors::ConnectorError>
+    {
+        let response: plaid::PlaidPaymentInitiationResponse = res
+            .response
+            .parse_struct("PlaidPaymentInitiationResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::PaymentInitiationSyncRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentLookup for Plaid {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationLookup,
+        auth_types::PaymentInitiationLookupRequest,
+        auth_types::PaymentInitiationLookupResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::PaymentInitiationLookupRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::PaymentInitiationLookupRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/payment_initiation/payment/list"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::PaymentInitiationLookupRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidPaymentInitiationListRequest::from(req);
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::PaymentInitiationLookupRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentInitiationPaymentLookupType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentInitiationPaymentLookupType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(
+                    auth_types::PaymentInitiationPaymentLookupType::get_request_body(self, req)?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::PaymentInitiationLookupRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationLookupRouterData, errors::ConnectorError>
+    {
+        let response: plaid::PlaidPaymentInitiationListResponse = res
+            .response
+            .parse_struct("PlaidPaymentInitiationListResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::PaymentInitiationLookupRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentReversal for Plaid {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationReversal,
+        auth_types::PaymentInitiationReversalRequest,
+        auth_types::PaymentInitiationReversalResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::PaymentInitiationReversalRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::PaymentInitiationReversalRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/payment_initiation/payment/reverse"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::PaymentInitiationReversalRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidPaymentInitiationReversalRequest::try_from(req)?;
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::PaymentInitiationReversalRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentInitiationPaymentReversalType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(
+                    auth_types::PaymentInitiationPaymentReversalType::get_headers(
+                        self, req, connectors,
+                    )?,
+                )
+                .set_body(
+                    auth_types::PaymentInitiationPaymentReversalType::get_request_body(self, req)?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::PaymentInitiationReversalRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationReversalRouterData, errors::ConnectorError>
+    {
+        let response: plaid::PlaidPaymentInitiationReversalResponse = res
+            .response
+            .parse_struct("PlaidPaymentInitiationReversalResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::PaymentInitiationReversalRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentReversalSync for Plaid {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationReversalSync,
+        auth_types::PaymentInitiationReversalSyncRequest,
+        auth_types::PaymentInitiationReversalResponse,
+    > for Plaid
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::PaymentInitiationReversalSyncRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::PaymentInitiationReversalSyncRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        // Plaid books reversals as refund transactions of the virtual account
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/wallet/transaction/get"
+        ))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::PaymentInitiationReversalSyncRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = plaid::PlaidPaymentInitiationReversalSyncRequest::from(req);
+        Ok(RequestContent::Json(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::PaymentInitiationReversalSyncRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(
+                    &auth_types::PaymentInitiationPaymentReversalSyncType::get_url(
+                        self, req, connectors,
+                    )?,
+                )
+                .attach_default_headers()
+                .headers(
+                    auth_types::PaymentInitiationPaymentReversalSyncType::get_headers(
+                        self, req, connectors,
+                    )?,
+                )
+                .set_body(
+                    auth_types::PaymentInitiationPaymentReversalSyncType::get_request_body(
+                        self, req,
+                    )?,
+                )
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::PaymentInitiationReversalSyncRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<
+        auth_types::PaymentInitiationReversalSyncRouterData,
+        errors::ConnectorError,
+    > {
+        let response: plaid::PlaidPaymentInitiationReversalSyncResponse = res
+            .response
+            .parse_struct("PlaidPaymentInitiationReversalSyncResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::PaymentInitiationReversalSyncRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
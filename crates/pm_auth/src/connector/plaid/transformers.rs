@@ -1,7 +1,11 @@
 use std::collections::HashMap;
 
-use common_enums::{PaymentMethod, PaymentMethodType};
-use common_utils::{id_type, types as util_types};
+use common_enums::{Currency, PaymentMethod, PaymentMethodType};
+use common_utils::{
+    id_type,
+    types::{self as util_types, AmountConvertor},
+};
+use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use serde::{Deserialize, Serialize};
 
@@ -445,6 +449,535 @@ impl<F, T>
         })
     }
 }
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub struct PlaidBankAccountBalanceRequest {
+    access_token: String,
+    options: BankAccountCredentialsOptions,
+}
+
+impl From<&types::BankAccountBalanceRouterData> for PlaidBankAccountBalanceRequest {
+    fn from(item: &types::BankAccountBalanceRouterData) -> Self {
+        Self {
+            access_token: item.request.access_token.peek().to_string(),
+            options: BankAccountCredentialsOptions {
+                account_ids: vec![item.request.account_id.peek().to_string()],
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, PartialEq)]
+pub struct PlaidBankAccountBalanceResponse {
+    pub accounts: Vec<PlaidBankAccountCredentialsAccounts>,
+    pub request_id: String,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PlaidBankAccountBalanceResponse,
+            T,
+            types::BankAccountBalanceResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::BankAccountBalanceResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidBankAccountBalanceResponse,
+            T,
+            types::BankAccountBalanceResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let account = item
+            .response
+            .accounts
+            .into_iter()
+            .next()
+            .ok_or(errors::ConnectorError::ResponseDeserializationFailed)
+            .attach_printable("no account found in plaid balance response")?;
+        let balances = account.balances;
+
+        Ok(Self {
+            response: Ok(types::BankAccountBalanceResponse {
+                account_id: account.account_id.into(),
+                available_balance: balances.as_ref().and_then(|balance| balance.available),
+                current_balance: balances.as_ref().and_then(|balance| balance.current),
+                currency: balances
+                    .and_then(|balance| balance.iso_currency_code)
+                    .and_then(|currency| currency.parse().ok()),
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PlaidIdentityMatchRequest {
+    access_token: String,
+    user: PlaidIdentityMatchUser,
+    options: BankAccountCredentialsOptions,
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PlaidIdentityMatchUser {
+    legal_name: Secret<String>,
+}
+
+impl From<&types::AccountOwnershipRouterData> for PlaidIdentityMatchRequest {
+    fn from(item: &types::AccountOwnershipRouterData) -> Self {
+        Self {
+            access_token: item.request.access_token.peek().to_string(),
+            user: PlaidIdentityMatchUser {
+                legal_name: item.request.legal_name.clone(),
+            },
+            options: BankAccountCredentialsOptions {
+                account_ids: vec![item.request.account_id.peek().to_string()],
+            },
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidIdentityMatchResponse {
+    pub accounts: Vec<PlaidIdentityMatchAccount>,
+    pub request_id: String,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidIdentityMatchAccount {
+    pub account_id: String,
+    pub legal_name: Option<PlaidLegalNameMatch>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidLegalNameMatch {
+    pub score: Option<u8>,
+    pub is_first_name_or_last_name_match: Option<bool>,
+    pub is_nickname_match: Option<bool>,
+    pub is_business_name_detected: Option<bool>,
+}
+
+impl<F, T>
+    TryFrom<
+        types::ResponseRouterData<
+            F,
+            PlaidIdentityMatchResponse,
+            T,
+            types::AccountOwnershipResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::AccountOwnershipResponse>
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidIdentityMatchResponse,
+            T,
+            types::AccountOwnershipResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let account = item
+            .response
+            .accounts
+            .into_iter()
+            .next()
+            .ok_or(errors::ConnectorError::ResponseDeserializationFailed)
+            .attach_printable("no account found in plaid identity match response")?;
+        let name_match_score = account.legal_name.and_then(|legal_name| legal_name.score);
+
+        Ok(Self {
+            response: Ok(types::AccountOwnershipResponse {
+                account_id: account.account_id.into(),
+                name_match_score,
+                is_name_matched: name_match_score
+                    .is_some_and(|score| score >= crate::consts::NAME_MATCH_THRESHOLD),
+            }),
+            ..item.data
+        })
+    }
+}
+
+/// Plaid accepts payment references of at most 18 characters
+const PLAID_REFERENCE_MAX_LENGTH: usize = 18;
+
+fn get_plaid_reference(reference: &str) -> String {
+    let reference = reference
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .collect::<String>();
+    let reference_len = reference.len();
+    reference
+        .get(reference_len.saturating_sub(PLAID_REFERENCE_MAX_LENGTH)..)
+        .unwrap_or_default()
+        .to_string()
+}
+
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct PlaidAmount {
+    currency: Currency,
+    value: util_types::FloatMajorUnit,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PlaidPaymentInitiationCreateRequest {
+    recipient_id: String,
+    reference: String,
+    amount: PlaidAmount,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    options: Option<PlaidPaymentInitiationOptions>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PlaidPaymentInitiationOptions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    iban: Option<Secret<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bacs: Option<PlaidBacs>,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PlaidBacs {
+    account: Secret<String>,
+    sort_code: Secret<String>,
+}
+
+impl TryFrom<&types::PaymentInitiationCreateRouterData> for PlaidPaymentInitiationCreateRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentInitiationCreateRouterData) -> Result<Self, Self::Error> {
+        let value = util_types::FloatMajorUnitForConnector
+            .convert(item.request.amount, item.request.currency)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        // Payment initiation is only available for UK and European accounts
+        let options = item
+            .request
+            .payer_account
+            .as_ref()
+            .and_then(|account| match account {
+                types::PaymentMethodTypeDetails::Sepa(sepa) => {
+                    Some(PlaidPaymentInitiationOptions {
+                        iban: Some(sepa.iban.clone()),
+                        bacs: None,
+                    })
+                }
+                types::PaymentMethodTypeDetails::Bacs(bacs) => {
+                    Some(PlaidPaymentInitiationOptions {
+                        iban: None,
+                        bacs: Some(PlaidBacs {
+                            account: bacs.account_number.clone(),
+                            sort_code: bacs.sort_code.clone(),
+                        }),
+                    })
+                }
+                types::PaymentMethodTypeDetails::Ach(_) => None,
+            });
+
+        Ok(Self {
+            recipient_id: item.request.recipient_id.clone(),
+            reference: get_plaid_reference(&item.request.reference),
+            amount: PlaidAmount {
+                currency: item.request.currency,
+                value,
+            },
+            options,
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationSyncRequest {
+    payment_id: String,
+}
+
+impl From<&types::PaymentInitiationSyncRouterData> for PlaidPaymentInitiationSyncRequest {
+    fn from(item: &types::PaymentInitiationSyncRouterData) -> Self {
+        Self {
+            payment_id: item.request.payment_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlaidPaymentStatus {
+    PaymentStatusInputNeeded,
+    PaymentStatusInitiated,
+    PaymentStatusInsufficientFunds,
+    PaymentStatusFailed,
+    PaymentStatusBlocked,
+    PaymentStatusCancelled,
+    PaymentStatusExecuted,
+    PaymentStatusSettled,
+    PaymentStatusEstablished,
+    PaymentStatusRejected,
+    PaymentStatusAuthorising,
+}
+
+impl From<PlaidPaymentStatus> for types::PaymentInitiationStatus {
+    fn from(item: PlaidPaymentStatus) -> Self {
+        match item {
+            PlaidPaymentStatus::PaymentStatusInputNeeded => Self::InputNeeded,
+            PlaidPaymentStatus::PaymentStatusInitiated => Self::Initiated,
+            PlaidPaymentStatus::PaymentStatusAuthorising => Self::Authorising,
+            PlaidPaymentStatus::PaymentStatusEstablished => Self::Established,
+            PlaidPaymentStatus::PaymentStatusExecuted => Self::Executed,
+            PlaidPaymentStatus::PaymentStatusSettled => Self::Settled,
+            PlaidPaymentStatus::PaymentStatusInsufficientFunds => Self::InsufficientFunds,
+            PlaidPaymentStatus::PaymentStatusBlocked => Self::Blocked,
+            PlaidPaymentStatus::PaymentStatusRejected => Self::Rejected,
+            PlaidPaymentStatus::PaymentStatusFailed => Self::Failed,
+            PlaidPaymentStatus::PaymentStatusCancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationResponse {
+    pub payment_id: String,
+    pub status: PlaidPaymentStatus,
+}
+
+impl<F, T>
+    From<
+        types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationResponse,
+            T,
+            types::PaymentInitiationResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::PaymentInitiationResponse>
+{
+    fn from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationResponse,
+            T,
+            types::PaymentInitiationResponse,
+        >,
+    ) -> Self {
+        Self {
+            response: Ok(types::PaymentInitiationResponse {
+                payment_id: item.response.payment_id,
+                status: item.response.status.into(),
+            }),
+            ..item.data
+        }
+    }
+}
+
+/// Number of the most recent payments searched for the reference of a payment, which is the
+/// maximum page size of the payment list
+const PLAID_PAYMENT_LIST_COUNT: u8 = 200;
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationListRequest {
+    count: u8,
+}
+
+impl From<&types::PaymentInitiationLookupRouterData> for PlaidPaymentInitiationListRequest {
+    fn from(_item: &types::PaymentInitiationLookupRouterData) -> Self {
+        Self {
+            count: PLAID_PAYMENT_LIST_COUNT,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationListResponse {
+    pub payments: Vec<PlaidPaymentInitiationListPayment>,
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationListPayment {
+    pub payment_id: String,
+    pub reference: String,
+    pub status: PlaidPaymentStatus,
+}
+
+impl<F>
+    From<
+        types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationListResponse,
+            types::PaymentInitiationLookupRequest,
+            types::PaymentInitiationLookupResponse,
+        >,
+    >
+    for types::PaymentAuthRouterData<
+        F,
+        types::PaymentInitiationLookupRequest,
+        types::PaymentInitiationLookupResponse,
+    >
+{
+    fn from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationListResponse,
+            types::PaymentInitiationLookupRequest,
+            types::PaymentInitiationLookupResponse,
+        >,
+    ) -> Self {
+        // The reference is sent to Plaid in its shortened form
+        let reference = get_plaid_reference(&item.data.request.reference);
+        let payment = item
+            .response
+            .payments
+            .into_iter()
+            .find(|payment| payment.reference == reference)
+            .map(|payment| types::PaymentInitiationResponse {
+                payment_id: payment.payment_id,
+                status: payment.status.into(),
+            });
+        Self {
+            response: Ok(types::PaymentInitiationLookupResponse { payment }),
+            ..item.data
+        }
+    }
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+pub struct PlaidPaymentInitiationReversalRequest {
+    payment_id: String,
+    idempotency_key: String,
+    reference: String,
+    amount: PlaidAmount,
+}
+
+impl TryFrom<&types::PaymentInitiationReversalRouterData>
+    for PlaidPaymentInitiationReversalRequest
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::PaymentInitiationReversalRouterData) -> Result<Self, Self::Error> {
+        let value = util_types::FloatMajorUnitForConnector
+            .convert(item.request.amount, item.request.currency)
+            .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+        Ok(Self {
+            payment_id: item.request.payment_id.clone(),
+            idempotency_key: item.request.reversal_reference.clone(),
+            reference: get_plaid_reference(&item.request.reversal_reference),
+            amount: PlaidAmount {
+                currency: item.request.currency,
+                value,
+            },
+        })
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Eq, PartialEq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlaidReversalStatus {
+    Authorising,
+    Initiated,
+    Executed,
+    Settled,
+    Blocked,
+    Failed,
+    Cancelled,
+    Returned,
+}
+
+impl From<PlaidReversalStatus> for types::PaymentInitiationReversalStatus {
+    fn from(item: PlaidReversalStatus) -> Self {
+        match item {
+            PlaidReversalStatus::Authorising | PlaidReversalStatus::Initiated => Self::Initiated,
+            PlaidReversalStatus::Executed | PlaidReversalStatus::Settled => Self::Executed,
+            PlaidReversalStatus::Blocked
+            | PlaidReversalStatus::Failed
+            | PlaidReversalStatus::Cancelled
+            | PlaidReversalStatus::Returned => Self::Failed,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationReversalResponse {
+    pub refund_id: String,
+    pub status: PlaidReversalStatus,
+}
+
+impl<F, T>
+    From<
+        types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationReversalResponse,
+            T,
+            types::PaymentInitiationReversalResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::PaymentInitiationReversalResponse>
+{
+    fn from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationReversalResponse,
+            T,
+            types::PaymentInitiationReversalResponse,
+        >,
+    ) -> Self {
+        Self {
+            response: Ok(types::PaymentInitiationReversalResponse {
+                reversal_id: item.response.refund_id,
+                status: item.response.status.into(),
+            }),
+            ..item.data
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationReversalSyncRequest {
+    transaction_id: String,
+}
+
+impl From<&types::PaymentInitiationReversalSyncRouterData>
+    for PlaidPaymentInitiationReversalSyncRequest
+{
+    fn from(item: &types::PaymentInitiationReversalSyncRouterData) -> Self {
+        Self {
+            transaction_id: item.request.reversal_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Eq, PartialEq)]
+pub struct PlaidPaymentInitiationReversalSyncResponse {
+    pub transaction_id: String,
+    pub status: PlaidReversalStatus,
+}
+
+impl<F, T>
+    From<
+        types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationReversalSyncResponse,
+            T,
+            types::PaymentInitiationReversalResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::PaymentInitiationReversalResponse>
+{
+    fn from(
+        item: types::ResponseRouterData<
+            F,
+            PlaidPaymentInitiationReversalSyncResponse,
+            T,
+            types::PaymentInitiationReversalResponse,
+        >,
+    ) -> Self {
+        Self {
+            response: Ok(types::PaymentInitiationReversalResponse {
+                reversal_id: item.response.transaction_id,
+                status: item.response.status.into(),
+            }),
+            ..item.data
+        }
+    }
+}
+
 pub struct PlaidAuthType {
     pub client_id: Secret<String>,
     pub secret: Secret<String>,
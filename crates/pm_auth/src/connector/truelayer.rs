@@ -0,0 +1,569 @@
+pub mod transformers;
+
+use common_utils::{
+    ext_traits::BytesExt,
+    request::{Method, Request, RequestBuilder, RequestContent},
+};
+use error_stack::ResultExt;
+use masking::{Mask, Maskable, PeekInterface};
+use transformers as truelayer;
+
+use crate::{
+    core::errors,
+    types::{
+        self as auth_types,
+        api::{
+            auth_service::{
+                self, AccountOwnership, BankAccountBalance, BankAccountCredentials, ExchangeToken,
+                LinkToken, PaymentInitiationCreate, PaymentInitiationLookup,
+                PaymentInitiationReversal, PaymentInitiationReversalSync, PaymentInitiationSync,
+                RecipientCreate,
+            },
+            ConnectorCommon, ConnectorCommonExt, ConnectorIntegration,
+        },
+    },
+};
+
+/// Base url of the TrueLayer auth server, which hosts the auth link and the token endpoint. The
+/// data api is served from `base_url`.
+const TRUELAYER_AUTH_BASE_URL: &str = "https://auth.truelayer-sandbox.com";
+
+/// TrueLayer data api connector. It verifies bank accounts and checks their balance and
+/// ownership, payment initiation is not supported.
+#[derive(Debug, Clone)]
+pub struct Truelayer;
+
+impl Truelayer {
+    fn get_bearer_auth_header(
+        access_token: &masking::Secret<String>,
+    ) -> (String, Maskable<String>) {
+        (
+            "Authorization".to_string(),
+            format!("Bearer {}", access_token.peek()).into_masked(),
+        )
+    }
+
+    fn get_not_supported_response<F, Req, Resp>(
+        data: &auth_types::PaymentAuthRouterData<F, Req, Resp>,
+    ) -> auth_types::PaymentAuthRouterData<F, Req, Resp>
+    where
+        F: Clone,
+        Req: Clone,
+        Resp: Clone,
+    {
+        auth_types::PaymentAuthRouterData {
+            response: Err(auth_types::ErrorResponse::get_not_implemented()),
+            ..data.clone()
+        }
+    }
+}
+
+impl<Flow, Request, Response> ConnectorCommonExt<Flow, Request, Response> for Truelayer
+where
+    Self: ConnectorIntegration<Flow, Request, Response>,
+{
+    fn build_headers(
+        &self,
+        req: &auth_types::PaymentAuthRouterData<Flow, Request, Response>,
+        _connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut header = vec![(
+            "Content-Type".to_string(),
+            self.get_content_type().to_string().into(),
+        )];
+
+        let mut auth = self.get_auth_header(&req.connector_auth_type)?;
+        header.append(&mut auth);
+        Ok(header)
+    }
+}
+
+impl ConnectorCommon for Truelayer {
+    fn id(&self) -> &'static str {
+        "truelayer"
+    }
+
+    fn common_get_content_type(&self) -> &'static str {
+        "application/json"
+    }
+
+    fn base_url<'a>(&self, _connectors: &'a auth_types::PaymentMethodAuthConnectors) -> &'a str {
+        "https://api.truelayer-sandbox.com"
+    }
+
+    fn get_auth_header(
+        &self,
+        auth_type: &auth_types::ConnectorAuthType,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        // Client credentials are only sent in the token exchange body, data api requests are
+        // authorized with the access token of the connection
+        truelayer::TruelayerAuthType::try_from(auth_type)
+            .change_context(errors::ConnectorError::FailedToObtainAuthType)?;
+        Ok(vec![])
+    }
+
+    fn build_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        let response: truelayer::TruelayerErrorResponse = res
+            .response
+            .parse_struct("TruelayerErrorResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(auth_types::ErrorResponse {
+            status_code: res.status_code,
+            code: response.error.clone(),
+            message: response.error_description.unwrap_or(response.error),
+            reason: None,
+        })
+    }
+}
+
+impl auth_service::AuthService for Truelayer {}
+impl auth_service::PaymentInitiation for Truelayer {}
+impl auth_service::AuthServiceLinkToken for Truelayer {}
+
+impl ConnectorIntegration<LinkToken, auth_types::LinkTokenRequest, auth_types::LinkTokenResponse>
+    for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::LinkTokenRouterData,
+    ) -> errors::CustomResult<auth_types::LinkTokenRouterData, errors::ConnectorError> {
+        let link_token = truelayer::get_truelayer_auth_link(TRUELAYER_AUTH_BASE_URL, data)?;
+
+        Ok(auth_types::LinkTokenRouterData {
+            response: Ok(auth_types::LinkTokenResponse { link_token }),
+            ..data.clone()
+        })
+    }
+}
+
+impl auth_service::AuthServiceExchangeToken for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        ExchangeToken,
+        auth_types::ExchangeTokenRequest,
+        auth_types::ExchangeTokenResponse,
+    > for Truelayer
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        self.build_headers(req, connectors)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        "application/x-www-form-urlencoded"
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::ExchangeTokenRouterData,
+        _connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", TRUELAYER_AUTH_BASE_URL, "/connect/token"))
+    }
+
+    fn get_request_body(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+    ) -> errors::CustomResult<RequestContent, errors::ConnectorError> {
+        let req_obj = truelayer::TruelayerExchangeTokenRequest::try_from(req)?;
+        Ok(RequestContent::FormUrlEncoded(Box::new(req_obj)))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::ExchangeTokenRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Post)
+                .url(&auth_types::PaymentAuthExchangeTokenType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthExchangeTokenType::get_headers(
+                    self, req, connectors,
+                )?)
+                .set_body(auth_types::PaymentAuthExchangeTokenType::get_request_body(
+                    self, req,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::ExchangeTokenRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ExchangeTokenRouterData, errors::ConnectorError> {
+        let response: truelayer::TruelayerExchangeTokenResponse = res
+            .response
+            .parse_struct("TruelayerExchangeTokenResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::ExchangeTokenRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceBankAccountCredentials for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        BankAccountCredentials,
+        auth_types::BankAccountCredentialsRequest,
+        auth_types::BankAccountCredentialsResponse,
+    > for Truelayer
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut header = self.build_headers(req, connectors)?;
+        header.push(Self::get_bearer_auth_header(&req.request.access_token));
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}{}",
+            self.base_url(connectors),
+            "/data/v1/accounts"
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankDetailsRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Get)
+                .url(&auth_types::PaymentAuthBankAccountDetailsType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthBankAccountDetailsType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankDetailsRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankDetailsRouterData, errors::ConnectorError> {
+        let response: truelayer::TruelayerAccountsResponse = res
+            .response
+            .parse_struct("TruelayerAccountsResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::BankDetailsRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceBankAccountBalance for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        BankAccountBalance,
+        auth_types::BankAccountBalanceRequest,
+        auth_types::BankAccountBalanceResponse,
+    > for Truelayer
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::BankAccountBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut header = self.build_headers(req, connectors)?;
+        header.push(Self::get_bearer_auth_header(&req.request.access_token));
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        req: &auth_types::BankAccountBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!(
+            "{}/data/v1/accounts/{}/balance",
+            self.base_url(connectors),
+            req.request.account_id.peek()
+        ))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::BankAccountBalanceRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Get)
+                .url(&auth_types::PaymentAuthBankAccountBalanceType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthBankAccountBalanceType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::BankAccountBalanceRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::BankAccountBalanceRouterData, errors::ConnectorError>
+    {
+        let response: truelayer::TruelayerBalanceResponse = res
+            .response
+            .parse_struct("TruelayerBalanceResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        <auth_types::BankAccountBalanceRouterData>::try_from(auth_types::ResponseRouterData {
+            response,
+            data: data.clone(),
+            http_code: res.status_code,
+        })
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::AuthServiceAccountOwnership for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        AccountOwnership,
+        auth_types::AccountOwnershipRequest,
+        auth_types::AccountOwnershipResponse,
+    > for Truelayer
+{
+    fn get_headers(
+        &self,
+        req: &auth_types::AccountOwnershipRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Vec<(String, Maskable<String>)>, errors::ConnectorError> {
+        let mut header = self.build_headers(req, connectors)?;
+        header.push(Self::get_bearer_auth_header(&req.request.access_token));
+        Ok(header)
+    }
+
+    fn get_content_type(&self) -> &'static str {
+        self.common_get_content_type()
+    }
+
+    fn get_url(
+        &self,
+        _req: &auth_types::AccountOwnershipRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<String, errors::ConnectorError> {
+        Ok(format!("{}{}", self.base_url(connectors), "/data/v1/info"))
+    }
+
+    fn build_request(
+        &self,
+        req: &auth_types::AccountOwnershipRouterData,
+        connectors: &auth_types::PaymentMethodAuthConnectors,
+    ) -> errors::CustomResult<Option<Request>, errors::ConnectorError> {
+        Ok(Some(
+            RequestBuilder::new()
+                .method(Method::Get)
+                .url(&auth_types::PaymentAuthAccountOwnershipType::get_url(
+                    self, req, connectors,
+                )?)
+                .attach_default_headers()
+                .headers(auth_types::PaymentAuthAccountOwnershipType::get_headers(
+                    self, req, connectors,
+                )?)
+                .build(),
+        ))
+    }
+
+    fn handle_response(
+        &self,
+        data: &auth_types::AccountOwnershipRouterData,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::AccountOwnershipRouterData, errors::ConnectorError> {
+        let response: truelayer::TruelayerInfoResponse = res
+            .response
+            .parse_struct("TruelayerInfoResponse")
+            .change_context(errors::ConnectorError::ResponseDeserializationFailed)?;
+        Ok(<auth_types::AccountOwnershipRouterData>::from(
+            auth_types::ResponseRouterData {
+                response,
+                data: data.clone(),
+                http_code: res.status_code,
+            },
+        ))
+    }
+
+    fn get_error_response(
+        &self,
+        res: auth_types::Response,
+    ) -> errors::CustomResult<auth_types::ErrorResponse, errors::ConnectorError> {
+        self.build_error_response(res)
+    }
+}
+
+impl auth_service::PaymentInitiationRecipientCreate for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        RecipientCreate,
+        auth_types::RecipientCreateRequest,
+        auth_types::RecipientCreateResponse,
+    > for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::RecipientCreateRouterData,
+    ) -> errors::CustomResult<auth_types::RecipientCreateRouterData, errors::ConnectorError> {
+        Ok(Self::get_not_supported_response(data))
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentCreate for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationCreate,
+        auth_types::PaymentInitiationCreateRequest,
+        auth_types::PaymentInitiationResponse,
+    > for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationCreateRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationCreateRouterData, errors::ConnectorError>
+    {
+        Ok(Self::get_not_supported_response(data))
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentSync for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationSync,
+        auth_types::PaymentInitiationSyncRequest,
+        auth_types::PaymentInitiationResponse,
+    > for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationSyncRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationSyncRouterData, errors::ConnectorError>
+    {
+        Ok(Self::get_not_supported_response(data))
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentLookup for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationLookup,
+        auth_types::PaymentInitiationLookupRequest,
+        auth_types::PaymentInitiationLookupResponse,
+    > for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationLookupRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationLookupRouterData, errors::ConnectorError>
+    {
+        Ok(Self::get_not_supported_response(data))
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentReversal for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationReversal,
+        auth_types::PaymentInitiationReversalRequest,
+        auth_types::PaymentInitiationReversalResponse,
+    > for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationReversalRouterData,
+    ) -> errors::CustomResult<auth_types::PaymentInitiationReversalRouterData, errors::ConnectorError>
+    {
+        Ok(Self::get_not_supported_response(data))
+    }
+}
+
+impl auth_service::PaymentInitiationPaymentReversalSync for Truelayer {}
+
+impl
+    ConnectorIntegration<
+        PaymentInitiationReversalSync,
+        auth_types::PaymentInitiationReversalSyncRequest,
+        auth_types::PaymentInitiationReversalResponse,
+    > for Truelayer
+{
+    fn handle_local_response(
+        &self,
+        data: &auth_types::PaymentInitiationReversalSyncRouterData,
+    ) -> errors::CustomResult<
+        auth_types::PaymentInitiationReversalSyncRouterData,
+        errors::ConnectorError,
+    > {
+        Ok(Self::get_not_supported_response(data))
+    }
+}
@@ -0,0 +1,359 @@
+use common_enums::{Currency, PaymentMethod, PaymentMethodType};
+use common_utils::types as util_types;
+use error_stack::ResultExt;
+use masking::{PeekInterface, Secret};
+use serde::{Deserialize, Serialize};
+
+use crate::{core::errors, types};
+
+/// Scopes requested in the auth link, `offline_access` grants a refresh token
+const TRUELAYER_AUTH_SCOPES: &str = "info accounts balance offline_access";
+
+pub struct TruelayerAuthType {
+    pub client_id: Secret<String>,
+    pub client_secret: Secret<String>,
+    pub redirect_uri: Secret<String>,
+}
+
+impl TryFrom<&types::ConnectorAuthType> for TruelayerAuthType {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(auth_type: &types::ConnectorAuthType) -> Result<Self, Self::Error> {
+        match auth_type {
+            types::ConnectorAuthType::SignatureKey {
+                client_id,
+                secret,
+                redirect_uri,
+            } => Ok(Self {
+                client_id: client_id.to_owned(),
+                client_secret: secret.to_owned(),
+                redirect_uri: redirect_uri.to_owned(),
+            }),
+            _ => Err(errors::ConnectorError::FailedToObtainAuthType.into()),
+        }
+    }
+}
+
+/// Maps the requested countries to TrueLayer provider groups, open banking providers for the UK
+/// and XS2A providers for the rest of Europe
+fn get_truelayer_providers(country_codes: &[String]) -> String {
+    country_codes
+        .iter()
+        .map(|country_code| match country_code.to_lowercase().as_str() {
+            "gb" | "uk" => "uk-ob-all uk-oauth-all".to_string(),
+            country_code => format!("{country_code}-xs2a-all"),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// TrueLayer has no link token, the user is sent to the auth link which redirects back with the
+/// authorization code that is exchanged for an access token.
+pub fn get_truelayer_auth_link(
+    auth_base_url: &str,
+    item: &types::LinkTokenRouterData,
+) -> errors::CustomResult<String, errors::ConnectorError> {
+    let auth = TruelayerAuthType::try_from(&item.connector_auth_type)?;
+    let country_codes = item.request.country_codes.as_ref().ok_or(
+        errors::ConnectorError::MissingRequiredField {
+            field_name: "country_codes",
+        },
+    )?;
+    let customer_id =
+        item.request
+            .user_info
+            .as_ref()
+            .ok_or(errors::ConnectorError::MissingRequiredField {
+                field_name: "user_info",
+            })?;
+
+    let auth_link = url::Url::parse_with_params(
+        auth_base_url,
+        &[
+            ("response_type", "code"),
+            ("client_id", auth.client_id.peek().as_str()),
+            ("scope", TRUELAYER_AUTH_SCOPES),
+            ("redirect_uri", auth.redirect_uri.peek().as_str()),
+            ("providers", get_truelayer_providers(country_codes).as_str()),
+            ("state", customer_id.get_string_repr()),
+        ],
+    )
+    .change_context(errors::ConnectorError::RequestEncodingFailed)?;
+
+    Ok(auth_link.to_string())
+}
+
+#[derive(Debug, Serialize)]
+pub struct TruelayerExchangeTokenRequest {
+    grant_type: &'static str,
+    client_id: Secret<String>,
+    client_secret: Secret<String>,
+    redirect_uri: Secret<String>,
+    code: String,
+}
+
+impl TryFrom<&types::ExchangeTokenRouterData> for TruelayerExchangeTokenRequest {
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(item: &types::ExchangeTokenRouterData) -> Result<Self, Self::Error> {
+        let auth = TruelayerAuthType::try_from(&item.connector_auth_type)?;
+        Ok(Self {
+            grant_type: "authorization_code",
+            client_id: auth.client_id,
+            client_secret: auth.client_secret,
+            redirect_uri: auth.redirect_uri,
+            code: item.request.public_token.clone(),
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerExchangeTokenResponse {
+    pub access_token: String,
+    pub expires_in: Option<i64>,
+    pub token_type: Option<String>,
+    pub refresh_token: Option<String>,
+}
+
+impl<F, T>
+    From<
+        types::ResponseRouterData<
+            F,
+            TruelayerExchangeTokenResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    > for types::PaymentAuthRouterData<F, T, types::ExchangeTokenResponse>
+{
+    fn from(
+        item: types::ResponseRouterData<
+            F,
+            TruelayerExchangeTokenResponse,
+            T,
+            types::ExchangeTokenResponse,
+        >,
+    ) -> Self {
+        Self {
+            response: Ok(types::ExchangeTokenResponse {
+                access_token: item.response.access_token,
+            }),
+            ..item.data
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerResults<T> {
+    pub results: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerAccount {
+    pub account_id: String,
+    pub account_type: Option<String>,
+    pub display_name: Option<String>,
+    pub currency: Option<Currency>,
+    pub account_number: TruelayerAccountNumber,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerAccountNumber {
+    pub iban: Option<Secret<String>>,
+    pub swift_bic: Option<Secret<String>>,
+    pub number: Option<Secret<String>>,
+    pub sort_code: Option<Secret<String>>,
+}
+
+pub type TruelayerAccountsResponse = TruelayerResults<TruelayerAccount>;
+
+impl
+    From<
+        types::ResponseRouterData<
+            types::api::auth_service::BankAccountCredentials,
+            TruelayerAccountsResponse,
+            types::BankAccountCredentialsRequest,
+            types::BankAccountCredentialsResponse,
+        >,
+    > for types::BankDetailsRouterData
+{
+    fn from(
+        item: types::ResponseRouterData<
+            types::api::auth_service::BankAccountCredentials,
+            TruelayerAccountsResponse,
+            types::BankAccountCredentialsRequest,
+            types::BankAccountCredentialsResponse,
+        >,
+    ) -> Self {
+        let requested_ids = item.data.request.optional_ids.as_ref().map(|optional_ids| {
+            optional_ids
+                .ids
+                .iter()
+                .map(|id| id.peek().clone())
+                .collect::<Vec<_>>()
+        });
+
+        let mut bank_account_vec = Vec::new();
+
+        item.response
+            .results
+            .into_iter()
+            .filter(|account| {
+                requested_ids
+                    .as_ref()
+                    .map_or(true, |ids| ids.contains(&account.account_id))
+            })
+            .for_each(|account| {
+                let TruelayerAccountNumber {
+                    iban,
+                    swift_bic,
+                    number,
+                    sort_code,
+                } = account.account_number;
+
+                if let (Some(account_number), Some(sort_code)) = (number, sort_code) {
+                    bank_account_vec.push(types::BankAccountDetails {
+                        account_name: account.display_name.clone(),
+                        account_details: types::PaymentMethodTypeDetails::Bacs(
+                            types::BankAccountDetailsBacs {
+                                account_number,
+                                sort_code,
+                            },
+                        ),
+                        payment_method_type: PaymentMethodType::Bacs,
+                        payment_method: PaymentMethod::BankDebit,
+                        account_id: account.account_id.clone().into(),
+                        account_type: account.account_type.clone(),
+                        balance: None,
+                    });
+                }
+
+                if let Some(iban) = iban {
+                    bank_account_vec.push(types::BankAccountDetails {
+                        account_name: account.display_name,
+                        account_details: types::PaymentMethodTypeDetails::Sepa(
+                            types::BankAccountDetailsSepa {
+                                iban,
+                                bic: swift_bic.unwrap_or_default(),
+                            },
+                        ),
+                        payment_method_type: PaymentMethodType::Sepa,
+                        payment_method: PaymentMethod::BankDebit,
+                        account_id: account.account_id.into(),
+                        account_type: account.account_type,
+                        balance: None,
+                    });
+                }
+            });
+
+        Self {
+            response: Ok(types::BankAccountCredentialsResponse {
+                credentials: bank_account_vec,
+            }),
+            ..item.data
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerBalance {
+    pub currency: Option<Currency>,
+    pub available: Option<util_types::FloatMajorUnit>,
+    pub current: Option<util_types::FloatMajorUnit>,
+}
+
+pub type TruelayerBalanceResponse = TruelayerResults<TruelayerBalance>;
+
+impl
+    TryFrom<
+        types::ResponseRouterData<
+            types::api::auth_service::BankAccountBalance,
+            TruelayerBalanceResponse,
+            types::BankAccountBalanceRequest,
+            types::BankAccountBalanceResponse,
+        >,
+    > for types::BankAccountBalanceRouterData
+{
+    type Error = error_stack::Report<errors::ConnectorError>;
+    fn try_from(
+        item: types::ResponseRouterData<
+            types::api::auth_service::BankAccountBalance,
+            TruelayerBalanceResponse,
+            types::BankAccountBalanceRequest,
+            types::BankAccountBalanceResponse,
+        >,
+    ) -> Result<Self, Self::Error> {
+        let balance = item
+            .response
+            .results
+            .into_iter()
+            .next()
+            .ok_or(errors::ConnectorError::ResponseDeserializationFailed)
+            .attach_printable("no balance found in truelayer balance response")?;
+
+        Ok(Self {
+            response: Ok(types::BankAccountBalanceResponse {
+                account_id: item.data.request.account_id.clone(),
+                available_balance: balance.available,
+                current_balance: balance.current,
+                currency: balance.currency,
+            }),
+            ..item.data
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerInfo {
+    pub full_name: Option<Secret<String>>,
+}
+
+pub type TruelayerInfoResponse = TruelayerResults<TruelayerInfo>;
+
+impl
+    From<
+        types::ResponseRouterData<
+            types::api::auth_service::AccountOwnership,
+            TruelayerInfoResponse,
+            types::AccountOwnershipRequest,
+            types::AccountOwnershipResponse,
+        >,
+    > for types::AccountOwnershipRouterData
+{
+    fn from(
+        item: types::ResponseRouterData<
+            types::api::auth_service::AccountOwnership,
+            TruelayerInfoResponse,
+            types::AccountOwnershipRequest,
+            types::AccountOwnershipResponse,
+        >,
+    ) -> Self {
+        // TrueLayer only returns the names on the connection, so the match is scored locally
+        // against the best matching holder name
+        let name_match_score = item
+            .response
+            .results
+            .iter()
+            .filter_map(|info| info.full_name.as_ref())
+            .map(|full_name| {
+                crate::utils::get_name_match_score(
+                    item.data.request.legal_name.peek(),
+                    full_name.peek(),
+                )
+            })
+            .max();
+
+        Self {
+            response: Ok(types::AccountOwnershipResponse {
+                account_id: item.data.request.account_id.clone(),
+                name_match_score,
+                is_name_matched: name_match_score
+                    .is_some_and(|score| score >= crate::consts::NAME_MATCH_THRESHOLD),
+            }),
+            ..item.data
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TruelayerErrorResponse {
+    pub error: String,
+    pub error_description: Option<String>,
+}
@@ -3,3 +3,4 @@ pub const REQUEST_TIMEOUT_ERROR_CODE: &str = "TIMEOUT"; // timeout error code
 pub const REQUEST_TIMEOUT_ERROR_MESSAGE: &str = "Connector did not respond in specified time"; // error message for timed out request
 pub const NO_ERROR_CODE: &str = "No error code";
 pub const NO_ERROR_MESSAGE: &str = "No error message";
+pub const NAME_MATCH_THRESHOLD: u8 = 70; // minimum score for an account holder name to be considered a match
@@ -2,3 +2,4 @@ pub mod connector;
 pub mod consts;
 pub mod core;
 pub mod types;
+pub mod utils;
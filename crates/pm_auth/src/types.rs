@@ -2,8 +2,12 @@ pub mod api;
 
 use std::marker::PhantomData;
 
-use api::auth_service::{BankAccountCredentials, ExchangeToken, LinkToken, RecipientCreate};
-use common_enums::{CountryAlpha2, PaymentMethod, PaymentMethodType};
+use api::auth_service::{
+    AccountOwnership, BankAccountBalance, BankAccountCredentials, ExchangeToken, LinkToken,
+    PaymentInitiationCreate, PaymentInitiationLookup, PaymentInitiationReversal,
+    PaymentInitiationReversalSync, PaymentInitiationSync, RecipientCreate,
+};
+use common_enums::{CountryAlpha2, Currency, PaymentMethod, PaymentMethodType};
 use common_utils::{id_type, types};
 use masking::Secret;
 
@@ -112,6 +116,46 @@ pub type BankDetailsRouterData = PaymentAuthRouterData<
     BankAccountCredentialsResponse,
 >;
 
+#[derive(Debug, Clone)]
+pub struct BankAccountBalanceRequest {
+    pub access_token: Secret<String>,
+    pub account_id: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct BankAccountBalanceResponse {
+    pub account_id: Secret<String>,
+    /// Balance that can be debited right now, after pending transactions
+    pub available_balance: Option<types::FloatMajorUnit>,
+    pub current_balance: Option<types::FloatMajorUnit>,
+    pub currency: Option<Currency>,
+}
+
+pub type BankAccountBalanceRouterData = PaymentAuthRouterData<
+    BankAccountBalance,
+    BankAccountBalanceRequest,
+    BankAccountBalanceResponse,
+>;
+
+#[derive(Debug, Clone)]
+pub struct AccountOwnershipRequest {
+    pub access_token: Secret<String>,
+    pub account_id: Secret<String>,
+    /// Name the account holder name is matched against
+    pub legal_name: Secret<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountOwnershipResponse {
+    pub account_id: Secret<String>,
+    /// How closely the account holder name matches the requested name, between 0 and 100
+    pub name_match_score: Option<u8>,
+    pub is_name_matched: bool,
+}
+
+pub type AccountOwnershipRouterData =
+    PaymentAuthRouterData<AccountOwnership, AccountOwnershipRequest, AccountOwnershipResponse>;
+
 #[derive(Debug, Clone)]
 pub struct RecipientCreateRequest {
     pub name: String,
@@ -144,6 +188,112 @@ pub struct RecipientCreateAddress {
 pub type RecipientCreateRouterData =
     PaymentAuthRouterData<RecipientCreate, RecipientCreateRequest, RecipientCreateResponse>;
 
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationCreateRequest {
+    pub recipient_id: String,
+    pub reference: String,
+    pub amount: types::MinorUnit,
+    pub currency: Currency,
+    /// Verified account of the payer, used to restrict the payment to that account
+    pub payer_account: Option<PaymentMethodTypeDetails>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationSyncRequest {
+    pub payment_id: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationResponse {
+    pub payment_id: String,
+    pub status: PaymentInitiationStatus,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaymentInitiationStatus {
+    InputNeeded,
+    Initiated,
+    Authorising,
+    Established,
+    Executed,
+    Settled,
+    InsufficientFunds,
+    Blocked,
+    Rejected,
+    Failed,
+    Cancelled,
+}
+
+pub type PaymentInitiationCreateRouterData = PaymentAuthRouterData<
+    PaymentInitiationCreate,
+    PaymentInitiationCreateRequest,
+    PaymentInitiationResponse,
+>;
+
+pub type PaymentInitiationSyncRouterData = PaymentAuthRouterData<
+    PaymentInitiationSync,
+    PaymentInitiationSyncRequest,
+    PaymentInitiationResponse,
+>;
+
+/// Looks up a payment at the connector by the reference it was initiated with, used when the
+/// connector payment id was not received
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationLookupRequest {
+    pub reference: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationLookupResponse {
+    /// The payment initiated with the reference, if the connector received it
+    pub payment: Option<PaymentInitiationResponse>,
+}
+
+pub type PaymentInitiationLookupRouterData = PaymentAuthRouterData<
+    PaymentInitiationLookup,
+    PaymentInitiationLookupRequest,
+    PaymentInitiationLookupResponse,
+>;
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationReversalRequest {
+    pub payment_id: String,
+    /// Unique reference of the reversal, also used as the idempotency key with the connector
+    pub reversal_reference: String,
+    pub amount: types::MinorUnit,
+    pub currency: Currency,
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationReversalResponse {
+    pub reversal_id: String,
+    pub status: PaymentInitiationReversalStatus,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PaymentInitiationReversalStatus {
+    Initiated,
+    Executed,
+    Failed,
+}
+
+pub type PaymentInitiationReversalRouterData = PaymentAuthRouterData<
+    PaymentInitiationReversal,
+    PaymentInitiationReversalRequest,
+    PaymentInitiationReversalResponse,
+>;
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationReversalSyncRequest {
+    pub reversal_id: String,
+}
+
+pub type PaymentInitiationReversalSyncRouterData = PaymentAuthRouterData<
+    PaymentInitiationReversalSync,
+    PaymentInitiationReversalSyncRequest,
+    PaymentInitiationReversalResponse,
+>;
+
 pub type PaymentAuthLinkTokenType =
     dyn api::ConnectorIntegration<LinkToken, LinkTokenRequest, LinkTokenResponse>;
 
@@ -156,13 +306,58 @@ pub type PaymentAuthBankAccountDetailsType = dyn api::ConnectorIntegration<
     BankAccountCredentialsResponse,
 >;
 
+pub type PaymentAuthBankAccountBalanceType = dyn api::ConnectorIntegration<
+    BankAccountBalance,
+    BankAccountBalanceRequest,
+    BankAccountBalanceResponse,
+>;
+
+pub type PaymentAuthAccountOwnershipType = dyn api::ConnectorIntegration<
+    AccountOwnership,
+    AccountOwnershipRequest,
+    AccountOwnershipResponse,
+>;
+
 pub type PaymentInitiationRecipientCreateType =
     dyn api::ConnectorIntegration<RecipientCreate, RecipientCreateRequest, RecipientCreateResponse>;
 
+pub type PaymentInitiationPaymentCreateType = dyn api::ConnectorIntegration<
+    PaymentInitiationCreate,
+    PaymentInitiationCreateRequest,
+    PaymentInitiationResponse,
+>;
+
+pub type PaymentInitiationPaymentSyncType = dyn api::ConnectorIntegration<
+    PaymentInitiationSync,
+    PaymentInitiationSyncRequest,
+    PaymentInitiationResponse,
+>;
+
+pub type PaymentInitiationPaymentLookupType = dyn api::ConnectorIntegration<
+    PaymentInitiationLookup,
+    PaymentInitiationLookupRequest,
+    PaymentInitiationLookupResponse,
+>;
+
+pub type PaymentInitiationPaymentReversalType = dyn api::ConnectorIntegration<
+    PaymentInitiationReversal,
+    PaymentInitiationReversalRequest,
+    PaymentInitiationReversalResponse,
+>;
+
+pub type PaymentInitiationPaymentReversalSyncType = dyn api::ConnectorIntegration<
+    PaymentInitiationReversalSync,
+    PaymentInitiationReversalSyncRequest,
+    PaymentInitiationReversalResponse,
+>;
+
 #[derive(Clone, Debug, strum::EnumString, strum::Display)]
 #[strum(serialize_all = "snake_case")]
 pub enum PaymentMethodAuthConnectors {
     Plaid,
+    Truelayer,
+    #[cfg(feature = "dummy_connector")]
+    DummyBank,
 }
 
 #[derive(Debug, Clone)]
@@ -181,7 +376,7 @@ pub struct ErrorResponse {
 }
 
 impl ErrorResponse {
-    fn get_not_implemented() -> Self {
+    pub(crate) fn get_not_implemented() -> Self {
         Self {
             code: "IR_00".to_string(),
             message: "This API is under development and will be made available soon.".to_string(),
@@ -218,6 +413,11 @@ pub enum ConnectorAuthType {
         client_id: Secret<String>,
         secret: Secret<String>,
     },
+    SignatureKey {
+        client_id: Secret<String>,
+        secret: Secret<String>,
+        redirect_uri: Secret<String>,
+    },
     #[default]
     NoKey,
 }
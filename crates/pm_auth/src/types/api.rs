@@ -66,6 +66,20 @@ pub trait ConnectorIntegration<T, Req, Resp>: ConnectorIntegrationAny<T, Req, Re
         Ok(data.clone())
     }
 
+    /// Builds the response for a flow that the connector serves without an outbound request,
+    /// i.e. when `build_request` returns `None`.
+    fn handle_local_response(
+        &self,
+        data: &super::PaymentAuthRouterData<T, Req, Resp>,
+    ) -> CustomResult<super::PaymentAuthRouterData<T, Req, Resp>, ConnectorError>
+    where
+        T: Clone,
+        Req: Clone,
+        Resp: Clone,
+    {
+        Ok(data.clone())
+    }
+
     fn get_error_response(
         &self,
         _res: auth_types::Response,
@@ -1,7 +1,11 @@
 use crate::types::{
-    BankAccountCredentialsRequest, BankAccountCredentialsResponse, ExchangeTokenRequest,
-    ExchangeTokenResponse, LinkTokenRequest, LinkTokenResponse, RecipientCreateRequest,
-    RecipientCreateResponse,
+    AccountOwnershipRequest, AccountOwnershipResponse, BankAccountBalanceRequest,
+    BankAccountBalanceResponse, BankAccountCredentialsRequest, BankAccountCredentialsResponse,
+    ExchangeTokenRequest, ExchangeTokenResponse, LinkTokenRequest, LinkTokenResponse,
+    PaymentInitiationCreateRequest, PaymentInitiationLookupRequest,
+    PaymentInitiationLookupResponse, PaymentInitiationResponse, PaymentInitiationReversalRequest,
+    PaymentInitiationReversalResponse, PaymentInitiationReversalSyncRequest,
+    PaymentInitiationSyncRequest, RecipientCreateRequest, RecipientCreateResponse,
 };
 
 pub trait AuthService:
@@ -9,10 +13,21 @@ pub trait AuthService:
     + AuthServiceLinkToken
     + AuthServiceExchangeToken
     + AuthServiceBankAccountCredentials
+    + AuthServiceBankAccountBalance
+    + AuthServiceAccountOwnership
 {
 }
 
-pub trait PaymentInitiation: super::ConnectorCommon + PaymentInitiationRecipientCreate {}
+pub trait PaymentInitiation:
+    super::ConnectorCommon
+    + PaymentInitiationRecipientCreate
+    + PaymentInitiationPaymentCreate
+    + PaymentInitiationPaymentSync
+    + PaymentInitiationPaymentLookup
+    + PaymentInitiationPaymentReversal
+    + PaymentInitiationPaymentReversalSync
+{
+}
 
 #[derive(Debug, Clone)]
 pub struct LinkToken;
@@ -42,6 +57,26 @@ pub trait AuthServiceBankAccountCredentials:
 {
 }
 
+#[derive(Debug, Clone)]
+pub struct BankAccountBalance;
+
+pub trait AuthServiceBankAccountBalance:
+    super::ConnectorIntegration<
+    BankAccountBalance,
+    BankAccountBalanceRequest,
+    BankAccountBalanceResponse,
+>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct AccountOwnership;
+
+pub trait AuthServiceAccountOwnership:
+    super::ConnectorIntegration<AccountOwnership, AccountOwnershipRequest, AccountOwnershipResponse>
+{
+}
+
 #[derive(Debug, Clone)]
 pub struct RecipientCreate;
 
@@ -49,3 +84,63 @@ pub trait PaymentInitiationRecipientCreate:
     super::ConnectorIntegration<RecipientCreate, RecipientCreateRequest, RecipientCreateResponse>
 {
 }
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationCreate;
+
+pub trait PaymentInitiationPaymentCreate:
+    super::ConnectorIntegration<
+    PaymentInitiationCreate,
+    PaymentInitiationCreateRequest,
+    PaymentInitiationResponse,
+>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationSync;
+
+pub trait PaymentInitiationPaymentSync:
+    super::ConnectorIntegration<
+    PaymentInitiationSync,
+    PaymentInitiationSyncRequest,
+    PaymentInitiationResponse,
+>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationLookup;
+
+pub trait PaymentInitiationPaymentLookup:
+    super::ConnectorIntegration<
+    PaymentInitiationLookup,
+    PaymentInitiationLookupRequest,
+    PaymentInitiationLookupResponse,
+>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationReversal;
+
+pub trait PaymentInitiationPaymentReversal:
+    super::ConnectorIntegration<
+    PaymentInitiationReversal,
+    PaymentInitiationReversalRequest,
+    PaymentInitiationReversalResponse,
+>
+{
+}
+
+#[derive(Debug, Clone)]
+pub struct PaymentInitiationReversalSync;
+
+pub trait PaymentInitiationPaymentReversalSync:
+    super::ConnectorIntegration<
+    PaymentInitiationReversalSync,
+    PaymentInitiationReversalSyncRequest,
+    PaymentInitiationReversalResponse,
+>
+{
+}
@@ -0,0 +1,85 @@
+use std::collections::HashSet;
+
+fn get_name_tokens(name: &str) -> Vec<String> {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+/// Splits the holder name of a joint account into the names of the individual holders
+fn get_holder_names(account_holder_name: &str) -> Vec<HashSet<String>> {
+    account_holder_name
+        .split(['&', '/', '+'])
+        .flat_map(|holder_name| {
+            get_name_tokens(holder_name)
+                .split(|token| token == "and")
+                .map(|tokens| tokens.iter().cloned().collect::<HashSet<_>>())
+                .collect::<Vec<_>>()
+        })
+        .filter(|holder_tokens| !holder_tokens.is_empty())
+        .collect()
+}
+
+/// Scores how closely an account holder name matches the expected name, between 0 and 100.
+///
+/// The holder name of a joint account is split into the names of the individual holders, and the
+/// best matching holder is scored. The score is the share of the expected name's tokens found in
+/// the holder's name, so that additional middle names do not lower the score. A single letter
+/// token matches any token starting with it, to account for initials.
+pub fn get_name_match_score(expected_name: &str, account_holder_name: &str) -> u8 {
+    let expected_tokens = get_name_tokens(expected_name);
+
+    get_holder_names(account_holder_name)
+        .iter()
+        .map(|holder_tokens| get_holder_name_match_score(&expected_tokens, holder_tokens))
+        .max()
+        .unwrap_or(0)
+}
+
+fn get_holder_name_match_score(expected_tokens: &[String], holder_tokens: &HashSet<String>) -> u8 {
+    if expected_tokens.is_empty() {
+        return 0;
+    }
+
+    let matched_tokens = expected_tokens
+        .iter()
+        .filter(|expected| {
+            holder_tokens.iter().any(|holder| {
+                holder == *expected
+                    || (expected.chars().count() == 1 && holder.starts_with(expected.as_str()))
+                    || (holder.chars().count() == 1 && expected.starts_with(holder.as_str()))
+            })
+        })
+        .count();
+
+    u8::try_from(matched_tokens * 100 / expected_tokens.len()).unwrap_or(u8::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_name_match_score() {
+        assert_eq!(get_name_match_score("John Doe", "DOE, JOHN"), 100);
+        assert_eq!(get_name_match_score("John Doe", "Mr John A Doe"), 100);
+        assert_eq!(get_name_match_score("John Doe", "J Doe"), 100);
+        assert_eq!(get_name_match_score("John Doe", "John Doe & Jane Doe"), 100);
+        assert_eq!(
+            get_name_match_score("John Doe", "Jane Doe and John Doe"),
+            100
+        );
+        assert_eq!(
+            get_name_match_score("John Doe", "John Smith & Jane Doe"),
+            50
+        );
+        assert_eq!(
+            get_name_match_score("John Doe", "John Smith / Jane Doe"),
+            50
+        );
+        assert_eq!(get_name_match_score("John Doe", "Jane Doe"), 50);
+        assert_eq!(get_name_match_score("John Doe", "Richard Roe"), 0);
+        assert_eq!(get_name_match_score("", "John Doe"), 0);
+    }
+}
@@ -15,11 +15,15 @@ use common_utils::{
 };
 use error_stack::{report, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, ListInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, ListInterface, LuaInterface, SetsInterface,
+        StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
         MultipleValues, RedisKey, RedisMap, RedisValue, Scanner, SetOptions, XCap, XReadResponse,
+        XReadValue,
     },
 };
 use futures::StreamExt;
@@ -30,6 +34,15 @@ use crate::{
     types::{DelReply, HsetnxReply, MsetnxReply, RedisEntryId, SaddReply, SetnxReply},
 };
 
+/// Deletes the key only if it holds the value passed as the first argument
+const DELETE_IF_EQUALS_SCRIPT: &str = r#"
+if redis.call("GET", KEYS[1]) == ARGV[1] then
+    return redis.call("DEL", KEYS[1])
+else
+    return 0
+end
+"#;
+
 impl super::RedisConnectionPool {
     pub fn add_prefix(&self, key: &str) -> String {
         if self.key_prefix.is_empty() {
@@ -56,6 +69,22 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn set_key_without_expiry<V>(
+        &self,
+        key: &str,
+        value: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send + Sync,
+        V::Error: Into<fred::error::RedisError> + Send + Sync,
+    {
+        self.pool
+            .set(self.add_prefix(key), value, None, None, false)
+            .await
+            .change_context(errors::RedisError::SetFailed)
+    }
+
     pub async fn set_key_without_modifying_ttl<V>(
         &self,
         key: &str,
@@ -209,6 +238,19 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::DeleteFailed)
     }
 
+    /// Deletes the key only if it holds `value`, so that a lock is only released by its holder
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn delete_key_if_value_equals(
+        &self,
+        key: &str,
+        value: &str,
+    ) -> CustomResult<DelReply, errors::RedisError> {
+        self.pool
+            .eval(DELETE_IF_EQUALS_SCRIPT, self.add_prefix(key), value)
+            .await
+            .change_context(errors::RedisError::DeleteFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn set_key_with_expiry<V>(
         &self,
@@ -602,6 +644,22 @@ impl super::RedisConnectionPool {
             })
     }
 
+    /// Reads the entries of a stream whose ids lie between `start` and `end`, both inclusive.
+    /// `-` and `+` can be used to denote the smallest and the greatest ids in the stream.
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn stream_read_range(
+        &self,
+        stream: &str,
+        start: &str,
+        end: &str,
+        count: Option<u64>,
+    ) -> CustomResult<Vec<XReadValue<String, String, String>>, errors::RedisError> {
+        self.pool
+            .xrange_values(self.add_prefix(stream), start, end, count)
+            .await
+            .change_context(errors::RedisError::StreamReadFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_read_with_options<K, Ids>(
         &self,
@@ -320,6 +320,82 @@ impl ProcessTrackerWorkflows<routes::SessionState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::PaymentMethodStatusUpdateWorkflow => Ok(Box::new(
                     workflows::payment_method_status_update::PaymentMethodStatusUpdateWorkflow,
                 )),
+                storage::ProcessTrackerRunner::PayoutBatchWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_batch::PayoutBatchWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run payout batch workflow when payouts feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::AutoCaptureWorkflow => {
+                    Ok(Box::new(workflows::auto_capture::AutoCaptureWorkflow))
+                }
+                storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow => Ok(Box::new(
+                    workflows::authorization_expiry::AuthorizationExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::VaultKeyRotationWorkflow => Ok(Box::new(
+                    workflows::vault_key_rotation::VaultKeyRotationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentMethodMigrationWorkflow => Ok(Box::new(
+                    workflows::payment_method_migration::PaymentMethodMigrationWorkflow,
+                )),
+                storage::ProcessTrackerRunner::AccountUpdaterWorkflow => {
+                    Ok(Box::new(workflows::account_updater::AccountUpdaterWorkflow))
+                }
+                storage::ProcessTrackerRunner::AccountUpdaterResultsWorkflow => Ok(Box::new(
+                    workflows::account_updater::AccountUpdaterResultsWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PaymentMethodHealthSweepWorkflow => Ok(Box::new(
+                    workflows::payment_method_status_update::PaymentMethodHealthSweepWorkflow,
+                )),
+                storage::ProcessTrackerRunner::DataSubjectErasureWorkflow => {
+                    #[cfg(all(
+                        feature = "olap",
+                        any(feature = "v1", feature = "v2"),
+                        not(feature = "customer_v2")
+                    ))]
+                    {
+                        Ok(Box::new(
+                            workflows::data_subject_erasure::DataSubjectErasureWorkflow,
+                        ))
+                    }
+                    #[cfg(not(all(
+                        feature = "olap",
+                        any(feature = "v1", feature = "v2"),
+                        not(feature = "customer_v2")
+                    )))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                                "Cannot run data subject erasure workflow when olap feature is disabled",
+                            )
+                    }
+                }
+                storage::ProcessTrackerRunner::PayByBankReversalSyncWorkflow => Ok(Box::new(
+                    workflows::pay_by_bank_reversal_sync::PayByBankReversalSyncWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayByBankPaymentSyncWorkflow => Ok(Box::new(
+                    workflows::pay_by_bank_payment_sync::PayByBankPaymentSyncWorkflow,
+                )),
+                storage::ProcessTrackerRunner::PayoutScheduleWorkflow => {
+                    #[cfg(feature = "payouts")]
+                    {
+                        Ok(Box::new(workflows::payout_schedule::PayoutScheduleWorkflow))
+                    }
+                    #[cfg(not(feature = "payouts"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run payout schedule workflow when payouts feature is disabled",
+                        )
+                    }
+                }
             }
         };
 
@@ -205,7 +205,7 @@ pub struct PaymentMethodData {
     pub created: Option<time::PrimitiveDateTime>,
 }
 
-#[derive(Default, Serialize, PartialEq, Eq)]
+#[derive(Debug, Default, Serialize, PartialEq, Eq)]
 pub struct CardDetails {
     pub country: Option<String>,
     pub last4: Option<String>,
@@ -835,6 +835,10 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::AddressNotFound => SC::AddressNotFound,
+            Self::InvalidRequestData { message } => SC::InvalidRequestData {
+                message: message.to_owned(),
+            },
         }
     }
 }
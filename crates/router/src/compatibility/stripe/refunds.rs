@@ -49,8 +49,16 @@ pub async fn refund_create(
         state.into_inner(),
         &req,
         create_refund_req,
-        |state, auth, req, _| {
-            refunds::refund_create_core(state, auth.merchant_account, None, auth.key_store, req)
+        |state, auth, req, req_state| {
+            refunds::refund_create_core(
+                state,
+                req_state,
+                auth.merchant_account,
+                None,
+                auth.key_store,
+                req,
+                None,
+            )
         },
         &auth::HeaderAuth(auth::ApiKeyAuth),
         api_locking::LockAction::NotApplicable,
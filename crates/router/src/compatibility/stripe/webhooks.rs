@@ -12,7 +12,8 @@ use router_env::logger;
 use serde::Serialize;
 
 use super::{
-    payment_intents::types::StripePaymentIntentResponse, refunds::types::StripeRefundResponse,
+    customers::types::CardDetails, payment_intents::types::StripePaymentIntentResponse,
+    refunds::types::StripeRefundResponse,
 };
 use crate::{
     core::{
@@ -87,6 +88,7 @@ pub enum StripeWebhookObject {
     Mandate(StripeMandateResponse),
     #[cfg(feature = "payouts")]
     Payout(StripePayoutResponse),
+    PaymentMethod(StripePaymentMethodResponse),
 }
 
 #[derive(Serialize, Debug)]
@@ -107,6 +109,15 @@ pub struct StripeMandateResponse {
     pub payment_method: String,
 }
 
+#[derive(Serialize, Debug)]
+pub struct StripePaymentMethodResponse {
+    pub id: String,
+    pub object: &'static str,
+    pub customer: Option<common_utils::id_type::CustomerId>,
+    pub card: Option<CardDetails>,
+    pub created: Option<i64>,
+}
+
 #[cfg(feature = "payouts")]
 #[derive(Clone, Serialize, Debug)]
 pub struct StripePayoutResponse {
@@ -238,6 +249,35 @@ impl From<api_models::mandates::MandateResponse> for StripeMandateResponse {
     }
 }
 
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+impl From<api_models::payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: api_models::payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            object: "payment_method",
+            customer: res.customer_id,
+            card: res.card.map(CardDetails::from),
+            created: res.created.map(|t| t.assume_utc().unix_timestamp()),
+        }
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+impl From<api_models::payment_methods::PaymentMethodResponse> for StripePaymentMethodResponse {
+    fn from(res: api_models::payment_methods::PaymentMethodResponse) -> Self {
+        Self {
+            id: res.payment_method_id,
+            object: "payment_method",
+            customer: Some(res.customer_id),
+            card: None,
+            created: res.created.map(|t| t.assume_utc().unix_timestamp()),
+        }
+    }
+}
+
 impl From<MandateStatus> for StripeMandateStatus {
     fn from(status: MandateStatus) -> Self {
         match status {
@@ -296,6 +336,8 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::PayoutProcessing => "payout.created",
         api_models::enums::EventType::PayoutExpired => "payout.failed",
         api_models::enums::EventType::PayoutReversed => "payout.reconciliation_completed",
+        api_models::enums::EventType::PaymentMethodUpdated
+        | api_models::enums::EventType::PaymentMethodHealthUpdated => "payment_method.updated",
     }
 }
 
@@ -338,6 +380,9 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             }
             #[cfg(feature = "payouts")]
             api::OutgoingWebhookContent::PayoutDetails(payout) => Self::Payout(payout.into()),
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod((*payment_method).into())
+            }
         }
     }
 }
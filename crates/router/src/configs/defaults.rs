@@ -73,6 +73,16 @@ impl Default for super::settings::Locker {
             //Time to live for storage entries in locker
             ttl_for_storage_in_secs: 60 * 60 * 24 * 365 * 7,
             decryption_scheme: Default::default(),
+            embedded_vault: false,
+        }
+    }
+}
+
+impl Default for super::settings::EmbeddedVault {
+    fn default() -> Self {
+        Self {
+            fingerprint_secret: Default::default(),
+            key_rotation_batch_size: 100,
         }
     }
 }
@@ -106,6 +116,46 @@ impl Default for super::settings::Refund {
     }
 }
 
+impl Default for super::settings::AuthorizationValidity {
+    fn default() -> Self {
+        Self {
+            default_validity_in_hours: 168,
+            connectors: HashMap::new(),
+            card_networks: HashMap::new(),
+            expiry_buffer_in_minutes: 60,
+        }
+    }
+}
+
+impl Default for super::settings::AccountUpdater {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            provider: external_services::account_updater::AccountUpdaterConfig::default(),
+            // Every day at 02:00 UTC
+            schedule: String::from("0 2 * * *"),
+            expiry_window_in_months: 1,
+            batch_size: 1000,
+            results_poll_interval_in_secs: 3600,
+            max_results_poll_attempts: 72,
+            // Expired card, and expired card with pick up
+            reissue_decline_codes: HashSet::from(["54".to_string(), "33".to_string()]),
+        }
+    }
+}
+
+impl Default for super::settings::PaymentMethodHealth {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            // Every day at 03:00 UTC
+            schedule: String::from("0 3 * * *"),
+            expiry_window_in_days: 30,
+            max_consecutive_mit_declines: 3,
+        }
+    }
+}
+
 impl Default for super::settings::EphemeralConfig {
     fn default() -> Self {
         Self { validity: 1 }
@@ -284,6 +284,39 @@ impl SecretsHandler for settings::UserAuthMethodSettings {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::EmbeddedVault {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let embedded_vault = value.get_inner();
+
+        let fingerprint_secret = secret_management_client
+            .get_secret(embedded_vault.fingerprint_secret.clone())
+            .await?;
+
+        Ok(value.transition_state(|embedded_vault| Self {
+            fingerprint_secret,
+            ..embedded_vault
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::DataSubjectRequests {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let report_signing_key = secret_management_client
+            .get_secret(value.get_inner().report_signing_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|_| Self { report_signing_key }))
+    }
+}
+
 /// # Panics
 ///
 /// Will panic even if kms decryption fails for at least one field
@@ -382,6 +415,22 @@ pub(crate) async fn fetch_raw_secrets(
     .await
     .expect("Failed to decrypt user_auth_methods configs");
 
+    #[allow(clippy::expect_used)]
+    let embedded_vault = settings::EmbeddedVault::convert_to_raw_secret(
+        conf.embedded_vault,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt embedded vault configs");
+
+    #[allow(clippy::expect_used)]
+    let data_subject_requests = settings::DataSubjectRequests::convert_to_raw_secret(
+        conf.data_subject_requests,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt data subject requests configs");
+
     Settings {
         server: conf.server,
         master_database,
@@ -396,11 +445,16 @@ pub(crate) async fn fetch_raw_secrets(
         key_manager,
         #[cfg(feature = "olap")]
         replica_database,
+        #[cfg(feature = "olap")]
+        replica_reads: conf.replica_reads,
         secrets,
         locker: conf.locker,
+        embedded_vault,
+        data_subject_requests,
         connectors: conf.connectors,
         forex_api,
         refund: conf.refund,
+        authorization_validity: conf.authorization_validity,
         eph_key: conf.eph_key,
         scheduler: conf.scheduler,
         jwekey,
@@ -410,6 +464,8 @@ pub(crate) async fn fetch_raw_secrets(
         bank_config: conf.bank_config,
         api_keys,
         file_storage: conf.file_storage,
+        account_updater: conf.account_updater,
+        payment_method_health: conf.payment_method_health,
         tokenization: conf.tokenization,
         connector_customer: conf.connector_customer,
         #[cfg(feature = "dummy_connector")]
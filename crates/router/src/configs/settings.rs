@@ -12,6 +12,7 @@ use error_stack::ResultExt;
 #[cfg(feature = "email")]
 use external_services::email::EmailSettings;
 use external_services::{
+    account_updater::AccountUpdaterConfig,
     file_storage::FileStorageConfig,
     managers::{
         encryption_management::EncryptionManagementConfig,
@@ -56,14 +57,19 @@ pub struct Settings<S: SecretState> {
     pub master_database: SecretStateContainer<Database, S>,
     #[cfg(feature = "olap")]
     pub replica_database: SecretStateContainer<Database, S>,
+    #[cfg(feature = "olap")]
+    pub replica_reads: storage_impl::config::ReplicaReadConfig,
     pub redis: RedisSettings,
     pub log: Log,
     pub secrets: SecretStateContainer<Secrets, S>,
     pub locker: Locker,
+    pub embedded_vault: SecretStateContainer<EmbeddedVault, S>,
+    pub data_subject_requests: SecretStateContainer<DataSubjectRequests, S>,
     pub key_manager: SecretStateContainer<KeyManagerConfig, S>,
     pub connectors: Connectors,
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
+    pub authorization_validity: AuthorizationValidity,
     pub eph_key: EphemeralConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
@@ -74,6 +80,8 @@ pub struct Settings<S: SecretState> {
     pub bank_config: BankRedirectConfig,
     pub api_keys: SecretStateContainer<ApiKeys, S>,
     pub file_storage: FileStorageConfig,
+    pub account_updater: AccountUpdater,
+    pub payment_method_health: PaymentMethodHealth,
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub tokenization: TokenizationConfig,
@@ -222,6 +230,8 @@ pub struct KeyManagerConfig {
 pub struct GenericLink {
     pub payment_method_collect: GenericLinkEnvConfig,
     pub payout_link: GenericLinkEnvConfig,
+    #[serde(default)]
+    pub payment_method_manage: PaymentMethodManageLinkEnvConfig,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -246,6 +256,22 @@ impl Default for GenericLinkEnvConfig {
     }
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentMethodManageLinkEnvConfig {
+    pub expiry: u32,
+    pub ui_config: GenericLinkEnvUiConfig,
+}
+
+impl Default for PaymentMethodManageLinkEnvConfig {
+    fn default() -> Self {
+        Self {
+            expiry: 900,
+            ui_config: GenericLinkEnvUiConfig::default(),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct GenericLinkEnvUiConfig {
     pub logo: url::Url,
@@ -536,6 +562,24 @@ pub struct Locker {
     pub locker_enabled: bool,
     pub ttl_for_storage_in_secs: i64,
     pub decryption_scheme: DecryptionScheme,
+    /// Store payment methods in the application database instead of the external locker
+    pub embedded_vault: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct EmbeddedVault {
+    /// Secret used to compute the fingerprints of the stored cards, for detecting duplicates
+    pub fingerprint_secret: Secret<String>,
+    /// Number of entries re-encrypted by a key rotation task in one run
+    pub key_rotation_batch_size: u32,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct DataSubjectRequests {
+    /// Key used to sign the completion reports of data erasure requests
+    pub report_signing_key: Secret<String>,
 }
 
 #[derive(Debug, Deserialize, Clone, Default)]
@@ -554,6 +598,58 @@ pub struct Refund {
     pub max_age: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AuthorizationValidity {
+    /// Number of hours for which an authorization remains valid, if it is not configured for the
+    /// connector or the card network
+    pub default_validity_in_hours: u32,
+    /// Number of hours for which an authorization remains valid, keyed by connector name
+    pub connectors: HashMap<String, u32>,
+    /// Number of hours for which an authorization remains valid, keyed by card network
+    pub card_networks: HashMap<String, u32>,
+    /// Number of minutes before the authorization expires by which it must be captured,
+    /// re-authorized or voided
+    pub expiry_buffer_in_minutes: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct AccountUpdater {
+    /// Whether saved cards are submitted to the account updater
+    pub enabled: bool,
+    /// The account updater service the saved cards are submitted to
+    pub provider: AccountUpdaterConfig,
+    /// Cron expression (in UTC) on which the saved cards of enrolled merchants are submitted
+    pub schedule: String,
+    /// Number of months within which a saved card must expire for it to be submitted
+    pub expiry_window_in_months: u8,
+    /// Maximum number of saved cards submitted in a single batch
+    pub batch_size: u32,
+    /// Number of seconds between the attempts at retrieving the results of a batch
+    pub results_poll_interval_in_secs: u32,
+    /// Number of attempts at retrieving the results of a batch, after which the batch is failed
+    pub max_results_poll_attempts: u32,
+    /// Connector decline codes indicating that the card has expired or was reissued, for which a
+    /// saved card declined in a payment is submitted to the account updater
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub reissue_decline_codes: HashSet<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentMethodHealth {
+    /// Whether the health of saved payment methods is tracked
+    pub enabled: bool,
+    /// Cron expression (in UTC) on which the saved payment methods of enrolled merchants are swept
+    pub schedule: String,
+    /// Number of days within which a saved card must expire for it to be flagged as expiring
+    pub expiry_window_in_days: u16,
+    /// Number of consecutive declined merchant initiated payments after which a saved payment
+    /// method is flagged as unhealthy
+    pub max_consecutive_mit_declines: u8,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(default)]
 pub struct EphemeralConfig {
@@ -790,6 +886,10 @@ impl Settings<SecuredSecret> {
         }
         self.secrets.get_inner().validate()?;
         self.locker.validate()?;
+        if self.locker.embedded_vault {
+            self.embedded_vault.get_inner().validate()?;
+        }
+        self.data_subject_requests.get_inner().validate()?;
         self.connectors.validate("connectors")?;
 
         self.cors.validate()?;
@@ -806,6 +906,9 @@ impl Settings<SecuredSecret> {
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
 
+        self.account_updater.validate()?;
+        self.payment_method_health.validate()?;
+
         self.lock_settings.validate()?;
         self.events.validate()?;
 
@@ -821,6 +924,7 @@ impl Settings<SecuredSecret> {
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
         self.generic_link.payment_method_collect.validate()?;
         self.generic_link.payout_link.validate()?;
+        self.generic_link.payment_method_manage.validate()?;
         Ok(())
     }
 }
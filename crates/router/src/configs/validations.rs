@@ -30,14 +30,16 @@ impl super::settings::Locker {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
 
-        when(!self.mock_locker && self.host.is_default_or_empty(), || {
+        let external_locker = !self.mock_locker && !self.embedded_vault;
+
+        when(external_locker && self.host.is_default_or_empty(), || {
             Err(ApplicationError::InvalidConfigurationValueError(
                 "locker host must not be empty when mock locker is disabled".into(),
             ))
         })?;
 
         when(
-            !self.mock_locker && self.basilisk_host.is_default_or_empty(),
+            external_locker && self.basilisk_host.is_default_or_empty(),
             || {
                 Err(ApplicationError::InvalidConfigurationValueError(
                     "basilisk host must not be empty when mock locker is disabled".into(),
@@ -47,6 +49,36 @@ impl super::settings::Locker {
     }
 }
 
+impl super::settings::EmbeddedVault {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.fingerprint_secret.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "embedded vault fingerprint secret must not be empty".into(),
+            ))
+        })?;
+
+        when(self.key_rotation_batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "embedded vault key rotation batch size must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::DataSubjectRequests {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.report_signing_key.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "data subject request report signing key must not be empty".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -192,3 +224,61 @@ impl super::settings::GenericLinkEnvConfig {
         })
     }
 }
+
+impl super::settings::PaymentMethodManageLinkEnvConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.expiry == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment method manage link's expiry should not be 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::AccountUpdater {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        self.provider
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+
+        common_utils::cron::CronSchedule::parse(&self.schedule).map_err(|_| {
+            ApplicationError::InvalidConfigurationValueError(
+                "account updater schedule must be a valid cron expression".into(),
+            )
+        })?;
+
+        when(self.batch_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater batch size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_results_poll_attempts == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "account updater results poll attempts must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentMethodHealth {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        common_utils::cron::CronSchedule::parse(&self.schedule).map_err(|_| {
+            ApplicationError::InvalidConfigurationValueError(
+                "payment method health schedule must be a valid cron expression".into(),
+            )
+        })?;
+
+        when(self.max_consecutive_mit_declines == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment method health max consecutive mit declines must be greater than 0".into(),
+            ))
+        })
+    }
+}
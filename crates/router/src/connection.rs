@@ -27,12 +27,36 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
 ) -> errors::CustomResult<
     PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
     storage_errors::StorageError,
+> {
+    get_read_connection(store, None).await
+}
+
+/// Fetches a connection to read `table` from, as per the read preference configured for the table
+pub async fn pg_connection_read_table<T: storage_impl::DatabaseStore>(
+    store: &T,
+    table: &str,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    storage_errors::StorageError,
+> {
+    get_read_connection(store, Some(table)).await
+}
+
+async fn get_read_connection<'a, T: storage_impl::DatabaseStore>(
+    store: &'a T,
+    table: Option<&str>,
+) -> errors::CustomResult<
+    PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    storage_errors::StorageError,
 > {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
-    let pool = store.get_replica_pool();
+    let pool = {
+        let _ = table;
+        store.get_replica_pool()
+    };
 
-    // If either one of these are true we need to get master pool.
+    // If either one of these are true reads are routed between the master and replica pools.
     //  1. Only OLTP is enabled.
     //  2. Both OLAP and OLTP is enabled.
     //  3. Both OLAP and OLTP is disabled.
@@ -41,7 +65,7 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
         all(feature = "olap", feature = "oltp"),
         all(not(feature = "olap"), not(feature = "oltp"))
     ))]
-    let pool = store.get_master_pool();
+    let pool = storage_impl::database::routing::get_read_pool(store, table).await;
 
     pool.get()
         .await
@@ -51,13 +75,20 @@ pub async fn pg_connection_read<T: storage_impl::DatabaseStore>(
 pub async fn pg_connection_write<T: storage_impl::DatabaseStore>(
     store: &T,
 ) -> errors::CustomResult<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    storage_impl::database::routing::WriteConnection<'_>,
     storage_errors::StorageError,
 > {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
+    // Reads made for the merchant after the write are not routed to a lagging replica
     pool.get()
         .await
         .change_context(storage_errors::StorageError::DatabaseConnectionError)
+        .map(|conn| {
+            storage_impl::database::routing::WriteConnection::new(
+                conn,
+                store.get_consistency_token().cloned(),
+            )
+        })
 }
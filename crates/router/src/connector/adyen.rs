@@ -230,7 +230,8 @@ impl ConnectorValidation for Adyen {
                 | PaymentMethodType::VietQr
                 | PaymentMethodType::Mifinity
                 | PaymentMethodType::LocalBankRedirect
-                | PaymentMethodType::OpenBankingPIS => {
+                | PaymentMethodType::OpenBankingPIS
+                | PaymentMethodType::PayByBank => {
                     capture_method_not_supported!(connector, capture_method, payment_method_type)
                 }
             },
@@ -2235,6 +2236,15 @@ impl api::FileUpload for Adyen {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "payout batch files cannot be uploaded to the connector".to_owned(),
+            })?,
+            api::FilePurpose::PaymentMethodMigration => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "payment method migration files cannot be uploaded to the connector"
+                        .to_owned(),
+                })?
+            }
         }
         Ok(())
     }
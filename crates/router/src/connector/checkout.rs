@@ -963,6 +963,15 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::PayoutBatch => Err(errors::ConnectorError::FileValidationFailed {
+                reason: "payout batch files cannot be uploaded to the connector".to_owned(),
+            })?,
+            api::FilePurpose::PaymentMethodMigration => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "payment method migration files cannot be uploaded to the connector"
+                        .to_owned(),
+                })?
+            }
         }
         Ok(())
     }
@@ -1609,10 +1609,17 @@ impl
         req: &types::PaymentsIncrementalAuthorizationRouterData,
         _connectors: &settings::Connectors,
     ) -> CustomResult<RequestContent, errors::ConnectorError> {
+        // A re-authorization is made for the total amount, an incremental authorization for the
+        // additional amount
+        let amount = if req.request.is_reauthorization {
+            req.request.total_amount
+        } else {
+            req.request.additional_amount
+        };
         let connector_router_data = cybersource::CybersourceRouterData::try_from((
             &self.get_currency_unit(),
             req.request.currency,
-            req.request.additional_amount,
+            amount,
             req,
         ))?;
         let connector_request =
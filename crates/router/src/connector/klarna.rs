@@ -629,7 +629,8 @@ impl
                         | common_enums::PaymentMethodType::DuitNow
                         | common_enums::PaymentMethodType::PromptPay
                         | common_enums::PaymentMethodType::VietQr
-                        | common_enums::PaymentMethodType::OpenBankingPIS,
+                        | common_enums::PaymentMethodType::OpenBankingPIS
+                        | common_enums::PaymentMethodType::PayByBank,
                     ) => Err(error_stack::report!(errors::ConnectorError::NotSupported {
                         message: payment_method_type.to_string(),
                         connector: "klarna",
@@ -1413,7 +1413,15 @@ impl TryFrom<&WellsfargoRouterData<&types::PaymentsIncrementalAuthorizationRoute
                         stored_credential_used: Some(true),
                     }),
                     merchant_intitiated_transaction: Some(MerchantInitiatedTransaction {
-                        reason: Some("5".to_owned()),
+                        // Reason 3 is a re-authorization, reason 5 an incremental authorization
+                        reason: Some(
+                            if item.router_data.request.is_reauthorization {
+                                "3"
+                            } else {
+                                "5"
+                            }
+                            .to_owned(),
+                        ),
                         previous_transaction_id: None,
                         original_authorized_amount: None,
                     }),
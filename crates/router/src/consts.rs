@@ -39,6 +39,11 @@ pub(crate) const UNSUPPORTED_ERROR_MESSAGE: &str = "Unsupported response type";
 pub(crate) const LOW_BALANCE_ERROR_MESSAGE: &str = "Insufficient balance in the payment method";
 pub(crate) const CONNECTOR_UNAUTHORIZED_ERROR: &str = "Authentication Error from the connector";
 pub(crate) const REFUND_VOIDED: &str = "Refund request has been voided.";
+pub(crate) const PRE_DISPUTE_ALERT_REFUND_REASON: &str = "pre_dispute_alert";
+pub(crate) const PAY_BY_BANK_RECONCILIATION_REQUIRED_MESSAGE: &str =
+    "No response received from the connector, the payment requires reconciliation";
+pub(crate) const PAY_BY_BANK_PAYMENT_NOT_INITIATED_MESSAGE: &str =
+    "The payment was not received by the connector";
 
 pub(crate) const CANNOT_CONTINUE_AUTH: &str =
     "Cannot continue with Authorization due to failed Liability Shift.";
@@ -136,3 +141,106 @@ pub const MAX_ALLOWED_AMOUNT: i64 = 999999999;
 //payment attempt default unified error code and unified error message
 pub const DEFAULT_UNIFIED_ERROR_CODE: &str = "UE_000";
 pub const DEFAULT_UNIFIED_ERROR_MESSAGE: &str = "Something went wrong";
+
+// Number of days a pre-dispute alert transaction date may differ from the payment creation date
+pub const PRE_DISPUTE_ALERT_TRANSACTION_DATE_TOLERANCE_IN_DAYS: i64 = 2;
+
+// Daily refund amount counters are keyed by the UTC date and expire after 2 days = 172800 seconds
+pub const REFUND_POLICY_DAILY_LIMIT_TTL: i64 = 172800;
+
+// Hash field that tracks the total amount refunded under a profile in a day
+pub(crate) const REFUND_POLICY_DAILY_TOTAL_FIELD: &str = "total";
+
+// Max size of a payout batch file, 10 Megabytes (MB)
+pub const PAYOUT_BATCH_MAX_FILE_SIZE: i32 = 10000000;
+
+// Number of payout batch rows processed before the progress of the batch is saved
+#[cfg(feature = "payouts")]
+pub const PAYOUT_BATCH_CHUNK_SIZE: i32 = 100;
+
+// Number of times the processing of a payout batch is retried before the batch is marked as failed
+#[cfg(feature = "payouts")]
+pub const PAYOUT_BATCH_MAX_RETRIES: i32 = 5;
+
+// Delay in seconds before the processing of a payout batch is retried, multiplied by the number of
+// retries made so far
+#[cfg(feature = "payouts")]
+pub const PAYOUT_BATCH_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+// Max size of a payment method migration file, 100 Megabytes (MB)
+pub const PAYMENT_METHOD_MIGRATION_MAX_FILE_SIZE: i32 = 100000000;
+
+// Number of records of a payment method migration file processed before the progress is saved
+pub const PAYMENT_METHOD_MIGRATION_CHUNK_SIZE: i32 = 100;
+
+// Number of chunks processed by a single run of the payment method migration workflow, which
+// reads the migration file once per run
+pub const PAYMENT_METHOD_MIGRATION_CHUNKS_PER_RUN: i32 = 20;
+
+// Number of records of a payment method migration file migrated concurrently by default
+pub const DEFAULT_PAYMENT_METHOD_MIGRATION_PARALLELISM: u8 = 10;
+
+// Max number of records of a payment method migration file migrated concurrently
+pub const MAX_PAYMENT_METHOD_MIGRATION_PARALLELISM: u8 = 50;
+
+// Number of times a failed run of the payment method migration workflow is retried before the
+// migration is marked as failed
+pub const PAYMENT_METHOD_MIGRATION_MAX_RETRIES: i32 = 5;
+
+// Delay in seconds before a failed run of the payment method migration workflow is retried,
+// multiplied by the number of retries made so far
+pub const PAYMENT_METHOD_MIGRATION_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+// Number of times the erasure of the data of a customer is retried before the request is marked
+// as failed
+pub const DATA_SUBJECT_ERASURE_MAX_RETRIES: i32 = 5;
+
+// Delay in seconds before the erasure of the data of a customer is retried, multiplied by the
+// number of retries made so far
+pub const DATA_SUBJECT_ERASURE_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+// Number of times a failed auto-capture of a payment is retried
+pub const AUTO_CAPTURE_MAX_RETRIES: i32 = 5;
+
+// Delay in seconds before a failed auto-capture is retried, multiplied by the number of retries
+// made so far
+pub const AUTO_CAPTURE_RETRY_DELAY_IN_SECONDS: i64 = 600;
+
+// Number of times the re-encryption of the data stored with a rotated vault data key is retried
+// before the rotation is marked as failed
+pub const VAULT_KEY_ROTATION_MAX_RETRIES: i32 = 5;
+
+// Delay in seconds before the re-encryption of the data stored with a rotated vault data key is
+// retried, multiplied by the number of retries made so far
+pub const VAULT_KEY_ROTATION_RETRY_DELAY_IN_SECONDS: i64 = 300;
+
+// Number of times the handling of an expiring authorization is retried, as long as the
+// authorization has not lapsed
+pub const AUTHORIZATION_EXPIRY_MAX_RETRIES: i32 = 5;
+
+// Delay in seconds before the handling of an expiring authorization is retried, multiplied by the
+// number of retries made so far
+pub const AUTHORIZATION_EXPIRY_RETRY_DELAY_IN_SECONDS: i64 = 120;
+
+// Number of times the status of a pending reversal of a bank payment is synced with the connector
+pub const PAY_BY_BANK_REVERSAL_SYNC_MAX_RETRIES: i32 = 10;
+
+// Delay in seconds before the status of a pending reversal of a bank payment is synced, multiplied
+// by the number of syncs made so far
+pub const PAY_BY_BANK_REVERSAL_SYNC_DELAY_IN_SECONDS: i64 = 300;
+
+// Number of times the status of a processing bank payment is synced with the connector
+pub const PAY_BY_BANK_PAYMENT_SYNC_MAX_RETRIES: i32 = 10;
+
+// Delay in seconds before the status of a processing bank payment is synced, multiplied by the
+// number of syncs made so far
+pub const PAY_BY_BANK_PAYMENT_SYNC_DELAY_IN_SECONDS: i64 = 300;
+
+// Max number of segments a customer can be part of
+pub const CUSTOMER_SEGMENTS_MAX_COUNT: usize = 20;
+
+// Max length of a customer segment
+pub const CUSTOMER_SEGMENT_MAX_LENGTH: usize = 64;
+
+// Max length of the label of a customer address
+pub const CUSTOMER_ADDRESS_LABEL_MAX_LENGTH: usize = 64;
@@ -13,6 +13,12 @@ pub mod connector_onboarding;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod currency;
 pub mod customers;
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+pub mod data_subject_requests;
 pub mod disputes;
 pub mod encryption;
 pub mod errors;
@@ -33,6 +39,8 @@ pub mod payout_link;
 pub mod payouts;
 pub mod pm_auth;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_config;
@@ -1220,6 +1220,11 @@ impl<'a> ConnectorAuthTypeAndMetadataValidation<'a> {
                 dummyconnector::transformers::DummyConnectorAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            #[cfg(feature = "dummy_connector")]
+            api_enums::Connector::DummyBank => {
+                pm_auth_types::ConnectorAuthType::foreign_try_from(self.auth_type.clone())?;
+                Ok(())
+            }
             api_enums::Connector::Aci => {
                 aci::transformers::AciAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -1453,6 +1458,10 @@ impl<'a> ConnectorAuthTypeAndMetadataValidation<'a> {
                 stripe::transformers::StripeAuthType::try_from(self.auth_type)?;
                 Ok(())
             }
+            api_enums::Connector::Truelayer => {
+                pm_auth_types::ConnectorAuthType::foreign_try_from(self.auth_type.clone())?;
+                Ok(())
+            }
             api_enums::Connector::Trustpay => {
                 trustpay::transformers::TrustpayAuthType::try_from(self.auth_type)?;
                 Ok(())
@@ -3336,6 +3345,10 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(auto_capture_policy) = &self.auto_capture_policy {
+            helpers::validate_auto_capture_policy(auto_capture_policy)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3446,6 +3459,11 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
                     .map(Into::into),
                 tax_connector_id: self.tax_connector_id,
                 is_tax_connector_enabled: self.is_tax_connector_enabled,
+                refund_policy: self.refund_policy.map(ForeignInto::foreign_into),
+                auto_capture_policy: self.auto_capture_policy.map(ForeignInto::foreign_into),
+                authorization_expiry_action: self.authorization_expiry_action,
+                is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+                is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
                 always_collect_billing_details_from_wallet_connector: self
                     .always_collect_billing_details_from_wallet_connector,
                 always_collect_shipping_details_from_wallet_connector: self
@@ -3465,6 +3483,10 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(auto_capture_policy) = &self.auto_capture_policy {
+            helpers::validate_auto_capture_policy(auto_capture_policy)?;
+        }
+
         // Generate a unique profile id
         // TODO: the profile_id should be generated from the profile_name
         let profile_id = common_utils::generate_profile_id_of_default_length();
@@ -3554,6 +3576,11 @@ impl BusinessProfileCreateBridge for api::BusinessProfileCreate {
                 default_fallback_routing: None,
                 tax_connector_id: self.tax_connector_id,
                 is_tax_connector_enabled: self.is_tax_connector_enabled,
+                refund_policy: self.refund_policy.map(ForeignInto::foreign_into),
+                auto_capture_policy: self.auto_capture_policy.map(ForeignInto::foreign_into),
+                authorization_expiry_action: self.authorization_expiry_action,
+                is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+                is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
             },
         ))
     }
@@ -3703,6 +3730,10 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(auto_capture_policy) = &self.auto_capture_policy {
+            helpers::validate_auto_capture_policy(auto_capture_policy)?;
+        }
+
         if let Some(intent_fulfillment_expiry) = self.intent_fulfillment_time {
             helpers::validate_intent_fulfillment_expiry(intent_fulfillment_expiry)?;
         }
@@ -3797,6 +3828,11 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
                     .always_collect_shipping_details_from_wallet_connector,
                 tax_connector_id: self.tax_connector_id,
                 is_tax_connector_enabled: self.is_tax_connector_enabled,
+                refund_policy: self.refund_policy.map(ForeignInto::foreign_into),
+                auto_capture_policy: self.auto_capture_policy.map(ForeignInto::foreign_into),
+                authorization_expiry_action: self.authorization_expiry_action,
+                is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+                is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
             },
         )))
     }
@@ -3814,6 +3850,10 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
 
+        if let Some(auto_capture_policy) = &self.auto_capture_policy {
+            helpers::validate_auto_capture_policy(auto_capture_policy)?;
+        }
+
         let webhook_details = self.webhook_details.map(ForeignInto::foreign_into);
 
         let payment_link_config = self
@@ -3889,6 +3929,11 @@ impl BusinessProfileUpdateBridge for api::BusinessProfileUpdate {
                     .always_collect_billing_details_from_wallet_connector,
                 always_collect_shipping_details_from_wallet_connector: self
                     .always_collect_shipping_details_from_wallet_connector,
+                refund_policy: self.refund_policy.map(ForeignInto::foreign_into),
+                auto_capture_policy: self.auto_capture_policy.map(ForeignInto::foreign_into),
+                authorization_expiry_action: self.authorization_expiry_action,
+                is_pre_debit_balance_check_enabled: self.is_pre_debit_balance_check_enabled,
+                is_account_ownership_check_enabled: self.is_account_ownership_check_enabled,
             },
         )))
     }
@@ -4204,10 +4249,14 @@ async fn process_open_banking_connectors(
 ) -> RouterResult<types::MerchantRecipientData> {
     let new_merchant_data = match additional_merchant_data {
         types::AdditionalMerchantData::OpenBankingRecipientData(merchant_data) => {
-            if connector_type != &api_enums::ConnectorType::PaymentProcessor {
+            if !matches!(
+                connector_type,
+                api_enums::ConnectorType::PaymentProcessor
+                    | api_enums::ConnectorType::PaymentMethodAuth
+            ) {
                 return Err(errors::ApiErrorResponse::InvalidConnectorConfiguration {
                     config:
-                        "OpenBanking connector for Payment Initiation should be a payment processor"
+                        "OpenBanking connector for Payment Initiation should be a payment processor or a payment method auth connector"
                             .to_string(),
                 }
                 .into());
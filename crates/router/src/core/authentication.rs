@@ -39,6 +39,7 @@ pub async fn perform_authentication(
     email: Option<common_utils::pii::Email>,
     webhook_url: String,
     three_ds_requestor_url: String,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> CustomResult<api::authentication::AuthenticationResponse, ApiErrorResponse> {
     let router_data = transformers::construct_authentication_router_data(
         merchant_id,
@@ -63,8 +64,14 @@ pub async fn perform_authentication(
     )?;
     let response =
         utils::do_auth_connector_call(state, authentication_connector.clone(), router_data).await?;
-    let authentication =
-        utils::update_trackers(state, response.clone(), authentication_data, None).await?;
+    let authentication = utils::update_trackers(
+        state,
+        response.clone(),
+        authentication_data,
+        None,
+        storage_scheme,
+    )
+    .await?;
     response
         .response
         .map_err(|err| ApiErrorResponse::ExternalConnectorError {
@@ -82,6 +89,7 @@ pub async fn perform_post_authentication(
     key_store: &domain::MerchantKeyStore,
     business_profile: domain::BusinessProfile,
     authentication_id: String,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
     let (authentication_connector, three_ds_connector_account) =
         utils::get_authentication_connector_data(state, key_store, &business_profile).await?;
@@ -96,6 +104,7 @@ pub async fn perform_post_authentication(
         .find_authentication_by_merchant_id_authentication_id(
             &business_profile.merchant_id,
             authentication_id.clone(),
+            storage_scheme,
         )
         .await
         .to_not_found_response(ApiErrorResponse::InternalServerError)
@@ -110,7 +119,7 @@ pub async fn perform_post_authentication(
         let router_data =
             utils::do_auth_connector_call(state, authentication_connector.to_string(), router_data)
                 .await?;
-        utils::update_trackers(state, router_data, authentication, None).await
+        utils::update_trackers(state, router_data, authentication, None, storage_scheme).await
     } else {
         Ok(authentication)
     }
@@ -124,6 +133,7 @@ pub async fn perform_pre_authentication(
     business_profile: &domain::BusinessProfile,
     acquirer_details: Option<types::AcquirerDetails>,
     payment_id: Option<common_utils::id_type::PaymentId>,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> CustomResult<storage::Authentication, ApiErrorResponse> {
     let (authentication_connector, three_ds_connector_account) =
         utils::get_authentication_connector_data(state, key_store, business_profile).await?;
@@ -139,6 +149,7 @@ pub async fn perform_pre_authentication(
             .get_mca_id()
             .ok_or(ApiErrorResponse::InternalServerError)
             .attach_printable("Error while finding mca_id from merchant_connector_account")?,
+        storage_scheme,
     )
     .await?;
 
@@ -157,9 +168,14 @@ pub async fn perform_pre_authentication(
         )
         .await?;
 
-        let updated_authentication =
-            utils::update_trackers(state, router_data, authentication, acquirer_details.clone())
-                .await?;
+        let updated_authentication = utils::update_trackers(
+            state,
+            router_data,
+            authentication,
+            acquirer_details.clone(),
+            storage_scheme,
+        )
+        .await?;
         // from version call response, we will get to know the maximum supported 3ds version.
         // If the version is not greater than or equal to 3DS 2.0, We should not do the successive pre authentication call.
         if !updated_authentication.is_separate_authn_required() {
@@ -180,5 +196,12 @@ pub async fn perform_pre_authentication(
     let router_data =
         utils::do_auth_connector_call(state, authentication_connector_name, router_data).await?;
 
-    utils::update_trackers(state, router_data, authentication, acquirer_details).await
+    utils::update_trackers(
+        state,
+        router_data,
+        authentication,
+        acquirer_details,
+        storage_scheme,
+    )
+    .await
 }
@@ -52,6 +52,7 @@ pub async fn update_trackers<F: Clone, Req>(
     router_data: RouterData<F, Req, AuthenticationResponseData>,
     authentication: storage::Authentication,
     acquirer_details: Option<super::types::AcquirerDetails>,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> RouterResult<storage::Authentication> {
     let authentication_update = match router_data.response {
         Ok(response) => match response {
@@ -156,6 +157,7 @@ pub async fn update_trackers<F: Clone, Req>(
         .update_authentication_by_merchant_id_authentication_id(
             authentication,
             authentication_update,
+            storage_scheme,
         )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -181,6 +183,7 @@ pub async fn create_new_authentication(
     profile_id: common_utils::id_type::ProfileId,
     payment_id: Option<common_utils::id_type::PaymentId>,
     merchant_connector_id: common_utils::id_type::MerchantConnectorAccountId,
+    storage_scheme: common_enums::MerchantStorageScheme,
 ) -> RouterResult<storage::Authentication> {
     let authentication_id =
         common_utils::generate_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
@@ -221,7 +224,7 @@ pub async fn create_new_authentication(
     };
     state
         .store
-        .insert_authentication(new_authorization)
+        .insert_authentication(new_authorization, storage_scheme)
         .await
         .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
             message: format!(
@@ -446,29 +446,38 @@ pub async fn generate_payment_fingerprint(
     merchant_id: common_utils::id_type::MerchantId,
     payment_method_data: Option<domain::PaymentMethodData>,
 ) -> CustomResult<Option<String>, errors::ApiErrorResponse> {
-    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, &merchant_id).await?;
+    if let Some(domain::PaymentMethodData::Card(card)) = payment_method_data.as_ref() {
+        generate_card_fingerprint(state, &merchant_id, &card.card_number).await
+    } else {
+        logger::error!("failed to retrieve card fingerprint");
+        Ok(None)
+    }
+}
 
-    Ok(
-        if let Some(domain::PaymentMethodData::Card(card)) = payment_method_data.as_ref() {
-            generate_fingerprint(
-                state,
-                StrongSecret::new(card.card_number.get_card_no()),
-                StrongSecret::new(merchant_fingerprint_secret),
-                api_models::enums::LockerChoice::HyperswitchCardVault,
-            )
-            .await
-            .attach_printable("error in pm fingerprint creation")
-            .map_or_else(
-                |error| {
-                    logger::error!(?error);
-                    None
-                },
-                Some,
-            )
-            .map(|payload| payload.card_fingerprint)
-        } else {
-            logger::error!("failed to retrieve card fingerprint");
+/// Generates the fingerprint of the card keyed with the fingerprint secret of the merchant, so
+/// that a card has the same fingerprint across the payments and saved payment methods of the
+/// merchant.
+pub async fn generate_card_fingerprint(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    card_number: &cards::CardNumber,
+) -> CustomResult<Option<String>, errors::ApiErrorResponse> {
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+
+    Ok(generate_fingerprint(
+        state,
+        StrongSecret::new(card_number.get_card_no()),
+        StrongSecret::new(merchant_fingerprint_secret),
+        api_models::enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .attach_printable("error in pm fingerprint creation")
+    .map_or_else(
+        |error| {
+            logger::error!(?error);
             None
         },
+        Some,
     )
+    .map(|payload| payload.card_fingerprint))
 }
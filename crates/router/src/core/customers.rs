@@ -1,6 +1,10 @@
 use api_models::customers::CustomerRequestWithEmail;
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-use common_utils::{crypto::Encryptable, types::Description};
+use common_utils::{
+    crypto::{Encryptable, OptionalEncryptableValue},
+    types::Description,
+    validation,
+};
 use common_utils::{
     errors::ReportSwitchExt,
     ext_traits::{AsyncExt, OptionExt},
@@ -10,10 +14,20 @@ use common_utils::{
 use error_stack::{report, ResultExt};
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 use masking::{Secret, SwitchStrategy};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use router_env::logger;
 use router_env::{instrument, tracing};
 
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use crate::core::payment_methods::cards::create_encrypted_data;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use crate::{
+    consts,
+    core::{data_subject_requests, payment_methods::cards},
+    routes::metrics,
+    types::storage::enums,
+    utils::CustomerAddress,
+};
 use crate::{
     core::errors::{self, StorageErrorExt},
     db::StorageInterface,
@@ -27,10 +41,6 @@ use crate::{
         transformers::ForeignFrom,
     },
 };
-#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
-use crate::{
-    core::payment_methods::cards, routes::metrics, types::storage::enums, utils::CustomerAddress,
-};
 
 pub const REDACTED: &str = "Redacted";
 
@@ -120,6 +130,10 @@ impl CustomerCreateBridge for customers::CustomerRequest {
         key_manager_state: &'a KeyManagerState,
         state: &'a SessionState,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse> {
+        // Validating the tax ids and segments before any of the customer data is written to Db
+        let tax_ids = encrypt_customer_tax_ids(state, key_store, self.tax_ids.as_ref()).await?;
+        let segments = validate_customer_segments(self.segments.as_ref())?;
+
         // Setting default billing address to Db
         let address = self.get_address();
         let merchant_id = merchant_account.get_id();
@@ -179,6 +193,8 @@ impl CustomerCreateBridge for customers::CustomerRequest {
             default_payment_method_id: None,
             updated_by: None,
             version: hyperswitch_domain_models::consts::API_VERSION,
+            tax_ids,
+            segments,
         })
     }
 
@@ -329,6 +345,143 @@ impl<'a> AddressStructForDbEntry<'a> {
     }
 }
 
+/// Prefix of the routing metadata keys carrying the segments of the customer of a payment, a
+/// customer in the `wholesale` segment gets the key `customer_segment.wholesale` set to `true`
+pub const CUSTOMER_SEGMENT_ROUTING_KEY_PREFIX: &str = "customer_segment";
+
+/// Validates the tax ids of a customer and encrypts them in their canonical form
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+async fn encrypt_customer_tax_ids(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    tax_ids: Option<&Vec<customers::CustomerTaxId>>,
+) -> errors::CustomResult<OptionalEncryptableValue, errors::CustomersErrorResponse> {
+    let Some(tax_ids) = tax_ids else {
+        return Ok(None);
+    };
+
+    let canonical_tax_ids = tax_ids
+        .iter()
+        .map(|tax_id| {
+            validation::validate_tax_id(tax_id.tax_id_type, tax_id.value.peek())
+                .change_context(errors::CustomersErrorResponse::InvalidRequestData {
+                    message: format!("Invalid tax id of type `{}`", tax_id.tax_id_type),
+                })
+                .map(|canonical_value| customers::CustomerTaxId {
+                    tax_id_type: tax_id.tax_id_type,
+                    value: Secret::new(canonical_value),
+                })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    cards::create_encrypted_data(state, key_store, canonical_tax_ids)
+        .await
+        .switch()
+        .attach_printable("Failed to encrypt the customer tax ids")
+        .map(Some)
+}
+
+/// Validates the segments of a customer, returning them in lowercase and without duplicates
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+fn validate_customer_segments(
+    segments: Option<&Vec<String>>,
+) -> errors::CustomResult<Option<Vec<String>>, errors::CustomersErrorResponse> {
+    let Some(segments) = segments else {
+        return Ok(None);
+    };
+
+    let mut validated_segments: Vec<String> = Vec::with_capacity(segments.len());
+    for segment in segments {
+        let segment = segment.trim().to_lowercase();
+        let is_valid_segment = !segment.is_empty()
+            && segment.len() <= consts::CUSTOMER_SEGMENT_MAX_LENGTH
+            && segment.chars().all(|character| {
+                character.is_ascii_alphanumeric() || matches!(character, '_' | '-')
+            });
+        if !is_valid_segment {
+            return Err(report!(errors::CustomersErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Customer segments must be 1 to {} characters long and contain only letters, digits, `_` or `-`",
+                    consts::CUSTOMER_SEGMENT_MAX_LENGTH
+                ),
+            }));
+        }
+        if !validated_segments.contains(&segment) {
+            validated_segments.push(segment);
+        }
+    }
+
+    if validated_segments.len() > consts::CUSTOMER_SEGMENTS_MAX_COUNT {
+        return Err(report!(
+            errors::CustomersErrorResponse::InvalidRequestData {
+                message: format!(
+                    "A customer can be part of at most {} segments",
+                    consts::CUSTOMER_SEGMENTS_MAX_COUNT
+                ),
+            }
+        ));
+    }
+
+    Ok(Some(validated_segments))
+}
+
+/// Fetches the segments of a customer for routing and surcharge rules. A failed lookup is logged
+/// and treated as the customer having no segments, so that it never fails the payment.
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub async fn get_customer_segments(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: Option<&id_type::CustomerId>,
+) -> Vec<String> {
+    let Some(customer_id) = customer_id else {
+        return Vec::new();
+    };
+
+    match state
+        .store
+        .find_customer_optional_by_customer_id_merchant_id(
+            &state.into(),
+            customer_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+    {
+        Ok(customer) => customer
+            .and_then(|customer| customer.segments)
+            .unwrap_or_default(),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the segments of the customer");
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(all(feature = "v2", feature = "customer_v2"))]
+pub async fn get_customer_segments(
+    _state: &SessionState,
+    _merchant_account: &domain::MerchantAccount,
+    _key_store: &domain::MerchantKeyStore,
+    _customer_id: Option<&id_type::CustomerId>,
+) -> Vec<String> {
+    Vec::new()
+}
+
+/// Segments of a customer already fetched by the flow, for routing and surcharge rules
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub fn get_segments_of_customer(customer: Option<&domain::Customer>) -> Vec<String> {
+    customer
+        .and_then(|customer| customer.segments.clone())
+        .unwrap_or_default()
+}
+
+#[cfg(all(feature = "v2", feature = "customer_v2"))]
+pub fn get_segments_of_customer(_customer: Option<&domain::Customer>) -> Vec<String> {
+    Vec::new()
+}
+
 struct MerchantReferenceIdForCustomer<'a> {
     merchant_reference_id: Option<&'a id_type::CustomerId>,
     merchant_id: &'a id_type::MerchantId,
@@ -683,6 +836,17 @@ pub async fn delete_customer(
         metadata: None,
         connector_customer: None,
         address_id: None,
+        tax_ids: Some(
+            cards::create_encrypted_data(
+                &state,
+                &key_store,
+                Vec::<customers::CustomerTaxId>::new(),
+            )
+            .await
+            .switch()
+            .attach_printable("Failed to encrypt the redacted customer tax ids")?,
+        ),
+        segments: None,
     };
     db.update_customer_by_customer_id_merchant_id(
         key_manager_state,
@@ -931,6 +1095,9 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
         state: &'a SessionState,
         domain_customer: &'a domain::Customer,
     ) -> errors::CustomResult<domain::Customer, errors::CustomersErrorResponse> {
+        let tax_ids = encrypt_customer_tax_ids(state, key_store, self.tax_ids.as_ref()).await?;
+        let segments = validate_customer_segments(self.segments.as_ref())?;
+
         let update_address_for_update_customer = AddressStructForDbUpdate {
             update_customer: self,
             merchant_account,
@@ -981,6 +1148,8 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
                     description: self.description.clone(),
                     connector_customer: None,
                     address_id: address.clone().map(|addr| addr.address_id),
+                    tax_ids,
+                    segments,
                 },
                 key_store,
                 merchant_account.storage_scheme,
@@ -1092,6 +1261,291 @@ impl CustomerUpdateBridge for customers::CustomerUpdateRequest {
     }
 }
 
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+fn validate_customer_address_label(
+    label: Option<&String>,
+) -> errors::CustomResult<(), errors::CustomersErrorResponse> {
+    match label {
+        Some(label) if label.len() > consts::CUSTOMER_ADDRESS_LABEL_MAX_LENGTH => Err(report!(
+            errors::CustomersErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The label of an address can be at most {} characters long",
+                    consts::CUSTOMER_ADDRESS_LABEL_MAX_LENGTH
+                ),
+            }
+        )),
+        _ => Ok(()),
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+fn get_customer_address_response(
+    address: &domain::Address,
+    customer_address_label: storage::CustomerAddressLabel,
+) -> customers::CustomerAddressResponse {
+    customers::CustomerAddressResponse {
+        address_id: customer_address_label.address_id,
+        customer_id: customer_address_label.customer_id,
+        address_type: customer_address_label.address_type,
+        label: customer_address_label.label,
+        address: api_models::payments::Address::from(address),
+        created_at: customer_address_label.created_at,
+        modified_at: customer_address_label.modified_at,
+    }
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip(state))]
+pub async fn create_customer_address(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    customer_id: id_type::CustomerId,
+    req: customers::CustomerAddressRequest,
+) -> errors::CustomerResponse<customers::CustomerAddressResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+
+    validate_customer_address_label(req.label.as_ref())?;
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .switch()?;
+
+    let customer_address = req
+        .get_domain_address(
+            &state,
+            req.address.clone(),
+            merchant_id,
+            &customer_id,
+            key_store.key.get_inner().peek(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .switch()
+        .attach_printable("Failed while encrypting address")?;
+
+    let address = db
+        .insert_address_for_customers(key_manager_state, customer_address, &key_store)
+        .await
+        .switch()
+        .attach_printable("Failed while inserting new address")?;
+
+    let now = common_utils::date_time::now();
+    let customer_address_label = db
+        .insert_customer_address_label(storage::CustomerAddressLabelNew {
+            address_id: address.address_id.clone(),
+            merchant_id: merchant_id.to_owned(),
+            customer_id,
+            address_type: req.address_type,
+            label: req.label,
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .switch()
+        .attach_printable("Failed while inserting the label of the address")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(&address, customer_address_label),
+    ))
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip(state))]
+pub async fn list_customer_addresses(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> errors::CustomerResponse<customers::CustomerAddressListResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    db.find_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        &req.customer_id,
+        merchant_account.get_id(),
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .switch()?;
+
+    let customer_address_labels = db
+        .list_customer_address_labels_by_merchant_id_customer_id(
+            merchant_account.get_id(),
+            &req.customer_id,
+        )
+        .await
+        .switch()?;
+
+    let addresses = futures::future::try_join_all(customer_address_labels.into_iter().map(
+        |customer_address_label| async {
+            db.find_address_by_address_id(
+                key_manager_state,
+                &customer_address_label.address_id,
+                &key_store,
+            )
+            .await
+            .switch()
+            .map(|address| get_customer_address_response(&address, customer_address_label))
+        },
+    ))
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerAddressListResponse { addresses },
+    ))
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip(state))]
+pub async fn retrieve_customer_address(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerAddressId,
+) -> errors::CustomerResponse<customers::CustomerAddressResponse> {
+    let db = state.store.as_ref();
+
+    let customer_address_label = db
+        .find_customer_address_label_by_merchant_id_customer_id_address_id(
+            merchant_account.get_id(),
+            &req.customer_id,
+            &req.address_id,
+        )
+        .await
+        .to_not_found_response(errors::CustomersErrorResponse::AddressNotFound)?;
+
+    let address = db
+        .find_address_by_address_id(&(&state).into(), &req.address_id, &key_store)
+        .await
+        .switch()?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(&address, customer_address_label),
+    ))
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip(state))]
+pub async fn update_customer_address(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    address_id: customers::CustomerAddressId,
+    req: customers::CustomerAddressRequest,
+) -> errors::CustomerResponse<customers::CustomerAddressResponse> {
+    let db = state.store.as_ref();
+
+    validate_customer_address_label(req.label.as_ref())?;
+
+    let customer_address_label = db
+        .find_customer_address_label_by_merchant_id_customer_id_address_id(
+            merchant_account.get_id(),
+            &address_id.customer_id,
+            &address_id.address_id,
+        )
+        .await
+        .to_not_found_response(errors::CustomersErrorResponse::AddressNotFound)?;
+
+    let address_update = req
+        .get_address_update(
+            &state,
+            req.address.clone(),
+            key_store.key.get_inner().peek(),
+            merchant_account.storage_scheme,
+            merchant_account.get_id().clone(),
+        )
+        .await
+        .switch()
+        .attach_printable("Failed while encrypting Address while Update")?;
+
+    let address = db
+        .update_address(
+            &(&state).into(),
+            address_id.address_id,
+            address_update,
+            &key_store,
+        )
+        .await
+        .switch()
+        .attach_printable("Failed while updating the address of the customer")?;
+
+    let customer_address_label = db
+        .update_customer_address_label(
+            customer_address_label,
+            storage::CustomerAddressLabelUpdate::Update {
+                address_type: req.address_type,
+                label: req.label,
+            },
+        )
+        .await
+        .switch()
+        .attach_printable("Failed while updating the label of the address")?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_customer_address_response(&address, customer_address_label),
+    ))
+}
+
+/// Removes an address from the addresses of the customer and redacts it, as the address may still
+/// be referenced by earlier payments
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip(state))]
+pub async fn delete_customer_address(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerAddressId,
+) -> errors::CustomerResponse<customers::CustomerAddressDeleteResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+
+    let customer_address_label = db
+        .delete_customer_address_label_by_merchant_id_customer_id_address_id(
+            merchant_account.get_id(),
+            &req.customer_id,
+            &req.address_id,
+        )
+        .await
+        .to_not_found_response(errors::CustomersErrorResponse::AddressNotFound)?;
+
+    let redacted_address_update = data_subject_requests::get_redacted_address_update(
+        key_manager_state,
+        &merchant_account,
+        &key_store,
+    )
+    .await
+    .switch()?;
+
+    db.update_address(
+        key_manager_state,
+        customer_address_label.address_id.clone(),
+        redacted_address_update,
+        &key_store,
+    )
+    .await
+    .switch()
+    .attach_printable("Failed while redacting the deleted address")?;
+
+    Ok(services::ApplicationResponse::Json(
+        customers::CustomerAddressDeleteResponse {
+            address_id: customer_address_label.address_id,
+            customer_id: customer_address_label.customer_id,
+            deleted: true,
+        },
+    ))
+}
+
 pub async fn migrate_customers(
     state: SessionState,
     customers: Vec<customers::CustomerRequest>,
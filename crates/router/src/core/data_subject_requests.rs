@@ -0,0 +1,1447 @@
+use api_models::customers::{
+    CustomerDataExportAddress, CustomerDataExportMandate, CustomerDataExportPaymentMethod,
+    CustomerDataExportResponse, DataErasureReport, DataErasureStore, DataErasureStoreReport,
+    DataErasureStoreStatus, DataSubjectRequestResponse, DataSubjectRequestRetrieveRequest,
+};
+use common_utils::{
+    crypto::{Encryptable, HmacSha256, SignMessage},
+    ext_traits::{Encode, ValueExt},
+    id_type, type_name,
+    types::{
+        keymanager::{Identifier, KeyManagerState},
+        Description,
+    },
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use masking::{PeekInterface, Secret, SwitchStrategy};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        customers::REDACTED,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+    },
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::SessionState,
+    services,
+    types::{
+        api::{self, customers, disputes},
+        domain::{self, types},
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+/// The stores holding data of a customer, in the order they are erased. The customer record is
+/// erased last, so that a retry can still look up the data tied to the customer.
+const DATA_ERASURE_STORES: [DataErasureStore; 6] = [
+    DataErasureStore::Vault,
+    DataErasureStore::Addresses,
+    DataErasureStore::Payments,
+    DataErasureStore::Files,
+    DataErasureStore::Analytics,
+    DataErasureStore::Customer,
+];
+
+/// Keys of the additional payment method data of an attempt that do not identify the customer
+const RETAINED_PAYMENT_METHOD_DATA_KEYS: [&str; 6] = [
+    "card_issuer",
+    "card_network",
+    "card_type",
+    "card_issuing_country",
+    "bank_code",
+    "payment_checks",
+];
+
+/// Keys of the browser information of an attempt that identify the customer
+const REDACTED_BROWSER_INFO_KEYS: [&str; 4] =
+    ["ip_address", "user_agent", "accept_header", "language"];
+
+struct CustomerPayment {
+    payment_intent: storage::PaymentIntent,
+    payment_attempts: Vec<storage::PaymentAttempt>,
+}
+
+#[instrument(skip_all)]
+pub async fn export_customer_data(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> RouterResponse<CustomerDataExportResponse> {
+    let db = state.store.as_ref();
+    let key_manager_state = &(&state).into();
+    let merchant_id = merchant_account.get_id();
+    let customer_id = &req.customer_id;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mut addresses = Vec::new();
+    let customer_address = match customer.address_id.as_deref() {
+        Some(address_id) => {
+            let address = db
+                .find_address_by_address_id(key_manager_state, address_id, &key_store)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?;
+            addresses.push(CustomerDataExportAddress {
+                address_id: address.address_id.clone(),
+                payment_id: None,
+                address: api::Address::from(&address),
+            });
+            Some(api_models::payments::AddressDetails::from(address))
+        }
+        None => None,
+    };
+
+    for customer_address_label in db
+        .list_customer_address_labels_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the labeled addresses of the customer")?
+    {
+        let address = db
+            .find_address_by_address_id(
+                key_manager_state,
+                &customer_address_label.address_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::AddressNotFound)?;
+        addresses.push(CustomerDataExportAddress {
+            address_id: address.address_id.clone(),
+            payment_id: None,
+            address: api::Address::from(&address),
+        });
+    }
+
+    let mut payment_methods = Vec::new();
+    for payment_method in
+        find_customer_payment_methods(&state, &merchant_account, customer_id).await?
+    {
+        let payment_method_data = cards::decrypt_generic_data(
+            &state,
+            payment_method.payment_method_data.clone(),
+            &key_store,
+        )
+        .await
+        .attach_printable("Failed to decrypt payment method data")?;
+        let billing = cards::decrypt_generic_data(
+            &state,
+            payment_method.payment_method_billing_address.clone(),
+            &key_store,
+        )
+        .await
+        .attach_printable("Failed to decrypt payment method billing address")?;
+        payment_methods.push(CustomerDataExportPaymentMethod {
+            payment_method_id: payment_method.payment_method_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            payment_method_issuer: payment_method.payment_method_issuer,
+            status: payment_method.status,
+            payment_method_data,
+            billing,
+            metadata: payment_method.metadata,
+            created_at: payment_method.created_at,
+            last_used_at: payment_method.last_used_at,
+        });
+    }
+
+    let mut payments = Vec::new();
+    let mut refunds = Vec::new();
+    let mut disputes = Vec::new();
+    for CustomerPayment {
+        payment_intent,
+        payment_attempts,
+    } in find_customer_payments(&state, &merchant_account, &key_store, customer_id).await?
+    {
+        let payment_id = &payment_intent.payment_id;
+        for address_id in get_payment_address_ids(&payment_intent, &payment_attempts) {
+            match db
+                .find_address_by_merchant_id_payment_id_address_id(
+                    key_manager_state,
+                    merchant_id,
+                    payment_id,
+                    &address_id,
+                    &key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_address) => addresses.push(CustomerDataExportAddress {
+                    address_id,
+                    payment_id: Some(payment_id.clone()),
+                    address: api::Address::from(&payment_address.address),
+                }),
+                Err(error) if error.current_context().is_db_not_found() => {}
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the address of a payment")?,
+            }
+        }
+
+        refunds.extend(
+            find_or_empty(
+                db.find_refund_by_payment_id_merchant_id(
+                    payment_id,
+                    merchant_id,
+                    merchant_account.storage_scheme,
+                )
+                .await,
+            )
+            .attach_printable("Failed to fetch the refunds of a payment")?
+            .into_iter()
+            .map(api::RefundResponse::foreign_from),
+        );
+        disputes.extend(
+            find_or_empty(
+                db.find_disputes_by_merchant_id_payment_id(
+                    merchant_id,
+                    payment_id,
+                    merchant_account.storage_scheme,
+                )
+                .await,
+            )
+            .attach_printable("Failed to fetch the disputes of a payment")?
+            .into_iter()
+            .map(api_models::disputes::DisputeResponse::foreign_from),
+        );
+
+        let active_attempt_id = payment_intent.active_attempt.get_id();
+        if let Some(active_attempt) = payment_attempts
+            .into_iter()
+            .find(|payment_attempt| payment_attempt.attempt_id == active_attempt_id)
+        {
+            payments.push(api::PaymentsResponse::foreign_from((
+                payment_intent,
+                active_attempt,
+            )));
+        }
+    }
+
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?
+        .into_iter()
+        .map(|mandate| CustomerDataExportMandate {
+            mandate_id: mandate.mandate_id,
+            payment_method_id: mandate.payment_method_id,
+            status: mandate.mandate_status,
+            mandate_type: mandate.mandate_type,
+            customer_accepted_at: mandate.customer_accepted_at,
+            customer_ip_address: mandate.customer_ip_address,
+            customer_user_agent: mandate.customer_user_agent,
+            created_at: mandate.created_at,
+        })
+        .collect();
+
+    let now = common_utils::date_time::now();
+    let data_subject_request = db
+        .insert_data_subject_request(storage::DataSubjectRequestNew {
+            request_id: utils::generate_id(consts::ID_LENGTH, "dsr"),
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+            request_type: storage_enums::DataSubjectRequestType::Export,
+            status: storage_enums::DataSubjectRequestStatus::Completed,
+            report: None,
+            created_at: now,
+            modified_at: now,
+            completed_at: Some(now),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record the data export request")?;
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerDataExportResponse {
+            request_id: data_subject_request.request_id,
+            customer: customers::CustomerResponse::foreign_from((customer, customer_address))
+                .into_inner(),
+            addresses,
+            payment_methods,
+            payments,
+            refunds,
+            disputes,
+            mandates,
+            exported_at: now,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn initiate_customer_data_erasure(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customers::CustomerId,
+) -> RouterResponse<DataSubjectRequestResponse> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+
+    db.find_customer_by_customer_id_merchant_id(
+        &(&state).into(),
+        &req.customer_id,
+        merchant_id,
+        &key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let customer_mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &req.customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the customer")?;
+    if customer_mandates
+        .iter()
+        .any(|mandate| mandate.mandate_status == storage_enums::MandateStatus::Active)
+    {
+        Err(errors::ApiErrorResponse::MandateActive)?
+    }
+
+    let report = DataErasureReport {
+        stores: DATA_ERASURE_STORES
+            .into_iter()
+            .map(|store| get_store_report(store, DataErasureStoreStatus::Pending))
+            .collect(),
+        digest: None,
+    };
+    let now = common_utils::date_time::now();
+    let data_subject_request = db
+        .insert_data_subject_request(storage::DataSubjectRequestNew {
+            request_id: utils::generate_id(consts::ID_LENGTH, "dsr"),
+            merchant_id: merchant_id.to_owned(),
+            customer_id: req.customer_id,
+            request_type: storage_enums::DataSubjectRequestType::Erasure,
+            status: storage_enums::DataSubjectRequestStatus::Pending,
+            report: Some(
+                report
+                    .encode_to_value()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to encode the data erasure report")?,
+            ),
+            created_at: now,
+            modified_at: now,
+            completed_at: None,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the data erasure request")?;
+
+    add_data_subject_erasure_task(&state, &data_subject_request).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        get_data_subject_request_response(data_subject_request)?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_data_subject_request(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: DataSubjectRequestRetrieveRequest,
+) -> RouterResponse<DataSubjectRequestResponse> {
+    let data_subject_request = state
+        .store
+        .find_data_subject_request_by_merchant_id_request_id(
+            merchant_account.get_id(),
+            &req.request_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data subject request not found".to_string(),
+        })?;
+
+    // Requests of other customers of the merchant are reported as not found
+    if data_subject_request.customer_id != req.customer_id {
+        Err(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Data subject request not found".to_string(),
+        })?
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        get_data_subject_request_response(data_subject_request)?,
+    ))
+}
+
+/// Erases the data of the customer from the stores that were not erased by a previous run,
+/// persisting the report after every store. The request is completed once all the stores are
+/// erased, and is left processing when a store fails, so that it is resumed from that store.
+#[instrument(skip_all)]
+pub async fn process_customer_data_erasure(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mut data_subject_request: storage::DataSubjectRequest,
+) -> RouterResult<storage::DataSubjectRequest> {
+    let db = state.store.as_ref();
+    let customer_id = data_subject_request.customer_id.clone();
+    let mut report = data_subject_request
+        .report
+        .clone()
+        .map(|report| report.parse_value::<DataErasureReport>("DataErasureReport"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the data erasure report")?
+        .unwrap_or_default();
+
+    let pending_stores: Vec<_> = report
+        .stores
+        .iter()
+        .enumerate()
+        .filter(|(_, store_report)| {
+            matches!(
+                store_report.status,
+                DataErasureStoreStatus::Pending | DataErasureStoreStatus::Failed
+            )
+        })
+        .map(|(index, store_report)| (index, store_report.store))
+        .collect();
+
+    for (index, store) in pending_stores {
+        let store_report =
+            match erase_store(state, merchant_account, key_store, &customer_id, store).await {
+                Ok(store_report) => store_report,
+                Err(error) => {
+                    logger::error!(%store, ?error, "Failed to erase customer data");
+                    DataErasureStoreReport {
+                        error_message: Some(error.current_context().to_string()),
+                        ..get_store_report(store, DataErasureStoreStatus::Failed)
+                    }
+                }
+            };
+        let is_failed = store_report.status == DataErasureStoreStatus::Failed;
+        if let Some(report_entry) = report.stores.get_mut(index) {
+            *report_entry = store_report;
+        }
+
+        data_subject_request = db
+            .update_data_subject_request(
+                data_subject_request,
+                storage::DataSubjectRequestUpdate::ReportUpdate {
+                    status: storage_enums::DataSubjectRequestStatus::Processing,
+                    report: report_to_value(&report)?,
+                    completed_at: None,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the data erasure report")?;
+
+        if is_failed {
+            return Ok(data_subject_request);
+        }
+    }
+
+    report.digest = Some(get_report_digest(
+        &state
+            .conf
+            .data_subject_requests
+            .get_inner()
+            .report_signing_key,
+        &data_subject_request.request_id,
+        &customer_id,
+        &report.stores,
+    )?);
+    db.update_data_subject_request(
+        data_subject_request,
+        storage::DataSubjectRequestUpdate::ReportUpdate {
+            status: storage_enums::DataSubjectRequestStatus::Completed,
+            report: report_to_value(&report)?,
+            completed_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to complete the data erasure request")
+}
+
+async fn erase_store(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    store: DataErasureStore,
+) -> RouterResult<DataErasureStoreReport> {
+    match store {
+        DataErasureStore::Vault => erase_vault(state, merchant_account, customer_id).await,
+        DataErasureStore::Addresses => {
+            erase_addresses(state, merchant_account, key_store, customer_id).await
+        }
+        DataErasureStore::Payments => {
+            erase_payments(state, merchant_account, key_store, customer_id).await
+        }
+        DataErasureStore::Files => {
+            erase_files(state, merchant_account, key_store, customer_id).await
+        }
+        DataErasureStore::Analytics => erase_analytics(state, merchant_account, customer_id).await,
+        DataErasureStore::Customer => {
+            erase_customer(state, merchant_account, key_store, customer_id).await
+        }
+    }
+}
+
+async fn erase_vault(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataErasureStoreReport> {
+    let merchant_id = merchant_account.get_id();
+    let payment_methods =
+        find_customer_payment_methods(state, merchant_account, customer_id).await?;
+
+    for payment_method in payment_methods.iter() {
+        if payment_method.payment_method == Some(storage_enums::PaymentMethod::Card) {
+            cards::delete_card_from_locker(
+                state,
+                customer_id,
+                merchant_id,
+                payment_method
+                    .locker_id
+                    .as_ref()
+                    .unwrap_or(&payment_method.payment_method_id),
+            )
+            .await?;
+        }
+        state
+            .store
+            .delete_payment_method_by_merchant_id_payment_method_id(
+                merchant_id,
+                &payment_method.payment_method_id,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the payment method")?;
+    }
+
+    let verified = find_customer_payment_methods(state, merchant_account, customer_id)
+        .await?
+        .is_empty();
+    let records = count(payment_methods.len());
+    Ok(DataErasureStoreReport {
+        records_found: records,
+        records_erased: records,
+        verified,
+        ..get_store_report(DataErasureStore::Vault, DataErasureStoreStatus::Completed)
+    })
+}
+
+async fn erase_addresses(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataErasureStoreReport> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let address_update =
+        get_redacted_address_update(key_manager_state, merchant_account, key_store).await?;
+
+    let customer_addresses = find_or_empty(
+        db.update_address_by_merchant_id_customer_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            address_update.clone(),
+            key_store,
+        )
+        .await,
+    )
+    .attach_printable("Failed to redact the addresses of the customer")?;
+    let mut records = customer_addresses.len();
+    let mut verified = customer_addresses.iter().all(is_address_redacted);
+
+    for CustomerPayment {
+        payment_intent,
+        payment_attempts,
+    } in find_customer_payments(state, merchant_account, key_store, customer_id).await?
+    {
+        for address_id in get_payment_address_ids(&payment_intent, &payment_attempts) {
+            let payment_address = match db
+                .find_address_by_merchant_id_payment_id_address_id(
+                    key_manager_state,
+                    merchant_id,
+                    &payment_intent.payment_id,
+                    &address_id,
+                    key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_address) => payment_address,
+                Err(error) if error.current_context().is_db_not_found() => continue,
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the address of a payment")?,
+            };
+            let updated_address = db
+                .update_address_for_payments(
+                    key_manager_state,
+                    payment_address,
+                    address_update.clone(),
+                    payment_intent.payment_id.clone(),
+                    key_store,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact the address of a payment")?;
+            records += 1;
+            verified &= is_address_redacted(&updated_address.address);
+        }
+    }
+
+    let records = count(records);
+    Ok(DataErasureStoreReport {
+        records_found: records,
+        records_erased: records,
+        verified,
+        ..get_store_report(
+            DataErasureStore::Addresses,
+            DataErasureStoreStatus::Completed,
+        )
+    })
+}
+
+async fn erase_payments(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataErasureStoreReport> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let redacted_details: Encryptable<Secret<serde_json::Value>> = types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::PaymentIntent),
+        types::CryptoOperation::Encrypt(Secret::new(serde_json::json!({}))),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the redacted payment details")?;
+
+    let mut records = 0;
+    let mut verified = true;
+    for CustomerPayment {
+        payment_intent,
+        payment_attempts,
+    } in find_customer_payments(state, merchant_account, key_store, customer_id).await?
+    {
+        let payment_intent_update = storage::PaymentIntentUpdate::CustomerDataRedactionUpdate {
+            description: payment_intent
+                .description
+                .as_ref()
+                .map(|_| REDACTED.to_string()),
+            customer_details: payment_intent
+                .customer_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            billing_details: payment_intent
+                .billing_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            shipping_details: payment_intent
+                .shipping_details
+                .as_ref()
+                .map(|_| redacted_details.clone()),
+            updated_by: merchant_account.storage_scheme.to_string(),
+        };
+        let updated_payment_intent = db
+            .update_payment_intent(
+                key_manager_state,
+                payment_intent,
+                payment_intent_update,
+                key_store,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to redact the payment intent")?;
+        records += 1;
+        verified &= is_payment_intent_redacted(&updated_payment_intent);
+
+        for payment_attempt in payment_attempts {
+            let payment_attempt_update =
+                storage::PaymentAttemptUpdate::CustomerDataRedactionUpdate {
+                    payment_method_data: payment_attempt
+                        .payment_method_data
+                        .as_ref()
+                        .map(redact_payment_method_data),
+                    browser_info: payment_attempt
+                        .browser_info
+                        .as_ref()
+                        .map(redact_browser_info),
+                    updated_by: merchant_account.storage_scheme.to_string(),
+                };
+            let updated_payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_attempt,
+                    payment_attempt_update,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to redact the payment attempt")?;
+            records += 1;
+            verified &= is_payment_attempt_redacted(&updated_payment_attempt);
+        }
+    }
+
+    let records = count(records);
+    Ok(DataErasureStoreReport {
+        records_found: records,
+        records_erased: records,
+        verified,
+        ..get_store_report(
+            DataErasureStore::Payments,
+            DataErasureStoreStatus::Completed,
+        )
+    })
+}
+
+async fn erase_files(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataErasureStoreReport> {
+    let db = state.store.as_ref();
+    let merchant_id = merchant_account.get_id();
+    let mut records_found = 0;
+    let mut records_erased = 0;
+    let mut erased_file_ids = Vec::new();
+
+    for CustomerPayment { payment_intent, .. } in
+        find_customer_payments(state, merchant_account, key_store, customer_id).await?
+    {
+        let payment_disputes = find_or_empty(
+            db.find_disputes_by_merchant_id_payment_id(
+                merchant_id,
+                &payment_intent.payment_id,
+                merchant_account.storage_scheme,
+            )
+            .await,
+        )
+        .attach_printable("Failed to fetch the disputes of a payment")?;
+
+        for dispute in payment_disputes {
+            let dispute_evidence: disputes::DisputeEvidence = dispute
+                .evidence
+                .clone()
+                .parse_value("DisputeEvidence")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error while parsing dispute evidence record")?;
+            let file_ids = get_dispute_evidence_file_ids(dispute_evidence);
+            records_found += file_ids.len();
+
+            // Evidence of disputes that can still be challenged is retained
+            if matches!(
+                dispute.dispute_status,
+                storage_enums::DisputeStatus::DisputeOpened
+                    | storage_enums::DisputeStatus::DisputeChallenged
+            ) {
+                continue;
+            }
+
+            let mut is_evidence_erased = true;
+            for file_id in file_ids {
+                if erase_file(state, merchant_account, &file_id).await? {
+                    records_erased += 1;
+                    erased_file_ids.push(file_id);
+                } else {
+                    is_evidence_erased = false;
+                }
+            }
+
+            if is_evidence_erased {
+                db.update_dispute(
+                    dispute,
+                    storage::DisputeUpdate::EvidenceUpdate {
+                        evidence: disputes::DisputeEvidence::default()
+                            .encode_to_value()
+                            .change_context(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable("Error while encoding dispute evidence")?
+                            .into(),
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to clear the evidence of a dispute")?;
+            }
+        }
+    }
+
+    let mut verified = true;
+    for file_id in erased_file_ids {
+        verified &= db
+            .find_file_metadata_by_merchant_id_file_id(merchant_id, &file_id)
+            .await
+            .is_err_and(|error| error.current_context().is_db_not_found());
+    }
+
+    Ok(DataErasureStoreReport {
+        records_found: count(records_found),
+        records_erased: count(records_erased),
+        records_retained: count(records_found - records_erased),
+        verified,
+        ..get_store_report(DataErasureStore::Files, DataErasureStoreStatus::Completed)
+    })
+}
+
+/// Deletes a file uploaded to the file storage of the router, returning whether the file was
+/// erased. Files stored with a connector cannot be deleted and are retained.
+async fn erase_file(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    file_id: &str,
+) -> RouterResult<bool> {
+    let file_metadata = match state
+        .store
+        .find_file_metadata_by_merchant_id_file_id(merchant_account.get_id(), file_id)
+        .await
+    {
+        Ok(file_metadata) => file_metadata,
+        // Already deleted by a previous run
+        Err(error) if error.current_context().is_db_not_found() => return Ok(true),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the file metadata")?,
+    };
+
+    match (
+        file_metadata.file_upload_provider,
+        file_metadata.provider_file_id,
+    ) {
+        (Some(storage_enums::FileUploadProvider::Router), Some(provider_file_id)) => {
+            state
+                .file_storage_client
+                .delete_file(&provider_file_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete the file from the file storage")?;
+            state
+                .store
+                .delete_file_metadata_by_merchant_id_file_id(merchant_account.get_id(), file_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to delete the file metadata")?;
+            Ok(true)
+        }
+        _ => Ok(false),
+    }
+}
+
+/// Deletes the records of the customer from the analytics store, and emits a tombstone event
+/// recording the erasure for the consumers of the analytics pipeline
+async fn erase_analytics(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataErasureStoreReport> {
+    let merchant_id = merchant_account.get_id();
+    #[cfg(feature = "olap")]
+    let (records_found, records_remaining) = {
+        let erasure = state
+            .pool
+            .erase_customer_data(merchant_id, customer_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to erase the records of the customer from analytics")?;
+        (erasure.records_found, erasure.records_remaining)
+    };
+    // The analytics store is only available with the olap feature
+    #[cfg(not(feature = "olap"))]
+    let (records_found, records_remaining) = (0, 0);
+
+    utils::when(records_remaining > 0, || {
+        Err(errors::ApiErrorResponse::InternalServerError).attach_printable(format!(
+            "{records_remaining} records of the customer remain in analytics after the erasure"
+        ))
+    })?;
+
+    state
+        .get_req_state()
+        .event_context
+        .event(AuditEvent::new(AuditEventType::CustomerDataErased {
+            merchant_id: merchant_id.to_owned(),
+            customer_id: customer_id.to_owned(),
+        }))
+        .emit();
+
+    let records = u32::try_from(records_found).unwrap_or(u32::MAX);
+    Ok(DataErasureStoreReport {
+        records_found: records,
+        records_erased: records,
+        verified: true,
+        ..get_store_report(
+            DataErasureStore::Analytics,
+            DataErasureStoreStatus::Completed,
+        )
+    })
+}
+
+async fn erase_customer(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<DataErasureStoreReport> {
+    let db = state.store.as_ref();
+    let key_manager_state = &state.into();
+    let merchant_id = merchant_account.get_id();
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let identifier = Identifier::Merchant(key_store.merchant_id.clone());
+    let key = key_store.key.get_inner().peek();
+    let redacted_encrypted_value: Encryptable<Secret<_>> = types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::Customer),
+        types::CryptoOperation::Encrypt(REDACTED.to_string().into()),
+        identifier.clone(),
+        key,
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the redacted customer details")?;
+
+    let customer_update = storage::CustomerUpdate::Update {
+        name: Some(redacted_encrypted_value.clone()),
+        email: Some(
+            types::crypto_operation(
+                key_manager_state,
+                type_name!(storage::Customer),
+                types::CryptoOperation::Encrypt(REDACTED.to_string().into()),
+                identifier,
+                key,
+            )
+            .await
+            .and_then(|val| val.try_into_operation())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the redacted customer email")?,
+        ),
+        phone: Box::new(Some(redacted_encrypted_value)),
+        description: Some(Description::new(REDACTED.to_string())),
+        phone_country_code: Some(REDACTED.to_string()),
+        metadata: None,
+        connector_customer: None,
+        address_id: None,
+        tax_ids: Some(
+            cards::create_encrypted_data(state, key_store, Vec::<customers::CustomerTaxId>::new())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to encrypt the redacted customer tax ids")?,
+        ),
+        segments: None,
+    };
+    db.update_customer_by_customer_id_merchant_id(
+        key_manager_state,
+        customer_id.to_owned(),
+        merchant_id.to_owned(),
+        customer,
+        customer_update,
+        key_store,
+        merchant_account.storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to redact the customer")?;
+
+    let verified = db
+        .find_customer_by_customer_id_merchant_id(
+            key_manager_state,
+            customer_id,
+            merchant_id,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the redacted customer")?
+        .name
+        .map_or(true, |name| name.into_inner().peek() == REDACTED);
+
+    Ok(DataErasureStoreReport {
+        records_found: 1,
+        records_erased: 1,
+        verified,
+        ..get_store_report(
+            DataErasureStore::Customer,
+            DataErasureStoreStatus::Completed,
+        )
+    })
+}
+
+pub(crate) async fn get_redacted_address_update(
+    key_manager_state: &KeyManagerState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<storage::AddressUpdate> {
+    let redacted_encrypted_value: Encryptable<Secret<_>> = types::crypto_operation(
+        key_manager_state,
+        type_name!(storage::Address),
+        types::CryptoOperation::Encrypt(REDACTED.to_string().into()),
+        Identifier::Merchant(key_store.merchant_id.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .and_then(|val| val.try_into_operation())
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt the redacted address details")?;
+
+    let redacted_encrypted_email = Encryptable::new(
+        redacted_encrypted_value
+            .clone()
+            .into_inner()
+            .switch_strategy(),
+        redacted_encrypted_value.clone().into_encrypted(),
+    );
+
+    Ok(storage::AddressUpdate::Update {
+        city: Some(REDACTED.to_string()),
+        country: None,
+        line1: Some(redacted_encrypted_value.clone()),
+        line2: Some(redacted_encrypted_value.clone()),
+        line3: Some(redacted_encrypted_value.clone()),
+        state: Some(redacted_encrypted_value.clone()),
+        zip: Some(redacted_encrypted_value.clone()),
+        first_name: Some(redacted_encrypted_value.clone()),
+        last_name: Some(redacted_encrypted_value.clone()),
+        phone_number: Some(redacted_encrypted_value),
+        country_code: Some(REDACTED.to_string()),
+        updated_by: merchant_account.storage_scheme.to_string(),
+        email: Some(redacted_encrypted_email),
+    })
+}
+
+async fn find_customer_payment_methods(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<Vec<storage::PaymentMethod>> {
+    find_or_empty(
+        state
+            .store
+            .find_payment_method_by_customer_id_merchant_id_list(
+                customer_id,
+                merchant_account.get_id(),
+                None,
+            )
+            .await,
+    )
+    .attach_printable("Failed to fetch the payment methods of the customer")
+}
+
+async fn find_customer_payments(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+) -> RouterResult<Vec<CustomerPayment>> {
+    let db = state.store.as_ref();
+    let constraints = PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+        offset: 0,
+        starting_at: None,
+        ending_at: None,
+        amount_filter: None,
+        connector: None,
+        currency: None,
+        status: None,
+        payment_method: None,
+        payment_method_type: None,
+        authentication_type: None,
+        merchant_connector_id: None,
+        profile_id: None,
+        customer_id: Some(customer_id.to_owned()),
+        starting_after_id: None,
+        ending_before_id: None,
+        limit: None,
+        order: Default::default(),
+    }));
+    let payment_intents = db
+        .filter_payment_intent_by_constraints(
+            &state.into(),
+            merchant_account.get_id(),
+            &constraints,
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments of the customer")?;
+
+    let mut customer_payments = Vec::with_capacity(payment_intents.len());
+    for payment_intent in payment_intents {
+        let payment_attempts = find_or_empty(
+            db.find_attempts_by_merchant_id_payment_id(
+                merchant_account.get_id(),
+                &payment_intent.payment_id,
+                merchant_account.storage_scheme,
+            )
+            .await,
+        )
+        .attach_printable("Failed to fetch the attempts of a payment")?;
+        customer_payments.push(CustomerPayment {
+            payment_intent,
+            payment_attempts,
+        });
+    }
+    Ok(customer_payments)
+}
+
+async fn add_data_subject_erasure_task(
+    state: &SessionState,
+    data_subject_request: &storage::DataSubjectRequest,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::DataSubjectErasureWorkflow;
+    let task = "DATA_SUBJECT_ERASURE";
+    let tag = ["CUSTOMER", "DATA_ERASURE"];
+    let process_tracker_id = format!("{runner}_{task}_{}", data_subject_request.request_id);
+    let tracking_data = storage::DataSubjectErasureTrackingData {
+        request_id: data_subject_request.request_id.clone(),
+        merchant_id: data_subject_request.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct data subject erasure process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert data subject erasure process tracker task")
+}
+
+fn get_data_subject_request_response(
+    data_subject_request: storage::DataSubjectRequest,
+) -> RouterResult<DataSubjectRequestResponse> {
+    let report = data_subject_request
+        .report
+        .map(|report| report.parse_value::<DataErasureReport>("DataErasureReport"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the data erasure report")?;
+    Ok(DataSubjectRequestResponse {
+        request_id: data_subject_request.request_id,
+        customer_id: data_subject_request.customer_id,
+        request_type: data_subject_request.request_type,
+        status: data_subject_request.status,
+        report,
+        created_at: data_subject_request.created_at,
+        completed_at: data_subject_request.completed_at,
+    })
+}
+
+fn get_store_report(
+    store: DataErasureStore,
+    status: DataErasureStoreStatus,
+) -> DataErasureStoreReport {
+    DataErasureStoreReport {
+        store,
+        status,
+        records_found: 0,
+        records_erased: 0,
+        records_retained: 0,
+        verified: false,
+        error_message: None,
+    }
+}
+
+fn report_to_value(report: &DataErasureReport) -> RouterResult<serde_json::Value> {
+    report
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the data erasure report")
+}
+
+/// Hex encoded HMAC-SHA256 of the request id, the customer id and the store reports, which lets
+/// the completion report be checked for tampering by the holder of the signing key
+fn get_report_digest(
+    signing_key: &Secret<String>,
+    request_id: &str,
+    customer_id: &id_type::CustomerId,
+    stores: &[DataErasureStoreReport],
+) -> RouterResult<String> {
+    let message = (request_id, customer_id, stores)
+        .encode_to_vec()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to encode the data erasure report")?;
+    HmacSha256
+        .sign_message(signing_key.peek().as_bytes(), &message)
+        .map(hex::encode)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate the digest of the data erasure report")
+}
+
+/// Ids of the billing and shipping addresses of a payment and of the billing addresses of the
+/// payment methods used in its attempts
+fn get_payment_address_ids(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempts: &[storage::PaymentAttempt],
+) -> Vec<String> {
+    let mut address_ids: Vec<String> = Vec::new();
+    for address_id in [
+        &payment_intent.billing_address_id,
+        &payment_intent.shipping_address_id,
+    ]
+    .into_iter()
+    .chain(
+        payment_attempts
+            .iter()
+            .map(|payment_attempt| &payment_attempt.payment_method_billing_address_id),
+    )
+    .flatten()
+    {
+        if !address_ids.contains(address_id) {
+            address_ids.push(address_id.to_owned());
+        }
+    }
+    address_ids
+}
+
+fn get_dispute_evidence_file_ids(dispute_evidence: disputes::DisputeEvidence) -> Vec<String> {
+    [
+        dispute_evidence.cancellation_policy,
+        dispute_evidence.customer_communication,
+        dispute_evidence.customer_signature,
+        dispute_evidence.receipt,
+        dispute_evidence.refund_policy,
+        dispute_evidence.service_documentation,
+        dispute_evidence.shipping_documentation,
+        dispute_evidence.invoice_showing_distinct_transactions,
+        dispute_evidence.recurring_transaction_agreement,
+        dispute_evidence.uncategorized_file,
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Keeps only the details of the payment method that do not identify the customer, such as the
+/// card network, from the additional payment method data of an attempt
+fn redact_payment_method_data(payment_method_data: &serde_json::Value) -> serde_json::Value {
+    match payment_method_data {
+        serde_json::Value::Object(payment_methods) => serde_json::Value::Object(
+            payment_methods
+                .iter()
+                .map(|(payment_method, details)| {
+                    let redacted_details = match details {
+                        serde_json::Value::Object(details) => serde_json::Value::Object(
+                            details
+                                .iter()
+                                .filter(|(key, _)| {
+                                    RETAINED_PAYMENT_METHOD_DATA_KEYS.contains(&key.as_str())
+                                })
+                                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                                .collect(),
+                        ),
+                        _ => serde_json::Value::Null,
+                    };
+                    (payment_method.to_owned(), redacted_details)
+                })
+                .collect(),
+        ),
+        _ => payment_method_data.to_owned(),
+    }
+}
+
+fn redact_browser_info(browser_info: &serde_json::Value) -> serde_json::Value {
+    match browser_info {
+        serde_json::Value::Object(browser_info) => serde_json::Value::Object(
+            browser_info
+                .iter()
+                .filter(|(key, _)| !REDACTED_BROWSER_INFO_KEYS.contains(&key.as_str()))
+                .map(|(key, value)| (key.to_owned(), value.to_owned()))
+                .collect(),
+        ),
+        _ => browser_info.to_owned(),
+    }
+}
+
+fn is_address_redacted(address: &domain::Address) -> bool {
+    address
+        .first_name
+        .as_ref()
+        .map_or(true, |first_name| first_name.get_inner().peek() == REDACTED)
+}
+
+fn is_payment_intent_redacted(payment_intent: &storage::PaymentIntent) -> bool {
+    [
+        &payment_intent.customer_details,
+        &payment_intent.billing_details,
+        &payment_intent.shipping_details,
+    ]
+    .into_iter()
+    .flatten()
+    .all(|details| {
+        details
+            .get_inner()
+            .peek()
+            .as_object()
+            .is_some_and(|details| details.is_empty())
+    }) && payment_intent
+        .description
+        .as_deref()
+        .map_or(true, |description| description == REDACTED)
+}
+
+fn is_payment_attempt_redacted(payment_attempt: &storage::PaymentAttempt) -> bool {
+    payment_attempt
+        .payment_method_data
+        .as_ref()
+        .map_or(true, |payment_method_data| {
+            redact_payment_method_data(payment_method_data) == *payment_method_data
+        })
+        && payment_attempt
+            .browser_info
+            .as_ref()
+            .map_or(true, |browser_info| {
+                redact_browser_info(browser_info) == *browser_info
+            })
+}
+
+fn find_or_empty<T>(
+    result: errors::CustomResult<Vec<T>, errors::StorageError>,
+) -> RouterResult<Vec<T>> {
+    match result {
+        Ok(records) => Ok(records),
+        Err(error) if error.current_context().is_db_not_found() => Ok(Vec::new()),
+        Err(error) => Err(error).change_context(errors::ApiErrorResponse::InternalServerError),
+    }
+}
+
+fn count(records: usize) -> u32 {
+    u32::try_from(records).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_redact_payment_method_data_keeps_only_non_identifying_details() {
+        let payment_method_data = json!({
+            "card": {
+                "last4": "4242",
+                "card_isin": "424242",
+                "card_holder_name": "John Doe",
+                "card_exp_month": "10",
+                "card_network": "Visa",
+                "card_type": "CREDIT",
+                "card_issuing_country": "US",
+                "payment_checks": {"cvc_check": "pass"}
+            }
+        });
+        let redacted = redact_payment_method_data(&payment_method_data);
+        assert_eq!(
+            redacted,
+            json!({
+                "card": {
+                    "card_network": "Visa",
+                    "card_type": "CREDIT",
+                    "card_issuing_country": "US",
+                    "payment_checks": {"cvc_check": "pass"}
+                }
+            })
+        );
+        assert_eq!(redact_payment_method_data(&redacted), redacted);
+    }
+
+    #[test]
+    fn test_redact_payment_method_data_drops_non_object_details() {
+        let payment_method_data = json!({"wallet": "apple_pay", "card": null});
+        assert_eq!(
+            redact_payment_method_data(&payment_method_data),
+            json!({"wallet": null, "card": null})
+        );
+    }
+
+    #[test]
+    fn test_redact_browser_info() {
+        let browser_info = json!({
+            "ip_address": "127.0.0.1",
+            "user_agent": "Mozilla/5.0",
+            "accept_header": "text/html",
+            "language": "en-US",
+            "color_depth": 24,
+            "java_enabled": true
+        });
+        assert_eq!(
+            redact_browser_info(&browser_info),
+            json!({"color_depth": 24, "java_enabled": true})
+        );
+    }
+
+    #[test]
+    fn test_report_digest_depends_on_the_report() {
+        let customer_id = id_type::CustomerId::try_from(std::borrow::Cow::from("cus_123")).unwrap();
+        let stores = [get_store_report(
+            DataErasureStore::Vault,
+            DataErasureStoreStatus::Completed,
+        )];
+        let signing_key = Secret::new("report_signing_key".to_string());
+        let digest = get_report_digest(&signing_key, "dsr_123", &customer_id, &stores).unwrap();
+
+        assert_eq!(digest.len(), 64);
+        assert_eq!(
+            digest,
+            get_report_digest(&signing_key, "dsr_123", &customer_id, &stores).unwrap()
+        );
+        assert_ne!(
+            digest,
+            get_report_digest(&signing_key, "dsr_456", &customer_id, &stores).unwrap()
+        );
+        assert_ne!(
+            digest,
+            get_report_digest(
+                &Secret::new("other_signing_key".to_string()),
+                "dsr_123",
+                &customer_id,
+                &stores
+            )
+            .unwrap()
+        );
+    }
+}
@@ -1,25 +1,31 @@
 use api_models::{disputes as dispute_models, files as files_api_models};
-use common_utils::ext_traits::{Encode, ValueExt};
-use error_stack::ResultExt;
-use router_env::{instrument, tracing};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    types::MinorUnit,
+};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, metrics::add_attributes, tracing};
 pub mod transformers;
 
 use super::{
-    errors::{self, ConnectorErrorExt, RouterResponse, StorageErrorExt},
+    errors::{self, ConnectorErrorExt, RouterResponse, RouterResult, StorageErrorExt},
     metrics,
 };
 use crate::{
-    core::{files, payments, utils as core_utils},
+    consts,
+    core::{files, payments, refunds, utils as core_utils, webhooks},
+    logger,
     routes::SessionState,
     services,
     types::{
         api::{self, disputes},
         domain,
-        storage::enums as storage_enums,
-        transformers::ForeignFrom,
+        storage::{self, enums as storage_enums},
+        transformers::{ForeignFrom, ForeignInto},
         AcceptDisputeRequestData, AcceptDisputeResponse, DefendDisputeRequestData,
         DefendDisputeResponse, SubmitEvidenceRequestData, SubmitEvidenceResponse,
     },
+    utils::generate_id,
 };
 
 #[instrument(skip(state))]
@@ -31,7 +37,11 @@ pub async fn retrieve_dispute(
 ) -> RouterResponse<api_models::disputes::DisputeResponse> {
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_account.get_id(),
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
@@ -72,7 +82,11 @@ pub async fn accept_dispute(
     let db = &state.store;
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_account.get_id(),
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
@@ -155,7 +169,11 @@ pub async fn accept_dispute(
         connector_status: accept_dispute_response.connector_status.clone(),
     };
     let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+        .update_dispute(
+            dispute.clone(),
+            update_dispute,
+            merchant_account.storage_scheme,
+        )
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable_lazy(|| {
@@ -176,7 +194,11 @@ pub async fn submit_evidence(
     let db = &state.store;
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_account.get_id(),
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id.clone(),
@@ -321,7 +343,11 @@ pub async fn submit_evidence(
         connector_status,
     };
     let updated_dispute = db
-        .update_dispute(dispute.clone(), update_dispute)
+        .update_dispute(
+            dispute.clone(),
+            update_dispute,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -347,7 +373,11 @@ pub async fn attach_evidence(
         .clone()
         .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
     let dispute = db
-        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_account.get_id(),
+            &dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.clone(),
@@ -370,6 +400,7 @@ pub async fn attach_evidence(
             })
         },
     )?;
+    let storage_scheme = merchant_account.storage_scheme;
     let create_file_response = Box::pin(files::files_create_core(
         state.clone(),
         merchant_account,
@@ -400,7 +431,7 @@ pub async fn attach_evidence(
             .attach_printable("Error while encoding dispute evidence")?
             .into(),
     };
-    db.update_dispute(dispute, update_dispute)
+    db.update_dispute(dispute, update_dispute, storage_scheme)
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -420,7 +451,11 @@ pub async fn retrieve_dispute_evidence(
 ) -> RouterResponse<Vec<api_models::disputes::DisputeEvidenceBlock>> {
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &req.dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_account.get_id(),
+            &req.dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
@@ -445,7 +480,11 @@ pub async fn delete_evidence(
     let dispute_id = delete_evidence_request.dispute_id.clone();
     let dispute = state
         .store
-        .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &dispute_id)
+        .find_dispute_by_merchant_id_dispute_id(
+            merchant_account.get_id(),
+            &dispute_id,
+            merchant_account.storage_scheme,
+        )
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.clone(),
@@ -467,7 +506,7 @@ pub async fn delete_evidence(
     };
     state
         .store
-        .update_dispute(dispute, update_dispute)
+        .update_dispute(dispute, update_dispute, merchant_account.storage_scheme)
         .await
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.to_owned(),
@@ -477,3 +516,556 @@ pub async fn delete_evidence(
         })?;
     Ok(services::ApplicationResponse::StatusOk)
 }
+
+#[instrument(skip(state))]
+pub async fn ingest_pre_dispute_alert(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: dispute_models::PreDisputeAlertRequest,
+) -> RouterResponse<dispute_models::PreDisputeAlertResponse> {
+    metrics::PRE_DISPUTE_ALERT_METRIC.add(
+        &metrics::CONTEXT,
+        1,
+        &add_attributes([("alert_provider", req.alert_provider.to_string())]),
+    );
+    let db = &*state.store;
+    let payment_attempt =
+        find_payment_attempt_for_pre_dispute_alert(&state, &merchant_account, &req).await?;
+    validate_pre_dispute_alert_against_payment_attempt(&req, &payment_attempt)?;
+
+    let option_dispute = db
+        .find_by_merchant_id_payment_id_connector_dispute_id(
+            merchant_account.get_id(),
+            &payment_attempt.payment_id,
+            &req.alert_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch dispute recorded for pre-dispute alert")?;
+
+    let (dispute, refund) = match option_dispute {
+        // Alert providers retry deliveries, the recorded outcome is returned for a replayed alert
+        Some(dispute) => {
+            let refund = find_pre_dispute_alert_refund(
+                &state,
+                &merchant_account,
+                &dispute,
+                Some(req.arn.as_str()),
+            )
+            .await?
+            .map(api::RefundResponse::foreign_from);
+            (dispute, refund)
+        }
+        None => {
+            let connector = payment_attempt
+                .connector
+                .clone()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("No connector populated in payment attempt")?;
+            let new_dispute = diesel_models::dispute::DisputeNew {
+                dispute_id: generate_id(consts::ID_LENGTH, "dp"),
+                amount: req.amount.to_string(),
+                currency: req.currency.to_string(),
+                dispute_stage: storage_enums::DisputeStage::PreDispute,
+                dispute_status: storage_enums::DisputeStatus::DisputeOpened,
+                payment_id: payment_attempt.payment_id.clone(),
+                attempt_id: payment_attempt.attempt_id.clone(),
+                merchant_id: merchant_account.get_id().clone(),
+                connector_status: dispute_models::PreDisputeAlertOutcome::MerchantNotified
+                    .to_string(),
+                connector_dispute_id: req.alert_id.clone(),
+                connector_reason: req.reason.clone(),
+                connector_reason_code: req.reason_code.clone(),
+                challenge_required_by: None,
+                connector_created_at: None,
+                connector_updated_at: None,
+                connector,
+                evidence: None,
+                profile_id: Some(payment_attempt.profile_id.clone()),
+                merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                dispute_amount: req.amount.get_amount_as_i64(),
+                organization_id: merchant_account.organization_id.clone(),
+            };
+            let dispute = db
+                .insert_dispute(new_dispute, merchant_account.storage_scheme)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to record pre-dispute alert")?;
+            let (dispute, refund) = apply_pre_dispute_alert_rule(
+                &state,
+                &merchant_account,
+                &key_store,
+                dispute,
+                Some(req.arn.as_str()),
+            )
+            .await?;
+
+            let business_profile = db
+                .find_business_profile_by_profile_id(
+                    &(&state).into(),
+                    &key_store,
+                    &payment_attempt.profile_id,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: payment_attempt.profile_id.get_string_repr().to_owned(),
+                })?;
+            let disputes_response = Box::new(dispute.clone().foreign_into());
+            Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+                state.clone(),
+                merchant_account.clone(),
+                business_profile,
+                &key_store,
+                dispute.dispute_status.foreign_into(),
+                storage_enums::EventClass::Disputes,
+                dispute.dispute_id.clone(),
+                storage_enums::EventObjectType::DisputeDetails,
+                api::OutgoingWebhookContent::DisputeDetails(disputes_response),
+                Some(dispute.created_at),
+            ))
+            .await?;
+            (dispute, refund)
+        }
+    };
+
+    let refund_status = refund.as_ref().map(|refund| refund.status);
+    Ok(services::ApplicationResponse::Json(
+        dispute_models::PreDisputeAlertResponse {
+            dispute_id: dispute.dispute_id,
+            alert_id: req.alert_id,
+            payment_id: dispute.payment_id,
+            outcome: get_pre_dispute_alert_outcome(refund_status),
+            refund_id: refund.map(|refund| refund.refund_id),
+            refund_status,
+            dispute_status: dispute.dispute_status,
+        },
+    ))
+}
+
+/// Applies the merchant's pre-dispute alert rule to a newly recorded pre-dispute.
+///
+/// Alerts for a payment which was already refunded are resolved by that refund. Alerts within
+/// the auto refund threshold are resolved by refunding the disputed amount. The dispute is marked
+/// as accepted once the refund is not awaiting approval, and the refund is returned. All other
+/// alerts are left open for the merchant to act upon.
+#[instrument(skip_all)]
+pub async fn apply_pre_dispute_alert_rule(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    dispute: diesel_models::dispute::Dispute,
+    arn: Option<&str>,
+) -> RouterResult<(
+    diesel_models::dispute::Dispute,
+    Option<api_models::refunds::RefundResponse>,
+)> {
+    if let Some(refund) = find_pre_dispute_alert_refund(state, merchant_account, &dispute, arn)
+        .await?
+        .map(api::RefundResponse::foreign_from)
+    {
+        logger::info!(
+            "Pre-dispute alert matched to existing refund {}",
+            refund.refund_id
+        );
+        if refund.status == api_models::refunds::RefundStatus::PendingApproval {
+            return Ok((dispute, Some(refund)));
+        }
+        let dispute = accept_pre_dispute(state, merchant_account, dispute).await?;
+        return Ok((dispute, Some(refund)));
+    }
+
+    let alert_amount = MinorUnit::new(dispute.dispute_amount);
+    let pre_dispute_alert_rule = get_pre_dispute_alert_rule(state, merchant_account.get_id()).await;
+    if !pre_dispute_alert_rule.should_auto_refund(alert_amount) {
+        return Ok((dispute, None));
+    }
+
+    let refund_id = get_pre_dispute_alert_refund_id(&dispute.dispute_id);
+    let refund_request = api_models::refunds::RefundRequest {
+        payment_id: dispute.payment_id.clone(),
+        refund_id: Some(refund_id.clone()),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        amount: Some(alert_amount),
+        reason: Some(consts::PRE_DISPUTE_ALERT_REFUND_REASON.to_string()),
+        refund_type: Some(api_models::refunds::RefundType::Instant),
+        metadata: None,
+        merchant_connector_details: None,
+        charges: None,
+        #[cfg(feature = "payouts")]
+        payout_details: None,
+    };
+    let refund_response = Box::pin(refunds::refund_create_core(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        refund_request,
+        None,
+    ))
+    .await;
+
+    match refund_response {
+        Ok(services::ApplicationResponse::Json(refund))
+            if refund.status == api_models::refunds::RefundStatus::PendingApproval =>
+        {
+            logger::info!(
+                "Automatic refund {} for pre-dispute alert is pending approval",
+                refund.refund_id
+            );
+            Ok((dispute, Some(refund)))
+        }
+        Ok(services::ApplicationResponse::Json(refund))
+            if refund.status != api_models::refunds::RefundStatus::Failed =>
+        {
+            metrics::PRE_DISPUTE_ALERT_AUTO_REFUND_METRIC.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("connector", dispute.connector.clone())]),
+            );
+            let dispute = accept_pre_dispute(state, merchant_account, dispute).await?;
+            Ok((dispute, Some(refund)))
+        }
+        refund_response => {
+            metrics::PRE_DISPUTE_ALERT_AUTO_REFUND_FAILURE_METRIC.add(
+                &metrics::CONTEXT,
+                1,
+                &add_attributes([("connector", dispute.connector.clone())]),
+            );
+            logger::error!(
+                ?refund_response,
+                "Automatic refund for pre-dispute alert failed, notifying merchant instead"
+            );
+            Ok((dispute, None))
+        }
+    }
+}
+
+async fn get_pre_dispute_alert_rule(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> dispute_models::PreDisputeAlertRule {
+    state
+        .store
+        .find_config_by_key_unwrap_or(
+            &merchant_id.get_pre_dispute_alert_rule_key(),
+            Some("{}".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .and_then(|pre_dispute_alert_rule| {
+            serde_json::from_str::<dispute_models::PreDisputeAlertRule>(
+                &pre_dispute_alert_rule.config,
+            )
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Pre-dispute alert rule parsing failed")
+        })
+        .map_err(|error| {
+            logger::error!(pre_dispute_alert_rule_error=?error);
+        })
+        .unwrap_or_default()
+}
+
+async fn accept_pre_dispute(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: diesel_models::dispute::Dispute,
+) -> RouterResult<diesel_models::dispute::Dispute> {
+    let dispute_id = dispute.dispute_id.clone();
+    let update_dispute = diesel_models::dispute::DisputeUpdate::StatusUpdate {
+        dispute_status: storage_enums::DisputeStatus::DisputeAccepted,
+        connector_status: Some(dispute_models::PreDisputeAlertOutcome::Refunded.to_string()),
+    };
+    state
+        .store
+        .update_dispute(dispute, update_dispute, merchant_account.storage_scheme)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Unable to update dispute with dispute_id: {dispute_id}"))
+}
+
+fn get_pre_dispute_alert_refund_id(dispute_id: &str) -> String {
+    format!("{dispute_id}_refund")
+}
+
+fn get_pre_dispute_alert_outcome(
+    refund_status: Option<api_models::refunds::RefundStatus>,
+) -> dispute_models::PreDisputeAlertOutcome {
+    match refund_status {
+        Some(api_models::refunds::RefundStatus::PendingApproval) => {
+            dispute_models::PreDisputeAlertOutcome::RefundPendingApproval
+        }
+        Some(_) => dispute_models::PreDisputeAlertOutcome::Refunded,
+        None => dispute_models::PreDisputeAlertOutcome::MerchantNotified,
+    }
+}
+
+/// A refund resolves a pre-dispute alert if it was made for the alerted transaction. Refunds
+/// carrying an ARN are matched on the ARN when the alert has one, other refunds on the disputed
+/// amount.
+fn is_refund_matching_pre_dispute_alert(
+    refund_arn: Option<&str>,
+    refund_amount: MinorUnit,
+    alert_arn: Option<&str>,
+    alert_amount: MinorUnit,
+) -> bool {
+    match (refund_arn, alert_arn) {
+        (Some(refund_arn), Some(alert_arn)) => refund_arn == alert_arn,
+        _ => refund_amount == alert_amount,
+    }
+}
+
+/// Finds the refund which resolves a pre-dispute alert, either the automatic refund made for the
+/// alert or an earlier refund of the payment matching the alert
+async fn find_pre_dispute_alert_refund(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    dispute: &diesel_models::dispute::Dispute,
+    arn: Option<&str>,
+) -> RouterResult<Option<storage::Refund>> {
+    let refunds = state
+        .store
+        .find_refund_by_payment_id_merchant_id(
+            &dispute.payment_id,
+            merchant_account.get_id(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch refunds of payment for pre-dispute alert")?;
+
+    let auto_refund_id = get_pre_dispute_alert_refund_id(&dispute.dispute_id);
+    let alert_amount = MinorUnit::new(dispute.dispute_amount);
+    let (auto_refunds, other_refunds): (Vec<_>, Vec<_>) = refunds
+        .into_iter()
+        .filter(|refund| {
+            !matches!(
+                refund.refund_status,
+                storage_enums::RefundStatus::Failure
+                    | storage_enums::RefundStatus::TransactionFailure
+            )
+        })
+        .partition(|refund| refund.refund_id == auto_refund_id);
+
+    Ok(auto_refunds.into_iter().next().or_else(|| {
+        other_refunds.into_iter().find(|refund| {
+            is_refund_matching_pre_dispute_alert(
+                refund.refund_arn.as_deref(),
+                refund.refund_amount,
+                arn,
+                alert_amount,
+            )
+        })
+    }))
+}
+
+/// Reference by which a pre-dispute alert is matched to a payment
+#[derive(Debug, PartialEq)]
+enum PreDisputeAlertPaymentReference<'a> {
+    PaymentId(&'a common_utils::id_type::PaymentId),
+    ConnectorTransactionId(&'a str),
+}
+
+/// Alerts carrying only an ARN are rejected, as the ARN is assigned by the acquirer after
+/// clearing and is not stored against the payment attempt
+fn get_pre_dispute_alert_payment_reference(
+    req: &dispute_models::PreDisputeAlertRequest,
+) -> RouterResult<PreDisputeAlertPaymentReference<'_>> {
+    match (&req.payment_id, &req.connector_transaction_id) {
+        (Some(payment_id), _) => Ok(PreDisputeAlertPaymentReference::PaymentId(payment_id)),
+        (None, Some(connector_transaction_id)) => Ok(
+            PreDisputeAlertPaymentReference::ConnectorTransactionId(connector_transaction_id),
+        ),
+        (None, None) => Err(report!(errors::ApiErrorResponse::MissingRequiredFields {
+            field_names: vec!["payment_id", "connector_transaction_id"],
+        })
+        .attach_printable("Pre-dispute alert cannot be matched to a payment by ARN alone")),
+    }
+}
+
+async fn find_payment_attempt_for_pre_dispute_alert(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    req: &dispute_models::PreDisputeAlertRequest,
+) -> RouterResult<storage::PaymentAttempt> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+    match get_pre_dispute_alert_payment_reference(req)? {
+        PreDisputeAlertPaymentReference::PaymentId(payment_id) => {
+            db.find_payment_attempt_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
+                payment_id,
+                merchant_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        }
+        PreDisputeAlertPaymentReference::ConnectorTransactionId(connector_transaction_id) => {
+            db.find_payment_attempt_by_merchant_id_connector_txn_id(
+                merchant_id,
+                connector_transaction_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+        }
+    }
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable("Unable to match pre-dispute alert to a payment")
+}
+
+fn validate_pre_dispute_alert_against_payment_attempt(
+    req: &dispute_models::PreDisputeAlertRequest,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    common_utils::fp_utils::when(
+        payment_attempt.currency != Some(req.currency) || req.amount > payment_attempt.net_amount,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Alert amount and currency do not match the payment".to_string(),
+            }))
+        },
+    )?;
+
+    let transaction_date_difference = (payment_attempt.created_at - req.transaction_date).abs();
+    common_utils::fp_utils::when(
+        transaction_date_difference
+            > time::Duration::days(consts::PRE_DISPUTE_ALERT_TRANSACTION_DATE_TOLERANCE_IN_DAYS),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Alert transaction date does not match the payment".to_string(),
+            }))
+        },
+    )?;
+
+    let payment_card_last4 = payment_attempt
+        .payment_method_data
+        .clone()
+        .map(|payment_method_data| {
+            payment_method_data
+                .parse_value::<api_models::payments::AdditionalPaymentData>("AdditionalPaymentData")
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse additional payment data of payment attempt")?
+        .and_then(|additional_payment_data| match additional_payment_data {
+            api_models::payments::AdditionalPaymentData::Card(card_info) => card_info.last4,
+            _ => None,
+        });
+    common_utils::fp_utils::when(
+        req.card_last4
+            .as_ref()
+            .zip(payment_card_last4.as_ref())
+            .is_some_and(|(alert_last4, payment_last4)| alert_last4 != payment_last4),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Alert card last4 does not match the payment".to_string(),
+            }))
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn get_pre_dispute_alert_request(
+        payment_id: Option<common_utils::id_type::PaymentId>,
+        connector_transaction_id: Option<String>,
+    ) -> dispute_models::PreDisputeAlertRequest {
+        dispute_models::PreDisputeAlertRequest {
+            alert_id: "alert_123".to_string(),
+            alert_provider: dispute_models::PreDisputeAlertProvider::Ethoca,
+            arn: "74537604221431003881865".to_string(),
+            payment_id,
+            connector_transaction_id,
+            amount: MinorUnit::new(1000),
+            currency: common_enums::Currency::USD,
+            transaction_date: common_utils::date_time::now(),
+            card_last4: None,
+            reason: None,
+            reason_code: None,
+        }
+    }
+
+    #[test]
+    fn test_pre_dispute_alert_matched_by_payment_id() {
+        let payment_id =
+            common_utils::id_type::PaymentId::try_from(std::borrow::Cow::Borrowed("pay_123"))
+                .unwrap();
+        let req =
+            get_pre_dispute_alert_request(Some(payment_id.clone()), Some("txn_123".to_string()));
+        assert_eq!(
+            get_pre_dispute_alert_payment_reference(&req).unwrap(),
+            PreDisputeAlertPaymentReference::PaymentId(&payment_id)
+        );
+    }
+
+    #[test]
+    fn test_pre_dispute_alert_matched_by_connector_transaction_id() {
+        let req = get_pre_dispute_alert_request(None, Some("txn_123".to_string()));
+        assert_eq!(
+            get_pre_dispute_alert_payment_reference(&req).unwrap(),
+            PreDisputeAlertPaymentReference::ConnectorTransactionId("txn_123")
+        );
+    }
+
+    #[test]
+    fn test_pre_dispute_alert_with_only_arn_is_rejected() {
+        let req = get_pre_dispute_alert_request(None, None);
+        assert!(get_pre_dispute_alert_payment_reference(&req).is_err());
+    }
+
+    #[test]
+    fn test_pre_dispute_alert_refund_id() {
+        assert_eq!(get_pre_dispute_alert_refund_id("dp_123"), "dp_123_refund");
+    }
+
+    #[test]
+    fn test_refund_matched_to_pre_dispute_alert() {
+        let arn = "74537604221431003881865";
+        let amount = MinorUnit::new(1000);
+
+        assert!(is_refund_matching_pre_dispute_alert(
+            Some(arn),
+            MinorUnit::new(500),
+            Some(arn),
+            amount
+        ));
+        assert!(!is_refund_matching_pre_dispute_alert(
+            Some("74537604221431003881866"),
+            amount,
+            Some(arn),
+            amount
+        ));
+        assert!(is_refund_matching_pre_dispute_alert(
+            None,
+            amount,
+            Some(arn),
+            amount
+        ));
+        assert!(!is_refund_matching_pre_dispute_alert(
+            None,
+            MinorUnit::new(500),
+            None,
+            amount
+        ));
+    }
+
+    #[test]
+    fn test_pre_dispute_alert_outcome() {
+        assert_eq!(
+            get_pre_dispute_alert_outcome(Some(api_models::refunds::RefundStatus::PendingApproval)),
+            dispute_models::PreDisputeAlertOutcome::RefundPendingApproval
+        );
+        assert_eq!(
+            get_pre_dispute_alert_outcome(Some(api_models::refunds::RefundStatus::Pending)),
+            dispute_models::PreDisputeAlertOutcome::Refunded
+        );
+        assert_eq!(
+            get_pre_dispute_alert_outcome(None),
+            dispute_models::PreDisputeAlertOutcome::MerchantNotified
+        );
+    }
+}
@@ -16,6 +16,12 @@ pub enum CustomersErrorResponse {
 
     #[error("Customer with the given customer id already exists")]
     CustomerAlreadyExists,
+
+    #[error("Address does not exist in our records")]
+    AddressNotFound,
+
+    #[error("{message}")]
+    InvalidRequestData { message: String },
 }
 
 impl actix_web::ResponseError for CustomersErrorResponse {
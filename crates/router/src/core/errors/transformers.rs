@@ -34,6 +34,15 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::AddressNotFound => AER::NotFound(ApiError::new(
+                "HE",
+                4,
+                "Address does not exist in our records",
+                None,
+            )),
+            Self::InvalidRequestData { message } => {
+                AER::BadRequest(ApiError::new("IR", 6, message.to_string(), None))
+            }
         }
     }
 }
@@ -62,6 +71,10 @@ impl ErrorSwitch<CustomersErrorResponse> for ApiErrorResponse {
             Self::InternalServerError => CER::InternalServerError,
             Self::MandateActive => CER::MandateActive,
             Self::CustomerNotFound => CER::CustomerNotFound,
+            Self::AddressNotFound => CER::AddressNotFound,
+            Self::InvalidRequestData { message } => CER::InvalidRequestData {
+                message: message.to_owned(),
+            },
             _ => CER::InternalServerError,
         }
     }
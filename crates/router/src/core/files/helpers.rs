@@ -5,6 +5,7 @@ use futures::TryStreamExt;
 use hyperswitch_domain_models::router_response_types::disputes::FileInfo;
 
 use crate::{
+    consts,
     core::{
         errors::{self, StorageErrorExt},
         payments, utils,
@@ -27,6 +28,8 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     let purpose = read_string(field).await;
     match purpose.as_deref() {
         Some("dispute_evidence") => Some(api::FilePurpose::DisputeEvidence),
+        Some("payout_batch") => Some(api::FilePurpose::PayoutBatch),
+        Some("payment_method_migration") => Some(api::FilePurpose::PaymentMethodMigration),
         _ => None,
     }
 }
@@ -44,7 +47,11 @@ pub async fn validate_file_upload(
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
             let dispute = state
                 .store
-                .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), dispute_id)
+                .find_dispute_by_merchant_id_dispute_id(
+                    merchant_account.get_id(),
+                    dispute_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
                     dispute_id: dispute_id.to_string(),
@@ -78,6 +85,34 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        api::FilePurpose::PayoutBatch => {
+            let supported_file_types = ["text/csv", "application/json"];
+            if create_file_request.file_size > consts::PAYOUT_BATCH_MAX_FILE_SIZE {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_size exceeded the max file size of 10MB".to_owned(),
+                })?
+            }
+            if !supported_file_types.contains(&create_file_request.file_type.essence_str()) {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV or JSON format".to_owned(),
+                })?
+            }
+            Ok(())
+        }
+        api::FilePurpose::PaymentMethodMigration => {
+            let supported_file_types = ["text/csv", "application/jsonl"];
+            if create_file_request.file_size > consts::PAYMENT_METHOD_MIGRATION_MAX_FILE_SIZE {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_size exceeded the max file size of 100MB".to_owned(),
+                })?
+            }
+            if !supported_file_types.contains(&create_file_request.file_type.essence_str()) {
+                Err(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "file_type does not match CSV or JSONL format".to_owned(),
+                })?
+            }
+            Ok(())
+        }
     }
 }
 
@@ -258,7 +293,11 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 .ok_or(errors::ApiErrorResponse::MissingDisputeId)?;
             let dispute = state
                 .store
-                .find_dispute_by_merchant_id_dispute_id(merchant_account.get_id(), &dispute_id)
+                .find_dispute_by_merchant_id_dispute_id(
+                    merchant_account.get_id(),
+                    &dispute_id,
+                    merchant_account.storage_scheme,
+                )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound { dispute_id })?;
             let connector_data = api::ConnectorData::get_connector_by_name(
@@ -349,5 +388,18 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::PayoutBatch | api::FilePurpose::PaymentMethodMigration => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
@@ -20,6 +20,7 @@ use super::errors::{ConnectorErrorExt, RouterResponse};
 use crate::{
     core::{
         errors::{self, RouterResult},
+        payment_methods,
         payments::{self, flows::ConstructFlowSpecificData, operations::BoxedOperation},
     },
     db::StorageInterface,
@@ -386,6 +387,14 @@ where
         profile_id,
     };
 
+    let card_fingerprint_customer_count =
+        payment_methods::fingerprint::get_payment_fingerprint_customer_count(
+            state,
+            merchant_account.get_id(),
+            &payment_data,
+        )
+        .await;
+
     let payment_to_frm_data = PaymentToFrmData {
         amount: payment_data.amount,
         payment_intent: payment_data.payment_intent.to_owned(),
@@ -395,6 +404,7 @@ where
         connector_details: frm_connector_details.clone(),
         order_details,
         frm_metadata: payment_data.payment_intent.frm_metadata,
+        card_fingerprint_customer_count,
     };
 
     let fraud_check_operation: operation::BoxedFraudCheckOperation<F> =
@@ -715,6 +725,7 @@ pub async fn frm_fulfillment_core(
                 .find_fraud_check_by_payment_id_if_present(
                     req.payment_id.clone(),
                     merchant_account.get_id().clone(),
+                    merchant_account.storage_scheme,
                 )
                 .await
                 .change_context(invalid_request_error.to_owned())?;
@@ -804,7 +815,11 @@ pub async fn make_fulfillment_api_call(
         payment_capture_method: fraud_check.payment_capture_method,
     };
     let _updated = db
-        .update_fraud_check_response_with_attempt_id(fraud_check_copy, fraud_check_update)
+        .update_fraud_check_response_with_attempt_id(
+            fraud_check_copy,
+            fraud_check_update,
+            merchant_account.storage_scheme,
+        )
         .await
         .map_err(|error| error.change_context(errors::ApiErrorResponse::PaymentNotFound))?;
     let fulfillment_response =
@@ -120,6 +120,7 @@ impl ConstructFlowSpecificData<frm_api::Checkout, FraudCheckCheckoutData, FraudC
                         field_name: "customer.customer_data.email",
                     })?,
                 gateway: self.payment_attempt.connector.clone(),
+                card_fingerprint_customer_count: self.card_fingerprint_customer_count,
             }, // self.order_details
             response: Ok(FraudCheckResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
@@ -96,6 +96,7 @@ impl ConstructFlowSpecificData<frm_api::Sale, FraudCheckSaleData, FraudCheckResp
                     .change_context(errors::ApiErrorResponse::InvalidDataValue {
                         field_name: "customer.customer_data.email",
                     })?,
+                card_fingerprint_customer_count: self.card_fingerprint_customer_count,
             },
             response: Ok(FraudCheckResponseData::TransactionResponse {
                 resource_id: ResponseId::ConnectorTransactionId("".to_string()),
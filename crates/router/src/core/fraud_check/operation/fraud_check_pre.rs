@@ -80,6 +80,7 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPre {
             .find_fraud_check_by_payment_id_if_present(
                 payment_data.payment_intent.payment_id.clone(),
                 payment_data.merchant_account.get_id().clone(),
+                payment_data.merchant_account.storage_scheme,
             )
             .await
             .ok();
@@ -87,25 +88,28 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPre {
         let fraud_check = match existing_fraud_check {
             Some(Some(fraud_check)) => Ok(fraud_check),
             _ => {
-                db.insert_fraud_check_response(FraudCheckNew {
-                    frm_id: Uuid::new_v4().simple().to_string(),
-                    payment_id: payment_data.payment_intent.payment_id.clone(),
-                    merchant_id: payment_data.merchant_account.get_id().clone(),
-                    attempt_id: payment_data.payment_attempt.attempt_id.clone(),
-                    created_at: common_utils::date_time::now(),
-                    frm_name: frm_connector_details.connector_name,
-                    frm_transaction_id: None,
-                    frm_transaction_type: FraudCheckType::PreFrm,
-                    frm_status: FraudCheckStatus::Pending,
-                    frm_score: None,
-                    frm_reason: None,
-                    frm_error: None,
-                    payment_details,
-                    metadata: None,
-                    modified_at: common_utils::date_time::now(),
-                    last_step: FraudCheckLastStep::Processing,
-                    payment_capture_method: payment_data.payment_attempt.capture_method,
-                })
+                db.insert_fraud_check_response(
+                    FraudCheckNew {
+                        frm_id: Uuid::new_v4().simple().to_string(),
+                        payment_id: payment_data.payment_intent.payment_id.clone(),
+                        merchant_id: payment_data.merchant_account.get_id().clone(),
+                        attempt_id: payment_data.payment_attempt.attempt_id.clone(),
+                        created_at: common_utils::date_time::now(),
+                        frm_name: frm_connector_details.connector_name,
+                        frm_transaction_id: None,
+                        frm_transaction_type: FraudCheckType::PreFrm,
+                        frm_status: FraudCheckStatus::Pending,
+                        frm_score: None,
+                        frm_reason: None,
+                        frm_error: None,
+                        payment_details,
+                        metadata: None,
+                        modified_at: common_utils::date_time::now(),
+                        last_step: FraudCheckLastStep::Processing,
+                        payment_capture_method: payment_data.payment_attempt.capture_method,
+                    },
+                    payment_data.merchant_account.storage_scheme,
+                )
                 .await
             }
         };
@@ -122,6 +126,7 @@ impl GetTracker<PaymentToFrmData> for FraudCheckPre {
                     order_details: payment_data.order_details,
                     refund: None,
                     frm_metadata: payment_data.frm_metadata,
+                    card_fingerprint_customer_count: payment_data.card_fingerprint_customer_count,
                 };
                 Ok(Some(frm_data))
             }
@@ -223,6 +228,9 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPre {
                 payment_method_data: router_data.request.payment_method_data,
                 email: router_data.request.email,
                 gateway: router_data.request.gateway,
+                card_fingerprint_customer_count: router_data
+                    .request
+                    .card_fingerprint_customer_count,
             }),
             response: FrmResponse::Checkout(router_data.response),
         })
@@ -358,6 +366,7 @@ impl<F: Clone + Send> UpdateTracker<FrmData, F> for FraudCheckPre {
                 .update_fraud_check_response_with_attempt_id(
                     frm_data.clone().fraud_check,
                     fraud_check_update,
+                    frm_data.merchant_account.storage_scheme,
                 )
                 .await
                 .map_err(|error| error.change_context(errors::ApiErrorResponse::PaymentNotFound))?,
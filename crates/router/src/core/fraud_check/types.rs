@@ -57,6 +57,7 @@ pub struct FrmData {
     pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
     pub refund: Option<RefundResponse>,
     pub frm_metadata: Option<SecretSerdeValue>,
+    pub card_fingerprint_customer_count: Option<i64>,
 }
 
 #[derive(Debug)]
@@ -81,6 +82,7 @@ pub struct PaymentToFrmData {
     pub connector_details: ConnectorDetailsCore,
     pub order_details: Option<Vec<api_models::payments::OrderDetailsWithAmount>>,
     pub frm_metadata: Option<SecretSerdeValue>,
+    pub card_fingerprint_customer_count: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -0,0 +1,262 @@
+// @ts-check
+/**
+ * Trigger - init
+ * Uses
+ *  - Update document's icon and theme
+ *  - Render merchant details
+ *  - Fetch and render saved payment methods
+ */
+function boot() {
+  // @ts-ignore
+  var manageDetails = window.__PM_MANAGE_DETAILS;
+
+  // Attach document icon
+  if (typeof manageDetails.logo === "string") {
+    var link = document.createElement("link");
+    link.rel = "icon";
+    link.href = manageDetails.logo;
+    link.type = "image/x-icon";
+    document.head.appendChild(link);
+  }
+  // Apply theme
+  if (typeof manageDetails.theme === "string") {
+    document.documentElement.style.setProperty(
+      "--primary-color",
+      manageDetails.theme
+    );
+  }
+  renderMerchantDetails(manageDetails);
+  renderReturnLink(manageDetails);
+  fetchPaymentMethods(manageDetails);
+}
+boot();
+
+/**
+ * Returns the base URL of the APIs, the link is served at
+ * {base_url}/payment_methods/manage/{merchant_id}/{pm_manage_link_id}
+ */
+function getBaseUrl() {
+  return window.location.href.split("/payment_methods/manage/")[0];
+}
+
+/**
+ * Trigger - on boot
+ * Uses
+ *  - Render merchant name and logo
+ */
+function renderMerchantDetails(manageDetails) {
+  var merchantHeaderNode = document.getElementById("merchant-header");
+  if (!(merchantHeaderNode instanceof HTMLDivElement)) {
+    return;
+  }
+  if (typeof manageDetails.merchant_name === "string") {
+    var merchantNameNode = document.createElement("div");
+    merchantNameNode.innerText = manageDetails.merchant_name;
+    merchantHeaderNode.appendChild(merchantNameNode);
+  }
+  if (typeof manageDetails.logo === "string") {
+    var merchantLogoNode = document.createElement("img");
+    merchantLogoNode.src = manageDetails.logo;
+    merchantHeaderNode.appendChild(merchantLogoNode);
+  }
+}
+
+/**
+ * Trigger - on boot
+ * Uses
+ *  - Render the link back to the merchant's page
+ */
+function renderReturnLink(manageDetails) {
+  var returnLinkNode = document.getElementById("return-link");
+  if (
+    typeof manageDetails.return_url === "string" &&
+    returnLinkNode instanceof HTMLDivElement
+  ) {
+    var anchorNode = document.createElement("a");
+    anchorNode.href = manageDetails.return_url;
+    anchorNode.innerText = "Done";
+    returnLinkNode.appendChild(anchorNode);
+  }
+}
+
+/**
+ * Renders a message below the list of saved payment methods
+ */
+function renderMessage(message) {
+  var messageNode = document.getElementById("manage-message");
+  if (messageNode instanceof HTMLDivElement) {
+    messageNode.innerText = message;
+  }
+}
+
+/**
+ * Calls the customer scoped payment method APIs using the ephemeral key of the link
+ */
+function callApi(manageDetails, method, path, body) {
+  var options = {
+    method: method,
+    headers: {
+      "Content-Type": "application/json",
+      "api-key": manageDetails.ephemeral_key,
+    },
+  };
+  if (body) {
+    options.body = JSON.stringify(body);
+  }
+  return fetch(getBaseUrl() + path, options).then(function (res) {
+    return res.json().then(function (data) {
+      if (!res.ok) {
+        var message =
+          data && data.error && typeof data.error.message === "string"
+            ? data.error.message
+            : "Something went wrong, please try again.";
+        throw new Error(message);
+      }
+      return data;
+    });
+  });
+}
+
+/**
+ * Trigger - on boot and after every update
+ * Uses
+ *  - Fetch the saved payment methods of the customer and render them
+ */
+function fetchPaymentMethods(manageDetails) {
+  callApi(manageDetails, "GET", "/customers/payment_methods")
+    .then(function (data) {
+      renderPaymentMethods(manageDetails, data.customer_payment_methods || []);
+    })
+    .catch(function (error) {
+      renderMessage(error.message);
+    });
+}
+
+/**
+ * Returns the label displayed for a saved payment method
+ */
+function getPaymentMethodLabel(paymentMethod) {
+  var card = paymentMethod.card;
+  if (card) {
+    if (typeof card.nick_name === "string" && card.nick_name.length > 0) {
+      return card.nick_name;
+    }
+    return (card.card_network || "Card") + " •••• " + (card.last4_digits || "");
+  }
+  if (paymentMethod.bank && typeof paymentMethod.bank.mask === "string") {
+    return "Bank account •••• " + paymentMethod.bank.mask;
+  }
+  return paymentMethod.payment_method_type || paymentMethod.payment_method;
+}
+
+/**
+ * Returns the description displayed below the label of a saved payment method
+ */
+function getPaymentMethodDescription(paymentMethod) {
+  var card = paymentMethod.card;
+  if (card && card.expiry_month && card.expiry_year) {
+    return "Expires " + card.expiry_month + "/" + card.expiry_year;
+  }
+  return "";
+}
+
+/**
+ * Renders the saved payment methods along with the actions for managing them
+ */
+function renderPaymentMethods(manageDetails, paymentMethods) {
+  var paymentMethodsNode = document.getElementById("payment-methods");
+  if (!(paymentMethodsNode instanceof HTMLDivElement)) {
+    return;
+  }
+  paymentMethodsNode.innerHTML = "";
+  if (paymentMethods.length === 0) {
+    renderMessage("You do not have any saved payment methods.");
+    return;
+  }
+  renderMessage("");
+
+  paymentMethods.forEach(function (paymentMethod) {
+    var paymentMethodId = paymentMethod.payment_method_id;
+    var path = "/customers/payment_methods/" + paymentMethodId;
+
+    var paymentMethodNode = document.createElement("div");
+    paymentMethodNode.className = "payment-method";
+
+    // Label
+    var labelNode = document.createElement("div");
+    labelNode.className = "payment-method-label";
+    var nameNode = document.createElement("div");
+    nameNode.innerText = getPaymentMethodLabel(paymentMethod);
+    if (paymentMethod.default_payment_method_set) {
+      var defaultNode = document.createElement("span");
+      defaultNode.className = "payment-method-default";
+      defaultNode.innerText = "Default";
+      nameNode.appendChild(defaultNode);
+    }
+    var descriptionNode = document.createElement("div");
+    descriptionNode.className = "payment-method-description";
+    descriptionNode.innerText = getPaymentMethodDescription(paymentMethod);
+    labelNode.appendChild(nameNode);
+    labelNode.appendChild(descriptionNode);
+
+    // Actions
+    var actionsNode = document.createElement("div");
+    actionsNode.className = "payment-method-actions";
+
+    var defaultButton = document.createElement("button");
+    defaultButton.innerText = "Make default";
+    defaultButton.disabled = paymentMethod.default_payment_method_set;
+    defaultButton.onclick = function () {
+      callApi(manageDetails, "POST", path + "/default")
+        .then(function () {
+          fetchPaymentMethods(manageDetails);
+        })
+        .catch(function (error) {
+          renderMessage(error.message);
+        });
+    };
+    actionsNode.appendChild(defaultButton);
+
+    if (paymentMethod.card) {
+      var renameButton = document.createElement("button");
+      renameButton.innerText = "Rename";
+      renameButton.onclick = function () {
+        var nickName = window.prompt(
+          "Enter a name for this card",
+          getPaymentMethodLabel(paymentMethod)
+        );
+        if (nickName === null || nickName.trim().length === 0) {
+          return;
+        }
+        callApi(manageDetails, "POST", path, { nick_name: nickName.trim() })
+          .then(function () {
+            fetchPaymentMethods(manageDetails);
+          })
+          .catch(function (error) {
+            renderMessage(error.message);
+          });
+      };
+      actionsNode.appendChild(renameButton);
+    }
+
+    var deleteButton = document.createElement("button");
+    deleteButton.innerText = "Remove";
+    deleteButton.onclick = function () {
+      if (!window.confirm("Remove this payment method?")) {
+        return;
+      }
+      callApi(manageDetails, "DELETE", path)
+        .then(function () {
+          fetchPaymentMethods(manageDetails);
+        })
+        .catch(function (error) {
+          renderMessage(error.message);
+        });
+    };
+    actionsNode.appendChild(deleteButton);
+
+    paymentMethodNode.appendChild(labelNode);
+    paymentMethodNode.appendChild(actionsNode);
+    paymentMethodsNode.appendChild(paymentMethodNode);
+  });
+}
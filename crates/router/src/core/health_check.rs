@@ -72,7 +72,8 @@ impl HealthCheckInterface for app::SessionState {
         &self,
     ) -> CustomResult<HealthState, errors::HealthCheckLockerError> {
         let locker = &self.conf.locker;
-        if !locker.mock_locker {
+        // The embedded vault is covered by the database health check
+        if !locker.mock_locker && !locker.embedded_vault {
             let mut url = locker.host_rs.to_owned();
             url.push_str(consts::LOCKER_HEALTH_CALL_PATH);
             let request = services::Request::new(services::Method::Get, &url);
@@ -32,6 +32,9 @@ counter_metric!(
     ATTACH_EVIDENCE_DISPUTE_STATUS_VALIDATION_FAILURE_METRIC,
     GLOBAL_METER
 );
+counter_metric!(PRE_DISPUTE_ALERT_METRIC, GLOBAL_METER); // No. of pre-dispute alerts received
+counter_metric!(PRE_DISPUTE_ALERT_AUTO_REFUND_METRIC, GLOBAL_METER); // No. of pre-dispute alerts resolved by an automatic refund
+counter_metric!(PRE_DISPUTE_ALERT_AUTO_REFUND_FAILURE_METRIC, GLOBAL_METER); // No. of pre-dispute alerts for which the automatic refund could not be created
 
 counter_metric!(INCOMING_PAYOUT_WEBHOOK_METRIC, GLOBAL_METER); // No. of incoming payout webhooks
 counter_metric!(
@@ -1,4 +1,10 @@
+pub mod account_updater;
 pub mod cards;
+pub mod embedded_vault;
+pub mod fingerprint;
+pub mod health;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub mod manage;
 pub mod migration;
 pub mod surcharge_decision_configs;
 pub mod transformers;
@@ -0,0 +1,811 @@
+use std::collections::HashMap;
+
+use api_models::payment_methods::AccountUpdaterEnrollmentResponse;
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+use api_models::payment_methods::{CardDetailsPaymentMethod, PaymentMethodsData};
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use external_services::account_updater::{AccountUpdate, AccountUpdaterInquiry};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payment_methods::cards,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+const ACCOUNT_UPDATER_TASK: &str = "ACCOUNT_UPDATER";
+const ACCOUNT_UPDATER_RESULTS_TASK: &str = "ACCOUNT_UPDATER_RESULTS";
+const ACCOUNT_UPDATER_TAG: [&str; 2] = ["PAYMENT_METHOD", "ACCOUNT_UPDATER"];
+/// Number of saved cards scanned at a time while looking for cards about to expire
+const PAYMENT_METHODS_PAGE_SIZE: i64 = 100;
+
+fn get_account_updater_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_{ACCOUNT_UPDATER_TASK}_{}",
+        storage::ProcessTrackerRunner::AccountUpdaterWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Enrolls the merchant in the account updater, so that the saved cards of the merchant are
+/// periodically submitted to the account updater.
+#[instrument(skip_all)]
+pub async fn enroll_merchant(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<AccountUpdaterEnrollmentResponse> {
+    let config = &state.conf.account_updater;
+    utils::when(!config.enabled, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "account updater is not enabled".to_string(),
+        })
+    })?;
+
+    let merchant_id = merchant_account.get_id();
+    let process = core_utils::schedule_recurring_process(
+        &state,
+        get_account_updater_process_tracker_id(merchant_id),
+        ACCOUNT_UPDATER_TASK,
+        storage::ProcessTrackerRunner::AccountUpdaterWorkflow,
+        ACCOUNT_UPDATER_TAG,
+        storage::AccountUpdaterTrackingData {
+            merchant_id: merchant_id.to_owned(),
+        },
+        &config.schedule,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        AccountUpdaterEnrollmentResponse {
+            merchant_id: merchant_id.to_owned(),
+            enrolled: true,
+            next_run_at: process.schedule_time,
+        },
+    ))
+}
+
+/// Unenrolls the merchant from the account updater. Saved cards which were already submitted
+/// are still updated once the results of their batches are available.
+#[instrument(skip_all)]
+pub async fn unenroll_merchant(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<AccountUpdaterEnrollmentResponse> {
+    let merchant_id = merchant_account.get_id();
+    core_utils::revoke_recurring_process(
+        &state,
+        &get_account_updater_process_tracker_id(merchant_id),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        AccountUpdaterEnrollmentResponse {
+            merchant_id: merchant_id.to_owned(),
+            enrolled: false,
+            next_run_at: None,
+        },
+    ))
+}
+
+/// Queues the saved card to be submitted to the account updater in the next run. A saved card
+/// which is already queued or submitted is not queued again.
+#[instrument(skip_all)]
+pub async fn add_account_updater_item(
+    state: &SessionState,
+    payment_method: &storage::PaymentMethod,
+    reason: storage_enums::AccountUpdaterReason,
+) -> RouterResult<()> {
+    let now = date_time::now();
+    let account_updater_item = storage::AccountUpdaterItemNew {
+        item_id: utils::generate_id(consts::ID_LENGTH, "au_item"),
+        merchant_id: payment_method.merchant_id.clone(),
+        payment_method_id: payment_method.payment_method_id.clone(),
+        customer_id: payment_method.customer_id.clone(),
+        reason,
+        status: storage_enums::AccountUpdaterItemStatus::Pending,
+        batch_id: None,
+        outcome: None,
+        error_message: None,
+        created_at: now,
+        modified_at: now,
+    };
+
+    match state
+        .store
+        .insert_account_updater_item(account_updater_item)
+        .await
+    {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            logger::debug!(
+                payment_method_id = %payment_method.payment_method_id,
+                "Saved card is already queued for the account updater"
+            );
+            Ok(())
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert account updater item")),
+    }
+}
+
+/// Checks whether a card with the provided expiry expires within `window_in_months` months from
+/// `now`. Cards which expired within the last `window_in_months` months are considered expiring
+/// as well, since their issuers may have reissued them since.
+fn is_card_expiring(
+    card_exp_month: &str,
+    card_exp_year: &str,
+    now: PrimitiveDateTime,
+    window_in_months: u8,
+) -> bool {
+    let (Ok(card_exp_month), Ok(card_exp_year)) = (
+        card_exp_month.trim().parse::<i32>(),
+        card_exp_year.trim().parse::<i32>(),
+    ) else {
+        return false;
+    };
+    if !(1..=12).contains(&card_exp_month) {
+        return false;
+    }
+
+    // Two digit expiry years are relative to the current century
+    let card_exp_year = if card_exp_year < 100 {
+        card_exp_year + now.year() / 100 * 100
+    } else {
+        card_exp_year
+    };
+    let expiry_in_months = card_exp_year * 12 + card_exp_month - 1;
+    let now_in_months = now.year() * 12 + i32::from(u8::from(now.month())) - 1;
+
+    (expiry_in_months - now_in_months).abs() <= i32::from(window_in_months)
+}
+
+/// Queues the active saved cards of the merchant which are about to expire, unless they were
+/// submitted to the account updater recently.
+async fn add_expiring_cards(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let config = &state.conf.account_updater;
+    let now = date_time::now();
+    let recently_submitted_after = now.saturating_sub(time::Duration::days(
+        31 * i64::from(config.expiry_window_in_months),
+    ));
+
+    let mut starting_after = String::new();
+    loop {
+        let payment_methods = db
+            .find_payment_methods_by_merchant_id_payment_method_status(
+                merchant_account.get_id(),
+                storage_enums::PaymentMethod::Card,
+                storage_enums::PaymentMethodStatus::Active,
+                &starting_after,
+                PAYMENT_METHODS_PAGE_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch saved cards of merchant")?;
+
+        for payment_method in &payment_methods {
+            let card = match cards::get_card_details_without_locker_fallback(
+                payment_method,
+                state,
+                key_store,
+            )
+            .await
+            {
+                Ok(card) => card,
+                Err(error) => {
+                    logger::warn!(
+                        ?error,
+                        payment_method_id = %payment_method.payment_method_id,
+                        "Failed to fetch card details of saved card"
+                    );
+                    continue;
+                }
+            };
+            let is_expiring = card
+                .expiry_month
+                .as_ref()
+                .zip(card.expiry_year.as_ref())
+                .map_or(false, |(card_exp_month, card_exp_year)| {
+                    is_card_expiring(
+                        card_exp_month.peek(),
+                        card_exp_year.peek(),
+                        now,
+                        config.expiry_window_in_months,
+                    )
+                });
+            if !is_expiring {
+                continue;
+            }
+
+            let is_recently_submitted = db
+                .find_account_updater_items_by_merchant_id_payment_method_id(
+                    &payment_method.merchant_id,
+                    &payment_method.payment_method_id,
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch account updater items of saved card")?
+                .iter()
+                .any(|item| item.created_at >= recently_submitted_after);
+            if !is_recently_submitted {
+                add_account_updater_item(
+                    state,
+                    payment_method,
+                    storage_enums::AccountUpdaterReason::CardExpiring,
+                )
+                .await?;
+            }
+        }
+
+        let Some(last_payment_method) = payment_methods.last() else {
+            break;
+        };
+        starting_after.clone_from(&last_payment_method.payment_method_id);
+    }
+
+    Ok(())
+}
+
+async fn fail_account_updater_item(
+    state: &SessionState,
+    item: storage::AccountUpdaterItem,
+    error_message: &str,
+) -> RouterResult<()> {
+    state
+        .store
+        .update_account_updater_item(
+            item,
+            storage::AccountUpdaterItemUpdate::ErrorUpdate {
+                error_message: error_message.to_string(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update account updater item")?;
+    Ok(())
+}
+
+/// Queues the expiring saved cards of the merchant and submits all the queued saved cards to the
+/// account updater, in batches of the configured size.
+#[instrument(skip_all)]
+pub async fn submit_account_updater_inquiries(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    add_expiring_cards(state, merchant_account, key_store).await?;
+
+    let batch_size = i64::from(state.conf.account_updater.batch_size);
+    loop {
+        let items = state
+            .store
+            .find_account_updater_items_by_merchant_id_status(
+                merchant_account.get_id(),
+                storage_enums::AccountUpdaterItemStatus::Pending,
+                batch_size,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch pending account updater items")?;
+        if items.is_empty() {
+            break;
+        }
+
+        // Every item of the batch is either submitted or failed, so that the next iteration
+        // picks up the next items
+        submit_account_updater_batch(state, merchant_account, items).await?;
+    }
+
+    Ok(())
+}
+
+async fn submit_account_updater_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    items: Vec<storage::AccountUpdaterItem>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "au_batch");
+
+    let mut inquiries = Vec::with_capacity(items.len());
+    let mut submitted_items = Vec::with_capacity(items.len());
+    for item in items {
+        let payment_method = match db
+            .find_payment_method(&item.payment_method_id, merchant_account.storage_scheme)
+            .await
+        {
+            Ok(payment_method)
+                if payment_method.status == storage_enums::PaymentMethodStatus::Active =>
+            {
+                payment_method
+            }
+            Ok(_) => {
+                fail_account_updater_item(state, item, "Saved card is no longer active").await?;
+                continue;
+            }
+            Err(error) if error.current_context().is_db_not_found() => {
+                fail_account_updater_item(state, item, "Saved card no longer exists").await?;
+                continue;
+            }
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch saved card"))
+            }
+        };
+
+        let card = match cards::get_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            payment_method
+                .locker_id
+                .as_ref()
+                .unwrap_or(&payment_method.payment_method_id),
+        )
+        .await
+        {
+            Ok(card) => card,
+            Err(error) => {
+                logger::warn!(?error, item_id = %item.item_id, "Failed to fetch card from locker");
+                fail_account_updater_item(state, item, "Failed to fetch card from locker").await?;
+                continue;
+            }
+        };
+
+        inquiries.push(AccountUpdaterInquiry {
+            reference_id: item.item_id.clone(),
+            card_number: Secret::new(card.card_number.get_card_no()),
+            card_exp_month: card.card_exp_month,
+            card_exp_year: card.card_exp_year,
+        });
+        submitted_items.push(item);
+    }
+
+    if inquiries.is_empty() {
+        return Ok(());
+    }
+
+    state
+        .account_updater_client
+        .submit_inquiries(&batch_id, inquiries)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to submit account updater inquiries")?;
+
+    for item in submitted_items {
+        db.update_account_updater_item(
+            item,
+            storage::AccountUpdaterItemUpdate::SubmissionUpdate {
+                batch_id: batch_id.clone(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update account updater item")?;
+    }
+
+    add_account_updater_results_task(state, merchant_account.get_id(), &batch_id).await
+}
+
+async fn add_account_updater_results_task(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::AccountUpdaterResultsWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        ACCOUNT_UPDATER_RESULTS_TASK,
+        batch_id,
+        merchant_id,
+    );
+    let tracking_data = storage::AccountUpdaterBatchTrackingData {
+        merchant_id: merchant_id.to_owned(),
+        batch_id: batch_id.to_owned(),
+    };
+    let schedule_time = date_time::now().saturating_add(time::Duration::seconds(i64::from(
+        state.conf.account_updater.results_poll_interval_in_secs,
+    )));
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        ACCOUNT_UPDATER_RESULTS_TASK,
+        runner,
+        ACCOUNT_UPDATER_TAG,
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct account updater results process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Failed while inserting task in process_tracker: batch_id: {batch_id}")
+        })?;
+    Ok(())
+}
+
+/// Applies the results of the submitted batch to the saved cards, returns `false` if the results
+/// of the batch are not available yet.
+#[instrument(skip_all)]
+pub async fn process_account_updater_results(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    batch_id: &str,
+) -> RouterResult<bool> {
+    let Some(results) = state
+        .account_updater_client
+        .retrieve_results(batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve account updater results")?
+    else {
+        return Ok(false);
+    };
+    let mut updates = results
+        .into_iter()
+        .map(|result| (result.reference_id, result.update))
+        .collect::<HashMap<_, _>>();
+
+    let items = state
+        .store
+        .find_account_updater_items_by_merchant_id_batch_id(merchant_account.get_id(), batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch account updater items of batch")?;
+    for item in items
+        .into_iter()
+        .filter(|item| item.status == storage_enums::AccountUpdaterItemStatus::Submitted)
+    {
+        match updates.remove(&item.item_id) {
+            Some(update) => {
+                apply_account_update(state, merchant_account, key_store, item, update).await?
+            }
+            None => {
+                fail_account_updater_item(state, item, "No result received for saved card").await?
+            }
+        }
+    }
+
+    Ok(true)
+}
+
+/// Fails the items of the submitted batch which are still awaiting their results.
+#[instrument(skip_all)]
+pub async fn fail_account_updater_batch(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<()> {
+    let items = state
+        .store
+        .find_account_updater_items_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch account updater items of batch")?;
+    for item in items
+        .into_iter()
+        .filter(|item| item.status == storage_enums::AccountUpdaterItemStatus::Submitted)
+    {
+        fail_account_updater_item(state, item, "Results of batch were not received in time")
+            .await?;
+    }
+    Ok(())
+}
+
+fn get_account_updater_outcome(update: &AccountUpdate) -> storage_enums::AccountUpdaterOutcome {
+    match update {
+        AccountUpdate::NoChange => storage_enums::AccountUpdaterOutcome::NoChange,
+        AccountUpdate::NewAccountNumber { .. } => {
+            storage_enums::AccountUpdaterOutcome::NewAccountNumber
+        }
+        AccountUpdate::NewExpiry { .. } => storage_enums::AccountUpdaterOutcome::NewExpiry,
+        AccountUpdate::AccountClosed => storage_enums::AccountUpdaterOutcome::AccountClosed,
+        AccountUpdate::ContactCardholder => storage_enums::AccountUpdaterOutcome::ContactCardholder,
+    }
+}
+
+async fn apply_account_update(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    item: storage::AccountUpdaterItem,
+    update: AccountUpdate,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payment_method = match db
+        .find_payment_method(&item.payment_method_id, merchant_account.storage_scheme)
+        .await
+    {
+        Ok(payment_method) => payment_method,
+        Err(error) if error.current_context().is_db_not_found() => {
+            return fail_account_updater_item(state, item, "Saved card no longer exists").await;
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch saved card"))
+        }
+    };
+
+    let outcome = get_account_updater_outcome(&update);
+    let update_result = match update {
+        AccountUpdate::NoChange | AccountUpdate::ContactCardholder => Ok(false),
+        AccountUpdate::NewAccountNumber {
+            card_number,
+            card_exp_month,
+            card_exp_year,
+        } => async {
+            let card_number = ::cards::CardNumber::try_from(card_number.expose())
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Invalid card number received from account updater")?;
+            update_saved_card(
+                state,
+                merchant_account,
+                key_store,
+                payment_method,
+                Some(card_number),
+                card_exp_month,
+                card_exp_year,
+            )
+            .await
+        }
+        .await
+        .map(|_| true),
+        AccountUpdate::NewExpiry {
+            card_exp_month,
+            card_exp_year,
+        } => update_saved_card(
+            state,
+            merchant_account,
+            key_store,
+            payment_method,
+            None,
+            card_exp_month,
+            card_exp_year,
+        )
+        .await
+        .map(|_| true),
+        AccountUpdate::AccountClosed => db
+            .update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::StatusUpdate {
+                    status: Some(storage_enums::PaymentMethodStatus::Inactive),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deactivate saved card")
+            .map(|_| true),
+    };
+
+    let is_updated = match update_result {
+        Ok(is_updated) => is_updated,
+        Err(error) => {
+            logger::error!(?error, item_id = %item.item_id, "Failed to update saved card");
+            return fail_account_updater_item(state, item, "Failed to update saved card").await;
+        }
+    };
+
+    let payment_method_id = item.payment_method_id.clone();
+    db.update_account_updater_item(
+        item,
+        storage::AccountUpdaterItemUpdate::ResultUpdate { outcome },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update account updater item")?;
+
+    if is_updated {
+        cards::trigger_payment_method_webhook(
+            state,
+            merchant_account,
+            key_store,
+            storage_enums::EventType::PaymentMethodUpdated,
+            payment_method_id,
+        )
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to trigger payment method webhook"))
+        .ok();
+    }
+
+    Ok(())
+}
+
+/// Replaces the card stored in the locker with the card details received from the account
+/// updater, and updates the card details saved with the payment method.
+#[cfg(all(
+    any(feature = "v1", feature = "v2"),
+    not(feature = "payment_methods_v2")
+))]
+async fn update_saved_card(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method: storage::PaymentMethod,
+    card_number: Option<::cards::CardNumber>,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+) -> RouterResult<()> {
+    let card_reference = payment_method
+        .locker_id
+        .clone()
+        .unwrap_or_else(|| payment_method.payment_method_id.clone());
+    let existing_card =
+        cards::get_card_details_without_locker_fallback(&payment_method, state, key_store).await?;
+    let card_from_locker = cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        &card_reference,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Error getting card from locker")?;
+
+    let card_number = card_number.unwrap_or(card_from_locker.card_number);
+    let updated_card_details = api::CardDetail {
+        card_number: card_number.clone(),
+        card_exp_month: card_exp_month.clone(),
+        card_exp_year: card_exp_year.clone(),
+        card_holder_name: card_from_locker.name_on_card,
+        nick_name: card_from_locker.nick_name.map(Secret::new),
+        card_issuing_country: existing_card.issuer_country.clone(),
+        card_network: existing_card.card_network.clone(),
+        card_issuer: existing_card.card_issuer.clone(),
+        card_type: existing_card.card_type.clone(),
+    };
+    let new_pm = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: payment_method.payment_method_issuer.clone(),
+        payment_method_issuer_code: payment_method.payment_method_issuer_code,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card: Some(updated_card_details.clone()),
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        client_secret: None,
+        payment_method_data: None,
+        card_network: None,
+        billing: None,
+        connector_mandate_details: None,
+        network_transaction_id: None,
+    };
+
+    // The updated card is stored under a new card reference, so that the payment method keeps
+    // pointing to a card in the locker if any of the following steps fail
+    let (locker_resp, _) = Box::pin(cards::add_card_to_locker(
+        state,
+        new_pm,
+        &updated_card_details,
+        &payment_method.customer_id,
+        merchant_account,
+        None,
+    ))
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to add updated card to locker")?;
+    let locker_id = locker_resp.payment_method_id;
+
+    let updated_card = api::CardDetailFromLocker {
+        last4_digits: Some(card_number.get_last4()),
+        card_isin: Some(card_number.get_card_isin()),
+        expiry_month: Some(card_exp_month),
+        expiry_year: Some(card_exp_year),
+        ..existing_card
+    };
+    let payment_method_data = cards::create_encrypted_data(
+        state,
+        key_store,
+        PaymentMethodsData::Card(CardDetailsPaymentMethod::from(updated_card)),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Unable to encrypt payment method data")?;
+
+    let customer_id = payment_method.customer_id.clone();
+    let merchant_id = payment_method.merchant_id.clone();
+    state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::AdditionalDataUpdate {
+                payment_method_data: Some(payment_method_data.into()),
+                status: None,
+                locker_id: Some(locker_id.clone()),
+                payment_method: None,
+                payment_method_type: None,
+                payment_method_issuer: None,
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    // The card stored under the previous card reference is no longer referenced, failing to
+    // delete it does not fail the update
+    if locker_id != card_reference {
+        cards::delete_card_from_locker(state, &customer_id, &merchant_id, &card_reference)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to delete previous card from locker"))
+            .ok();
+    }
+    Ok(())
+}
+
+#[cfg(all(feature = "v2", feature = "payment_methods_v2"))]
+async fn update_saved_card(
+    _state: &SessionState,
+    _merchant_account: &domain::MerchantAccount,
+    _key_store: &domain::MerchantKeyStore,
+    _payment_method: storage::PaymentMethod,
+    _card_number: Option<::cards::CardNumber>,
+    _card_exp_month: Secret<String>,
+    _card_exp_year: Secret<String>,
+) -> RouterResult<()> {
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_is_card_expiring() {
+        let now = time::macros::datetime!(2024-11-15 10:00);
+
+        // Expiring this month and next month
+        assert!(is_card_expiring("11", "2024", now, 1));
+        assert!(is_card_expiring("12", "24", now, 1));
+        // Expired last month
+        assert!(is_card_expiring("10", "2024", now, 1));
+        // Expiring across the year boundary
+        assert!(is_card_expiring("01", "2025", now, 2));
+        assert!(!is_card_expiring("01", "2025", now, 1));
+        // Expiring later, or expired long ago
+        assert!(!is_card_expiring("03", "2025", now, 1));
+        assert!(!is_card_expiring("05", "2023", now, 1));
+    }
+
+    #[test]
+    fn test_is_card_expiring_with_invalid_expiry() {
+        let now = time::macros::datetime!(2024-11-15 10:00);
+
+        assert!(!is_card_expiring("13", "2024", now, 1));
+        assert!(!is_card_expiring("", "2024", now, 1));
+        assert!(!is_card_expiring("11", "twenty", now, 1));
+    }
+}
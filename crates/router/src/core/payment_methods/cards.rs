@@ -75,13 +75,15 @@ use crate::{
         settings,
     },
     core::{
+        blocklist::utils as blocklist_utils,
+        customers,
         errors::{self, StorageErrorExt},
-        payment_methods::{transformers as payment_methods, vault},
+        payment_methods::{embedded_vault, transformers as payment_methods, vault},
         payments::{
             helpers,
             routing::{self, SessionFlowRoutingInput},
         },
-        utils as core_utils,
+        utils as core_utils, webhooks,
     },
     db, logger,
     pii::prelude::*,
@@ -131,6 +133,7 @@ pub async fn create_payment_method(
     storage_scheme: MerchantStorageScheme,
     payment_method_billing_address: Option<Encryption>,
     card_scheme: Option<String>,
+    fingerprint_id: Option<String>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::ApiErrorResponse> {
     let db = &*state.store;
     let customer = db
@@ -184,6 +187,9 @@ pub async fn create_payment_method(
                 last_used_at: current_time,
                 payment_method_billing_address,
                 updated_by: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                fingerprint_id,
             },
             storage_scheme,
         )
@@ -230,6 +236,7 @@ pub async fn create_payment_method(
     _storage_scheme: MerchantStorageScheme,
     _payment_method_billing_address: Option<Encryption>,
     _card_scheme: Option<String>,
+    _fingerprint_id: Option<String>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::ApiErrorResponse> {
     todo!()
 }
@@ -263,6 +270,7 @@ pub fn store_default_payment_method(
         payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
         last_used_at: Some(common_utils::date_time::now()),
         client_secret: None,
+        health_status: None,
     };
 
     (payment_method_response, None)
@@ -329,6 +337,12 @@ pub async fn get_or_insert_payment_method(
         Ok(pm) => Ok(pm),
         Err(err) => {
             if err.current_context().is_db_not_found() {
+                let fingerprint_id = generate_saved_card_fingerprint(
+                    state,
+                    merchant_account.get_id(),
+                    req.card.as_ref(),
+                )
+                .await;
                 insert_payment_method(
                     state,
                     resp,
@@ -343,6 +357,7 @@ pub async fn get_or_insert_payment_method(
                     req.network_transaction_id.clone(),
                     merchant_account.storage_scheme,
                     None,
+                    fingerprint_id,
                 )
                 .await
             } else {
@@ -367,6 +382,67 @@ pub async fn get_or_insert_payment_method(
     todo!()
 }
 
+/// Generates the fingerprint of the card being saved, which links the saved cards of the merchant
+/// having the same card number. The fingerprint is generated by the vault the card is saved in, so
+/// a card saved while the locker is disabled or mocked is saved without one.
+pub async fn generate_saved_card_fingerprint(
+    state: &routes::SessionState,
+    merchant_id: &id_type::MerchantId,
+    card: Option<&api::CardDetail>,
+) -> Option<String> {
+    let locker = &state.conf.locker;
+    let card_number = &card?.card_number;
+    if locker.embedded_vault {
+        embedded_vault::generate_fingerprint(
+            &state.conf.embedded_vault.get_inner().fingerprint_secret,
+            merchant_id,
+            card_number,
+        )
+        .map_err(|error| logger::error!(?error, "Failed to generate fingerprint of saved card"))
+        .ok()
+    } else if locker.locker_enabled && !locker.mock_locker {
+        blocklist_utils::generate_card_fingerprint(state, merchant_id, card_number)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to generate fingerprint of saved card"))
+            .ok()
+            .flatten()
+    } else {
+        None
+    }
+}
+
+/// Finds the active payment method of the customer saved with the card having the provided
+/// fingerprint, if the customer has already saved the card.
+pub async fn find_customer_payment_method_by_fingerprint_id(
+    state: &routes::SessionState,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    fingerprint_id: &str,
+    storage_scheme: MerchantStorageScheme,
+) -> errors::RouterResult<Option<storage::PaymentMethod>> {
+    let payment_methods = match state
+        .store
+        .find_payment_method_by_customer_id_merchant_id_status(
+            customer_id,
+            merchant_id,
+            enums::PaymentMethodStatus::Active,
+            None,
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(payment_methods) => payment_methods,
+        Err(error) if error.current_context().is_db_not_found() => Vec::new(),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to find payment methods of customer")?,
+    };
+
+    Ok(payment_methods
+        .into_iter()
+        .find(|payment_method| payment_method.fingerprint_id.as_deref() == Some(fingerprint_id)))
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -737,6 +813,9 @@ pub async fn skip_locker_call_and_migrate_payment_method(
                 last_used_at: current_time,
                 payment_method_billing_address: payment_method_billing_address.map(Into::into),
                 updated_by: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                fingerprint_id: None,
             },
             merchant_account.storage_scheme,
         )
@@ -857,6 +936,7 @@ pub async fn get_client_secret_or_add_payment_method(
             merchant_account.storage_scheme,
             payment_method_billing_address.map(Into::into),
             None,
+            None,
         )
         .await?;
 
@@ -1331,34 +1411,69 @@ pub async fn add_payment_method(
             }
         },
         None => {
-            let pm_metadata = resp.metadata.as_ref().map(|data| data.peek());
+            let fingerprint_id =
+                generate_saved_card_fingerprint(state, merchant_id, req.card.as_ref()).await;
+            let existing_pm = match fingerprint_id.as_deref() {
+                Some(fingerprint_id) => {
+                    find_customer_payment_method_by_fingerprint_id(
+                        state,
+                        &customer_id,
+                        merchant_id,
+                        fingerprint_id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await?
+                }
+                None => None,
+            };
 
-            let locker_id = if resp.payment_method == Some(api_enums::PaymentMethod::Card)
-                || resp.payment_method == Some(api_enums::PaymentMethod::BankTransfer)
-            {
-                Some(resp.payment_method_id)
+            if let Some(existing_pm) = existing_pm {
+                // The customer has already saved the card, so the card just added to the locker
+                // is removed and the existing payment method is returned instead
+                delete_card_from_locker(state, &customer_id, merchant_id, &resp.payment_method_id)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(?error, "Failed to delete duplicate card from locker")
+                    })
+                    .ok();
+                update_last_used_at(&existing_pm, state, merchant_account.storage_scheme)
+                    .await
+                    .map_err(|error| logger::error!(?error, "Failed to update last used at"))
+                    .ok();
+
+                resp.payment_method_id = existing_pm.payment_method_id;
+                resp.client_secret = existing_pm.client_secret;
             } else {
-                None
-            };
-            resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
-            let pm = insert_payment_method(
-                state,
-                &resp,
-                &req,
-                key_store,
-                merchant_id,
-                &customer_id,
-                pm_metadata.cloned(),
-                None,
-                locker_id,
-                connector_mandate_details,
-                req.network_transaction_id.clone(),
-                merchant_account.storage_scheme,
-                payment_method_billing_address.map(Into::into),
-            )
-            .await?;
+                let pm_metadata = resp.metadata.as_ref().map(|data| data.peek());
+
+                let locker_id = if resp.payment_method == Some(api_enums::PaymentMethod::Card)
+                    || resp.payment_method == Some(api_enums::PaymentMethod::BankTransfer)
+                {
+                    Some(resp.payment_method_id)
+                } else {
+                    None
+                };
+                resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
+                let pm = insert_payment_method(
+                    state,
+                    &resp,
+                    &req,
+                    key_store,
+                    merchant_id,
+                    &customer_id,
+                    pm_metadata.cloned(),
+                    None,
+                    locker_id,
+                    connector_mandate_details,
+                    req.network_transaction_id.clone(),
+                    merchant_account.storage_scheme,
+                    payment_method_billing_address.map(Into::into),
+                    fingerprint_id,
+                )
+                .await?;
 
-            resp.client_secret = pm.client_secret;
+                resp.client_secret = pm.client_secret;
+            }
         }
     }
 
@@ -1395,6 +1510,7 @@ pub async fn insert_payment_method(
     network_transaction_id: Option<String>,
     storage_scheme: MerchantStorageScheme,
     payment_method_billing_address: Option<Encryption>,
+    fingerprint_id: Option<String>,
 ) -> errors::RouterResult<diesel_models::PaymentMethod> {
     let pm_card_details = resp
         .card
@@ -1429,6 +1545,7 @@ pub async fn insert_payment_method(
             card.card_network
                 .map(|card_network| card_network.to_string())
         }),
+        fingerprint_id,
     )
     .await
 }
@@ -1449,6 +1566,7 @@ pub async fn insert_payment_method(
     network_transaction_id: Option<String>,
     storage_scheme: MerchantStorageScheme,
     payment_method_billing_address: Option<Encryption>,
+    fingerprint_id: Option<String>,
 ) -> errors::RouterResult<diesel_models::PaymentMethod> {
     let pm_card_details = match &resp.payment_method_data {
         Some(api::PaymentMethodResponseData::Card(card_data)) => Some(PaymentMethodsData::Card(
@@ -1482,6 +1600,7 @@ pub async fn insert_payment_method(
         storage_scheme,
         payment_method_billing_address,
         None,
+        fingerprint_id,
     )
     .await
 }
@@ -1699,6 +1818,7 @@ pub async fn update_customer_payment_method(
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: pm.client_secret.clone(),
+                health_status: pm.health_status,
             }
         };
 
@@ -2064,7 +2184,16 @@ pub async fn get_payment_method_from_hs_locker<'a>(
     let locker = &state.conf.locker;
     let jwekey = state.conf.jwekey.get_inner();
 
-    let payment_method_data = if !locker.mock_locker {
+    let payment_method_data = if locker.embedded_vault {
+        let enc_data = embedded_vault::get_payment_method_data_from_vault(
+            state,
+            merchant_id,
+            customer_id,
+            payment_method_reference,
+        )
+        .await?;
+        decode_and_decrypt_locker_data(state, key_store, enc_data).await?
+    } else if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
             locker,
@@ -2126,6 +2255,9 @@ pub async fn call_to_locker_hs(
     locker_choice: api_enums::LockerChoice,
 ) -> errors::CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
     let locker = &state.conf.locker;
+    if locker.embedded_vault {
+        return embedded_vault::store_in_vault(state, payload, customer_id).await;
+    }
     let jwekey = state.conf.jwekey.get_inner();
     let db = &*state.store;
     let stored_card_response = if !locker.mock_locker {
@@ -2223,7 +2355,9 @@ pub async fn get_card_from_hs_locker<'a>(
     let locker = &state.conf.locker;
     let jwekey = &state.conf.jwekey.get_inner();
 
-    if !locker.mock_locker {
+    if locker.embedded_vault {
+        embedded_vault::get_card_from_vault(state, merchant_id, customer_id, card_reference).await
+    } else if !locker.mock_locker {
         let request = payment_methods::mk_get_card_request_hs(
             jwekey,
             locker,
@@ -2279,6 +2413,13 @@ pub async fn delete_card_from_hs_locker<'a>(
     let locker = &state.conf.locker;
     let jwekey = &state.conf.jwekey.get_inner();
 
+    if locker.embedded_vault {
+        return embedded_vault::delete_from_vault(state, merchant_id, customer_id, card_reference)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("card_delete_failure_message");
+    }
+
     let request = payment_methods::mk_delete_card_request_hs(
         jwekey,
         locker,
@@ -2920,6 +3061,7 @@ pub async fn list_payment_methods(
             merchant_account: &merchant_account,
             payment_attempt,
             payment_intent,
+            customer: customer.as_ref(),
             chosen,
         };
         let result = routing::perform_session_flow_routing(sfr, &enums::TransactionType::Payment)
@@ -3525,6 +3667,7 @@ pub async fn list_payment_methods(
                 payment_attempt,
                 payment_intent,
                 billing_address,
+                customer.as_ref(),
                 &mut payment_method_responses,
             ))
             .await?
@@ -3662,6 +3805,7 @@ pub async fn call_surcharge_decision_management(
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: storage::PaymentIntent,
     billing_address: Option<domain::Address>,
+    customer: Option<&domain::Customer>,
     response_payment_method_types: &mut [ResponsePaymentMethodsEnabled],
 ) -> errors::RouterResult<api_surcharge_decision_configs::MerchantSurchargeConfigs> {
     #[cfg(feature = "v1")]
@@ -3678,6 +3822,8 @@ pub async fn call_surcharge_decision_management(
     #[cfg(feature = "v2")]
     let algorithm_ref: routing_types::RoutingAlgorithmRef = todo!();
 
+    let customer_segments = customers::get_segments_of_customer(customer);
+
     let (surcharge_results, merchant_sucharge_configs) =
         perform_surcharge_decision_management_for_payment_method_list(
             &state,
@@ -3685,6 +3831,7 @@ pub async fn call_surcharge_decision_management(
             payment_attempt,
             &payment_intent,
             billing_address.as_ref().map(Into::into),
+            &customer_segments,
             response_payment_method_types,
         )
         .await
@@ -3734,12 +3881,21 @@ pub async fn call_surcharge_decision_management_for_saved_card(
     #[cfg(feature = "v2")]
     let algorithm_ref: routing_types::RoutingAlgorithmRef = todo!();
 
+    let customer_segments = customers::get_customer_segments(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent.customer_id.as_ref(),
+    )
+    .await;
+
     // TODO: Move to business profile surcharge column
     let surcharge_results = perform_surcharge_decision_management_for_saved_cards(
         state,
         algorithm_ref,
         payment_attempt,
         &payment_intent,
+        &customer_segments,
         &mut customer_payment_method_response.customer_payment_methods,
     )
     .await
@@ -4214,7 +4370,7 @@ pub async fn list_customer_payment_method(
 
     let requires_cvv = is_requires_cvv.config != "false";
 
-    let resp = db
+    let mut resp = db
         .find_payment_method_by_customer_id_merchant_id_status(
             customer_id,
             merchant_account.get_id(),
@@ -4224,6 +4380,11 @@ pub async fn list_customer_payment_method(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    // Unhealthy payment methods are likely to be declined, so they are listed after the healthy
+    // ones, retaining the order of the payment methods otherwise
+    resp.sort_by_key(
+        |pm| matches!(pm.health_status, Some(health_status) if health_status.is_unhealthy()),
+    );
     let mut customer_pms = Vec::new();
 
     let profile_id = payment_intent
@@ -4344,6 +4505,7 @@ pub async fn list_customer_payment_method(
             default_payment_method_set: customer.default_payment_method_id.is_some()
                 && customer.default_payment_method_id == Some(pm.payment_method_id),
             billing: payment_method_billing,
+            health_status: pm.health_status,
         };
         if requires_cvv || mca_enabled {
             customer_pms.push(pma.to_owned());
@@ -4712,7 +4874,7 @@ pub async fn list_customer_payment_method(
         .await
         .transpose()?;
 
-    let saved_payment_methods = db
+    let mut saved_payment_methods = db
         .find_payment_method_by_customer_id_merchant_id_status(
             customer_id,
             merchant_account.get_id(),
@@ -4722,6 +4884,11 @@ pub async fn list_customer_payment_method(
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    // Unhealthy payment methods are likely to be declined, so they are listed after the healthy
+    // ones, retaining the order of the payment methods otherwise
+    saved_payment_methods.sort_by_key(
+        |pm| matches!(pm.health_status, Some(health_status) if health_status.is_unhealthy()),
+    );
 
     let mut filtered_saved_payment_methods_ctx = Vec::new();
     for pm in saved_payment_methods.into_iter() {
@@ -4891,6 +5058,7 @@ async fn generate_saved_pm_response(
         is_default: customer.default_payment_method_id.is_some()
             && customer.default_payment_method_id == Some(pm.payment_method_id),
         billing: payment_method_billing,
+        health_status: pm.health_status,
     };
 
     payment_info
@@ -5588,6 +5756,7 @@ pub async fn retrieve_payment_method(
             payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
             last_used_at: Some(pm.last_used_at),
             client_secret: pm.client_secret,
+            health_status: pm.health_status,
         },
     ))
 }
@@ -5640,8 +5809,61 @@ pub async fn retrieve_payment_method(
             payment_method_data: card.clone().map(api::PaymentMethodResponseData::Card),
             last_used_at: Some(pm.last_used_at),
             client_secret: pm.client_secret,
+            health_status: pm.health_status,
+        },
+    ))
+}
+
+/// Notifies the merchant of a change to the saved payment method, through the default business
+/// profile of the merchant.
+#[instrument(skip_all)]
+pub async fn trigger_payment_method_webhook(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    event_type: enums::EventType,
+    payment_method_id: String,
+) -> errors::RouterResult<()> {
+    let Some(profile_id) = merchant_account.default_profile.as_ref() else {
+        logger::info!("Merchant has no default business profile to send webhooks through");
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let services::ApplicationResponse::Json(payment_method_response) = retrieve_payment_method(
+        state.clone(),
+        api::PaymentMethodId {
+            payment_method_id: payment_method_id.clone(),
         },
+        key_store.clone(),
+        merchant_account.clone(),
+    )
+    .await?
+    else {
+        return Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response when retrieving payment method");
+    };
+
+    let primary_object_created_at = payment_method_response.created;
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::PaymentMethods,
+        payment_method_id,
+        enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(payment_method_response)),
+        primary_object_created_at,
     ))
+    .await
 }
 
 #[cfg(all(
@@ -0,0 +1,834 @@
+//! An in-process card vault, storing payment methods in the application database as an
+//! alternative to the external locker.
+//!
+//! The stored data is encrypted with a data key of the merchant. Data keys are generated by the
+//! application and stored encrypted by the encryption management service, which is called only
+//! when a data key is not cached. Rotating the data key of a merchant re-encrypts the data
+//! stored with the previous key in the background.
+
+use api_models::{embedded_vault as embedded_vault_api, payment_methods::Card};
+use common_utils::{
+    crypto::{self, DecodeMessage, EncodeMessage, GcmAes256, SignMessage},
+    encryption::Encryption,
+    ext_traits::{ByteSliceExt, Encode},
+    id_type,
+};
+use error_stack::{report, ResultExt};
+use masking::{PeekInterface, Secret};
+use once_cell::sync::Lazy;
+use router_env::{instrument, logger, tracing};
+use storage_impl::redis::cache::{Cache, CacheKey};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult},
+        payment_methods::transformers as payment_methods,
+    },
+    db::StorageInterface,
+    routes::{app::SessionStateInfo, SessionState},
+    services,
+    types::{
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+    utils::generate_id,
+};
+
+const DATA_KEY_LENGTH: usize = 32;
+const KEY_ROTATION_TASK: &str = "VAULT_KEY_ROTATION";
+const DEFAULT_ACCESS_LOG_LIMIT: i64 = 100;
+
+/// Data keys decrypted by the encryption management service, by key ID
+static DATA_KEY_CACHE: Lazy<Cache> =
+    Lazy::new(|| Cache::new("EMBEDDED_VAULT_DATA_KEY_CACHE", 30 * 60, 10 * 60, Some(10)));
+
+/// The data stored in a vault entry
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "type", content = "data", rename_all = "snake_case")]
+enum VaultData {
+    Card(Card),
+    /// Payment method data encrypted with the key of the merchant
+    Generic(String),
+}
+
+#[instrument(skip_all)]
+pub async fn store_in_vault(
+    state: &SessionState,
+    payload: &payment_methods::StoreLockerReq,
+    customer_id: &id_type::CustomerId,
+) -> CustomResult<payment_methods::StoreCardRespPayload, errors::VaultError> {
+    let db = &*state.store;
+    let (merchant_id, requestor_reference, data) = match payload {
+        payment_methods::StoreLockerReq::LockerCard(store_card_req) => (
+            &store_card_req.merchant_id,
+            store_card_req.requestor_card_reference.clone(),
+            VaultData::Card(store_card_req.card.clone()),
+        ),
+        payment_methods::StoreLockerReq::LockerGeneric(store_generic_req) => (
+            &store_generic_req.merchant_id,
+            None,
+            VaultData::Generic(store_generic_req.enc_data.clone()),
+        ),
+    };
+
+    let fingerprint = match &data {
+        VaultData::Card(card) => Some(generate_fingerprint(
+            &state.conf.embedded_vault.get_inner().fingerprint_secret,
+            merchant_id,
+            &card.card_number,
+        )?),
+        VaultData::Generic(_) => None,
+    };
+    let existing_entry = match &fingerprint {
+        Some(fingerprint) => db
+            .find_vault_entry_by_fingerprint(merchant_id, customer_id, fingerprint)
+            .await
+            .change_context(errors::VaultError::SaveCardFailed)
+            .attach_printable("Failed to look up vault entry by fingerprint")?,
+        None => None,
+    };
+
+    let (entry, duplication_check) = match existing_entry {
+        Some(entry) => {
+            let existing_key = get_data_key(state, &entry.key_id).await?;
+            let existing_data = decrypt_data(&existing_key, entry.encrypted_data.clone())?;
+            if is_same_data(&existing_data, &data)? {
+                (
+                    entry,
+                    Some(payment_methods::DataDuplicationCheck::Duplicated),
+                )
+            } else {
+                let (data_key, key) = get_active_data_key(state, merchant_id).await?;
+                let entry_update = storage::VaultEntryUpdate::DataUpdate {
+                    key_id: data_key.key_id,
+                    encrypted_data: encrypt_data(&key, &data)?,
+                };
+                let entry = db
+                    .update_vault_entry(entry, entry_update)
+                    .await
+                    .change_context(errors::VaultError::SaveCardFailed)
+                    .attach_printable("Failed to update vault entry")?;
+                (
+                    entry,
+                    Some(payment_methods::DataDuplicationCheck::MetaDataChanged),
+                )
+            }
+        }
+        None => {
+            let (data_key, key) = get_active_data_key(state, merchant_id).await?;
+            let now = common_utils::date_time::now();
+            let entry_new = storage::VaultEntryNew {
+                entry_id: generate_id(consts::ID_LENGTH, "vault"),
+                merchant_id: merchant_id.to_owned(),
+                customer_id: customer_id.to_owned(),
+                key_id: data_key.key_id,
+                encrypted_data: encrypt_data(&key, &data)?,
+                fingerprint,
+                requestor_reference,
+                created_at: now,
+                modified_at: now,
+            };
+            let entry = db
+                .insert_vault_entry(entry_new)
+                .await
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to insert vault entry")?;
+            (entry, None)
+        }
+    };
+
+    record_access(
+        state,
+        merchant_id,
+        Some(&entry.entry_id),
+        Some(&entry.key_id),
+        enums::VaultAccessAction::Store,
+    )
+    .await
+    .change_context(errors::VaultError::SaveCardFailed)?;
+
+    Ok(payment_methods::StoreCardRespPayload {
+        card_reference: entry.entry_id,
+        duplication_check,
+    })
+}
+
+#[instrument(skip_all)]
+pub async fn get_card_from_vault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    entry_id: &str,
+) -> CustomResult<Card, errors::VaultError> {
+    match retrieve_from_vault(state, merchant_id, customer_id, entry_id).await? {
+        VaultData::Card(card) => Ok(card),
+        VaultData::Generic(_) => Err(report!(errors::VaultError::FetchCardFailed))
+            .attach_printable("The vault entry does not contain a card"),
+    }
+}
+
+/// Fetches the payment method data stored in the vault, still encrypted with the key of the
+/// merchant
+#[instrument(skip_all)]
+pub async fn get_payment_method_data_from_vault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    entry_id: &str,
+) -> CustomResult<String, errors::VaultError> {
+    match retrieve_from_vault(state, merchant_id, customer_id, entry_id)
+        .await
+        .change_context(errors::VaultError::FetchPaymentMethodFailed)?
+    {
+        VaultData::Generic(enc_data) => Ok(enc_data),
+        VaultData::Card(_) => Err(report!(errors::VaultError::FetchPaymentMethodFailed))
+            .attach_printable("The vault entry does not contain payment method data"),
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn delete_from_vault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    entry_id: &str,
+) -> CustomResult<payment_methods::DeleteCardResp, errors::VaultError> {
+    let db = &*state.store;
+    let entry = find_customer_entry(db, merchant_id, customer_id, entry_id).await?;
+    db.delete_vault_entry_by_merchant_id_entry_id(merchant_id, entry_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to delete vault entry")?;
+
+    record_access(
+        state,
+        merchant_id,
+        Some(entry_id),
+        Some(&entry.key_id),
+        enums::VaultAccessAction::Delete,
+    )
+    .await
+    .change_context(errors::VaultError::FetchCardFailed)?;
+
+    Ok(payment_methods::DeleteCardResp {
+        status: "Ok".to_string(),
+        error_code: None,
+        error_message: None,
+    })
+}
+
+async fn retrieve_from_vault(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    entry_id: &str,
+) -> CustomResult<VaultData, errors::VaultError> {
+    let entry = find_customer_entry(&*state.store, merchant_id, customer_id, entry_id).await?;
+    let key = get_data_key(state, &entry.key_id).await?;
+    let data = decrypt_data(&key, entry.encrypted_data)?;
+
+    record_access(
+        state,
+        merchant_id,
+        Some(entry_id),
+        Some(&entry.key_id),
+        enums::VaultAccessAction::Retrieve,
+    )
+    .await
+    .change_context(errors::VaultError::FetchCardFailed)?;
+
+    Ok(data)
+}
+
+async fn find_customer_entry(
+    db: &dyn StorageInterface,
+    merchant_id: &id_type::MerchantId,
+    customer_id: &id_type::CustomerId,
+    entry_id: &str,
+) -> CustomResult<storage::VaultEntry, errors::VaultError> {
+    let entry = db
+        .find_vault_entry_by_merchant_id_entry_id(merchant_id, entry_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to fetch vault entry")?;
+
+    if entry.customer_id != *customer_id {
+        return Err(report!(errors::VaultError::FetchCardFailed))
+            .attach_printable("The vault entry belongs to another customer");
+    }
+    Ok(entry)
+}
+
+/// Rotates the data key of the merchant. Newly stored data is encrypted with a new data key right
+/// away, while the data stored with the previous key is re-encrypted by a scheduled task.
+#[instrument(skip_all)]
+pub async fn rotate_data_key(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<embedded_vault_api::DataKeyRotationResponse> {
+    let db = &*state.store;
+    let rotating_key = match db
+        .find_active_vault_data_key_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch active data key")?
+    {
+        Some(data_key) => Some(
+            db.update_vault_data_key(
+                data_key,
+                storage::VaultDataKeyUpdate::StatusUpdate {
+                    status: enums::VaultDataKeyStatus::Rotating,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update data key status")?,
+        ),
+        None => None,
+    };
+
+    let active_key = insert_data_key(&state, &merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // Keys left rotating by earlier attempts which failed to schedule their task, and keys whose
+    // rotation failed, are picked up too
+    let data_keys = db
+        .list_vault_data_keys_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list data keys")?;
+    for data_key in data_keys {
+        let data_key = match data_key.status {
+            enums::VaultDataKeyStatus::Rotating => data_key,
+            enums::VaultDataKeyStatus::RotationFailed => db
+                .update_vault_data_key(
+                    data_key,
+                    storage::VaultDataKeyUpdate::StatusUpdate {
+                        status: enums::VaultDataKeyStatus::Rotating,
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update data key status")?,
+            enums::VaultDataKeyStatus::Active | enums::VaultDataKeyStatus::Retired => continue,
+        };
+        add_key_rotation_task(&state, &data_key).await?;
+    }
+
+    record_access(
+        &state,
+        &merchant_id,
+        None,
+        Some(&active_key.key_id),
+        enums::VaultAccessAction::RotateKey,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    Ok(services::ApplicationResponse::Json(
+        embedded_vault_api::DataKeyRotationResponse {
+            merchant_id,
+            active_key: ForeignFrom::foreign_from(active_key),
+            rotating_key: rotating_key.map(ForeignFrom::foreign_from),
+        },
+    ))
+}
+
+async fn add_key_rotation_task(
+    state: &SessionState,
+    data_key: &storage::VaultDataKey,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::VaultKeyRotationWorkflow;
+    let process_tracker_id = scheduler::utils::get_process_tracker_id(
+        runner,
+        KEY_ROTATION_TASK,
+        &data_key.key_id,
+        &data_key.merchant_id,
+    );
+    let tracking_data = storage::VaultKeyRotationTrackingData {
+        merchant_id: data_key.merchant_id.clone(),
+        key_id: data_key.key_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id.clone(),
+        KEY_ROTATION_TASK,
+        runner,
+        ["EMBEDDED_VAULT"],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct key rotation process tracker task")?;
+
+    match state.store.insert_process(process_tracker_entry).await {
+        Ok(_) => Ok(()),
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            let process = state
+                .store
+                .find_process_by_id(&process_tracker_id)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch key rotation process tracker task")?;
+            match process {
+                // The task of a key whose rotation failed has finished, and is run again
+                Some(process) if process.status == enums::ProcessTrackerStatus::Finish => {
+                    state
+                        .store
+                        .reset_process(process, common_utils::date_time::now())
+                        .await
+                        .change_context(errors::ApiErrorResponse::InternalServerError)
+                        .attach_printable("Failed to restart key rotation process tracker task")?;
+                    logger::info!(key_id = %data_key.key_id, "Restarted key rotation task");
+                }
+                _ => logger::debug!(key_id = %data_key.key_id, "Key rotation task already exists"),
+            }
+            Ok(())
+        }
+        Err(error) => Err(error
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert key rotation process tracker task")),
+    }
+}
+
+/// Marks the rotation of the data key `key_id` as failed, once its re-encryption cannot be retried
+/// anymore. Rotating the data key of the merchant again restarts the rotation.
+pub async fn fail_key_rotation(state: &SessionState, key_id: &str) -> RouterResult<()> {
+    let db = &*state.store;
+    let data_key = db
+        .find_vault_data_key_by_key_id(key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch data key")?;
+    if data_key.status != enums::VaultDataKeyStatus::Rotating {
+        return Ok(());
+    }
+
+    db.update_vault_data_key(
+        data_key,
+        storage::VaultDataKeyUpdate::StatusUpdate {
+            status: enums::VaultDataKeyStatus::RotationFailed,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update data key status")?;
+    Ok(())
+}
+
+/// Re-encrypts a batch of the entries stored with the data key `key_id` using the active data key
+/// of the merchant. Returns whether entries stored with the data key may remain.
+#[instrument(skip_all)]
+pub async fn re_encrypt_entries(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    key_id: &str,
+) -> RouterResult<bool> {
+    let db = &*state.store;
+    let batch_size = i64::from(
+        state
+            .conf
+            .embedded_vault
+            .get_inner()
+            .key_rotation_batch_size,
+    );
+    let entries = db
+        .find_vault_entries_by_key_id(key_id, batch_size)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch vault entries of data key")?;
+    if entries.is_empty() {
+        return Ok(false);
+    }
+    let remaining = i64::try_from(entries.len()).unwrap_or(i64::MAX) >= batch_size;
+
+    let old_key = get_data_key(state, key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    let (active_key, new_key) = get_active_data_key(state, merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    for entry in entries {
+        let data = decrypt_data(&old_key, entry.encrypted_data.clone())
+            .change_context(errors::ApiErrorResponse::InternalServerError)?;
+        let entry_update = storage::VaultEntryUpdate::DataUpdate {
+            key_id: active_key.key_id.clone(),
+            encrypted_data: encrypt_data(&new_key, &data)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        };
+        let entry = match db.update_vault_entry(entry, entry_update).await {
+            Ok(entry) => entry,
+            // The entry was updated by a request meanwhile, with the active data key
+            Err(error) if error.current_context().is_db_not_found() => continue,
+            Err(error) => {
+                return Err(error
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update vault entry"))
+            }
+        };
+
+        record_access(
+            state,
+            merchant_id,
+            Some(&entry.entry_id),
+            Some(&entry.key_id),
+            enums::VaultAccessAction::ReEncrypt,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+    }
+
+    Ok(remaining)
+}
+
+#[instrument(skip_all)]
+pub async fn list_data_keys(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+) -> RouterResponse<embedded_vault_api::DataKeyListResponse> {
+    let keys = state
+        .store
+        .list_vault_data_keys_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list data keys")?
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        embedded_vault_api::DataKeyListResponse { merchant_id, keys },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn list_access_logs(
+    state: SessionState,
+    merchant_id: id_type::MerchantId,
+    constraints: embedded_vault_api::AccessLogListConstraints,
+) -> RouterResponse<embedded_vault_api::AccessLogListResponse> {
+    let access_logs = state
+        .store
+        .list_vault_access_logs_by_merchant_id(
+            &merchant_id,
+            constraints.entry_id.as_deref(),
+            constraints
+                .limit
+                .map_or(DEFAULT_ACCESS_LOG_LIMIT, i64::from),
+            constraints.offset.map_or(0, i64::from),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list vault access logs")?
+        .into_iter()
+        .map(ForeignFrom::foreign_from)
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        embedded_vault_api::AccessLogListResponse {
+            merchant_id,
+            access_logs,
+        },
+    ))
+}
+
+/// Fetches the active data key of the merchant, creating it on first use
+async fn get_active_data_key(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<(storage::VaultDataKey, Secret<Vec<u8>>), errors::VaultError> {
+    let db = &*state.store;
+    let data_key = match db
+        .find_active_vault_data_key_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to fetch active data key")?
+    {
+        Some(data_key) => data_key,
+        None => insert_data_key(state, merchant_id).await?,
+    };
+    let key = get_data_key(state, &data_key.key_id).await?;
+
+    Ok((data_key, key))
+}
+
+/// Generates a data key for the merchant, which becomes its active data key
+async fn insert_data_key(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+) -> CustomResult<storage::VaultDataKey, errors::VaultError> {
+    let db = &*state.store;
+    let version = db
+        .list_vault_data_keys_by_merchant_id(merchant_id)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to list data keys")?
+        .iter()
+        .map(|data_key| data_key.version)
+        .max()
+        .map_or(1, |version| version + 1);
+
+    let key = crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>().to_vec();
+    let encrypted_key = state
+        .encryption_client
+        .encrypt(&key)
+        .await
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to encrypt data key")?;
+    let data_key_new = storage::VaultDataKeyNew {
+        key_id: generate_id(consts::ID_LENGTH, "vkey"),
+        merchant_id: merchant_id.to_owned(),
+        version,
+        encrypted_key: Encryption::new(encrypted_key.into()),
+        status: enums::VaultDataKeyStatus::Active,
+        created_at: common_utils::date_time::now(),
+    };
+
+    let data_key = match db.insert_vault_data_key(data_key_new).await {
+        Ok(data_key) => data_key,
+        // Another request created the active data key of the merchant meanwhile
+        Err(error) if error.current_context().is_db_unique_violation() => {
+            return db
+                .find_active_vault_data_key_by_merchant_id(merchant_id)
+                .await
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to fetch active data key")?
+                .ok_or(report!(errors::VaultError::SaveCardFailed))
+                .attach_printable("Active data key not found after a conflicting insert");
+        }
+        Err(error) => {
+            return Err(error
+                .change_context(errors::VaultError::SaveCardFailed)
+                .attach_printable("Failed to insert data key"))
+        }
+    };
+    DATA_KEY_CACHE
+        .push(
+            data_key_cache_key(state, &data_key.key_id),
+            Secret::new(key),
+        )
+        .await;
+
+    Ok(data_key)
+}
+
+async fn get_data_key(
+    state: &SessionState,
+    key_id: &str,
+) -> CustomResult<Secret<Vec<u8>>, errors::VaultError> {
+    let cache_key = data_key_cache_key(state, key_id);
+    if let Some(key) = DATA_KEY_CACHE
+        .get_val::<Secret<Vec<u8>>>(cache_key.clone())
+        .await
+    {
+        return Ok(key);
+    }
+
+    let data_key = state
+        .store
+        .find_vault_data_key_by_key_id(key_id)
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to fetch data key")?;
+    let key = state
+        .encryption_client
+        .decrypt(data_key.encrypted_key.get_inner().peek())
+        .await
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to decrypt data key")
+        .map(Secret::new)?;
+    DATA_KEY_CACHE.push(cache_key, key.clone()).await;
+
+    Ok(key)
+}
+
+fn data_key_cache_key(state: &SessionState, key_id: &str) -> CacheKey {
+    CacheKey {
+        key: key_id.to_owned(),
+        prefix: state.tenant.redis_key_prefix.clone(),
+    }
+}
+
+async fn record_access(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    entry_id: Option<&str>,
+    key_id: Option<&str>,
+    action: enums::VaultAccessAction,
+) -> CustomResult<(), errors::StorageError> {
+    let access_log = storage::VaultAccessLogNew {
+        log_id: generate_id(consts::ID_LENGTH, "vlog"),
+        merchant_id: merchant_id.to_owned(),
+        entry_id: entry_id.map(ToOwned::to_owned),
+        key_id: key_id.map(ToOwned::to_owned),
+        action,
+        request_id: state.get_request_id(),
+        created_at: common_utils::date_time::now(),
+    };
+
+    state
+        .store
+        .insert_vault_access_log(access_log)
+        .await
+        .attach_printable("Failed to insert vault access log")
+        .map(|_| ())
+}
+
+/// Computes the fingerprint of a card number, scoped to the merchant. The fingerprint is keyed so
+/// that card numbers cannot be recovered from it by enumeration.
+pub fn generate_fingerprint(
+    secret: &Secret<String>,
+    merchant_id: &id_type::MerchantId,
+    card_number: &cards::CardNumber,
+) -> CustomResult<String, errors::VaultError> {
+    let message = format!(
+        "{}:{}",
+        merchant_id.get_string_repr(),
+        card_number.get_card_no()
+    );
+    crypto::HmacSha256
+        .sign_message(secret.peek().as_bytes(), message.as_bytes())
+        .map(hex::encode)
+        .change_context(errors::VaultError::GenerateFingerprintFailed)
+}
+
+fn encrypt_data(
+    key: &Secret<Vec<u8>>,
+    data: &VaultData,
+) -> CustomResult<Encryption, errors::VaultError> {
+    let plaintext = data
+        .encode_to_vec()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+    GcmAes256
+        .encode_message(key.peek(), &plaintext)
+        .map(|ciphertext| Encryption::new(ciphertext.into()))
+        .change_context(errors::VaultError::SaveCardFailed)
+        .attach_printable("Failed to encrypt vault data")
+}
+
+fn decrypt_data(
+    key: &Secret<Vec<u8>>,
+    encrypted_data: Encryption,
+) -> CustomResult<VaultData, errors::VaultError> {
+    GcmAes256
+        .decode_message(key.peek(), encrypted_data.into_inner())
+        .change_context(errors::VaultError::FetchCardFailed)
+        .attach_printable("Failed to decrypt vault data")?
+        .parse_struct("VaultData")
+        .change_context(errors::VaultError::ResponseDeserializationFailed)
+}
+
+fn is_same_data(
+    existing_data: &VaultData,
+    data: &VaultData,
+) -> CustomResult<bool, errors::VaultError> {
+    let existing_data = existing_data
+        .encode_to_vec()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+    let data = data
+        .encode_to_vec()
+        .change_context(errors::VaultError::RequestEncodingFailed)?;
+    Ok(existing_data == data)
+}
+
+impl ForeignFrom<storage::VaultDataKey> for embedded_vault_api::DataKeyResponse {
+    fn foreign_from(data_key: storage::VaultDataKey) -> Self {
+        Self {
+            key_id: data_key.key_id,
+            version: data_key.version,
+            status: data_key.status,
+            created_at: data_key.created_at,
+            retired_at: data_key.retired_at,
+        }
+    }
+}
+
+impl ForeignFrom<storage::VaultAccessLog> for embedded_vault_api::AccessLogResponse {
+    fn foreign_from(access_log: storage::VaultAccessLog) -> Self {
+        Self {
+            log_id: access_log.log_id,
+            entry_id: access_log.entry_id,
+            key_id: access_log.key_id,
+            action: access_log.action,
+            request_id: access_log.request_id,
+            created_at: access_log.created_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn card(card_number: &str, nick_name: Option<&str>) -> VaultData {
+        VaultData::Card(Card {
+            card_number: cards::CardNumber::from_str(card_number).unwrap(),
+            name_on_card: None,
+            card_exp_month: "12".to_string().into(),
+            card_exp_year: "2030".to_string().into(),
+            card_brand: None,
+            card_isin: None,
+            nick_name: nick_name.map(ToOwned::to_owned),
+        })
+    }
+
+    #[test]
+    fn test_fingerprint_is_keyed_and_scoped_to_merchant() {
+        let secret = Secret::new("fingerprint_secret".to_string());
+        let merchant_id = id_type::MerchantId::default();
+        let other_merchant_id =
+            id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_2")).unwrap();
+        let card_number = cards::CardNumber::from_str("4111111111111111").unwrap();
+
+        let fingerprint = generate_fingerprint(&secret, &merchant_id, &card_number).unwrap();
+        assert_eq!(
+            fingerprint,
+            generate_fingerprint(&secret, &merchant_id, &card_number).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_fingerprint(&secret, &other_merchant_id, &card_number).unwrap()
+        );
+        assert_ne!(
+            fingerprint,
+            generate_fingerprint(
+                &Secret::new("another_secret".to_string()),
+                &merchant_id,
+                &card_number
+            )
+            .unwrap()
+        );
+        assert!(!fingerprint.contains("4111111111111111"));
+    }
+
+    #[test]
+    fn test_data_encryption_roundtrip() {
+        let key = Secret::new(
+            crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>().to_vec(),
+        );
+        let data = card("4111111111111111", Some("personal"));
+
+        let encrypted_data = encrypt_data(&key, &data).unwrap();
+        let decrypted_data = decrypt_data(&key, encrypted_data.clone()).unwrap();
+        assert!(is_same_data(&data, &decrypted_data).unwrap());
+
+        let other_key = Secret::new(
+            crypto::generate_cryptographically_secure_random_bytes::<DATA_KEY_LENGTH>().to_vec(),
+        );
+        assert!(decrypt_data(&other_key, encrypted_data).is_err());
+    }
+
+    #[test]
+    fn test_changed_card_details_are_not_duplicates() {
+        let data = card("4111111111111111", Some("personal"));
+
+        assert!(is_same_data(&data, &card("4111111111111111", Some("personal"))).unwrap());
+        assert!(!is_same_data(&data, &card("4111111111111111", Some("business"))).unwrap());
+        assert!(!is_same_data(&data, &VaultData::Generic("enc_data".to_string())).unwrap());
+    }
+}
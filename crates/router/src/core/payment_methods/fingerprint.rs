@@ -0,0 +1,212 @@
+use api_models::payment_methods::{
+    FingerprintLinkedCustomer, FingerprintLinkedPayment, PaymentMethodFingerprintLinksRequest,
+    PaymentMethodFingerprintLinksResponse,
+};
+use common_utils::id_type;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payments,
+    },
+    routes::SessionState,
+    services,
+    types::{domain, storage},
+    utils,
+};
+
+/// Maximum number of saved payment methods sharing a fingerprint which are looked up
+const LINKED_PAYMENT_METHODS_LIMIT: i64 = 1000;
+const DEFAULT_LINKED_PAYMENTS_LIMIT: u32 = 10;
+const MAX_LINKED_PAYMENTS_LIMIT: u32 = 100;
+
+async fn find_linked_payment_methods(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    fingerprint_id: &str,
+) -> RouterResult<Vec<storage::PaymentMethod>> {
+    state
+        .store
+        .find_payment_methods_by_merchant_id_fingerprint_id(
+            merchant_id,
+            fingerprint_id,
+            Some(LINKED_PAYMENT_METHODS_LIMIT),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment methods by fingerprint")
+}
+
+/// Groups the saved payment methods by their customers, retaining the order in which the
+/// customers first appear.
+fn group_payment_methods_by_customer(
+    payment_methods: impl IntoIterator<Item = (id_type::CustomerId, String, PrimitiveDateTime)>,
+) -> Vec<FingerprintLinkedCustomer> {
+    let mut customers: Vec<FingerprintLinkedCustomer> = Vec::new();
+    for (customer_id, payment_method_id, last_used_at) in payment_methods {
+        match customers
+            .iter_mut()
+            .find(|customer| customer.customer_id == customer_id)
+        {
+            Some(customer) => {
+                customer.payment_method_ids.push(payment_method_id);
+                customer.last_used_at = customer.last_used_at.max(last_used_at);
+            }
+            None => customers.push(FingerprintLinkedCustomer {
+                customer_id,
+                payment_method_ids: vec![payment_method_id],
+                last_used_at,
+            }),
+        }
+    }
+    customers
+}
+
+/// Returns the number of customers of the merchant who have saved the card with the provided
+/// fingerprint. A card saved by many customers is a signal of fraud.
+#[instrument(skip_all)]
+pub async fn get_fingerprint_customer_count(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    fingerprint_id: &str,
+) -> RouterResult<i64> {
+    state
+        .store
+        .get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+            merchant_id,
+            fingerprint_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get customer count of card fingerprint")
+}
+
+/// Returns the number of customers of the merchant who have saved the card used for the payment,
+/// for routing and fraud checks to act on cards shared across customers. The count is only
+/// available for payments whose card is already fingerprinted, such as those made with a saved
+/// card.
+pub async fn get_payment_fingerprint_customer_count<F: Clone>(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_data: &payments::PaymentData<F>,
+) -> Option<i64> {
+    let saved_card_fingerprint_id = payment_data
+        .payment_method_info
+        .as_ref()
+        .and_then(|payment_method| payment_method.fingerprint_id.as_deref());
+    let fingerprint_id = payment_data
+        .payment_attempt
+        .fingerprint_id
+        .as_deref()
+        .or(saved_card_fingerprint_id)?;
+
+    get_fingerprint_customer_count(state, merchant_id, fingerprint_id)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to get customer count of card fingerprint"))
+        .ok()
+}
+
+/// Lists the customers who have saved the card with the provided fingerprint, and the payments
+/// made with the card. Only the payments whose card was fingerprinted are listed.
+#[instrument(skip_all)]
+pub async fn list_fingerprint_links(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    fingerprint_id: String,
+    req: PaymentMethodFingerprintLinksRequest,
+) -> RouterResponse<PaymentMethodFingerprintLinksResponse> {
+    let limit = req.limit.unwrap_or(DEFAULT_LINKED_PAYMENTS_LIMIT);
+    utils::when(limit > MAX_LINKED_PAYMENTS_LIMIT, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should not exceed {MAX_LINKED_PAYMENTS_LIMIT}"),
+        })
+    })?;
+
+    let merchant_id = merchant_account.get_id();
+    let payment_methods = find_linked_payment_methods(&state, merchant_id, &fingerprint_id).await?;
+    let customers =
+        group_payment_methods_by_customer(payment_methods.into_iter().map(|payment_method| {
+            (
+                payment_method.customer_id,
+                payment_method.payment_method_id,
+                payment_method.last_used_at,
+            )
+        }));
+
+    let payments = state
+        .store
+        .find_attempts_by_merchant_id_fingerprint_id(
+            merchant_id,
+            &fingerprint_id,
+            Some(i64::from(limit)),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find payment attempts by fingerprint")?
+        .into_iter()
+        .map(|payment_attempt| FingerprintLinkedPayment {
+            payment_id: payment_attempt.payment_id,
+            attempt_id: payment_attempt.attempt_id,
+            status: payment_attempt.status,
+            amount: payment_attempt.amount,
+            currency: payment_attempt.currency,
+            payment_method_id: payment_attempt.payment_method_id,
+            created_at: payment_attempt.created_at,
+        })
+        .collect();
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodFingerprintLinksResponse {
+            fingerprint_id,
+            customer_count: customers.len(),
+            customers,
+            payments,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    #[test]
+    fn test_group_payment_methods_by_customer() {
+        let customer_1 = id_type::CustomerId::try_from(Cow::from("cus_1")).unwrap();
+        let customer_2 = id_type::CustomerId::try_from(Cow::from("cus_2")).unwrap();
+
+        let customers = group_payment_methods_by_customer([
+            (
+                customer_1.clone(),
+                "pm_1".to_string(),
+                time::macros::datetime!(2024-09-20 10:00),
+            ),
+            (
+                customer_2.clone(),
+                "pm_2".to_string(),
+                time::macros::datetime!(2024-09-19 10:00),
+            ),
+            (
+                customer_1.clone(),
+                "pm_3".to_string(),
+                time::macros::datetime!(2024-09-18 10:00),
+            ),
+        ]);
+
+        assert_eq!(customers.len(), 2);
+        assert_eq!(customers[0].customer_id, customer_1);
+        assert_eq!(customers[0].payment_method_ids, vec!["pm_1", "pm_3"]);
+        assert_eq!(
+            customers[0].last_used_at,
+            time::macros::datetime!(2024-09-20 10:00)
+        );
+        assert_eq!(customers[1].customer_id, customer_2);
+        assert_eq!(customers[1].payment_method_ids, vec!["pm_2"]);
+    }
+}
@@ -0,0 +1,339 @@
+use api_models::payment_methods::PaymentMethodHealthEnrollmentResponse;
+use common_utils::{date_time, id_type};
+use error_stack::ResultExt;
+use masking::PeekInterface;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResponse, RouterResult},
+        payment_methods::cards,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+const PAYMENT_METHOD_HEALTH_SWEEP_TASK: &str = "PAYMENT_METHOD_HEALTH_SWEEP";
+const PAYMENT_METHOD_HEALTH_TAG: [&str; 2] = ["PAYMENT_METHOD", "HEALTH"];
+/// Number of saved cards evaluated at a time
+const PAYMENT_METHODS_PAGE_SIZE: i64 = 100;
+
+fn get_payment_method_health_process_tracker_id(merchant_id: &id_type::MerchantId) -> String {
+    format!(
+        "{}_{PAYMENT_METHOD_HEALTH_SWEEP_TASK}_{}",
+        storage::ProcessTrackerRunner::PaymentMethodHealthSweepWorkflow,
+        merchant_id.get_string_repr()
+    )
+}
+
+/// Enrolls the merchant in the payment method health sweep, so that the health of the saved cards
+/// of the merchant is periodically evaluated.
+#[instrument(skip_all)]
+pub async fn enroll_merchant(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<PaymentMethodHealthEnrollmentResponse> {
+    let config = &state.conf.payment_method_health;
+    utils::when(!config.enabled, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "payment method health tracking is not enabled".to_string(),
+        })
+    })?;
+
+    let merchant_id = merchant_account.get_id();
+    let process = core_utils::schedule_recurring_process(
+        &state,
+        get_payment_method_health_process_tracker_id(merchant_id),
+        PAYMENT_METHOD_HEALTH_SWEEP_TASK,
+        storage::ProcessTrackerRunner::PaymentMethodHealthSweepWorkflow,
+        PAYMENT_METHOD_HEALTH_TAG,
+        storage::PaymentMethodHealthTrackingData {
+            merchant_id: merchant_id.to_owned(),
+        },
+        &config.schedule,
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodHealthEnrollmentResponse {
+            merchant_id: merchant_id.to_owned(),
+            enrolled: true,
+            next_run_at: process.schedule_time,
+        },
+    ))
+}
+
+/// Unenrolls the merchant from the payment method health sweep. The health statuses already
+/// recorded on the saved cards of the merchant are retained.
+#[instrument(skip_all)]
+pub async fn unenroll_merchant(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<PaymentMethodHealthEnrollmentResponse> {
+    let merchant_id = merchant_account.get_id();
+    core_utils::revoke_recurring_process(
+        &state,
+        &get_payment_method_health_process_tracker_id(merchant_id),
+    )
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodHealthEnrollmentResponse {
+            merchant_id: merchant_id.to_owned(),
+            enrolled: false,
+            next_run_at: None,
+        },
+    ))
+}
+
+/// Returns the time at which a card with the provided expiry expires, which is the start of the
+/// month following its expiry month.
+fn get_card_expires_at(
+    card_exp_month: &str,
+    card_exp_year: &str,
+    now: PrimitiveDateTime,
+) -> Option<PrimitiveDateTime> {
+    let card_exp_month = card_exp_month.trim().parse::<u8>().ok()?;
+    let card_exp_year = card_exp_year.trim().parse::<i32>().ok()?;
+    let card_exp_month = time::Month::try_from(card_exp_month).ok()?;
+
+    // Two digit expiry years are relative to the current century
+    let card_exp_year = if card_exp_year < 100 {
+        card_exp_year + now.year() / 100 * 100
+    } else {
+        card_exp_year
+    };
+    let expires_at_year = match card_exp_month {
+        time::Month::December => card_exp_year + 1,
+        _ => card_exp_year,
+    };
+
+    time::Date::from_calendar_date(expires_at_year, card_exp_month.next(), 1)
+        .ok()
+        .map(|date| date.midnight())
+}
+
+/// Evaluates the health of a saved card from its expiry and the number of merchant initiated
+/// payments made with it which were declined in a row. A card with an invalid expiry is evaluated
+/// on its declines alone.
+fn evaluate_payment_method_health(
+    card_expiry: Option<(&str, &str)>,
+    consecutive_mit_declines: i32,
+    now: PrimitiveDateTime,
+    config: &settings::PaymentMethodHealth,
+) -> storage_enums::PaymentMethodHealthStatus {
+    let expires_at = card_expiry.and_then(|(card_exp_month, card_exp_year)| {
+        get_card_expires_at(card_exp_month, card_exp_year, now)
+    });
+
+    if expires_at.is_some_and(|expires_at| expires_at <= now) {
+        storage_enums::PaymentMethodHealthStatus::Expired
+    } else if consecutive_mit_declines >= i32::from(config.max_consecutive_mit_declines) {
+        storage_enums::PaymentMethodHealthStatus::RepeatedDeclines
+    } else if expires_at.is_some_and(|expires_at| {
+        expires_at - now <= time::Duration::days(i64::from(config.expiry_window_in_days))
+    }) {
+        storage_enums::PaymentMethodHealthStatus::ExpiringSoon
+    } else {
+        storage_enums::PaymentMethodHealthStatus::Healthy
+    }
+}
+
+/// Records the consecutive declined merchant initiated payments made with the saved payment
+/// method, the count is reset once such a payment succeeds. The health status of the payment
+/// method is evaluated from the count in the next sweep.
+#[instrument(skip_all)]
+pub async fn update_consecutive_mit_declines(
+    state: &SessionState,
+    payment_method_id: &str,
+    is_declined: bool,
+) -> RouterResult<()> {
+    // The count is kept in the database, so that the declines of concurrent payments made with
+    // the payment method are all counted
+    if is_declined {
+        state
+            .store
+            .increment_payment_method_consecutive_mit_declines(payment_method_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to increment consecutive mit declines of payment method")?;
+    } else {
+        state
+            .store
+            .reset_payment_method_consecutive_mit_declines(payment_method_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reset consecutive mit declines of payment method")?;
+    }
+    Ok(())
+}
+
+/// Evaluates the health of the active saved cards of the merchant, records the health statuses
+/// which changed and notifies the merchant of each change.
+#[instrument(skip_all)]
+pub async fn sweep_payment_method_health(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let config = &state.conf.payment_method_health;
+    let now = date_time::now();
+
+    let mut starting_after = String::new();
+    loop {
+        let payment_methods = db
+            .find_payment_methods_by_merchant_id_payment_method_status(
+                merchant_account.get_id(),
+                storage_enums::PaymentMethod::Card,
+                storage_enums::PaymentMethodStatus::Active,
+                &starting_after,
+                PAYMENT_METHODS_PAGE_SIZE,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch saved cards of merchant")?;
+        let Some(last_payment_method) = payment_methods.last() else {
+            break;
+        };
+        starting_after.clone_from(&last_payment_method.payment_method_id);
+
+        for payment_method in payment_methods {
+            // A saved card whose details are unavailable is evaluated in the next sweep, rather
+            // than being flagged from its declines alone
+            let card = match cards::get_card_details_without_locker_fallback(
+                &payment_method,
+                state,
+                key_store,
+            )
+            .await
+            {
+                Ok(card) => card,
+                Err(error) => {
+                    logger::warn!(
+                        ?error,
+                        payment_method_id = %payment_method.payment_method_id,
+                        "Failed to fetch card details of saved card"
+                    );
+                    continue;
+                }
+            };
+            let card_expiry = card
+                .expiry_month
+                .as_ref()
+                .zip(card.expiry_year.as_ref())
+                .map(|(card_exp_month, card_exp_year)| {
+                    (
+                        card_exp_month.peek().as_str(),
+                        card_exp_year.peek().as_str(),
+                    )
+                });
+
+            let health_status = evaluate_payment_method_health(
+                card_expiry,
+                payment_method.consecutive_mit_declines.unwrap_or_default(),
+                now,
+                config,
+            );
+            if payment_method.health_status.unwrap_or_default() == health_status {
+                continue;
+            }
+
+            let payment_method_id = payment_method.payment_method_id.clone();
+            db.update_payment_method(
+                payment_method,
+                storage::PaymentMethodUpdate::HealthStatusUpdate {
+                    health_status: Some(health_status),
+                },
+                merchant_account.storage_scheme,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update health status of payment method")?;
+
+            // The health status is already recorded, so a failed notification does not fail the
+            // sweep of the remaining saved cards
+            cards::trigger_payment_method_webhook(
+                state,
+                merchant_account,
+                key_store,
+                storage_enums::EventType::PaymentMethodHealthUpdated,
+                payment_method_id,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to trigger payment method health webhook")
+            })
+            .ok();
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_card_expires_at() {
+        let now = time::macros::datetime!(2024-11-15 10:00);
+
+        assert_eq!(
+            get_card_expires_at("11", "2024", now),
+            Some(time::macros::datetime!(2024-12-01 00:00))
+        );
+        assert_eq!(
+            get_card_expires_at("12", "24", now),
+            Some(time::macros::datetime!(2025-01-01 00:00))
+        );
+        assert_eq!(get_card_expires_at("13", "2024", now), None);
+        assert_eq!(get_card_expires_at("", "2024", now), None);
+        assert_eq!(get_card_expires_at("11", "twenty", now), None);
+    }
+
+    #[test]
+    fn test_evaluate_payment_method_health() {
+        let now = time::macros::datetime!(2024-11-15 10:00);
+        let config = settings::PaymentMethodHealth {
+            expiry_window_in_days: 30,
+            max_consecutive_mit_declines: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            evaluate_payment_method_health(Some(("10", "2024")), 0, now, &config),
+            storage_enums::PaymentMethodHealthStatus::Expired
+        );
+        assert_eq!(
+            evaluate_payment_method_health(Some(("11", "2024")), 0, now, &config),
+            storage_enums::PaymentMethodHealthStatus::ExpiringSoon
+        );
+        assert_eq!(
+            evaluate_payment_method_health(Some(("12", "2024")), 0, now, &config),
+            storage_enums::PaymentMethodHealthStatus::Healthy
+        );
+        assert_eq!(
+            evaluate_payment_method_health(Some(("12", "2030")), 3, now, &config),
+            storage_enums::PaymentMethodHealthStatus::RepeatedDeclines
+        );
+        // An expired card is flagged as expired regardless of its declines
+        assert_eq!(
+            evaluate_payment_method_health(Some(("10", "2024")), 3, now, &config),
+            storage_enums::PaymentMethodHealthStatus::Expired
+        );
+        assert_eq!(
+            evaluate_payment_method_health(None, 2, now, &config),
+            storage_enums::PaymentMethodHealthStatus::Healthy
+        );
+    }
+}
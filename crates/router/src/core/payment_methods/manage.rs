@@ -0,0 +1,498 @@
+use std::collections::HashSet;
+
+use api_models::payment_methods::{
+    self as payment_methods_api, CustomerDefaultPaymentMethodResponse,
+    CustomerPaymentMethodUpdateRequest, CustomerPaymentMethodUpdateResponse, PaymentMethodsData,
+};
+use common_utils::{
+    consts::DEFAULT_LOCALE,
+    ext_traits::{AsyncExt, Encode},
+    id_type, link_utils,
+};
+use diesel_models::{GenericLinkNew, PaymentMethodManageLink, PaymentMethodManageLinkData};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::api::{GenericLinks, GenericLinksData};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use time::Duration;
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::cards,
+        payments::helpers,
+    },
+    routes::SessionState,
+    services,
+    types::{api, domain, storage},
+    utils,
+};
+
+/// Returns the customer the ephemeral key was issued for, after verifying that it was issued by
+/// the authenticated merchant.
+async fn get_ephemeral_key_customer_id(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    ephemeral_api_key: &str,
+) -> RouterResult<id_type::CustomerId> {
+    let ephemeral_key = state
+        .store
+        .get_ephemeral_key(ephemeral_api_key)
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)?;
+
+    utils::when(
+        &ephemeral_key.merchant_id != merchant_account.get_id(),
+        || Err(errors::ApiErrorResponse::Unauthorized),
+    )?;
+
+    Ok(ephemeral_key.customer_id)
+}
+
+/// Finds a saved payment method of the customer. A payment method saved for another customer is
+/// reported as not found, so that its existence is not revealed.
+async fn find_customer_payment_method(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &id_type::CustomerId,
+    payment_method_id: &str,
+) -> RouterResult<storage::PaymentMethod> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+
+    utils::when(
+        &payment_method.customer_id != customer_id
+            || &payment_method.merchant_id != merchant_account.get_id(),
+        || Err(errors::ApiErrorResponse::PaymentMethodNotFound),
+    )?;
+
+    Ok(payment_method)
+}
+
+/// Sets a saved payment method as the default payment method of the customer the ephemeral key
+/// was issued for.
+#[instrument(skip_all)]
+pub async fn set_default_customer_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentMethodId,
+    ephemeral_api_key: &str,
+) -> RouterResponse<CustomerDefaultPaymentMethodResponse> {
+    let customer_id =
+        get_ephemeral_key_customer_id(&state, &merchant_account, ephemeral_api_key).await?;
+
+    cards::set_default_payment_method(
+        &state,
+        merchant_account.get_id(),
+        key_store,
+        &customer_id,
+        req.payment_method_id,
+        merchant_account.storage_scheme,
+    )
+    .await
+}
+
+/// Updates the nick name and the billing address of a saved payment method of the customer the
+/// ephemeral key was issued for. The payment method details stored in the locker are left as is.
+#[instrument(skip_all)]
+pub async fn update_customer_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_method_id: String,
+    req: CustomerPaymentMethodUpdateRequest,
+    ephemeral_api_key: &str,
+) -> RouterResponse<CustomerPaymentMethodUpdateResponse> {
+    utils::when(req.nick_name.is_none() && req.billing.is_none(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "either nick_name or billing must be provided".to_string(),
+        })
+    })?;
+
+    let customer_id =
+        get_ephemeral_key_customer_id(&state, &merchant_account, ephemeral_api_key).await?;
+    let payment_method =
+        find_customer_payment_method(&state, &merchant_account, &customer_id, &payment_method_id)
+            .await?;
+
+    utils::when(
+        payment_method.status == storage::enums::PaymentMethodStatus::AwaitingData,
+        || {
+            Err(errors::ApiErrorResponse::NotSupported {
+                message: "Payment method is awaiting data so it cannot be updated".into(),
+            })
+        },
+    )?;
+
+    let payment_methods_data = cards::decrypt_generic_data::<PaymentMethodsData>(
+        &state,
+        payment_method.payment_method_data.clone(),
+        &key_store,
+    )
+    .await
+    .attach_printable("Failed to decrypt payment method data")?;
+
+    // Only the nick name of saved cards can be updated
+    let (payment_methods_data, nick_name) = match (payment_methods_data, req.nick_name) {
+        (Some(PaymentMethodsData::Card(mut card)), Some(nick_name)) => {
+            card.nick_name = Some(nick_name);
+            let nick_name = card.nick_name.clone();
+            (Some(PaymentMethodsData::Card(card)), nick_name)
+        }
+        (_, Some(_)) => Err(errors::ApiErrorResponse::NotSupported {
+            message: "nick_name can only be updated for saved cards".into(),
+        })?,
+        (Some(PaymentMethodsData::Card(card)), None) => (None, card.nick_name),
+        (_, None) => (None, None),
+    };
+
+    let encrypted_payment_methods_data = payment_methods_data
+        .async_map(|payment_methods_data| {
+            cards::create_encrypted_data(&state, &key_store, payment_methods_data)
+        })
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt payment method data")?;
+    let encrypted_billing = req
+        .billing
+        .clone()
+        .async_map(|billing| cards::create_encrypted_data(&state, &key_store, billing))
+        .await
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to encrypt payment method billing address")?;
+
+    let billing = match req.billing {
+        Some(billing) => Some(billing),
+        None => cards::decrypt_generic_data::<api_models::payments::Address>(
+            &state,
+            payment_method.payment_method_billing_address.clone(),
+            &key_store,
+        )
+        .await
+        .attach_printable("Failed to decrypt payment method billing address")?,
+    };
+
+    let payment_method = state
+        .store
+        .update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CustomerDetailsUpdate {
+                payment_method_data: encrypted_payment_methods_data.map(Into::into),
+                payment_method_billing_address: encrypted_billing.map(Into::into),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method in db")?;
+
+    Ok(services::ApplicationResponse::Json(
+        CustomerPaymentMethodUpdateResponse {
+            payment_method_id: payment_method.payment_method_id,
+            customer_id: payment_method.customer_id,
+            payment_method: payment_method.payment_method,
+            payment_method_type: payment_method.payment_method_type,
+            nick_name,
+            billing,
+        },
+    ))
+}
+
+/// Deletes a saved payment method of the customer the ephemeral key was issued for.
+#[instrument(skip_all)]
+pub async fn delete_customer_payment_method(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: api::PaymentMethodId,
+    ephemeral_api_key: &str,
+) -> RouterResponse<api::PaymentMethodDeleteResponse> {
+    let customer_id =
+        get_ephemeral_key_customer_id(&state, &merchant_account, ephemeral_api_key).await?;
+    find_customer_payment_method(
+        &state,
+        &merchant_account,
+        &customer_id,
+        &req.payment_method_id,
+    )
+    .await?;
+
+    cards::delete_payment_method(state, merchant_account, req, key_store).await
+}
+
+/// Creates a link to a hosted page on which the customer can manage their saved payment methods.
+#[instrument(skip_all)]
+pub async fn initiate_pm_manage_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payment_methods_api::PaymentMethodManageLinkRequest,
+) -> RouterResponse<payment_methods_api::PaymentMethodManageLinkResponse> {
+    let merchant_id = merchant_account.get_id();
+    state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &(&state).into(),
+            &req.customer_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let default_config = &state.conf.generic_link.payment_method_manage;
+    let session_expiry = req.session_expiry.unwrap_or(default_config.expiry);
+    utils::when(session_expiry == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "session_expiry should be greater than 0".to_string(),
+        })
+    })?;
+
+    let pm_manage_link_id = utils::generate_id(consts::ID_LENGTH, "pm_manage_link");
+    let link = Secret::new(format!(
+        "{}/payment_methods/manage/{}/{pm_manage_link_id}",
+        state.base_url,
+        merchant_id.get_string_repr()
+    ));
+    let ui_config = req.ui_config.unwrap_or(link_utils::GenericLinkUiConfig {
+        logo: None,
+        merchant_name: None,
+        theme: None,
+    });
+    let link_data = PaymentMethodManageLinkData {
+        pm_manage_link_id: pm_manage_link_id.clone(),
+        customer_id: req.customer_id.clone(),
+        link: link.clone(),
+        session_expiry,
+        ui_config,
+    };
+
+    let pm_manage_link = GenericLinkNew {
+        link_id: pm_manage_link_id,
+        primary_reference: req.customer_id.get_string_repr().to_string(),
+        merchant_id: merchant_id.to_owned(),
+        link_type: common_enums::GenericLinkType::PaymentMethodManage,
+        link_status: link_utils::GenericLinkStatus::PaymentMethodManage(
+            link_utils::PaymentMethodManageStatus::Initiated,
+        ),
+        link_data: serde_json::to_value(&link_data)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to convert PaymentMethodManageLinkData to Value")?,
+        url: link,
+        return_url: req.return_url,
+        expiry: common_utils::date_time::now() + Duration::seconds(session_expiry.into()),
+        ..Default::default()
+    };
+    let pm_manage_link = state
+        .store
+        .insert_pm_manage_link(pm_manage_link)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: "payment method manage link already exists".to_string(),
+        })?;
+
+    let url = pm_manage_link.url.peek();
+    Ok(services::ApplicationResponse::Json(
+        payment_methods_api::PaymentMethodManageLinkResponse {
+            pm_manage_link_id: pm_manage_link.link_id,
+            customer_id: pm_manage_link.link_data.customer_id,
+            expiry: pm_manage_link.expiry,
+            link: url::Url::parse(url)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!("Failed to parse the payment method manage link - {}", url)
+                })?
+                .into(),
+            return_url: pm_manage_link.return_url,
+            ui_config: pm_manage_link.link_data.ui_config,
+        },
+    ))
+}
+
+/// Returns whether the manage link can no longer be used
+fn is_pm_manage_link_expired(
+    pm_manage_link: &PaymentMethodManageLink,
+    now: time::PrimitiveDateTime,
+) -> bool {
+    pm_manage_link.link_status != link_utils::PaymentMethodManageStatus::Initiated
+        || now > pm_manage_link.expiry
+}
+
+/// Renders the hosted page on which the customer can manage their saved payment methods. An
+/// ephemeral key scoped to the customer is issued on every render, which the page uses to call
+/// the customer payment method APIs.
+#[instrument(skip_all)]
+pub async fn render_pm_manage_link(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    req: payment_methods_api::PaymentMethodManageLinkRenderRequest,
+) -> RouterResponse<services::GenericLinkFormData> {
+    let pm_manage_link = state
+        .store
+        .find_pm_manage_link_by_link_id(&req.pm_manage_link_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "payment method manage link not found".to_string(),
+        })?;
+    utils::when(
+        &pm_manage_link.merchant_id != merchant_account.get_id(),
+        || {
+            Err(errors::ApiErrorResponse::GenericNotFoundError {
+                message: "payment method manage link not found".to_string(),
+            })
+        },
+    )?;
+
+    let link_data = &pm_manage_link.link_data;
+    let default_ui_config = state
+        .conf
+        .generic_link
+        .payment_method_manage
+        .ui_config
+        .clone();
+    let theme = link_data
+        .ui_config
+        .theme
+        .clone()
+        .unwrap_or(default_ui_config.theme);
+
+    let now = common_utils::date_time::now();
+    if is_pm_manage_link_expired(&pm_manage_link, now) {
+        let expired_link_data = services::GenericExpiredLinkData {
+            title: "Payment methods link has expired".to_string(),
+            message: "This link for managing your payment methods has expired.".to_string(),
+            theme,
+        };
+        return Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
+            GenericLinks {
+                allowed_domains: HashSet::from([]),
+                data: GenericLinksData::ExpiredLink(expired_link_data),
+                locale: DEFAULT_LOCALE.to_string(),
+            },
+        )));
+    }
+
+    // The link may be opened, including by link previewers, any number of times until it expires,
+    // so the ephemeral key minted on each render must not outlive the link itself
+    let ephemeral_key_validity =
+        Duration::hours(state.conf.eph_key.validity).min(pm_manage_link.expiry - now);
+    let ephemeral_key = helpers::create_ephemeral_key(
+        &state,
+        link_data.customer_id.clone(),
+        pm_manage_link.merchant_id.clone(),
+        ephemeral_key_validity,
+    )
+    .await?;
+
+    let js_data = payment_methods_api::PaymentMethodManageLinkDetails {
+        ephemeral_key: Secret::new(ephemeral_key.secret),
+        pm_manage_link_id: pm_manage_link.link_id.clone(),
+        customer_id: link_data.customer_id.clone(),
+        session_expiry: pm_manage_link.expiry,
+        return_url: pm_manage_link.return_url.clone(),
+        ui_config: link_utils::GenericLinkUiConfigFormData {
+            logo: link_data
+                .ui_config
+                .logo
+                .clone()
+                .unwrap_or(default_ui_config.logo),
+            merchant_name: link_data
+                .ui_config
+                .merchant_name
+                .clone()
+                .unwrap_or(default_ui_config.merchant_name),
+            theme,
+        },
+    };
+    let serialized_js_content = format!(
+        "window.__PM_MANAGE_DETAILS = {}",
+        js_data
+            .encode_to_string_of_json()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize PaymentMethodManageLinkDetails")?
+    );
+
+    Ok(services::ApplicationResponse::GenericLinkForm(Box::new(
+        GenericLinks {
+            allowed_domains: HashSet::from([]),
+            data: GenericLinksData::PaymentMethodManage(services::GenericLinkStatusData {
+                js_data: serialized_js_content,
+                css_data: String::new(),
+            }),
+            locale: DEFAULT_LOCALE.to_string(),
+        },
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn get_pm_manage_link(
+        link_status: link_utils::PaymentMethodManageStatus,
+        expiry: time::PrimitiveDateTime,
+    ) -> PaymentMethodManageLink {
+        let customer_id = id_type::CustomerId::try_from(Cow::from("cus_1")).unwrap();
+        PaymentMethodManageLink {
+            link_id: "pm_manage_link_1".to_string(),
+            primary_reference: customer_id.get_string_repr().to_string(),
+            merchant_id: id_type::MerchantId::default(),
+            created_at: expiry,
+            last_modified_at: expiry,
+            expiry,
+            link_data: PaymentMethodManageLinkData {
+                pm_manage_link_id: "pm_manage_link_1".to_string(),
+                customer_id,
+                link: Secret::new(String::new()),
+                session_expiry: 900,
+                ui_config: link_utils::GenericLinkUiConfig {
+                    logo: None,
+                    merchant_name: None,
+                    theme: None,
+                },
+            },
+            link_status,
+            link_type: common_enums::GenericLinkType::PaymentMethodManage,
+            url: Secret::new(String::new()),
+            return_url: None,
+        }
+    }
+
+    #[test]
+    fn test_is_pm_manage_link_expired() {
+        let now = time::macros::datetime!(2024-09-23 10:00);
+
+        assert!(!is_pm_manage_link_expired(
+            &get_pm_manage_link(
+                link_utils::PaymentMethodManageStatus::Initiated,
+                time::macros::datetime!(2024-09-23 10:15),
+            ),
+            now
+        ));
+        assert!(is_pm_manage_link_expired(
+            &get_pm_manage_link(
+                link_utils::PaymentMethodManageStatus::Initiated,
+                time::macros::datetime!(2024-09-23 09:45),
+            ),
+            now
+        ));
+        assert!(is_pm_manage_link_expired(
+            &get_pm_manage_link(
+                link_utils::PaymentMethodManageStatus::Invalidated,
+                time::macros::datetime!(2024-09-23 10:15),
+            ),
+            now
+        ));
+    }
+}
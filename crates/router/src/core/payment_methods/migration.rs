@@ -1,12 +1,39 @@
+use std::str::FromStr;
+
 use actix_multipart::form::{bytes::Bytes, MultipartForm};
-use api_models::payment_methods::{PaymentMethodMigrationResponse, PaymentMethodRecord};
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use api_models::customers::CustomerRequest;
+use api_models::payment_methods::{
+    PaymentMethodMigrationCreateRequest, PaymentMethodMigrationJobResponse,
+    PaymentMethodMigrationReportRecord, PaymentMethodMigrationResponse,
+    PaymentMethodMigrationRetrieveRequest, PaymentMethodRecord,
+};
 use csv::Reader;
+use error_stack::ResultExt;
+use futures::StreamExt;
+use masking::PeekInterface;
 use rdkafka::message::ToBytes;
+use router_env::{instrument, logger, tracing};
 
 use crate::{
-    core::{errors, payment_methods::cards::migrate_payment_method},
+    consts,
+    core::{
+        customers,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        payment_methods::cards::{
+            find_customer_payment_method_by_fingerprint_id, generate_saved_card_fingerprint,
+            migrate_payment_method,
+        },
+        utils as core_utils,
+    },
     routes, services,
-    types::{api, domain},
+    types::{
+        api, domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils,
 };
 
 pub async fn migrate_payment_methods(
@@ -84,3 +111,800 @@ pub fn get_payment_method_records(
         }),
     }
 }
+
+/// A record of a payment method migration file
+#[derive(Debug, Clone)]
+enum MigrationRecord {
+    Csv(Box<PaymentMethodRecord>),
+    Jsonl(Box<api::PaymentMethodMigrate>),
+}
+
+type MigrationRecordResult = Result<MigrationRecord, errors::ApiErrorResponse>;
+
+impl MigrationRecord {
+    fn get_merchant_id(&self) -> &common_utils::id_type::MerchantId {
+        match self {
+            Self::Csv(record) => &record.merchant_id,
+            Self::Jsonl(record) => &record.merchant_id,
+        }
+    }
+
+    fn get_customer_id(&self) -> Option<&common_utils::id_type::CustomerId> {
+        match self {
+            Self::Csv(record) => Some(&record.customer_id),
+            Self::Jsonl(record) => record.customer_id.as_ref(),
+        }
+    }
+
+    /// Returns the request for creating the customer of the record, if the record has a customer
+    #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+    fn get_customer_request(&self) -> Option<CustomerRequest> {
+        match self {
+            Self::Csv(record) => Some(CustomerRequest::from(*record.clone())),
+            Self::Jsonl(record) => record
+                .customer_id
+                .is_some()
+                .then(|| CustomerRequest::from(*record.clone())),
+        }
+    }
+
+    #[cfg(all(feature = "v2", feature = "customer_v2"))]
+    fn get_customer_request(&self) -> Option<api_models::customers::CustomerRequest> {
+        todo!()
+    }
+}
+
+impl From<MigrationRecord> for api::PaymentMethodMigrate {
+    fn from(record: MigrationRecord) -> Self {
+        match record {
+            MigrationRecord::Csv(record) => Self::from(*record),
+            MigrationRecord::Jsonl(record) => *record,
+        }
+    }
+}
+
+/// Reports a migration file which cannot be read as a single invalid record spanning the file
+fn invalid_file_error(error: csv::Error, file_data: &[u8]) -> (MigrationRecordResult, usize) {
+    (
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        }),
+        file_data.len(),
+    )
+}
+
+/// Parses the records of a payment method migration file following the byte `offset`, along with
+/// the byte offset at which the next record starts. Records which cannot be parsed are returned as
+/// errors, so that they are reported against their line numbers.
+fn parse_migration_records(
+    file_format: storage_enums::PaymentMethodMigrationFileFormat,
+    file_data: &[u8],
+    offset: usize,
+) -> Box<dyn Iterator<Item = (MigrationRecordResult, usize)> + '_> {
+    match file_format {
+        storage_enums::PaymentMethodMigrationFileFormat::Csv => {
+            let mut reader = Reader::from_reader(std::io::Cursor::new(file_data));
+            // The headers are read before seeking, so that the records following the offset are
+            // deserialized by their names
+            let headers = match reader.byte_headers() {
+                Ok(headers) => headers.clone(),
+                Err(error) => {
+                    return Box::new(std::iter::once(invalid_file_error(error, file_data)))
+                }
+            };
+            if offset > 0 {
+                let mut position = csv::Position::new();
+                position.set_byte(u64::try_from(offset).unwrap_or(u64::MAX));
+                if let Err(error) = reader.seek(position) {
+                    return Box::new(std::iter::once(invalid_file_error(error, file_data)));
+                }
+            }
+
+            Box::new(std::iter::from_fn(move || {
+                let mut record = csv::ByteRecord::new();
+                let record = match reader.read_byte_record(&mut record) {
+                    Ok(false) => return None,
+                    Ok(true) => record.deserialize::<PaymentMethodRecord>(Some(&headers)),
+                    Err(error) => Err(error),
+                };
+                let next_offset =
+                    usize::try_from(reader.position().byte()).unwrap_or(file_data.len());
+                Some((
+                    record
+                        .map(|record| MigrationRecord::Csv(Box::new(record)))
+                        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                            message: error.to_string(),
+                        }),
+                    next_offset,
+                ))
+            }))
+        }
+        storage_enums::PaymentMethodMigrationFileFormat::Jsonl => {
+            let mut next_offset = offset;
+            Box::new(std::iter::from_fn(move || loop {
+                let remaining = file_data
+                    .get(next_offset..)
+                    .filter(|data| !data.is_empty())?;
+                let line_length = remaining
+                    .iter()
+                    .position(|byte| *byte == b'\n')
+                    .map_or(remaining.len(), |position| position + 1);
+                let line = &remaining[..line_length];
+                next_offset += line_length;
+                if line.iter().all(u8::is_ascii_whitespace) {
+                    continue;
+                }
+
+                let record = serde_json::from_slice::<api::PaymentMethodMigrate>(line)
+                    .map(|record| MigrationRecord::Jsonl(Box::new(record)))
+                    .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                        message: error.to_string(),
+                    });
+                return Some((record, next_offset));
+            }))
+        }
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_payment_method_migration(
+    state: routes::SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: PaymentMethodMigrationCreateRequest,
+) -> RouterResponse<PaymentMethodMigrationJobResponse> {
+    let parallelism = req
+        .parallelism
+        .unwrap_or(consts::DEFAULT_PAYMENT_METHOD_MIGRATION_PARALLELISM);
+    utils::when(
+        parallelism == 0 || parallelism > consts::MAX_PAYMENT_METHOD_MIGRATION_PARALLELISM,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "parallelism must be between 1 and {}",
+                    consts::MAX_PAYMENT_METHOD_MIGRATION_PARALLELISM
+                ),
+            })
+        },
+    )?;
+
+    let (file_format, file_data) =
+        get_migration_file(&state, &merchant_account, &key_store, &req.file_id).await?;
+    let total_count = i32::try_from(parse_migration_records(file_format, &file_data, 0).count())
+        .change_context(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "too many records in the migration file".to_string(),
+        })
+        .attach_printable("Failed to convert migration record count to i32")?;
+    utils::when(total_count == 0, || {
+        Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "migration file does not contain any records".to_string(),
+        })
+    })?;
+
+    let migration_id = utils::generate_id(consts::ID_LENGTH, "pm_migration");
+    let now = common_utils::date_time::now();
+    let payment_method_migration_new = storage::PaymentMethodMigrationNew {
+        migration_id: migration_id.clone(),
+        merchant_id: merchant_account.get_id().to_owned(),
+        file_id: req.file_id,
+        file_format,
+        status: storage_enums::PaymentMethodMigrationStatus::Pending,
+        parallelism: i32::from(parallelism),
+        total_count,
+        processed_count: 0,
+        failed_count: 0,
+        processed_offset: 0,
+        created_at: now,
+        modified_at: now,
+    };
+    let payment_method_migration = state
+        .store
+        .insert_payment_method_migration(payment_method_migration_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!("Payment method migration with id {migration_id} already exists"),
+        })?;
+
+    add_payment_method_migration_task(&state, &payment_method_migration).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodMigrationJobResponse::foreign_from(payment_method_migration),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_payment_method_migration(
+    state: routes::SessionState,
+    req: PaymentMethodMigrationRetrieveRequest,
+) -> RouterResponse<PaymentMethodMigrationJobResponse> {
+    let payment_method_migration = find_payment_method_migration(&state, &req).await?;
+
+    Ok(services::ApplicationResponse::Json(
+        PaymentMethodMigrationJobResponse::foreign_from(payment_method_migration),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn get_payment_method_migration_report(
+    state: routes::SessionState,
+    req: PaymentMethodMigrationRetrieveRequest,
+) -> RouterResponse<serde_json::Value> {
+    let payment_method_migration = find_payment_method_migration(&state, &req).await?;
+
+    let items = state
+        .store
+        .find_payment_method_migration_items_by_merchant_id_migration_id(
+            &payment_method_migration.merchant_id,
+            &payment_method_migration.migration_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment method migration items")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for item in items {
+        csv_writer
+            .serialize(PaymentMethodMigrationReportRecord {
+                line_number: item.line_number,
+                customer_id: item.customer_id,
+                payment_method_id: item.payment_method_id,
+                status: item.status,
+                error_code: item.error_code,
+                error_message: item.error_message,
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write payment method migration report record")?;
+    }
+    let report = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate payment method migration report")?;
+
+    Ok(services::ApplicationResponse::FileData((
+        report,
+        mime::TEXT_CSV,
+    )))
+}
+
+/// Migrates the next records of the payment method migration. The migration file is read once,
+/// and the progress of the migration is saved after every chunk of records along with the byte
+/// offset of the next record, so that an interrupted migration resumes parsing the file from the
+/// last saved chunk.
+#[instrument(skip_all)]
+pub async fn process_payment_method_migration(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_migration: storage::PaymentMethodMigration,
+) -> RouterResult<storage::PaymentMethodMigration> {
+    let (_, file_data) = get_migration_file(
+        state,
+        merchant_account,
+        key_store,
+        &payment_method_migration.file_id,
+    )
+    .await?;
+
+    let mut payment_method_migration = payment_method_migration;
+    for _ in 0..consts::PAYMENT_METHOD_MIGRATION_CHUNKS_PER_RUN {
+        payment_method_migration = process_payment_method_migration_chunk(
+            state,
+            merchant_account,
+            key_store,
+            payment_method_migration,
+            &file_data,
+        )
+        .await?;
+        if payment_method_migration.status == storage_enums::PaymentMethodMigrationStatus::Completed
+        {
+            break;
+        }
+    }
+    Ok(payment_method_migration)
+}
+
+/// Migrates the records of the next chunk concurrently, with at most `parallelism` records being
+/// migrated at a time, and updates the progress of the migration. Records which already have an
+/// item recorded against them are skipped, which makes re-running a partially processed chunk safe.
+async fn process_payment_method_migration_chunk(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_migration: storage::PaymentMethodMigration,
+    file_data: &[u8],
+) -> RouterResult<storage::PaymentMethodMigration> {
+    let db = &*state.store;
+    let start_line_number = payment_method_migration.processed_count + 1;
+    let end_line_number = std::cmp::min(
+        start_line_number + consts::PAYMENT_METHOD_MIGRATION_CHUNK_SIZE,
+        payment_method_migration.total_count + 1,
+    );
+    let processed_items = db
+        .find_payment_method_migration_items_by_line_number_range(
+            &payment_method_migration.merchant_id,
+            &payment_method_migration.migration_id,
+            start_line_number,
+            end_line_number,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment method migration items")?;
+
+    let offset = usize::try_from(payment_method_migration.processed_offset)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid offset of payment method migration")?;
+    let chunk = parse_migration_records(payment_method_migration.file_format, file_data, offset)
+        .zip(start_line_number..end_line_number)
+        .collect::<Vec<_>>();
+
+    let mut failed_count = payment_method_migration.failed_count;
+    for item in &processed_items {
+        match item.status {
+            storage_enums::PaymentMethodMigrationItemStatus::Succeeded => (),
+            storage_enums::PaymentMethodMigrationItemStatus::Failed => failed_count += 1,
+            // The record was being migrated when the migration was interrupted. It is not
+            // migrated again, as its payment method may have been created already, in which case
+            // the item is completed with the created payment method.
+            storage_enums::PaymentMethodMigrationItemStatus::Pending => {
+                let record = chunk
+                    .iter()
+                    .find(|(_, line_number)| *line_number == item.line_number)
+                    .and_then(|((record, _), _)| record.as_ref().ok())
+                    .cloned();
+                let payment_method_id = match (record, item.customer_id.as_ref()) {
+                    (Some(record), Some(customer_id)) => {
+                        find_migrated_payment_method(state, merchant_account, customer_id, record)
+                            .await?
+                    }
+                    _ => None,
+                };
+                let item_update = match payment_method_id {
+                    Some(payment_method_id) => {
+                        storage::PaymentMethodMigrationItemUpdate::SuccessUpdate {
+                            payment_method_id,
+                        }
+                    }
+                    None => {
+                        failed_count += 1;
+                        storage::PaymentMethodMigrationItemUpdate::ErrorUpdate {
+                            error_code: None,
+                            error_message: Some(
+                                "Migration of the record was interrupted".to_string(),
+                            ),
+                        }
+                    }
+                };
+                db.update_payment_method_migration_item(item.clone(), item_update)
+                    .await
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to update payment method migration item")?;
+            }
+        }
+    }
+
+    let next_offset = chunk
+        .last()
+        .map_or(offset, |((_, next_offset), _)| *next_offset);
+    let records = chunk
+        .into_iter()
+        .filter(|(_, line_number)| {
+            !processed_items
+                .iter()
+                .any(|item| item.line_number == *line_number)
+        })
+        .map(|((record, _), line_number)| (record, line_number))
+        .collect::<Vec<_>>();
+    let parallelism = usize::try_from(payment_method_migration.parallelism)
+        .unwrap_or_default()
+        .max(1);
+    let items = futures::stream::iter(records)
+        .map(|(record, line_number)| {
+            process_payment_method_migration_record(
+                state,
+                merchant_account,
+                key_store,
+                &payment_method_migration,
+                line_number,
+                record,
+            )
+        })
+        .buffer_unordered(parallelism)
+        .collect::<Vec<_>>()
+        .await
+        .into_iter()
+        .collect::<RouterResult<Vec<_>>>()?;
+    failed_count += i32::try_from(
+        items
+            .iter()
+            .filter(|item| item.status == storage_enums::PaymentMethodMigrationItemStatus::Failed)
+            .count(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    let status = if end_line_number > payment_method_migration.total_count {
+        storage_enums::PaymentMethodMigrationStatus::Completed
+    } else {
+        storage_enums::PaymentMethodMigrationStatus::Processing
+    };
+    let migration_id = payment_method_migration.migration_id.clone();
+    db.update_payment_method_migration(
+        payment_method_migration,
+        storage::PaymentMethodMigrationUpdate::ProgressUpdate {
+            status,
+            processed_count: end_line_number - 1,
+            failed_count,
+            processed_offset: i64::try_from(next_offset)
+                .change_context(errors::ApiErrorResponse::InternalServerError)?,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to update payment method migration: {migration_id}"))
+}
+
+async fn process_payment_method_migration_record(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_migration: &storage::PaymentMethodMigration,
+    line_number: i32,
+    record: MigrationRecordResult,
+) -> RouterResult<storage::PaymentMethodMigrationItem> {
+    let db = &*state.store;
+    let record = record.and_then(|record| {
+        utils::when(
+            record.get_merchant_id() != &payment_method_migration.merchant_id,
+            || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message:
+                        "merchant_id of the record does not match the merchant_id of the migration"
+                            .to_string(),
+                })
+            },
+        )?;
+        Ok(record)
+    });
+    let (record, error) = match record {
+        Ok(record) => (Some(record), None),
+        Err(error) => (None, Some(core_utils::get_error_code_and_message(&error))),
+    };
+
+    // The item is recorded before migrating the record, so that a record is never migrated twice
+    // if the workflow is interrupted
+    let now = common_utils::date_time::now();
+    let item = db
+        .insert_payment_method_migration_item(storage::PaymentMethodMigrationItemNew {
+            merchant_id: payment_method_migration.merchant_id.clone(),
+            migration_id: payment_method_migration.migration_id.clone(),
+            line_number,
+            customer_id: record
+                .as_ref()
+                .and_then(MigrationRecord::get_customer_id)
+                .cloned(),
+            payment_method_id: None,
+            status: if error.is_some() {
+                storage_enums::PaymentMethodMigrationItemStatus::Failed
+            } else {
+                storage_enums::PaymentMethodMigrationItemStatus::Pending
+            },
+            error_code: error.as_ref().map(|(code, _)| code.clone()),
+            error_message: error.map(|(_, message)| message),
+            created_at: now,
+            modified_at: now,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed to insert item {line_number} of payment method migration: {}",
+                payment_method_migration.migration_id
+            )
+        })?;
+
+    let Some(record) = record else {
+        return Ok(item);
+    };
+    let item_update = match migrate_record(state, merchant_account, key_store, record).await {
+        Ok(payment_method_id) => {
+            storage::PaymentMethodMigrationItemUpdate::SuccessUpdate { payment_method_id }
+        }
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to migrate record {line_number} of payment method migration: {}",
+                payment_method_migration.migration_id
+            );
+            let (error_code, error_message) =
+                core_utils::get_error_code_and_message(error.current_context());
+            storage::PaymentMethodMigrationItemUpdate::ErrorUpdate {
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+            }
+        }
+    };
+    db.update_payment_method_migration_item(item, item_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payment method migration item")
+}
+
+/// Creates the customer of the record if it does not exist yet, and migrates the payment method
+/// of the record
+async fn migrate_record(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    record: MigrationRecord,
+) -> RouterResult<String> {
+    if let Some(customer) = record.get_customer_request() {
+        customers::migrate_customers(
+            state.clone(),
+            vec![customer],
+            merchant_account.clone(),
+            key_store.clone(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create customer of the migration record")?;
+    }
+
+    match migrate_payment_method(
+        state.clone(),
+        api::PaymentMethodMigrate::from(record),
+        merchant_account.get_id(),
+        merchant_account,
+        key_store,
+    )
+    .await?
+    {
+        services::ApplicationResponse::Json(response) => Ok(response.payment_method_id),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response while migrating payment method"),
+    }
+}
+
+/// Finds the payment method created for a record whose migration was interrupted, by the
+/// fingerprint of its card. Cards which were not saved in the locker have no fingerprint, and
+/// cannot be matched.
+async fn find_migrated_payment_method(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &common_utils::id_type::CustomerId,
+    record: MigrationRecord,
+) -> RouterResult<Option<String>> {
+    let req = api::PaymentMethodMigrate::from(record);
+    let Some(card_number) = req
+        .card
+        .as_ref()
+        .and_then(|card| cards::CardNumber::from_str(card.card_number.peek()).ok())
+    else {
+        return Ok(None);
+    };
+    let payment_method_create =
+        api::PaymentMethodCreate::get_payment_method_create_from_payment_method_migrate(
+            card_number,
+            &req,
+        );
+    let Some(fingerprint_id) = generate_saved_card_fingerprint(
+        state,
+        merchant_account.get_id(),
+        payment_method_create.card.as_ref(),
+    )
+    .await
+    else {
+        return Ok(None);
+    };
+
+    Ok(find_customer_payment_method_by_fingerprint_id(
+        state,
+        customer_id,
+        merchant_account.get_id(),
+        &fingerprint_id,
+        merchant_account.storage_scheme,
+    )
+    .await?
+    .map(|payment_method| payment_method.payment_method_id))
+}
+
+async fn find_payment_method_migration(
+    state: &routes::SessionState,
+    req: &PaymentMethodMigrationRetrieveRequest,
+) -> RouterResult<storage::PaymentMethodMigration> {
+    state
+        .store
+        .find_payment_method_migration_by_merchant_id_migration_id(
+            &req.merchant_id,
+            &req.migration_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!(
+                "Payment method migration with id {} does not exist",
+                req.migration_id
+            ),
+        })
+}
+
+/// Marks the payment method migration as failed, once the workflow processing it has exhausted its
+/// retries
+pub async fn fail_payment_method_migration(
+    state: &routes::SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    migration_id: &str,
+) -> RouterResult<storage::PaymentMethodMigration> {
+    let db = &*state.store;
+    let payment_method_migration = db
+        .find_payment_method_migration_by_merchant_id_migration_id(merchant_id, migration_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payment method migration")?;
+    db.update_payment_method_migration(
+        payment_method_migration,
+        storage::PaymentMethodMigrationUpdate::StatusUpdate {
+            status: storage_enums::PaymentMethodMigrationStatus::Failed,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to update payment method migration: {migration_id}"))
+}
+
+async fn get_migration_file(
+    state: &routes::SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    file_id: &str,
+) -> RouterResult<(storage_enums::PaymentMethodMigrationFileFormat, Vec<u8>)> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id.to_string()),
+        merchant_account,
+        key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let file_format = match file_info.file_type.as_deref() {
+        Some("text/csv") => storage_enums::PaymentMethodMigrationFileFormat::Csv,
+        Some("application/jsonl") => storage_enums::PaymentMethodMigrationFileFormat::Jsonl,
+        _ => Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "migration file must be in CSV or JSONL format".to_string(),
+        })?,
+    };
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Payment method migration file data not found")?;
+    Ok((file_format, file_data))
+}
+
+async fn add_payment_method_migration_task(
+    state: &routes::SessionState,
+    payment_method_migration: &storage::PaymentMethodMigration,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::PaymentMethodMigrationWorkflow;
+    let task = "PAYMENT_METHOD_MIGRATION";
+    let tag = ["PAYMENT_METHOD", "MIGRATION"];
+    let process_tracker_id = format!("{runner}_{task}_{}", payment_method_migration.migration_id);
+    let tracking_data = storage::PaymentMethodMigrationTrackingData {
+        migration_id: payment_method_migration.migration_id.clone(),
+        merchant_id: payment_method_migration.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment method migration process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: migration_id: {}",
+                payment_method_migration.migration_id
+            )
+        })
+}
+
+impl ForeignFrom<storage::PaymentMethodMigration> for PaymentMethodMigrationJobResponse {
+    fn foreign_from(payment_method_migration: storage::PaymentMethodMigration) -> Self {
+        Self {
+            migration_id: payment_method_migration.migration_id,
+            merchant_id: payment_method_migration.merchant_id,
+            file_id: payment_method_migration.file_id,
+            file_format: payment_method_migration.file_format,
+            status: payment_method_migration.status,
+            parallelism: payment_method_migration.parallelism,
+            total_count: payment_method_migration.total_count,
+            processed_count: payment_method_migration.processed_count,
+            failed_count: payment_method_migration.failed_count,
+            created_at: payment_method_migration.created_at,
+            modified_at: payment_method_migration.modified_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_migration_records() {
+        let file_data = "customer_id,name,email,phone,phone_country_code,merchant_id,payment_method,payment_method_type,nick_name,payment_instrument_id,card_number_masked,card_expiry_month,card_expiry_year,card_scheme,original_transaction_id,billing_address_zip,billing_address_state,billing_address_first_name,billing_address_last_name,billing_address_city,billing_address_country,billing_address_line1,billing_address_line2,billing_address_line3,raw_card_number,merchant_connector_id,original_transaction_amount,original_transaction_currency,line_number
+cus_1,John,john@example.com,9123456789,+1,merchant_1,card,credit,John's card,pi_1,411111XXXXXX1111,12,2030,Visa,ntid_1,94122,CA,John,Doe,San Francisco,US,1 Main St,,,,mca_1,1000,USD,
+cus_2,Jane,jane@example.com,9123456789,+1,merchant_1,card,credit,Jane's card,pi_2,411111XXXXXX1111,not_a_month
+";
+        let records = parse_migration_records(
+            storage_enums::PaymentMethodMigrationFileFormat::Csv,
+            file_data.as_bytes(),
+            0,
+        )
+        .collect::<Vec<_>>();
+
+        assert_eq!(records.len(), 2);
+        let record = api::PaymentMethodMigrate::from(records[0].0.clone().unwrap());
+        assert_eq!(record.network_transaction_id.as_deref(), Some("ntid_1"));
+        assert!(record.connector_mandate_details.is_some());
+        assert!(matches!(
+            records[1].0,
+            Err(errors::ApiErrorResponse::InvalidRequestData { .. })
+        ));
+
+        // Parsing resumes from the offset of a record, with the headers of the file
+        let resumed_records = parse_migration_records(
+            storage_enums::PaymentMethodMigrationFileFormat::Csv,
+            file_data.as_bytes(),
+            records[0].1,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(resumed_records.len(), 1);
+        assert_eq!(resumed_records[0].1, records[1].1);
+        assert!(resumed_records[0].0.is_err());
+    }
+
+    #[test]
+    fn test_parse_jsonl_migration_records() {
+        let file_data = r#"{"merchant_id":"merchant_1","customer_id":"cus_1","payment_method":"card","card":{"card_number":"4111111111111111","card_exp_month":"12","card_exp_year":"2030"},"network_transaction_id":"ntid_1","connector_mandate_details":{"mca_1":{"connector_mandate_id":"pi_1"}}}
+
+{"merchant_id":"merchant_1","card":"invalid"}
+"#;
+        let records = parse_migration_records(
+            storage_enums::PaymentMethodMigrationFileFormat::Jsonl,
+            file_data.as_bytes(),
+            0,
+        )
+        .collect::<Vec<_>>();
+
+        // Blank lines are not counted as records
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[1].1, file_data.len());
+        let record = records[0].0.clone().unwrap();
+        assert_eq!(
+            record
+                .get_customer_id()
+                .map(|customer_id| customer_id.get_string_repr()),
+            Some("cus_1")
+        );
+        let record = api::PaymentMethodMigrate::from(record);
+        assert_eq!(record.network_transaction_id.as_deref(), Some("ntid_1"));
+        assert!(record.connector_mandate_details.is_some());
+        assert!(records[1].0.is_err());
+
+        let resumed_records = parse_migration_records(
+            storage_enums::PaymentMethodMigrationFileFormat::Jsonl,
+            file_data.as_bytes(),
+            records[0].1,
+        )
+        .collect::<Vec<_>>();
+        assert_eq!(resumed_records.len(), 1);
+        assert!(resumed_records[0].0.is_err());
+    }
+}
@@ -26,7 +26,8 @@ use crate::{
     core::{
         errors::{self, ConditionalConfigError as ConfigError},
         payments::{
-            conditional_configs::ConditionalConfigResult, routing::make_dsl_input_for_surcharge,
+            conditional_configs::ConditionalConfigResult,
+            routing::{add_customer_segments_to_dsl_input, make_dsl_input_for_surcharge},
             types, PaymentData,
         },
     },
@@ -108,6 +109,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
     billing_address: Option<payments::Address>,
+    customer_segments: &[String],
     response_payment_method_types: &mut [api_models::payment_methods::ResponsePaymentMethodsEnabled],
 ) -> ConditionalConfigResult<(
     types::SurchargeMetadata,
@@ -153,6 +155,7 @@ pub async fn perform_surcharge_decision_management_for_payment_method_list(
     let mut backend_input =
         make_dsl_input_for_surcharge(payment_attempt, payment_intent, billing_address)
             .change_context(ConfigError::InputConstructionError)?;
+    add_customer_segments_to_dsl_input(customer_segments, &mut backend_input);
 
     for payment_methods_enabled in response_payment_method_types.iter_mut() {
         for payment_method_type_response in
@@ -226,6 +229,7 @@ pub async fn perform_surcharge_decision_management_for_session_flow<O>(
     algorithm_ref: routing::RoutingAlgorithmRef,
     payment_data: &mut PaymentData<O>,
     payment_method_type_list: &Vec<common_enums::PaymentMethodType>,
+    customer_segments: &[String],
 ) -> ConditionalConfigResult<types::SurchargeMetadata>
 where
     O: Send + Clone,
@@ -257,6 +261,7 @@ where
         payment_data.address.get_payment_method_billing().cloned(),
     )
     .change_context(ConfigError::InputConstructionError)?;
+    add_customer_segments_to_dsl_input(customer_segments, &mut backend_input);
     for payment_method_type in payment_method_type_list {
         backend_input.payment_method.payment_method_type = Some(*payment_method_type);
         // in case of session flow, payment_method will always be wallet
@@ -286,6 +291,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     algorithm_ref: routing::RoutingAlgorithmRef,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
+    customer_segments: &[String],
     customer_payment_method_list: &mut [api_models::payment_methods::CustomerPaymentMethod],
 ) -> ConditionalConfigResult<types::SurchargeMetadata> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
@@ -315,6 +321,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     logger::debug!(customer_saved_card_list_surcharge_source = surcharge_source_log_message);
     let mut backend_input = make_dsl_input_for_surcharge(payment_attempt, payment_intent, None)
         .change_context(ConfigError::InputConstructionError)?;
+    add_customer_segments_to_dsl_input(customer_segments, &mut backend_input);
 
     for customer_payment_method in customer_payment_method_list.iter_mut() {
         let payment_token = customer_payment_method.payment_token.clone();
@@ -362,6 +369,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     algorithm_ref: routing::RoutingAlgorithmRef,
     payment_attempt: &storage::PaymentAttempt,
     payment_intent: &storage::PaymentIntent,
+    customer_segments: &[String],
     customer_payment_method_list: &mut [api_models::payment_methods::CustomerPaymentMethod],
 ) -> ConditionalConfigResult<types::SurchargeMetadata> {
     let mut surcharge_metadata = types::SurchargeMetadata::new(payment_attempt.attempt_id.clone());
@@ -391,6 +399,7 @@ pub async fn perform_surcharge_decision_management_for_saved_cards(
     logger::debug!(customer_saved_card_list_surcharge_source = surcharge_source_log_message);
     let mut backend_input = make_dsl_input_for_surcharge(payment_attempt, payment_intent, None)
         .change_context(ConfigError::InputConstructionError)?;
+    add_customer_segments_to_dsl_input(customer_segments, &mut backend_input);
 
     for customer_payment_method in customer_payment_method_list.iter_mut() {
         let payment_token = customer_payment_method
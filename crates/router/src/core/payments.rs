@@ -1,4 +1,6 @@
 pub mod access_token;
+pub mod authorization_expiry;
+pub mod auto_capture;
 pub mod conditional_configs;
 pub mod connector_integration_v2_impls;
 pub mod customers;
@@ -200,6 +202,7 @@ where
         &business_profile,
         &key_store,
         &mut payment_data,
+        &customer,
         eligible_connectors,
         mandate_type,
     )
@@ -263,6 +266,7 @@ where
                 &mut should_continue_transaction,
                 &connector_details,
                 &business_profile,
+                &merchant_account,
                 &key_store,
                 mandate_type,
             )
@@ -507,6 +511,7 @@ where
                             &merchant_account,
                             &business_profile,
                             &mut payment_data,
+                            &customer,
                             &connectors,
                         )
                         .await?;
@@ -597,6 +602,18 @@ where
             .await?;
     }
 
+    authorization_expiry::track_authorization_expiry(
+        state,
+        &merchant_account,
+        &key_store,
+        &business_profile,
+        &mut payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await
+    .map_err(|error| logger::error!(authorization_expiry_tracking_error=?error))
+    .ok();
+
     let cloned_payment_data = payment_data.clone();
     let cloned_customer = customer.clone();
 
@@ -610,6 +627,15 @@ where
         )
         .await?;
 
+    auto_capture::add_auto_capture_task(
+        state,
+        &payment_data.payment_intent,
+        &payment_data.payment_attempt,
+    )
+    .await
+    .map_err(|error| logger::error!(auto_capture_process_tracker_error=?error))
+    .ok();
+
     crate::utils::trigger_payments_webhook(
         merchant_account,
         business_profile,
@@ -758,9 +784,10 @@ pub fn get_connector_data(
 #[instrument(skip_all)]
 pub async fn call_surcharge_decision_management_for_session_flow<O>(
     state: &SessionState,
-    _merchant_account: &domain::MerchantAccount,
+    merchant_account: &domain::MerchantAccount,
     _business_profile: &domain::BusinessProfile,
     payment_data: &mut PaymentData<O>,
+    customer: &Option<domain::Customer>,
     session_connector_data: &[api::SessionConnectorData],
 ) -> RouterResult<Option<api::SessionSurchargeDetails>>
 where
@@ -787,7 +814,7 @@ where
             .collect();
 
         #[cfg(feature = "v1")]
-        let algorithm_ref: api::routing::RoutingAlgorithmRef = _merchant_account
+        let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
             .routing_algorithm
             .clone()
             .map(|val| val.parse_value("routing algorithm"))
@@ -800,12 +827,15 @@ where
         #[cfg(feature = "v2")]
         let algorithm_ref: api::routing::RoutingAlgorithmRef = todo!();
 
+        let customer_segments = crate::core::customers::get_segments_of_customer(customer.as_ref());
+
         let surcharge_results =
             surcharge_decision_configs::perform_surcharge_decision_management_for_session_flow(
                 state,
                 algorithm_ref,
                 payment_data,
                 &payment_method_type_list,
+                &customer_segments,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1275,6 +1305,7 @@ impl PaymentRedirectFlow for PaymentAuthenticateCompleteAuthorize {
             .find_authentication_by_merchant_id_authentication_id(
                 &merchant_id,
                 authentication_id.clone(),
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
@@ -2787,6 +2818,7 @@ pub struct IncrementalAuthorizationDetails {
     pub total_amount: MinorUnit,
     pub reason: Option<String>,
     pub authorization_id: Option<String>,
+    pub is_reauthorization: bool,
 }
 
 pub trait CustomerDetailsExt {
@@ -3265,6 +3297,7 @@ pub async fn get_connector_choice<F, Req>(
     business_profile: &domain::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut PaymentData<F>,
+    customer: &Option<domain::Customer>,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
 ) -> RouterResult<Option<ConnectorCallType>>
@@ -3290,6 +3323,7 @@ where
                     merchant_account,
                     key_store,
                     payment_data,
+                    customer,
                     connectors,
                 )
                 .await?;
@@ -3303,6 +3337,7 @@ where
                     business_profile,
                     key_store,
                     payment_data,
+                    customer,
                     Some(straight_through),
                     eligible_connectors,
                     mandate_type,
@@ -3317,6 +3352,7 @@ where
                     business_profile,
                     key_store,
                     payment_data,
+                    customer,
                     None,
                     eligible_connectors,
                     mandate_type,
@@ -3343,6 +3379,7 @@ pub async fn connector_selection<F>(
     business_profile: &domain::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut PaymentData<F>,
+    customer: &Option<domain::Customer>,
     request_straight_through: Option<serde_json::Value>,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
@@ -3383,6 +3420,7 @@ where
         business_profile,
         key_store,
         payment_data,
+        customer,
         request_straight_through,
         &mut routing_data,
         eligible_connectors,
@@ -3411,6 +3449,7 @@ pub async fn decide_connector<F>(
     business_profile: &domain::BusinessProfile,
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut PaymentData<F>,
+    customer: &Option<domain::Customer>,
     request_straight_through: Option<api::routing::StraightThroughAlgorithm>,
     routing_data: &mut storage::RoutingData,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
@@ -3644,6 +3683,7 @@ where
         key_store,
         TransactionData::Payment(payment_data),
         routing_data,
+        customer,
         eligible_connectors,
         mandate_type,
     )
@@ -3879,6 +3919,7 @@ pub async fn perform_session_token_routing<F>(
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
     payment_data: &mut PaymentData<F>,
+    customer: &Option<domain::Customer>,
     connectors: Vec<api::SessionConnectorData>,
 ) -> RouterResult<Vec<api::SessionConnectorData>>
 where
@@ -3964,7 +4005,7 @@ where
         merchant_account,
         payment_attempt: &payment_data.payment_attempt,
         payment_intent: &payment_data.payment_intent,
-
+        customer: customer.as_ref(),
         chosen,
     };
     let result = self_routing::perform_session_flow_routing(sfr, &enums::TransactionType::Payment)
@@ -3999,6 +4040,7 @@ pub async fn route_connector_v1<F>(
     key_store: &domain::MerchantKeyStore,
     transaction_data: TransactionData<'_, F>,
     routing_data: &mut storage::RoutingData,
+    customer: &Option<domain::Customer>,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
 ) -> RouterResult<ConnectorCallType>
@@ -4014,6 +4056,7 @@ where
         routing_algorithm_id,
         business_profile,
         &transaction_data,
+        customer.as_ref(),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -4084,6 +4127,7 @@ pub async fn route_connector_v1<F>(
     key_store: &domain::MerchantKeyStore,
     transaction_data: TransactionData<'_, F>,
     routing_data: &mut storage::RoutingData,
+    customer: &Option<domain::Customer>,
     eligible_connectors: Option<Vec<enums::RoutableConnectors>>,
     mandate_type: Option<api::MandateTransactionType>,
 ) -> RouterResult<ConnectorCallType>
@@ -4113,6 +4157,7 @@ where
         routing_algorithm_id,
         business_profile,
         &transaction_data,
+        customer.as_ref(),
     )
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)?;
@@ -4302,6 +4347,7 @@ pub async fn payment_external_authentication(
                 .clone()
                 .ok_or(errors::ApiErrorResponse::InternalServerError)
                 .attach_printable("missing authentication_id in payment_attempt")?,
+            storage_scheme,
         )
         .await
         .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
@@ -4377,6 +4423,7 @@ pub async fn payment_external_authentication(
         optional_customer.and_then(|customer| customer.email.map(pii::Email::from)),
         webhook_url,
         authentication_details.three_ds_requestor_url.clone(),
+        storage_scheme,
     ))
     .await?;
     Ok(services::ApplicationResponse::Json(
@@ -0,0 +1,690 @@
+use api_models::payments::{
+    AdditionalPaymentData, PaymentsCancelRequest, PaymentsIncrementalAuthorizationRequest,
+};
+#[cfg(feature = "olap")]
+use api_models::payments::{
+    ExpiringAuthorizationResponse, ExpiringAuthorizationsListConstraints,
+    ExpiringAuthorizationsListResponse,
+};
+use common_utils::{date_time, ext_traits::ValueExt, id_type};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use super::{
+    auto_capture, payments_core, CallConnectorAction, PaymentCancel,
+    PaymentIncrementalAuthorization,
+};
+#[cfg(feature = "olap")]
+use crate::services::ApplicationResponse;
+use crate::{
+    configs::settings::AuthorizationValidity,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        webhooks,
+    },
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+        transformers::ForeignFrom,
+    },
+};
+#[cfg(feature = "olap")]
+use crate::{core::errors::RouterResponse, utils};
+
+const AUTHORIZATION_EXPIRY_TASK: &str = "AUTHORIZATION_EXPIRY";
+const EXPIRING_AUTHORIZATION_REASON: &str = "Authorization about to expire";
+#[cfg(feature = "olap")]
+const DEFAULT_EXPIRING_WITHIN_HOURS: u32 = 24;
+
+/// The data the authorization expiry workflow operates on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuthorizationExpiryTrackingData {
+    pub payment_id: id_type::PaymentId,
+    pub merchant_id: id_type::MerchantId,
+    pub attempt_id: String,
+    /// When the authorization the task acts on lapses, failed runs of the task are retried only
+    /// until then. Not recorded for tasks scheduled before the field was added.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub authorization_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+/// Determines how long an authorization made through `connector` on a card of `card_network`
+/// remains valid at the issuer. The shortest of the validities configured for the connector and
+/// the card network applies, falling back to the default validity if neither is configured.
+pub fn get_authorization_validity(
+    config: &AuthorizationValidity,
+    connector: Option<&str>,
+    card_network: Option<&enums::CardNetwork>,
+) -> time::Duration {
+    let find_validity = |validities: &std::collections::HashMap<String, u32>, key: &str| {
+        validities
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(key))
+            .map(|(_, hours)| *hours)
+    };
+
+    let connector_validity =
+        connector.and_then(|connector| find_validity(&config.connectors, connector));
+    let card_network_validity = card_network
+        .and_then(|card_network| find_validity(&config.card_networks, &card_network.to_string()));
+
+    let validity_in_hours = match (connector_validity, card_network_validity) {
+        (Some(connector_validity), Some(card_network_validity)) => {
+            connector_validity.min(card_network_validity)
+        }
+        (Some(validity), None) | (None, Some(validity)) => validity,
+        (None, None) => config.default_validity_in_hours,
+    };
+
+    time::Duration::hours(i64::from(validity_in_hours))
+}
+
+/// Determines when the authorization expiry workflow must act on an authorization expiring at
+/// `expires_at`, leaving the configured buffer for the connector to process the request.
+pub fn get_authorization_expiry_schedule(
+    config: &AuthorizationValidity,
+    expires_at: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    expires_at
+        .saturating_sub(time::Duration::minutes(i64::from(
+            config.expiry_buffer_in_minutes,
+        )))
+        .max(date_time::now())
+}
+
+/// The action to be taken on the payment before its authorization lapses. The action of the
+/// auto-capture policy of the payment takes precedence over that of the business profile.
+pub fn get_authorization_expiry_action(
+    payment_intent: &storage::PaymentIntent,
+    business_profile: &domain::BusinessProfile,
+) -> Option<enums::AuthorizationExpiryAction> {
+    payment_intent
+        .auto_capture_policy
+        .as_ref()
+        .map(|policy| policy.authorization_expiry_action)
+        .or(business_profile.authorization_expiry_action)
+}
+
+fn get_card_network(payment_attempt: &storage::PaymentAttempt) -> Option<enums::CardNetwork> {
+    payment_attempt
+        .payment_method_data
+        .clone()
+        .and_then(|payment_method_data| {
+            payment_method_data
+                .parse_value::<AdditionalPaymentData>("AdditionalPaymentData")
+                .map_err(|error| logger::warn!(additional_payment_data_parsing_error=?error))
+                .ok()
+        })
+        .and_then(|additional_payment_data| match additional_payment_data {
+            AdditionalPaymentData::Card(card_info) => card_info.card_network,
+            _ => None,
+        })
+}
+
+fn compute_authorization_expires_at(
+    config: &AuthorizationValidity,
+    payment_attempt: &storage::PaymentAttempt,
+    authorized_at: time::PrimitiveDateTime,
+) -> time::PrimitiveDateTime {
+    let validity = get_authorization_validity(
+        config,
+        payment_attempt.connector.as_deref(),
+        get_card_network(payment_attempt).as_ref(),
+    );
+    authorized_at.saturating_add(validity)
+}
+
+async fn update_authorization_expires_at(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: storage::PaymentIntent,
+    authorization_expires_at: time::PrimitiveDateTime,
+) -> RouterResult<storage::PaymentIntent> {
+    state
+        .store
+        .update_payment_intent(
+            &state.into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::AuthorizationExpiryUpdate {
+                authorization_expires_at,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+        .attach_printable("Failed to update authorization expiry of payment")
+}
+
+/// Records when the authorization of a payment authorized with manual capture lapses at the
+/// issuer, and schedules the expiry action of the payment just before then, if one applies.
+/// Payments whose authorization is already tracked, or whose authorization time was not
+/// recorded, are left untouched.
+#[instrument(skip_all)]
+pub async fn track_authorization_expiry(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &domain::BusinessProfile,
+    payment_intent: &mut storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(authorized_at) = payment_intent.authorized_at else {
+        return Ok(());
+    };
+    if payment_intent.status != enums::IntentStatus::RequiresCapture
+        || payment_intent.authorization_expires_at.is_some()
+    {
+        return Ok(());
+    }
+
+    let authorization_expires_at = compute_authorization_expires_at(
+        &state.conf.authorization_validity,
+        payment_attempt,
+        authorized_at,
+    );
+    *payment_intent = update_authorization_expires_at(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent.clone(),
+        authorization_expires_at,
+    )
+    .await?;
+
+    if get_authorization_expiry_action(payment_intent, business_profile).is_none() {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::AuthorizationExpiryWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        AUTHORIZATION_EXPIRY_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+
+    let existing_task = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch authorization expiry task")?;
+    if existing_task.is_some() {
+        return Ok(());
+    }
+
+    let schedule_time = get_authorization_expiry_schedule(
+        &state.conf.authorization_validity,
+        authorization_expires_at,
+    );
+    let tracking_data = AuthorizationExpiryTrackingData {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        authorization_expires_at: Some(authorization_expires_at),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        AUTHORIZATION_EXPIRY_TASK,
+        runner,
+        ["PAYMENTS", "AUTHORIZATION_EXPIRY"],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct authorization expiry task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add authorization expiry task to process tracker")?;
+
+    logger::info!(
+        payment_id = ?payment_intent.payment_id,
+        %authorization_expires_at,
+        %schedule_time,
+        "Scheduled authorization expiry task"
+    );
+
+    Ok(())
+}
+
+/// Captures, re-authorizes or voids a payment whose authorization is about to lapse, as per the
+/// expiry action of the payment. Payments which were already captured, voided or retried by the
+/// time the task runs are left untouched.
+///
+/// Returns the time at which the renewed authorization expires, if the payment was
+/// re-authorized.
+#[instrument(skip_all, fields(payment_id = ?tracking_data.payment_id))]
+pub async fn handle_expiring_authorization(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: &AuthorizationExpiryTrackingData,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &tracking_data.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        || !auto_capture::is_capturable(payment_intent.status)
+    {
+        logger::info!(
+            status = ?payment_intent.status,
+            "Skipping expiring authorization of payment which is no longer capturable"
+        );
+        return Ok(None);
+    }
+
+    let profile_id = payment_intent
+        .profile_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing profile_id in payment")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, &key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    // The action is determined again, since the profile may have been updated since the task
+    // was scheduled
+    let Some(action) = get_authorization_expiry_action(&payment_intent, &business_profile) else {
+        logger::info!("Skipping expiring authorization of payment without an expiry action");
+        return Ok(None);
+    };
+    logger::info!(?action, "Handling expiring authorization");
+
+    match action {
+        enums::AuthorizationExpiryAction::Capture => {
+            let status = auto_capture::capture_payment(
+                state,
+                merchant_account,
+                key_store,
+                tracking_data.payment_id.clone(),
+                tracking_data.merchant_id.clone(),
+            )
+            .await?;
+
+            // The authorization lapses unless the capture went through, so the task is retried
+            match status {
+                Some(status)
+                    if !auto_capture::is_capturable(status)
+                        && status != enums::IntentStatus::Failed => {}
+                status => {
+                    return Err(report!(errors::ApiErrorResponse::InternalServerError))
+                        .attach_printable_lazy(|| {
+                            format!(
+                                "Failed to capture payment with expiring authorization, status: {status:?}"
+                            )
+                        });
+                }
+            }
+        }
+        enums::AuthorizationExpiryAction::Reauthorize => {
+            // Payments whose authorization cannot be extended are voided instead, to release the
+            // funds held on the card before the issuer does
+            let reauthorized_at = if payment_intent.incremental_authorization_allowed == Some(true)
+            {
+                reauthorize_payment(state, &merchant_account, &key_store, &payment_intent)
+                    .await
+                    .map_err(|error| logger::warn!(reauthorization_error=?error))
+                    .ok()
+                    .flatten()
+            } else {
+                None
+            };
+
+            if let Some(reauthorized_at) = reauthorized_at {
+                let payment_attempt = db
+                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                        &payment_intent.payment_id,
+                        merchant_account.get_id(),
+                        &tracking_data.attempt_id,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                let authorization_expires_at = compute_authorization_expires_at(
+                    &state.conf.authorization_validity,
+                    &payment_attempt,
+                    reauthorized_at,
+                );
+                // The payment may have been updated by the re-authorization
+                let payment_intent = db
+                    .find_payment_intent_by_payment_id_merchant_id(
+                        key_manager_state,
+                        &tracking_data.payment_id,
+                        merchant_account.get_id(),
+                        &key_store,
+                        merchant_account.storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                update_authorization_expires_at(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    payment_intent,
+                    authorization_expires_at,
+                )
+                .await?;
+
+                logger::info!(%authorization_expires_at, "Re-authorized payment");
+                return Ok(Some(authorization_expires_at));
+            }
+
+            void_payment(
+                state,
+                merchant_account,
+                key_store,
+                tracking_data.payment_id.clone(),
+            )
+            .await?;
+        }
+        enums::AuthorizationExpiryAction::Void => {
+            void_payment(
+                state,
+                merchant_account,
+                key_store,
+                tracking_data.payment_id.clone(),
+            )
+            .await?;
+        }
+    }
+
+    Ok(None)
+}
+
+/// Re-authorizes the authorized amount of a payment at the connector, returning when the
+/// connector confirmed the re-authorization, if it did.
+async fn reauthorize_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    let reauthorization_request = PaymentsIncrementalAuthorizationRequest {
+        payment_id: payment_intent.payment_id.clone(),
+        amount: payment_intent.amount,
+        reason: Some(EXPIRING_AUTHORIZATION_REASON.to_string()),
+        is_reauthorization: true,
+    };
+    let response = Box::pin(payments_core::<
+        api::IncrementalAuthorization,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        PaymentIncrementalAuthorization,
+        reauthorization_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    let services::ApplicationResponse::JsonWithHeaders((payments_response, _)) = response else {
+        return Ok(None);
+    };
+    let Some(reauthorization_id) = payments_response
+        .incremental_authorizations
+        .as_ref()
+        .and_then(|authorizations| authorizations.last())
+        .filter(|authorization| authorization.status == common_enums::AuthorizationStatus::Success)
+        .map(|authorization| authorization.authorization_id.clone())
+    else {
+        return Ok(None);
+    };
+
+    // The authorization is last modified when the response of the connector is recorded
+    let reauthorized_at = state
+        .store
+        .find_all_authorizations_by_merchant_id_payment_id(
+            merchant_account.get_id(),
+            &payment_intent.payment_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch authorizations of payment")?
+        .into_iter()
+        .find(|authorization| authorization.authorization_id == reauthorization_id)
+        .map(|authorization| authorization.modified_at);
+
+    Ok(reauthorized_at)
+}
+
+/// Voids a payment on behalf of the merchant, and notifies the merchant of the cancellation.
+async fn void_payment(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+) -> RouterResult<()> {
+    let cancel_request = PaymentsCancelRequest {
+        payment_id,
+        cancellation_reason: Some(EXPIRING_AUTHORIZATION_REASON.to_string()),
+        merchant_connector_details: None,
+    };
+    let cancel_response = Box::pin(payments_core::<api::Void, api::PaymentsResponse, _, _, _>(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        PaymentCancel,
+        cancel_request,
+        services::AuthFlow::Merchant,
+        CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?;
+
+    if let services::ApplicationResponse::JsonWithHeaders((payments_response, _)) = cancel_response
+    {
+        trigger_void_webhook(state, merchant_account, &key_store, payments_response)
+            .await
+            .map_err(|error| logger::warn!(expired_authorization_void_webhook_error=?error))
+            .ok();
+    }
+
+    Ok(())
+}
+
+/// The payments core does not notify merchants of cancelled payments, since merchants cancel
+/// payments themselves. Payments voided on behalf of the merchant are notified explicitly.
+async fn trigger_void_webhook(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payments_response: api::PaymentsResponse,
+) -> RouterResult<()> {
+    let Some(event_type) = Option::<enums::EventType>::foreign_from(payments_response.status)
+        .filter(|event_type| *event_type == enums::EventType::PaymentCancelled)
+    else {
+        return Ok(());
+    };
+
+    let profile_id = payments_response
+        .profile_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing profile_id in payment")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, &profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let primary_object_created_at = payments_response.created;
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Payments,
+        payments_response.payment_id.get_string_repr().to_owned(),
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+        primary_object_created_at,
+    ))
+    .await
+}
+
+/// Lists the capturable payments of the merchant whose authorization lapses within the
+/// requested number of hours, the earliest expiring first.
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn list_expiring_authorizations(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    constraints: ExpiringAuthorizationsListConstraints,
+) -> RouterResponse<ExpiringAuthorizationsListResponse> {
+    let limit = constraints
+        .limit
+        .unwrap_or(common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1);
+    utils::when(
+        !(1..=common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1).contains(&limit),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "limit should be in between 1 and {}",
+                    common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1
+                ),
+            })
+        },
+    )?;
+
+    let expiring_within_hours = constraints
+        .expiring_within_hours
+        .unwrap_or(DEFAULT_EXPIRING_WITHIN_HOURS);
+    let expiring_before =
+        date_time::now().saturating_add(time::Duration::hours(i64::from(expiring_within_hours)));
+
+    let payment_intents = state
+        .store
+        .find_payment_intents_with_expiring_authorization(
+            &(&state).into(),
+            merchant_account.get_id(),
+            constraints.profile_id.as_ref(),
+            expiring_before,
+            limit,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payments with expiring authorization")?;
+
+    let data = payment_intents
+        .into_iter()
+        .filter_map(|payment_intent| {
+            payment_intent
+                .authorization_expires_at
+                .map(|authorization_expires_at| ExpiringAuthorizationResponse {
+                    payment_id: payment_intent.payment_id,
+                    profile_id: payment_intent.profile_id,
+                    status: payment_intent.status,
+                    amount: payment_intent.amount,
+                    currency: payment_intent.currency,
+                    authorization_expires_at,
+                    created: payment_intent.created_at,
+                })
+        })
+        .collect::<Vec<_>>();
+
+    Ok(ApplicationResponse::Json(
+        ExpiringAuthorizationsListResponse {
+            count: data.len(),
+            data,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::HashMap;
+
+    use super::*;
+
+    fn config() -> AuthorizationValidity {
+        AuthorizationValidity {
+            default_validity_in_hours: 168,
+            connectors: HashMap::from([("adyen".to_string(), 672)]),
+            card_networks: HashMap::from([
+                ("Visa".to_string(), 168),
+                ("Discover".to_string(), 240),
+            ]),
+            expiry_buffer_in_minutes: 60,
+        }
+    }
+
+    #[test]
+    fn test_default_authorization_validity() {
+        let validity = get_authorization_validity(&config(), Some("stripe"), None);
+
+        assert_eq!(validity, time::Duration::hours(168));
+    }
+
+    #[test]
+    fn test_connector_authorization_validity() {
+        let validity = get_authorization_validity(&config(), Some("Adyen"), None);
+
+        assert_eq!(validity, time::Duration::hours(672));
+
+        // Authorizations on cards of networks without a configured validity last as long as the
+        // connector allows
+        let validity = get_authorization_validity(
+            &config(),
+            Some("adyen"),
+            Some(&enums::CardNetwork::Mastercard),
+        );
+
+        assert_eq!(validity, time::Duration::hours(672));
+    }
+
+    #[test]
+    fn test_shortest_authorization_validity_applies() {
+        let validity =
+            get_authorization_validity(&config(), Some("adyen"), Some(&enums::CardNetwork::Visa));
+
+        assert_eq!(validity, time::Duration::hours(168));
+
+        let validity = get_authorization_validity(
+            &config(),
+            Some("stripe"),
+            Some(&enums::CardNetwork::Discover),
+        );
+
+        assert_eq!(validity, time::Duration::hours(240));
+    }
+}
@@ -0,0 +1,278 @@
+use api_models::payments::PaymentsCaptureRequest;
+use common_utils::{cron::CronSchedule, id_type};
+use diesel_models::business_profile::AutoCapturePolicy;
+use error_stack::{report, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use super::{payments_core, CallConnectorAction, PaymentCapture};
+use crate::{
+    core::errors::{self, RouterResult, StorageErrorExt},
+    routes::SessionState,
+    services,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+const AUTO_CAPTURE_TASK: &str = "AUTO_CAPTURE";
+
+/// The data the auto-capture workflow operates on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AutoCaptureTrackingData {
+    pub payment_id: id_type::PaymentId,
+    pub merchant_id: id_type::MerchantId,
+    pub attempt_id: String,
+}
+
+/// Schedules the capture of a payment authorized with manual capture, if an auto-capture policy
+/// applies to the payment. Payments which already have an auto-capture task are left untouched.
+///
+/// Authorizations which would lapse before the scheduled capture are handled by the
+/// authorization expiry workflow, the capture is skipped if the payment is no longer capturable
+/// by the time the task runs.
+#[instrument(skip_all)]
+pub async fn add_auto_capture_task(
+    state: &SessionState,
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> RouterResult<()> {
+    let Some(policy) = payment_intent.auto_capture_policy.as_ref() else {
+        return Ok(());
+    };
+    if payment_intent.status != enums::IntentStatus::RequiresCapture {
+        return Ok(());
+    }
+
+    // The policy is copied onto every payment of the profile, it applies to the payment only if
+    // it ends up captured manually, whichever way the capture method was set. Payments whose
+    // authorization time was not recorded are left untouched.
+    let Some(authorized_at) = payment_intent.authorized_at else {
+        return Ok(());
+    };
+    let Some(schedule_time) = get_auto_capture_time(policy, authorized_at)? else {
+        return Ok(());
+    };
+
+    let db = &*state.store;
+    let runner = storage::ProcessTrackerRunner::AutoCaptureWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        AUTO_CAPTURE_TASK,
+        &payment_attempt.attempt_id,
+        &payment_attempt.merchant_id,
+    );
+
+    let existing_task = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch auto-capture task")?;
+    if existing_task.is_some() {
+        return Ok(());
+    }
+
+    let tracking_data = AutoCaptureTrackingData {
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: payment_intent.merchant_id.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        AUTO_CAPTURE_TASK,
+        runner,
+        ["PAYMENTS", "AUTO_CAPTURE"],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct auto-capture task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add auto-capture task to process tracker")?;
+
+    logger::info!(
+        payment_id = ?payment_intent.payment_id,
+        %schedule_time,
+        "Scheduled auto-capture task"
+    );
+
+    Ok(())
+}
+
+/// Determines when a payment authorized at `authorized_at` is to be captured as per the
+/// auto-capture policy.
+pub fn get_auto_capture_time(
+    policy: &AutoCapturePolicy,
+    authorized_at: time::PrimitiveDateTime,
+) -> RouterResult<Option<time::PrimitiveDateTime>> {
+    match (
+        policy.capture_after_hours,
+        policy.capture_schedule.as_deref(),
+    ) {
+        (Some(hours), _) => Ok(Some(
+            authorized_at.saturating_add(time::Duration::hours(i64::from(hours))),
+        )),
+        (None, Some(capture_schedule)) => Ok(CronSchedule::parse(capture_schedule)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Invalid capture schedule in auto-capture policy")?
+            .next_occurrence_after(authorized_at)),
+        (None, None) => Ok(None),
+    }
+}
+
+/// Captures a payment as scheduled by its auto-capture policy. Payments which were already
+/// captured, voided or retried by the time the task runs are left untouched.
+///
+/// Fails if the payment could not be captured and is still capturable, so that the capture is
+/// retried.
+#[instrument(skip_all, fields(payment_id = ?tracking_data.payment_id))]
+pub async fn execute_auto_capture(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    tracking_data: AutoCaptureTrackingData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            key_manager_state,
+            &tracking_data.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_intent.active_attempt.get_id() != tracking_data.attempt_id
+        || !is_capturable(payment_intent.status)
+    {
+        logger::info!(
+            status = ?payment_intent.status,
+            "Skipping auto-capture of payment which is no longer capturable"
+        );
+        return Ok(());
+    }
+
+    let status = capture_payment(
+        state,
+        merchant_account,
+        key_store,
+        tracking_data.payment_id,
+        tracking_data.merchant_id,
+    )
+    .await?;
+
+    match status {
+        Some(status) if is_capturable(status) => {
+            Err(report!(errors::ApiErrorResponse::InternalServerError)).attach_printable_lazy(
+                || format!("Payment is still capturable after auto-capture, status: {status}"),
+            )
+        }
+        _ => Ok(()),
+    }
+}
+
+pub(super) fn is_capturable(status: enums::IntentStatus) -> bool {
+    matches!(
+        status,
+        enums::IntentStatus::RequiresCapture | enums::IntentStatus::PartiallyCapturedAndCapturable
+    )
+}
+
+/// Captures the remaining authorized amount of a payment on behalf of the merchant, returning the
+/// status of the payment after the capture. Outgoing webhooks for captured payments are sent by
+/// the payments core.
+pub(super) async fn capture_payment(
+    state: &SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_id: id_type::PaymentId,
+    merchant_id: id_type::MerchantId,
+) -> RouterResult<Option<enums::IntentStatus>> {
+    let capture_request = PaymentsCaptureRequest {
+        payment_id,
+        merchant_id: Some(merchant_id),
+        amount_to_capture: None,
+        refund_uncaptured_amount: None,
+        statement_descriptor_suffix: None,
+        statement_descriptor_prefix: None,
+        merchant_connector_details: None,
+    };
+    let response = Box::pin(
+        payments_core::<api::Capture, api::PaymentsResponse, _, _, _>(
+            state.clone(),
+            state.get_req_state(),
+            merchant_account,
+            None,
+            key_store,
+            PaymentCapture,
+            capture_request,
+            services::AuthFlow::Merchant,
+            CallConnectorAction::Trigger,
+            None,
+            api::HeaderPayload::default(),
+        ),
+    )
+    .await?;
+
+    Ok(match response {
+        services::ApplicationResponse::JsonWithHeaders((payments_response, _)) => {
+            Some(payments_response.status)
+        }
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn policy(
+        capture_after_hours: Option<u16>,
+        capture_schedule: Option<&str>,
+    ) -> AutoCapturePolicy {
+        AutoCapturePolicy {
+            capture_after_hours,
+            capture_schedule: capture_schedule.map(ToOwned::to_owned),
+            authorization_expiry_action: enums::AuthorizationExpiryAction::Void,
+        }
+    }
+
+    #[test]
+    fn test_capture_after_delay() {
+        let capture_at =
+            get_auto_capture_time(&policy(Some(24), None), datetime!(2024-09-09 10:30)).unwrap();
+
+        assert_eq!(capture_at, Some(datetime!(2024-09-10 10:30)));
+    }
+
+    #[test]
+    fn test_capture_on_schedule() {
+        // Captured in the nightly batch following the authorization
+        let capture_at = get_auto_capture_time(
+            &policy(None, Some("0 2 * * *")),
+            datetime!(2024-09-09 10:30),
+        )
+        .unwrap();
+
+        assert_eq!(capture_at, Some(datetime!(2024-09-10 02:00)));
+    }
+
+    #[test]
+    fn test_no_capture_without_delay_or_schedule() {
+        let capture_at =
+            get_auto_capture_time(&policy(None, None), datetime!(2024-09-09 10:30)).unwrap();
+
+        assert_eq!(capture_at, None);
+    }
+}
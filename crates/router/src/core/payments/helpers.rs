@@ -1669,6 +1669,8 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R>(
                         default_payment_method_id: None,
                         updated_by: None,
                         version: hyperswitch_domain_models::consts::API_VERSION,
+                        tax_ids: None,
+                        segments: None,
                     };
                     metrics::CUSTOMER_CREATED.add(&metrics::CONTEXT, 1, &[]);
                     db.insert_customer(new_customer, key_manager_state, key_store, storage_scheme)
@@ -2467,7 +2469,7 @@ pub fn validate_payment_method_type_against_payment_method(
         ),
         api_enums::PaymentMethod::OpenBanking => matches!(
             payment_method_type,
-            api_enums::PaymentMethodType::OpenBankingPIS
+            api_enums::PaymentMethodType::OpenBankingPIS | api_enums::PaymentMethodType::PayByBank
         ),
     }
 }
@@ -2634,6 +2636,22 @@ pub async fn make_ephemeral_key(
     customer_id: id_type::CustomerId,
     merchant_id: id_type::MerchantId,
 ) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
+    let ek = create_ephemeral_key(
+        &state,
+        customer_id,
+        merchant_id,
+        time::Duration::hours(state.conf.eph_key.validity),
+    )
+    .await?;
+    Ok(services::ApplicationResponse::Json(ek))
+}
+
+pub async fn create_ephemeral_key(
+    state: &SessionState,
+    customer_id: id_type::CustomerId,
+    merchant_id: id_type::MerchantId,
+    validity: time::Duration,
+) -> RouterResult<ephemeral_key::EphemeralKey> {
     let store = &state.store;
     let id = utils::generate_id(consts::ID_LENGTH, "eki");
     let secret = format!("epk_{}", &Uuid::new_v4().simple().to_string());
@@ -2643,12 +2661,11 @@ pub async fn make_ephemeral_key(
         merchant_id: merchant_id.to_owned(),
         secret,
     };
-    let ek = store
-        .create_ephemeral_key(ek, state.conf.eph_key.validity)
+    store
+        .create_ephemeral_key(ek, validity)
         .await
         .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Unable to create ephemeral key")?;
-    Ok(services::ApplicationResponse::Json(ek))
+        .attach_printable("Unable to create ephemeral key")
 }
 
 pub async fn delete_ephemeral_key(
@@ -3059,6 +3076,10 @@ mod tests {
             shipping_details: None,
             is_payment_processor_token_flow: None,
             organization_id: id_type::OrganizationId::default(),
+            auto_capture_policy: None,
+            authorization_expires_at: None,
+            authorized_at: None,
+            captured_at: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_ok());
@@ -3125,6 +3146,10 @@ mod tests {
             shipping_details: None,
             is_payment_processor_token_flow: None,
             organization_id: id_type::OrganizationId::default(),
+            auto_capture_policy: None,
+            authorization_expires_at: None,
+            authorized_at: None,
+            captured_at: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent,).is_err())
@@ -3189,6 +3214,10 @@ mod tests {
             shipping_details: None,
             is_payment_processor_token_flow: None,
             organization_id: id_type::OrganizationId::default(),
+            auto_capture_policy: None,
+            authorization_expires_at: None,
+            authorized_at: None,
+            captured_at: None,
         };
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
@@ -4796,6 +4825,30 @@ pub fn validate_intent_fulfillment_expiry(
     }
 }
 
+pub fn validate_auto_capture_policy(
+    auto_capture_policy: &api_models::admin::AutoCapturePolicy,
+) -> Result<(), errors::ApiErrorResponse> {
+    match (
+        auto_capture_policy.capture_after_hours,
+        auto_capture_policy.capture_schedule.as_deref(),
+    ) {
+        (Some(capture_after_hours), None) => utils::when(capture_after_hours == 0, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "capture_after_hours should be greater than 0".to_string(),
+            })
+        }),
+        (None, Some(capture_schedule)) => common_utils::cron::CronSchedule::parse(capture_schedule)
+            .map(|_| ())
+            .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                message: error.current_context().to_string(),
+            }),
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of capture_after_hours and capture_schedule should be provided"
+                .to_string(),
+        }),
+    }
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: AdditionalPaymentMethodConnectorResponse,
@@ -173,7 +173,8 @@ pub trait Domain<F: Clone, R>: Send + Sync {
         _payment_data: &mut PaymentData<F>,
         _should_continue_confirm_transaction: &mut bool,
         _connector_call_type: &ConnectorCallType,
-        _merchant_account: &domain::BusinessProfile,
+        _business_profile: &domain::BusinessProfile,
+        _merchant_account: &domain::MerchantAccount,
         _key_store: &domain::MerchantKeyStore,
         _mandate_type: Option<api_models::payments::MandateTransactionType>,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
@@ -132,7 +132,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsCaptureRequest>
         payment_intent.billing_address_id = billing_address.clone().map(|i| i.address_id);
 
         let frm_response = db
-        .find_fraud_check_by_payment_id(payment_intent.payment_id.clone(), merchant_account.get_id().clone())
+        .find_fraud_check_by_payment_id(payment_intent.payment_id.clone(), merchant_account.get_id().clone(), merchant_account.storage_scheme)
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)
         .attach_printable_lazy(|| {
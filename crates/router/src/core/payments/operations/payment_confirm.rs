@@ -861,6 +861,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
         should_continue_confirm_transaction: &mut bool,
         connector_call_type: &ConnectorCallType,
         business_profile: &domain::BusinessProfile,
+        merchant_account: &domain::MerchantAccount,
         key_store: &domain::MerchantKeyStore,
         mandate_type: Option<api_models::payments::MandateTransactionType>,
     ) -> CustomResult<(), errors::ApiErrorResponse> {
@@ -888,6 +889,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
                     business_profile,
                     Some(acquirer_details),
                     Some(payment_data.payment_attempt.payment_id.clone()),
+                    merchant_account.storage_scheme,
                 )
                 .await?;
                 if authentication.is_separate_authn_required()
@@ -927,6 +929,7 @@ impl<F: Clone + Send> Domain<F, api::PaymentsRequest> for PaymentConfirm {
                     key_store,
                     business_profile.clone(),
                     authentication_id.clone(),
+                    merchant_account.storage_scheme,
                 )
                 .await?;
                 //If authentication is not successful, skip the payment connector flows and mark the payment as failure
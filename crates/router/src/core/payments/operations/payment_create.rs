@@ -296,6 +296,7 @@ impl<F: Send + Clone> GetTracker<F, PaymentData<F>, api::PaymentsRequest> for Pa
             attempt_id,
             profile_id.clone(),
             session_expiry,
+            &business_profile,
         )
         .await?;
 
@@ -1083,6 +1084,7 @@ impl PaymentCreate {
         active_attempt_id: String,
         profile_id: common_utils::id_type::ProfileId,
         session_expiry: PrimitiveDateTime,
+        business_profile: &domain::BusinessProfile,
     ) -> RouterResult<storage::PaymentIntent> {
         let created_at @ modified_at @ last_synced = common_utils::date_time::now();
 
@@ -1183,6 +1185,11 @@ impl PaymentCreate {
             },
         );
 
+        // The auto-capture policy of the profile is copied onto the payment so that later changes
+        // to the profile do not affect it. It is only applied once the payment is authorized, as
+        // the capture method may still be set when the payment is confirmed.
+        let auto_capture_policy = business_profile.auto_capture_policy.clone();
+
         // Encrypting our Customer Details to be stored in Payment Intent
         let customer_details = raw_customer_details
             .async_map(|customer_details| create_encrypted_data(state, key_store, customer_details))
@@ -1244,6 +1251,10 @@ impl PaymentCreate {
             shipping_details,
             is_payment_processor_token_flow,
             organization_id: merchant_account.organization_id.clone(),
+            auto_capture_policy,
+            authorization_expires_at: None,
+            authorized_at: None,
+            captured_at: None,
         })
     }
 
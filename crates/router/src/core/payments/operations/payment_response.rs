@@ -1269,6 +1269,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 .update_authentication_by_merchant_id_authentication_id(
                     authentication,
                     authentication_update,
+                    storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
@@ -1304,6 +1305,19 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
             incremental_authorization_allowed: payment_data
                 .payment_intent
                 .incremental_authorization_allowed,
+            // Only the first authorization and capture are recorded, later syncs of the payment
+            // keep them
+            authorized_at: (payment_data.payment_intent.authorized_at.is_none()
+                && payment_data.payment_attempt.status == enums::AttemptStatus::Authorized)
+                .then(common_utils::date_time::now),
+            captured_at: (payment_data.payment_intent.captured_at.is_none()
+                && matches!(
+                    payment_data.payment_attempt.status,
+                    enums::AttemptStatus::Charged
+                        | enums::AttemptStatus::PartialCharged
+                        | enums::AttemptStatus::PartialChargedAndChargeable
+                ))
+            .then(common_utils::date_time::now),
         },
     };
 
@@ -1475,6 +1489,14 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
             }
         };
 
+        let is_reissue_decline = payment_response.as_ref().is_err_and(|error_response| {
+            state
+                .conf
+                .account_updater
+                .reissue_decline_codes
+                .contains(&error_response.code)
+        });
+
         let pm_resp_network_transaction_id = payment_response
             .map(|resp| if let types::PaymentsResponseData::TransactionResponse { network_txn_id: network_transaction_id, .. } = resp {
                 network_transaction_id
@@ -1499,6 +1521,26 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
                 None
             };
 
+        // A saved card declined in a payment as expired is submitted to the account updater, in
+        // case the card was reissued
+        if attempt_status == common_enums::AttemptStatus::Failure
+            && is_reissue_decline
+            && state.conf.account_updater.enabled
+            && payment_method.status == common_enums::PaymentMethodStatus::Active
+            && payment_method.payment_method == Some(enums::PaymentMethod::Card)
+        {
+            payment_methods::account_updater::add_account_updater_item(
+                state,
+                &payment_method,
+                enums::AccountUpdaterReason::PaymentFailed,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to queue saved card for the account updater")
+            })
+            .ok();
+        }
+
         let pm_update = if payment_method.status != common_enums::PaymentMethodStatus::Active
             && payment_method.status != attempt_status.into()
         {
@@ -1518,12 +1560,35 @@ async fn update_payment_method_status_and_ntid<F: Clone>(
             }
         };
 
-        state
+        let payment_method = state
             .store
             .update_payment_method(payment_method, pm_update, storage_scheme)
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
             .attach_printable("Failed to update payment method in db")?;
+
+        // Merchant initiated payments declined in a row are counted, for the payment method
+        // health sweep to flag saved payment methods which are likely to be declined again
+        let is_mit_declined = match attempt_status {
+            common_enums::AttemptStatus::Failure => Some(true),
+            common_enums::AttemptStatus::Charged | common_enums::AttemptStatus::Authorized => {
+                Some(false)
+            }
+            _ => None,
+        };
+        if let Some(is_mit_declined) = is_mit_declined.filter(|_| {
+            state.conf.payment_method_health.enabled
+                && payment_data.payment_intent.off_session == Some(true)
+        }) {
+            payment_methods::health::update_consecutive_mit_declines(
+                state,
+                &payment_method.payment_method_id,
+                is_mit_declined,
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to update consecutive mit declines"))
+            .ok();
+        }
     };
     Ok(())
 }
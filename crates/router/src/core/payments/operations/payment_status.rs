@@ -331,7 +331,7 @@ async fn get_tracker_for_sync<
         })?;
 
     let disputes = db
-        .find_disputes_by_merchant_id_payment_id(merchant_account.get_id(), &payment_id)
+        .find_disputes_by_merchant_id_payment_id(merchant_account.get_id(), &payment_id, storage_scheme)
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)
         .attach_printable_lazy(|| {
@@ -339,7 +339,7 @@ async fn get_tracker_for_sync<
         })?;
 
     let frm_response = db
-        .find_fraud_check_by_payment_id(payment_id.to_owned(), merchant_account.get_id().clone())
+        .find_fraud_check_by_payment_id(payment_id.to_owned(), merchant_account.get_id().clone(), storage_scheme)
         .await
         .change_context(errors::ApiErrorResponse::PaymentNotFound)
         .attach_printable_lazy(|| {
@@ -402,7 +402,7 @@ async fn get_tracker_for_sync<
     let authentication = payment_attempt.authentication_id.clone().async_map(|authentication_id| async move {
             db.find_authentication_by_merchant_id_authentication_id(
                     &merchant_id,
-                    authentication_id.clone(),
+                    authentication_id.clone(), storage_scheme,
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
@@ -80,7 +80,14 @@ impl<F: Send + Clone>
             })?
         }
 
-        if payment_intent.amount > request.amount {
+        // A re-authorization is made for the authorized amount
+        if request.is_reauthorization {
+            if payment_intent.amount != request.amount {
+                Err(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Amount should be equal to the authorized amount".to_owned(),
+                })?
+            }
+        } else if payment_intent.amount > request.amount {
             Err(errors::ApiErrorResponse::PreconditionFailed {
                 message: "Amount should be greater than original authorized amount".to_owned(),
             })?
@@ -153,6 +160,7 @@ impl<F: Send + Clone>
                 total_amount: request.amount,
                 reason: request.reason.clone(),
                 authorization_id: None,
+                is_reauthorization: request.is_reauthorization,
             }),
             authorizations: vec![],
             authentication: None,
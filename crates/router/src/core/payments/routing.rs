@@ -40,7 +40,7 @@ use crate::core::admin;
 use crate::core::payouts;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        customers, errors, errors as oss_errors, payment_methods, payments as payments_oss,
         routing::{self},
     },
     logger,
@@ -67,6 +67,7 @@ pub struct SessionFlowRoutingInput<'a> {
     pub merchant_account: &'a domain::MerchantAccount,
     pub payment_attempt: &'a oss_storage::PaymentAttempt,
     pub payment_intent: &'a oss_storage::PaymentIntent,
+    pub customer: Option<&'a domain::Customer>,
     pub chosen: Vec<api::SessionConnectorData>,
 }
 
@@ -138,6 +139,7 @@ pub fn make_dsl_input_for_payouts(
             .map(api_enums::Country::from_alpha2),
         business_label: payout_data.payout_attempt.business_label.clone(),
         setup_future_usage: None,
+        card_fingerprint_customer_count: None,
     };
     let payment_method = dsl_inputs::PaymentMethodInput {
         payment_method: payout_data
@@ -252,6 +254,7 @@ where
             .map(api_enums::Country::from_alpha2),
         business_label: payment_data.payment_intent.business_label.clone(),
         setup_future_usage: payment_data.payment_intent.setup_future_usage,
+        card_fingerprint_customer_count: None,
     };
 
     let metadata = payment_data
@@ -278,6 +281,7 @@ pub async fn perform_static_routing_v1<F: Clone>(
     algorithm_id: Option<common_utils::id_type::RoutingId>,
     business_profile: &domain::BusinessProfile,
     transaction_data: &routing::TransactionData<'_, F>,
+    customer: Option<&domain::Customer>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let algorithm_id = if let Some(id) = algorithm_id {
         id
@@ -316,7 +320,21 @@ pub async fn perform_static_routing_v1<F: Clone>(
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
-                routing::TransactionData::Payment(payment_data) => make_dsl_input(payment_data)?,
+                routing::TransactionData::Payment(payment_data) => {
+                    let mut backend_input = make_dsl_input(payment_data)?;
+                    backend_input.payment.card_fingerprint_customer_count =
+                        payment_methods::fingerprint::get_payment_fingerprint_customer_count(
+                            state,
+                            merchant_id,
+                            payment_data,
+                        )
+                        .await;
+                    add_customer_segments_to_dsl_input(
+                        &customers::get_segments_of_customer(customer),
+                        &mut backend_input,
+                    );
+                    backend_input
+                }
                 #[cfg(feature = "payouts")]
                 routing::TransactionData::Payout(payout_data) => {
                     make_dsl_input_for_payouts(payout_data)?
@@ -328,6 +346,28 @@ pub async fn perform_static_routing_v1<F: Clone>(
     })
 }
 
+/// Adds the segments of the customer of the payment to the routing metadata, so that routing and
+/// surcharge rules can match the customers of a segment on its `customer_segment.<segment>` key
+pub fn add_customer_segments_to_dsl_input(
+    customer_segments: &[String],
+    backend_input: &mut dsl_inputs::BackendInput,
+) {
+    if customer_segments.is_empty() {
+        return;
+    }
+
+    let metadata = backend_input.metadata.get_or_insert_with(Default::default);
+    for segment in customer_segments {
+        metadata.insert(
+            format!(
+                "{}.{segment}",
+                customers::CUSTOMER_SEGMENT_ROUTING_KEY_PREFIX
+            ),
+            true.to_string(),
+        );
+    }
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &SessionState,
     merchant_id: &common_utils::id_type::MerchantId,
@@ -874,6 +914,7 @@ pub async fn perform_session_flow_routing(
             .map(storage_enums::Country::from_alpha2),
         business_label: session_input.payment_intent.business_label.clone(),
         setup_future_usage: session_input.payment_intent.setup_future_usage,
+        card_fingerprint_customer_count: None,
     };
 
     let metadata = session_input
@@ -896,6 +937,10 @@ pub async fn perform_session_flow_routing(
             payment_type: None,
         },
     };
+    add_customer_segments_to_dsl_input(
+        &customers::get_segments_of_customer(session_input.customer),
+        &mut backend_input,
+    );
 
     for connector_data in session_input.chosen.iter() {
         pm_type_map
@@ -1151,6 +1196,7 @@ pub fn make_dsl_input_for_surcharge(
             .map(api_enums::Country::from_alpha2),
         business_label: payment_intent.business_label.clone(),
         setup_future_usage: payment_intent.setup_future_usage,
+        card_fingerprint_customer_count: None,
     };
     let metadata = payment_intent
         .metadata
@@ -238,6 +238,13 @@ where
                     .change_context(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("Unable to encrypt payment method billing address")?;
 
+                let fingerprint_id = payment_methods::cards::generate_saved_card_fingerprint(
+                    state,
+                    merchant_id,
+                    payment_method_create_request.card.as_ref(),
+                )
+                .await;
+
                 let mut payment_method_id = resp.payment_method_id.clone();
                 let mut locker_id = None;
 
@@ -339,6 +346,7 @@ where
                                                 card.card_network
                                                     .map(|card_network| card_network.to_string())
                                             }),
+                                            fingerprint_id,
                                         )
                                         .await
                                     } else {
@@ -437,6 +445,7 @@ where
                                                         card_network.to_string()
                                                     })
                                                 }),
+                                                fingerprint_id,
                                             )
                                             .await
                                         } else {
@@ -585,11 +594,36 @@ where
                                     }
                                 }
                             }
+                        } else if let Some(fingerprint_id) = fingerprint_id.as_deref() {
+                            payment_methods::cards::find_customer_payment_method_by_fingerprint_id(
+                                state,
+                                &customer_id,
+                                merchant_id,
+                                fingerprint_id,
+                                merchant_account.storage_scheme,
+                            )
+                            .await
                         } else {
                             Ok(None)
                         }?;
 
                         if let Some(customer_saved_pm) = customer_saved_pm_option {
+                            if fingerprint_id.is_some() {
+                                // The customer has already saved the card, so the card just added
+                                // to the locker is removed in favour of the saved one
+                                payment_methods::cards::delete_card_from_locker(
+                                    state,
+                                    &customer_id,
+                                    merchant_id,
+                                    &resp.payment_method_id,
+                                )
+                                .await
+                                .map_err(|e| {
+                                    logger::error!("Failed to delete duplicate card: {:?}", e);
+                                })
+                                .ok();
+                            }
+
                             payment_methods::cards::update_last_used_at(
                                 &customer_saved_pm,
                                 state,
@@ -634,6 +668,7 @@ where
                                     card.card_network
                                         .map(|card_network| card_network.to_string())
                                 }),
+                                fingerprint_id,
                             )
                             .await?;
                         };
@@ -739,6 +774,7 @@ async fn skip_saving_card_in_locker(
                 bank_transfer: None,
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                health_status: None,
             };
 
             Ok((pm_resp, None))
@@ -761,6 +797,7 @@ async fn skip_saving_card_in_locker(
                 bank_transfer: None,
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                health_status: None,
             };
             Ok((payment_method_response, None))
         }
@@ -826,6 +863,7 @@ pub async fn save_in_locker(
                 payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]), //[#219]
                 last_used_at: Some(common_utils::date_time::now()),
                 client_secret: None,
+                health_status: None,
             };
             Ok((payment_method_response, None))
         }
@@ -961,6 +961,7 @@ where
             payment_method_id: payment_attempt.payment_method_id,
             payment_method_status: payment_data.payment_method_info.map(|info| info.status),
             updated: Some(payment_intent.modified_at),
+            authorization_expires_at: payment_intent.authorization_expires_at,
             charges: charges_response,
             frm_metadata: payment_intent.frm_metadata,
             merchant_order_reference_id: payment_intent.merchant_order_reference_id,
@@ -1213,6 +1214,7 @@ impl ForeignFrom<(storage::PaymentIntent, storage::PaymentAttempt)> for api::Pay
             payment_method_id: None,
             payment_method_status: None,
             updated: None,
+            authorization_expires_at: pi.authorization_expires_at,
             charges: None,
             frm_metadata: None,
         }
@@ -1578,12 +1580,17 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>>
                 report!(errors::ApiErrorResponse::InternalServerError)
                     .attach_printable("missing incremental_authorization_details in payment_data"),
             )?;
+        let is_reauthorization = payment_data
+            .incremental_authorization_details
+            .as_ref()
+            .is_some_and(|details| details.is_reauthorization);
         Ok(Self {
             total_amount: total_amount.get_amount_as_i64(),
             additional_amount: additional_amount.get_amount_as_i64(),
             reason: payment_data
                 .incremental_authorization_details
                 .and_then(|details| details.reason),
+            is_reauthorization,
             currency: payment_data.currency,
             connector_transaction_id: connector
                 .connector
@@ -1,4 +1,5 @@
 pub mod access_token;
+pub mod batch;
 pub mod helpers;
 #[cfg(feature = "payout_retry")]
 pub mod retry;
@@ -344,7 +345,9 @@ pub async fn payouts_create_core(
         .await?;
     }
 
-    if let Some(true) = payout_data.payouts.confirm {
+    if payout_data.payouts.scheduled_at.is_some() {
+        schedule_payout(&state, &merchant_account, &key_store, &mut payout_data).await?
+    } else if let Some(true) = payout_data.payouts.confirm {
         payouts_core(
             &state,
             &merchant_account,
@@ -2198,6 +2201,8 @@ pub async fn response_handler(
         created: Some(payouts.created_at),
         connector_transaction_id: payout_attempt.connector_payout_id,
         priority: payouts.priority,
+        scheduled_at: payouts.scheduled_at,
+        recurrence: payouts.recurrence,
         attempts: None,
         payout_link: payout_link
             .map(|payout_link| {
@@ -2318,6 +2323,13 @@ pub async fn payout_create_db_entries(
         storage_enums::PayoutStatus::RequiresPayoutMethodData
     };
 
+    // Recurring payouts without a schedule are executed right away by the scheduler
+    let scheduled_at = req.scheduled_at.or_else(|| {
+        req.recurrence
+            .as_ref()
+            .map(|_| common_utils::date_time::now())
+    });
+
     let payouts_req = storage::PayoutsNew {
         payout_id: payout_id.to_string(),
         merchant_id: merchant_id.to_owned(),
@@ -2342,6 +2354,8 @@ pub async fn payout_create_db_entries(
             .map(|link_data| link_data.link_id.clone()),
         client_secret: Some(client_secret),
         priority: req.priority,
+        scheduled_at,
+        recurrence: req.recurrence.clone(),
         status,
         created_at: common_utils::date_time::now(),
         last_modified_at: common_utils::date_time::now(),
@@ -2617,6 +2631,268 @@ pub async fn add_external_account_addition_task(
     Ok(())
 }
 
+/// Saves the payout method of a scheduled payout to the locker, as the temporary locker expires
+/// before the payout is executed, and adds the task which executes the payout at its schedule
+#[instrument(skip_all)]
+pub async fn schedule_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let scheduled_at = payout_data
+        .payouts
+        .scheduled_at
+        .get_required_value("scheduled_at")?;
+    let customer_id = payout_data
+        .payouts
+        .customer_id
+        .clone()
+        .get_required_value("customer_id")?;
+    let payout_method_data = payout_data
+        .payout_method_data
+        .clone()
+        .get_required_value("payout_method_data")?;
+
+    helpers::save_payout_data_to_locker(
+        state,
+        payout_data,
+        &customer_id,
+        &payout_method_data,
+        merchant_account,
+        key_store,
+    )
+    .await
+    .attach_printable("Failed to save payout method of scheduled payout to locker")?;
+
+    add_payout_schedule_task(&*state.store, &payout_data.payout_attempt, scheduled_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payout schedule task to process tracker")
+}
+
+/// Executes a scheduled payout with the payout method saved in the locker. The connector is chosen
+/// at this point, using the routing configured for the profile at the time of execution.
+#[instrument(skip_all)]
+pub async fn execute_scheduled_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_data: &mut PayoutData,
+) -> RouterResult<()> {
+    let customer_id = payout_data
+        .payouts
+        .customer_id
+        .clone()
+        .get_required_value("customer_id")?;
+    let payout_method_id = payout_data
+        .payouts
+        .payout_method_id
+        .clone()
+        .get_required_value("payout_method_id")?;
+
+    payout_data.payout_method_data = Some(
+        helpers::get_payout_method_data_from_locker(
+            state,
+            key_store,
+            &customer_id,
+            merchant_account.get_id(),
+            &payout_method_id,
+            payout_data.payouts.payout_type,
+        )
+        .await?,
+    );
+
+    payouts_core(state, merchant_account, key_store, payout_data, None, None).await
+}
+
+/// Creates the next payout of a recurring payout and schedules it for execution. The recurrence
+/// ends once all of its occurrences are scheduled, or when the next payout is cancelled.
+///
+/// The next payout and its task are identified by the occurrence, so that scheduling the same
+/// occurrence again (e.g. when the task is retried) does not create a second payout.
+#[instrument(skip_all)]
+pub async fn schedule_next_recurring_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payout_data: &PayoutData,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    let payouts = &payout_data.payouts;
+    let payout_attempt = &payout_data.payout_attempt;
+
+    let Some((next_scheduled_at, next_recurrence)) = payouts
+        .recurrence
+        .as_ref()
+        .zip(payouts.scheduled_at)
+        .and_then(|(recurrence, scheduled_at)| recurrence.get_next_occurrence(scheduled_at))
+    else {
+        return Ok(());
+    };
+
+    let payout_id = get_next_recurring_payout_id(&payouts.payout_id, next_scheduled_at);
+    let payout_attempt_id = utils::get_payout_attempt_id(&payout_id, 1);
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        storage::ProcessTrackerRunner::PayoutScheduleWorkflow,
+        PAYOUT_SCHEDULE_TASK,
+        &payout_attempt_id,
+        &payouts.merchant_id,
+    );
+    let is_scheduled = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout schedule task")?
+        .is_some();
+    if is_scheduled {
+        return Ok(());
+    }
+
+    // The payout may have been created by an earlier attempt which failed to add its task
+    if let Some(next_payouts) = validator::validate_uniqueness_of_payout_id_against_merchant_id(
+        db,
+        &payout_id,
+        &payouts.merchant_id,
+        merchant_account.storage_scheme,
+    )
+    .await?
+    {
+        let next_payout_attempt = db
+            .find_payout_attempt_by_merchant_id_payout_attempt_id(
+                &next_payouts.merchant_id,
+                &payout_attempt_id,
+                merchant_account.storage_scheme,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)
+            .attach_printable("Failed to fetch payout_attempt of next recurring payout")?;
+        return add_payout_schedule_task(db, &next_payout_attempt, next_scheduled_at)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add payout schedule task to process tracker");
+    }
+
+    let status = storage_enums::PayoutStatus::RequiresCreation;
+    let client_secret = utils::generate_id(
+        consts::ID_LENGTH,
+        format!("payout_{payout_id}_secret").as_str(),
+    );
+
+    let payouts_req = storage::PayoutsNew {
+        payout_id: payout_id.clone(),
+        merchant_id: payouts.merchant_id.clone(),
+        customer_id: payouts.customer_id.clone(),
+        address_id: payouts.address_id.clone(),
+        payout_type: payouts.payout_type,
+        payout_method_id: payouts.payout_method_id.clone(),
+        amount: payouts.amount,
+        destination_currency: payouts.destination_currency,
+        source_currency: payouts.source_currency,
+        description: payouts.description.clone(),
+        recurring: payouts.recurring,
+        auto_fulfill: payouts.auto_fulfill,
+        return_url: payouts.return_url.clone(),
+        entity_type: payouts.entity_type,
+        metadata: payouts.metadata.clone(),
+        created_at: common_utils::date_time::now(),
+        last_modified_at: common_utils::date_time::now(),
+        attempt_count: 1,
+        profile_id: payouts.profile_id.clone(),
+        status,
+        confirm: Some(true),
+        payout_link_id: None,
+        client_secret: Some(client_secret),
+        priority: payouts.priority,
+        scheduled_at: Some(next_scheduled_at),
+        recurrence: Some(next_recurrence),
+    };
+    let next_payouts = db
+        .insert_payout(payouts_req, merchant_account.storage_scheme)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout {
+            payout_id: payout_id.clone(),
+        })
+        .attach_printable("Error inserting next recurring payout in db")?;
+
+    let payout_attempt_req = storage::PayoutAttemptNew {
+        payout_attempt_id,
+        payout_id: payout_id.clone(),
+        merchant_id: payouts.merchant_id.clone(),
+        status,
+        business_country: payout_attempt.business_country,
+        business_label: payout_attempt.business_label.clone(),
+        payout_token: None,
+        profile_id: payouts.profile_id.clone(),
+        customer_id: payouts.customer_id.clone(),
+        address_id: payouts.address_id.clone(),
+        connector: None,
+        connector_payout_id: None,
+        is_eligible: None,
+        error_message: None,
+        error_code: None,
+        created_at: common_utils::date_time::now(),
+        last_modified_at: common_utils::date_time::now(),
+        merchant_connector_id: None,
+        routing_info: None,
+    };
+    let next_payout_attempt = db
+        .insert_payout_attempt(
+            payout_attempt_req,
+            &next_payouts,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicatePayout { payout_id })
+        .attach_printable("Error inserting payout_attempt of next recurring payout in db")?;
+
+    add_payout_schedule_task(db, &next_payout_attempt, next_scheduled_at)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payout schedule task to process tracker")
+}
+
+/// Payout id of the occurrence of a recurring payout at `scheduled_at`, derived from the payout
+/// of the previous occurrence
+fn get_next_recurring_payout_id(payout_id: &str, scheduled_at: time::PrimitiveDateTime) -> String {
+    let occurrence = format!("{payout_id}_{}", scheduled_at.assume_utc().unix_timestamp());
+    blake3::hash(occurrence.as_bytes()).to_hex()[..32].to_string()
+}
+
+const PAYOUT_SCHEDULE_TASK: &str = "EXECUTE_SCHEDULED_PAYOUT";
+
+pub async fn add_payout_schedule_task(
+    db: &dyn StorageInterface,
+    payout_attempt: &storage::PayoutAttempt,
+    schedule_time: time::PrimitiveDateTime,
+) -> CustomResult<(), errors::StorageError> {
+    let runner = storage::ProcessTrackerRunner::PayoutScheduleWorkflow;
+    let task = PAYOUT_SCHEDULE_TASK;
+    let tag = ["PAYOUTS", "SCHEDULE"];
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        task,
+        &payout_attempt.payout_attempt_id,
+        &payout_attempt.merchant_id,
+    );
+    let tracking_data = api::PayoutRetrieveRequest {
+        payout_id: payout_attempt.payout_id.to_owned(),
+        force_sync: None,
+        merchant_id: Some(payout_attempt.merchant_id.to_owned()),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)?;
+
+    db.insert_process(process_tracker_entry).await?;
+    Ok(())
+}
+
 async fn validate_and_get_business_profile(
     state: &SessionState,
     merchant_key_store: &domain::MerchantKeyStore,
@@ -0,0 +1,552 @@
+use std::collections::HashMap;
+
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+
+use super::{payouts_create_core, validator};
+use crate::{
+    consts,
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        files::helpers as file_helpers,
+        utils as core_utils,
+    },
+    routes::SessionState,
+    services::ApplicationResponse,
+    types::{
+        api::{self, payouts},
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils::{self, OptionExt},
+};
+
+type PayoutBatchRow = Result<payouts::PayoutCreateRequest, errors::ApiErrorResponse>;
+
+#[instrument(skip_all)]
+pub async fn payout_batch_create_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: payouts::PayoutBatchCreateRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    #[cfg(feature = "v1")]
+    let profile_id = core_utils::get_profile_id_from_business_details(
+        &(&state).into(),
+        &key_store,
+        None,
+        None,
+        &merchant_account,
+        req.profile_id.as_ref(),
+        db,
+        true,
+    )
+    .await?;
+
+    #[cfg(feature = "v2")]
+    // Profile id will be mandatory in v2 in the request / headers
+    let profile_id =
+        req.profile_id
+            .clone()
+            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "profile_id",
+            })?;
+
+    let (file_format, rows) =
+        get_payout_batch_rows(&state, &merchant_account, &key_store, &req.file_id).await?;
+    let total_count = i32::try_from(rows.len())
+        .change_context(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "too many rows in the payout batch file".to_string(),
+        })
+        .attach_printable("Failed to convert payout batch row count to i32")?;
+    utils::when(total_count == 0, || {
+        Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "payout batch file does not contain any rows".to_string(),
+        })
+    })?;
+
+    let batch_id = utils::generate_id(consts::ID_LENGTH, "batch");
+    let now = common_utils::date_time::now();
+    let payout_batch_new = storage::PayoutBatchNew {
+        batch_id: batch_id.clone(),
+        merchant_id: merchant_id.to_owned(),
+        profile_id,
+        file_id: req.file_id,
+        file_format,
+        status: storage_enums::PayoutBatchStatus::Pending,
+        confirm: req.confirm.unwrap_or(true),
+        auto_fulfill: req.auto_fulfill.unwrap_or(true),
+        total_count,
+        processed_count: 0,
+        failed_count: 0,
+        created_at: now,
+        modified_at: now,
+    };
+    let payout_batch = db
+        .insert_payout_batch(payout_batch_new)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::GenericDuplicateError {
+            message: format!("Payout batch with id {batch_id} already exists"),
+        })?;
+
+    add_payout_batch_task(&state, &payout_batch).await?;
+
+    Ok(ApplicationResponse::Json(
+        get_payout_batch_response(&state, payout_batch).await?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_retrieve_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: payouts::PayoutBatchRetrieveRequest,
+) -> RouterResponse<payouts::PayoutBatchResponse> {
+    let payout_batch = find_payout_batch(
+        &state,
+        &merchant_account,
+        profile_id.as_ref(),
+        &req.batch_id,
+    )
+    .await?;
+
+    Ok(ApplicationResponse::Json(
+        get_payout_batch_response(&state, payout_batch).await?,
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn payout_batch_report_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    req: payouts::PayoutBatchRetrieveRequest,
+) -> RouterResponse<serde_json::Value> {
+    let payout_batch = find_payout_batch(
+        &state,
+        &merchant_account,
+        profile_id.as_ref(),
+        &req.batch_id,
+    )
+    .await?;
+
+    let payout_batch_items = state
+        .store
+        .find_payout_batch_items_with_payout_status(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?;
+
+    let mut csv_writer = csv::Writer::from_writer(Vec::new());
+    for (item, status) in payout_batch_items {
+        csv_writer
+            .serialize(payouts::PayoutBatchReportRecord {
+                row_number: item.row_number,
+                payout_id: item.payout_id,
+                status,
+                error_code: item.error_code,
+                error_message: item.error_message,
+            })
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to write payout batch report record")?;
+    }
+    let report = csv_writer
+        .into_inner()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to generate payout batch report")?;
+
+    Ok(ApplicationResponse::FileData((report, mime::TEXT_CSV)))
+}
+
+/// Creates payouts for all remaining rows of the payout batch, updating the batch progress after
+/// every chunk of rows. The batch file is downloaded and parsed once per run.
+#[instrument(skip_all)]
+pub async fn process_payout_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mut payout_batch: storage::PayoutBatch,
+) -> RouterResult<storage::PayoutBatch> {
+    let (_, rows) =
+        get_payout_batch_rows(state, merchant_account, key_store, &payout_batch.file_id).await?;
+    let mut rows = rows
+        .into_iter()
+        .skip(usize::try_from(payout_batch.processed_count).unwrap_or_default());
+
+    while matches!(
+        payout_batch.status,
+        storage_enums::PayoutBatchStatus::Pending | storage_enums::PayoutBatchStatus::Processing
+    ) {
+        let chunk_size = usize::try_from(consts::PAYOUT_BATCH_CHUNK_SIZE).unwrap_or_default();
+        let chunk = rows.by_ref().take(chunk_size).collect();
+        payout_batch =
+            process_payout_batch_chunk(state, merchant_account, key_store, payout_batch, chunk)
+                .await?;
+    }
+    Ok(payout_batch)
+}
+
+/// Creates payouts for the next chunk of rows of the payout batch and updates the batch progress.
+/// Rows whose payout was created or which failed are skipped, and rows whose payout creation was
+/// interrupted are retried, which makes re-running a partially processed chunk safe.
+async fn process_payout_batch_chunk(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_batch: storage::PayoutBatch,
+    rows: Vec<PayoutBatchRow>,
+) -> RouterResult<storage::PayoutBatch> {
+    let db = &*state.store;
+    let start_row_number = payout_batch.processed_count + 1;
+    let end_row_number = std::cmp::min(
+        start_row_number + consts::PAYOUT_BATCH_CHUNK_SIZE,
+        payout_batch.total_count + 1,
+    );
+    let mut recorded_items = db
+        .find_payout_batch_items_by_merchant_id_batch_id_row_number_range(
+            &payout_batch.merchant_id,
+            &payout_batch.batch_id,
+            start_row_number,
+            end_row_number,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout batch items")?
+        .into_iter()
+        .map(|item| (item.row_number, item))
+        .collect::<HashMap<_, _>>();
+
+    let mut failed_count = payout_batch.failed_count;
+    for (row_number, row) in (start_row_number..end_row_number).zip(rows) {
+        let recorded_item = recorded_items.remove(&row_number);
+        let item = match recorded_item {
+            Some(item) if item.payout_id.is_some() || item.error_code.is_some() => item,
+            recorded_item => {
+                process_payout_batch_row(
+                    state,
+                    merchant_account,
+                    key_store,
+                    &payout_batch,
+                    row_number,
+                    row,
+                    recorded_item,
+                )
+                .await?
+            }
+        };
+        if item.error_code.is_some() {
+            failed_count += 1;
+        }
+    }
+
+    let status = if end_row_number > payout_batch.total_count {
+        storage_enums::PayoutBatchStatus::Completed
+    } else {
+        storage_enums::PayoutBatchStatus::Processing
+    };
+    let batch_id = payout_batch.batch_id.clone();
+    db.update_payout_batch(
+        payout_batch,
+        storage::PayoutBatchUpdate::ProgressUpdate {
+            status,
+            processed_count: end_row_number - 1,
+            failed_count,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to update payout batch: {batch_id}"))
+}
+
+/// Payout id used for rows which do not specify one, derived from the row so that retrying an
+/// interrupted row does not create a second payout
+fn get_payout_batch_row_payout_id(batch_id: &str, row_number: i32) -> String {
+    format!("{batch_id}_{row_number}")
+}
+
+async fn process_payout_batch_row(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payout_batch: &storage::PayoutBatch,
+    row_number: i32,
+    row: PayoutBatchRow,
+    recorded_item: Option<storage::PayoutBatchItem>,
+) -> RouterResult<storage::PayoutBatchItem> {
+    let db = &*state.store;
+    let row = row.and_then(|mut req| {
+        validator::validate_payout_batch_row(&req)?;
+        req.payout_id =
+            Some(req.payout_id.clone().unwrap_or_else(|| {
+                get_payout_batch_row_payout_id(&payout_batch.batch_id, row_number)
+            }));
+        req.merchant_id = None;
+        req.profile_id = Some(payout_batch.profile_id.clone());
+        req.confirm = Some(payout_batch.confirm);
+        req.auto_fulfill = Some(payout_batch.auto_fulfill);
+        Ok(req)
+    });
+    let (req, error) = match row {
+        Ok(req) => (Some(req), None),
+        Err(error) => (None, Some(core_utils::get_error_code_and_message(&error))),
+    };
+
+    // The item is recorded before creating the payout, and the payout id is only stored against
+    // it once the payout was created for the row
+    let item = match recorded_item {
+        Some(item) => item,
+        None => db
+            .insert_payout_batch_item(storage::PayoutBatchItemNew {
+                merchant_id: payout_batch.merchant_id.clone(),
+                batch_id: payout_batch.batch_id.clone(),
+                row_number,
+                payout_id: None,
+                error_code: error.as_ref().map(|(code, _)| code.clone()),
+                error_message: error.map(|(_, message)| message),
+                created_at: common_utils::date_time::now(),
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to insert item {row_number} of payout batch: {}",
+                    payout_batch.batch_id
+                )
+            })?,
+    };
+
+    let Some(req) = req else {
+        return Ok(item);
+    };
+    let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
+    let payout_response = payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        req,
+        common_utils::consts::DEFAULT_LOCALE,
+    )
+    .await;
+
+    // A payout with the id of the row which was created after the item was recorded belongs to
+    // the row, it may have been created by an earlier run which was interrupted or before the
+    // payout creation failed
+    let row_payout = db
+        .find_optional_payout_by_merchant_id_payout_id(
+            &payout_batch.merchant_id,
+            &payout_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout of payout batch item")?
+        .filter(|payout| payout.created_at >= item.created_at);
+
+    let item_update = match payout_response {
+        Ok(_) => storage::PayoutBatchItemUpdate::ResultUpdate {
+            payout_id: Some(payout_id),
+            error_code: None,
+            error_message: None,
+        },
+        Err(error)
+            if row_payout.is_some()
+                && matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::DuplicatePayout { .. }
+                ) =>
+        {
+            storage::PayoutBatchItemUpdate::ResultUpdate {
+                payout_id: Some(payout_id),
+                error_code: None,
+                error_message: None,
+            }
+        }
+        Err(error) => {
+            logger::warn!(
+                ?error,
+                "Failed to create payout for item {row_number} of payout batch: {}",
+                payout_batch.batch_id
+            );
+            let (error_code, error_message) =
+                core_utils::get_error_code_and_message(error.current_context());
+            storage::PayoutBatchItemUpdate::ResultUpdate {
+                payout_id: row_payout.map(|payout| payout.payout_id),
+                error_code: Some(error_code),
+                error_message: Some(error_message),
+            }
+        }
+    };
+
+    db.update_payout_batch_item(item, item_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update payout batch item")
+}
+
+/// Marks a payout batch which could not be processed as failed
+pub async fn fail_payout_batch(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let db = &*state.store;
+    let payout_batch = db
+        .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| format!("Failed to fetch payout batch: {batch_id}"))?;
+    db.update_payout_batch(
+        payout_batch,
+        storage::PayoutBatchUpdate::StatusUpdate {
+            status: storage_enums::PayoutBatchStatus::Failed,
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable_lazy(|| format!("Failed to update payout batch: {batch_id}"))
+}
+
+async fn find_payout_batch(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+    batch_id: &str,
+) -> RouterResult<storage::PayoutBatch> {
+    let payout_batch = state
+        .store
+        .find_payout_batch_by_merchant_id_batch_id(merchant_account.get_id(), batch_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Payout batch with id {batch_id} does not exist"),
+        })?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id.cloned(), &payout_batch)?;
+    Ok(payout_batch)
+}
+
+async fn get_payout_batch_response(
+    state: &SessionState,
+    payout_batch: storage::PayoutBatch,
+) -> RouterResult<payouts::PayoutBatchResponse> {
+    let status_with_count = state
+        .store
+        .get_payout_batch_status_with_count(&payout_batch.merchant_id, &payout_batch.batch_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch payout status counts of payout batch")?
+        .into_iter()
+        .collect::<HashMap<_, _>>();
+
+    Ok(payouts::PayoutBatchResponse {
+        batch_id: payout_batch.batch_id,
+        merchant_id: payout_batch.merchant_id,
+        profile_id: payout_batch.profile_id,
+        file_id: payout_batch.file_id,
+        file_format: payout_batch.file_format,
+        status: payout_batch.status,
+        total_count: payout_batch.total_count,
+        processed_count: payout_batch.processed_count,
+        failed_count: payout_batch.failed_count,
+        status_with_count,
+        created_at: payout_batch.created_at,
+        modified_at: payout_batch.modified_at,
+    })
+}
+
+async fn get_payout_batch_rows(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    file_id: &str,
+) -> RouterResult<(storage_enums::PayoutBatchFileFormat, Vec<PayoutBatchRow>)> {
+    let file_info = file_helpers::retrieve_file_and_provider_file_id_from_file_id(
+        state,
+        Some(file_id.to_string()),
+        merchant_account,
+        key_store,
+        api::FileDataRequired::Required,
+    )
+    .await?;
+    let file_format = match file_info.file_type.as_deref() {
+        Some("text/csv") => storage_enums::PayoutBatchFileFormat::Csv,
+        Some("application/json") => storage_enums::PayoutBatchFileFormat::Json,
+        _ => Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "payout batch file must be in CSV or JSON format".to_string(),
+        })?,
+    };
+    let file_data = file_info
+        .file_data
+        .ok_or(errors::ApiErrorResponse::FileNotAvailable)
+        .attach_printable("Payout batch file data not found")?;
+
+    let rows = match file_format {
+        storage_enums::PayoutBatchFileFormat::Csv => csv::Reader::from_reader(file_data.as_slice())
+            .deserialize::<payouts::PayoutBatchRecord>()
+            .map(|record| {
+                record
+                    .map(payouts::PayoutCreateRequest::from)
+                    .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+                        message: error.to_string(),
+                    })
+            })
+            .collect(),
+        storage_enums::PayoutBatchFileFormat::Json => {
+            serde_json::from_slice::<Vec<serde_json::Value>>(&file_data)
+                .change_context(errors::ApiErrorResponse::FileValidationFailed {
+                    reason: "payout batch file must contain a JSON array of payouts".to_string(),
+                })?
+                .into_iter()
+                .map(|row| {
+                    serde_json::from_value::<payouts::PayoutCreateRequest>(row).map_err(|error| {
+                        errors::ApiErrorResponse::InvalidRequestData {
+                            message: error.to_string(),
+                        }
+                    })
+                })
+                .collect()
+        }
+    };
+    Ok((file_format, rows))
+}
+
+async fn add_payout_batch_task(
+    state: &SessionState,
+    payout_batch: &storage::PayoutBatch,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::PayoutBatchWorkflow;
+    let task = "PAYOUT_BATCH";
+    let tag = ["PAYOUTS", "BATCH"];
+    let process_tracker_id = format!("{runner}_{task}_{}", payout_batch.batch_id);
+    let tracking_data = storage::PayoutBatchTrackingData {
+        batch_id: payout_batch.batch_id.clone(),
+        merchant_id: payout_batch.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        task,
+        runner,
+        tag,
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payout batch process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting task in process_tracker: batch_id: {}",
+                payout_batch.batch_id
+            )
+        })
+}
@@ -193,6 +193,50 @@ pub async fn make_payout_method_data<'a>(
     }
 }
 
+/// Fetches the payout method saved in the locker against `payout_method_id`, for executing a payout
+/// after its payout method has expired from the temporary locker
+pub async fn get_payout_method_data_from_locker(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &id_type::CustomerId,
+    merchant_id: &id_type::MerchantId,
+    payout_method_id: &str,
+    payout_type: Option<api_enums::PayoutType>,
+) -> RouterResult<api::PayoutMethodData> {
+    match payout_type.get_required_value("payout_type")? {
+        api_enums::PayoutType::Card => {
+            let card =
+                cards::get_card_from_locker(state, customer_id, merchant_id, payout_method_id)
+                    .await
+                    .attach_printable("Payout method [card] could not be fetched from HS locker")?;
+            Ok(api::PayoutMethodData::Card(api::CardPayout {
+                card_number: card.card_number,
+                expiry_month: card.card_exp_month,
+                expiry_year: card.card_exp_year,
+                card_holder_name: card.name_on_card,
+            }))
+        }
+        api_enums::PayoutType::Bank | api_enums::PayoutType::Wallet => {
+            cards::get_payment_method_from_hs_locker(
+                state,
+                key_store,
+                customer_id,
+                merchant_id,
+                payout_method_id,
+                None,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Payout method could not be fetched from HS locker")?
+            .peek()
+            .to_string()
+            .parse_struct("PayoutMethodData")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse payout method data fetched from HS locker")
+        }
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -511,6 +555,12 @@ pub async fn save_payout_data_to_locker(
     // Insert new entry in payment_methods table
     if should_insert_in_pm_table {
         let payment_method_id = common_utils::generate_id(consts::ID_LENGTH, "pm");
+        let fingerprint_id = cards::generate_saved_card_fingerprint(
+            state,
+            merchant_account.get_id(),
+            new_payment_method.card.as_ref(),
+        )
+        .await;
         cards::create_payment_method(
             state,
             &new_payment_method,
@@ -528,6 +578,7 @@ pub async fn save_payout_data_to_locker(
             merchant_account.storage_scheme,
             None,
             None,
+            fingerprint_id,
         )
         .await?;
     }
@@ -709,6 +760,8 @@ pub(super) async fn get_or_create_customer_details(
                     default_payment_method_id: None,
                     updated_by: None,
                     version: hyperswitch_domain_models::consts::API_VERSION,
+                    tax_ids: None,
+                    segments: None,
                 };
 
                 Ok(Some(
@@ -878,6 +931,7 @@ pub async fn decide_payout_connector(
         key_store,
         TransactionData::<()>::Payout(payout_data),
         routing_data,
+        &None,
         eligible_connectors,
         None,
     )
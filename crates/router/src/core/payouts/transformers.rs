@@ -86,6 +86,8 @@ impl
             created: Some(payout.created_at),
             connector_transaction_id: attempt.connector_transaction_id.clone(),
             priority: payout.priority,
+            scheduled_at: payout.scheduled_at,
+            recurrence: payout.recurrence,
             attempts: Some(vec![attempt]),
             billing: None,
             client_secret: None,
@@ -137,6 +137,10 @@ pub async fn validate_create_request(
         None
     };
 
+    if req.scheduled_at.is_some() || req.recurrence.is_some() {
+        validate_payout_schedule_request(req, customer.as_ref())?;
+    }
+
     // payout_token
     let payout_method_data = match (req.payout_token.as_ref(), customer.as_ref()) {
         (Some(_), None) => Err(report!(errors::ApiErrorResponse::MissingRequiredField {
@@ -203,6 +207,99 @@ pub fn validate_payout_link_request(
     Ok(())
 }
 
+/// Validates a scheduled or recurring payout on below checks
+/// - payout is confirmed and the schedule is in the future
+/// - customer and payout method are passed, as the payout method is saved for the execution
+/// - connector and routing are not passed, as the connector is chosen at the time of execution
+pub fn validate_payout_schedule_request(
+    req: &payouts::PayoutCreateRequest,
+    customer: Option<&domain::Customer>,
+) -> Result<(), errors::ApiErrorResponse> {
+    utils::when(!req.confirm.unwrap_or(false), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "confirm must be true for scheduled payouts".to_string(),
+        })
+    })?;
+
+    utils::when(
+        req.scheduled_at
+            .is_some_and(|scheduled_at| scheduled_at <= common_utils::date_time::now()),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "scheduled_at must be a time in the future".to_string(),
+            })
+        },
+    )?;
+
+    utils::when(req.payout_link.unwrap_or(false), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout links cannot be created for scheduled payouts".to_string(),
+        })
+    })?;
+
+    utils::when(customer.is_none(), || {
+        Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "customer or customer_id when scheduled_at or recurrence is provided",
+        })
+    })?;
+
+    utils::when(
+        req.payout_method_data.is_none() && req.payout_token.is_none(),
+        || {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name:
+                    "payout_method_data or payout_token when scheduled_at or recurrence is provided",
+            })
+        },
+    )?;
+
+    utils::when(req.payout_type.is_none(), || {
+        Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "payout_type when scheduled_at or recurrence is provided",
+        })
+    })?;
+
+    utils::when(req.connector.is_some() || req.routing.is_some(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "connector and routing cannot be passed for scheduled payouts, the connector is chosen when the payout is executed".to_string(),
+        })
+    })?;
+
+    Ok(())
+}
+
+/// Validates a single row of a payout batch file on below checks, before the payout is created
+/// - amount and currency are passed and the amount is positive
+/// - payout link is not requested, as batch payouts are created without customer interaction
+pub fn validate_payout_batch_row(
+    req: &payouts::PayoutCreateRequest,
+) -> Result<(), errors::ApiErrorResponse> {
+    let amount = req.amount.map(common_utils::types::MinorUnit::from).ok_or(
+        errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "amount",
+        },
+    )?;
+    utils::when(amount.get_amount_as_i64() <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidDataValue {
+            field_name: "amount",
+        })
+    })?;
+
+    utils::when(req.currency.is_none(), || {
+        Err(errors::ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        })
+    })?;
+
+    utils::when(req.payout_link.unwrap_or(false), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "payout links cannot be created for batch payouts".to_string(),
+        })
+    })?;
+
+    Ok(())
+}
+
 #[cfg(feature = "olap")]
 pub(super) fn validate_payout_list_request(
     req: &payouts::PayoutListConstraints,
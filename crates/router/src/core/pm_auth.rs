@@ -7,6 +7,7 @@ use api_models::{
 use common_enums::{enums::MerchantStorageScheme, PaymentMethodType};
 use hex;
 pub mod helpers;
+pub mod payment_initiation;
 pub mod transformers;
 
 use common_utils::{
@@ -25,7 +26,10 @@ use pm_auth::{
     types::{
         self as pm_auth_types,
         api::{
-            auth_service::{BankAccountCredentials, ExchangeToken, LinkToken},
+            auth_service::{
+                AccountOwnership, BankAccountBalance, BankAccountCredentials, ExchangeToken,
+                LinkToken,
+            },
             BoxedConnectorIntegration, PaymentAuthConnectorData,
         },
     },
@@ -506,6 +510,9 @@ async fn store_bank_details_in_payment_methods(
                 client_secret: None,
                 payment_method_billing_address: None,
                 updated_by: None,
+                health_status: None,
+                consecutive_mit_declines: None,
+                fingerprint_id: None,
             };
 
             new_entries.push(pm_new);
@@ -611,6 +618,124 @@ pub async fn get_bank_account_creds(
     Ok(bank_account_details_resp)
 }
 
+pub async fn get_bank_account_balance(
+    connector: PaymentAuthConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    access_token: &Secret<String>,
+    auth_type: pm_auth_types::ConnectorAuthType,
+    state: &SessionState,
+    bank_account_id: Secret<String>,
+) -> RouterResult<pm_auth_types::BankAccountBalanceResponse> {
+    let connector_integration_balance: BoxedConnectorIntegration<
+        '_,
+        BankAccountBalance,
+        pm_auth_types::BankAccountBalanceRequest,
+        pm_auth_types::BankAccountBalanceResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data_balance = pm_auth_types::BankAccountBalanceRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(connector_name.to_string()),
+        request: pm_auth_types::BankAccountBalanceRequest {
+            access_token: access_token.clone(),
+            account_id: bank_account_id.clone(),
+        },
+        response: Ok(pm_auth_types::BankAccountBalanceResponse {
+            account_id: bank_account_id,
+            available_balance: None,
+            current_balance: None,
+            currency: None,
+        }),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let balance_resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration_balance,
+        &router_data_balance,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling bank account balance connector api")?;
+
+    let bank_account_balance_resp =
+        balance_resp
+            .response
+            .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.connector_name.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    Ok(bank_account_balance_resp)
+}
+
+#[allow(clippy::too_many_arguments)]
+pub async fn get_account_ownership(
+    connector: PaymentAuthConnectorData,
+    merchant_account: &domain::MerchantAccount,
+    connector_name: &str,
+    access_token: &Secret<String>,
+    auth_type: pm_auth_types::ConnectorAuthType,
+    state: &SessionState,
+    bank_account_id: Secret<String>,
+    legal_name: Secret<String>,
+) -> RouterResult<pm_auth_types::AccountOwnershipResponse> {
+    let connector_integration_ownership: BoxedConnectorIntegration<
+        '_,
+        AccountOwnership,
+        pm_auth_types::AccountOwnershipRequest,
+        pm_auth_types::AccountOwnershipResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data_ownership = pm_auth_types::AccountOwnershipRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(connector_name.to_string()),
+        request: pm_auth_types::AccountOwnershipRequest {
+            access_token: access_token.clone(),
+            account_id: bank_account_id.clone(),
+            legal_name,
+        },
+        response: Ok(pm_auth_types::AccountOwnershipResponse {
+            account_id: bank_account_id,
+            name_match_score: None,
+            is_name_matched: false,
+        }),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let ownership_resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration_ownership,
+        &router_data_ownership,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling account ownership connector api")?;
+
+    let account_ownership_resp =
+        ownership_resp
+            .response
+            .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+                code: err.code,
+                message: err.message,
+                connector: connector.connector_name.to_string(),
+                status_code: err.status_code,
+                reason: err.reason,
+            })?;
+
+    Ok(account_ownership_resp)
+}
+
 async fn get_access_token_from_exchange_api(
     connector: &PaymentAuthConnectorData,
     connector_name: &str,
@@ -717,7 +842,7 @@ pub async fn retrieve_payment_method_from_auth_service(
     key_store: &domain::MerchantKeyStore,
     auth_token: &payment_methods::BankAccountTokenData,
     payment_intent: &PaymentIntent,
-    _customer: &Option<domain::Customer>,
+    customer: &Option<domain::Customer>,
 ) -> RouterResult<Option<(domain::PaymentMethodData, enums::PaymentMethod)>> {
     let db = state.store.as_ref();
 
@@ -768,11 +893,11 @@ pub async fn retrieve_payment_method_from_auth_service(
         &auth_token.connector_details.access_token;
 
     let bank_account_creds = get_bank_account_creds(
-        connector,
+        connector.clone(),
         &merchant_account,
         &auth_token.connector_details.connector,
         access_token,
-        auth_type,
+        auth_type.clone(),
         state,
         Some(auth_token.connector_details.account_id.clone()),
     )
@@ -803,6 +928,102 @@ pub async fn retrieve_payment_method_from_auth_service(
         }
     }
 
+    let profile_id = payment_intent
+        .profile_id
+        .clone()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing profile_id in payment")?;
+    let business_profile = db
+        .find_business_profile_by_profile_id(key_manager_state, key_store, &profile_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    if business_profile.is_account_ownership_check_enabled == Some(true) {
+        match customer.as_ref().and_then(|customer| customer.name.clone()) {
+            Some(customer_name) => {
+                let account_ownership = get_account_ownership(
+                    connector.clone(),
+                    &merchant_account,
+                    &auth_token.connector_details.connector,
+                    access_token,
+                    auth_type.clone(),
+                    state,
+                    auth_token.connector_details.account_id.clone(),
+                    customer_name.into_inner(),
+                )
+                .await?;
+
+                if !account_ownership.is_name_matched {
+                    logger::info!(
+                        name_match_score = ?account_ownership.name_match_score,
+                        "Bank account holder name does not match the customer name"
+                    );
+                    return Err((ApiErrorResponse::PreconditionFailed {
+                        message: "selected bank account is not owned by the customer".to_string(),
+                    })
+                    .into());
+                }
+            }
+            None => logger::warn!(
+                "Skipping account ownership check of bank account, customer name is not available"
+            ),
+        }
+    }
+
+    // The balance reported with the account credentials may be stale, the pre-debit balance check
+    // fetches the live balance to further reduce returns for non sufficient funds on ACH and SEPA
+    // debits, which are only reported days after the debit was accepted
+    let is_balance_check_required = business_profile.is_pre_debit_balance_check_enabled
+        == Some(true)
+        && matches!(
+            bank_account.account_details,
+            pm_auth_types::PaymentMethodTypeDetails::Ach(_)
+                | pm_auth_types::PaymentMethodTypeDetails::Sepa(_)
+        );
+
+    if is_balance_check_required {
+        let bank_account_balance = get_bank_account_balance(
+            connector,
+            &merchant_account,
+            &auth_token.connector_details.connector,
+            access_token,
+            auth_type,
+            state,
+            auth_token.connector_details.account_id.clone(),
+        )
+        .await?;
+
+        let balance = bank_account_balance
+            .available_balance
+            .or(bank_account_balance.current_balance);
+
+        match (balance, payment_intent.currency) {
+            (Some(balance), Some(currency))
+                if bank_account_balance
+                    .currency
+                    .map_or(true, |account_currency| account_currency == currency) =>
+            {
+                let required_conversion = util_types::FloatMajorUnitForConnector;
+                let converted_amount = required_conversion
+                    .convert_back(balance, currency)
+                    .change_context(ApiErrorResponse::InternalServerError)
+                    .attach_printable("Could not convert FloatMajorUnit to MinorUnit")?;
+
+                if converted_amount < payment_intent.amount {
+                    return Err((ApiErrorResponse::PreconditionFailed {
+                        message: "selected bank account has insufficient balance".to_string(),
+                    })
+                    .into());
+                }
+            }
+            _ => logger::warn!(
+                "Skipping pre-debit balance check of bank account, balance in the payment currency is not available"
+            ),
+        }
+    }
+
     let mut bank_type = None;
     if let Some(account_type) = bank_account.account_type.clone() {
         bank_type = common_enums::BankType::from_str(account_type.as_str())
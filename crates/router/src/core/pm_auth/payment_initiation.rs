@@ -0,0 +1,1485 @@
+use api_models::{enums, payment_methods::BankAccountAccessCreds};
+use common_utils::{
+    consts as common_consts, ext_traits::ValueExt, generate_id, id_type, types::MinorUnit,
+};
+use error_stack::ResultExt;
+use hyperswitch_domain_models::payments::{payment_attempt::PaymentAttempt, PaymentIntent};
+use masking::{ExposeInterface, PeekInterface};
+use pm_auth::types::{
+    self as pm_auth_types,
+    api::{
+        auth_service::{
+            PaymentInitiationCreate, PaymentInitiationLookup, PaymentInitiationReversal,
+            PaymentInitiationReversalSync, PaymentInitiationSync,
+        },
+        BoxedConnectorIntegration, PaymentAuthConnectorData,
+    },
+};
+use router_env::logger;
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::{
+        errors::{ApiErrorResponse, RouterResponse, RouterResult, StorageErrorExt},
+        payments::{self, helpers as oss_helpers},
+        pm_auth::{
+            get_bank_account_creds,
+            helpers::{self as pm_auth_helpers, PaymentAuthConnectorDataExt},
+        },
+        webhooks,
+    },
+    routes::SessionState,
+    services::{self, pm_auth as pm_auth_services, ApplicationResponse},
+    types::{self, api, domain, storage, transformers::ForeignFrom},
+};
+
+const PAY_BY_BANK_REVERSAL_SYNC_TASK: &str = "PAY_BY_BANK_REVERSAL_SYNC";
+const PAY_BY_BANK_PAYMENT_SYNC_TASK: &str = "PAY_BY_BANK_PAYMENT_SYNC";
+
+/// The data the pay by bank reversal sync workflow operates on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayByBankReversalSyncTrackingData {
+    pub refund_id: String,
+    pub merchant_id: id_type::MerchantId,
+}
+
+/// The data the pay by bank payment sync workflow operates on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PayByBankPaymentSyncTrackingData {
+    pub payment_id: id_type::PaymentId,
+    pub merchant_id: id_type::MerchantId,
+}
+
+pub async fn initiate_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payload: api_models::pm_auth::PaymentInitiationCreateRequest,
+) -> RouterResponse<api_models::pm_auth::PaymentInitiationResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payload.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    oss_helpers::authenticate_client_secret(payload.client_secret.as_ref(), &payment_intent)?;
+
+    oss_helpers::validate_payment_status_against_allowed_statuses(
+        &payment_intent.status,
+        &[
+            enums::IntentStatus::RequiresPaymentMethod,
+            enums::IntentStatus::RequiresConfirmation,
+        ],
+        "initiate a bank payment for",
+    )?;
+
+    let currency = payment_intent
+        .currency
+        .ok_or(ApiErrorResponse::MissingRequiredField {
+            field_name: "currency",
+        })?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    let token_data = oss_helpers::retrieve_payment_token_data(
+        &state,
+        payload.payment_token.clone(),
+        Some(enums::PaymentMethod::BankDebit),
+    )
+    .await?;
+
+    let storage::PaymentTokenData::AuthBankDebit(bank_account_token) = token_data else {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: "payment_token does not belong to a verified bank account".to_string(),
+        }
+        .into());
+    };
+    let connector_details = bank_account_token.connector_details;
+    let connector_name = connector_details.connector.as_str();
+
+    let connector = PaymentAuthConnectorData::get_connector_by_name(connector_name)?;
+
+    let mca = get_merchant_connector_account(
+        &state,
+        &merchant_account,
+        &key_store,
+        &connector_details.mca_id,
+    )
+    .await?;
+    let recipient_id = get_connector_recipient_id(&mca)?;
+    let auth_type = pm_auth_helpers::get_connector_auth_type(mca)?;
+
+    let BankAccountAccessCreds::AccessToken(access_token) = &connector_details.access_token;
+
+    let bank_account_creds = get_bank_account_creds(
+        connector.clone(),
+        &merchant_account,
+        connector_name,
+        access_token,
+        auth_type.clone(),
+        &state,
+        Some(connector_details.account_id.clone()),
+    )
+    .await?;
+
+    let payer_account = bank_account_creds
+        .credentials
+        .into_iter()
+        .find(|account| {
+            account.payment_method_type == bank_account_token.payment_method_type
+                && account.payment_method == bank_account_token.payment_method
+        })
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("Bank account details not found")?;
+
+    let payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::ConfirmUpdate {
+                amount: payment_attempt.amount,
+                currency,
+                status: enums::AttemptStatus::Pending,
+                authentication_type: payment_attempt.authentication_type,
+                capture_method: payment_attempt.capture_method,
+                payment_method: Some(enums::PaymentMethod::OpenBanking),
+                browser_info: None,
+                connector: Some(connector_name.to_string()),
+                payment_token: Some(payload.payment_token),
+                payment_method_data: None,
+                payment_method_type: Some(enums::PaymentMethodType::PayByBank),
+                payment_experience: None,
+                business_sub_label: None,
+                straight_through_algorithm: None,
+                error_code: Some(None),
+                error_message: Some(None),
+                amount_capturable: None,
+                updated_by: storage_scheme.to_string(),
+                surcharge_amount: None,
+                tax_amount: None,
+                merchant_connector_id: Some(connector_details.mca_id.clone()),
+                external_three_ds_authentication_attempted: None,
+                authentication_connector: None,
+                authentication_id: None,
+                payment_method_billing_address_id: None,
+                fingerprint_id: None,
+                payment_method_id: None,
+                client_source: None,
+                client_version: None,
+                customer_acceptance: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    // The payment is moved out of the initiable statuses before calling the connector, so that a
+    // concurrent or repeated request cannot initiate a second payment at the bank
+    let payment_intent = db
+        .update_payment_intent(
+            &(&state).into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Processing,
+                incremental_authorization_allowed: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        PaymentInitiationCreate,
+        pm_auth_types::PaymentInitiationCreateRequest,
+        pm_auth_types::PaymentInitiationResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::PaymentInitiationCreateRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(connector_name.to_string()),
+        request: pm_auth_types::PaymentInitiationCreateRequest {
+            recipient_id,
+            reference: payment_intent.payment_id.get_string_repr().to_owned(),
+            amount: payment_attempt.amount,
+            currency,
+            payer_account: Some(payer_account.account_details),
+        },
+        response: Err(get_unsupported_error_response()),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let initiation_result = pm_auth_services::execute_connector_processing_step(
+        &state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .map(|resp| resp.response);
+
+    let response = match initiation_result {
+        Ok(Ok(initiation_resp)) => {
+            update_trackers_with_connector_status(
+                &state,
+                &merchant_account,
+                &key_store,
+                payment_intent,
+                payment_attempt,
+                initiation_resp,
+            )
+            .await?
+        }
+        // The bank may have received the payment even though no response was received, the attempt
+        // is kept pending and flagged for reconciliation instead of being failed, which would let
+        // the customer initiate the payment again
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed while calling payment initiation create connector api"
+            );
+            mark_attempt_for_reconciliation(
+                &state,
+                &merchant_account,
+                &payment_intent,
+                payment_attempt,
+                connector_name,
+                None,
+            )
+            .await?
+        }
+        Ok(Err(err)) if is_unknown_initiation_outcome(&err) => {
+            mark_attempt_for_reconciliation(
+                &state,
+                &merchant_account,
+                &payment_intent,
+                payment_attempt,
+                connector_name,
+                Some(err.message),
+            )
+            .await?
+        }
+        Ok(Err(err)) => {
+            update_trackers_with_failure(
+                &state,
+                &merchant_account,
+                &key_store,
+                payment_intent,
+                payment_attempt,
+                connector_name,
+                err,
+            )
+            .await?
+        }
+    };
+
+    // The payment is synced until the connector settles it, which also reconciles a payment whose
+    // initiation outcome is unknown
+    if response.status == enums::IntentStatus::Processing {
+        add_payment_sync_task(&state, merchant_account.get_id(), &response.payment_id)
+            .await
+            .map_err(|error| logger::error!(payment_sync_process_tracker_error=?error))
+            .ok();
+    }
+
+    trigger_payment_webhook(&state, &merchant_account, &key_store, &response.payment_id)
+        .await
+        .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+        .ok();
+
+    Ok(ApplicationResponse::Json(response))
+}
+
+pub async fn sync_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payload: api_models::pm_auth::PaymentInitiationSyncRequest,
+) -> RouterResponse<api_models::pm_auth::PaymentInitiationResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payload.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    oss_helpers::authenticate_client_secret(payload.client_secret.as_ref(), &payment_intent)?;
+
+    let payment_attempt =
+        get_pay_by_bank_attempt(&state, &merchant_account, &payment_intent).await?;
+
+    // A settled payment has nothing left to sync, and without a connector payment id the payment
+    // stays processing until it is reconciled with the connector by the payment sync task
+    if is_pending_reconciliation(&payment_attempt) || is_terminal_status(payment_attempt.status) {
+        return Ok(ApplicationResponse::Json(get_stored_payment_response(
+            payment_intent,
+            payment_attempt,
+        )));
+    }
+
+    sync_payment_with_connector(
+        &state,
+        &merchant_account,
+        &key_store,
+        payment_intent,
+        payment_attempt,
+    )
+    .await
+    .map(ApplicationResponse::Json)
+}
+
+/// Syncs the status of a processing bank payment with the connector. A payment whose initiation
+/// outcome is unknown is looked up at the connector by its reference instead, and failed if the
+/// connector has not received it. Returns the status of the payment.
+pub async fn sync_processing_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<api_models::pm_auth::PaymentInitiationResponse> {
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &state.into(),
+            payment_id,
+            merchant_account.get_id(),
+            key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = get_pay_by_bank_attempt(state, merchant_account, &payment_intent).await?;
+
+    if is_pending_reconciliation(&payment_attempt) {
+        return reconcile_payment(
+            state,
+            merchant_account,
+            key_store,
+            payment_intent,
+            payment_attempt,
+        )
+        .await;
+    }
+
+    if is_terminal_status(payment_attempt.status) {
+        return Ok(get_stored_payment_response(payment_intent, payment_attempt));
+    }
+
+    sync_payment_with_connector(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent,
+        payment_attempt,
+    )
+    .await
+}
+
+/// Looks up a payment whose initiation outcome is unknown at the connector by its reference, and
+/// updates the payment with its status at the connector
+async fn reconcile_payment(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: PaymentIntent,
+    payment_attempt: PaymentAttempt,
+) -> RouterResult<api_models::pm_auth::PaymentInitiationResponse> {
+    let connector_name = payment_attempt
+        .connector
+        .clone()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("connector not found in payment attempt")?;
+
+    let connector = PaymentAuthConnectorData::get_connector_by_name(connector_name.as_str())?;
+    let auth_type =
+        get_connector_auth_type_for_attempt(state, merchant_account, key_store, &payment_attempt)
+            .await?;
+
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        PaymentInitiationLookup,
+        pm_auth_types::PaymentInitiationLookupRequest,
+        pm_auth_types::PaymentInitiationLookupResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::PaymentInitiationLookupRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(connector_name.clone()),
+        request: pm_auth_types::PaymentInitiationLookupRequest {
+            reference: payment_intent.payment_id.get_string_repr().to_owned(),
+        },
+        response: Err(get_unsupported_error_response()),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling payment initiation lookup connector api")?;
+
+    let lookup_resp = resp
+        .response
+        .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })?;
+
+    let previous_status = payment_intent.status;
+    let response = match lookup_resp.payment {
+        Some(initiation_resp) => {
+            update_trackers_with_connector_status(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                payment_attempt,
+                initiation_resp,
+            )
+            .await?
+        }
+        // The bank never received the payment, so the customer can be asked to pay again
+        None => {
+            update_trackers_with_failure(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                payment_attempt,
+                &connector_name,
+                pm_auth_types::ErrorResponse {
+                    status_code: http::StatusCode::NOT_FOUND.as_u16(),
+                    code: consts::NO_ERROR_CODE.to_string(),
+                    message: consts::PAY_BY_BANK_PAYMENT_NOT_INITIATED_MESSAGE.to_string(),
+                    reason: None,
+                },
+            )
+            .await?
+        }
+    };
+
+    if response.status != previous_status {
+        trigger_payment_webhook(state, merchant_account, key_store, &response.payment_id)
+            .await
+            .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+            .ok();
+    }
+
+    Ok(response)
+}
+
+async fn sync_payment_with_connector(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: PaymentIntent,
+    payment_attempt: PaymentAttempt,
+) -> RouterResult<api_models::pm_auth::PaymentInitiationResponse> {
+    let (connector_name, connector_payment_id) = get_connector_details(&payment_attempt)?;
+
+    let connector = PaymentAuthConnectorData::get_connector_by_name(connector_name.as_str())?;
+    let auth_type =
+        get_connector_auth_type_for_attempt(state, merchant_account, key_store, &payment_attempt)
+            .await?;
+
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        PaymentInitiationSync,
+        pm_auth_types::PaymentInitiationSyncRequest,
+        pm_auth_types::PaymentInitiationResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::PaymentInitiationSyncRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(connector_name.clone()),
+        request: pm_auth_types::PaymentInitiationSyncRequest {
+            payment_id: connector_payment_id,
+        },
+        response: Err(get_unsupported_error_response()),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling payment initiation sync connector api")?;
+
+    let sync_resp = resp
+        .response
+        .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })?;
+
+    let previous_status = payment_intent.status;
+    let response = update_trackers_with_connector_status(
+        state,
+        merchant_account,
+        key_store,
+        payment_intent,
+        payment_attempt,
+        sync_resp,
+    )
+    .await?;
+
+    if response.status != previous_status {
+        trigger_payment_webhook(state, merchant_account, key_store, &response.payment_id)
+            .await
+            .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
+            .ok();
+    }
+
+    Ok(response)
+}
+
+pub async fn reverse_payment(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payload: api_models::pm_auth::PaymentInitiationReversalRequest,
+) -> RouterResponse<api_models::pm_auth::PaymentInitiationReversalResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &payload.payment_id,
+            merchant_account.get_id(),
+            &key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt =
+        get_pay_by_bank_attempt(&state, &merchant_account, &payment_intent).await?;
+
+    if payment_attempt.status != enums::AttemptStatus::Charged {
+        return Err(ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "reverse".to_string(),
+            field_name: "status".to_string(),
+            current_value: payment_attempt.status.to_string(),
+            states: enums::AttemptStatus::Charged.to_string(),
+        }
+        .into());
+    }
+
+    let (connector_name, connector_payment_id) = get_connector_details(&payment_attempt)?;
+    let currency = payment_attempt
+        .currency
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("currency not found in payment attempt")?;
+
+    let existing_refunds = db
+        .find_refund_by_payment_id_merchant_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::RefundNotFound)?;
+
+    let reversed_amount = existing_refunds
+        .iter()
+        .filter(|refund| refund.refund_status != enums::RefundStatus::Failure)
+        .fold(MinorUnit::zero(), |total, refund| {
+            total + refund.refund_amount
+        });
+
+    let reversal_amount = payload
+        .amount
+        .unwrap_or(payment_attempt.amount - reversed_amount);
+
+    if reversal_amount <= MinorUnit::zero()
+        || reversed_amount + reversal_amount > payment_attempt.amount
+    {
+        return Err(ApiErrorResponse::RefundAmountExceedsPaymentAmount.into());
+    }
+
+    // The refund is booked before the reversal is sent, its id is the reference of the reversal at
+    // the connector so that a reversal whose outcome is unknown can be sent again without the
+    // payment being reversed twice
+    let refund_id = generate_id(common_consts::ID_LENGTH, "ref");
+
+    let refund_new = storage::RefundNew {
+        refund_id: refund_id.clone(),
+        internal_reference_id: generate_id(common_consts::ID_LENGTH, "refid"),
+        external_reference_id: Some(refund_id),
+        payment_id: payment_intent.payment_id.clone(),
+        merchant_id: merchant_account.get_id().clone(),
+        connector_transaction_id: connector_payment_id,
+        connector: connector_name.clone(),
+        connector_refund_id: None,
+        refund_type: enums::RefundType::InstantRefund,
+        total_amount: payment_attempt.amount,
+        currency,
+        refund_amount: reversal_amount,
+        refund_status: enums::RefundStatus::Pending,
+        sent_to_gateway: false,
+        metadata: None,
+        refund_arn: None,
+        created_at: common_utils::date_time::now(),
+        modified_at: common_utils::date_time::now(),
+        description: payload.reason.clone(),
+        attempt_id: payment_attempt.attempt_id.clone(),
+        refund_reason: payload.reason,
+        profile_id: payment_intent.profile_id.clone(),
+        updated_by: storage_scheme.to_string(),
+        merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+        charges: None,
+        organization_id: merchant_account.organization_id.clone(),
+        payout_id: None,
+        created_by: None,
+        daily_refund_limit_reservation: None,
+    };
+
+    let refund = db
+        .insert_refund(refund_new, storage_scheme)
+        .await
+        .to_duplicate_response(ApiErrorResponse::DuplicateRefundRequest)?;
+
+    let refund = send_reversal(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_attempt,
+        refund,
+    )
+    .await?;
+
+    if refund.refund_status == enums::RefundStatus::Pending {
+        add_reversal_sync_task(&state, &refund)
+            .await
+            .map_err(|error| logger::error!(reversal_sync_process_tracker_error=?error))
+            .ok();
+    }
+
+    trigger_refund_webhook(&state, &merchant_account, &key_store, &refund)
+        .await
+        .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+        .ok();
+
+    Ok(ApplicationResponse::Json(
+        api_models::pm_auth::PaymentInitiationReversalResponse {
+            refund_id: refund.refund_id,
+            payment_id: refund.payment_id,
+            amount: refund.refund_amount,
+            currency: refund.currency,
+            status: refund.refund_status,
+            connector: refund.connector,
+        },
+    ))
+}
+
+/// Syncs the status of a pending reversal of a bank payment with the connector, and notifies the
+/// merchant if the reversal has settled or failed. Returns the refund booked for the reversal.
+pub async fn sync_reversal(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund_id: &str,
+) -> RouterResult<storage::Refund> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(merchant_account.get_id(), refund_id, storage_scheme)
+        .await
+        .to_not_found_response(ApiErrorResponse::RefundNotFound)?;
+
+    if refund.refund_status != enums::RefundStatus::Pending {
+        return Ok(refund);
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &refund.payment_id,
+            merchant_account.get_id(),
+            &refund.attempt_id,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    // The outcome of the reversal was unknown, it is sent again with the same reference which the
+    // connector does not reverse the payment twice for
+    let Some(reversal_id) = refund.connector_refund_id.clone() else {
+        let refund =
+            send_reversal(state, merchant_account, key_store, &payment_attempt, refund).await?;
+
+        if refund.refund_status != enums::RefundStatus::Pending {
+            trigger_refund_webhook(state, merchant_account, key_store, &refund)
+                .await
+                .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+                .ok();
+        }
+
+        return Ok(refund);
+    };
+
+    let connector = PaymentAuthConnectorData::get_connector_by_name(refund.connector.as_str())?;
+    let auth_type =
+        get_connector_auth_type_for_attempt(state, merchant_account, key_store, &payment_attempt)
+            .await?;
+
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        PaymentInitiationReversalSync,
+        pm_auth_types::PaymentInitiationReversalSyncRequest,
+        pm_auth_types::PaymentInitiationReversalResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::PaymentInitiationReversalSyncRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(refund.connector.clone()),
+        request: pm_auth_types::PaymentInitiationReversalSyncRequest { reversal_id },
+        response: Err(get_unsupported_error_response()),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let resp = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while calling payment initiation reversal sync connector api")?;
+
+    let reversal_resp = resp
+        .response
+        .map_err(|err| ApiErrorResponse::ExternalConnectorError {
+            code: err.code,
+            message: err.message,
+            connector: connector.connector_name.to_string(),
+            status_code: err.status_code,
+            reason: err.reason,
+        })?;
+
+    let refund_status = enums::RefundStatus::foreign_from(reversal_resp.status);
+    if refund_status == refund.refund_status {
+        return Ok(refund);
+    }
+
+    let refund = db
+        .update_refund(
+            refund,
+            storage::RefundUpdate::StatusUpdate {
+                connector_refund_id: Some(reversal_resp.reversal_id),
+                sent_to_gateway: true,
+                refund_status,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::RefundNotFound)?;
+
+    trigger_refund_webhook(state, merchant_account, key_store, &refund)
+        .await
+        .map_err(|error| logger::warn!(refunds_outgoing_webhook_error=?error))
+        .ok();
+
+    Ok(refund)
+}
+
+/// Sends the reversal booked for a refund to the connector, with the refund id as the reference of
+/// the reversal. The refund is kept pending when the outcome of the reversal is unknown.
+async fn send_reversal(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &PaymentAttempt,
+    refund: storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let storage_scheme = merchant_account.storage_scheme;
+
+    let connector = PaymentAuthConnectorData::get_connector_by_name(refund.connector.as_str())?;
+    let auth_type =
+        get_connector_auth_type_for_attempt(state, merchant_account, key_store, payment_attempt)
+            .await?;
+
+    let connector_integration: BoxedConnectorIntegration<
+        '_,
+        PaymentInitiationReversal,
+        pm_auth_types::PaymentInitiationReversalRequest,
+        pm_auth_types::PaymentInitiationReversalResponse,
+    > = connector.connector.get_connector_integration();
+
+    let router_data = pm_auth_types::PaymentInitiationReversalRouterData {
+        flow: std::marker::PhantomData,
+        merchant_id: Some(merchant_account.get_id().clone()),
+        connector: Some(refund.connector.clone()),
+        request: pm_auth_types::PaymentInitiationReversalRequest {
+            payment_id: refund.connector_transaction_id.clone(),
+            reversal_reference: refund.refund_id.clone(),
+            amount: refund.refund_amount,
+            currency: refund.currency,
+        },
+        response: Err(get_unsupported_error_response()),
+        connector_http_status_code: None,
+        connector_auth_type: auth_type,
+    };
+
+    let reversal_result = pm_auth_services::execute_connector_processing_step(
+        state,
+        connector_integration,
+        &router_data,
+        &connector.connector_name,
+    )
+    .await
+    .map(|resp| resp.response);
+
+    let refund_update = match reversal_result {
+        Ok(Ok(reversal_resp)) => storage::RefundUpdate::StatusUpdate {
+            connector_refund_id: Some(reversal_resp.reversal_id),
+            sent_to_gateway: true,
+            refund_status: enums::RefundStatus::foreign_from(reversal_resp.status),
+            updated_by: storage_scheme.to_string(),
+        },
+        // The connector may have received the reversal even though no response was received, the
+        // refund is kept pending instead of being failed, which would let the payment be reversed
+        // again
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed while calling payment initiation reversal connector api"
+            );
+            return Ok(refund);
+        }
+        Ok(Err(err)) if is_unknown_initiation_outcome(&err) => {
+            logger::error!(error=?err, "Outcome of the payment initiation reversal is unknown");
+            return Ok(refund);
+        }
+        Ok(Err(err)) => storage::RefundUpdate::ErrorUpdate {
+            refund_status: Some(enums::RefundStatus::Failure),
+            refund_error_message: Some(err.message),
+            refund_error_code: Some(err.code),
+            updated_by: storage_scheme.to_string(),
+            connector_refund_id: None,
+        },
+    };
+
+    state
+        .store
+        .update_refund(refund, refund_update, storage_scheme)
+        .await
+        .to_not_found_response(ApiErrorResponse::RefundNotFound)
+}
+
+/// Schedules the sync of a reversal which the connector has not settled yet, or whose outcome is
+/// unknown
+async fn add_reversal_sync_task(
+    state: &SessionState,
+    refund: &storage::Refund,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::PayByBankReversalSyncWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        PAY_BY_BANK_REVERSAL_SYNC_TASK,
+        &refund.refund_id,
+        &refund.merchant_id,
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        consts::PAY_BY_BANK_REVERSAL_SYNC_DELAY_IN_SECONDS,
+    ));
+
+    let tracking_data = PayByBankReversalSyncTrackingData {
+        refund_id: refund.refund_id.clone(),
+        merchant_id: refund.merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAY_BY_BANK_REVERSAL_SYNC_TASK,
+        runner,
+        ["REFUND", "PAY_BY_BANK"],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct reversal sync task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add reversal sync task to process tracker")?;
+
+    Ok(())
+}
+
+/// Schedules the sync of a bank payment which the connector has not settled yet, or whose
+/// initiation outcome is unknown
+async fn add_payment_sync_task(
+    state: &SessionState,
+    merchant_id: &id_type::MerchantId,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<()> {
+    let runner = storage::ProcessTrackerRunner::PayByBankPaymentSyncWorkflow;
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        runner,
+        PAY_BY_BANK_PAYMENT_SYNC_TASK,
+        payment_id.get_string_repr(),
+        merchant_id,
+    );
+    let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+        consts::PAY_BY_BANK_PAYMENT_SYNC_DELAY_IN_SECONDS,
+    ));
+
+    let tracking_data = PayByBankPaymentSyncTrackingData {
+        payment_id: payment_id.clone(),
+        merchant_id: merchant_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        PAY_BY_BANK_PAYMENT_SYNC_TASK,
+        runner,
+        ["PAYMENT", "PAY_BY_BANK"],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct payment sync task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to add payment sync task to process tracker")?;
+
+    Ok(())
+}
+
+/// Keeps the attempt pending and flags it for reconciliation, used when the outcome of the
+/// initiation at the connector is unknown. The payment intent stays processing.
+async fn mark_attempt_for_reconciliation(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &PaymentIntent,
+    payment_attempt: PaymentAttempt,
+    connector_name: &str,
+    error_reason: Option<String>,
+) -> RouterResult<api_models::pm_auth::PaymentInitiationResponse> {
+    let storage_scheme = merchant_account.storage_scheme;
+
+    state
+        .store
+        .update_payment_attempt_with_attempt_id(
+            payment_attempt,
+            storage::PaymentAttemptUpdate::ErrorUpdate {
+                connector: Some(connector_name.to_string()),
+                status: enums::AttemptStatus::Pending,
+                error_code: Some(Some(consts::REQUEST_TIMEOUT_ERROR_CODE.to_string())),
+                error_message: Some(Some(
+                    consts::PAY_BY_BANK_RECONCILIATION_REQUIRED_MESSAGE.to_string(),
+                )),
+                error_reason: Some(error_reason),
+                amount_capturable: Some(MinorUnit::zero()),
+                updated_by: storage_scheme.to_string(),
+                unified_code: None,
+                unified_message: None,
+                connector_transaction_id: None,
+                payment_method_data: None,
+                authentication_type: None,
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(api_models::pm_auth::PaymentInitiationResponse {
+        payment_id: payment_intent.payment_id.clone(),
+        status: payment_intent.status,
+        connector: connector_name.to_string(),
+        connector_payment_id: None,
+        error_code: Some(consts::REQUEST_TIMEOUT_ERROR_CODE.to_string()),
+        error_message: Some(consts::PAY_BY_BANK_RECONCILIATION_REQUIRED_MESSAGE.to_string()),
+    })
+}
+
+/// Notifies the merchant of the status of a bank payment. The payment is updated outside the
+/// payments core, so the payments response is built from the stored payment without calling the
+/// connector.
+async fn trigger_payment_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_id: &id_type::PaymentId,
+) -> RouterResult<()> {
+    let request = api::PaymentsRetrieveRequest {
+        resource_id: api::PaymentIdType::PaymentIntentId(payment_id.clone()),
+        merchant_id: Some(merchant_account.get_id().clone()),
+        force_sync: false,
+        ..Default::default()
+    };
+    let payments_response = match Box::pin(payments::payments_core::<
+        api::PSync,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+    >(
+        state.clone(),
+        state.get_req_state(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payments::PaymentStatus,
+        request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Avoid,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await?
+    {
+        ApplicationResponse::Json(payments_response)
+        | ApplicationResponse::JsonWithHeaders((payments_response, _)) => payments_response,
+        _ => Err(ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payment retrieve")?,
+    };
+
+    let Some(event_type) = Option::<enums::EventType>::foreign_from(payments_response.status)
+    else {
+        return Ok(());
+    };
+
+    let profile_id = payments_response
+        .profile_id
+        .clone()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing profile_id in payment")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, &profile_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let primary_object_created_at = payments_response.created;
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Payments,
+        payment_id.get_string_repr().to_owned(),
+        enums::EventObjectType::PaymentDetails,
+        api::OutgoingWebhookContent::PaymentDetails(payments_response),
+        primary_object_created_at,
+    ))
+    .await
+}
+
+/// Notifies the merchant of the status of a reversal of a bank payment
+async fn trigger_refund_webhook(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund: &storage::Refund,
+) -> RouterResult<()> {
+    let Some(event_type) = Option::<enums::EventType>::foreign_from(refund.refund_status) else {
+        return Ok(());
+    };
+
+    let profile_id = refund
+        .profile_id
+        .clone()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing profile_id in refund")?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&state.into(), key_store, &profile_id)
+        .await
+        .to_not_found_response(ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.get_string_repr().to_owned(),
+        })?;
+
+    let refund_response = api_models::refunds::RefundResponse::foreign_from(refund.clone());
+    Box::pin(webhooks::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        event_type,
+        enums::EventClass::Refunds,
+        refund.refund_id.clone(),
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund_response),
+        Some(refund.created_at),
+    ))
+    .await
+}
+
+/// Fails the attempt and the payment with the error of the connector
+async fn update_trackers_with_failure(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: PaymentIntent,
+    payment_attempt: PaymentAttempt,
+    connector_name: &str,
+    err: pm_auth_types::ErrorResponse,
+) -> RouterResult<api_models::pm_auth::PaymentInitiationResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt,
+        storage::PaymentAttemptUpdate::ErrorUpdate {
+            connector: Some(connector_name.to_string()),
+            status: enums::AttemptStatus::Failure,
+            error_code: Some(Some(err.code.clone())),
+            error_message: Some(Some(err.message.clone())),
+            error_reason: Some(err.reason),
+            amount_capturable: Some(MinorUnit::zero()),
+            updated_by: storage_scheme.to_string(),
+            unified_code: None,
+            unified_message: None,
+            connector_transaction_id: None,
+            payment_method_data: None,
+            authentication_type: None,
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_intent = db
+        .update_payment_intent(
+            &state.into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::PGStatusUpdate {
+                status: enums::IntentStatus::Failed,
+                incremental_authorization_allowed: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(api_models::pm_auth::PaymentInitiationResponse {
+        payment_id: payment_intent.payment_id,
+        status: payment_intent.status,
+        connector: connector_name.to_string(),
+        connector_payment_id: None,
+        error_code: Some(err.code),
+        error_message: Some(err.message),
+    })
+}
+
+async fn update_trackers_with_connector_status(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: PaymentIntent,
+    payment_attempt: PaymentAttempt,
+    initiation_resp: pm_auth_types::PaymentInitiationResponse,
+) -> RouterResult<api_models::pm_auth::PaymentInitiationResponse> {
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+    let attempt_status = enums::AttemptStatus::foreign_from(initiation_resp.status);
+    let connector_name = payment_attempt.connector.clone().unwrap_or_default();
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_attempt.clone(),
+        storage::PaymentAttemptUpdate::ResponseUpdate {
+            status: attempt_status,
+            connector: None,
+            connector_transaction_id: Some(initiation_resp.payment_id.clone()),
+            authentication_type: None,
+            payment_method_id: None,
+            mandate_id: None,
+            connector_metadata: None,
+            payment_token: None,
+            error_code: None,
+            error_message: None,
+            error_reason: None,
+            connector_response_reference_id: Some(
+                payment_intent.payment_id.get_string_repr().to_owned(),
+            ),
+            amount_capturable: Some(MinorUnit::zero()),
+            updated_by: storage_scheme.to_string(),
+            authentication_data: None,
+            encoded_data: None,
+            unified_code: None,
+            unified_message: None,
+            payment_method_data: None,
+            charge_id: None,
+        },
+        storage_scheme,
+    )
+    .await
+    .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    let authorized_at = (payment_intent.authorized_at.is_none()
+        && attempt_status == enums::AttemptStatus::Authorized)
+        .then(common_utils::date_time::now);
+    let captured_at = (payment_intent.captured_at.is_none()
+        && attempt_status == enums::AttemptStatus::Charged)
+        .then(common_utils::date_time::now);
+    let payment_intent = db
+        .update_payment_intent(
+            &state.into(),
+            payment_intent,
+            storage::PaymentIntentUpdate::ResponseUpdate {
+                status: enums::IntentStatus::foreign_from(attempt_status),
+                amount_captured: (attempt_status == enums::AttemptStatus::Charged)
+                    .then_some(payment_attempt.amount),
+                return_url: None,
+                updated_by: storage_scheme.to_string(),
+                fingerprint_id: None,
+                incremental_authorization_allowed: None,
+                authorized_at,
+                captured_at,
+            },
+            key_store,
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(api_models::pm_auth::PaymentInitiationResponse {
+        payment_id: payment_intent.payment_id,
+        status: payment_intent.status,
+        connector: connector_name,
+        connector_payment_id: Some(initiation_resp.payment_id),
+        error_code: None,
+        error_message: None,
+    })
+}
+
+fn get_stored_payment_response(
+    payment_intent: PaymentIntent,
+    payment_attempt: PaymentAttempt,
+) -> api_models::pm_auth::PaymentInitiationResponse {
+    api_models::pm_auth::PaymentInitiationResponse {
+        payment_id: payment_intent.payment_id,
+        status: payment_intent.status,
+        connector: payment_attempt.connector.unwrap_or_default(),
+        connector_payment_id: payment_attempt.connector_transaction_id,
+        error_code: payment_attempt.error_code,
+        error_message: payment_attempt.error_message,
+    }
+}
+
+async fn get_pay_by_bank_attempt(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    payment_intent: &PaymentIntent,
+) -> RouterResult<PaymentAttempt> {
+    let payment_attempt = state
+        .store
+        .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+            &payment_intent.payment_id,
+            merchant_account.get_id(),
+            payment_intent.active_attempt.get_id().as_str(),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::PaymentNotFound)?;
+
+    if payment_attempt.payment_method_type != Some(enums::PaymentMethodType::PayByBank) {
+        return Err(ApiErrorResponse::InvalidRequestData {
+            message: "payment was not initiated through a verified bank account".to_string(),
+        }
+        .into());
+    }
+
+    Ok(payment_attempt)
+}
+
+fn get_connector_details(payment_attempt: &PaymentAttempt) -> RouterResult<(String, String)> {
+    let connector_name = payment_attempt
+        .connector
+        .clone()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("connector not found in payment attempt")?;
+
+    let connector_payment_id = payment_attempt.connector_transaction_id.clone().ok_or(
+        ApiErrorResponse::PaymentUnexpectedState {
+            current_flow: "bank payment".to_string(),
+            field_name: "connector_transaction_id".to_string(),
+            current_value: "None".to_string(),
+            states: "initiated".to_string(),
+        },
+    )?;
+
+    Ok((connector_name, connector_payment_id))
+}
+
+async fn get_connector_auth_type_for_attempt(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_attempt: &PaymentAttempt,
+) -> RouterResult<pm_auth_types::ConnectorAuthType> {
+    let mca_id = payment_attempt
+        .merchant_connector_id
+        .as_ref()
+        .ok_or(ApiErrorResponse::InternalServerError)
+        .attach_printable("merchant_connector_id not found in payment attempt")?;
+
+    let mca = get_merchant_connector_account(state, merchant_account, key_store, mca_id).await?;
+
+    pm_auth_helpers::get_connector_auth_type(mca)
+}
+
+async fn get_merchant_connector_account(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mca_id: &common_utils::id_type::MerchantConnectorAccountId,
+) -> RouterResult<domain::MerchantConnectorAccount> {
+    #[cfg(feature = "v1")]
+    let mca = state
+        .store
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            &state.into(),
+            merchant_account.get_id(),
+            mca_id,
+            key_store,
+        )
+        .await
+        .to_not_found_response(ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: mca_id.get_string_repr().to_string(),
+        })?;
+
+    #[cfg(feature = "v2")]
+    let mca = {
+        let _ = state;
+        let _ = merchant_account;
+        let _ = key_store;
+        let _ = mca_id;
+        todo!()
+    };
+
+    Ok(mca)
+}
+
+/// Recipient registered at the payment method auth connector for the merchant, only connector
+/// issued recipient ids can be paid out to from a verified bank account.
+fn get_connector_recipient_id(mca: &domain::MerchantConnectorAccount) -> RouterResult<String> {
+    let additional_merchant_data = mca
+        .additional_merchant_data
+        .clone()
+        .ok_or(ApiErrorResponse::InvalidConnectorConfiguration {
+            config: "additional_merchant_data".to_string(),
+        })?
+        .into_inner()
+        .expose()
+        .parse_value::<types::AdditionalMerchantData>("AdditionalMerchantData")
+        .change_context(ApiErrorResponse::InternalServerError)
+        .attach_printable("failed to decode AdditionalMerchantData")?;
+
+    let types::AdditionalMerchantData::OpenBankingRecipientData(recipient_data) =
+        additional_merchant_data;
+
+    match recipient_data {
+        types::MerchantRecipientData::ConnectorRecipientId(id)
+        | types::MerchantRecipientData::AccountData(
+            types::MerchantAccountData::Iban {
+                connector_recipient_id: Some(types::RecipientIdType::ConnectorId(id)),
+                ..
+            }
+            | types::MerchantAccountData::Bacs {
+                connector_recipient_id: Some(types::RecipientIdType::ConnectorId(id)),
+                ..
+            },
+        ) => Ok(id.peek().clone()),
+        _ => Err(ApiErrorResponse::InvalidConnectorConfiguration {
+            config: "recipient_id".to_string(),
+        }
+        .into()),
+    }
+}
+
+/// A connector timeout or server error leaves it unknown whether the bank received the payment
+fn is_unknown_initiation_outcome(err: &pm_auth_types::ErrorResponse) -> bool {
+    err.code == pm_auth::consts::REQUEST_TIMEOUT_ERROR_CODE
+        || http::StatusCode::from_u16(err.status_code)
+            .is_ok_and(|status_code| status_code.is_server_error())
+}
+
+fn is_pending_reconciliation(payment_attempt: &PaymentAttempt) -> bool {
+    payment_attempt.status == enums::AttemptStatus::Pending
+        && payment_attempt.connector_transaction_id.is_none()
+        && payment_attempt.error_code.as_deref() == Some(consts::REQUEST_TIMEOUT_ERROR_CODE)
+}
+
+fn is_terminal_status(status: enums::AttemptStatus) -> bool {
+    matches!(
+        status,
+        enums::AttemptStatus::Charged
+            | enums::AttemptStatus::Failure
+            | enums::AttemptStatus::AuthorizationFailed
+            | enums::AttemptStatus::Voided
+    )
+}
+
+fn get_unsupported_error_response() -> pm_auth_types::ErrorResponse {
+    pm_auth_types::ErrorResponse {
+        status_code: http::StatusCode::INTERNAL_SERVER_ERROR.as_u16(),
+        code: consts::NO_ERROR_CODE.to_string(),
+        message: consts::UNSUPPORTED_ERROR_MESSAGE.to_string(),
+        reason: None,
+    }
+}
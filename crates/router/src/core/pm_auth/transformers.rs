@@ -1,6 +1,11 @@
+use common_enums::enums;
 use pm_auth::types::{self as pm_auth_types};
 
-use crate::{core::errors, types, types::transformers::ForeignTryFrom};
+use crate::{
+    core::errors,
+    types,
+    types::transformers::{ForeignFrom, ForeignTryFrom},
+};
 
 impl From<types::MerchantAccountData> for pm_auth_types::MerchantAccountData {
     fn from(from: types::MerchantAccountData) -> Self {
@@ -42,7 +47,44 @@ impl ForeignTryFrom<types::ConnectorAuthType> for pm_auth_types::ConnectorAuthTy
                     secret: key1.to_owned(),
                 })
             }
+            types::ConnectorAuthType::SignatureKey {
+                api_key,
+                key1,
+                api_secret,
+            } => Ok::<Self, errors::ConnectorError>(Self::SignatureKey {
+                client_id: api_key,
+                secret: key1,
+                redirect_uri: api_secret,
+            }),
             _ => Err(errors::ConnectorError::FailedToObtainAuthType),
         }
     }
 }
+
+impl ForeignFrom<pm_auth_types::PaymentInitiationStatus> for enums::AttemptStatus {
+    fn foreign_from(status: pm_auth_types::PaymentInitiationStatus) -> Self {
+        match status {
+            pm_auth_types::PaymentInitiationStatus::InputNeeded => Self::AuthenticationPending,
+            pm_auth_types::PaymentInitiationStatus::Initiated => Self::Pending,
+            pm_auth_types::PaymentInitiationStatus::Authorising => Self::Authorizing,
+            pm_auth_types::PaymentInitiationStatus::Established => Self::Authorized,
+            pm_auth_types::PaymentInitiationStatus::Executed
+            | pm_auth_types::PaymentInitiationStatus::Settled => Self::Charged,
+            pm_auth_types::PaymentInitiationStatus::InsufficientFunds
+            | pm_auth_types::PaymentInitiationStatus::Blocked
+            | pm_auth_types::PaymentInitiationStatus::Rejected => Self::AuthorizationFailed,
+            pm_auth_types::PaymentInitiationStatus::Failed => Self::Failure,
+            pm_auth_types::PaymentInitiationStatus::Cancelled => Self::Voided,
+        }
+    }
+}
+
+impl ForeignFrom<pm_auth_types::PaymentInitiationReversalStatus> for enums::RefundStatus {
+    fn foreign_from(status: pm_auth_types::PaymentInitiationReversalStatus) -> Self {
+        match status {
+            pm_auth_types::PaymentInitiationReversalStatus::Initiated => Self::Pending,
+            pm_auth_types::PaymentInitiationReversalStatus::Executed => Self::Success,
+            pm_auth_types::PaymentInitiationReversalStatus::Failed => Self::Failure,
+        }
+    }
+}
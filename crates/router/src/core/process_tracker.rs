@@ -0,0 +1,439 @@
+use std::str::FromStr;
+
+use api_models::process_tracker as process_tracker_api_types;
+use common_utils::{fp_utils::when, id_type};
+use diesel_models::enums::ProcessTrackerStatus;
+use error_stack::{report, ResultExt};
+use events::EventInfo;
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    events::audit_events::{AuditEvent, AuditEventType},
+    routes::{app::ReqState, SessionState},
+    services::{self, authentication as auth, authorization::roles},
+    types::{storage, transformers::ForeignInto},
+};
+
+/// Returns the merchant whose tasks the user is restricted to, internal users can access the
+/// tasks of all merchants.
+async fn get_restricted_merchant_id(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+) -> RouterResult<Option<id_type::MerchantId>> {
+    let role_info = roles::RoleInfo::from_role_id(
+        state,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch role info of the user")?;
+
+    Ok((!role_info.is_internal()).then(|| user_from_token.merchant_id.clone()))
+}
+
+fn is_task_of_merchant(task: &storage::ProcessTracker, merchant_id: &id_type::MerchantId) -> bool {
+    task.tracking_data
+        .get("merchant_id")
+        .and_then(|value| value.as_str())
+        .is_some_and(|task_merchant_id| task_merchant_id == merchant_id.get_string_repr())
+}
+
+async fn find_accessible_task(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+    task_id: &str,
+) -> RouterResult<storage::ProcessTracker> {
+    let restricted_merchant_id = get_restricted_merchant_id(state, user_from_token).await?;
+
+    state
+        .store
+        .find_process_by_id(task_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch process tracker task")?
+        .filter(|task| {
+            restricted_merchant_id
+                .as_ref()
+                .map_or(true, |merchant_id| is_task_of_merchant(task, merchant_id))
+        })
+        .ok_or_else(|| {
+            report!(errors::ApiErrorResponse::GenericNotFoundError {
+                message: format!("Task with id `{task_id}` does not exist in our records"),
+            })
+        })
+}
+
+async fn ensure_user_is_internal(
+    state: &SessionState,
+    user_from_token: &auth::UserFromToken,
+) -> RouterResult<()> {
+    let restricted_merchant_id = get_restricted_merchant_id(state, user_from_token).await?;
+    when(restricted_merchant_id.is_some(), || {
+        Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: "process_tracker_runner".to_string(),
+        }))
+    })
+}
+
+fn parse_runner(runner: &str) -> RouterResult<storage::ProcessTrackerRunner> {
+    storage::ProcessTrackerRunner::from_str(&runner.to_uppercase()).map_err(|_| {
+        report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("Unknown runner `{runner}`"),
+        })
+    })
+}
+
+fn ensure_task_is_idle(task: &storage::ProcessTracker) -> RouterResult<()> {
+    match task.status {
+        ProcessTrackerStatus::ProcessStarted | ProcessTrackerStatus::Processing => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Task is being executed by the scheduler, its status is `{}`",
+                    task.status
+                ),
+            }))
+        }
+        ProcessTrackerStatus::Finish => {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Task has already finished".to_string(),
+            }))
+        }
+        ProcessTrackerStatus::New | ProcessTrackerStatus::Pending => Ok(()),
+    }
+}
+
+/// Updates a task which is waiting to be picked up by the scheduler. The status is checked in the
+/// same query, so that a task picked up by the scheduler in the meantime is never updated.
+async fn update_idle_task(
+    state: &SessionState,
+    task: storage::ProcessTracker,
+    task_update: storage::ProcessTrackerUpdate,
+) -> RouterResult<storage::ProcessTracker> {
+    ensure_task_is_idle(&task)?;
+    state
+        .store
+        .update_process_if_status_in(
+            task,
+            vec![ProcessTrackerStatus::New, ProcessTrackerStatus::Pending],
+            task_update,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update process tracker task")?
+        .ok_or(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Task was picked up by the scheduler or finished, retry the request"
+                .to_string(),
+        }))
+}
+
+#[instrument(skip_all)]
+pub async fn list_tasks(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    constraints: process_tracker_api_types::ProcessTrackerListConstraints,
+) -> RouterResponse<Vec<process_tracker_api_types::ProcessTrackerResponse>> {
+    let status = constraints
+        .status
+        .as_deref()
+        .map(ProcessTrackerStatus::from_str)
+        .transpose()
+        .map_err(|_| {
+            report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Unknown task status".to_string(),
+            })
+        })?;
+    let runner = constraints
+        .runner
+        .as_deref()
+        .map(parse_runner)
+        .transpose()?;
+
+    // Users other than internal users can only look up the tasks of their own merchant
+    let merchant_id = get_restricted_merchant_id(&state, &user_from_token)
+        .await?
+        .or(constraints.merchant_id);
+
+    let filters = storage::ProcessTrackerFilters {
+        runner: runner.map(|runner| runner.to_string()),
+        status,
+        business_status: constraints.business_status,
+        merchant_id,
+        scheduled_after: constraints.scheduled_after,
+        scheduled_before: constraints.scheduled_before,
+        limit: Some(i64::from(
+            constraints.limit.unwrap_or(consts::DEFAULT_LIST_API_LIMIT),
+        )),
+        offset: constraints.offset.map(i64::from),
+    };
+
+    let tasks = state
+        .store
+        .find_processes_by_filters(&filters)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list process tracker tasks")?;
+
+    Ok(services::ApplicationResponse::Json(
+        tasks.into_iter().map(ForeignInto::foreign_into).collect(),
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_task(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    task_id: String,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerResponse> {
+    let task = find_accessible_task(&state, &user_from_token, &task_id).await?;
+
+    Ok(services::ApplicationResponse::Json(task.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn run_task_now(
+    state: SessionState,
+    req_state: ReqState,
+    user_from_token: auth::UserFromToken,
+    task_id: String,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerResponse> {
+    let task = find_accessible_task(&state, &user_from_token, &task_id).await?;
+
+    let task = schedule_task(&state, task, common_utils::date_time::now()).await?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::ProcessTrackerTaskRunNow {
+            performed_by: user_from_token.user_id,
+        }))
+        .with(ProcessTrackerEvent {
+            process: task.clone(),
+        })
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(task.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn reschedule_task(
+    state: SessionState,
+    req_state: ReqState,
+    user_from_token: auth::UserFromToken,
+    task_id: String,
+    request: process_tracker_api_types::ProcessTrackerRescheduleRequest,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerResponse> {
+    when(
+        request.schedule_time < common_utils::date_time::now(),
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "`schedule_time` must not be in the past".to_string(),
+            }))
+        },
+    )?;
+
+    let task = find_accessible_task(&state, &user_from_token, &task_id).await?;
+
+    let task = schedule_task(&state, task, request.schedule_time).await?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ProcessTrackerTaskRescheduled {
+                performed_by: user_from_token.user_id,
+                schedule_time: request.schedule_time,
+            },
+        ))
+        .with(ProcessTrackerEvent {
+            process: task.clone(),
+        })
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(task.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn cancel_task(
+    state: SessionState,
+    req_state: ReqState,
+    user_from_token: auth::UserFromToken,
+    task_id: String,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerResponse> {
+    let task = find_accessible_task(&state, &user_from_token, &task_id).await?;
+
+    // The status is updated directly instead of finishing the task through the scheduler, as
+    // finishing a recurring task would schedule its next run
+    let task = update_idle_task(
+        &state,
+        task,
+        storage::ProcessTrackerUpdate::StatusUpdate {
+            status: ProcessTrackerStatus::Finish,
+            business_status: Some(String::from(storage::business_status::CANCELLED)),
+        },
+    )
+    .await?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ProcessTrackerTaskCancelled {
+                performed_by: user_from_token.user_id,
+            },
+        ))
+        .with(ProcessTrackerEvent {
+            process: task.clone(),
+        })
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(task.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn retrieve_runner(
+    state: SessionState,
+    user_from_token: auth::UserFromToken,
+    runner: String,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerRunnerResponse> {
+    ensure_user_is_internal(&state, &user_from_token).await?;
+    let runner = parse_runner(&runner)?.to_string();
+
+    let paused_by = state
+        .store
+        .get_runner_paused_by(&runner)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch pause state of runner")?;
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api_types::ProcessTrackerRunnerResponse {
+            runner,
+            is_paused: paused_by.is_some(),
+            paused_by,
+            rescheduled_tasks: None,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn pause_runner(
+    state: SessionState,
+    req_state: ReqState,
+    user_from_token: auth::UserFromToken,
+    runner: String,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerRunnerResponse> {
+    ensure_user_is_internal(&state, &user_from_token).await?;
+    let runner = parse_runner(&runner)?.to_string();
+
+    state
+        .store
+        .pause_runner(&runner, &user_from_token.user_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to pause runner")?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ProcessTrackerRunnerPaused {
+                runner: runner.clone(),
+                performed_by: user_from_token.user_id.clone(),
+            },
+        ))
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api_types::ProcessTrackerRunnerResponse {
+            runner,
+            is_paused: true,
+            paused_by: Some(user_from_token.user_id),
+            rescheduled_tasks: None,
+        },
+    ))
+}
+
+#[instrument(skip_all)]
+pub async fn resume_runner(
+    state: SessionState,
+    req_state: ReqState,
+    user_from_token: auth::UserFromToken,
+    runner: String,
+) -> RouterResponse<process_tracker_api_types::ProcessTrackerRunnerResponse> {
+    ensure_user_is_internal(&state, &user_from_token).await?;
+    let runner = parse_runner(&runner)?.to_string();
+
+    state
+        .store
+        .resume_runner(&runner)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to resume runner")?;
+
+    // Tasks that became due while the runner was paused may have fallen out of the window the
+    // producer fetches tasks from, bring them back so that they are picked up right away
+    let rescheduled_tasks = state
+        .store
+        .reschedule_due_processes_by_runner(&runner, common_utils::date_time::now())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to reschedule overdue tasks of runner")?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(
+            AuditEventType::ProcessTrackerRunnerResumed {
+                runner: runner.clone(),
+                performed_by: user_from_token.user_id,
+                rescheduled_tasks,
+            },
+        ))
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(
+        process_tracker_api_types::ProcessTrackerRunnerResponse {
+            runner,
+            is_paused: false,
+            paused_by: None,
+            rescheduled_tasks: Some(rescheduled_tasks),
+        },
+    ))
+}
+
+async fn schedule_task(
+    state: &SessionState,
+    task: storage::ProcessTracker,
+    schedule_time: PrimitiveDateTime,
+) -> RouterResult<storage::ProcessTracker> {
+    update_idle_task(
+        state,
+        task,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: Some(schedule_time),
+            tracking_data: None,
+            business_status: None,
+            status: Some(ProcessTrackerStatus::Pending),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await
+}
+
+#[derive(Clone, serde::Serialize, Debug)]
+pub struct ProcessTrackerEvent {
+    process: storage::ProcessTracker,
+}
+
+impl EventInfo for ProcessTrackerEvent {
+    type Data = Self;
+    fn data(&self) -> error_stack::Result<Self::Data, events::EventsError> {
+        Ok(self.clone())
+    }
+
+    fn key(&self) -> String {
+        "process_tracker".to_string()
+    }
+}
@@ -5,12 +5,15 @@ use std::collections::HashMap;
 
 #[cfg(feature = "olap")]
 use api_models::admin::MerchantConnectorInfo;
+#[cfg(feature = "payouts")]
+use api_models::payouts as payout_types;
 use common_utils::{
     ext_traits::{AsyncExt, ValueExt},
     types::MinorUnit,
 };
-use diesel_models::process_tracker::business_status;
+use diesel_models::{business_profile::RefundPolicy, process_tracker::business_status};
 use error_stack::{report, ResultExt};
+use events::EventInfo;
 use hyperswitch_domain_models::router_data::ErrorResponse;
 use hyperswitch_interfaces::integrity::{CheckIntegrity, FlowIntegrity, GetIntegrityObject};
 use masking::PeekInterface;
@@ -19,6 +22,8 @@ use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
 #[cfg(feature = "olap")]
 use strum::IntoEnumIterator;
 
+#[cfg(feature = "payouts")]
+use crate::core::payouts;
 use crate::{
     consts,
     core::{
@@ -26,8 +31,10 @@ use crate::{
         payments::{self, access_token, types::PaymentCharges},
         utils as core_utils,
     },
-    db, logger,
-    routes::{metrics, SessionState},
+    db,
+    events::audit_events::{AuditEvent, AuditEventType},
+    logger,
+    routes::{app::ReqState, metrics, SessionState},
     services,
     types::{
         self,
@@ -46,10 +53,12 @@ use crate::{
 #[instrument(skip_all)]
 pub async fn refund_create_core(
     state: SessionState,
+    req_state: ReqState,
     merchant_account: domain::MerchantAccount,
     _profile_id: Option<common_utils::id_type::ProfileId>,
     key_store: domain::MerchantKeyStore,
     req: refunds::RefundRequest,
+    user_id: Option<String>,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
     let (merchant_id, payment_intent, payment_attempt, amount);
@@ -120,6 +129,7 @@ pub async fn refund_create_core(
 
     Box::pin(validate_and_create_refund(
         &state,
+        &req_state,
         &merchant_account,
         &key_store,
         &payment_attempt,
@@ -127,6 +137,7 @@ pub async fn refund_create_core(
         amount,
         req,
         creds_identifier,
+        user_id.as_deref(),
     ))
     .await
     .map(services::ApplicationResponse::Json)
@@ -251,7 +262,7 @@ pub async fn trigger_refund_to_gateway(
                 });
         // Update the refund status as failure if connector_error is NotImplemented
         if let Some(refund_error_update) = option_refund_error_update {
-            state
+            let failed_refund = state
                 .store
                 .update_refund(
                     refund.to_owned(),
@@ -266,6 +277,8 @@ pub async fn trigger_refund_to_gateway(
                         refund.refund_id
                     )
                 })?;
+            release_daily_refund_limits(state, merchant_account.storage_scheme, failed_refund)
+                .await;
         }
         let mut refund_router_data_res = router_data_res.to_refund_failed_response()?;
         // Initiating Integrity check
@@ -350,6 +363,11 @@ pub async fn trigger_refund_to_gateway(
                 refund.refund_id
             )
         })?;
+    let response = if response.refund_status == enums::RefundStatus::Failure {
+        release_daily_refund_limits(state, merchant_account.storage_scheme, response).await
+    } else {
+        response
+    };
     Ok(response)
 }
 
@@ -420,6 +438,23 @@ pub async fn refund_retrieve_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
     core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+
+    #[cfg(feature = "payouts")]
+    if let Some(payout_id) = refund.payout_id.clone() {
+        return if should_call_refund_payout(&refund, request.force_sync.unwrap_or(false)) {
+            Box::pin(sync_refund_with_payout(
+                &state,
+                &merchant_account,
+                &key_store,
+                refund,
+                payout_id,
+            ))
+            .await
+        } else {
+            Ok(refund)
+        };
+    }
+
     let payment_id = &refund.payment_id;
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
@@ -642,6 +677,13 @@ pub async fn sync_refund_with_gateway(
                 refund.refund_id
             )
         })?;
+    let response = if refund.refund_status != enums::RefundStatus::Failure
+        && response.refund_status == enums::RefundStatus::Failure
+    {
+        release_daily_refund_limits(state, merchant_account.storage_scheme, response).await
+    } else {
+        response
+    };
     Ok(response)
 }
 
@@ -681,12 +723,494 @@ pub async fn refund_update_core(
     Ok(services::ApplicationResponse::Json(response.foreign_into()))
 }
 
+// ********************************************** REFUND APPROVAL **********************************************
+
+#[instrument(skip_all)]
+pub async fn refund_approve_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    user_id: String,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let refund =
+        find_refund_pending_approval(&state, &merchant_account, profile_id, &req.refund_id).await?;
+
+    utils::when(
+        refund.created_by.as_deref() == Some(user_id.as_str()),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Refunds cannot be approved by the user who created them".to_string(),
+            }))
+        },
+    )?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &refund.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let payment_attempt = db
+        .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+            &refund.connector_transaction_id,
+            &refund.payment_id,
+            merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    let charges = get_charge_refunds(&payment_intent, &payment_attempt, refund.charges.as_ref())?;
+
+    let refund = db
+        .update_refund(
+            refund,
+            storage::RefundUpdate::ManualUpdate {
+                refund_status: Some(enums::RefundStatus::Pending),
+                refund_error_message: None,
+                refund_error_code: None,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to approve refund with refund_id: {}", req.refund_id)
+        })?;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::RefundApproved {
+            approved_by: user_id,
+            reason: req.reason,
+        }))
+        .with(RefundEvent {
+            refund: refund.clone(),
+        })
+        .emit();
+
+    let refund_type = refund.refund_type.foreign_into();
+    let response = Box::pin(schedule_refund_execution(
+        &state,
+        refund,
+        refund_type,
+        &merchant_account,
+        &key_store,
+        &payment_attempt,
+        &payment_intent,
+        None,
+        charges,
+    ))
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+#[instrument(skip_all)]
+pub async fn refund_reject_core(
+    state: SessionState,
+    req_state: ReqState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    user_id: String,
+    req: refunds::RefundApprovalRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let refund =
+        find_refund_pending_approval(&state, &merchant_account, profile_id, &req.refund_id).await?;
+
+    let response = state
+        .store
+        .update_refund(
+            refund,
+            storage::RefundUpdate::ManualUpdate {
+                refund_status: Some(enums::RefundStatus::Failure),
+                refund_error_message: Some(
+                    req.reason
+                        .clone()
+                        .unwrap_or_else(|| "Refund rejected during approval".to_string()),
+                ),
+                refund_error_code: None,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!("Unable to reject refund with refund_id: {}", req.refund_id)
+        })?;
+    let response =
+        release_daily_refund_limits(&state, merchant_account.storage_scheme, response).await;
+
+    req_state
+        .event_context
+        .event(AuditEvent::new(AuditEventType::RefundRejected {
+            rejected_by: user_id,
+            reason: req.reason,
+        }))
+        .with(RefundEvent {
+            refund: response.clone(),
+        })
+        .emit();
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+async fn find_refund_pending_approval(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    refund_id: &str,
+) -> RouterResult<storage::Refund> {
+    let refund = state
+        .store
+        .find_refund_by_merchant_id_refund_id(
+            merchant_account.get_id(),
+            refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+
+    utils::when(
+        refund.refund_status != enums::RefundStatus::PendingApproval,
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Refund is in {} status, only refunds pending approval can be approved or rejected",
+                    refund.refund_status
+                ),
+            }))
+        },
+    )?;
+
+    Ok(refund)
+}
+
+// ********************************************** REFUND PAYOUT **********************************************
+
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+pub async fn refund_payout_core(
+    state: SessionState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<common_utils::id_type::ProfileId>,
+    key_store: domain::MerchantKeyStore,
+    req: refunds::RefundPayoutRequest,
+) -> RouterResponse<refunds::RefundResponse> {
+    let db = &*state.store;
+    let merchant_id = merchant_account.get_id();
+
+    let refund = db
+        .find_refund_by_merchant_id_refund_id(
+            merchant_id,
+            &req.refund_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+    core_utils::validate_profile_id_from_auth_layer(profile_id, &refund)?;
+
+    utils::when(
+        refund.refund_status != enums::RefundStatus::Failure || refund.payout_id.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Refund is in {} status, only failed refunds which were not sent as a payout can be sent as a payout",
+                    refund.refund_status
+                ),
+            }))
+        },
+    )?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &(&state).into(),
+            &refund.payment_id,
+            merchant_id,
+            &key_store,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // The failed refund is not counted against the captured amount, other refunds may have been
+    // created against the payment since
+    let all_refunds = db
+        .find_refund_by_merchant_id_connector_transaction_id(
+            merchant_id,
+            &refund.connector_transaction_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
+
+    validator::validate_refund_amount(
+        payment_intent
+            .amount_captured
+            .unwrap_or(refund.total_amount)
+            .get_amount_as_i64(),
+        &all_refunds,
+        refund.refund_amount.get_amount_as_i64(),
+    )
+    .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
+
+    utils::when(payment_intent.customer_id.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Refunds can only be sent as a payout for payments made by a customer"
+                .to_string(),
+        }))
+    })?;
+
+    let response = Box::pin(route_refund_via_payout(
+        &state,
+        &merchant_account,
+        &key_store,
+        &payment_intent,
+        refund,
+        req.payout_method,
+    ))
+    .await?;
+
+    Ok(services::ApplicationResponse::Json(response.foreign_into()))
+}
+
+/// Sends the refund amount to the customer as a payout and links the payout to the refund
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+async fn route_refund_via_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    refund: storage::Refund,
+    payout_method: refunds::RefundPayoutMethod,
+) -> RouterResult<storage::Refund> {
+    // The daily refund limits were released when the refund failed at the gateway, the amount
+    // sent as a payout counts against them again
+    let refund = if refund.refund_status == enums::RefundStatus::Failure {
+        reserve_daily_refund_limits_for_refund(
+            state,
+            merchant_account.storage_scheme,
+            key_store,
+            refund,
+        )
+        .await?
+    } else {
+        refund
+    };
+
+    let payout_create_request = payout_types::PayoutCreateRequest {
+        amount: Some(refund.refund_amount.into()),
+        currency: Some(refund.currency),
+        connector: payout_method.connector,
+        confirm: Some(true),
+        auto_fulfill: Some(true),
+        payout_type: payout_method.payout_type,
+        payout_method_data: payout_method.payout_method_data,
+        payout_token: payout_method.payout_token,
+        billing: payout_method.billing,
+        customer_id: payment_intent.customer_id.clone(),
+        profile_id: refund.profile_id.clone(),
+        description: Some(format!("Refund for {}", refund.refund_id)),
+        metadata: refund.metadata.clone(),
+        ..Default::default()
+    };
+
+    let payout_response = Box::pin(payouts::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payout_create_request,
+        common_utils::consts::DEFAULT_LOCALE,
+    ))
+    .await;
+
+    let payout = match payout_response {
+        Ok(services::ApplicationResponse::Json(payout)) => payout,
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payouts create"))?,
+        Err(error) => {
+            let failed_refund = state
+                .store
+                .update_refund(
+                    refund.clone(),
+                    storage::RefundUpdate::ErrorUpdate {
+                        refund_status: Some(enums::RefundStatus::Failure),
+                        refund_error_message: Some(error.current_context().to_string()),
+                        refund_error_code: Some("PAYOUT_FAILED".to_string()),
+                        updated_by: merchant_account.storage_scheme.to_string(),
+                        connector_refund_id: None,
+                    },
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while updating refund: refund_id: {}",
+                        refund.refund_id
+                    )
+                })?;
+            release_daily_refund_limits(state, merchant_account.storage_scheme, failed_refund)
+                .await;
+            return Err(error);
+        }
+    };
+
+    let refund = update_refund_with_payout(state, merchant_account, refund, payout).await?;
+
+    // The payout status is reconciled with the refund by the refund sync task, which already
+    // exists if the refund was sent to the gateway before
+    if refund.refund_status == enums::RefundStatus::Pending {
+        add_or_reset_refund_sync_task(
+            &*state.store,
+            &refund,
+            storage::ProcessTrackerRunner::RefundWorkflowRouter,
+        )
+        .await
+        .attach_printable("Failed to schedule refund sync task for refund payout")?;
+    }
+
+    Ok(refund)
+}
+
+#[cfg(feature = "payouts")]
+fn should_call_refund_payout(refund: &storage::Refund, force_sync: bool) -> bool {
+    force_sync
+        || !matches!(
+            refund.refund_status,
+            enums::RefundStatus::Failure | enums::RefundStatus::Success
+        )
+}
+
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+async fn sync_refund_with_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund: storage::Refund,
+    payout_id: String,
+) -> RouterResult<storage::Refund> {
+    let payout_response = Box::pin(payouts::payouts_retrieve_core(
+        state.clone(),
+        merchant_account.clone(),
+        None,
+        key_store.clone(),
+        payout_types::PayoutRetrieveRequest {
+            payout_id,
+            force_sync: Some(true),
+            merchant_id: Some(merchant_account.get_id().clone()),
+        },
+    ))
+    .await?;
+
+    match payout_response {
+        services::ApplicationResponse::Json(payout) => {
+            update_refund_with_payout(state, merchant_account, refund, payout).await
+        }
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Unexpected response from payouts retrieve")),
+    }
+}
+
+#[cfg(feature = "payouts")]
+async fn update_refund_with_payout(
+    state: &SessionState,
+    merchant_account: &domain::MerchantAccount,
+    refund: storage::Refund,
+    payout: payout_types::PayoutCreateResponse,
+) -> RouterResult<storage::Refund> {
+    let refund_status = get_refund_status_from_payout_status(payout.status);
+    if refund.payout_id.as_ref() == Some(&payout.payout_id) && refund.refund_status == refund_status
+    {
+        return Ok(refund);
+    }
+
+    if refund_status == enums::RefundStatus::Success {
+        metrics::SUCCESSFUL_REFUND.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([(
+                "connector",
+                payout.connector.unwrap_or_else(|| refund.connector.clone()),
+            )]),
+        )
+    }
+
+    let refund_update = storage::RefundUpdate::PayoutUpdate {
+        payout_id: payout.payout_id,
+        refund_status,
+        refund_error_message: payout.error_message,
+        refund_error_code: payout.error_code,
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+
+    let refund = state
+        .store
+        .update_refund(
+            refund.to_owned(),
+            refund_update,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while updating refund: refund_id: {}",
+                refund.refund_id
+            )
+        })?;
+    let refund = if refund.refund_status == enums::RefundStatus::Failure {
+        release_daily_refund_limits(state, merchant_account.storage_scheme, refund).await
+    } else {
+        refund
+    };
+    Ok(refund)
+}
+
+#[cfg(feature = "payouts")]
+fn get_refund_status_from_payout_status(
+    payout_status: common_enums::PayoutStatus,
+) -> enums::RefundStatus {
+    match payout_status {
+        common_enums::PayoutStatus::Success => enums::RefundStatus::Success,
+        common_enums::PayoutStatus::Failed
+        | common_enums::PayoutStatus::Cancelled
+        | common_enums::PayoutStatus::Expired
+        | common_enums::PayoutStatus::Reversed
+        | common_enums::PayoutStatus::Ineligible => enums::RefundStatus::Failure,
+        common_enums::PayoutStatus::Initiated
+        | common_enums::PayoutStatus::Pending
+        | common_enums::PayoutStatus::RequiresCreation
+        | common_enums::PayoutStatus::RequiresConfirmation
+        | common_enums::PayoutStatus::RequiresPayoutMethodData
+        | common_enums::PayoutStatus::RequiresFulfillment
+        | common_enums::PayoutStatus::RequiresVendorAccountCreation => enums::RefundStatus::Pending,
+    }
+}
+
 // ********************************************** VALIDATIONS **********************************************
 
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn validate_and_create_refund(
     state: &SessionState,
+    req_state: &ReqState,
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
     payment_attempt: &storage::PaymentAttempt,
@@ -694,45 +1218,31 @@ pub async fn validate_and_create_refund(
     refund_amount: MinorUnit,
     req: refunds::RefundRequest,
     creds_identifier: Option<String>,
+    user_id: Option<&str>,
 ) -> RouterResult<refunds::RefundResponse> {
     let db = &*state.store;
 
     // Validate charge_id and refund options
-    let charges = match (
-        payment_intent.charges.as_ref(),
-        payment_attempt.charge_id.as_ref(),
-    ) {
-        (Some(charges), Some(charge_id)) => {
-            let refund_charge_request = req.charges.clone().get_required_value("charges")?;
-            utils::when(*charge_id != refund_charge_request.charge_id, || {
-                Err(report!(errors::ApiErrorResponse::InvalidDataValue {
-                    field_name: "charges.charge_id"
-                }))
-                .attach_printable("charge_id sent in request mismatches with original charge_id")
-            })?;
-            let payment_charges: PaymentCharges = charges
-                .peek()
-                .clone()
-                .parse_value("PaymentCharges")
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed to parse charges in to PaymentCharges")?;
-            let options = validator::validate_charge_refund(
-                &refund_charge_request,
-                &payment_charges.charge_type,
-            )?;
-            Some(ChargeRefunds {
-                charge_id: charge_id.to_string(),
-                charge_type: payment_charges.charge_type,
-                transfer_account_id: payment_charges.transfer_account_id,
-                options,
-            })
-        }
-        _ => None,
-    };
+    let charges = get_charge_refunds(payment_intent, payment_attempt, req.charges.as_ref())?;
 
     // Only for initial dev and testing
     let refund_type = req.refund_type.unwrap_or_default();
 
+    #[cfg(feature = "payouts")]
+    let payout_details = req.payout_details.clone();
+    #[cfg(feature = "payouts")]
+    validate_refund_payout_details(
+        payout_details.as_ref(),
+        refund_type,
+        payment_intent.customer_id.as_ref(),
+    )?;
+    #[cfg(feature = "payouts")]
+    let skip_refund_to_gateway = payout_details.as_ref().is_some_and(|payout_details| {
+        payout_details.trigger == refunds::RefundPayoutTrigger::Always
+    });
+    #[cfg(not(feature = "payouts"))]
+    let skip_refund_to_gateway = false;
+
     // If Refund Id not passed in request Generate one.
 
     let refund_id = core_utils::get_or_generate_id("refund_id", &req.refund_id, "ref")?;
@@ -787,17 +1297,72 @@ pub async fn validate_and_create_refund(
     )
     .change_context(errors::ApiErrorResponse::RefundAmountExceedsPaymentAmount)?;
 
-    validator::validate_maximum_refund_against_payment_attempt(
-        &all_refunds,
-        state.conf.refund.max_attempts,
-    )
-    .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
+    validator::validate_maximum_refund_against_payment_attempt(
+        &all_refunds,
+        state.conf.refund.max_attempts,
+    )
+    .change_context(errors::ApiErrorResponse::MaximumRefundCount)?;
+
+    let refund_policy =
+        get_refund_policy(state, key_store, payment_intent.profile_id.as_ref()).await?;
+
+    if let Some(max_refund_age_in_days) = refund_policy
+        .as_ref()
+        .and_then(|refund_policy| refund_policy.max_refund_age_in_days)
+    {
+        // Payments captured before the capture time was recorded fall back to the last update of
+        // the successful attempt
+        let captured_at = payment_intent
+            .captured_at
+            .unwrap_or(payment_attempt.modified_at);
+        validator::validate_payment_order_age(&captured_at, max_refund_age_in_days)
+            .change_context(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "Refunds can only be created within {max_refund_age_in_days} days of the payment being captured"
+                ),
+            })?;
+    }
+
+    let approval_threshold = refund_policy
+        .as_ref()
+        .and_then(|refund_policy| refund_policy.approval_threshold)
+        .filter(|approval_threshold| refund_amount > *approval_threshold);
+
+    #[cfg(feature = "payouts")]
+    utils::when(
+        approval_threshold.is_some() && payout_details.is_some(),
+        || {
+            Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Refunds which require approval cannot be sent as a payout".to_string(),
+            }))
+        },
+    )?;
 
     let connector = payment_attempt
         .connector
         .clone()
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("No connector populated in payment attempt")?;
+
+    let daily_refund_limit_reservation =
+        match (refund_policy.as_ref(), payment_intent.profile_id.as_ref()) {
+            (Some(refund_policy), Some(profile_id)) => {
+                let redis_conn = db
+                    .get_redis_conn()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to get redis connection")?;
+                reserve_daily_refund_limits(
+                    &redis_conn,
+                    profile_id,
+                    refund_policy,
+                    user_id,
+                    refund_amount,
+                )
+                .await?
+                .map(|reservation| (redis_conn, reservation))
+            }
+            _ => None,
+        };
     let refund_create_req = storage::RefundNew {
         refund_id: refund_id.to_string(),
         internal_reference_id: utils::generate_id(consts::ID_LENGTH, "refid"),
@@ -812,7 +1377,11 @@ pub async fn validate_and_create_refund(
         currency,
         created_at: common_utils::date_time::now(),
         modified_at: common_utils::date_time::now(),
-        refund_status: enums::RefundStatus::Pending,
+        refund_status: if approval_threshold.is_some() {
+            enums::RefundStatus::PendingApproval
+        } else {
+            enums::RefundStatus::Pending
+        },
         metadata: req.metadata,
         description: req.reason.clone(),
         attempt_id: payment_attempt.attempt_id.clone(),
@@ -825,27 +1394,52 @@ pub async fn validate_and_create_refund(
         refund_arn: None,
         updated_by: Default::default(),
         organization_id: merchant_account.organization_id.clone(),
+        payout_id: None,
+        created_by: user_id.map(ToOwned::to_owned),
+        daily_refund_limit_reservation: daily_refund_limit_reservation
+            .as_ref()
+            .map(|(_, reservation)| reservation.clone()),
     };
 
     let refund = match db
         .insert_refund(refund_create_req, merchant_account.storage_scheme)
         .await
     {
-        Ok(refund) => {
-            Box::pin(schedule_refund_execution(
-                state,
-                refund.clone(),
-                refund_type,
-                merchant_account,
-                key_store,
-                payment_attempt,
-                payment_intent,
-                creds_identifier,
-                charges,
-            ))
-            .await?
-        }
+        Ok(refund) => match approval_threshold {
+            Some(approval_threshold) => {
+                req_state
+                    .event_context
+                    .event(AuditEvent::new(AuditEventType::RefundApprovalRequested {
+                        requested_by: user_id.map(ToOwned::to_owned),
+                        approval_threshold,
+                    }))
+                    .with(RefundEvent {
+                        refund: refund.clone(),
+                    })
+                    .emit();
+                refund
+            }
+            // The refund amount is sent to the customer as a payout below
+            None if skip_refund_to_gateway => refund,
+            None => {
+                Box::pin(schedule_refund_execution(
+                    state,
+                    refund.clone(),
+                    refund_type,
+                    merchant_account,
+                    key_store,
+                    payment_attempt,
+                    payment_intent,
+                    creds_identifier,
+                    charges,
+                ))
+                .await?
+            }
+        },
         Err(err) => {
+            if let Some((redis_conn, reservation)) = daily_refund_limit_reservation {
+                release_daily_refund_limit_reservation(&redis_conn, &reservation).await;
+            }
             if err.current_context().is_db_unique_violation() {
                 Err(errors::ApiErrorResponse::DuplicateRefundRequest)?
             } else {
@@ -856,9 +1450,368 @@ pub async fn validate_and_create_refund(
         }
     };
 
+    #[cfg(feature = "payouts")]
+    let refund = match payout_details {
+        Some(payout_details)
+            if skip_refund_to_gateway || refund.refund_status == enums::RefundStatus::Failure =>
+        {
+            Box::pin(route_refund_via_payout(
+                state,
+                merchant_account,
+                key_store,
+                payment_intent,
+                refund,
+                payout_details.payout_method,
+            ))
+            .await?
+        }
+        _ => refund,
+    };
+
     Ok(refund.foreign_into())
 }
 
+#[cfg(feature = "payouts")]
+fn validate_refund_payout_details(
+    payout_details: Option<&refunds::RefundPayoutDetails>,
+    refund_type: refunds::RefundType,
+    customer_id: Option<&common_utils::id_type::CustomerId>,
+) -> RouterResult<()> {
+    let Some(payout_details) = payout_details else {
+        return Ok(());
+    };
+
+    // Scheduled refunds reach the connector from the scheduler, after the refund has been created
+    utils::when(
+        payout_details.trigger == refunds::RefundPayoutTrigger::OnRefundFailure
+            && refund_type != refunds::RefundType::Instant,
+        || {
+            Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+                message: "payout_details with trigger `on_refund_failure` can only be used with instant refunds".to_string(),
+            }))
+        },
+    )?;
+
+    utils::when(customer_id.is_none(), || {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Refunds can only be sent as a payout for payments made by a customer"
+                .to_string(),
+        }))
+    })
+}
+
+#[derive(Clone, serde::Serialize, Debug)]
+pub struct RefundEvent {
+    refund: storage::Refund,
+}
+
+impl EventInfo for RefundEvent {
+    type Data = Self;
+    fn data(&self) -> error_stack::Result<Self::Data, events::EventsError> {
+        Ok(self.clone())
+    }
+
+    fn key(&self) -> String {
+        "refund".to_string()
+    }
+}
+
+fn get_charge_refunds(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+    refund_charges: Option<&common_utils::types::ChargeRefunds>,
+) -> RouterResult<Option<ChargeRefunds>> {
+    match (
+        payment_intent.charges.as_ref(),
+        payment_attempt.charge_id.as_ref(),
+    ) {
+        (Some(charges), Some(charge_id)) => {
+            let refund_charge_request = refund_charges.get_required_value("charges")?;
+            utils::when(*charge_id != refund_charge_request.charge_id, || {
+                Err(report!(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "charges.charge_id"
+                }))
+                .attach_printable("charge_id sent in request mismatches with original charge_id")
+            })?;
+            let payment_charges: PaymentCharges = charges
+                .peek()
+                .clone()
+                .parse_value("PaymentCharges")
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse charges in to PaymentCharges")?;
+            let options = validator::validate_charge_refund(
+                refund_charge_request,
+                &payment_charges.charge_type,
+            )?;
+            Ok(Some(ChargeRefunds {
+                charge_id: charge_id.to_string(),
+                charge_type: payment_charges.charge_type,
+                transfer_account_id: payment_charges.transfer_account_id,
+                options,
+            }))
+        }
+        _ => Ok(None),
+    }
+}
+
+async fn get_refund_policy(
+    state: &SessionState,
+    key_store: &domain::MerchantKeyStore,
+    profile_id: Option<&common_utils::id_type::ProfileId>,
+) -> RouterResult<Option<RefundPolicy>> {
+    let business_profile = profile_id
+        .async_map(|profile_id| async {
+            state
+                .store
+                .find_business_profile_by_profile_id(&state.into(), key_store, profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.get_string_repr().to_owned(),
+                })
+        })
+        .await
+        .transpose()?;
+
+    Ok(business_profile.and_then(|business_profile| business_profile.refund_policy))
+}
+
+/// Removes the refund amount from the daily refund counters it was added to
+async fn release_daily_refund_limit_reservation(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    reservation: &storage::DailyRefundLimitReservation,
+) {
+    for field in &reservation.fields {
+        redis_conn
+            .increment_field_in_hash(
+                &reservation.key,
+                field,
+                -reservation.refund_amount.get_amount_as_i64(),
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to release daily refund amount for {field}")
+            })
+            .ok();
+    }
+}
+
+fn get_daily_refund_limit_key(
+    profile_id: &common_utils::id_type::ProfileId,
+    date: time::Date,
+) -> String {
+    format!("refund_limits_{}_{date}", profile_id.get_string_repr())
+}
+
+/// Daily refund counters of the refund policy which apply to a refund, along with their limits
+fn get_daily_refund_limits(
+    refund_policy: &RefundPolicy,
+    user_id: Option<&str>,
+) -> Vec<(String, MinorUnit)> {
+    [
+        refund_policy
+            .max_refund_amount_per_day
+            .map(|limit| (consts::REFUND_POLICY_DAILY_TOTAL_FIELD.to_string(), limit)),
+        refund_policy
+            .max_refund_amount_per_user_per_day
+            .zip(user_id)
+            .map(|(limit, user_id)| (format!("user_{user_id}"), limit)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Whether a reservation still limits new refunds. Reservations made on earlier days are not
+/// released, as the counters of earlier days no longer limit new refunds.
+fn is_daily_refund_limit_reservation_active(
+    profile_id: &common_utils::id_type::ProfileId,
+    reservation: &storage::DailyRefundLimitReservation,
+    today: time::Date,
+) -> bool {
+    reservation.key == get_daily_refund_limit_key(profile_id, today)
+}
+
+/// Removes the amount reserved for a refund which will not be refunded from the daily refund
+/// counters it was added to. The reservation is removed from the refund first, so that it is
+/// released at most once.
+async fn release_daily_refund_limits(
+    state: &SessionState,
+    storage_scheme: enums::MerchantStorageScheme,
+    refund: storage::Refund,
+) -> storage::Refund {
+    let today = common_utils::date_time::now().date();
+    let Some(reservation) = refund
+        .profile_id
+        .as_ref()
+        .zip(refund.daily_refund_limit_reservation.as_ref())
+        .filter(|(profile_id, reservation)| {
+            is_daily_refund_limit_reservation_active(profile_id, reservation, today)
+        })
+        .map(|(_, reservation)| reservation.clone())
+    else {
+        return refund;
+    };
+
+    let refund = match state
+        .store
+        .update_refund(
+            refund.clone(),
+            storage::RefundUpdate::DailyRefundLimitReservationUpdate {
+                daily_refund_limit_reservation: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => refund,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to remove the daily refund limit reservation from the refund"
+            );
+            return refund;
+        }
+    };
+
+    match state.store.get_redis_conn() {
+        Ok(redis_conn) => release_daily_refund_limit_reservation(&redis_conn, &reservation).await,
+        Err(error) => logger::error!(?error, "Failed to get redis connection"),
+    }
+    refund
+}
+
+/// Adds the amount of a refund created on the current day back to the daily refund counters it
+/// was released from, and stores the new reservation with the refund
+#[cfg(feature = "payouts")]
+async fn reserve_daily_refund_limits_for_refund(
+    state: &SessionState,
+    storage_scheme: enums::MerchantStorageScheme,
+    key_store: &domain::MerchantKeyStore,
+    refund: storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let Some(profile_id) = refund
+        .profile_id
+        .as_ref()
+        .filter(|_| refund.created_at.date() == common_utils::date_time::now().date())
+    else {
+        return Ok(refund);
+    };
+
+    let Some(refund_policy) = get_refund_policy(state, key_store, Some(profile_id)).await? else {
+        return Ok(refund);
+    };
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let Some(reservation) = reserve_daily_refund_limits(
+        &redis_conn,
+        profile_id,
+        &refund_policy,
+        refund.created_by.as_deref(),
+        refund.refund_amount,
+    )
+    .await?
+    else {
+        return Ok(refund);
+    };
+
+    match state
+        .store
+        .update_refund(
+            refund.clone(),
+            storage::RefundUpdate::DailyRefundLimitReservationUpdate {
+                daily_refund_limit_reservation: Some(reservation.clone()),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+    {
+        Ok(refund) => Ok(refund),
+        Err(error) => {
+            release_daily_refund_limit_reservation(&redis_conn, &reservation).await;
+            Err(error)
+                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable_lazy(|| {
+                    format!(
+                        "Failed while updating refund: refund_id: {}",
+                        refund.refund_id
+                    )
+                })
+        }
+    }
+}
+
+/// Adds the refund amount to the daily refund counters of the profile, failing if any of the
+/// daily limits of the refund policy would be exceeded. Refunds created using API keys are not
+/// attributed to a user and are only checked against the profile wide limit.
+async fn reserve_daily_refund_limits(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    profile_id: &common_utils::id_type::ProfileId,
+    refund_policy: &RefundPolicy,
+    user_id: Option<&str>,
+    refund_amount: MinorUnit,
+) -> RouterResult<Option<storage::DailyRefundLimitReservation>> {
+    let limits = get_daily_refund_limits(refund_policy, user_id);
+    if limits.is_empty() {
+        return Ok(None);
+    }
+
+    let mut reservation = storage::DailyRefundLimitReservation {
+        key: get_daily_refund_limit_key(profile_id, common_utils::date_time::now().date()),
+        fields: Vec::with_capacity(limits.len()),
+        refund_amount,
+    };
+
+    for (field, limit) in limits {
+        let refunded_amount = match redis_conn
+            .increment_field_in_hash(&reservation.key, &field, refund_amount.get_amount_as_i64())
+            .await
+        {
+            Ok(refunded_amount) => refunded_amount,
+            Err(error) => {
+                release_daily_refund_limit_reservation(redis_conn, &reservation).await;
+                return Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to increment daily refund amount");
+            }
+        };
+        let exceeds_limit = i64::try_from(refunded_amount)
+            .map(|refunded_amount| refunded_amount > limit.get_amount_as_i64())
+            .unwrap_or(true);
+        let limit_name = if field == consts::REFUND_POLICY_DAILY_TOTAL_FIELD {
+            "daily refund limit of the profile"
+        } else {
+            "daily refund limit of the user"
+        };
+        reservation.fields.push(field);
+
+        if exceeds_limit {
+            release_daily_refund_limit_reservation(redis_conn, &reservation).await;
+            return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!("Refund amount exceeds the {limit_name}"),
+            }));
+        }
+    }
+
+    // Without an expiry the counters would never be removed, so the reservation is rolled back
+    if let Err(error) = redis_conn
+        .set_expiry(&reservation.key, consts::REFUND_POLICY_DAILY_LIMIT_TTL)
+        .await
+    {
+        release_daily_refund_limit_reservation(redis_conn, &reservation).await;
+        return Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to set expiry for daily refund amounts");
+    }
+
+    Ok(Some(reservation))
+}
+
 // ********************************************** Refund list **********************************************
 
 ///   If payment-id is provided, lists all the refunds associated with that particular payment-id
@@ -968,7 +1921,7 @@ pub async fn refund_manual_update(
         refund_error_code: req.error_code,
         updated_by: merchant_account.storage_scheme.to_string(),
     };
-    state
+    let updated_refund = state
         .store
         .update_refund(
             refund.to_owned(),
@@ -983,6 +1936,11 @@ pub async fn refund_manual_update(
                 refund.refund_id
             )
         })?;
+    if refund.refund_status != enums::RefundStatus::Failure
+        && updated_refund.refund_status == enums::RefundStatus::Failure
+    {
+        release_daily_refund_limits(&state, merchant_account.storage_scheme, updated_refund).await;
+    }
     Ok(services::ApplicationResponse::StatusOk)
 }
 
@@ -1054,6 +2012,7 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
             charges: refund.charges,
+            payout_id: refund.payout_id,
         }
     }
 }
@@ -1431,6 +2390,36 @@ pub async fn add_refund_sync_task(
     Ok(response)
 }
 
+#[cfg(feature = "payouts")]
+#[instrument(skip_all)]
+async fn add_or_reset_refund_sync_task(
+    db: &dyn db::StorageInterface,
+    refund: &storage::Refund,
+    runner: storage::ProcessTrackerRunner,
+) -> RouterResult<()> {
+    let process_tracker_id = format!("{runner}_SYNC_REFUND_{}", refund.internal_reference_id);
+    let refund_sync_process = db
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to find the refund sync process")?;
+
+    match refund_sync_process {
+        Some(refund_sync_process) => db
+            .as_scheduler()
+            .reset_process(refund_sync_process, common_utils::date_time::now())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed while resetting refund sync task: refund_id: {}",
+                    refund.refund_id
+                )
+            }),
+        None => add_refund_sync_task(db, refund, runner).await.map(|_| ()),
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn add_refund_execute_task(
     db: &dyn db::StorageInterface,
@@ -1493,3 +2482,302 @@ pub async fn get_refund_sync_process_schedule_time(
 
     Ok(process_tracker_utils::get_time_from_delta(time_delta))
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use super::*;
+
+    async fn get_redis_conn() -> redis_interface::RedisConnectionPool {
+        redis_interface::RedisConnectionPool::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to connect to redis")
+    }
+
+    fn get_profile_id() -> common_utils::id_type::ProfileId {
+        common_utils::id_type::ProfileId::try_from(std::borrow::Cow::from(utils::generate_id(
+            consts::ID_LENGTH,
+            "pro",
+        )))
+        .unwrap()
+    }
+
+    fn build_refund_policy(
+        max_refund_amount_per_day: i64,
+        max_refund_amount_per_user_per_day: Option<i64>,
+    ) -> RefundPolicy {
+        RefundPolicy {
+            max_refund_age_in_days: None,
+            max_refund_amount_per_day: Some(MinorUnit::new(max_refund_amount_per_day)),
+            max_refund_amount_per_user_per_day: max_refund_amount_per_user_per_day
+                .map(MinorUnit::new),
+            approval_threshold: None,
+        }
+    }
+
+    async fn get_refunded_amount(
+        redis_conn: &redis_interface::RedisConnectionPool,
+        key: &str,
+        field: &str,
+    ) -> i64 {
+        redis_conn
+            .get_hash_field::<Option<i64>>(key, field)
+            .await
+            .unwrap()
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_reserve_and_release_daily_refund_limits() {
+        let redis_conn = get_redis_conn().await;
+        let profile_id = get_profile_id();
+        let refund_policy = build_refund_policy(1000, Some(500));
+
+        let reservation = reserve_daily_refund_limits(
+            &redis_conn,
+            &profile_id,
+            &refund_policy,
+            Some("user_1"),
+            MinorUnit::new(300),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            reservation.fields,
+            vec![
+                consts::REFUND_POLICY_DAILY_TOTAL_FIELD.to_string(),
+                "user_user_1".to_string()
+            ]
+        );
+        assert_eq!(reservation.refund_amount, MinorUnit::new(300));
+        for field in &reservation.fields {
+            assert_eq!(
+                get_refunded_amount(&redis_conn, &reservation.key, field).await,
+                300
+            );
+        }
+
+        release_daily_refund_limit_reservation(&redis_conn, &reservation).await;
+        for field in &reservation.fields {
+            assert_eq!(
+                get_refunded_amount(&redis_conn, &reservation.key, field).await,
+                0
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reserve_daily_refund_limits_exceeding_limit_is_rolled_back() {
+        let redis_conn = get_redis_conn().await;
+        let profile_id = get_profile_id();
+        let refund_policy = build_refund_policy(1000, Some(500));
+
+        let reservation = reserve_daily_refund_limits(
+            &redis_conn,
+            &profile_id,
+            &refund_policy,
+            Some("user_1"),
+            MinorUnit::new(400),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        // The profile wide counter is incremented before the user limit is exceeded
+        let result = reserve_daily_refund_limits(
+            &redis_conn,
+            &profile_id,
+            &refund_policy,
+            Some("user_1"),
+            MinorUnit::new(200),
+        )
+        .await;
+
+        assert!(result.is_err());
+        for field in &reservation.fields {
+            assert_eq!(
+                get_refunded_amount(&redis_conn, &reservation.key, field).await,
+                400
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_release_daily_refund_limits_after_refund_policy_change() {
+        let redis_conn = get_redis_conn().await;
+        let profile_id = get_profile_id();
+
+        let reservation = reserve_daily_refund_limits(
+            &redis_conn,
+            &profile_id,
+            &build_refund_policy(1000, None),
+            Some("user_1"),
+            MinorUnit::new(300),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+        assert_eq!(
+            reservation.fields,
+            vec![consts::REFUND_POLICY_DAILY_TOTAL_FIELD.to_string()]
+        );
+
+        // A user limit added after the refund was created must not be released for the refund
+        let other_reservation = reserve_daily_refund_limits(
+            &redis_conn,
+            &profile_id,
+            &build_refund_policy(1000, Some(500)),
+            Some("user_1"),
+            MinorUnit::new(100),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        release_daily_refund_limit_reservation(&redis_conn, &reservation).await;
+
+        assert_eq!(
+            get_refunded_amount(
+                &redis_conn,
+                &reservation.key,
+                consts::REFUND_POLICY_DAILY_TOTAL_FIELD
+            )
+            .await,
+            100
+        );
+        assert_eq!(
+            get_refunded_amount(&redis_conn, &other_reservation.key, "user_user_1").await,
+            100
+        );
+    }
+
+    #[test]
+    fn test_daily_refund_limit_reservation_of_earlier_day_is_not_active() {
+        let profile_id = get_profile_id();
+        let today = common_utils::date_time::now().date();
+        let yesterday = today.previous_day().unwrap();
+        let reservation = storage::DailyRefundLimitReservation {
+            key: get_daily_refund_limit_key(&profile_id, yesterday),
+            fields: vec![consts::REFUND_POLICY_DAILY_TOTAL_FIELD.to_string()],
+            refund_amount: MinorUnit::new(300),
+        };
+
+        assert!(!is_daily_refund_limit_reservation_active(
+            &profile_id,
+            &reservation,
+            today
+        ));
+        assert!(is_daily_refund_limit_reservation_active(
+            &profile_id,
+            &reservation,
+            yesterday
+        ));
+    }
+
+    #[cfg(feature = "payouts")]
+    fn build_refund_payout_details(
+        trigger: refunds::RefundPayoutTrigger,
+    ) -> refunds::RefundPayoutDetails {
+        refunds::RefundPayoutDetails {
+            trigger,
+            payout_method: refunds::RefundPayoutMethod::default(),
+        }
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_validate_refund_payout_details() {
+        let customer_id =
+            common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from("cus_123")).unwrap();
+
+        assert!(validate_refund_payout_details(None, refunds::RefundType::Scheduled, None).is_ok());
+        assert!(validate_refund_payout_details(
+            Some(&build_refund_payout_details(
+                refunds::RefundPayoutTrigger::OnRefundFailure
+            )),
+            refunds::RefundType::Instant,
+            Some(&customer_id)
+        )
+        .is_ok());
+        assert!(validate_refund_payout_details(
+            Some(&build_refund_payout_details(
+                refunds::RefundPayoutTrigger::Always
+            )),
+            refunds::RefundType::Scheduled,
+            Some(&customer_id)
+        )
+        .is_ok());
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_validate_refund_payout_details_on_refund_failure_requires_instant_refund() {
+        let customer_id =
+            common_utils::id_type::CustomerId::try_from(std::borrow::Cow::from("cus_123")).unwrap();
+
+        let error = validate_refund_payout_details(
+            Some(&build_refund_payout_details(
+                refunds::RefundPayoutTrigger::OnRefundFailure,
+            )),
+            refunds::RefundType::Scheduled,
+            Some(&customer_id),
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::InvalidRequestData { .. }
+        ));
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_validate_refund_payout_details_requires_customer() {
+        let error = validate_refund_payout_details(
+            Some(&build_refund_payout_details(
+                refunds::RefundPayoutTrigger::Always,
+            )),
+            refunds::RefundType::Instant,
+            None,
+        )
+        .unwrap_err();
+
+        assert!(matches!(
+            error.current_context(),
+            errors::ApiErrorResponse::PreconditionFailed { .. }
+        ));
+    }
+
+    #[cfg(feature = "payouts")]
+    #[test]
+    fn test_get_refund_status_from_payout_status() {
+        assert_eq!(
+            get_refund_status_from_payout_status(common_enums::PayoutStatus::Success),
+            enums::RefundStatus::Success
+        );
+        for payout_status in [
+            common_enums::PayoutStatus::Failed,
+            common_enums::PayoutStatus::Cancelled,
+            common_enums::PayoutStatus::Expired,
+            common_enums::PayoutStatus::Reversed,
+            common_enums::PayoutStatus::Ineligible,
+        ] {
+            assert_eq!(
+                get_refund_status_from_payout_status(payout_status),
+                enums::RefundStatus::Failure
+            );
+        }
+        for payout_status in [
+            common_enums::PayoutStatus::Initiated,
+            common_enums::PayoutStatus::Pending,
+            common_enums::PayoutStatus::RequiresFulfillment,
+        ] {
+            assert_eq!(
+                get_refund_status_from_payout_status(payout_status),
+                enums::RefundStatus::Pending
+            );
+        }
+    }
+}
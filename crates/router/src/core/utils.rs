@@ -1403,6 +1403,13 @@ impl GetProfileId for storage::Payouts {
         Some(&self.profile_id)
     }
 }
+#[cfg(feature = "payouts")]
+impl GetProfileId for storage::PayoutBatch {
+    fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
+        Some(&self.profile_id)
+    }
+}
+
 #[cfg(feature = "payouts")]
 impl<T, F> GetProfileId for (storage::Payouts, T, F) {
     fn get_profile_id(&self) -> Option<&common_utils::id_type::ProfileId> {
@@ -1459,3 +1466,117 @@ pub(crate) fn validate_profile_id_from_auth_layer<T: GetProfileId + std::fmt::De
         (None, None) | (None, Some(_)) => Ok(()),
     }
 }
+
+/// Returns the error code and message with which the error is returned by the API, for recording
+/// the errors of records processed in the background
+pub fn get_error_code_and_message(error: &errors::ApiErrorResponse) -> (String, String) {
+    let api_error: api_models::errors::types::ApiErrorResponse =
+        common_utils::errors::ErrorSwitch::switch(error);
+    let error_info = api_error.get_internal_error();
+    (
+        format!("{}_{:02}", error_info.sub_code, error_info.error_identifier),
+        error_info.error_message.clone(),
+    )
+}
+
+/// Schedules the recurring process tracker task with the provided id to run on the provided cron
+/// schedule. A task which was revoked earlier is put back on its schedule, and a task which is
+/// already scheduled is left as is.
+pub async fn schedule_recurring_process<T>(
+    state: &SessionState,
+    process_tracker_id: String,
+    task: &str,
+    runner: storage::ProcessTrackerRunner,
+    tag: impl IntoIterator<Item = impl Into<String>>,
+    tracking_data: T,
+    schedule: &str,
+) -> RouterResult<storage::ProcessTracker>
+where
+    T: serde::Serialize + std::fmt::Debug,
+{
+    let now = common_utils::date_time::now();
+    let next_run_at = common_utils::cron::CronSchedule::parse(schedule)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid schedule of recurring process tracker task")?
+        .next_occurrence_after(now)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable(
+            "Schedule of recurring process tracker task has no upcoming occurrence",
+        )?;
+
+    let process = state
+        .store
+        .find_process_by_id(&process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch recurring process tracker task")?;
+
+    match process {
+        Some(process) if process.status != enums::ProcessTrackerStatus::Finish => Ok(process),
+        Some(process) => state
+            .store
+            .update_process(
+                process,
+                storage::ProcessTrackerUpdate::Update {
+                    name: None,
+                    retry_count: Some(0),
+                    schedule_time: Some(next_run_at),
+                    tracking_data: None,
+                    business_status: Some(String::from(storage::business_status::PENDING)),
+                    status: Some(enums::ProcessTrackerStatus::New),
+                    updated_at: Some(now),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to reschedule recurring process tracker task"),
+        None => {
+            let process_tracker_entry = storage::ProcessTrackerNew::new(
+                process_tracker_id,
+                task,
+                runner,
+                tag,
+                tracking_data,
+                next_run_at,
+            )
+            .and_then(|process_tracker_entry| process_tracker_entry.with_cron_expression(schedule))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct recurring process tracker task")?;
+
+            state
+                .store
+                .insert_process(process_tracker_entry)
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert recurring process tracker task")
+        }
+    }
+}
+
+/// Revokes the recurring process tracker task with the provided id, so that it is not run again.
+/// A task which is not scheduled is left as is.
+pub async fn revoke_recurring_process(
+    state: &SessionState,
+    process_tracker_id: &str,
+) -> RouterResult<()> {
+    let process = state
+        .store
+        .find_process_by_id(process_tracker_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch recurring process tracker task")?;
+
+    if let Some(process) =
+        process.filter(|process| process.status != enums::ProcessTrackerStatus::Finish)
+    {
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::REVOKED)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to revoke recurring process tracker task")?;
+    }
+
+    Ok(())
+}
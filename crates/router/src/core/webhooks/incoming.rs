@@ -21,7 +21,7 @@ use super::{types, utils, MERCHANT_ID};
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse, StorageErrorExt},
         metrics, payments, refunds, utils as core_utils,
         webhooks::utils::construct_webhook_router_data,
@@ -907,6 +907,7 @@ async fn get_or_update_dispute_object(
     event_type: webhooks::IncomingWebhookEvent,
     business_profile: &domain::BusinessProfile,
     connector_name: &str,
+    storage_scheme: enums::MerchantStorageScheme,
 ) -> CustomResult<diesel_models::dispute::Dispute, errors::ApiErrorResponse> {
     let db = &*state.store;
     match option_dispute {
@@ -940,7 +941,7 @@ async fn get_or_update_dispute_object(
             };
             state
                 .store
-                .insert_dispute(new_dispute.clone())
+                .insert_dispute(new_dispute.clone(), storage_scheme)
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
         }
@@ -967,7 +968,7 @@ async fn get_or_update_dispute_object(
                 challenge_required_by: dispute_details.challenge_required_by,
                 connector_updated_at: dispute_details.updated_at,
             };
-            db.update_dispute(dispute, update_dispute)
+            db.update_dispute(dispute, update_dispute, storage_scheme)
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)
         }
@@ -1012,6 +1013,7 @@ async fn external_authentication_incoming_webhook_flow(
                         .find_authentication_by_merchant_id_authentication_id(
                             merchant_account.get_id(),
                             authentication_id.clone(),
+                            merchant_account.storage_scheme,
                         )
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
@@ -1025,6 +1027,7 @@ async fn external_authentication_incoming_webhook_flow(
                         .find_authentication_by_merchant_id_connector_authentication_id(
                             merchant_account.get_id().clone(),
                             connector_authentication_id.clone(),
+                            merchant_account.storage_scheme,
                         )
                         .await
                         .to_not_found_response(errors::ApiErrorResponse::AuthenticationNotFound {
@@ -1042,6 +1045,7 @@ async fn external_authentication_incoming_webhook_flow(
             .update_authentication_by_merchant_id_authentication_id(
                 authentication,
                 authentication_update,
+                merchant_account.storage_scheme,
             )
             .await
             .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -1371,9 +1375,12 @@ async fn disputes_incoming_webhook_flow(
                 merchant_account.get_id(),
                 &payment_attempt.payment_id,
                 &dispute_details.connector_dispute_id,
+                merchant_account.storage_scheme,
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        let is_new_pre_dispute = option_dispute.is_none()
+            && dispute_details.dispute_stage == enums::DisputeStage::PreDispute;
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute,
@@ -1384,8 +1391,22 @@ async fn disputes_incoming_webhook_flow(
             event_type,
             &business_profile,
             connector.id(),
+            merchant_account.storage_scheme,
         )
         .await?;
+        let dispute_object = if is_new_pre_dispute {
+            let (dispute_object, _refund) = disputes::apply_pre_dispute_alert_rule(
+                &state,
+                &merchant_account,
+                &key_store,
+                dispute_object,
+                None,
+            )
+            .await?;
+            dispute_object
+        } else {
+            dispute_object
+        };
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
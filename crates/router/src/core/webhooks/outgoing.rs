@@ -10,7 +10,7 @@ use common_utils::{
     type_name,
     types::keymanager::{Identifier, KeyManagerState},
 };
-use diesel_models::process_tracker::business_status;
+use diesel_models::process_tracker::{business_status, priority};
 use error_stack::{report, ResultExt};
 use hyperswitch_domain_models::type_encryption::{crypto_operation, CryptoOperation};
 use masking::{ExposeInterface, Mask, PeekInterface, Secret};
@@ -558,7 +558,8 @@ pub(crate) async fn add_outgoing_webhook_retry_task_to_process_tracker(
         tracking_data,
         schedule_time,
     )
-    .map_err(errors::StorageError::from)?;
+    .map_err(errors::StorageError::from)?
+    .with_priority(priority::HIGH);
 
     match db.insert_process(process_tracker_entry).await {
         Ok(process_tracker) => {
@@ -928,6 +929,11 @@ impl ForeignFrom<&api::OutgoingWebhookContent> for storage::EventMetadata {
             webhooks::OutgoingWebhookContent::PayoutDetails(payout_response) => Self::Payout {
                 payout_id: payout_response.payout_id.clone(),
             },
+            webhooks::OutgoingWebhookContent::PaymentMethodDetails(payment_method_response) => {
+                Self::PaymentMethod {
+                    payment_method_id: payment_method_response.payment_method_id.clone(),
+                }
+            }
         }
     }
 }
@@ -972,5 +978,11 @@ fn get_outgoing_webhook_event_content_from_event_metadata(
             mandate_id,
             content: serde_json::Value::Null,
         },
+        diesel_models::EventMetadata::PaymentMethod { payment_method_id } => {
+            OutgoingWebhookEventContent::PaymentMethod {
+                payment_method_id,
+                content: serde_json::Value::Null,
+            }
+        }
     })
 }
@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod authentication;
@@ -7,10 +8,13 @@ pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_vault;
 pub mod cards_info;
 pub mod configs;
+pub mod customer_address_label;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod ephemeral_key;
 pub mod events;
@@ -28,6 +32,8 @@ pub mod merchant_key_store;
 pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration;
+pub mod payout_batch;
 pub mod refund;
 pub mod reverse_lookup;
 pub mod role;
@@ -39,6 +45,7 @@ pub mod user_key_store;
 pub mod user_role;
 use common_utils::id_type;
 use diesel_models::{
+    enums::MerchantStorageScheme,
     fraud_check::{FraudCheck, FraudCheckUpdate},
     organization::{Organization, OrganizationNew, OrganizationUpdate},
 };
@@ -85,13 +92,17 @@ pub trait StorageInterface:
     Send
     + Sync
     + dyn_clone::DynClone
+    + account_updater::AccountUpdaterItemInterface
     + address::AddressInterface
     + api_keys::ApiKeyInterface
     + blocklist_lookup::BlocklistLookupInterface
     + configs::ConfigInterface
     + capture::CaptureInterface
+    + card_vault::CardVaultInterface
+    + customer_address_label::CustomerAddressLabelInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
+    + data_subject_request::DataSubjectRequestInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
@@ -105,6 +116,8 @@ pub trait StorageInterface:
     + PaymentAttemptInterface
     + PaymentIntentInterface
     + payment_method::PaymentMethodInterface
+    + payment_method_migration::PaymentMethodMigrationInterface
+    + payout_batch::PayoutBatchInterface
     + blocklist::BlocklistInterface
     + blocklist_fingerprint::BlocklistFingerprintInterface
     + scheduler::SchedulerInterface
@@ -222,6 +235,7 @@ impl CommonStorageInterface for Store {
 
 pub trait RequestIdStore {
     fn add_request_id(&mut self, _request_id: String) {}
+    fn add_merchant_id(&mut self, _merchant_id: &common_utils::id_type::MerchantId) {}
     fn get_request_id(&self) -> Option<String> {
         None
     }
@@ -231,9 +245,16 @@ impl RequestIdStore for MockDb {}
 
 impl RequestIdStore for Store {
     fn add_request_id(&mut self, request_id: String) {
+        // Writes made by other requests on the shared store do not affect this request
+        self.reset_consistency_token();
         self.request_id = Some(request_id)
     }
 
+    fn add_merchant_id(&mut self, merchant_id: &common_utils::id_type::MerchantId) {
+        // Reads made for the merchant after its writes are not routed to a lagging replica
+        self.set_consistency_token(merchant_id)
+    }
+
     fn get_request_id(&self) -> Option<String> {
         self.request_id.clone()
     }
@@ -262,6 +283,10 @@ impl RequestIdStore for KafkaStore {
     fn add_request_id(&mut self, request_id: String) {
         self.diesel_store.add_request_id(request_id)
     }
+
+    fn add_merchant_id(&mut self, merchant_id: &common_utils::id_type::MerchantId) {
+        self.diesel_store.add_merchant_id(merchant_id)
+    }
 }
 
 #[async_trait::async_trait]
@@ -269,8 +294,12 @@ impl FraudCheckInterface for KafkaStore {
     async fn insert_fraud_check_response(
         &self,
         new: storage::FraudCheckNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, StorageError> {
-        let frm = self.diesel_store.insert_fraud_check_response(new).await?;
+        let frm = self
+            .diesel_store
+            .insert_fraud_check_response(new, storage_scheme)
+            .await?;
         if let Err(er) = self
             .kafka_producer
             .log_fraud_check(&frm, None, self.tenant_id.clone())
@@ -284,10 +313,11 @@ impl FraudCheckInterface for KafkaStore {
         &self,
         this: FraudCheck,
         fraud_check: FraudCheckUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, StorageError> {
         let frm = self
             .diesel_store
-            .update_fraud_check_response_with_attempt_id(this, fraud_check)
+            .update_fraud_check_response_with_attempt_id(this, fraud_check, storage_scheme)
             .await?;
         if let Err(er) = self
             .kafka_producer
@@ -302,10 +332,11 @@ impl FraudCheckInterface for KafkaStore {
         &self,
         payment_id: id_type::PaymentId,
         merchant_id: id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<FraudCheck, StorageError> {
         let frm = self
             .diesel_store
-            .find_fraud_check_by_payment_id(payment_id, merchant_id)
+            .find_fraud_check_by_payment_id(payment_id, merchant_id, storage_scheme)
             .await?;
         if let Err(er) = self
             .kafka_producer
@@ -320,10 +351,11 @@ impl FraudCheckInterface for KafkaStore {
         &self,
         payment_id: id_type::PaymentId,
         merchant_id: id_type::MerchantId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<FraudCheck>, StorageError> {
         let frm = self
             .diesel_store
-            .find_fraud_check_by_payment_id_if_present(payment_id, merchant_id)
+            .find_fraud_check_by_payment_id_if_present(payment_id, merchant_id, storage_scheme)
             .await?;
 
         if let Some(fraud_check) = frm.clone() {
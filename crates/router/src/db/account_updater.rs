@@ -0,0 +1,218 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait AccountUpdaterItemInterface {
+    async fn insert_account_updater_item(
+        &self,
+        account_updater_item: storage::AccountUpdaterItemNew,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError>;
+
+    async fn update_account_updater_item(
+        &self,
+        this: storage::AccountUpdaterItem,
+        account_updater_item_update: storage::AccountUpdaterItemUpdate,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError>;
+
+    async fn find_account_updater_items_by_merchant_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: enums::AccountUpdaterItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError>;
+
+    async fn find_account_updater_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError>;
+
+    async fn find_account_updater_items_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterItemInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_account_updater_item(
+        &self,
+        account_updater_item: storage::AccountUpdaterItemNew,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        account_updater_item
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_account_updater_item(
+        &self,
+        this: storage::AccountUpdaterItem,
+        account_updater_item_update: storage::AccountUpdaterItemUpdate,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, account_updater_item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_items_by_merchant_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: enums::AccountUpdaterItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        // The items are read from the master database, as the items which were just submitted
+        // must not be picked up again
+        let conn = connection::pg_connection_write(self).await?;
+        storage::AccountUpdaterItem::find_by_merchant_id_status(&conn, merchant_id, status, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterItem::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_account_updater_items_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AccountUpdaterItem::find_by_merchant_id_payment_method_id(
+            &conn,
+            merchant_id,
+            payment_method_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterItemInterface for MockDb {
+    async fn insert_account_updater_item(
+        &self,
+        _account_updater_item: storage::AccountUpdaterItemNew,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_account_updater_item(
+        &self,
+        _this: storage::AccountUpdaterItem,
+        _account_updater_item_update: storage::AccountUpdaterItemUpdate,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_account_updater_items_by_merchant_id_status(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _status: enums::AccountUpdaterItemStatus,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_account_updater_items_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_account_updater_items_by_merchant_id_payment_method_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl AccountUpdaterItemInterface for KafkaStore {
+    async fn insert_account_updater_item(
+        &self,
+        account_updater_item: storage::AccountUpdaterItemNew,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError> {
+        self.diesel_store
+            .insert_account_updater_item(account_updater_item)
+            .await
+    }
+
+    async fn update_account_updater_item(
+        &self,
+        this: storage::AccountUpdaterItem,
+        account_updater_item_update: storage::AccountUpdaterItemUpdate,
+    ) -> CustomResult<storage::AccountUpdaterItem, errors::StorageError> {
+        self.diesel_store
+            .update_account_updater_item(this, account_updater_item_update)
+            .await
+    }
+
+    async fn find_account_updater_items_by_merchant_id_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        status: enums::AccountUpdaterItemStatus,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        self.diesel_store
+            .find_account_updater_items_by_merchant_id_status(merchant_id, status, limit)
+            .await
+    }
+
+    async fn find_account_updater_items_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        self.diesel_store
+            .find_account_updater_items_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn find_account_updater_items_by_merchant_id_payment_method_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        payment_method_id: &str,
+    ) -> CustomResult<Vec<storage::AccountUpdaterItem>, errors::StorageError> {
+        self.diesel_store
+            .find_account_updater_items_by_merchant_id_payment_method_id(
+                merchant_id,
+                payment_method_id,
+            )
+            .await
+    }
+}
@@ -109,7 +109,7 @@ mod storage {
             address_id: &str,
             key_store: &domain::MerchantKeyStore,
         ) -> CustomResult<domain::Address, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "address").await?;
             storage_types::Address::find_by_address_id(&conn, address_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -136,7 +136,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<domain::PaymentAddress, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "address").await?;
             storage_types::Address::find_by_merchant_id_payment_id_address_id(
                 &conn,
                 merchant_id,
@@ -337,7 +337,7 @@ mod storage {
             address_id: &str,
             key_store: &domain::MerchantKeyStore,
         ) -> CustomResult<domain::Address, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "address").await?;
             storage_types::Address::find_by_address_id(&conn, address_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -364,7 +364,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<domain::PaymentAddress, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "address").await?;
             let database_call = || async {
                 storage_types::Address::find_by_merchant_id_payment_id_address_id(
                     &conn,
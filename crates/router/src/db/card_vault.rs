@@ -0,0 +1,495 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CardVaultInterface {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn find_active_vault_data_key_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError>;
+
+    async fn list_vault_data_keys_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::VaultDataKey>, errors::StorageError>;
+
+    async fn update_vault_data_key(
+        &self,
+        this: storage::VaultDataKey,
+        data_key_update: storage::VaultDataKeyUpdate,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError>;
+
+    async fn insert_vault_entry(
+        &self,
+        entry: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError>;
+
+    async fn find_vault_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError>;
+
+    async fn find_vault_entry_by_fingerprint(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError>;
+
+    async fn find_vault_entries_by_key_id(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultEntry>, errors::StorageError>;
+
+    async fn update_vault_entry(
+        &self,
+        this: storage::VaultEntry,
+        entry_update: storage::VaultEntryUpdate,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError>;
+
+    async fn delete_vault_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError>;
+
+    async fn insert_vault_access_log(
+        &self,
+        access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError>;
+
+    async fn list_vault_access_logs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_key
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultDataKey::find_by_key_id(&conn, key_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_active_vault_data_key_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        // Storing data with a key retired by a concurrent rotation would leave the data unreadable
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultDataKey::find_optional_active_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_vault_data_keys_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::VaultDataKey>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultDataKey::list_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_vault_data_key(
+        &self,
+        this: storage::VaultDataKey,
+        data_key_update: storage::VaultDataKeyUpdate,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, data_key_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_vault_entry(
+        &self,
+        entry: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        entry
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_vault_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultEntry::find_by_merchant_id_entry_id(&conn, merchant_id, entry_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_vault_entry_by_fingerprint(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultEntry::find_optional_by_fingerprint(
+            &conn,
+            merchant_id,
+            customer_id,
+            fingerprint,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_vault_entries_by_key_id(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultEntry>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultEntry::find_by_key_id(&conn, key_id, limit)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_vault_entry(
+        &self,
+        this: storage::VaultEntry,
+        entry_update: storage::VaultEntryUpdate,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, entry_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_vault_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::VaultEntry::delete_by_merchant_id_entry_id(&conn, merchant_id, entry_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_vault_access_log(
+        &self,
+        access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        access_log
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_vault_access_logs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::VaultAccessLog::list_by_merchant_id(&conn, merchant_id, entry_id, limit, offset)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for MockDb {
+    async fn insert_vault_data_key(
+        &self,
+        _data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        _key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_active_vault_data_key_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_vault_data_keys_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::VaultDataKey>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_vault_data_key(
+        &self,
+        _this: storage::VaultDataKey,
+        _data_key_update: storage::VaultDataKeyUpdate,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_vault_entry(
+        &self,
+        _entry: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_entry_by_merchant_id_entry_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _entry_id: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_entry_by_fingerprint(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_vault_entries_by_key_id(
+        &self,
+        _key_id: &str,
+        _limit: i64,
+    ) -> CustomResult<Vec<storage::VaultEntry>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_vault_entry(
+        &self,
+        _this: storage::VaultEntry,
+        _entry_update: storage::VaultEntryUpdate,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_vault_entry_by_merchant_id_entry_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_vault_access_log(
+        &self,
+        _access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_vault_access_logs_by_merchant_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _entry_id: Option<&str>,
+        _limit: i64,
+        _offset: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessLog>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CardVaultInterface for KafkaStore {
+    async fn insert_vault_data_key(
+        &self,
+        data_key: storage::VaultDataKeyNew,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        self.diesel_store.insert_vault_data_key(data_key).await
+    }
+
+    async fn find_vault_data_key_by_key_id(
+        &self,
+        key_id: &str,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        self.diesel_store
+            .find_vault_data_key_by_key_id(key_id)
+            .await
+    }
+
+    async fn find_active_vault_data_key_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Option<storage::VaultDataKey>, errors::StorageError> {
+        self.diesel_store
+            .find_active_vault_data_key_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn list_vault_data_keys_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+    ) -> CustomResult<Vec<storage::VaultDataKey>, errors::StorageError> {
+        self.diesel_store
+            .list_vault_data_keys_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_vault_data_key(
+        &self,
+        this: storage::VaultDataKey,
+        data_key_update: storage::VaultDataKeyUpdate,
+    ) -> CustomResult<storage::VaultDataKey, errors::StorageError> {
+        self.diesel_store
+            .update_vault_data_key(this, data_key_update)
+            .await
+    }
+
+    async fn insert_vault_entry(
+        &self,
+        entry: storage::VaultEntryNew,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        self.diesel_store.insert_vault_entry(entry).await
+    }
+
+    async fn find_vault_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        self.diesel_store
+            .find_vault_entry_by_merchant_id_entry_id(merchant_id, entry_id)
+            .await
+    }
+
+    async fn find_vault_entry_by_fingerprint(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        fingerprint: &str,
+    ) -> CustomResult<Option<storage::VaultEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_vault_entry_by_fingerprint(merchant_id, customer_id, fingerprint)
+            .await
+    }
+
+    async fn find_vault_entries_by_key_id(
+        &self,
+        key_id: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::VaultEntry>, errors::StorageError> {
+        self.diesel_store
+            .find_vault_entries_by_key_id(key_id, limit)
+            .await
+    }
+
+    async fn update_vault_entry(
+        &self,
+        this: storage::VaultEntry,
+        entry_update: storage::VaultEntryUpdate,
+    ) -> CustomResult<storage::VaultEntry, errors::StorageError> {
+        self.diesel_store
+            .update_vault_entry(this, entry_update)
+            .await
+    }
+
+    async fn delete_vault_entry_by_merchant_id_entry_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: &str,
+    ) -> CustomResult<bool, errors::StorageError> {
+        self.diesel_store
+            .delete_vault_entry_by_merchant_id_entry_id(merchant_id, entry_id)
+            .await
+    }
+
+    async fn insert_vault_access_log(
+        &self,
+        access_log: storage::VaultAccessLogNew,
+    ) -> CustomResult<storage::VaultAccessLog, errors::StorageError> {
+        self.diesel_store.insert_vault_access_log(access_log).await
+    }
+
+    async fn list_vault_access_logs_by_merchant_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        entry_id: Option<&str>,
+        limit: i64,
+        offset: i64,
+    ) -> CustomResult<Vec<storage::VaultAccessLog>, errors::StorageError> {
+        self.diesel_store
+            .list_vault_access_logs_by_merchant_id(merchant_id, entry_id, limit, offset)
+            .await
+    }
+}
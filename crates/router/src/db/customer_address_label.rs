@@ -0,0 +1,235 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait CustomerAddressLabelInterface {
+    async fn insert_customer_address_label(
+        &self,
+        customer_address_label: storage::CustomerAddressLabelNew,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError>;
+
+    async fn find_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError>;
+
+    async fn list_customer_address_labels_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerAddressLabel>, errors::StorageError>;
+
+    async fn update_customer_address_label(
+        &self,
+        this: storage::CustomerAddressLabel,
+        customer_address_label_update: storage::CustomerAddressLabelUpdate,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError>;
+
+    async fn delete_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl CustomerAddressLabelInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_customer_address_label(
+        &self,
+        customer_address_label: storage::CustomerAddressLabelNew,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        customer_address_label
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerAddressLabel::find_by_merchant_id_customer_id_address_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            address_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn list_customer_address_labels_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerAddressLabel>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::CustomerAddressLabel::list_by_merchant_id_customer_id(
+            &conn,
+            merchant_id,
+            customer_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_customer_address_label(
+        &self,
+        this: storage::CustomerAddressLabel,
+        customer_address_label_update: storage::CustomerAddressLabelUpdate,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, customer_address_label_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn delete_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::CustomerAddressLabel::delete_by_merchant_id_customer_id_address_id(
+            &conn,
+            merchant_id,
+            customer_id,
+            address_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerAddressLabelInterface for MockDb {
+    async fn insert_customer_address_label(
+        &self,
+        _customer_address_label: storage::CustomerAddressLabelNew,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn list_customer_address_labels_by_merchant_id_customer_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerAddressLabel>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_customer_address_label(
+        &self,
+        _this: storage::CustomerAddressLabel,
+        _customer_address_label_update: storage::CustomerAddressLabelUpdate,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn delete_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _customer_id: &common_utils::id_type::CustomerId,
+        _address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl CustomerAddressLabelInterface for KafkaStore {
+    async fn insert_customer_address_label(
+        &self,
+        customer_address_label: storage::CustomerAddressLabelNew,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        self.diesel_store
+            .insert_customer_address_label(customer_address_label)
+            .await
+    }
+
+    async fn find_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        self.diesel_store
+            .find_customer_address_label_by_merchant_id_customer_id_address_id(
+                merchant_id,
+                customer_id,
+                address_id,
+            )
+            .await
+    }
+
+    async fn list_customer_address_labels_by_merchant_id_customer_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+    ) -> CustomResult<Vec<storage::CustomerAddressLabel>, errors::StorageError> {
+        self.diesel_store
+            .list_customer_address_labels_by_merchant_id_customer_id(merchant_id, customer_id)
+            .await
+    }
+
+    async fn update_customer_address_label(
+        &self,
+        this: storage::CustomerAddressLabel,
+        customer_address_label_update: storage::CustomerAddressLabelUpdate,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        self.diesel_store
+            .update_customer_address_label(this, customer_address_label_update)
+            .await
+    }
+
+    async fn delete_customer_address_label_by_merchant_id_customer_id_address_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        customer_id: &common_utils::id_type::CustomerId,
+        address_id: &str,
+    ) -> CustomResult<storage::CustomerAddressLabel, errors::StorageError> {
+        self.diesel_store
+            .delete_customer_address_label_by_merchant_id_customer_id_address_id(
+                merchant_id,
+                customer_id,
+                address_id,
+            )
+            .await
+    }
+}
@@ -192,7 +192,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Option<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let database_call = || async {
                 storage_types::Customer::find_optional_by_customer_id_merchant_id(
                     &conn,
@@ -263,7 +263,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Option<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let database_call = || async {
                 storage_types::Customer::find_optional_by_customer_id_merchant_id(
                     &conn,
@@ -328,7 +328,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Option<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let database_call = || async {
                 storage_types::Customer::find_optional_by_merchant_id_merchant_reference_id(
                     &conn,
@@ -481,7 +481,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<customer::Customer, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let database_call = || async {
                 storage_types::Customer::find_by_merchant_reference_id_merchant_id(
                     &conn,
@@ -546,7 +546,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<customer::Customer, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let database_call = || async {
                 storage_types::Customer::find_by_customer_id_merchant_id(
                     &conn,
@@ -609,7 +609,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             constraints: super::CustomerListConstraints,
         ) -> CustomResult<Vec<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
 
             let customer_list_constraints =
                 diesel_models::query::customers::CustomerListConstraints::from(constraints);
@@ -820,7 +820,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<customer::Customer, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let database_call = || async {
                 storage_types::Customer::find_by_global_id(&conn, id)
                     .await
@@ -987,7 +987,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Option<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let maybe_customer: Option<customer::Customer> =
                 storage_types::Customer::find_optional_by_customer_id_merchant_id(
                     &conn,
@@ -1025,7 +1025,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Option<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let maybe_customer: Option<customer::Customer> =
                 storage_types::Customer::find_optional_by_customer_id_merchant_id(
                     &conn,
@@ -1055,7 +1055,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Option<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let maybe_customer: Option<customer::Customer> =
                 storage_types::Customer::find_optional_by_customer_id_merchant_id(
                     &conn,
@@ -1122,7 +1122,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<customer::Customer, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let customer: customer::Customer =
                 storage_types::Customer::find_by_customer_id_merchant_id(
                     &conn,
@@ -1155,7 +1155,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<customer::Customer, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let customer: customer::Customer =
                 storage_types::Customer::find_by_merchant_reference_id_merchant_id(
                     &conn,
@@ -1186,7 +1186,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             constraints: super::CustomerListConstraints,
         ) -> CustomResult<Vec<customer::Customer>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
 
             let customer_list_constraints =
                 diesel_models::query::customers::CustomerListConstraints::from(constraints);
@@ -1291,7 +1291,7 @@ mod storage {
             key_store: &domain::MerchantKeyStore,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<customer::Customer, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "customers").await?;
             let customer: customer::Customer =
                 storage_types::Customer::find_by_global_id(&conn, customer_id, merchant_id)
                     .await
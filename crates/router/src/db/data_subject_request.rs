@@ -0,0 +1,131 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait DataSubjectRequestInterface {
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError>;
+
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError>;
+
+    async fn update_data_subject_request(
+        &self,
+        this: storage::DataSubjectRequest,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl DataSubjectRequestInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        data_subject_request
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::DataSubjectRequest::find_by_merchant_id_request_id(&conn, merchant_id, request_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_data_subject_request(
+        &self,
+        this: storage::DataSubjectRequest,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, data_subject_request_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl DataSubjectRequestInterface for MockDb {
+    async fn insert_data_subject_request(
+        &self,
+        _data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_data_subject_request(
+        &self,
+        _this: storage::DataSubjectRequest,
+        _data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl DataSubjectRequestInterface for KafkaStore {
+    async fn insert_data_subject_request(
+        &self,
+        data_subject_request: storage::DataSubjectRequestNew,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.diesel_store
+            .insert_data_subject_request(data_subject_request)
+            .await
+    }
+
+    async fn find_data_subject_request_by_merchant_id_request_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        request_id: &str,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.diesel_store
+            .find_data_subject_request_by_merchant_id_request_id(merchant_id, request_id)
+            .await
+    }
+
+    async fn update_data_subject_request(
+        &self,
+        this: storage::DataSubjectRequest,
+        data_subject_request_update: storage::DataSubjectRequestUpdate,
+    ) -> CustomResult<storage::DataSubjectRequest, errors::StorageError> {
+        self.diesel_store
+            .update_data_subject_request(this, data_subject_request_update)
+            .await
+    }
+}
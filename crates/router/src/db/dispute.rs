@@ -1,130 +1,473 @@
-use error_stack::report;
-use router_env::{instrument, tracing};
-
-use super::{MockDb, Store};
+use super::MockDb;
 use crate::{
-    connection,
     core::errors::{self, CustomResult},
-    types::storage::{self, DisputeDbExt},
+    types::storage::{self as storage_types, enums},
 };
 
 #[async_trait::async_trait]
 pub trait DisputeInterface {
     async fn insert_dispute(
         &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        dispute: storage_types::DisputeNew,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 
     async fn find_by_merchant_id_payment_id_connector_dispute_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
         connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError>;
 
     async fn find_dispute_by_merchant_id_dispute_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 
     async fn find_disputes_by_merchant_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError>;
 
     async fn find_disputes_by_merchant_id_payment_id(
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError>;
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError>;
 
     async fn update_dispute(
         &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError>;
+        this: storage_types::Dispute,
+        dispute: storage_types::DisputeUpdate,
+        storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError>;
 }
 
-#[async_trait::async_trait]
-impl DisputeInterface for Store {
-    #[instrument(skip_all)]
-    async fn insert_dispute(
-        &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        dispute
-            .insert(&conn)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+#[cfg(feature = "kv_store")]
+mod storage {
+    use common_utils::fallback_reverse_lookup_not_found;
+    use error_stack::{report, ResultExt};
+    use redis_interface::HsetnxReply;
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::{
+        decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey,
+    };
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, utils::RedisErrorExt, CustomResult},
+        db::reverse_lookup::ReverseLookupInterface,
+        services::Store,
+        types::storage::{self as storage_types, enums, kv, DisputeDbExt},
+        utils::db_utils,
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_dispute(
+            &self,
+            dispute: storage_types::DisputeNew,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let storage_scheme = decide_storage_scheme::<_, storage_types::Dispute>(
+                self,
+                storage_scheme,
+                Op::Insert,
+            )
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    dispute
+                        .insert(&conn)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let created_dispute = storage_types::Dispute::from(&dispute);
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id: &created_dispute.merchant_id,
+                        payment_id: &created_dispute.payment_id,
+                    };
+                    let key_str = key.to_string();
+                    let field = format!(
+                        "pa_{}_dis_{}",
+                        created_dispute.attempt_id, created_dispute.dispute_id
+                    );
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Insert {
+                            insertable: kv::Insertable::Dispute(dispute),
+                        },
+                    };
+
+                    let reverse_lookup = storage_types::ReverseLookupNew {
+                        sk_id: field.clone(),
+                        lookup_id: format!(
+                            "dis_dis_id_{}_{}",
+                            created_dispute.merchant_id.get_string_repr(),
+                            created_dispute.dispute_id
+                        ),
+                        pk_id: key_str.clone(),
+                        source: "dispute".to_string(),
+                        updated_by: storage_scheme.to_string(),
+                    };
+                    self.insert_reverse_lookup(reverse_lookup, storage_scheme)
+                        .await?;
+
+                    match kv_wrapper::<storage_types::Dispute, _, _>(
+                        self,
+                        KvOperation::<storage_types::Dispute>::HSetNx(
+                            &field,
+                            &created_dispute,
+                            redis_entry,
+                        ),
+                        key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hsetnx()
+                    {
+                        Ok(HsetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                            entity: "dispute",
+                            key: Some(created_dispute.dispute_id),
+                        }
+                        .into()),
+                        Ok(HsetnxReply::KeySet) => Ok(created_dispute),
+                        Err(er) => Err(er).change_context(errors::StorageError::KVError),
+                    }
+                }
+            }
+        }
 
-    #[instrument(skip_all)]
-    async fn find_by_merchant_id_payment_id_connector_dispute_id(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        payment_id: &common_utils::id_type::PaymentId,
-        connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
-            &conn,
-            merchant_id,
-            payment_id,
-            connector_dispute_id,
-        )
-        .await
-        .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        #[instrument(skip_all)]
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            connector_dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read_table(self, "dispute").await?;
+                storage_types::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                    connector_dispute_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme =
+                decide_storage_scheme::<_, storage_types::Dispute>(self, storage_scheme, Op::Find)
+                    .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    };
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Dispute>::Scan("pa_*_dis_*"),
+                                key,
+                            )
+                            .await?
+                            .try_into_scan()?
+                            .into_iter()
+                            .find(|dispute: &storage_types::Dispute| {
+                                dispute.connector_dispute_id == connector_dispute_id
+                            })
+                            .map(Some)
+                            .ok_or_else(|| report!(redis_interface::errors::RedisError::NotFound))
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
 
-    #[instrument(skip_all)]
-    async fn find_dispute_by_merchant_id_dispute_id(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        #[instrument(skip_all)]
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_id: &str,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read_table(self, "dispute").await?;
+                storage_types::Dispute::find_by_merchant_id_dispute_id(
+                    &conn,
+                    merchant_id,
+                    dispute_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme =
+                decide_storage_scheme::<_, storage_types::Dispute>(self, storage_scheme, Op::Find)
+                    .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let lookup_id =
+                        format!("dis_dis_id_{}_{dispute_id}", merchant_id.get_string_repr());
+                    let lookup = fallback_reverse_lookup_not_found!(
+                        self.get_lookup_by_lookup_id(&lookup_id, storage_scheme)
+                            .await,
+                        database_call().await
+                    );
+
+                    let key = PartitionKey::CombinationKey {
+                        combination: &lookup.pk_id,
+                    };
+                    Box::pin(db_utils::try_redis_get_else_try_database_get(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Dispute>::HGet(&lookup.sk_id),
+                                key,
+                            )
+                            .await?
+                            .try_into_hget()
+                        },
+                        database_call,
+                    ))
+                    .await
+                }
+            }
+        }
 
-    #[instrument(skip_all)]
-    async fn find_disputes_by_merchant_id(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
-    }
+        #[instrument(skip_all)]
+        async fn find_disputes_by_merchant_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_constraints: api_models::disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "dispute").await?;
+            storage_types::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
 
-    #[instrument(skip_all)]
-    async fn find_disputes_by_merchant_id_payment_id(
-        &self,
-        merchant_id: &common_utils::id_type::MerchantId,
-        payment_id: &common_utils::id_type::PaymentId,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
-        let conn = connection::pg_connection_read(self).await?;
-        storage::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
-            .await
-            .map_err(|error| report!(errors::StorageError::from(error)))
+        #[instrument(skip_all)]
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let database_call = || async {
+                let conn = connection::pg_connection_read_table(self, "dispute").await?;
+                storage_types::Dispute::find_by_merchant_id_payment_id(
+                    &conn,
+                    merchant_id,
+                    payment_id,
+                )
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+            };
+            let storage_scheme =
+                decide_storage_scheme::<_, storage_types::Dispute>(self, storage_scheme, Op::Find)
+                    .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => database_call().await,
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key = PartitionKey::MerchantIdPaymentId {
+                        merchant_id,
+                        payment_id,
+                    };
+                    Box::pin(db_utils::find_all_combined_kv_database(
+                        async {
+                            kv_wrapper(
+                                self,
+                                KvOperation::<storage_types::Dispute>::Scan("pa_*_dis_*"),
+                                key,
+                            )
+                            .await?
+                            .try_into_scan()
+                        },
+                        database_call,
+                        None,
+                    ))
+                    .await
+                }
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn update_dispute(
+            &self,
+            this: storage_types::Dispute,
+            dispute: storage_types::DisputeUpdate,
+            storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let merchant_id = this.merchant_id.clone();
+            let payment_id = this.payment_id.clone();
+            let key = PartitionKey::MerchantIdPaymentId {
+                merchant_id: &merchant_id,
+                payment_id: &payment_id,
+            };
+            let field = format!("pa_{}_dis_{}", this.attempt_id, this.dispute_id);
+            let updated_by = storage_scheme.to_string();
+            let storage_scheme = decide_storage_scheme::<_, storage_types::Dispute>(
+                self,
+                storage_scheme,
+                Op::Update(key.clone(), &field, Some(&updated_by)),
+            )
+            .await;
+            match storage_scheme {
+                enums::MerchantStorageScheme::PostgresOnly => {
+                    let conn = connection::pg_connection_write(self).await?;
+                    this.update(&conn, dispute)
+                        .await
+                        .map_err(|error| report!(errors::StorageError::from(error)))
+                }
+                enums::MerchantStorageScheme::RedisKv => {
+                    let key_str = key.to_string();
+                    let updated_dispute = dispute.clone().apply_changeset(this.clone());
+
+                    let redis_value = serde_json::to_string(&updated_dispute)
+                        .change_context(errors::StorageError::SerializationFailed)?;
+
+                    let redis_entry = kv::TypedSql {
+                        op: kv::DBOperation::Update {
+                            updatable: kv::Updateable::DisputeUpdate(kv::DisputeUpdateMems {
+                                orig: this,
+                                update_data: dispute,
+                            }),
+                        },
+                    };
+
+                    kv_wrapper::<(), _, _>(
+                        self,
+                        KvOperation::<storage_types::Dispute>::Hset(
+                            (&field, redis_value),
+                            redis_entry,
+                        ),
+                        key,
+                    )
+                    .await
+                    .map_err(|err| err.to_redis_failed_response(&key_str))?
+                    .try_into_hset()
+                    .change_context(errors::StorageError::KVError)?;
+
+                    Ok(updated_dispute)
+                }
+            }
+        }
     }
+}
 
-    #[instrument(skip_all)]
-    async fn update_dispute(
-        &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let conn = connection::pg_connection_write(self).await?;
-        this.update(&conn, dispute)
+#[cfg(not(feature = "kv_store"))]
+mod storage {
+    use error_stack::report;
+    use router_env::{instrument, tracing};
+
+    use super::DisputeInterface;
+    use crate::{
+        connection,
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::{self as storage_types, enums, DisputeDbExt},
+    };
+
+    #[async_trait::async_trait]
+    impl DisputeInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_dispute(
+            &self,
+            dispute: storage_types::DisputeNew,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            dispute
+                .insert(&conn)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_by_merchant_id_payment_id_connector_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            connector_dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "dispute").await?;
+            storage_types::Dispute::find_by_merchant_id_payment_id_connector_dispute_id(
+                &conn,
+                merchant_id,
+                payment_id,
+                connector_dispute_id,
+            )
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_dispute_by_merchant_id_dispute_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_id: &str,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "dispute").await?;
+            storage_types::Dispute::find_by_merchant_id_dispute_id(&conn, merchant_id, dispute_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_disputes_by_merchant_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            dispute_constraints: api_models::disputes::DisputeListConstraints,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "dispute").await?;
+            storage_types::Dispute::filter_by_constraints(&conn, merchant_id, dispute_constraints)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_disputes_by_merchant_id_payment_id(
+            &self,
+            merchant_id: &common_utils::id_type::MerchantId,
+            payment_id: &common_utils::id_type::PaymentId,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "dispute").await?;
+            storage_types::Dispute::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn update_dispute(
+            &self,
+            this: storage_types::Dispute,
+            dispute: storage_types::DisputeUpdate,
+            _storage_scheme: enums::MerchantStorageScheme,
+        ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            this.update(&conn, dispute)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -132,8 +475,9 @@ impl DisputeInterface for Store {
 impl DisputeInterface for MockDb {
     async fn insert_dispute(
         &self,
-        dispute: storage::DisputeNew,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        dispute: storage_types::DisputeNew,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let evidence = dispute.evidence.ok_or(errors::StorageError::MockDbError)?;
 
         let mut locked_disputes = self.disputes.lock().await;
@@ -147,7 +491,7 @@ impl DisputeInterface for MockDb {
 
         let now = common_utils::date_time::now();
 
-        let new_dispute = storage::Dispute {
+        let new_dispute = storage_types::Dispute {
             dispute_id: dispute.dispute_id,
             amount: dispute.amount,
             currency: dispute.currency,
@@ -182,7 +526,8 @@ impl DisputeInterface for MockDb {
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
         connector_dispute_id: &str,
-    ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Option<storage_types::Dispute>, errors::StorageError> {
         Ok(self
             .disputes
             .lock()
@@ -200,7 +545,8 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_id: &str,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         locked_disputes
@@ -215,7 +561,7 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         dispute_constraints: api_models::disputes::DisputeListConstraints,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         Ok(locked_disputes
@@ -286,7 +632,8 @@ impl DisputeInterface for MockDb {
         &self,
         merchant_id: &common_utils::id_type::MerchantId,
         payment_id: &common_utils::id_type::PaymentId,
-    ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage_types::Dispute>, errors::StorageError> {
         let locked_disputes = self.disputes.lock().await;
 
         Ok(locked_disputes
@@ -298,9 +645,10 @@ impl DisputeInterface for MockDb {
 
     async fn update_dispute(
         &self,
-        this: storage::Dispute,
-        dispute: storage::DisputeUpdate,
-    ) -> CustomResult<storage::Dispute, errors::StorageError> {
+        this: storage_types::Dispute,
+        dispute: storage_types::DisputeUpdate,
+        _storage_scheme: enums::MerchantStorageScheme,
+    ) -> CustomResult<storage_types::Dispute, errors::StorageError> {
         let mut locked_disputes = self.disputes.lock().await;
 
         let dispute_to_update = locked_disputes
@@ -311,7 +659,7 @@ impl DisputeInterface for MockDb {
         let now = common_utils::date_time::now();
 
         match dispute {
-            storage::DisputeUpdate::Update {
+            storage_types::DisputeUpdate::Update {
                 dispute_stage,
                 dispute_status,
                 connector_status,
@@ -340,7 +688,7 @@ impl DisputeInterface for MockDb {
                 dispute_to_update.dispute_status = dispute_status;
                 dispute_to_update.connector_status = connector_status;
             }
-            storage::DisputeUpdate::StatusUpdate {
+            storage_types::DisputeUpdate::StatusUpdate {
                 dispute_status,
                 connector_status,
             } => {
@@ -349,7 +697,7 @@ impl DisputeInterface for MockDb {
                 }
                 dispute_to_update.dispute_status = dispute_status;
             }
-            storage::DisputeUpdate::EvidenceUpdate { evidence } => {
+            storage_types::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
         }
@@ -369,7 +717,7 @@ mod tests {
         use api_models::disputes::DisputeListConstraints;
         use diesel_models::{
             dispute::DisputeNew,
-            enums::{DisputeStage, DisputeStatus},
+            enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
         };
         use masking::Secret;
         use redis_interface::RedisSettings;
@@ -422,16 +770,19 @@ mod tests {
                 common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
-                        "payment_1",
-                    ))
-                    .unwrap(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
+                            "payment_1",
+                        ))
+                        .unwrap(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -458,30 +809,36 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
-                        "payment_1",
-                    ))
-                    .unwrap(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
+                            "payment_1",
+                        ))
+                        .unwrap(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
-                        "payment_1",
-                    ))
-                    .unwrap(),
-                    connector_dispute_id: "connector_dispute_2".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: common_utils::id_type::PaymentId::try_from(Cow::Borrowed(
+                            "payment_1",
+                        ))
+                        .unwrap(),
+                        connector_dispute_id: "connector_dispute_2".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -491,6 +848,7 @@ mod tests {
                     &common_utils::id_type::PaymentId::try_from(Cow::Borrowed("payment_1"))
                         .unwrap(),
                     "connector_dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
                 )
                 .await
                 .unwrap();
@@ -513,29 +871,39 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_dispute = mockdb
-                .find_dispute_by_merchant_id_dispute_id(&merchant_id, "dispute_1")
+                .find_dispute_by_merchant_id_dispute_id(
+                    &merchant_id,
+                    "dispute_1",
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -555,24 +923,30 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -614,29 +988,39 @@ mod tests {
                 .expect("Failed to create Mock store");
 
             let created_dispute = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_1".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_1".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let _ = mockdb
-                .insert_dispute(create_dispute_new(DisputeNewIds {
-                    dispute_id: "dispute_2".into(),
-                    attempt_id: "attempt_1".into(),
-                    merchant_id: merchant_id.clone(),
-                    payment_id: payment_id.clone(),
-                    connector_dispute_id: "connector_dispute_1".into(),
-                }))
+                .insert_dispute(
+                    create_dispute_new(DisputeNewIds {
+                        dispute_id: "dispute_2".into(),
+                        attempt_id: "attempt_1".into(),
+                        merchant_id: merchant_id.clone(),
+                        payment_id: payment_id.clone(),
+                        connector_dispute_id: "connector_dispute_1".into(),
+                    }),
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
             let found_disputes = mockdb
-                .find_disputes_by_merchant_id_payment_id(&merchant_id, &payment_id)
+                .find_disputes_by_merchant_id_payment_id(
+                    &merchant_id,
+                    &payment_id,
+                    MerchantStorageScheme::PostgresOnly,
+                )
                 .await
                 .unwrap();
 
@@ -650,7 +1034,7 @@ mod tests {
 
             use diesel_models::{
                 dispute::DisputeUpdate,
-                enums::{DisputeStage, DisputeStatus},
+                enums::{DisputeStage, DisputeStatus, MerchantStorageScheme},
             };
             use masking::Secret;
             use serde_json::Value;
@@ -677,13 +1061,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: merchant_id.clone(),
-                        payment_id: payment_id.clone(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: payment_id.clone(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -699,6 +1086,7 @@ mod tests {
                             challenge_required_by: Some(datetime!(2019-01-10 0:00)),
                             connector_updated_at: Some(datetime!(2019-01-11 0:00)),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -761,13 +1149,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: merchant_id.clone(),
-                        payment_id: payment_id.clone(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: payment_id.clone(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -778,6 +1169,7 @@ mod tests {
                             dispute_status: DisputeStatus::DisputeExpired,
                             connector_status: Some("updated_connector_status".into()),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -840,13 +1232,16 @@ mod tests {
                     .expect("Failed to create Mock store");
 
                 let created_dispute = mockdb
-                    .insert_dispute(create_dispute_new(DisputeNewIds {
-                        dispute_id: "dispute_1".into(),
-                        attempt_id: "attempt_1".into(),
-                        merchant_id: merchant_id.clone(),
-                        payment_id: payment_id.clone(),
-                        connector_dispute_id: "connector_dispute_1".into(),
-                    }))
+                    .insert_dispute(
+                        create_dispute_new(DisputeNewIds {
+                            dispute_id: "dispute_1".into(),
+                            attempt_id: "attempt_1".into(),
+                            merchant_id: merchant_id.clone(),
+                            payment_id: payment_id.clone(),
+                            connector_dispute_id: "connector_dispute_1".into(),
+                        }),
+                        MerchantStorageScheme::PostgresOnly,
+                    )
                     .await
                     .unwrap();
 
@@ -856,6 +1251,7 @@ mod tests {
                         DisputeUpdate::EvidenceUpdate {
                             evidence: Secret::from(Value::String("updated_evidence".into())),
                         },
+                        MerchantStorageScheme::PostgresOnly,
                     )
                     .await
                     .unwrap();
@@ -1,5 +1,3 @@
-use time::ext::NumericalDuration;
-
 use crate::{
     core::errors::{self, CustomResult},
     db::MockDb,
@@ -11,7 +9,7 @@ pub trait EphemeralKeyInterface {
     async fn create_ephemeral_key(
         &self,
         _ek: EphemeralKeyNew,
-        _validity: i64,
+        _validity: time::Duration,
     ) -> CustomResult<EphemeralKey, errors::StorageError>;
     async fn get_ephemeral_key(
         &self,
@@ -29,7 +27,6 @@ mod storage {
     use redis_interface::HsetnxReply;
     use router_env::{instrument, tracing};
     use storage_impl::redis::kv_store::RedisConnInterface;
-    use time::ext::NumericalDuration;
 
     use super::EphemeralKeyInterface;
     use crate::{
@@ -44,13 +41,13 @@ mod storage {
         async fn create_ephemeral_key(
             &self,
             new: EphemeralKeyNew,
-            validity: i64,
+            validity: time::Duration,
         ) -> CustomResult<EphemeralKey, errors::StorageError> {
             let secret_key = format!("epkey_{}", &new.secret);
             let id_key = format!("epkey_{}", &new.id);
 
             let created_at = date_time::now();
-            let expires = created_at.saturating_add(validity.hours());
+            let expires = created_at.saturating_add(validity);
             let created_ek = EphemeralKey {
                 id: new.id,
                 created_at: created_at.assume_utc().unix_timestamp(),
@@ -133,11 +130,11 @@ impl EphemeralKeyInterface for MockDb {
     async fn create_ephemeral_key(
         &self,
         ek: EphemeralKeyNew,
-        validity: i64,
+        validity: time::Duration,
     ) -> CustomResult<EphemeralKey, errors::StorageError> {
         let mut ephemeral_keys = self.ephemeral_keys.lock().await;
         let created_at = common_utils::date_time::now();
-        let expires = created_at.saturating_add(validity.hours());
+        let expires = created_at.saturating_add(validity);
 
         let ephemeral_key = EphemeralKey {
             id: ek.id,
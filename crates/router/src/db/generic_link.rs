@@ -46,6 +46,16 @@ pub trait GenericLinkInterface {
         payout_link: storage::PayoutLink,
         payout_link_update: storage::PayoutLinkUpdate,
     ) -> CustomResult<storage::PayoutLink, errors::StorageError>;
+
+    async fn find_pm_manage_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError>;
+
+    async fn insert_pm_manage_link(
+        &self,
+        _pm_manage_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -131,6 +141,29 @@ impl GenericLinkInterface for Store {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
+
+    #[instrument(skip_all)]
+    async fn find_pm_manage_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::GenericLink::find_pm_manage_link_by_link_id(&conn, link_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_pm_manage_link(
+        &self,
+        pm_manage_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        pm_manage_link
+            .insert_pm_manage_link(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
 }
 
 #[async_trait::async_trait]
@@ -191,4 +224,20 @@ impl GenericLinkInterface for MockDb {
         // TODO: Implement function for `MockDb`
         Err(errors::StorageError::MockDbError)?
     }
+
+    async fn find_pm_manage_link_by_link_id(
+        &self,
+        _generic_link_id: &str,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_pm_manage_link(
+        &self,
+        _pm_manage_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
 }
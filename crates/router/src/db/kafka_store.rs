@@ -550,8 +550,12 @@ impl DisputeInterface for KafkaStore {
     async fn insert_dispute(
         &self,
         dispute_new: storage::DisputeNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
-        let dispute = self.diesel_store.insert_dispute(dispute_new).await?;
+        let dispute = self
+            .diesel_store
+            .insert_dispute(dispute_new, storage_scheme)
+            .await?;
 
         if let Err(er) = self
             .kafka_producer
@@ -569,12 +573,14 @@ impl DisputeInterface for KafkaStore {
         merchant_id: &id_type::MerchantId,
         payment_id: &id_type::PaymentId,
         connector_dispute_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Option<storage::Dispute>, errors::StorageError> {
         self.diesel_store
             .find_by_merchant_id_payment_id_connector_dispute_id(
                 merchant_id,
                 payment_id,
                 connector_dispute_id,
+                storage_scheme,
             )
             .await
     }
@@ -583,9 +589,10 @@ impl DisputeInterface for KafkaStore {
         &self,
         merchant_id: &id_type::MerchantId,
         dispute_id: &str,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         self.diesel_store
-            .find_dispute_by_merchant_id_dispute_id(merchant_id, dispute_id)
+            .find_dispute_by_merchant_id_dispute_id(merchant_id, dispute_id, storage_scheme)
             .await
     }
 
@@ -603,10 +610,11 @@ impl DisputeInterface for KafkaStore {
         &self,
         this: storage::Dispute,
         dispute: storage::DisputeUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let dispute_new = self
             .diesel_store
-            .update_dispute(this.clone(), dispute)
+            .update_dispute(this.clone(), dispute, storage_scheme)
             .await?;
         if let Err(er) = self
             .kafka_producer
@@ -623,9 +631,10 @@ impl DisputeInterface for KafkaStore {
         &self,
         merchant_id: &id_type::MerchantId,
         payment_id: &id_type::PaymentId,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<storage::Dispute>, errors::StorageError> {
         self.diesel_store
-            .find_disputes_by_merchant_id_payment_id(merchant_id, payment_id)
+            .find_disputes_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
 }
@@ -635,7 +644,7 @@ impl EphemeralKeyInterface for KafkaStore {
     async fn create_ephemeral_key(
         &self,
         ek: EphemeralKeyNew,
-        validity: i64,
+        validity: time::Duration,
     ) -> CustomResult<EphemeralKey, errors::StorageError> {
         self.diesel_store.create_ephemeral_key(ek, validity).await
     }
@@ -1307,6 +1316,18 @@ impl QueueInterface for KafkaStore {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.diesel_store.get_key(key).await
     }
+
+    async fn pause_runner(&self, runner: &str, paused_by: &str) -> CustomResult<(), RedisError> {
+        self.diesel_store.pause_runner(runner, paused_by).await
+    }
+
+    async fn resume_runner(&self, runner: &str) -> CustomResult<(), RedisError> {
+        self.diesel_store.resume_runner(runner).await
+    }
+
+    async fn get_runner_paused_by(&self, runner: &str) -> CustomResult<Option<String>, RedisError> {
+        self.diesel_store.get_runner_paused_by(runner).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1508,6 +1529,23 @@ impl PaymentAttemptInterface for KafkaStore {
             .find_attempts_by_merchant_id_payment_id(merchant_id, payment_id, storage_scheme)
             .await
     }
+
+    async fn find_attempts_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<storage::PaymentAttempt>, errors::DataStorageError> {
+        self.diesel_store
+            .find_attempts_by_merchant_id_fingerprint_id(
+                merchant_id,
+                fingerprint_id,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -1678,6 +1716,28 @@ impl PaymentIntentInterface for KafkaStore {
             .await
     }
 
+    #[cfg(feature = "olap")]
+    async fn find_payment_intents_with_expiring_authorization(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &id_type::MerchantId,
+        profile_id: Option<&id_type::ProfileId>,
+        expiring_before: PrimitiveDateTime,
+        limit: u32,
+        key_store: &domain::MerchantKeyStore,
+    ) -> CustomResult<Vec<storage::PaymentIntent>, errors::DataStorageError> {
+        self.diesel_store
+            .find_payment_intents_with_expiring_authorization(
+                state,
+                merchant_id,
+                profile_id,
+                expiring_before,
+                limit,
+                key_store,
+            )
+            .await
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut storage::PaymentIntent,
@@ -1731,6 +1791,49 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        starting_after: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_methods_by_merchant_id_payment_method_status(
+                merchant_id,
+                payment_method,
+                status,
+                starting_after,
+                limit,
+            )
+            .await
+    }
+
+    async fn find_payment_methods_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_methods_by_merchant_id_fingerprint_id(merchant_id, fingerprint_id, limit)
+            .await
+    }
+
+    async fn get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        self.diesel_store
+            .get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+                merchant_id,
+                fingerprint_id,
+            )
+            .await
+    }
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &id_type::CustomerId,
@@ -1786,6 +1889,24 @@ impl PaymentMethodInterface for KafkaStore {
             .delete_payment_method_by_merchant_id_payment_method_id(merchant_id, payment_method_id)
             .await
     }
+
+    async fn increment_payment_method_consecutive_mit_declines(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .increment_payment_method_consecutive_mit_declines(payment_method_id)
+            .await
+    }
+
+    async fn reset_payment_method_consecutive_mit_declines(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .reset_payment_method_consecutive_mit_declines(payment_method_id)
+            .await
+    }
 }
 
 #[cfg(not(feature = "payouts"))]
@@ -2074,6 +2195,17 @@ impl ProcessTrackerInterface for KafkaStore {
         self.diesel_store.insert_process(new).await
     }
 
+    async fn update_process_if_status_in(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store
+            .update_process_if_status_in(this, statuses, process)
+            .await
+    }
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
@@ -2111,6 +2243,23 @@ impl ProcessTrackerInterface for KafkaStore {
             .find_processes_by_time_status(time_lower_limit, time_upper_limit, status, limit)
             .await
     }
+
+    async fn find_processes_by_filters(
+        &self,
+        filters: &storage::ProcessTrackerFilters,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        self.diesel_store.find_processes_by_filters(filters).await
+    }
+
+    async fn reschedule_due_processes_by_runner(
+        &self,
+        runner: &str,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        self.diesel_store
+            .reschedule_due_processes_by_runner(runner, schedule_time)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -3143,10 +3292,11 @@ impl AuthenticationInterface for KafkaStore {
     async fn insert_authentication(
         &self,
         authentication: storage::AuthenticationNew,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let auth = self
             .diesel_store
-            .insert_authentication(authentication)
+            .insert_authentication(authentication, storage_scheme)
             .await?;
 
         if let Err(er) = self
@@ -3164,9 +3314,14 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         merchant_id: &id_type::MerchantId,
         authentication_id: String,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         self.diesel_store
-            .find_authentication_by_merchant_id_authentication_id(merchant_id, authentication_id)
+            .find_authentication_by_merchant_id_authentication_id(
+                merchant_id,
+                authentication_id,
+                storage_scheme,
+            )
             .await
     }
 
@@ -3174,11 +3329,13 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         merchant_id: id_type::MerchantId,
         connector_authentication_id: String,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         self.diesel_store
             .find_authentication_by_merchant_id_connector_authentication_id(
                 merchant_id,
                 connector_authentication_id,
+                storage_scheme,
             )
             .await
     }
@@ -3187,12 +3344,14 @@ impl AuthenticationInterface for KafkaStore {
         &self,
         previous_state: storage::Authentication,
         authentication_update: storage::AuthenticationUpdate,
+        storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<storage::Authentication, errors::StorageError> {
         let auth = self
             .diesel_store
             .update_authentication_by_merchant_id_authentication_id(
                 previous_state.clone(),
                 authentication_update,
+                storage_scheme,
             )
             .await?;
 
@@ -3339,6 +3498,24 @@ impl GenericLinkInterface for KafkaStore {
             .update_payout_link(payout_link, payout_link_update)
             .await
     }
+
+    async fn find_pm_manage_link_by_link_id(
+        &self,
+        link_id: &str,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError> {
+        self.diesel_store
+            .find_pm_manage_link_by_link_id(link_id)
+            .await
+    }
+
+    async fn insert_pm_manage_link(
+        &self,
+        pm_manage_link: storage::GenericLinkNew,
+    ) -> CustomResult<storage::PaymentMethodManageLink, errors::StorageError> {
+        self.diesel_store
+            .insert_pm_manage_link(pm_manage_link)
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -80,7 +80,7 @@ mod storage {
             mandate_id: &str,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             let database_call = || async {
                 storage_types::Mandate::find_by_merchant_id_mandate_id(
                     &conn,
@@ -126,7 +126,7 @@ mod storage {
             connector_mandate_id: &str,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             let database_call = || async {
                 storage_types::Mandate::find_by_merchant_id_connector_mandate_id(
                     &conn,
@@ -180,7 +180,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             customer_id: &id_type::CustomerId,
         ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -280,7 +280,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             mandate_constraints: api_models::mandates::MandateListConstraints,
         ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -394,7 +394,7 @@ mod storage {
             mandate_id: &str,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             storage_types::Mandate::find_by_merchant_id_mandate_id(&conn, merchant_id, mandate_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -407,7 +407,7 @@ mod storage {
             connector_mandate_id: &str,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::Mandate, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             storage_types::Mandate::find_by_merchant_id_connector_mandate_id(
                 &conn,
                 merchant_id,
@@ -423,7 +423,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             customer_id: &id_type::CustomerId,
         ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             storage_types::Mandate::find_by_merchant_id_customer_id(&conn, merchant_id, customer_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -455,7 +455,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             mandate_constraints: api_models::mandates::MandateListConstraints,
         ) -> CustomResult<Vec<storage_types::Mandate>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "mandate").await?;
             storage_types::Mandate::filter_by_constraints(&conn, merchant_id, mandate_constraints)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -45,6 +45,28 @@ pub trait PaymentMethodInterface {
         status: common_enums::PaymentMethodStatus,
     ) -> CustomResult<i64, errors::StorageError>;
 
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        starting_after: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
+    async fn find_payment_methods_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError>;
+
+    async fn get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<i64, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         payment_method_new: storage_types::PaymentMethodNew,
@@ -63,6 +85,21 @@ pub trait PaymentMethodInterface {
         merchant_id: &id_type::MerchantId,
         payment_method_id: &str,
     ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
+
+    /// Atomically increments the count of consecutive declined merchant initiated payments of the
+    /// payment method. The count is kept in the database whatever the storage scheme, as it is
+    /// only read by the payment method health sweep.
+    async fn increment_payment_method_consecutive_mit_declines(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError>;
+
+    /// Resets the count of consecutive declined merchant initiated payments of the payment method
+    /// in the database, returns the number of payment methods whose count was reset.
+    async fn reset_payment_method_consecutive_mit_declines(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[cfg(feature = "kv_store")]
@@ -94,7 +131,7 @@ mod storage {
             payment_method_id: &str,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             let database_call = || async {
                 storage_types::PaymentMethod::find_by_payment_method_id(&conn, payment_method_id)
                     .await
@@ -143,7 +180,7 @@ mod storage {
             locker_id: &str,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             let database_call = || async {
                 storage_types::PaymentMethod::find_by_locker_id(&conn, locker_id)
                     .await
@@ -193,7 +230,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             status: common_enums::PaymentMethodStatus,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::get_count_by_customer_id_merchant_id_status(
                 &conn,
                 customer_id,
@@ -369,7 +406,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             limit: Option<i64>,
         ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::find_by_customer_id_merchant_id(
                 &conn,
                 customer_id,
@@ -389,7 +426,7 @@ mod storage {
             limit: Option<i64>,
             storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             let database_call = || async {
                 storage_types::PaymentMethod::find_by_customer_id_merchant_id_status(
                     &conn,
@@ -438,6 +475,64 @@ mod storage {
             }
         }
 
+        // Merchant wide scans are served from the database alone, payment methods which are yet
+        // to be drained are picked up by a later scan
+        #[instrument(skip_all)]
+        async fn find_payment_methods_by_merchant_id_payment_method_status(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            payment_method: common_enums::PaymentMethod,
+            status: common_enums::PaymentMethodStatus,
+            starting_after: &str,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
+            storage_types::PaymentMethod::find_by_merchant_id_payment_method_status(
+                &conn,
+                merchant_id,
+                payment_method,
+                status,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_payment_methods_by_merchant_id_fingerprint_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            fingerprint_id: &str,
+            limit: Option<i64>,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
+            storage_types::PaymentMethod::find_by_merchant_id_fingerprint_id(
+                &conn,
+                merchant_id,
+                fingerprint_id,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            fingerprint_id: &str,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
+            storage_types::PaymentMethod::get_customer_count_by_merchant_id_fingerprint_id(
+                &conn,
+                merchant_id,
+                fingerprint_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         async fn delete_payment_method_by_merchant_id_payment_method_id(
             &self,
             merchant_id: &id_type::MerchantId,
@@ -452,6 +547,31 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn increment_payment_method_consecutive_mit_declines(
+            &self,
+            payment_method_id: &str,
+        ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::PaymentMethod::increment_consecutive_mit_declines(
+                &conn,
+                payment_method_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn reset_payment_method_consecutive_mit_declines(
+            &self,
+            payment_method_id: &str,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::PaymentMethod::reset_consecutive_mit_declines(&conn, payment_method_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -477,7 +597,7 @@ mod storage {
             payment_method_id: &str,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::find_by_payment_method_id(&conn, payment_method_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -489,7 +609,7 @@ mod storage {
             locker_id: &str,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::find_by_locker_id(&conn, locker_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -502,7 +622,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             status: common_enums::PaymentMethodStatus,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::get_count_by_customer_id_merchant_id_status(
                 &conn,
                 customer_id,
@@ -547,7 +667,7 @@ mod storage {
             merchant_id: &id_type::MerchantId,
             limit: Option<i64>,
         ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::find_by_customer_id_merchant_id(
                 &conn,
                 customer_id,
@@ -567,7 +687,7 @@ mod storage {
             limit: Option<i64>,
             _storage_scheme: MerchantStorageScheme,
         ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
             storage_types::PaymentMethod::find_by_customer_id_merchant_id_status(
                 &conn,
                 customer_id,
@@ -579,6 +699,62 @@ mod storage {
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
 
+        #[instrument(skip_all)]
+        async fn find_payment_methods_by_merchant_id_payment_method_status(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            payment_method: common_enums::PaymentMethod,
+            status: common_enums::PaymentMethodStatus,
+            starting_after: &str,
+            limit: i64,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
+            storage_types::PaymentMethod::find_by_merchant_id_payment_method_status(
+                &conn,
+                merchant_id,
+                payment_method,
+                status,
+                starting_after,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn find_payment_methods_by_merchant_id_fingerprint_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            fingerprint_id: &str,
+            limit: Option<i64>,
+        ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
+            storage_types::PaymentMethod::find_by_merchant_id_fingerprint_id(
+                &conn,
+                merchant_id,
+                fingerprint_id,
+                limit,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+            &self,
+            merchant_id: &id_type::MerchantId,
+            fingerprint_id: &str,
+        ) -> CustomResult<i64, errors::StorageError> {
+            let conn = connection::pg_connection_read_table(self, "payment_methods").await?;
+            storage_types::PaymentMethod::get_customer_count_by_merchant_id_fingerprint_id(
+                &conn,
+                merchant_id,
+                fingerprint_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
         async fn delete_payment_method_by_merchant_id_payment_method_id(
             &self,
             merchant_id: &id_type::MerchantId,
@@ -593,6 +769,31 @@ mod storage {
             .await
             .map_err(|error| report!(errors::StorageError::from(error)))
         }
+
+        #[instrument(skip_all)]
+        async fn increment_payment_method_consecutive_mit_declines(
+            &self,
+            payment_method_id: &str,
+        ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::PaymentMethod::increment_consecutive_mit_declines(
+                &conn,
+                payment_method_id,
+            )
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+        }
+
+        #[instrument(skip_all)]
+        async fn reset_payment_method_consecutive_mit_declines(
+            &self,
+            payment_method_id: &str,
+        ) -> CustomResult<usize, errors::StorageError> {
+            let conn = connection::pg_connection_write(self).await?;
+            storage_types::PaymentMethod::reset_consecutive_mit_declines(&conn, payment_method_id)
+                .await
+                .map_err(|error| report!(errors::StorageError::from(error)))
+        }
     }
 }
 
@@ -694,6 +895,9 @@ impl PaymentMethodInterface for MockDb {
             network_transaction_id: payment_method_new.network_transaction_id,
             updated_by: payment_method_new.updated_by,
             payment_method_billing_address: payment_method_new.payment_method_billing_address,
+            health_status: payment_method_new.health_status,
+            consecutive_mit_declines: payment_method_new.consecutive_mit_declines,
+            fingerprint_id: payment_method_new.fingerprint_id,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -751,6 +955,72 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_methods_by_merchant_id_payment_method_status(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        starting_after: &str,
+        limit: i64,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.payment_method == Some(payment_method)
+                    && pm.status == status
+                    && pm.payment_method_id.as_str() > starting_after
+            })
+            .cloned()
+            .collect();
+        payment_methods_found.sort_by(|a, b| a.payment_method_id.cmp(&b.payment_method_id));
+        payment_methods_found.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(payment_methods_found)
+    }
+
+    async fn find_payment_methods_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+    ) -> CustomResult<Vec<storage_types::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods_found: Vec<storage_types::PaymentMethod> = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.fingerprint_id.as_deref() == Some(fingerprint_id)
+            })
+            .cloned()
+            .collect();
+        payment_methods_found.sort_by(|a, b| b.last_used_at.cmp(&a.last_used_at));
+        if let Some(limit) = limit.and_then(|limit| usize::try_from(limit).ok()) {
+            payment_methods_found.truncate(limit);
+        }
+
+        Ok(payment_methods_found)
+    }
+
+    async fn get_payment_method_customer_count_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &id_type::MerchantId,
+        fingerprint_id: &str,
+    ) -> CustomResult<i64, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let customer_count = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.merchant_id == *merchant_id
+                    && pm.fingerprint_id.as_deref() == Some(fingerprint_id)
+            })
+            .map(|pm| &pm.customer_id)
+            .collect::<std::collections::HashSet<_>>()
+            .len();
+        i64::try_from(customer_count).change_context(errors::StorageError::MockDbError)
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &id_type::MerchantId,
@@ -799,4 +1069,40 @@ impl PaymentMethodInterface for MockDb {
             .into()),
         }
     }
+
+    async fn increment_payment_method_consecutive_mit_declines(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<storage_types::PaymentMethod, errors::StorageError> {
+        let mut payment_methods = self.payment_methods.lock().await;
+        let payment_method = payment_methods
+            .iter_mut()
+            .find(|pm| pm.payment_method_id == payment_method_id)
+            .ok_or(errors::StorageError::ValueNotFound(
+                "cannot find payment method to update".to_string(),
+            ))?;
+        payment_method.consecutive_mit_declines = Some(
+            payment_method
+                .consecutive_mit_declines
+                .unwrap_or_default()
+                .saturating_add(1),
+        );
+        Ok(payment_method.clone())
+    }
+
+    async fn reset_payment_method_consecutive_mit_declines(
+        &self,
+        payment_method_id: &str,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut payment_methods = self.payment_methods.lock().await;
+        let reset_payment_methods = payment_methods
+            .iter_mut()
+            .filter(|pm| {
+                pm.payment_method_id == payment_method_id
+                    && pm.consecutive_mit_declines.unwrap_or_default() > 0
+            })
+            .map(|pm| pm.consecutive_mit_declines = Some(0))
+            .count();
+        Ok(reset_payment_methods)
+    }
 }
@@ -0,0 +1,308 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait PaymentMethodMigrationInterface {
+    async fn insert_payment_method_migration(
+        &self,
+        payment_method_migration: storage::PaymentMethodMigrationNew,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError>;
+
+    async fn find_payment_method_migration_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError>;
+
+    async fn update_payment_method_migration(
+        &self,
+        this: storage::PaymentMethodMigration,
+        payment_method_migration_update: storage::PaymentMethodMigrationUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError>;
+
+    async fn insert_payment_method_migration_item(
+        &self,
+        payment_method_migration_item: storage::PaymentMethodMigrationItemNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError>;
+
+    async fn update_payment_method_migration_item(
+        &self,
+        this: storage::PaymentMethodMigrationItem,
+        payment_method_migration_item_update: storage::PaymentMethodMigrationItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError>;
+
+    async fn find_payment_method_migration_items_by_line_number_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+        start_line_number: i32,
+        end_line_number: i32,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError>;
+
+    async fn find_payment_method_migration_items_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodMigrationInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payment_method_migration(
+        &self,
+        payment_method_migration: storage::PaymentMethodMigrationNew,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_method_migration
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodMigration::find_by_merchant_id_migration_id(
+            &conn,
+            merchant_id,
+            migration_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_migration(
+        &self,
+        this: storage::PaymentMethodMigration,
+        payment_method_migration_update: storage::PaymentMethodMigrationUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payment_method_migration_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payment_method_migration_item(
+        &self,
+        payment_method_migration_item: storage::PaymentMethodMigrationItemNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payment_method_migration_item
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payment_method_migration_item(
+        &self,
+        this: storage::PaymentMethodMigrationItem,
+        payment_method_migration_item_update: storage::PaymentMethodMigrationItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payment_method_migration_item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_items_by_line_number_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+        start_line_number: i32,
+        end_line_number: i32,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError> {
+        // The items are read from the master database, as the items of an interrupted chunk may
+        // have been inserted right before the chunk is resumed
+        let conn = connection::pg_connection_write(self).await?;
+        storage::PaymentMethodMigrationItem::find_by_merchant_id_migration_id_line_number_range(
+            &conn,
+            merchant_id,
+            migration_id,
+            start_line_number,
+            end_line_number,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payment_method_migration_items_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethodMigrationItem::find_by_merchant_id_migration_id(
+            &conn,
+            merchant_id,
+            migration_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodMigrationInterface for MockDb {
+    async fn insert_payment_method_migration(
+        &self,
+        _payment_method_migration: storage::PaymentMethodMigrationNew,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_migration_by_merchant_id_migration_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _migration_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_method_migration(
+        &self,
+        _this: storage::PaymentMethodMigration,
+        _payment_method_migration_update: storage::PaymentMethodMigrationUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_payment_method_migration_item(
+        &self,
+        _payment_method_migration_item: storage::PaymentMethodMigrationItemNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payment_method_migration_item(
+        &self,
+        _this: storage::PaymentMethodMigrationItem,
+        _payment_method_migration_item_update: storage::PaymentMethodMigrationItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_migration_items_by_line_number_range(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _migration_id: &str,
+        _start_line_number: i32,
+        _end_line_number: i32,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payment_method_migration_items_by_merchant_id_migration_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _migration_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PaymentMethodMigrationInterface for KafkaStore {
+    async fn insert_payment_method_migration(
+        &self,
+        payment_method_migration: storage::PaymentMethodMigrationNew,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_migration(payment_method_migration)
+            .await
+    }
+
+    async fn find_payment_method_migration_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_migration_by_merchant_id_migration_id(merchant_id, migration_id)
+            .await
+    }
+
+    async fn update_payment_method_migration(
+        &self,
+        this: storage::PaymentMethodMigration,
+        payment_method_migration_update: storage::PaymentMethodMigrationUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigration, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_migration(this, payment_method_migration_update)
+            .await
+    }
+
+    async fn insert_payment_method_migration_item(
+        &self,
+        payment_method_migration_item: storage::PaymentMethodMigrationItemNew,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError> {
+        self.diesel_store
+            .insert_payment_method_migration_item(payment_method_migration_item)
+            .await
+    }
+
+    async fn update_payment_method_migration_item(
+        &self,
+        this: storage::PaymentMethodMigrationItem,
+        payment_method_migration_item_update: storage::PaymentMethodMigrationItemUpdate,
+    ) -> CustomResult<storage::PaymentMethodMigrationItem, errors::StorageError> {
+        self.diesel_store
+            .update_payment_method_migration_item(this, payment_method_migration_item_update)
+            .await
+    }
+
+    async fn find_payment_method_migration_items_by_line_number_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+        start_line_number: i32,
+        end_line_number: i32,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_migration_items_by_line_number_range(
+                merchant_id,
+                migration_id,
+                start_line_number,
+                end_line_number,
+            )
+            .await
+    }
+
+    async fn find_payment_method_migration_items_by_merchant_id_migration_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        migration_id: &str,
+    ) -> CustomResult<Vec<storage::PaymentMethodMigrationItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_migration_items_by_merchant_id_migration_id(
+                merchant_id,
+                migration_id,
+            )
+            .await
+    }
+}
@@ -0,0 +1,346 @@
+use error_stack::report;
+use router_env::{instrument, tracing};
+use storage_impl::MockDb;
+
+use super::Store;
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    db::kafka_store::KafkaStore,
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait PayoutBatchInterface {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError>;
+
+    async fn insert_payout_batch_item(
+        &self,
+        payout_batch_item: storage::PayoutBatchItemNew,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError>;
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id_row_number_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError>;
+
+    async fn find_payout_batch_items_with_payout_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<
+        Vec<(storage::PayoutBatchItem, Option<enums::PayoutStatus>)>,
+        errors::StorageError,
+    >;
+
+    async fn get_payout_batch_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutStatus, i64)>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatch::find_by_merchant_id_batch_id(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn insert_payout_batch_item(
+        &self,
+        payout_batch_item: storage::PayoutBatchItemNew,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        payout_batch_item
+            .insert(&conn)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update(&conn, payout_batch_item_update)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_items_by_merchant_id_batch_id_row_number_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::find_by_merchant_id_batch_id_row_number_range(
+            &conn,
+            merchant_id,
+            batch_id,
+            start_row_number,
+            end_row_number,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn find_payout_batch_items_with_payout_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<
+        Vec<(storage::PayoutBatchItem, Option<enums::PayoutStatus>)>,
+        errors::StorageError,
+    > {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::find_by_merchant_id_batch_id_with_payout_status(
+            &conn,
+            merchant_id,
+            batch_id,
+        )
+        .await
+        .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    #[instrument(skip_all)]
+    async fn get_payout_batch_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutStatus, i64)>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PayoutBatchItem::get_payout_status_with_count(&conn, merchant_id, batch_id)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for MockDb {
+    async fn insert_payout_batch(
+        &self,
+        _payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch(
+        &self,
+        _this: storage::PayoutBatch,
+        _payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn insert_payout_batch_item(
+        &self,
+        _payout_batch_item: storage::PayoutBatchItemNew,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        _this: storage::PayoutBatchItem,
+        _payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id_row_number_range(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+        _start_row_number: i32,
+        _end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn find_payout_batch_items_with_payout_status(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<
+        Vec<(storage::PayoutBatchItem, Option<enums::PayoutStatus>)>,
+        errors::StorageError,
+    > {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+
+    async fn get_payout_batch_status_with_count(
+        &self,
+        _merchant_id: &common_utils::id_type::MerchantId,
+        _batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutStatus, i64)>, errors::StorageError> {
+        // TODO: Implement function for `MockDb`
+        Err(errors::StorageError::MockDbError)?
+    }
+}
+
+#[async_trait::async_trait]
+impl PayoutBatchInterface for KafkaStore {
+    async fn insert_payout_batch(
+        &self,
+        payout_batch: storage::PayoutBatchNew,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store.insert_payout_batch(payout_batch).await
+    }
+
+    async fn find_payout_batch_by_merchant_id_batch_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_by_merchant_id_batch_id(merchant_id, batch_id)
+            .await
+    }
+
+    async fn update_payout_batch(
+        &self,
+        this: storage::PayoutBatch,
+        payout_batch_update: storage::PayoutBatchUpdate,
+    ) -> CustomResult<storage::PayoutBatch, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch(this, payout_batch_update)
+            .await
+    }
+
+    async fn insert_payout_batch_item(
+        &self,
+        payout_batch_item: storage::PayoutBatchItemNew,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .insert_payout_batch_item(payout_batch_item)
+            .await
+    }
+
+    async fn update_payout_batch_item(
+        &self,
+        this: storage::PayoutBatchItem,
+        payout_batch_item_update: storage::PayoutBatchItemUpdate,
+    ) -> CustomResult<storage::PayoutBatchItem, errors::StorageError> {
+        self.diesel_store
+            .update_payout_batch_item(this, payout_batch_item_update)
+            .await
+    }
+
+    async fn find_payout_batch_items_by_merchant_id_batch_id_row_number_range(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+        start_row_number: i32,
+        end_row_number: i32,
+    ) -> CustomResult<Vec<storage::PayoutBatchItem>, errors::StorageError> {
+        self.diesel_store
+            .find_payout_batch_items_by_merchant_id_batch_id_row_number_range(
+                merchant_id,
+                batch_id,
+                start_row_number,
+                end_row_number,
+            )
+            .await
+    }
+
+    async fn find_payout_batch_items_with_payout_status(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<
+        Vec<(storage::PayoutBatchItem, Option<enums::PayoutStatus>)>,
+        errors::StorageError,
+    > {
+        self.diesel_store
+            .find_payout_batch_items_with_payout_status(merchant_id, batch_id)
+            .await
+    }
+
+    async fn get_payout_batch_status_with_count(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        batch_id: &str,
+    ) -> CustomResult<Vec<(enums::PayoutStatus, i64)>, errors::StorageError> {
+        self.diesel_store
+            .get_payout_batch_status_with_count(merchant_id, batch_id)
+            .await
+    }
+}
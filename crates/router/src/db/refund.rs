@@ -114,7 +114,7 @@ mod storage {
             merchant_id: &common_utils::id_type::MerchantId,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             storage_types::Refund::find_by_internal_reference_id_merchant_id(
                 &conn,
                 internal_reference_id,
@@ -143,7 +143,7 @@ mod storage {
             connector_transaction_id: &str,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             storage_types::Refund::find_by_merchant_id_connector_transaction_id(
                 &conn,
                 merchant_id,
@@ -173,7 +173,7 @@ mod storage {
             refund_id: &str,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             storage_types::Refund::find_by_merchant_id_refund_id(&conn, merchant_id, refund_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -187,7 +187,7 @@ mod storage {
             connector: &str,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             storage_types::Refund::find_by_merchant_id_connector_refund_id_connector(
                 &conn,
                 merchant_id,
@@ -205,7 +205,7 @@ mod storage {
             merchant_id: &common_utils::id_type::MerchantId,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             storage_types::Refund::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
                 .await
                 .map_err(|error| report!(errors::StorageError::from(error)))
@@ -221,7 +221,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -241,7 +241,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(
                 &conn,
                 merchant_id,
@@ -258,7 +258,7 @@ mod storage {
             refund_details: &api_models::refunds::RefundListRequest,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -299,7 +299,7 @@ mod storage {
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let database_call = || async {
-                let conn = connection::pg_connection_read(self).await?;
+                let conn = connection::pg_connection_read_table(self, "refund").await?;
                 storage_types::Refund::find_by_internal_reference_id_merchant_id(
                     &conn,
                     internal_reference_id,
@@ -399,6 +399,9 @@ mod storage {
                         merchant_connector_id: new.merchant_connector_id.clone(),
                         charges: new.charges.clone(),
                         organization_id: new.organization_id.clone(),
+                        payout_id: new.payout_id.clone(),
+                        created_by: new.created_by.clone(),
+                        daily_refund_limit_reservation: new.daily_refund_limit_reservation.clone(),
                     };
 
                     let field = format!(
@@ -491,7 +494,7 @@ mod storage {
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
             let database_call = || async {
-                let conn = connection::pg_connection_read(self).await?;
+                let conn = connection::pg_connection_read_table(self, "refund").await?;
                 storage_types::Refund::find_by_merchant_id_connector_transaction_id(
                     &conn,
                     merchant_id,
@@ -579,6 +582,7 @@ mod storage {
                             updatable: kv::Updateable::RefundUpdate(kv::RefundUpdateMems {
                                 orig: this,
                                 update_data: refund,
+                                coalesced_updates: Vec::new(),
                             }),
                         },
                     };
@@ -609,7 +613,7 @@ mod storage {
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let database_call = || async {
-                let conn = connection::pg_connection_read(self).await?;
+                let conn = connection::pg_connection_read_table(self, "refund").await?;
                 storage_types::Refund::find_by_merchant_id_refund_id(&conn, merchant_id, refund_id)
                     .await
                     .map_err(|error| report!(errors::StorageError::from(error)))
@@ -657,7 +661,7 @@ mod storage {
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<storage_types::Refund, errors::StorageError> {
             let database_call = || async {
-                let conn = connection::pg_connection_read(self).await?;
+                let conn = connection::pg_connection_read_table(self, "refund").await?;
                 storage_types::Refund::find_by_merchant_id_connector_refund_id_connector(
                     &conn,
                     merchant_id,
@@ -711,7 +715,7 @@ mod storage {
             storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<Vec<storage_types::Refund>, errors::StorageError> {
             let database_call = || async {
-                let conn = connection::pg_connection_read(self).await?;
+                let conn = connection::pg_connection_read_table(self, "refund").await?;
                 storage_types::Refund::find_by_payment_id_merchant_id(
                     &conn,
                     payment_id,
@@ -757,7 +761,7 @@ mod storage {
             limit: i64,
             offset: i64,
         ) -> CustomResult<Vec<diesel_models::refund::Refund>, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_constraints(
                 &conn,
                 merchant_id,
@@ -777,7 +781,7 @@ mod storage {
             refund_details: &api_models::payments::TimeRange,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<api_models::refunds::RefundListMetaData, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::filter_by_meta_constraints(&conn, merchant_id, refund_details)
                         .await
                         .map_err(|error|report!(errors::StorageError::from(error)))
@@ -791,7 +795,7 @@ mod storage {
             refund_details: &api_models::refunds::RefundListRequest,
             _storage_scheme: enums::MerchantStorageScheme,
         ) -> CustomResult<i64, errors::StorageError> {
-            let conn = connection::pg_connection_read(self).await?;
+            let conn = connection::pg_connection_read_table(self, "refund").await?;
             <diesel_models::refund::Refund as storage_types::RefundDbExt>::get_refunds_count(
                 &conn,
                 merchant_id,
@@ -861,6 +865,9 @@ impl RefundInterface for MockDb {
             merchant_connector_id: new.merchant_connector_id,
             charges: new.charges,
             organization_id: new.organization_id,
+            payout_id: new.payout_id,
+            created_by: new.created_by,
+            daily_refund_limit_reservation: new.daily_refund_limit_reservation,
         };
         refunds.push(refund.clone());
         Ok(refund)
@@ -1,4 +1,4 @@
-use common_utils::types::MinorUnit;
+use common_utils::{id_type, types::MinorUnit};
 use diesel_models::fraud_check::FraudCheck;
 use events::{Event, EventInfo};
 use serde::Serialize;
@@ -27,6 +27,43 @@ pub enum AuditEventType {
         capture_amount: Option<MinorUnit>,
         multiple_capture_count: Option<i16>,
     },
+    RefundApprovalRequested {
+        requested_by: Option<String>,
+        approval_threshold: MinorUnit,
+    },
+    RefundApproved {
+        approved_by: String,
+        reason: Option<String>,
+    },
+    RefundRejected {
+        rejected_by: String,
+        reason: Option<String>,
+    },
+    ProcessTrackerTaskRunNow {
+        performed_by: String,
+    },
+    ProcessTrackerTaskRescheduled {
+        performed_by: String,
+        #[serde(with = "common_utils::custom_serde::iso8601")]
+        schedule_time: PrimitiveDateTime,
+    },
+    ProcessTrackerTaskCancelled {
+        performed_by: String,
+    },
+    ProcessTrackerRunnerPaused {
+        runner: String,
+        performed_by: String,
+    },
+    ProcessTrackerRunnerResumed {
+        runner: String,
+        performed_by: String,
+        rescheduled_tasks: usize,
+    },
+    /// Tombstone of a customer whose records were erased from the analytics store
+    CustomerDataErased {
+        merchant_id: id_type::MerchantId,
+        customer_id: id_type::CustomerId,
+    },
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -65,6 +102,17 @@ impl Event for AuditEvent {
             AuditEventType::RefundSuccess => "refund_success",
             AuditEventType::RefundFail => "refund_fail",
             AuditEventType::PaymentCancelled { .. } => "payment_cancelled",
+            AuditEventType::RefundApprovalRequested { .. } => "refund_approval_requested",
+            AuditEventType::RefundApproved { .. } => "refund_approved",
+            AuditEventType::RefundRejected { .. } => "refund_rejected",
+            AuditEventType::ProcessTrackerTaskRunNow { .. } => "process_tracker_task_run_now",
+            AuditEventType::ProcessTrackerTaskRescheduled { .. } => {
+                "process_tracker_task_rescheduled"
+            }
+            AuditEventType::ProcessTrackerTaskCancelled { .. } => "process_tracker_task_cancelled",
+            AuditEventType::ProcessTrackerRunnerPaused { .. } => "process_tracker_runner_paused",
+            AuditEventType::ProcessTrackerRunnerResumed { .. } => "process_tracker_runner_resumed",
+            AuditEventType::CustomerDataErased { .. } => "customer_data_erased",
         };
         format!(
             "{event_type}-{}",
@@ -15,6 +15,8 @@ pub mod customers;
 pub mod disputes;
 #[cfg(feature = "dummy_connector")]
 pub mod dummy_connector;
+#[cfg(feature = "olap")]
+pub mod embedded_vault;
 pub mod ephemeral_key;
 pub mod files;
 #[cfg(feature = "frm")]
@@ -35,6 +37,8 @@ pub mod payouts;
 #[cfg(any(feature = "olap", feature = "oltp"))]
 pub mod pm_auth;
 pub mod poll;
+#[cfg(feature = "olap")]
+pub mod process_tracker;
 #[cfg(feature = "recon")]
 pub mod recon;
 pub mod refunds;
@@ -65,7 +69,9 @@ pub use self::app::{
     Poll, Refunds, SessionState, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Organization, Routing, Verify, WebhookEvents};
+pub use self::app::{
+    Blocklist, EmbeddedVault, Organization, ProcessTracker, Routing, Verify, WebhookEvents,
+};
 #[cfg(feature = "payouts")]
 pub use self::app::{PayoutLink, Payouts};
 #[cfg(all(
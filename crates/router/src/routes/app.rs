@@ -9,7 +9,9 @@ use common_enums::TransactionType;
 use common_utils::crypto::Blake3;
 #[cfg(feature = "email")]
 use external_services::email::{ses::AwsSes, EmailService};
-use external_services::file_storage::FileStorageInterface;
+use external_services::{
+    account_updater::AccountUpdaterInterface, file_storage::FileStorageInterface,
+};
 use hyperswitch_interfaces::{
     encryption_interface::EncryptionManagementInterface,
     secrets_interface::secret_state::{RawSecret, SecuredSecret},
@@ -26,6 +28,8 @@ use super::blocklist;
 use super::currency;
 #[cfg(feature = "dummy_connector")]
 use super::dummy_connector::*;
+#[cfg(feature = "olap")]
+use super::embedded_vault;
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "customer_v2"),
@@ -47,6 +51,8 @@ use super::pm_auth;
 #[cfg(feature = "oltp")]
 use super::poll::retrieve_poll_status;
 #[cfg(feature = "olap")]
+use super::process_tracker;
+#[cfg(feature = "olap")]
 use super::routing;
 #[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
@@ -100,11 +106,13 @@ pub struct SessionState {
     #[cfg(feature = "olap")]
     pub pool: AnalyticsProvider,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub account_updater_client: Arc<dyn AccountUpdaterInterface>,
     pub request_id: Option<RequestId>,
     pub base_url: String,
     pub tenant: Tenant,
     #[cfg(feature = "olap")]
     pub opensearch_client: Arc<OpenSearchClient>,
+    pub encryption_client: Arc<dyn EncryptionManagementInterface>,
 }
 impl scheduler::SchedulerSessionState for SessionState {
     fn get_db(&self) -> Box<dyn SchedulerInterface> {
@@ -201,6 +209,7 @@ pub struct AppState {
     pub opensearch_client: Arc<OpenSearchClient>,
     pub request_id: Option<RequestId>,
     pub file_storage_client: Arc<dyn FileStorageInterface>,
+    pub account_updater_client: Arc<dyn AccountUpdaterInterface>,
     pub encryption_client: Arc<dyn EncryptionManagementInterface>,
 }
 impl scheduler::SchedulerAppState for AppState {
@@ -350,6 +359,11 @@ impl AppState {
             let email_client = Arc::new(create_email_client(&conf).await);
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
+            let account_updater_client = conf
+                .account_updater
+                .provider
+                .get_account_updater_client()
+                .await;
 
             Self {
                 flow_name: String::from("default"),
@@ -366,6 +380,7 @@ impl AppState {
                 opensearch_client,
                 request_id: None,
                 file_storage_client,
+                account_updater_client,
                 encryption_client,
             }
         })
@@ -440,6 +455,7 @@ impl AppState {
             #[cfg(feature = "olap")]
             pool: self.pools.get(tenant).ok_or_else(err)?.clone(),
             file_storage_client: self.file_storage_client.clone(),
+            account_updater_client: self.account_updater_client.clone(),
             request_id: self.request_id,
             base_url: tenant_conf.base_url.clone(),
             tenant: tenant_conf.clone(),
@@ -447,6 +463,7 @@ impl AppState {
             email_client: Arc::clone(&self.email_client),
             #[cfg(feature = "olap")]
             opensearch_client: Arc::clone(&self.opensearch_client),
+            encryption_client: Arc::clone(&self.encryption_client),
         })
     }
 }
@@ -547,6 +564,10 @@ impl Payments {
                 .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
                 .service(web::resource("/v2/filter").route(web::get().to(get_payment_filters)))
                 .service(web::resource("/aggregate").route(web::get().to(get_payments_aggregates)))
+                .service(
+                    web::resource("/authorizations/expiring")
+                        .route(web::get().to(payments_expiring_authorizations_list)),
+                )
                 .service(
                     web::resource("/v2/profile/filter")
                         .route(web::get().to(get_payment_filters_profile)),
@@ -934,6 +955,18 @@ impl Customers {
                         .route(web::get().to(get_customer_mandates)),
                 )
                 .service(web::resource("/list").route(web::get().to(customers_list)))
+                .service(
+                    web::resource("/{customer_id}/data_requests/export")
+                        .route(web::post().to(customers_data_export)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_requests/erasure")
+                        .route(web::post().to(customers_data_erasure)),
+                )
+                .service(
+                    web::resource("/{customer_id}/data_requests/{request_id}")
+                        .route(web::get().to(customers_data_request_retrieve)),
+                )
         }
 
         #[cfg(feature = "oltp")]
@@ -948,10 +981,30 @@ impl Customers {
                     web::resource("/{customer_id}/payment_methods")
                         .route(web::get().to(list_customer_payment_method_api)),
                 )
+                .service(
+                    web::resource("/payment_methods/{payment_method_id}")
+                        .route(web::post().to(customer_payment_method_update_api))
+                        .route(web::delete().to(customer_payment_method_delete_api)),
+                )
+                .service(
+                    web::resource("/payment_methods/{payment_method_id}/default")
+                        .route(web::post().to(customer_payment_method_set_default_api)),
+                )
                 .service(
                     web::resource("/{customer_id}/payment_methods/{payment_method_id}/default")
                         .route(web::post().to(default_payment_method_set_api)),
                 )
+                .service(
+                    web::resource("/{customer_id}/addresses")
+                        .route(web::post().to(customers_address_create))
+                        .route(web::get().to(customers_address_list)),
+                )
+                .service(
+                    web::resource("/{customer_id}/addresses/{address_id}")
+                        .route(web::get().to(customers_address_retrieve))
+                        .route(web::post().to(customers_address_update))
+                        .route(web::delete().to(customers_address_delete)),
+                )
                 .service(
                     web::resource("/{customer_id}")
                         .route(web::get().to(customers_retrieve))
@@ -984,7 +1037,9 @@ impl Refunds {
                 .service(
                     web::resource("/{id}/manual-update")
                         .route(web::put().to(refunds_manual_update)),
-                );
+                )
+                .service(web::resource("/{id}/approve").route(web::post().to(refunds_approve)))
+                .service(web::resource("/{id}/reject").route(web::post().to(refunds_reject)));
         }
         #[cfg(feature = "oltp")]
         {
@@ -996,6 +1051,12 @@ impl Refunds {
                         .route(web::get().to(refunds_retrieve))
                         .route(web::post().to(refunds_update)),
                 );
+
+            #[cfg(feature = "payouts")]
+            {
+                route = route
+                    .service(web::resource("/{id}/payout").route(web::post().to(refunds_payout)));
+            }
         }
         route
     }
@@ -1028,6 +1089,14 @@ impl Payouts {
                 );
         }
         route = route
+            .service(web::resource("/batch").route(web::post().to(payouts_batch_create)))
+            .service(
+                web::resource("/batch/{batch_id}").route(web::get().to(payouts_batch_retrieve)),
+            )
+            .service(
+                web::resource("/batch/{batch_id}/report")
+                    .route(web::get().to(payouts_batch_report)),
+            )
             .service(
                 web::resource("/{payout_id}")
                     .route(web::get().to(payouts_retrieve))
@@ -1056,6 +1125,10 @@ impl PaymentMethods {
                 web::resource("/filter")
                     .route(web::get().to(list_countries_currencies_for_connector_payment_method)),
             );
+            route = route.service(
+                web::resource("/fingerprint/{fingerprint_id}/links")
+                    .route(web::get().to(payment_method_fingerprint_links)),
+            );
         }
         #[cfg(feature = "oltp")]
         {
@@ -1071,6 +1144,28 @@ impl PaymentMethods {
                 .service(
                     web::resource("/migrate-batch").route(web::post().to(migrate_payment_methods)),
                 )
+                .service(
+                    web::resource("/migrate/jobs")
+                        .route(web::post().to(payment_method_migration_create)),
+                )
+                .service(
+                    web::resource("/migrate/jobs/{merchant_id}/{migration_id}")
+                        .route(web::get().to(payment_method_migration_retrieve)),
+                )
+                .service(
+                    web::resource("/migrate/jobs/{merchant_id}/{migration_id}/report")
+                        .route(web::get().to(payment_method_migration_report)),
+                )
+                .service(
+                    web::resource("/account_updater/{merchant_id}/enrollment")
+                        .route(web::post().to(account_updater_enroll))
+                        .route(web::delete().to(account_updater_unenroll)),
+                )
+                .service(
+                    web::resource("/health/{merchant_id}/enrollment")
+                        .route(web::post().to(payment_method_health_enroll))
+                        .route(web::delete().to(payment_method_health_unenroll)),
+                )
                 .service(
                     web::resource("/collect").route(web::post().to(initiate_pm_collect_link_flow)),
                 )
@@ -1078,6 +1173,11 @@ impl PaymentMethods {
                     web::resource("/collect/{merchant_id}/{collect_id}")
                         .route(web::get().to(render_pm_collect_link)),
                 )
+                .service(web::resource("/manage").route(web::post().to(initiate_pm_manage_link)))
+                .service(
+                    web::resource("/manage/{merchant_id}/{pm_manage_link_id}")
+                        .route(web::get().to(render_pm_manage_link)),
+                )
                 .service(
                     web::resource("/{payment_method_id}")
                         .route(web::get().to(payment_method_retrieve_api))
@@ -1097,6 +1197,18 @@ impl PaymentMethods {
                 .service(
                     web::resource("/auth/exchange").route(web::post().to(pm_auth::exchange_token)),
                 )
+                .service(
+                    web::resource("/auth/payments")
+                        .route(web::post().to(pm_auth::payment_initiation_create)),
+                )
+                .service(
+                    web::resource("/auth/payments/sync")
+                        .route(web::post().to(pm_auth::payment_initiation_sync)),
+                )
+                .service(
+                    web::resource("/auth/payments/{payment_id}/reverse")
+                        .route(web::post().to(pm_auth::payment_initiation_reverse)),
+                )
         }
         route
     }
@@ -1446,6 +1558,7 @@ impl Disputes {
                 web::resource("/evidence/{dispute_id}")
                     .route(web::get().to(retrieve_dispute_evidence)),
             )
+            .service(web::resource("/alerts").route(web::post().to(ingest_pre_dispute_alert)))
             .service(web::resource("/{dispute_id}").route(web::get().to(retrieve_dispute)))
     }
 }
@@ -1640,6 +1753,74 @@ impl Gsm {
     }
 }
 
+#[cfg(feature = "olap")]
+pub struct EmbeddedVault;
+
+#[cfg(feature = "olap")]
+impl EmbeddedVault {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/embedded_vault")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("/keys/{merchant_id}")
+                    .route(web::get().to(embedded_vault::list_data_keys)),
+            )
+            .service(
+                web::resource("/keys/{merchant_id}/rotate")
+                    .route(web::post().to(embedded_vault::rotate_data_key)),
+            )
+            .service(
+                web::resource("/access_logs/{merchant_id}")
+                    .route(web::get().to(embedded_vault::list_access_logs)),
+            )
+    }
+}
+
+#[cfg(feature = "olap")]
+pub struct ProcessTracker;
+
+#[cfg(feature = "olap")]
+impl ProcessTracker {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/process_tracker")
+            .app_data(web::Data::new(state))
+            .service(
+                web::scope("/tasks")
+                    .service(web::resource("").route(web::get().to(process_tracker::list_tasks)))
+                    .service(
+                        web::resource("/{task_id}")
+                            .route(web::get().to(process_tracker::retrieve_task)),
+                    )
+                    .service(
+                        web::resource("/{task_id}/run")
+                            .route(web::post().to(process_tracker::run_task_now)),
+                    )
+                    .service(
+                        web::resource("/{task_id}/reschedule")
+                            .route(web::post().to(process_tracker::reschedule_task)),
+                    )
+                    .service(
+                        web::resource("/{task_id}/cancel")
+                            .route(web::post().to(process_tracker::cancel_task)),
+                    ),
+            )
+            .service(
+                web::scope("/runners/{runner}")
+                    .service(
+                        web::resource("").route(web::get().to(process_tracker::retrieve_runner)),
+                    )
+                    .service(
+                        web::resource("/pause")
+                            .route(web::post().to(process_tracker::pause_runner)),
+                    )
+                    .service(
+                        web::resource("/resume")
+                            .route(web::post().to(process_tracker::resume_runner)),
+                    ),
+            )
+    }
+}
+
 #[cfg(feature = "olap")]
 pub struct Verify;
 
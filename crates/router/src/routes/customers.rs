@@ -6,6 +6,12 @@ use common_utils::id_type;
 use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+use crate::core::data_subject_requests;
 use crate::{
     core::{api_locking, customers::*},
     services::{api, authentication as auth, authorization::permissions::Permission},
@@ -277,3 +283,285 @@ pub async fn get_customer_mandates(
     ))
     .await
 }
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersDataExport))]
+pub async fn customers_data_export(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> impl Responder {
+    let flow = Flow::CustomersDataExport;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            data_subject_requests::export_customer_data(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersDataErasure))]
+pub async fn customers_data_erasure(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> impl Responder {
+    let flow = Flow::CustomersDataErasure;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            data_subject_requests::initiate_customer_data_erasure(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersDataRequestRetrieve))]
+pub async fn customers_data_request_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::CustomerId, String)>,
+) -> impl Responder {
+    let flow = Flow::CustomersDataRequestRetrieve;
+    let (customer_id, request_id) = path.into_inner();
+    let payload = api_models::customers::DataSubjectRequestRetrieveRequest {
+        customer_id,
+        request_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            data_subject_requests::retrieve_data_subject_request(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersAddressCreate))]
+pub async fn customers_address_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+    json_payload: web::Json<customers::CustomerAddressRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersAddressCreate;
+    let customer_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            create_customer_address(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersAddressList))]
+pub async fn customers_address_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::CustomerId>,
+) -> HttpResponse {
+    let flow = Flow::CustomersAddressList;
+    let payload = customers::CustomerId {
+        customer_id: path.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            list_customer_addresses(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersAddressRetrieve))]
+pub async fn customers_address_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::CustomerId, String)>,
+) -> HttpResponse {
+    let flow = Flow::CustomersAddressRetrieve;
+    let (customer_id, address_id) = path.into_inner();
+    let payload = customers::CustomerAddressId {
+        customer_id,
+        address_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            retrieve_customer_address(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersAddressUpdate))]
+pub async fn customers_address_update(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::CustomerId, String)>,
+    json_payload: web::Json<customers::CustomerAddressRequest>,
+) -> HttpResponse {
+    let flow = Flow::CustomersAddressUpdate;
+    let (customer_id, address_id) = path.into_inner();
+    let customer_address_id = customers::CustomerAddressId {
+        customer_id,
+        address_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            update_customer_address(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                customer_address_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::CustomersAddressDelete))]
+pub async fn customers_address_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::CustomerId, String)>,
+) -> HttpResponse {
+    let flow = Flow::CustomersAddressDelete;
+    let (customer_id, address_id) = path.into_inner();
+    let payload = customers::CustomerAddressId {
+        customer_id,
+        address_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            delete_customer_address(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -383,3 +383,43 @@ pub async fn delete_dispute_evidence(
     ))
     .await
 }
+
+/// Disputes - Ingest Pre-Dispute Alert
+///
+/// To record a chargeback prevention alert against a payment and resolve it as per the merchant's pre-dispute alert rule
+#[utoipa::path(
+    post,
+    path = "/disputes/alerts",
+    request_body=PreDisputeAlertRequest,
+    responses(
+        (status = 200, description = "The pre-dispute alert was recorded successfully", body = PreDisputeAlertResponse),
+        (status = 404, description = "Payment referenced by the alert does not exist in our records")
+    ),
+    tag = "Disputes",
+    operation_id = "Ingest a Pre-Dispute Alert",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::PreDisputeAlertIngest))]
+pub async fn ingest_pre_dispute_alert(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::PreDisputeAlertRequest>,
+) -> HttpResponse {
+    let flow = Flow::PreDisputeAlertIngest;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            disputes::ingest_pre_dispute_alert(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -0,0 +1,72 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::embedded_vault as embedded_vault_api;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, payment_methods::embedded_vault},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::EmbeddedVaultKeyRotate))]
+pub async fn rotate_data_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::EmbeddedVaultKeyRotate;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.clone(),
+        |state, _, merchant_id, _| embedded_vault::rotate_data_key(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::EmbeddedVaultKeyList))]
+pub async fn list_data_keys(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::EmbeddedVaultKeyList;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.clone(),
+        |state, _, merchant_id, _| embedded_vault::list_data_keys(state, merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::EmbeddedVaultAccessLogList))]
+pub async fn list_access_logs(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::MerchantId>,
+    query: web::Query<embedded_vault_api::AccessLogListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::EmbeddedVaultAccessLogList;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, _, constraints, _| {
+            embedded_vault::list_access_logs(state, merchant_id.clone(), constraints)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -29,7 +29,9 @@ pub enum ApiIdentifier {
     Blocklist,
     Forex,
     RustLockerMigration,
+    EmbeddedVault,
     Gsm,
+    ProcessTracker,
     Role,
     User,
     UserRole,
@@ -91,6 +93,14 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
+            | Flow::CustomersDataExport
+            | Flow::CustomersDataErasure
+            | Flow::CustomersDataRequestRetrieve
+            | Flow::CustomersAddressCreate
+            | Flow::CustomersAddressList
+            | Flow::CustomersAddressRetrieve
+            | Flow::CustomersAddressUpdate
+            | Flow::CustomersAddressDelete
             | Flow::CustomersList => Self::Customers,
 
             Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
@@ -100,18 +110,31 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsMigrate
+            | Flow::PaymentMethodMigrationCreate
+            | Flow::PaymentMethodMigrationRetrieve
+            | Flow::PaymentMethodMigrationReport
+            | Flow::AccountUpdaterEnroll
+            | Flow::AccountUpdaterUnenroll
+            | Flow::PaymentMethodHealthEnroll
+            | Flow::PaymentMethodHealthUnenroll
+            | Flow::PaymentMethodFingerprintLinks
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
             | Flow::PaymentMethodsRetrieve
             | Flow::PaymentMethodsUpdate
             | Flow::PaymentMethodsDelete
             | Flow::PaymentMethodCollectLink
+            | Flow::PaymentMethodManageLink
             | Flow::ValidatePaymentMethod
             | Flow::ListCountriesCurrencies
             | Flow::DefaultPaymentMethodsSet
             | Flow::PaymentMethodSave => Self::PaymentMethods,
 
-            Flow::PmAuthLinkTokenCreate | Flow::PmAuthExchangeToken => Self::PaymentMethodAuth,
+            Flow::PmAuthLinkTokenCreate
+            | Flow::PmAuthExchangeToken
+            | Flow::PmAuthPaymentInitiationCreate
+            | Flow::PmAuthPaymentInitiationSync
+            | Flow::PmAuthPaymentInitiationReversal => Self::PaymentMethodAuth,
 
             Flow::PaymentsCreate
             | Flow::PaymentsRetrieve
@@ -127,6 +150,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsList
             | Flow::PaymentsFilters
             | Flow::PaymentsAggregate
+            | Flow::PaymentsExpiringAuthorizationsList
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
@@ -140,6 +164,9 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PayoutsUpdate
             | Flow::PayoutsCancel
             | Flow::PayoutsFulfill
+            | Flow::PayoutsBatchCreate
+            | Flow::PayoutsBatchRetrieve
+            | Flow::PayoutsBatchReport
             | Flow::PayoutsList
             | Flow::PayoutsFilter
             | Flow::PayoutsAccounts
@@ -152,7 +179,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RefundsUpdate
             | Flow::RefundsList
             | Flow::RefundsFilters
-            | Flow::RefundsManualUpdate => Self::Refunds,
+            | Flow::RefundsManualUpdate
+            | Flow::RefundsApprove
+            | Flow::RefundsReject
+            | Flow::RefundsPayout => Self::Refunds,
 
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
@@ -171,7 +201,8 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
-            | Flow::DeleteDisputeEvidence => Self::Disputes,
+            | Flow::DeleteDisputeEvidence
+            | Flow::PreDisputeAlertIngest => Self::Disputes,
 
             Flow::CardsInfo => Self::CardsInfo,
 
@@ -196,11 +227,25 @@ impl From<Flow> for ApiIdentifier {
             Flow::Verification => Self::Verification,
 
             Flow::RustLockerMigration => Self::RustLockerMigration,
+
+            Flow::EmbeddedVaultKeyRotate
+            | Flow::EmbeddedVaultKeyList
+            | Flow::EmbeddedVaultAccessLogList => Self::EmbeddedVault,
+
             Flow::GsmRuleCreate
             | Flow::GsmRuleRetrieve
             | Flow::GsmRuleUpdate
             | Flow::GsmRuleDelete => Self::Gsm,
 
+            Flow::ProcessTrackerTaskList
+            | Flow::ProcessTrackerTaskRetrieve
+            | Flow::ProcessTrackerTaskRun
+            | Flow::ProcessTrackerTaskReschedule
+            | Flow::ProcessTrackerTaskCancel
+            | Flow::ProcessTrackerRunnerRetrieve
+            | Flow::ProcessTrackerRunnerPause
+            | Flow::ProcessTrackerRunnerResume => Self::ProcessTracker,
+
             Flow::ApplePayCertificatesMigration => Self::ApplePayCertificatesMigration,
 
             Flow::UserConnectAccount
@@ -30,7 +30,10 @@ use crate::{
     not(feature = "customer_v2")
 ))]
 use crate::{
-    core::{customers, payment_methods::migration},
+    core::{
+        customers,
+        payment_methods::{manage, migration},
+    },
     types::api::customers::CustomerRequest,
 };
 
@@ -168,6 +171,221 @@ pub async fn migrate_payment_methods(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodMigrationCreate))]
+pub async fn payment_method_migration_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_models::payment_methods::PaymentMethodMigrationCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodMigrationCreate;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req, _| async move {
+            let (key_store, merchant_account) =
+                get_merchant_account(&state, &req.merchant_id).await?;
+            payment_methods_routes::migration::create_payment_method_migration(
+                state,
+                merchant_account,
+                key_store,
+                req,
+            )
+            .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodMigrationRetrieve))]
+pub async fn payment_method_migration_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodMigrationRetrieve;
+    let (merchant_id, migration_id) = path.into_inner();
+    let payload = api_models::payment_methods::PaymentMethodMigrationRetrieveRequest {
+        merchant_id,
+        migration_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            payment_methods_routes::migration::retrieve_payment_method_migration(state, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodMigrationReport))]
+pub async fn payment_method_migration_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodMigrationReport;
+    let (merchant_id, migration_id) = path.into_inner();
+    let payload = api_models::payment_methods::PaymentMethodMigrationRetrieveRequest {
+        merchant_id,
+        migration_id,
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req, _| {
+            payment_methods_routes::migration::get_payment_method_migration_report(state, req)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterEnroll))]
+pub async fn account_updater_enroll(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::AccountUpdaterEnroll;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| async move {
+            let (_, merchant_account) = get_merchant_account(&state, &merchant_id).await?;
+            payment_methods_routes::account_updater::enroll_merchant(state, merchant_account).await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::AccountUpdaterUnenroll))]
+pub async fn account_updater_unenroll(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::AccountUpdaterUnenroll;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| async move {
+            let (_, merchant_account) = get_merchant_account(&state, &merchant_id).await?;
+            payment_methods_routes::account_updater::unenroll_merchant(state, merchant_account)
+                .await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodHealthEnroll))]
+pub async fn payment_method_health_enroll(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodHealthEnroll;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| async move {
+            let (_, merchant_account) = get_merchant_account(&state, &merchant_id).await?;
+            payment_methods_routes::health::enroll_merchant(state, merchant_account).await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodHealthUnenroll))]
+pub async fn payment_method_health_unenroll(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<id_type::MerchantId>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodHealthUnenroll;
+    let merchant_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id,
+        |state, _, merchant_id, _| async move {
+            let (_, merchant_account) = get_merchant_account(&state, &merchant_id).await?;
+            payment_methods_routes::health::unenroll_merchant(state, merchant_account).await
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodFingerprintLinks))]
+pub async fn payment_method_fingerprint_links(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    query_payload: web::Query<api_models::payment_methods::PaymentMethodFingerprintLinksRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodFingerprintLinks;
+    let fingerprint_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            payment_methods_routes::fingerprint::list_fingerprint_links(
+                state,
+                auth.merchant_account,
+                fingerprint_id.clone(),
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::CustomerRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -709,6 +927,171 @@ pub async fn default_payment_method_set_api(
     ))
     .await
 }
+
+/// Set a saved payment method as the default payment method of the customer the ephemeral key
+/// was issued for
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::DefaultPaymentMethodsSet))]
+pub async fn customer_payment_method_set_default_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::DefaultPaymentMethodsSet;
+    let payload = PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let ephemeral_api_key = match auth::get_api_key(req.headers()) {
+        Ok(api_key) => api_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            manage::set_default_customer_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                ephemeral_api_key,
+            )
+        },
+        &auth::EphemeralKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Update the nick name and the billing address of a saved payment method of the customer the
+/// ephemeral key was issued for
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsUpdate))]
+pub async fn customer_payment_method_update_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<payment_methods::CustomerPaymentMethodUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsUpdate;
+    let payment_method_id = path.into_inner();
+    let ephemeral_api_key = match auth::get_api_key(req.headers()) {
+        Ok(api_key) => api_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            manage::update_customer_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payment_method_id.clone(),
+                req,
+                ephemeral_api_key,
+            )
+        },
+        &auth::EphemeralKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Delete a saved payment method of the customer the ephemeral key was issued for
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodsDelete))]
+pub async fn customer_payment_method_delete_api(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodsDelete;
+    let payload = PaymentMethodId {
+        payment_method_id: path.into_inner(),
+    };
+    let ephemeral_api_key = match auth::get_api_key(req.headers()) {
+        Ok(api_key) => api_key,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            manage::delete_customer_payment_method(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+                ephemeral_api_key,
+            )
+        },
+        &auth::EphemeralKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Generate a link to a hosted page for managing the saved payment methods of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodManageLink))]
+pub async fn initiate_pm_manage_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payment_methods::PaymentMethodManageLinkRequest>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodManageLink;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, req, _| {
+            manage::initiate_pm_manage_link(state, auth.merchant_account, auth.key_store, req)
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Render the hosted page for managing the saved payment methods of a customer
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+#[instrument(skip_all, fields(flow = ?Flow::PaymentMethodManageLink))]
+pub async fn render_pm_manage_link(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(id_type::MerchantId, String)>,
+) -> HttpResponse {
+    let flow = Flow::PaymentMethodManageLink;
+    let (merchant_id, pm_manage_link_id) = path.into_inner();
+    let payload = payment_methods::PaymentMethodManageLinkRenderRequest {
+        merchant_id: merchant_id.clone(),
+        pm_manage_link_id,
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            manage::render_pm_manage_link(state, auth.merchant_account, req)
+        },
+        &auth::MerchantIdAuth(merchant_id),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(test)]
 mod tests {
     #![allow(clippy::unwrap_used)]
@@ -1230,6 +1230,38 @@ pub async fn get_payments_aggregates(
     .await
 }
 
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsExpiringAuthorizationsList))]
+#[cfg(feature = "olap")]
+pub async fn payments_expiring_authorizations_list(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    payload: web::Query<payment_types::ExpiringAuthorizationsListConstraints>,
+) -> impl Responder {
+    let flow = Flow::PaymentsExpiringAuthorizationsList;
+    let payload = payload.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth: auth::AuthenticationData, req, _| {
+            payments::authorization_expiry::list_expiring_authorizations(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[cfg(feature = "oltp")]
 #[instrument(skip_all, fields(flow = ?Flow::PaymentsApprove, payment_id))]
 pub async fn payments_approve(
@@ -192,6 +192,90 @@ pub async fn payouts_fulfill(
     .await
 }
 
+/// Payouts - Batch Create
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchCreate))]
+pub async fn payouts_batch_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<payout_types::PayoutBatchCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req, _| {
+            batch::payout_batch_create_core(state, auth.merchant_account, auth.key_store, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PayoutWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Retrieve
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchRetrieve))]
+pub async fn payouts_batch_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchRetrieve;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::payout_batch_retrieve_core(state, auth.merchant_account, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Payouts - Batch Report
+#[instrument(skip_all, fields(flow = ?Flow::PayoutsBatchReport))]
+pub async fn payouts_batch_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::PayoutsBatchReport;
+    let payload = payout_types::PayoutBatchRetrieveRequest {
+        batch_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req, _| {
+            batch::payout_batch_report_core(state, auth.merchant_account, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::PayoutRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 /// Payouts - List
 #[cfg(feature = "olap")]
 #[instrument(skip_all, fields(flow = ?Flow::PayoutsList))]
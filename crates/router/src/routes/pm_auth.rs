@@ -2,7 +2,11 @@ use actix_web::{web, HttpRequest, Responder};
 use api_models as api_types;
 use router_env::{instrument, tracing, types::Flow};
 
-use crate::{core::api_locking, routes::AppState, services::api as oss_api};
+use crate::{
+    core::api_locking,
+    routes::{lock_utils, AppState},
+    services::{api as oss_api, authentication as auth},
+};
 
 #[instrument(skip_all, fields(flow = ?Flow::PmAuthLinkTokenCreate))]
 pub async fn link_token_create(
@@ -12,10 +16,7 @@ pub async fn link_token_create(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
     let flow = Flow::PmAuthLinkTokenCreate;
-    let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
-        req.headers(),
-        &payload,
-    ) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return oss_api::log_and_return_error_response(e),
     };
@@ -46,10 +47,7 @@ pub async fn exchange_token(
 ) -> impl Responder {
     let payload = json_payload.into_inner();
     let flow = Flow::PmAuthExchangeToken;
-    let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
-        req.headers(),
-        &payload,
-    ) {
+    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return oss_api::log_and_return_error_response(e),
     };
@@ -71,3 +69,115 @@ pub async fn exchange_token(
     ))
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::PmAuthPaymentInitiationCreate))]
+pub async fn payment_initiation_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::pm_auth::PaymentInitiationCreateRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+    let flow = Flow::PmAuthPaymentInitiationCreate;
+    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+        Ok((auth, _auth_flow)) => (auth, _auth_flow),
+        Err(e) => return oss_api::log_and_return_error_response(e),
+    };
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, _| {
+            crate::core::pm_auth::payment_initiation::initiate_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        &*auth,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmAuthPaymentInitiationSync))]
+pub async fn payment_initiation_sync(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<api_types::pm_auth::PaymentInitiationSyncRequest>,
+) -> impl Responder {
+    let payload = json_payload.into_inner();
+    let flow = Flow::PmAuthPaymentInitiationSync;
+    let (auth, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+        Ok((auth, _auth_flow)) => (auth, _auth_flow),
+        Err(e) => return oss_api::log_and_return_error_response(e),
+    };
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, _| {
+            crate::core::pm_auth::payment_initiation::sync_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        &*auth,
+        locking_action,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::PmAuthPaymentInitiationReversal))]
+pub async fn payment_initiation_reverse(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<common_utils::id_type::PaymentId>,
+    json_payload: web::Json<api_types::pm_auth::PaymentInitiationReversalRequest>,
+) -> impl Responder {
+    let mut payload = json_payload.into_inner();
+    payload.payment_id = path.into_inner();
+    let flow = Flow::PmAuthPaymentInitiationReversal;
+    let locking_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: payload.payment_id.get_string_repr().to_owned(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+    Box::pin(oss_api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, payload, _| {
+            crate::core::pm_auth::payment_initiation::reverse_payment(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                payload,
+            )
+        },
+        &auth::HeaderAuth(auth::ApiKeyAuth),
+        locking_action,
+    ))
+    .await
+}
@@ -0,0 +1,193 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::process_tracker as process_tracker_api_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, process_tracker},
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskList))]
+pub async fn list_tasks(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<process_tracker_api_types::ProcessTrackerListConstraints>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskList;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, user_from_token, constraints, _| {
+            process_tracker::list_tasks(state, user_from_token, constraints)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskRetrieve))]
+pub async fn retrieve_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user_from_token, task_id, _| {
+            process_tracker::retrieve_task(state, user_from_token, task_id)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskRun))]
+pub async fn run_task_now(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskRun;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user_from_token, task_id, req_state| {
+            process_tracker::run_task_now(state, req_state, user_from_token, task_id)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskReschedule))]
+pub async fn reschedule_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<process_tracker_api_types::ProcessTrackerRescheduleRequest>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskReschedule;
+    let task_id = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, user_from_token, request, req_state| {
+            process_tracker::reschedule_task(
+                state,
+                req_state,
+                user_from_token,
+                task_id.clone(),
+                request,
+            )
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerTaskCancel))]
+pub async fn cancel_task(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerTaskCancel;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user_from_token, task_id, req_state| {
+            process_tracker::cancel_task(state, req_state, user_from_token, task_id)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRunnerRetrieve))]
+pub async fn retrieve_runner(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRunnerRetrieve;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user_from_token, runner, _| {
+            process_tracker::retrieve_runner(state, user_from_token, runner)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerRead),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRunnerPause))]
+pub async fn pause_runner(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRunnerPause;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user_from_token, runner, req_state| {
+            process_tracker::pause_runner(state, req_state, user_from_token, runner)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::ProcessTrackerRunnerResume))]
+pub async fn resume_runner(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::ProcessTrackerRunnerResume;
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, user_from_token, runner, req_state| {
+            process_tracker::resume_runner(state, req_state, user_from_token, runner)
+        },
+        &auth::JWTAuth(Permission::ProcessTrackerWrite),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -1,9 +1,13 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use router_env::{instrument, tracing, Flow};
+use router_env::{instrument, tracing, types::FlowMetric, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{
+        api_locking::{self, GetLockingInput},
+        refunds::*,
+    },
+    routes::lock_utils,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -36,13 +40,15 @@ pub async fn refunds_create(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth, req, _| {
+        |state, (auth, user_id): auth::AuthenticationDataWithOptionalUserId, req, req_state| {
             refund_create_core(
                 state,
+                req_state,
                 auth.merchant_account,
                 auth.profile_id,
                 auth.key_store,
                 req,
+                user_id,
             )
         },
         auth::auth_type(
@@ -421,3 +427,193 @@ pub async fn refunds_manual_update(
     ))
     .await
 }
+
+/// Refunds - Approve
+///
+/// To approve a refund that is pending approval as per the refund policy of the business profile
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/approve",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body=RefundApprovalRequest,
+    responses(
+        (status = 200, description = "Refund approved", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval or was created by the approving user")
+    ),
+    tag = "Refunds",
+    operation_id = "Approve a Refund",
+    security(("jwt_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsApprove))]
+#[cfg(feature = "olap")]
+pub async fn refunds_approve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsApprove;
+    let mut refund_approval_req = payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    let locking_action = refund_approval_req.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, req_state| {
+            refund_approve_core(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                user_id,
+                req,
+            )
+        },
+        &auth::JWTAuth(Permission::RefundWrite),
+        locking_action,
+    ))
+    .await
+}
+
+/// Refunds - Reject
+///
+/// To reject a refund that is pending approval as per the refund policy of the business profile
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/reject",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body=RefundApprovalRequest,
+    responses(
+        (status = 200, description = "Refund rejected", body = RefundResponse),
+        (status = 400, description = "Refund is not pending approval")
+    ),
+    tag = "Refunds",
+    operation_id = "Reject a Refund",
+    security(("jwt_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsReject))]
+#[cfg(feature = "olap")]
+pub async fn refunds_reject(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Json<refunds::RefundApprovalRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsReject;
+    let mut refund_approval_req = payload.into_inner();
+    refund_approval_req.refund_id = path.into_inner();
+    let locking_action = refund_approval_req.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_approval_req,
+        |state, (auth, user_id): auth::AuthenticationDataWithUserId, req, req_state| {
+            refund_reject_core(
+                state,
+                req_state,
+                auth.merchant_account,
+                auth.profile_id,
+                user_id,
+                req,
+            )
+        },
+        &auth::JWTAuth(Permission::RefundWrite),
+        locking_action,
+    ))
+    .await
+}
+
+/// Refunds - Payout
+///
+/// To send the amount of a failed refund to the customer as a payout
+#[utoipa::path(
+    post,
+    path = "/refunds/{refund_id}/payout",
+    params(
+        ("refund_id" = String, Path, description = "The identifier for refund")
+    ),
+    request_body=RefundPayoutRequest,
+    responses(
+        (status = 200, description = "Refund sent as a payout", body = RefundResponse),
+        (status = 400, description = "Refund cannot be sent as a payout")
+    ),
+    tag = "Refunds",
+    operation_id = "Send a Refund as a Payout",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RefundsPayout))]
+#[cfg(feature = "payouts")]
+pub async fn refunds_payout(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<refunds::RefundPayoutRequest>,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RefundsPayout;
+    let mut refund_payout_req = json_payload.into_inner();
+    refund_payout_req.refund_id = path.into_inner();
+    let locking_action = refund_payout_req.get_locking_input(flow.clone());
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        refund_payout_req,
+        |state, auth, req, _| {
+            refund_payout_core(
+                state,
+                auth.merchant_account,
+                auth.profile_id,
+                auth.key_store,
+                req,
+            )
+        },
+        auth::auth_type(
+            &auth::HeaderAuth(auth::ApiKeyAuth),
+            &auth::JWTAuth(Permission::RefundWrite),
+            req.headers(),
+        ),
+        locking_action,
+    ))
+    .await
+}
+
+#[cfg(feature = "payouts")]
+impl GetLockingInput for refunds::RefundPayoutRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.refund_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
+
+impl GetLockingInput for refunds::RefundApprovalRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.refund_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
@@ -68,7 +68,11 @@ pub async fn get_store(
     #[cfg(feature = "olap")]
     // this would get abstracted, for all cases
     #[allow(clippy::useless_conversion)]
-    let conf = (master_config.into(), replica_config.into());
+    let conf = (
+        master_config.into(),
+        replica_config.into(),
+        config.replica_reads.clone(),
+    );
 
     let store: RouterStore<StoreType> = if test_transaction {
         RouterStore::test_store(conf, tenant, &config.redis, master_enc_key).await?
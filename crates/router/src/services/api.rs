@@ -773,6 +773,10 @@ where
 
     request_state.event_context.record_info(auth_type.clone());
 
+    if let Some(merchant_id) = auth_type.get_merchant_id() {
+        session_state.store.add_merchant_id(merchant_id);
+    }
+
     let merchant_id = auth_type
         .get_merchant_id()
         .cloned()
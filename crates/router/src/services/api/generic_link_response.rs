@@ -32,6 +32,9 @@ pub fn build_generic_link_html(
         GenericLinksData::SecurePaymentLink(payment_link_data) => {
             build_secure_payment_link_html(payment_link_data)
         }
+        GenericLinksData::PaymentMethodManage(pm_manage_data) => {
+            build_pm_manage_link_html(&pm_manage_data)
+        }
     }
 }
 
@@ -251,3 +254,49 @@ pub fn build_pm_collect_link_status_html(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to render payment method collect link status HTML template")
 }
+
+pub fn build_pm_manage_link_html(
+    link_data: &GenericLinkStatusData,
+) -> CustomResult<String, errors::ApiErrorResponse> {
+    let mut tera = Tera::default();
+    let mut context = Context::new();
+
+    // Insert dynamic context in CSS
+    let css_dynamic_context = "{{ color_scheme }}";
+    let css_template =
+        include_str!("../../core/generic_link/payment_method_manage/styles.css").to_string();
+    let final_css = format!("{}\n{}", css_dynamic_context, css_template);
+    let _ = tera.add_raw_template("pm_manage_link_styles", &final_css);
+    context.insert("color_scheme", &link_data.css_data);
+
+    let css_style_tag = tera
+        .render("pm_manage_link_styles", &context)
+        .map(|css| format!("<style>{}</style>", css))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to render payment method manage link CSS template")?;
+
+    // Insert dynamic context in JS
+    let js_dynamic_context = "{{ script_data }}";
+    let js_template =
+        include_str!("../../core/generic_link/payment_method_manage/script.js").to_string();
+    let final_js = format!("{}\n{}", js_dynamic_context, js_template);
+    let _ = tera.add_raw_template("pm_manage_link_script", &final_js);
+    context.insert("script_data", &link_data.js_data);
+
+    let js_script_tag = tera
+        .render("pm_manage_link_script", &context)
+        .map(|js| format!("<script>{}</script>", js))
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to render payment method manage link JS template")?;
+
+    // Build HTML
+    let html_template =
+        include_str!("../../core/generic_link/payment_method_manage/index.html").to_string();
+    let _ = tera.add_raw_template("payment_method_manage_link", &html_template);
+    context.insert("css_style_tag", &css_style_tag);
+    context.insert("js_script_tag", &js_script_tag);
+
+    tera.render("payment_method_manage_link", &context)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to render payment method manage link HTML template")
+}
@@ -1613,6 +1613,56 @@ where
     }
 }
 
+pub type AuthenticationDataWithOptionalUserId = (AuthenticationData, Option<String>);
+
+#[async_trait]
+impl<A, I> AuthenticateAndFetch<AuthenticationDataWithOptionalUserId, A> for HeaderAuth<I>
+where
+    A: SessionStateInfo + Sync,
+    I: Sync + Send,
+    Self: AuthenticateAndFetch<AuthenticationData, A>,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationDataWithOptionalUserId, AuthenticationType)> {
+        let (auth, auth_type) =
+            AuthenticateAndFetch::<AuthenticationData, A>::authenticate_and_fetch(
+                self,
+                request_headers,
+                state,
+            )
+            .await?;
+        Ok(((auth, None), auth_type))
+    }
+}
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationDataWithOptionalUserId, A> for JWTAuth
+where
+    A: SessionStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationDataWithOptionalUserId, AuthenticationType)> {
+        let (auth, auth_type) =
+            AuthenticateAndFetch::<AuthenticationData, A>::authenticate_and_fetch(
+                self,
+                request_headers,
+                state,
+            )
+            .await?;
+        let user_id = match &auth_type {
+            AuthenticationType::MerchantJwt { user_id, .. } => user_id.clone(),
+            _ => None,
+        };
+        Ok(((auth, user_id), auth_type))
+    }
+}
+
 pub struct DashboardNoPermissionAuth;
 
 #[cfg(feature = "olap")]
@@ -1784,6 +1834,18 @@ impl ClientSecretFetch for api_models::pm_auth::ExchangeTokenCreateRequest {
     }
 }
 
+impl ClientSecretFetch for api_models::pm_auth::PaymentInitiationCreateRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+}
+
+impl ClientSecretFetch for api_models::pm_auth::PaymentInitiationSyncRequest {
+    fn get_client_secret(&self) -> Option<&String> {
+        self.client_secret.as_ref()
+    }
+}
+
 impl ClientSecretFetch for api_models::payment_methods::PaymentMethodUpdate {
     fn get_client_secret(&self) -> Option<&String> {
         self.client_secret.as_ref()
@@ -207,7 +207,7 @@ fn get_group_description(group: PermissionGroup) -> &'static str {
             "View Routing, 3DS Decision Manager, Surcharge Decision Manager"
         }
         PermissionGroup::WorkflowsManage => {
-            "Create, modify and delete Routing, 3DS Decision Manager, Surcharge Decision Manager"
+            "Create, modify and delete Routing, 3DS Decision Manager, Surcharge Decision Manager, Scheduled Tasks"
         }
         PermissionGroup::AnalyticsView => "View Analytics",
         PermissionGroup::UsersView => "View Users",
@@ -58,12 +58,14 @@ pub static WORKFLOWS_VIEW: [Permission; 5] = [
     Permission::MerchantAccountRead,
 ];
 
-pub static WORKFLOWS_MANAGE: [Permission; 5] = [
+pub static WORKFLOWS_MANAGE: [Permission; 7] = [
     Permission::RoutingWrite,
     Permission::ThreeDsDecisionManagerWrite,
     Permission::SurchargeDecisionManagerWrite,
     Permission::MerchantConnectorAccountRead,
     Permission::MerchantAccountRead,
+    Permission::ProcessTrackerRead,
+    Permission::ProcessTrackerWrite,
 ];
 
 pub static ANALYTICS_VIEW: [Permission; 3] = [
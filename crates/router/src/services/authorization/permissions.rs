@@ -35,6 +35,8 @@ pub enum Permission {
     PayoutRead,
     PayoutWrite,
     GenerateReport,
+    ProcessTrackerRead,
+    ProcessTrackerWrite,
 }
 
 impl Permission {
@@ -77,6 +79,10 @@ impl Permission {
             Self::PayoutRead => "View all payouts",
             Self::PayoutWrite => "Create payout, download payout data",
             Self::GenerateReport => "Generate reports for payments, refunds and disputes",
+            Self::ProcessTrackerRead => "View scheduled background tasks",
+            Self::ProcessTrackerWrite => {
+                "Run, reschedule and cancel scheduled background tasks, pause task runners"
+            }
         }
     }
 }
@@ -89,6 +89,6 @@ where
                 }
             }
         }
-        None => Ok(router_data),
+        None => connector_integration.handle_local_response(&router_data),
     }
 }
@@ -499,7 +499,14 @@ impl ConnectorData {
                 enums::Connector::Signifyd
                 | enums::Connector::Riskified
                 | enums::Connector::Gpayments
-                | enums::Connector::Threedsecureio => {
+                | enums::Connector::Threedsecureio
+                | enums::Connector::Truelayer => {
+                    Err(report!(errors::ConnectorError::InvalidConnectorName)
+                        .attach_printable(format!("invalid connector name: {connector_name}")))
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                }
+                #[cfg(feature = "dummy_connector")]
+                enums::Connector::DummyBank => {
                     Err(report!(errors::ConnectorError::InvalidConnectorName)
                         .attach_printable(format!("invalid connector name: {connector_name}")))
                     .change_context(errors::ApiErrorResponse::InternalServerError)
@@ -163,6 +163,11 @@ impl ForeignTryFrom<domain::BusinessProfile> for BusinessProfileResponse {
             outgoing_webhook_custom_http_headers,
             tax_connector_id: item.tax_connector_id,
             is_tax_connector_enabled: item.is_tax_connector_enabled,
+            refund_policy: item.refund_policy.map(ForeignInto::foreign_into),
+            auto_capture_policy: item.auto_capture_policy.map(ForeignInto::foreign_into),
+            authorization_expiry_action: item.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: item.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: item.is_account_ownership_check_enabled,
         })
     }
 }
@@ -228,6 +233,11 @@ impl ForeignTryFrom<domain::BusinessProfile> for BusinessProfileResponse {
             order_fulfillment_time_origin: item.order_fulfillment_time_origin,
             tax_connector_id: item.tax_connector_id,
             is_tax_connector_enabled: item.is_tax_connector_enabled,
+            refund_policy: item.refund_policy.map(ForeignInto::foreign_into),
+            auto_capture_policy: item.auto_capture_policy.map(ForeignInto::foreign_into),
+            authorization_expiry_action: item.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: item.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: item.is_account_ownership_check_enabled,
         })
     }
 }
@@ -347,6 +357,11 @@ pub async fn create_business_profile_from_merchant_account(
                 .map(Into::into),
             tax_connector_id: request.tax_connector_id,
             is_tax_connector_enabled: request.is_tax_connector_enabled,
+            refund_policy: request.refund_policy.map(ForeignInto::foreign_into),
+            auto_capture_policy: request.auto_capture_policy.map(ForeignInto::foreign_into),
+            authorization_expiry_action: request.authorization_expiry_action,
+            is_pre_debit_balance_check_enabled: request.is_pre_debit_balance_check_enabled,
+            is_account_ownership_check_enabled: request.is_account_ownership_check_enabled,
         },
     ))
 }
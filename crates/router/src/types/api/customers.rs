@@ -1,12 +1,21 @@
 use api_models::customers;
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 pub use api_models::customers::GlobalId;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+pub use api_models::customers::{
+    CustomerAddressDeleteResponse, CustomerAddressId, CustomerAddressListResponse,
+    CustomerAddressRequest, CustomerAddressResponse,
+};
 pub use api_models::customers::{
-    CustomerDeleteResponse, CustomerId, CustomerListRequest, CustomerRequest,
+    CustomerDeleteResponse, CustomerId, CustomerListRequest, CustomerRequest, CustomerTaxId,
     CustomerUpdateRequest, UpdateCustomerId,
 };
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 use hyperswitch_domain_models::customer;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use masking::ExposeInterface;
+#[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
+use router_env::logger;
 use serde::Serialize;
 
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
@@ -41,6 +50,12 @@ impl ForeignFrom<(domain::Customer, Option<payments::AddressDetails>)> for Custo
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            tax_ids: cust.tax_ids.and_then(|tax_ids| {
+                serde_json::from_value(tax_ids.into_inner().expose())
+                    .map_err(|error| logger::error!(?error, "Failed to parse customer tax ids"))
+                    .ok()
+            }),
+            segments: cust.segments,
         }
         .into()
     }
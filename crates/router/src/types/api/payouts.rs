@@ -1,9 +1,10 @@
 pub use api_models::payouts::{
     AchBankTransfer, BacsBankTransfer, Bank as BankPayout, CardPayout, PayoutActionRequest,
-    PayoutAttemptResponse, PayoutCreateRequest, PayoutCreateResponse, PayoutLinkResponse,
-    PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters, PayoutListResponse,
-    PayoutMethodData, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest, PixBankTransfer,
-    SepaBankTransfer, Wallet as WalletPayout,
+    PayoutAttemptResponse, PayoutBatchCreateRequest, PayoutBatchRecord, PayoutBatchReportRecord,
+    PayoutBatchResponse, PayoutBatchRetrieveRequest, PayoutCreateRequest, PayoutCreateResponse,
+    PayoutLinkResponse, PayoutListConstraints, PayoutListFilterConstraints, PayoutListFilters,
+    PayoutListResponse, PayoutMethodData, PayoutRequest, PayoutRetrieveBody, PayoutRetrieveRequest,
+    PixBankTransfer, SepaBankTransfer, Wallet as WalletPayout,
 };
 pub use hyperswitch_domain_models::router_flow_types::payouts::{
     PoCancel, PoCreate, PoEligibility, PoFulfill, PoQuote, PoRecipient, PoRecipientAccount, PoSync,
@@ -1,6 +1,10 @@
 pub use api_models::refunds::{
-    RefundRequest, RefundResponse, RefundStatus, RefundType, RefundUpdateRequest,
-    RefundsRetrieveRequest,
+    RefundApprovalRequest, RefundRequest, RefundResponse, RefundStatus, RefundType,
+    RefundUpdateRequest, RefundsRetrieveRequest,
+};
+#[cfg(feature = "payouts")]
+pub use api_models::refunds::{
+    RefundPayoutDetails, RefundPayoutMethod, RefundPayoutRequest, RefundPayoutTrigger,
 };
 pub use hyperswitch_domain_models::router_flow_types::refunds::{Execute, RSync};
 pub use hyperswitch_interfaces::api::refunds::{Refund, RefundExecute, RefundSync};
@@ -15,6 +19,7 @@ impl ForeignFrom<storage_enums::RefundStatus> for RefundStatus {
             storage_enums::RefundStatus::ManualReview => Self::Review,
             storage_enums::RefundStatus::Pending => Self::Pending,
             storage_enums::RefundStatus::Success => Self::Succeeded,
+            storage_enums::RefundStatus::PendingApproval => Self::PendingApproval,
         }
     }
 }
@@ -1,3 +1,4 @@
+pub mod account_updater;
 pub mod address;
 pub mod api_keys;
 pub mod authentication;
@@ -7,10 +8,13 @@ pub mod blocklist_fingerprint;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
+pub mod card_vault;
 pub mod cards_info;
 pub mod configs;
+pub mod customer_address_label;
 pub mod customers;
 pub mod dashboard_metadata;
+pub mod data_subject_request;
 pub mod dispute;
 pub mod enums;
 pub mod ephemeral_key;
@@ -29,7 +33,9 @@ pub mod merchant_key_store;
 pub mod payment_attempt;
 pub mod payment_link;
 pub mod payment_method;
+pub mod payment_method_migration;
 pub mod payout_attempt;
+pub mod payout_batch;
 pub mod payouts;
 pub mod refund;
 pub mod reverse_lookup;
@@ -43,8 +49,8 @@ pub mod user_role;
 use std::collections::HashMap;
 
 pub use diesel_models::{
-    process_tracker::business_status, ProcessTracker, ProcessTrackerNew, ProcessTrackerRunner,
-    ProcessTrackerUpdate,
+    process_tracker::business_status, ProcessTracker, ProcessTrackerFilters, ProcessTrackerNew,
+    ProcessTrackerRunner, ProcessTrackerUpdate,
 };
 pub use hyperswitch_domain_models::payments::{
     payment_attempt::{PaymentAttempt, PaymentAttemptNew, PaymentAttemptUpdate},
@@ -59,13 +65,14 @@ pub use hyperswitch_domain_models::payouts::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
-    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, generic_link::*, gsm::*, locker_mock_up::*, mandate::*,
-    merchant_account::*, merchant_connector_account::*, merchant_key_store::*, payment_link::*,
-    payment_method::*, process_tracker::*, refund::*, reverse_lookup::*, role::*,
-    routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
+    account_updater::*, address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
+    blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, card_vault::*,
+    cards_info::*, configs::*, customer_address_label::*, customers::*, dashboard_metadata::*,
+    data_subject_request::*, dispute::*, ephemeral_key::*, events::*, file::*, fraud_check::*,
+    generic_link::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
+    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
+    payment_method_migration::*, payout_batch::*, process_tracker::*, refund::*, reverse_lookup::*,
+    role::*, routing_algorithm::*, unified_translations::*, user::*, user_authentication_method::*,
     user_role::*,
 };
 use crate::types::api::routing;
@@ -0,0 +1,4 @@
+pub use diesel_models::account_updater::{
+    AccountUpdaterBatchTrackingData, AccountUpdaterItem, AccountUpdaterItemNew,
+    AccountUpdaterItemUpdate, AccountUpdaterTrackingData,
+};
@@ -0,0 +1,4 @@
+pub use diesel_models::card_vault::{
+    VaultAccessLog, VaultAccessLogNew, VaultDataKey, VaultDataKeyNew, VaultDataKeyUpdate,
+    VaultEntry, VaultEntryNew, VaultEntryUpdate, VaultKeyRotationTrackingData,
+};
@@ -0,0 +1,3 @@
+pub use diesel_models::customer_address_label::{
+    CustomerAddressLabel, CustomerAddressLabelNew, CustomerAddressLabelUpdate,
+};
@@ -0,0 +1,4 @@
+pub use diesel_models::data_subject_request::{
+    DataSubjectErasureTrackingData, DataSubjectRequest, DataSubjectRequestNew,
+    DataSubjectRequestUpdate,
+};
@@ -121,3 +121,9 @@ pub struct PaymentMethodStatusTrackingData {
     pub curr_status: enums::PaymentMethodStatus,
     pub merchant_id: common_utils::id_type::MerchantId,
 }
+
+/// The data the recurring payment method health sweep of a merchant operates on
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
+pub struct PaymentMethodHealthTrackingData {
+    pub merchant_id: common_utils::id_type::MerchantId,
+}
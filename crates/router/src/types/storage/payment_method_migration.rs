@@ -0,0 +1,5 @@
+pub use diesel_models::payment_method_migration::{
+    PaymentMethodMigration, PaymentMethodMigrationItem, PaymentMethodMigrationItemNew,
+    PaymentMethodMigrationItemUpdate, PaymentMethodMigrationNew,
+    PaymentMethodMigrationTrackingData, PaymentMethodMigrationUpdate,
+};
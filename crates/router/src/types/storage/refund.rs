@@ -3,7 +3,8 @@ use async_bb8_diesel::AsyncRunQueryDsl;
 use common_utils::errors::CustomResult;
 use diesel::{associations::HasTable, ExpressionMethods, QueryDsl};
 pub use diesel_models::refund::{
-    Refund, RefundCoreWorkflow, RefundNew, RefundUpdate, RefundUpdateInternal,
+    DailyRefundLimitReservation, Refund, RefundCoreWorkflow, RefundNew, RefundUpdate,
+    RefundUpdateInternal,
 };
 use diesel_models::{
     enums::{Currency, RefundStatus},
@@ -2,7 +2,7 @@
 use actix_web::http::header::HeaderMap;
 use api_models::{
     enums as api_enums, gsm as gsm_api_types, payment_methods, payments,
-    routing::ConnectorSelection,
+    process_tracker as process_tracker_api_types, routing::ConnectorSelection,
 };
 use common_utils::{
     consts::X_HS_LATENCY,
@@ -81,6 +81,17 @@ impl ForeignFrom<api_models::refunds::RefundType> for storage_enums::RefundType
     }
 }
 
+impl ForeignFrom<storage_enums::RefundType> for api_models::refunds::RefundType {
+    fn foreign_from(item: storage_enums::RefundType) -> Self {
+        match item {
+            storage_enums::RefundType::InstantRefund => Self::Instant,
+            storage_enums::RefundType::RegularRefund | storage_enums::RefundType::RetryRefund => {
+                Self::Scheduled
+            }
+        }
+    }
+}
+
 #[cfg(all(
     any(feature = "v1", feature = "v2"),
     not(feature = "payment_methods_v2")
@@ -359,11 +370,22 @@ impl ForeignTryFrom<api_enums::Connector> for common_enums::RoutableConnectors {
             api_enums::Connector::DummyConnector6 => Self::DummyConnector6,
             #[cfg(feature = "dummy_connector")]
             api_enums::Connector::DummyConnector7 => Self::DummyConnector7,
+            #[cfg(feature = "dummy_connector")]
+            api_enums::Connector::DummyBank => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "dummy_bank is not a routable connector".to_string(),
+                })?
+            }
             api_enums::Connector::Threedsecureio => {
                 Err(common_utils::errors::ValidationError::InvalidValue {
                     message: "threedsecureio is not a routable connector".to_string(),
                 })?
             }
+            api_enums::Connector::Truelayer => {
+                Err(common_utils::errors::ValidationError::InvalidValue {
+                    message: "truelayer is not a routable connector".to_string(),
+                })?
+            }
         })
     }
 }
@@ -519,6 +541,7 @@ impl ForeignFrom<api_enums::PaymentMethodType> for api_enums::PaymentMethod {
             | api_enums::PaymentMethodType::OnlineBankingSlovakia
             | api_enums::PaymentMethodType::OpenBankingUk
             | api_enums::PaymentMethodType::OpenBankingPIS
+            | api_enums::PaymentMethodType::PayByBank
             | api_enums::PaymentMethodType::Przelewy24
             | api_enums::PaymentMethodType::Trustly
             | api_enums::PaymentMethodType::Bizum
@@ -614,7 +637,8 @@ impl ForeignFrom<storage_enums::RefundStatus> for Option<storage_enums::EventTyp
             storage_enums::RefundStatus::Failure => Some(storage_enums::EventType::RefundFailed),
             api_enums::RefundStatus::ManualReview
             | api_enums::RefundStatus::Pending
-            | api_enums::RefundStatus::TransactionFailure => None,
+            | api_enums::RefundStatus::TransactionFailure
+            | api_enums::RefundStatus::PendingApproval => None,
         }
     }
 }
@@ -1532,6 +1556,26 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
     }
 }
 
+impl ForeignFrom<storage::ProcessTracker> for process_tracker_api_types::ProcessTrackerResponse {
+    fn foreign_from(value: storage::ProcessTracker) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+            runner: value.runner,
+            status: value.status.to_string(),
+            business_status: value.business_status,
+            tracking_data: value.tracking_data,
+            retry_count: value.retry_count,
+            schedule_time: value.schedule_time,
+            priority: value.priority,
+            cron_expression: value.cron_expression,
+            tag: value.tag,
+            created_at: value.created_at,
+            updated_at: value.updated_at,
+        }
+    }
+}
+
 #[cfg(all(feature = "v2", feature = "customer_v2"))]
 impl ForeignFrom<&domain::Customer> for payments::CustomerDetailsResponse {
     fn foreign_from(_customer: &domain::Customer) -> Self {
@@ -1691,6 +1735,56 @@ impl ForeignFrom<diesel_models::business_profile::AuthenticationConnectorDetails
     }
 }
 
+impl ForeignFrom<api_models::admin::RefundPolicy>
+    for diesel_models::business_profile::RefundPolicy
+{
+    fn foreign_from(item: api_models::admin::RefundPolicy) -> Self {
+        Self {
+            max_refund_age_in_days: item.max_refund_age_in_days,
+            max_refund_amount_per_day: item.max_refund_amount_per_day,
+            max_refund_amount_per_user_per_day: item.max_refund_amount_per_user_per_day,
+            approval_threshold: item.approval_threshold,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::RefundPolicy>
+    for api_models::admin::RefundPolicy
+{
+    fn foreign_from(item: diesel_models::business_profile::RefundPolicy) -> Self {
+        Self {
+            max_refund_age_in_days: item.max_refund_age_in_days,
+            max_refund_amount_per_day: item.max_refund_amount_per_day,
+            max_refund_amount_per_user_per_day: item.max_refund_amount_per_user_per_day,
+            approval_threshold: item.approval_threshold,
+        }
+    }
+}
+
+impl ForeignFrom<api_models::admin::AutoCapturePolicy>
+    for diesel_models::business_profile::AutoCapturePolicy
+{
+    fn foreign_from(item: api_models::admin::AutoCapturePolicy) -> Self {
+        Self {
+            capture_after_hours: item.capture_after_hours,
+            capture_schedule: item.capture_schedule,
+            authorization_expiry_action: item.authorization_expiry_action,
+        }
+    }
+}
+
+impl ForeignFrom<diesel_models::business_profile::AutoCapturePolicy>
+    for api_models::admin::AutoCapturePolicy
+{
+    fn foreign_from(item: diesel_models::business_profile::AutoCapturePolicy) -> Self {
+        Self {
+            capture_after_hours: item.capture_after_hours,
+            capture_schedule: item.capture_schedule,
+            authorization_expiry_action: item.authorization_expiry_action,
+        }
+    }
+}
+
 impl ForeignFrom<api_models::admin::WebhookDetails>
     for diesel_models::business_profile::WebhookDetails
 {
@@ -248,6 +248,10 @@ pub async fn generate_sample_data(
             shipping_details: None,
             is_payment_processor_token_flow: None,
             organization_id: org_id.clone(),
+            auto_capture_policy: None,
+            authorization_expires_at: None,
+            authorized_at: None,
+            captured_at: None,
         };
         let payment_attempt = PaymentAttemptBatchNew {
             attempt_id: attempt_id.clone(),
@@ -363,6 +367,9 @@ pub async fn generate_sample_data(
                 merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
                 charges: None,
                 organization_id: org_id.clone(),
+                payout_id: None,
+                created_by: None,
+                daily_refund_limit_reservation: None,
             })
         } else {
             None
@@ -1,9 +1,26 @@
+pub mod account_updater;
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
 #[cfg(feature = "payouts")]
 pub mod attach_payout_account_workflow;
+pub mod authorization_expiry;
+pub mod auto_capture;
+#[cfg(all(
+    feature = "olap",
+    any(feature = "v1", feature = "v2"),
+    not(feature = "customer_v2")
+))]
+pub mod data_subject_erasure;
 pub mod outgoing_webhook_retry;
+pub mod pay_by_bank_payment_sync;
+pub mod pay_by_bank_reversal_sync;
+pub mod payment_method_migration;
 pub mod payment_method_status_update;
 pub mod payment_sync;
+#[cfg(feature = "payouts")]
+pub mod payout_batch;
+#[cfg(feature = "payouts")]
+pub mod payout_schedule;
 pub mod refund_router;
 pub mod tokenized_data;
+pub mod vault_key_rotation;
@@ -0,0 +1,186 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::payment_methods::account_updater,
+    errors as core_errors,
+    routes::SessionState,
+    types::{
+        domain,
+        storage::{self, AccountUpdaterBatchTrackingData, AccountUpdaterTrackingData},
+    },
+};
+
+/// Periodically submits the saved cards of an enrolled merchant to the account updater
+pub struct AccountUpdaterWorkflow;
+
+/// Applies the results of a submitted batch once the account updater has made them available
+pub struct AccountUpdaterResultsWorkflow;
+
+async fn get_merchant_account_and_key_store(
+    state: &SessionState,
+    merchant_id: &common_utils::id_type::MerchantId,
+) -> Result<(domain::MerchantAccount, domain::MerchantKeyStore), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let key_manager_state = &state.into();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            key_manager_state,
+            merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(key_manager_state, merchant_id, &key_store)
+        .await?;
+
+    Ok((merchant_account, key_store))
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: AccountUpdaterTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterTrackingData")?;
+
+        if !state.conf.account_updater.enabled {
+            logger::info!("Account updater is disabled, skipping account updater run");
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    storage::business_status::COMPLETED_BY_PT,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let (merchant_account, key_store) =
+            match get_merchant_account_and_key_store(state, &tracking_data.merchant_id).await {
+                Ok(merchant_account_and_key_store) => merchant_account_and_key_store,
+                Err(errors::ProcessTrackerError::EStorageError(error))
+                    if error.current_context().is_db_not_found() =>
+                {
+                    // The merchant no longer exists, the task is not scheduled again
+                    db.as_scheduler()
+                        .finish_process_with_business_status(
+                            process,
+                            storage::business_status::REVOKED,
+                        )
+                        .await?;
+                    return Ok(());
+                }
+                Err(error) => return Err(error),
+            };
+
+        account_updater::submit_account_updater_inquiries(state, &merchant_account, &key_store)
+            .await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        logger::error!(pt.name=?process.name, pt.id=%process.id, ?error, "Failed to execute workflow");
+
+        // The saved cards which could not be submitted remain queued, and are submitted in the
+        // next run of the task
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::GLOBAL_FAILURE)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    }
+}
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AccountUpdaterResultsWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let config = &state.conf.account_updater;
+        let tracking_data: AccountUpdaterBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AccountUpdaterBatchTrackingData")?;
+
+        let (merchant_account, key_store) =
+            get_merchant_account_and_key_store(state, &tracking_data.merchant_id).await?;
+
+        let is_processed = account_updater::process_account_updater_results(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.batch_id,
+        )
+        .await?;
+
+        if is_processed {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    storage::business_status::COMPLETED_BY_PT,
+                )
+                .await?;
+        } else if i64::from(process.retry_count) + 1 >= i64::from(config.max_results_poll_attempts)
+        {
+            logger::warn!(
+                batch_id = %tracking_data.batch_id,
+                "Results of account updater batch were not received in time"
+            );
+            account_updater::fail_account_updater_batch(
+                state,
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    storage::business_status::RETRIES_EXCEEDED,
+                )
+                .await?;
+        } else {
+            let schedule_time = common_utils::date_time::now().saturating_add(
+                time::Duration::seconds(i64::from(config.results_poll_interval_in_secs)),
+            );
+            db.as_scheduler()
+                .retry_process(process, schedule_time)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,145 @@
+use common_utils::ext_traits::{Encode, ValueExt};
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts, core::payments::authorization_expiry, errors as core_errors, routes::SessionState,
+    types::storage,
+};
+
+pub struct AuthorizationExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AuthorizationExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: authorization_expiry::AuthorizationExpiryTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AuthorizationExpiryTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let renewed_authorization_expires_at = authorization_expiry::handle_expiring_authorization(
+            state,
+            merchant_account,
+            key_store,
+            &tracking_data,
+        )
+        .await?;
+
+        // Re-authorized payments are handled again before the renewed authorization lapses
+        match renewed_authorization_expires_at {
+            Some(authorization_expires_at) => {
+                let tracking_data = authorization_expiry::AuthorizationExpiryTrackingData {
+                    authorization_expires_at: Some(authorization_expires_at),
+                    ..tracking_data
+                }
+                .encode_to_value()
+                .change_context(errors::ProcessTrackerError::SerializationFailed)?;
+                db.as_scheduler()
+                    .update_process(
+                        process,
+                        storage::ProcessTrackerUpdate::Update {
+                            name: None,
+                            retry_count: Some(0),
+                            schedule_time: Some(
+                                authorization_expiry::get_authorization_expiry_schedule(
+                                    &state.conf.authorization_validity,
+                                    authorization_expires_at,
+                                ),
+                            ),
+                            tracking_data: Some(tracking_data),
+                            business_status: None,
+                            status: Some(storage::enums::ProcessTrackerStatus::New),
+                            updated_at: Some(common_utils::date_time::now()),
+                        },
+                    )
+                    .await?;
+            }
+            None => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        let authorization_expires_at = process
+            .tracking_data
+            .clone()
+            .parse_value::<authorization_expiry::AuthorizationExpiryTrackingData>(
+                "AuthorizationExpiryTrackingData",
+            )
+            .map_err(|error| {
+                logger::error!(?error, "Failed to parse authorization expiry tracking data")
+            })
+            .ok()
+            .and_then(|tracking_data| tracking_data.authorization_expires_at);
+
+        // The payment is checked again before retrying, so that payments captured or voided in
+        // the meantime are left untouched. Retries are made only while the authorization holds.
+        let schedule_time = common_utils::date_time::now().saturating_add(time::Duration::seconds(
+            consts::AUTHORIZATION_EXPIRY_RETRY_DELAY_IN_SECONDS
+                * i64::from(process.retry_count + 1),
+        ));
+        if process.retry_count < consts::AUTHORIZATION_EXPIRY_MAX_RETRIES
+            && authorization_expires_at
+                .is_some_and(|authorization_expires_at| schedule_time < authorization_expires_at)
+        {
+            logger::warn!(?error, "Failed to handle expiring authorization, retrying");
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        logger::error!(
+            pt.id = %process.id,
+            ?error,
+            "Failed to handle expiring authorization before it lapsed"
+        );
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::FAILURE)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    }
+}
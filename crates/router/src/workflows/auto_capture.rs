@@ -0,0 +1,83 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts, core::payments::auto_capture, errors as core_errors, routes::SessionState,
+    types::storage,
+};
+
+pub struct AutoCaptureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for AutoCaptureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: auto_capture::AutoCaptureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("AutoCaptureTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        match auto_capture::execute_auto_capture(state, merchant_account, key_store, tracking_data)
+            .await
+        {
+            Ok(()) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?
+            }
+            // The payment is checked again before retrying, so that payments captured or voided
+            // in the meantime are left untouched
+            Err(error) if process.retry_count < consts::AUTO_CAPTURE_MAX_RETRIES => {
+                logger::warn!(?error, "Failed to auto-capture payment, retrying");
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                        consts::AUTO_CAPTURE_RETRY_DELAY_IN_SECONDS
+                            * i64::from(process.retry_count + 1),
+                    ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            Err(error) => Err(error)?,
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,122 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::data_subject_requests,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums, DataSubjectErasureTrackingData},
+};
+
+pub struct DataSubjectErasureWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for DataSubjectErasureWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: DataSubjectErasureTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DataSubjectErasureTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let data_subject_request = db
+            .find_data_subject_request_by_merchant_id_request_id(
+                &tracking_data.merchant_id,
+                &tracking_data.request_id,
+            )
+            .await?;
+
+        let data_subject_request = match data_subject_request.status {
+            enums::DataSubjectRequestStatus::Completed
+            | enums::DataSubjectRequestStatus::Failed => data_subject_request,
+            enums::DataSubjectRequestStatus::Pending
+            | enums::DataSubjectRequestStatus::Processing => {
+                data_subject_requests::process_customer_data_erasure(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    data_subject_request,
+                )
+                .await?
+            }
+        };
+
+        match data_subject_request.status {
+            // A store failed to be erased, resume from that store after a backoff
+            enums::DataSubjectRequestStatus::Pending
+            | enums::DataSubjectRequestStatus::Processing
+                if process.retry_count < consts::DATA_SUBJECT_ERASURE_MAX_RETRIES =>
+            {
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                        consts::DATA_SUBJECT_ERASURE_RETRY_DELAY_IN_SECONDS
+                            * i64::from(process.retry_count + 1),
+                    ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            enums::DataSubjectRequestStatus::Pending
+            | enums::DataSubjectRequestStatus::Processing => {
+                db.update_data_subject_request(
+                    data_subject_request,
+                    storage::DataSubjectRequestUpdate::StatusUpdate {
+                        status: enums::DataSubjectRequestStatus::Failed,
+                    },
+                )
+                .await?;
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::RETRIES_EXCEEDED,
+                    )
+                    .await?
+            }
+            enums::DataSubjectRequestStatus::Completed
+            | enums::DataSubjectRequestStatus::Failed => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
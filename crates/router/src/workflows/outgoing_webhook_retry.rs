@@ -338,6 +338,7 @@ async fn get_outgoing_webhook_content_and_event_type(
 ) -> Result<(OutgoingWebhookContent, Option<EventType>), errors::ProcessTrackerError> {
     use api_models::{
         mandates::MandateId,
+        payment_methods::PaymentMethodId,
         payments::{HeaderPayload, PaymentIdType, PaymentsResponse, PaymentsRetrieveRequest},
         refunds::{RefundResponse, RefundsRetrieveRequest},
     };
@@ -346,6 +347,7 @@ async fn get_outgoing_webhook_content_and_event_type(
         core::{
             disputes::retrieve_dispute,
             mandate::get_mandate,
+            payment_methods::cards::retrieve_payment_method,
             payments::{payments_core, CallConnectorAction, PaymentStatus},
             refunds::refund_retrieve_core,
         },
@@ -505,6 +507,37 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method_id = tracking_data.primary_object_id.clone();
+            let request = PaymentMethodId { payment_method_id };
+
+            let payment_method_response =
+                match retrieve_payment_method(state, request, key_store, merchant_account).await? {
+                    ApplicationResponse::Json(payment_method_response)
+                    | ApplicationResponse::JsonWithHeaders((payment_method_response, _)) => {
+                        Ok(payment_method_response)
+                    }
+                    ApplicationResponse::StatusOk
+                    | ApplicationResponse::TextPlain(_)
+                    | ApplicationResponse::JsonForRedirection(_)
+                    | ApplicationResponse::Form(_)
+                    | ApplicationResponse::GenericLinkForm(_)
+                    | ApplicationResponse::PaymentLinkForm(_)
+                    | ApplicationResponse::FileData(_) => {
+                        Err(errors::ProcessTrackerError::ResourceFetchingFailed {
+                            resource_name: tracking_data.primary_object_id.clone(),
+                        })
+                    }
+                }
+                .map(Box::new)?;
+
+            // The event type of a payment method event is not derived from the state of the
+            // payment method, so the event type of the initial delivery is retained
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+                Some(tracking_data.event_type),
+            ))
+        }
         #[cfg(feature = "payouts")]
         diesel_models::enums::EventClass::Payouts => {
             let payout_id = tracking_data.primary_object_id.clone();
@@ -0,0 +1,102 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::pm_auth::payment_initiation,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct PayByBankPaymentSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayByBankPaymentSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: payment_initiation::PayByBankPaymentSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayByBankPaymentSyncTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let sync_result = payment_initiation::sync_processing_payment(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.payment_id,
+        )
+        .await;
+
+        match sync_result {
+            Ok(payment) if payment.status != enums::IntentStatus::Processing => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?
+            }
+            // The payment is synced again while the connector has not settled it, or the sync
+            // failed
+            result if process.retry_count < consts::PAY_BY_BANK_PAYMENT_SYNC_MAX_RETRIES => {
+                if let Err(error) = result {
+                    logger::warn!(?error, "Failed to sync bank payment, retrying");
+                }
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                        consts::PAY_BY_BANK_PAYMENT_SYNC_DELAY_IN_SECONDS
+                            * i64::from(process.retry_count + 1),
+                    ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            Ok(_) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::RETRIES_EXCEEDED,
+                    )
+                    .await?
+            }
+            Err(error) => Err(error)?,
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
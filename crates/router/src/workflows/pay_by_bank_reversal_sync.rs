@@ -0,0 +1,102 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::pm_auth::payment_initiation,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums},
+};
+
+pub struct PayByBankReversalSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayByBankReversalSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: payment_initiation::PayByBankReversalSyncTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayByBankReversalSyncTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let sync_result = payment_initiation::sync_reversal(
+            state,
+            &merchant_account,
+            &key_store,
+            &tracking_data.refund_id,
+        )
+        .await;
+
+        match sync_result {
+            Ok(refund) if refund.refund_status != enums::RefundStatus::Pending => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?
+            }
+            // The reversal is synced again while the connector has not settled it, or the sync
+            // failed
+            result if process.retry_count < consts::PAY_BY_BANK_REVERSAL_SYNC_MAX_RETRIES => {
+                if let Err(error) = result {
+                    logger::warn!(?error, "Failed to sync reversal, retrying");
+                }
+                let schedule_time =
+                    common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                        consts::PAY_BY_BANK_REVERSAL_SYNC_DELAY_IN_SECONDS
+                            * i64::from(process.retry_count + 1),
+                    ));
+                db.as_scheduler()
+                    .retry_process(process, schedule_time)
+                    .await?
+            }
+            Ok(_) => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::RETRIES_EXCEEDED,
+                    )
+                    .await?
+            }
+            Err(error) => Err(error)?,
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
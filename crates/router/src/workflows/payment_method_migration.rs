@@ -0,0 +1,137 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::payment_methods::migration,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums, PaymentMethodMigrationTrackingData},
+};
+
+pub struct PaymentMethodMigrationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodMigrationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PaymentMethodMigrationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodMigrationTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payment_method_migration = db
+            .find_payment_method_migration_by_merchant_id_migration_id(
+                &tracking_data.merchant_id,
+                &tracking_data.migration_id,
+            )
+            .await?;
+
+        let payment_method_migration = match payment_method_migration.status {
+            enums::PaymentMethodMigrationStatus::Completed
+            | enums::PaymentMethodMigrationStatus::Failed => payment_method_migration,
+            enums::PaymentMethodMigrationStatus::Pending
+            | enums::PaymentMethodMigrationStatus::Processing => {
+                migration::process_payment_method_migration(
+                    state,
+                    &merchant_account,
+                    &key_store,
+                    payment_method_migration,
+                )
+                .await?
+            }
+        };
+
+        // Continue with the next records right away, until all records are processed
+        match payment_method_migration.status {
+            enums::PaymentMethodMigrationStatus::Pending
+            | enums::PaymentMethodMigrationStatus::Processing => {
+                db.as_scheduler()
+                    .reset_process(process, common_utils::date_time::now())
+                    .await?
+            }
+            enums::PaymentMethodMigrationStatus::Completed
+            | enums::PaymentMethodMigrationStatus::Failed => {
+                db.as_scheduler()
+                    .finish_process_with_business_status(
+                        process,
+                        storage::business_status::COMPLETED_BY_PT,
+                    )
+                    .await?
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        if process.retry_count < consts::PAYMENT_METHOD_MIGRATION_MAX_RETRIES {
+            logger::warn!(
+                ?error,
+                "Failed to process payment method migration, retrying"
+            );
+            let schedule_time =
+                common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                    consts::PAYMENT_METHOD_MIGRATION_RETRY_DELAY_IN_SECONDS
+                        * i64::from(process.retry_count + 1),
+                ));
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<PaymentMethodMigrationTrackingData>("PaymentMethodMigrationTrackingData")
+        {
+            Ok(tracking_data) => {
+                if let Err(error) = migration::fail_payment_method_migration(
+                    state,
+                    &tracking_data.merchant_id,
+                    &tracking_data.migration_id,
+                )
+                .await
+                {
+                    logger::error!(?error, "Failed to mark payment method migration as failed");
+                }
+            }
+            Err(error) => logger::error!(?error, "Failed to parse payment method migration data"),
+        }
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -1,17 +1,22 @@
 use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
 use scheduler::{
     consumer::types::process_data, utils as pt_utils, workflows::ProcessTrackerWorkflow,
 };
 
 use crate::{
+    core::payment_methods::health,
     errors,
-    logger::error,
+    logger::{error, info},
     routes::SessionState,
-    types::storage::{self, PaymentMethodStatusTrackingData},
+    types::storage::{self, PaymentMethodHealthTrackingData, PaymentMethodStatusTrackingData},
 };
 
 pub struct PaymentMethodStatusUpdateWorkflow;
 
+/// Periodically evaluates the health of the saved cards of an enrolled merchant
+pub struct PaymentMethodHealthSweepWorkflow;
+
 #[async_trait::async_trait]
 impl ProcessTrackerWorkflow<SessionState> for PaymentMethodStatusUpdateWorkflow {
     async fn execute_workflow<'a>(
@@ -106,3 +111,82 @@ impl ProcessTrackerWorkflow<SessionState> for PaymentMethodStatusUpdateWorkflow
         Ok(())
     }
 }
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PaymentMethodHealthSweepWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PaymentMethodHealthTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PaymentMethodHealthTrackingData")?;
+
+        if !state.conf.payment_method_health.enabled {
+            info!(
+                "Payment method health tracking is disabled, skipping payment method health sweep"
+            );
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    storage::business_status::COMPLETED_BY_PT,
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let key_manager_state = &state.into();
+        let key_store = match db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await
+        {
+            Ok(key_store) => key_store,
+            Err(error) if error.current_context().is_db_not_found() => {
+                // The merchant no longer exists, the task is not scheduled again
+                db.as_scheduler()
+                    .finish_process_with_business_status(process, storage::business_status::REVOKED)
+                    .await?;
+                return Ok(());
+            }
+            Err(error) => return Err(error.into()),
+        };
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        health::sweep_payment_method_health(state, &merchant_account, &key_store).await?;
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        error!(pt.name=?process.name, pt.id=%process.id, ?error, "Failed to execute workflow");
+
+        // The saved cards which could not be evaluated are evaluated in the next run of the task
+        state
+            .store
+            .as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::GLOBAL_FAILURE)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed)
+    }
+}
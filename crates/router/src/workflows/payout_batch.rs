@@ -0,0 +1,119 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::payouts::batch,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums, PayoutBatchTrackingData},
+};
+
+pub struct PayoutBatchWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutBatchWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: PayoutBatchTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutBatchTrackingData")?;
+
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(
+                key_manager_state,
+                &tracking_data.merchant_id,
+                &key_store,
+            )
+            .await?;
+
+        let payout_batch = db
+            .find_payout_batch_by_merchant_id_batch_id(
+                &tracking_data.merchant_id,
+                &tracking_data.batch_id,
+            )
+            .await?;
+
+        let payout_batch = match payout_batch.status {
+            enums::PayoutBatchStatus::Completed | enums::PayoutBatchStatus::Failed => payout_batch,
+            enums::PayoutBatchStatus::Pending | enums::PayoutBatchStatus::Processing => {
+                batch::process_payout_batch(state, &merchant_account, &key_store, payout_batch)
+                    .await?
+            }
+        };
+        logger::info!(
+            "Processed payout batch {} with status {:?}",
+            payout_batch.batch_id,
+            payout_batch.status
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        // The batch resumes from its saved progress, so transient failures are retried
+        if process.retry_count < consts::PAYOUT_BATCH_MAX_RETRIES {
+            logger::warn!(?error, "Failed to process payout batch, retrying");
+            let schedule_time =
+                common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                    consts::PAYOUT_BATCH_RETRY_DELAY_IN_SECONDS
+                        * i64::from(process.retry_count + 1),
+                ));
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        // The batch cannot be resumed once its process is finished, so it is marked as failed
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<PayoutBatchTrackingData>("PayoutBatchTrackingData")
+        {
+            Ok(tracking_data) => {
+                if let Err(error) = batch::fail_payout_batch(
+                    state,
+                    &tracking_data.merchant_id,
+                    &tracking_data.batch_id,
+                )
+                .await
+                {
+                    logger::error!(?error, "Failed to mark payout batch as failed");
+                }
+            }
+            Err(error) => logger::error!(?error, "Failed to parse payout batch tracking data"),
+        }
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
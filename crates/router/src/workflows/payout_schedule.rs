@@ -0,0 +1,85 @@
+use common_utils::ext_traits::{OptionExt, ValueExt};
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    core::payouts,
+    errors as core_errors,
+    routes::SessionState,
+    types::{api, storage, storage::enums},
+};
+
+pub struct PayoutScheduleWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for PayoutScheduleWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        // Gather context
+        let db = &*state.store;
+        let tracking_data: api::PayoutRetrieveRequest = process
+            .tracking_data
+            .clone()
+            .parse_value("PayoutRetrieveRequest")?;
+
+        let merchant_id = tracking_data
+            .merchant_id
+            .clone()
+            .get_required_value("merchant_id")?;
+        let key_manager_state = &state.into();
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                key_manager_state,
+                &merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(key_manager_state, &merchant_id, &key_store)
+            .await?;
+
+        let request = api::payouts::PayoutRequest::PayoutRetrieveRequest(tracking_data);
+
+        let mut payout_data =
+            payouts::make_payout_data(state, &merchant_account, None, &key_store, &request).await?;
+
+        // The next occurrence is scheduled before executing the payout, so that the recurrence
+        // continues when the execution fails. Cancelling a payout of a recurrence stops the
+        // recurrence.
+        if payout_data.payout_attempt.status != enums::PayoutStatus::Cancelled {
+            payouts::schedule_next_recurring_payout(state, &merchant_account, &payout_data).await?;
+        }
+
+        // Payouts which were cancelled or confirmed before their schedule are not executed again
+        if payout_data.payout_attempt.status == enums::PayoutStatus::RequiresCreation {
+            payouts::execute_scheduled_payout(
+                state,
+                &merchant_account,
+                &key_store,
+                &mut payout_data,
+            )
+            .await?;
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
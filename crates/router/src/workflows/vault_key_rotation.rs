@@ -0,0 +1,108 @@
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::logger;
+use scheduler::{
+    consumer::{self, workflows::ProcessTrackerWorkflow},
+    errors,
+};
+
+use crate::{
+    consts,
+    core::payment_methods::embedded_vault,
+    errors as core_errors,
+    routes::SessionState,
+    types::storage::{self, enums, VaultKeyRotationTrackingData},
+};
+
+pub struct VaultKeyRotationWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<SessionState> for VaultKeyRotationWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db = &*state.store;
+        let tracking_data: VaultKeyRotationTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("VaultKeyRotationTrackingData")?;
+
+        let data_key = db
+            .find_vault_data_key_by_key_id(&tracking_data.key_id)
+            .await?;
+
+        if data_key.status == enums::VaultDataKeyStatus::Rotating {
+            let remaining = embedded_vault::re_encrypt_entries(
+                state,
+                &tracking_data.merchant_id,
+                &tracking_data.key_id,
+            )
+            .await?;
+
+            // Re-encrypt the next batch right away, until no entry uses the data key
+            if remaining {
+                db.as_scheduler()
+                    .reset_process(process, common_utils::date_time::now())
+                    .await?;
+                return Ok(());
+            }
+
+            db.update_vault_data_key(
+                data_key,
+                storage::VaultDataKeyUpdate::StatusUpdate {
+                    status: enums::VaultDataKeyStatus::Retired,
+                },
+            )
+            .await?;
+        }
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, storage::business_status::COMPLETED_BY_PT)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a SessionState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> core_errors::CustomResult<(), errors::ProcessTrackerError> {
+        // Entries already re-encrypted use the active data key, so the rotation resumes with the
+        // entries remaining
+        if process.retry_count < consts::VAULT_KEY_ROTATION_MAX_RETRIES {
+            logger::warn!(?error, "Failed to rotate vault data key, retrying");
+            let schedule_time =
+                common_utils::date_time::now().saturating_add(time::Duration::seconds(
+                    consts::VAULT_KEY_ROTATION_RETRY_DELAY_IN_SECONDS
+                        * i64::from(process.retry_count + 1),
+                ));
+            return state
+                .store
+                .as_scheduler()
+                .retry_process(process, schedule_time)
+                .await
+                .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+        }
+
+        // The key is marked as failed, so that rotating the data key again restarts the rotation
+        match process
+            .tracking_data
+            .clone()
+            .parse_value::<VaultKeyRotationTrackingData>("VaultKeyRotationTrackingData")
+        {
+            Ok(tracking_data) => {
+                if let Err(error) =
+                    embedded_vault::fail_key_rotation(state, &tracking_data.key_id).await
+                {
+                    logger::error!(?error, "Failed to mark vault data key rotation as failed");
+                }
+            }
+            Err(error) => logger::error!(?error, "Failed to parse key rotation tracking data"),
+        }
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -440,6 +440,9 @@ async fn payments_create_core() {
         payment_method_id: None,
         payment_method_status: None,
         updated: None,
+        authorization_expires_at: None,
+        authorized_at: None,
+        captured_at: None,
         charges: None,
         frm_metadata: None,
         merchant_order_reference_id: None,
@@ -694,6 +697,9 @@ async fn payments_create_core_adyen_no_redirect() {
             payment_method_id: None,
             payment_method_status: None,
             updated: None,
+            authorization_expires_at: None,
+            authorized_at: None,
+            captured_at: None,
             charges: None,
             frm_metadata: None,
             merchant_order_reference_id: None,
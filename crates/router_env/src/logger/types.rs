@@ -107,6 +107,22 @@ pub enum Flow {
     CustomersDelete,
     /// Customers get mandates flow.
     CustomersGetMandates,
+    /// Customers data export flow.
+    CustomersDataExport,
+    /// Customers data erasure flow.
+    CustomersDataErasure,
+    /// Customers data request retrieve flow.
+    CustomersDataRequestRetrieve,
+    /// Customers address create flow.
+    CustomersAddressCreate,
+    /// Customers address list flow.
+    CustomersAddressList,
+    /// Customers address retrieve flow.
+    CustomersAddressRetrieve,
+    /// Customers address update flow.
+    CustomersAddressUpdate,
+    /// Customers address delete flow.
+    CustomersAddressDelete,
     /// Create an Ephemeral Key.
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
@@ -121,6 +137,22 @@ pub enum Flow {
     PaymentMethodsCreate,
     /// Payment methods migrate flow.
     PaymentMethodsMigrate,
+    /// Payment method migration job create flow.
+    PaymentMethodMigrationCreate,
+    /// Payment method migration job retrieve flow.
+    PaymentMethodMigrationRetrieve,
+    /// Payment method migration job report flow.
+    PaymentMethodMigrationReport,
+    /// Account updater enrollment flow.
+    AccountUpdaterEnroll,
+    /// Account updater unenrollment flow.
+    AccountUpdaterUnenroll,
+    /// Payment method health sweep enrollment flow.
+    PaymentMethodHealthEnroll,
+    /// Payment method health sweep unenrollment flow.
+    PaymentMethodHealthUnenroll,
+    /// Payment method fingerprint links flow.
+    PaymentMethodFingerprintLinks,
     /// Payment methods list flow.
     PaymentMethodsList,
     /// Payment method save flow
@@ -133,6 +165,8 @@ pub enum Flow {
     ListCountriesCurrencies,
     /// Payment method create collect link flow.
     PaymentMethodCollectLink,
+    /// Payment method manage link flow.
+    PaymentMethodManageLink,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -169,6 +203,8 @@ pub enum Flow {
     PaymentsFilters,
     /// Payments aggregates flow
     PaymentsAggregate,
+    /// Payments with expiring authorizations list flow
+    PaymentsExpiringAuthorizationsList,
     #[cfg(feature = "payouts")]
     /// Payouts create flow
     PayoutsCreate,
@@ -187,6 +223,15 @@ pub enum Flow {
     /// Payouts fulfill flow.
     PayoutsFulfill,
     #[cfg(feature = "payouts")]
+    /// Payouts batch create flow.
+    PayoutsBatchCreate,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch retrieve flow.
+    PayoutsBatchRetrieve,
+    #[cfg(feature = "payouts")]
+    /// Payouts batch report flow.
+    PayoutsBatchReport,
+    #[cfg(feature = "payouts")]
     /// Payouts list flow.
     PayoutsList,
     #[cfg(feature = "payouts")]
@@ -212,6 +257,12 @@ pub enum Flow {
     RefundsList,
     /// Refunds filters flow
     RefundsFilters,
+    /// Refunds approve flow
+    RefundsApprove,
+    /// Refunds reject flow
+    RefundsReject,
+    /// Refunds payout flow
+    RefundsPayout,
     // Retrieve forex flow.
     RetrieveForexFlow,
     /// Toggles recon service for a merchant.
@@ -286,6 +337,8 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Pre-dispute alert ingestion flow
+    PreDisputeAlertIngest,
     /// Invalidate cache flow
     CacheInvalidate,
     /// Payment Link Retrieve flow
@@ -312,6 +365,12 @@ pub enum Flow {
     Verification,
     /// Rust locker migration
     RustLockerMigration,
+    /// Embedded vault data key rotation flow
+    EmbeddedVaultKeyRotate,
+    /// Embedded vault data key list flow
+    EmbeddedVaultKeyList,
+    /// Embedded vault access log list flow
+    EmbeddedVaultAccessLogList,
     /// Gsm Rule Creation flow
     GsmRuleCreate,
     /// Gsm Rule Retrieve flow
@@ -322,6 +381,22 @@ pub enum Flow {
     ApplePayCertificatesMigration,
     /// Gsm Rule Delete flow
     GsmRuleDelete,
+    /// Process tracker task list flow
+    ProcessTrackerTaskList,
+    /// Process tracker task retrieve flow
+    ProcessTrackerTaskRetrieve,
+    /// Process tracker task run now flow
+    ProcessTrackerTaskRun,
+    /// Process tracker task reschedule flow
+    ProcessTrackerTaskReschedule,
+    /// Process tracker task cancel flow
+    ProcessTrackerTaskCancel,
+    /// Process tracker runner retrieve flow
+    ProcessTrackerRunnerRetrieve,
+    /// Process tracker runner pause flow
+    ProcessTrackerRunnerPause,
+    /// Process tracker runner resume flow
+    ProcessTrackerRunnerResume,
     /// User Sign Up
     UserSignUp,
     /// User Sign Up
@@ -396,6 +471,12 @@ pub enum Flow {
     PmAuthLinkTokenCreate,
     /// PaymentMethodAuth Exchange token create
     PmAuthExchangeToken,
+    /// PaymentMethodAuth Payment initiation create
+    PmAuthPaymentInitiationCreate,
+    /// PaymentMethodAuth Payment initiation sync
+    PmAuthPaymentInitiationSync,
+    /// PaymentMethodAuth Payment initiation reversal
+    PmAuthPaymentInitiationReversal,
     /// Get reset password link
     ForgotPassword,
     /// Reset password using link
@@ -28,6 +28,7 @@ impl Default for super::settings::ConsumerSettings {
         Self {
             disabled: false,
             consumer_group: "SCHEDULER_GROUP".into(),
+            runner_concurrency_limits: std::collections::HashMap::new(),
         }
     }
 }
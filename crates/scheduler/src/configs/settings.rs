@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 pub use router_env::config::{Log, LogConsole, LogFile, LogTelemetry};
 use serde::Deserialize;
 
@@ -36,4 +38,7 @@ pub struct ProducerSettings {
 pub struct ConsumerSettings {
     pub disabled: bool,
     pub consumer_group: String,
+    /// Maximum number of tasks of a runner that are executed concurrently, keyed by the runner
+    /// name. Runners that are not listed are not limited.
+    pub runner_concurrency_limits: HashMap<String, usize>,
 }
@@ -19,6 +19,8 @@ impl super::settings::SchedulerSettings {
 
         self.producer.validate()?;
 
+        self.consumer.validate()?;
+
         self.server.validate()?;
 
         Ok(())
@@ -35,6 +37,20 @@ impl super::settings::ProducerSettings {
     }
 }
 
+impl super::settings::ConsumerSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        self.runner_concurrency_limits
+            .iter()
+            .try_for_each(|(runner, limit)| {
+                common_utils::fp_utils::when(*limit == 0, || {
+                    Err(ApplicationError::InvalidConfigurationValueError(format!(
+                        "concurrency limit of scheduler runner {runner} must be greater than 0"
+                    )))
+                })
+            })
+    }
+}
+
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         common_utils::fp_utils::when(self.host.is_default_or_empty(), || {
@@ -28,6 +28,9 @@ use crate::{
     metrics, utils as pt_utils, SchedulerAppState, SchedulerInterface, SchedulerSessionState,
 };
 
+/// Delay after which a task throttled by its runner's concurrency limit is picked up again
+const THROTTLED_TASK_RESCHEDULE_DELAY_IN_SECONDS: i64 = 5;
+
 // Valid consumer business statuses
 pub fn valid_business_statuses() -> Vec<&'static str> {
     vec![storage::business_status::PENDING]
@@ -65,6 +68,9 @@ where
         tokio::time::interval(Duration::from_millis(settings.graceful_shutdown_interval));
 
     let consumer_operation_counter = sync::Arc::new(atomic::AtomicU64::new(0));
+    let concurrency_limiter = sync::Arc::new(types::RunnerConcurrencyLimiter::new(
+        &settings.consumer.runner_concurrency_limits,
+    ));
     let signal = get_allowed_signals()
         .map_err(|error| {
             logger::error!(?error, "Signal Handler Error");
@@ -92,6 +98,7 @@ where
                     pt_utils::consumer_operation_handler(
                         session_state.clone(),
                         settings.clone(),
+                        concurrency_limiter.clone(),
                         |error| {
                             logger::error!(?error, "Failed to perform consumer operation");
                         },
@@ -138,6 +145,7 @@ where
 pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
     state: &T,
     settings: &SchedulerSettings,
+    concurrency_limiter: sync::Arc<types::RunnerConcurrencyLimiter>,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     let stream_name = settings.stream.clone();
@@ -167,10 +175,37 @@ pub async fn consumer_operations<T: SchedulerSessionState + 'static>(
 
         metrics::TASK_CONSUMED.add(&metrics::CONTEXT, 1, &[]);
 
+        // Tasks of a runner at its concurrency limit are put back on the schedule, rather than
+        // holding up the consumer while waiting for a free slot
+        let Some(permit) = concurrency_limiter.try_acquire(task.runner.as_deref()) else {
+            logger::debug!(pt.id=%task.id, pt.runner=?task.runner, "Runner concurrency limit reached");
+            metrics::TASK_THROTTLED.add(&metrics::CONTEXT, 1, &[]);
+            let schedule_time = pickup_time.saturating_add(time::Duration::seconds(
+                THROTTLED_TASK_RESCHEDULE_DELAY_IN_SECONDS,
+            ));
+            if let Err(error) = state
+                .get_db()
+                .as_scheduler()
+                .update_process(
+                    task.clone(),
+                    storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                        status: enums::ProcessTrackerStatus::New,
+                        retry_count: task.retry_count,
+                        schedule_time,
+                    },
+                )
+                .await
+            {
+                logger::error!(?error, pt.id=%task.id, "Failed to reschedule throttled task");
+            }
+            continue;
+        };
+
         handler.push(tokio::task::spawn(start_workflow(
             state.clone(),
             task.clone(),
             pickup_time,
+            permit,
             workflow_selector,
         )))
     }
@@ -226,11 +261,13 @@ pub async fn fetch_consumer_tasks(
 }
 
 // Accept flow_options if required
-#[instrument(skip(state), fields(workflow_id))]
+#[instrument(skip(state, _permit), fields(workflow_id))]
 pub async fn start_workflow<T>(
     state: T,
     process: storage::ProcessTracker,
     _pickup_time: PrimitiveDateTime,
+    // The permit holds the slot of the runner until the workflow completes
+    _permit: types::RunnerPermit,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + std::fmt::Debug,
 ) -> CustomResult<(), errors::ProcessTrackerError>
 where
@@ -238,6 +275,7 @@ where
 {
     tracing::Span::current().record("workflow_id", Uuid::new_v4().to_string());
     logger::info!(pt.name=?process.name, pt.id=%process.id);
+
     let res = workflow_selector
         .trigger_workflow(&state.clone(), process.clone())
         .await
@@ -256,6 +294,14 @@ pub async fn consumer_error_handler(
 ) -> CustomResult<(), errors::ProcessTrackerError> {
     logger::error!(pt.name=?process.name, pt.id=%process.id, ?error, "Failed to execute workflow");
 
+    // Recurring tasks are put back on their schedule, a failed run does not end the recurrence
+    if process.cron_expression.is_some() {
+        return state
+            .finish_process_with_business_status(process, storage::business_status::GLOBAL_ERROR)
+            .await
+            .change_context(errors::ProcessTrackerError::ProcessUpdateFailed);
+    }
+
     state
         .process_tracker_update_process_status_by_ids(
             vec![process.id],
@@ -1,4 +1,8 @@
 pub mod batch;
+pub mod concurrency;
 pub mod process_data;
 
-pub use self::batch::ProcessTrackerBatch;
+pub use self::{
+    batch::ProcessTrackerBatch,
+    concurrency::{RunnerConcurrencyLimiter, RunnerPermit},
+};
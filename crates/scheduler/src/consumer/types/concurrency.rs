@@ -0,0 +1,73 @@
+use std::{collections::HashMap, sync::Arc};
+
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Caps the number of tasks of a runner that the consumer executes at the same time, so that a
+/// burst of tasks of one runner cannot hog the consumer at the expense of other runners.
+#[derive(Debug, Default)]
+pub struct RunnerConcurrencyLimiter {
+    semaphores: HashMap<String, Arc<Semaphore>>,
+}
+
+/// A slot of a runner held by an executing task
+#[derive(Debug)]
+pub struct RunnerPermit(Option<OwnedSemaphorePermit>);
+
+impl RunnerConcurrencyLimiter {
+    /// Creates a limiter from a mapping of runner names to the maximum number of tasks of the
+    /// runner that may execute concurrently. Runner names are matched case-insensitively.
+    pub fn new(limits: &HashMap<String, usize>) -> Self {
+        Self {
+            semaphores: limits
+                .iter()
+                .map(|(runner, limit)| (runner.to_uppercase(), Arc::new(Semaphore::new(*limit))))
+                .collect(),
+        }
+    }
+
+    /// Takes a free slot for the runner without waiting, returns `None` if the runner has no free
+    /// slot left. The returned permit holds the slot until it is dropped, runners without a
+    /// configured limit are never throttled.
+    pub fn try_acquire(&self, runner: Option<&str>) -> Option<RunnerPermit> {
+        match self.get_semaphore(runner) {
+            Some(semaphore) => semaphore.clone().try_acquire_owned().ok().map(Some),
+            None => Some(None),
+        }
+        .map(RunnerPermit)
+    }
+
+    fn get_semaphore(&self, runner: Option<&str>) -> Option<&Arc<Semaphore>> {
+        runner.and_then(|runner| self.semaphores.get(&runner.to_uppercase()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_runner_concurrency_limit() {
+        let limiter = RunnerConcurrencyLimiter::new(&HashMap::from([(
+            String::from("payments_sync_workflow"),
+            1,
+        )]));
+
+        let permit = limiter.try_acquire(Some("PAYMENTS_SYNC_WORKFLOW"));
+        assert!(permit.is_some());
+        assert!(limiter
+            .try_acquire(Some("PAYMENTS_SYNC_WORKFLOW"))
+            .is_none());
+
+        // Runners without a limit are never throttled
+        assert!(limiter
+            .try_acquire(Some("OUTGOING_WEBHOOK_RETRY_WORKFLOW"))
+            .is_some());
+        assert!(limiter.try_acquire(None).is_some());
+
+        drop(permit);
+        assert!(limiter
+            .try_acquire(Some("PAYMENTS_SYNC_WORKFLOW"))
+            .is_some());
+    }
+}
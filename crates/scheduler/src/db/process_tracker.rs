@@ -37,6 +37,15 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         new: storage::ProcessTrackerNew,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError>;
 
+    /// Updates the process only if it is in one of `statuses`, returns `None` if the process has
+    /// moved to another status since it was read.
+    async fn update_process_if_status_in(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError>;
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
@@ -62,6 +71,17 @@ pub trait ProcessTrackerInterface: Send + Sync + 'static {
         status: storage_enums::ProcessTrackerStatus,
         limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn find_processes_by_filters(
+        &self,
+        filters: &storage::ProcessTrackerFilters,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError>;
+
+    async fn reschedule_due_processes_by_runner(
+        &self,
+        runner: &str,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -106,6 +126,27 @@ impl ProcessTrackerInterface for Store {
         .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn find_processes_by_filters(
+        &self,
+        filters: &storage::ProcessTrackerFilters,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ProcessTracker::find_processes_by_filters(&conn, filters)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
+    async fn reschedule_due_processes_by_runner(
+        &self,
+        runner: &str,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ProcessTracker::reschedule_due_processes_by_runner(&conn, runner, schedule_time)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn insert_process(
         &self,
         new: storage::ProcessTrackerNew,
@@ -127,6 +168,18 @@ impl ProcessTrackerInterface for Store {
             .map_err(|error| report!(errors::StorageError::from(error)))
     }
 
+    async fn update_process_if_status_in(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        this.update_if_status_in(&conn, statuses, process)
+            .await
+            .map_err(|error| report!(errors::StorageError::from(error)))
+    }
+
     async fn reset_process(
         &self,
         this: storage::ProcessTracker,
@@ -168,16 +221,47 @@ impl ProcessTrackerInterface for Store {
         this: storage::ProcessTracker,
         business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        self.update_process(
-            this,
-            storage::ProcessTrackerUpdate::StatusUpdate {
-                status: storage_enums::ProcessTrackerStatus::Finish,
-                business_status: Some(String::from(business_status)),
-            },
-        )
-        .await
-        .attach_printable("Failed to update business status of process")?;
-        metrics::TASK_FINISHED.add(&metrics::CONTEXT, 1, &[]);
+        // Recurring tasks are put back on their schedule instead of being finished, unless the
+        // resource they operate on no longer exists
+        let next_occurrence = if business_status == storage::business_status::REVOKED {
+            None
+        } else {
+            this.get_next_cron_occurrence(common_utils::date_time::now())
+        };
+
+        match next_occurrence {
+            Some(schedule_time) => {
+                // A recurring process revoked while it was running stays finished
+                let rescheduled_process = self
+                    .update_process_if_status_in(
+                        this,
+                        vec![
+                            storage_enums::ProcessTrackerStatus::Processing,
+                            storage_enums::ProcessTrackerStatus::New,
+                            storage_enums::ProcessTrackerStatus::Pending,
+                            storage_enums::ProcessTrackerStatus::ProcessStarted,
+                        ],
+                        storage::ProcessTrackerUpdate::RecurrenceUpdate { schedule_time },
+                    )
+                    .await
+                    .attach_printable("Failed to reschedule recurring process")?;
+                if rescheduled_process.is_some() {
+                    metrics::TASK_RESCHEDULED.add(&metrics::CONTEXT, 1, &[]);
+                }
+            }
+            None => {
+                self.update_process(
+                    this,
+                    storage::ProcessTrackerUpdate::StatusUpdate {
+                        status: storage_enums::ProcessTrackerStatus::Finish,
+                        business_status: Some(String::from(business_status)),
+                    },
+                )
+                .await
+                .attach_printable("Failed to update business status of process")?;
+                metrics::TASK_FINISHED.add(&metrics::CONTEXT, 1, &[]);
+            }
+        }
         Ok(())
     }
 
@@ -212,22 +296,126 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn reinitialize_limbo_processes(
         &self,
-        _ids: Vec<String>,
-        _schedule_time: PrimitiveDateTime,
+        ids: Vec<String>,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let mut updated_count = 0;
+        for process in processes.iter_mut().filter(|process| {
+            process.status == storage_enums::ProcessTrackerStatus::ProcessStarted
+                && ids.contains(&process.id)
+        }) {
+            process.status = storage_enums::ProcessTrackerStatus::Processing;
+            process.schedule_time = Some(schedule_time);
+            updated_count += 1;
+        }
+        Ok(updated_count)
     }
 
     async fn find_processes_by_time_status(
         &self,
-        _time_lower_limit: PrimitiveDateTime,
-        _time_upper_limit: PrimitiveDateTime,
-        _status: storage_enums::ProcessTrackerStatus,
-        _limit: Option<i64>,
+        time_lower_limit: PrimitiveDateTime,
+        time_upper_limit: PrimitiveDateTime,
+        status: storage_enums::ProcessTrackerStatus,
+        limit: Option<i64>,
     ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                process.status == status
+                    && process.schedule_time.is_some_and(|schedule_time| {
+                        schedule_time >= time_lower_limit && schedule_time <= time_upper_limit
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        processes.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(processes.into_iter().take(limit).collect())
+    }
+
+    async fn find_processes_by_filters(
+        &self,
+        filters: &storage::ProcessTrackerFilters,
+    ) -> CustomResult<Vec<storage::ProcessTracker>, errors::StorageError> {
+        let mut processes = self
+            .processes
+            .lock()
+            .await
+            .iter()
+            .filter(|process| {
+                filters
+                    .runner
+                    .as_ref()
+                    .map_or(true, |runner| process.runner.as_ref() == Some(runner))
+                    && filters
+                        .status
+                        .map_or(true, |status| process.status == status)
+                    && filters
+                        .business_status
+                        .as_ref()
+                        .map_or(true, |business_status| {
+                            &process.business_status == business_status
+                        })
+                    && filters.merchant_id.as_ref().map_or(true, |merchant_id| {
+                        process
+                            .tracking_data
+                            .get("merchant_id")
+                            .and_then(|value| value.as_str())
+                            == Some(merchant_id.get_string_repr())
+                    })
+                    && filters.scheduled_after.map_or(true, |scheduled_after| {
+                        process.schedule_time >= Some(scheduled_after)
+                    })
+                    && filters.scheduled_before.map_or(true, |scheduled_before| {
+                        process
+                            .schedule_time
+                            .is_some_and(|schedule_time| schedule_time <= scheduled_before)
+                    })
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        processes.sort_by(|a, b| b.schedule_time.cmp(&a.schedule_time));
+
+        let offset = filters
+            .offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = filters
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(processes.into_iter().skip(offset).take(limit).collect())
+    }
+
+    async fn reschedule_due_processes_by_runner(
+        &self,
+        runner: &str,
+        schedule_time: PrimitiveDateTime,
+    ) -> CustomResult<usize, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        let mut updated_count = 0;
+        for process in processes.iter_mut().filter(|process| {
+            process.runner.as_deref() == Some(runner)
+                && matches!(
+                    process.status,
+                    storage_enums::ProcessTrackerStatus::New
+                        | storage_enums::ProcessTrackerStatus::Pending
+                )
+                && process
+                    .schedule_time
+                    .is_some_and(|process_schedule_time| process_schedule_time < schedule_time)
+        }) {
+            process.schedule_time = Some(schedule_time);
+            updated_count += 1;
+        }
+        Ok(updated_count)
     }
 
     async fn insert_process(
@@ -249,6 +437,8 @@ impl ProcessTrackerInterface for MockDb {
             event: new.event,
             created_at: new.created_at,
             updated_at: new.updated_at,
+            priority: new.priority,
+            cron_expression: new.cron_expression,
         };
         processes.push(process.clone());
         Ok(process)
@@ -256,46 +446,276 @@ impl ProcessTrackerInterface for MockDb {
 
     async fn update_process(
         &self,
-        _this: storage::ProcessTracker,
-        _process: storage::ProcessTrackerUpdate,
+        this: storage::ProcessTracker,
+        process: storage::ProcessTrackerUpdate,
     ) -> CustomResult<storage::ProcessTracker, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let mut processes = self.processes.lock().await;
+        let stored_process = processes
+            .iter_mut()
+            .find(|stored_process| stored_process.id == this.id)
+            .ok_or(errors::StorageError::ValueNotFound(format!(
+                "No process found for id = {}",
+                this.id
+            )))?;
+        *stored_process = storage::ProcessTrackerUpdateInternal::from(process)
+            .apply_changeset(stored_process.clone());
+        Ok(stored_process.clone())
+    }
+
+    async fn update_process_if_status_in(
+        &self,
+        this: storage::ProcessTracker,
+        statuses: Vec<storage_enums::ProcessTrackerStatus>,
+        process: storage::ProcessTrackerUpdate,
+    ) -> CustomResult<Option<storage::ProcessTracker>, errors::StorageError> {
+        let mut processes = self.processes.lock().await;
+        Ok(processes
+            .iter_mut()
+            .find(|stored_process| {
+                stored_process.id == this.id && statuses.contains(&stored_process.status)
+            })
+            .map(|stored_process| {
+                *stored_process = storage::ProcessTrackerUpdateInternal::from(process)
+                    .apply_changeset(stored_process.clone());
+                stored_process.clone()
+            }))
     }
 
     async fn reset_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::New,
+                retry_count: 0,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn retry_process(
         &self,
-        _this: storage::ProcessTracker,
-        _schedule_time: PrimitiveDateTime,
+        this: storage::ProcessTracker,
+        schedule_time: PrimitiveDateTime,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let retry_count = this.retry_count + 1;
+        self.update_process(
+            this,
+            storage::ProcessTrackerUpdate::StatusRetryUpdate {
+                status: storage_enums::ProcessTrackerStatus::Pending,
+                retry_count,
+                schedule_time,
+            },
+        )
+        .await?;
+        Ok(())
     }
 
     async fn finish_process_with_business_status(
         &self,
-        _this: storage::ProcessTracker,
-        _business_status: &'static str,
+        this: storage::ProcessTracker,
+        business_status: &'static str,
     ) -> CustomResult<(), errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let next_occurrence = if business_status == storage::business_status::REVOKED {
+            None
+        } else {
+            this.get_next_cron_occurrence(common_utils::date_time::now())
+        };
+
+        match next_occurrence {
+            Some(schedule_time) => {
+                self.update_process_if_status_in(
+                    this,
+                    vec![
+                        storage_enums::ProcessTrackerStatus::Processing,
+                        storage_enums::ProcessTrackerStatus::New,
+                        storage_enums::ProcessTrackerStatus::Pending,
+                        storage_enums::ProcessTrackerStatus::ProcessStarted,
+                    ],
+                    storage::ProcessTrackerUpdate::RecurrenceUpdate { schedule_time },
+                )
+                .await?;
+            }
+            None => {
+                self.update_process(
+                    this,
+                    storage::ProcessTrackerUpdate::StatusUpdate {
+                        status: storage_enums::ProcessTrackerStatus::Finish,
+                        business_status: Some(String::from(business_status)),
+                    },
+                )
+                .await?;
+            }
+        }
+        Ok(())
     }
 
     async fn process_tracker_update_process_status_by_ids(
         &self,
-        _task_ids: Vec<String>,
-        _task_update: storage::ProcessTrackerUpdate,
+        task_ids: Vec<String>,
+        task_update: storage::ProcessTrackerUpdate,
     ) -> CustomResult<usize, errors::StorageError> {
-        // [#172]: Implement function for `MockDb`
-        Err(errors::StorageError::MockDbError)?
+        let task_update = storage::ProcessTrackerUpdateInternal::from(task_update);
+        let mut processes = self.processes.lock().await;
+        let mut updated_count = 0;
+        for process in processes
+            .iter_mut()
+            .filter(|process| task_ids.contains(&process.id))
+        {
+            *process = task_update.clone().apply_changeset(process.clone());
+            updated_count += 1;
+        }
+        Ok(updated_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::expect_used, clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use diesel_models::{enums as storage_enums, process_tracker as storage};
+    use redis_interface::RedisSettings;
+    use storage_impl::mock_db::MockDb;
+
+    use super::ProcessTrackerInterface;
+
+    fn create_process_new(
+        id: &str,
+        merchant_id: &str,
+        schedule_offset_in_secs: i64,
+    ) -> storage::ProcessTrackerNew {
+        storage::ProcessTrackerNew::new(
+            id,
+            "PAYMENTS_SYNC",
+            storage::ProcessTrackerRunner::PaymentsSyncWorkflow,
+            ["PAYMENT"],
+            serde_json::json!({ "merchant_id": merchant_id }),
+            common_utils::date_time::now()
+                .saturating_add(time::Duration::seconds(schedule_offset_in_secs)),
+        )
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_find_processes_by_filters_matches_merchant_in_tracking_data() {
+        let mockdb = MockDb::new(&RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+
+        for (id, merchant_id, offset) in [
+            ("task_1", "merchant_1", 10),
+            ("task_2", "merchant_2", 20),
+            ("task_3", "merchant_1", 30),
+        ] {
+            mockdb
+                .insert_process(create_process_new(id, merchant_id, offset))
+                .await
+                .unwrap();
+        }
+
+        let filters = storage::ProcessTrackerFilters {
+            merchant_id: Some(
+                common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap(),
+            ),
+            ..Default::default()
+        };
+        let processes = mockdb.find_processes_by_filters(&filters).await.unwrap();
+        assert_eq!(
+            processes
+                .iter()
+                .map(|process| process.id.as_str())
+                .collect::<Vec<_>>(),
+            vec!["task_3", "task_1"]
+        );
+
+        let filters = storage::ProcessTrackerFilters {
+            limit: Some(1),
+            offset: Some(1),
+            ..filters
+        };
+        let processes = mockdb.find_processes_by_filters(&filters).await.unwrap();
+        assert_eq!(processes.len(), 1);
+        assert_eq!(processes[0].id, "task_1");
+    }
+
+    #[tokio::test]
+    async fn test_update_process_if_status_in_skips_finished_process() {
+        let mockdb = MockDb::new(&RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+
+        let process = mockdb
+            .insert_process(create_process_new("task_1", "merchant_1", 10))
+            .await
+            .unwrap();
+        mockdb
+            .finish_process_with_business_status(
+                process.clone(),
+                storage::business_status::COMPLETED_BY_PT,
+            )
+            .await
+            .unwrap();
+
+        let updated_process = mockdb
+            .update_process_if_status_in(
+                process,
+                vec![
+                    storage_enums::ProcessTrackerStatus::New,
+                    storage_enums::ProcessTrackerStatus::Pending,
+                ],
+                storage::ProcessTrackerUpdate::StatusUpdate {
+                    status: storage_enums::ProcessTrackerStatus::Finish,
+                    business_status: Some(String::from(storage::business_status::REVOKED)),
+                },
+            )
+            .await
+            .unwrap();
+        assert!(updated_process.is_none());
+
+        let stored_process = mockdb.find_process_by_id("task_1").await.unwrap().unwrap();
+        assert_eq!(
+            stored_process.status,
+            storage_enums::ProcessTrackerStatus::Finish
+        );
+        assert_eq!(
+            stored_process.business_status,
+            storage::business_status::COMPLETED_BY_PT
+        );
+    }
+
+    #[tokio::test]
+    async fn test_reschedule_due_processes_by_runner_moves_only_due_processes() {
+        let mockdb = MockDb::new(&RedisSettings::default())
+            .await
+            .expect("Failed to create a mock DB");
+
+        mockdb
+            .insert_process(create_process_new("task_1", "merchant_1", -60))
+            .await
+            .unwrap();
+        mockdb
+            .insert_process(create_process_new("task_2", "merchant_1", 600))
+            .await
+            .unwrap();
+
+        let schedule_time =
+            common_utils::date_time::now().saturating_add(time::Duration::minutes(5));
+        let rescheduled_count = mockdb
+            .reschedule_due_processes_by_runner(
+                &storage::ProcessTrackerRunner::PaymentsSyncWorkflow.to_string(),
+                schedule_time,
+            )
+            .await
+            .unwrap();
+        assert_eq!(rescheduled_count, 1);
+
+        let process = mockdb.find_process_by_id("task_1").await.unwrap().unwrap();
+        assert_eq!(process.schedule_time, Some(schedule_time));
     }
 }
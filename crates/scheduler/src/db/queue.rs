@@ -40,6 +40,18 @@ pub trait QueueInterface {
     ) -> CustomResult<(), RedisError>;
 
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError>;
+
+    /// Pauses the runner, the producer stops scheduling tasks of the runner until it is resumed
+    async fn pause_runner(&self, runner: &str, paused_by: &str) -> CustomResult<(), RedisError>;
+
+    async fn resume_runner(&self, runner: &str) -> CustomResult<(), RedisError>;
+
+    /// Returns who paused the runner, or `None` if the runner is not paused
+    async fn get_runner_paused_by(&self, runner: &str) -> CustomResult<Option<String>, RedisError>;
+}
+
+fn get_runner_pause_key(runner: &str) -> String {
+    format!("SCHEDULER_PAUSED_RUNNER_{}", runner.to_uppercase())
 }
 
 #[async_trait::async_trait]
@@ -132,6 +144,26 @@ impl QueueInterface for Store {
     async fn get_key(&self, key: &str) -> CustomResult<Vec<u8>, RedisError> {
         self.get_redis_conn()?.get_key::<Vec<u8>>(key).await
     }
+
+    async fn pause_runner(&self, runner: &str, paused_by: &str) -> CustomResult<(), RedisError> {
+        // The pause must outlive the default TTL of keys, it lasts until the runner is resumed
+        self.get_redis_conn()?
+            .set_key_without_expiry(&get_runner_pause_key(runner), paused_by)
+            .await
+    }
+
+    async fn resume_runner(&self, runner: &str) -> CustomResult<(), RedisError> {
+        self.get_redis_conn()?
+            .delete_key(&get_runner_pause_key(runner))
+            .await
+            .map(|_del_reply| ())
+    }
+
+    async fn get_runner_paused_by(&self, runner: &str) -> CustomResult<Option<String>, RedisError> {
+        self.get_redis_conn()?
+            .get_key::<Option<String>>(&get_runner_pause_key(runner))
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -187,4 +219,19 @@ impl QueueInterface for MockDb {
     async fn get_key(&self, _key: &str) -> CustomResult<Vec<u8>, RedisError> {
         Err(RedisError::RedisConnectionError.into())
     }
+
+    async fn pause_runner(&self, _runner: &str, _paused_by: &str) -> CustomResult<(), RedisError> {
+        Err(RedisError::RedisConnectionError.into())
+    }
+
+    async fn resume_runner(&self, _runner: &str) -> CustomResult<(), RedisError> {
+        Err(RedisError::RedisConnectionError.into())
+    }
+
+    async fn get_runner_paused_by(
+        &self,
+        _runner: &str,
+    ) -> CustomResult<Option<String>, RedisError> {
+        Ok(None)
+    }
 }
@@ -13,3 +13,5 @@ counter_metric!(TASK_CONSUMED, PT_METER); // Tasks consumed by consumer
 counter_metric!(TASK_PROCESSED, PT_METER); // Tasks completed processing
 counter_metric!(TASK_FINISHED, PT_METER); // Tasks finished
 counter_metric!(TASK_RETRIED, PT_METER); // Tasks added for retries
+counter_metric!(TASK_RESCHEDULED, PT_METER); // Recurring tasks rescheduled for their next run
+counter_metric!(TASK_THROTTLED, PT_METER); // Tasks rescheduled as their runner was at its concurrency limit
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{collections::HashSet, sync::Arc};
 
 use common_utils::errors::CustomResult;
 use diesel_models::enums::ProcessTrackerStatus;
@@ -173,8 +173,46 @@ pub async fn fetch_producer_tasks(
 
     new_tasks.append(&mut pending_tasks);
 
+    let paused_runners = get_paused_runners(db, &new_tasks).await;
+    if !paused_runners.is_empty() {
+        warn!(?paused_runners, "Skipping tasks of paused runners");
+        new_tasks.retain(|task| {
+            task.runner
+                .as_ref()
+                .map_or(true, |runner| !paused_runners.contains(runner))
+        });
+    }
+
+    // Tasks are batched and appended to the stream in this order, so that higher priority tasks
+    // are consumed ahead of the rest of the tasks due in the same window
+    new_tasks.sort_by_key(|task| (std::cmp::Reverse(task.priority), task.schedule_time));
+
     // Safety: Assuming we won't deal with more than `u64::MAX` tasks at once
     #[allow(clippy::as_conversions)]
     metrics::TASKS_PICKED_COUNT.add(&metrics::CONTEXT, new_tasks.len() as u64, &[]);
     Ok(new_tasks)
 }
+
+/// Returns the runners of the tasks that have been paused. Failing to read the pause state of a
+/// runner does not block scheduling of its tasks.
+async fn get_paused_runners(
+    db: &dyn SchedulerInterface,
+    tasks: &[storage::ProcessTracker],
+) -> HashSet<String> {
+    let runners = tasks
+        .iter()
+        .filter_map(|task| task.runner.clone())
+        .collect::<HashSet<_>>();
+
+    let mut paused_runners = HashSet::new();
+    for runner in runners {
+        match db.get_runner_paused_by(&runner).await {
+            Ok(Some(_paused_by)) => {
+                paused_runners.insert(runner);
+            }
+            Ok(None) => {}
+            Err(error) => error!(?error, %runner, "Failed to check whether runner is paused"),
+        }
+    }
+    paused_runners
+}
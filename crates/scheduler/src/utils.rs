@@ -267,6 +267,7 @@ pub fn get_time_from_delta(delta: Option<i32>) -> Option<time::PrimitiveDateTime
 pub async fn consumer_operation_handler<E, T>(
     state: T,
     settings: sync::Arc<SchedulerSettings>,
+    concurrency_limiter: sync::Arc<consumer::types::RunnerConcurrencyLimiter>,
     error_handler_fun: E,
     workflow_selector: impl workflows::ProcessTrackerWorkflows<T> + 'static + Copy + std::fmt::Debug,
 ) where
@@ -274,7 +275,9 @@ pub async fn consumer_operation_handler<E, T>(
     E: FnOnce(error_stack::Report<errors::ProcessTrackerError>),
     T: SchedulerSessionState + Send + Sync + 'static,
 {
-    match consumer::consumer_operations(&state, &settings, workflow_selector).await {
+    match consumer::consumer_operations(&state, &settings, concurrency_limiter, workflow_selector)
+        .await
+    {
         Ok(_) => (),
         Err(err) => error_handler_fun(err),
     }
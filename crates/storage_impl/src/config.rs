@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use common_utils::DbConnectionParams;
 use masking::Secret;
 
@@ -72,3 +74,80 @@ impl Default for Database {
         }
     }
 }
+
+/// Routing of reads between the master and replica databases
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(default)]
+pub struct ReplicaReadConfig {
+    /// Routes reads to the replica database, all reads use the master database if disabled
+    pub enabled: bool,
+    /// Time in milliseconds after a write, on top of the replica lag, for which reads made by the
+    /// same request use the master database
+    pub read_after_write_delay: u64,
+    /// Replica lag in milliseconds beyond which all reads use the master database
+    pub max_lag: u64,
+    /// Interval in seconds after which the replica lag is measured again
+    pub lag_probe_interval: u64,
+    /// Read preference of tables which are not configured in `tables`, other than the payment
+    /// tables
+    pub default_preference: ReadPreference,
+    /// Read preference of specific tables
+    pub tables: HashMap<String, ReadPreference>,
+}
+
+/// Tables read from the master database unless configured otherwise. The writes to these tables
+/// are often followed by reads made by other requests, such as webhooks and redirections served
+/// by other instances, which the consistency token of an instance does not cover.
+const MASTER_PREFERRED_TABLES: [&str; 7] = [
+    "payment_intent",
+    "payment_attempt",
+    "refund",
+    "captures",
+    "dispute",
+    "payouts",
+    "payout_attempt",
+];
+
+impl ReplicaReadConfig {
+    /// The read preference of `table`. Reads which do not specify the table may read any table,
+    /// so they use the master database.
+    pub fn get_preference(&self, table: Option<&str>) -> ReadPreference {
+        let Some(table) = table else {
+            return ReadPreference::Master;
+        };
+
+        self.tables.get(table).copied().unwrap_or_else(|| {
+            if MASTER_PREFERRED_TABLES.contains(&table) {
+                ReadPreference::Master
+            } else {
+                self.default_preference
+            }
+        })
+    }
+}
+
+impl Default for ReplicaReadConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            read_after_write_delay: 1000,
+            max_lag: 5000,
+            lag_probe_interval: 5,
+            default_preference: ReadPreference::default(),
+            tables: HashMap::new(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReadPreference {
+    /// Reads always use the master database
+    Master,
+    /// Reads use the replica database, unless it is yet to catch up with the writes made by the
+    /// request
+    #[default]
+    Consistent,
+    /// Reads use the replica database even after writes, for data which tolerates stale reads
+    Replica,
+}
@@ -25,12 +25,36 @@ pub async fn pg_connection_read<T: crate::DatabaseStore>(
 ) -> errors::CustomResult<
     PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
     crate::errors::StorageError,
+> {
+    get_read_connection(store, None).await
+}
+
+/// Fetches a connection to read `table` from, as per the read preference configured for the table
+pub async fn pg_connection_read_table<T: crate::DatabaseStore>(
+    store: &T,
+    table: &str,
+) -> errors::CustomResult<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    crate::errors::StorageError,
+> {
+    get_read_connection(store, Some(table)).await
+}
+
+async fn get_read_connection<'a, T: crate::DatabaseStore>(
+    store: &'a T,
+    table: Option<&str>,
+) -> errors::CustomResult<
+    PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    crate::errors::StorageError,
 > {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
-    let pool = store.get_replica_pool();
+    let pool = {
+        let _ = table;
+        store.get_replica_pool()
+    };
 
-    // If either one of these are true we need to get master pool.
+    // If either one of these are true reads are routed between the master and replica pools.
     //  1. Only OLTP is enabled.
     //  2. Both OLAP and OLTP is enabled.
     //  3. Both OLAP and OLTP is disabled.
@@ -39,7 +63,7 @@ pub async fn pg_connection_read<T: crate::DatabaseStore>(
         all(feature = "olap", feature = "oltp"),
         all(not(feature = "olap"), not(feature = "oltp"))
     ))]
-    let pool = store.get_master_pool();
+    let pool = crate::database::routing::get_read_pool(store, table).await;
 
     pool.get()
         .await
@@ -48,14 +72,19 @@ pub async fn pg_connection_read<T: crate::DatabaseStore>(
 
 pub async fn pg_connection_write<T: crate::DatabaseStore>(
     store: &T,
-) -> errors::CustomResult<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    crate::errors::StorageError,
-> {
+) -> errors::CustomResult<crate::database::routing::WriteConnection<'_>, crate::errors::StorageError>
+{
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
+    // Reads made for the merchant after the write are not routed to a lagging replica
     pool.get()
         .await
         .change_context(crate::errors::StorageError::DatabaseConnectionError)
+        .map(|conn| {
+            crate::database::routing::WriteConnection::new(
+                conn,
+                store.get_consistency_token().cloned(),
+            )
+        })
 }
@@ -1 +1,2 @@
+pub mod routing;
 pub mod store;
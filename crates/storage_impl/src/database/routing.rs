@@ -0,0 +1,504 @@
+use std::{
+    collections::HashMap,
+    ops::Deref,
+    sync::{Arc, Mutex, PoisonError},
+    time::{Duration, Instant},
+};
+
+use async_bb8_diesel::AsyncRunQueryDsl;
+use bb8::PooledConnection;
+use diesel::PgConnection;
+use error_stack::ResultExt;
+use hyperswitch_domain_models::errors::{StorageError, StorageResult};
+use router_env::{logger, metrics::add_attributes};
+
+use crate::{
+    config::{ReadPreference, ReplicaReadConfig},
+    database::store::{DatabaseStore, PgPool},
+    metrics,
+};
+
+/// Measures the replica lag in milliseconds. The lag is zero once the replica has replayed all
+/// the WAL it received, and when the database is not a replica.
+const REPLICA_LAG_QUERY: &str = "SELECT CASE \
+    WHEN pg_last_wal_receive_lsn() = pg_last_wal_replay_lsn() THEN 0 \
+    ELSE COALESCE(EXTRACT(EPOCH FROM now() - pg_last_xact_replay_timestamp()) * 1000, 0) \
+    END::BIGINT AS lag";
+
+/// Time after its last write for which the consistency token of a merchant is retained. It exceeds
+/// the replica lag beyond which reads use the master database along with the read-after-write
+/// delay, after which the last write of the token no longer affects where reads go.
+const CONSISTENCY_TOKEN_RETENTION: Duration = Duration::from_secs(60);
+
+/// Records the time of the last write made for a merchant, so that reads made for the merchant
+/// after the write, by any request, are not served by a replica which is yet to replay it
+#[derive(Debug, Clone, Default)]
+pub struct ConsistencyToken {
+    last_write_at: Arc<Mutex<Option<Instant>>>,
+}
+
+impl ConsistencyToken {
+    pub fn record_write(&self) {
+        *self
+            .last_write_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+    }
+
+    pub fn last_write_at(&self) -> Option<Instant> {
+        *self
+            .last_write_at
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Whether no request holds the token and its last write, if any, was made longer than the
+    /// retention ago
+    fn is_expired(&self, now: Instant) -> bool {
+        Arc::strong_count(&self.last_write_at) == 1
+            && self.last_write_at().map_or(true, |last_write_at| {
+                now.saturating_duration_since(last_write_at) >= CONSISTENCY_TOKEN_RETENTION
+            })
+    }
+}
+
+#[derive(Debug)]
+struct ConsistencyTokenMap {
+    tokens: HashMap<String, ConsistencyToken>,
+    evicted_at: Instant,
+}
+
+/// The consistency tokens of the merchants, shared by the requests served by a store. Expired
+/// tokens are evicted at most once per retention period.
+#[derive(Debug)]
+pub struct ConsistencyTokens {
+    tokens: Mutex<ConsistencyTokenMap>,
+}
+
+impl Default for ConsistencyTokens {
+    fn default() -> Self {
+        Self {
+            tokens: Mutex::new(ConsistencyTokenMap {
+                tokens: HashMap::new(),
+                evicted_at: Instant::now(),
+            }),
+        }
+    }
+}
+
+impl ConsistencyTokens {
+    pub fn get(&self, merchant_id: &common_utils::id_type::MerchantId) -> ConsistencyToken {
+        self.get_at(merchant_id, Instant::now())
+    }
+
+    fn get_at(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        now: Instant,
+    ) -> ConsistencyToken {
+        let mut token_map = self.tokens.lock().unwrap_or_else(PoisonError::into_inner);
+        if now.saturating_duration_since(token_map.evicted_at) >= CONSISTENCY_TOKEN_RETENTION {
+            token_map.tokens.retain(|_, token| !token.is_expired(now));
+            token_map.evicted_at = now;
+        }
+
+        token_map
+            .tokens
+            .entry(merchant_id.get_string_repr().to_owned())
+            .or_default()
+            .clone()
+    }
+
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.tokens
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .tokens
+            .len()
+    }
+}
+
+/// A connection to the master database, which records the write in the consistency token of the
+/// request once it is released, that is after the write has been committed
+pub struct WriteConnection<'a> {
+    conn: PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    consistency_token: Option<ConsistencyToken>,
+}
+
+impl<'a> WriteConnection<'a> {
+    pub fn new(
+        conn: PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+        consistency_token: Option<ConsistencyToken>,
+    ) -> Self {
+        Self {
+            conn,
+            consistency_token,
+        }
+    }
+}
+
+impl<'a> Deref for WriteConnection<'a> {
+    type Target = PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.conn
+    }
+}
+
+impl Drop for WriteConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(consistency_token) = &self.consistency_token {
+            consistency_token.record_write();
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReadTarget {
+    Master,
+    Replica,
+}
+
+/// The database picked for a read, along with the reason for picking it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoutingDecision {
+    /// Reads are not routed to the replica
+    RoutingDisabled,
+    /// The table is configured to be read from the master database
+    MasterPreferred,
+    /// The replica lag could not be measured
+    ReplicaLagUnknown,
+    /// The replica lags behind by more than the configured maximum
+    ReplicaLagExceeded,
+    /// The replica may be yet to replay a write made by the request
+    ReadAfterWrite,
+    Replica,
+}
+
+impl RoutingDecision {
+    pub fn target(self) -> ReadTarget {
+        match self {
+            Self::Replica => ReadTarget::Replica,
+            Self::RoutingDisabled
+            | Self::MasterPreferred
+            | Self::ReplicaLagUnknown
+            | Self::ReplicaLagExceeded
+            | Self::ReadAfterWrite => ReadTarget::Master,
+        }
+    }
+
+    fn reason(self) -> &'static str {
+        match self {
+            Self::RoutingDisabled => "routing_disabled",
+            Self::MasterPreferred => "master_preferred",
+            Self::ReplicaLagUnknown => "replica_lag_unknown",
+            Self::ReplicaLagExceeded => "replica_lag_exceeded",
+            Self::ReadAfterWrite => "read_after_write",
+            Self::Replica => "replica",
+        }
+    }
+}
+
+/// Picks the database to read from. Reads go to the master database when the replica lag is
+/// unknown or too high, and for consistent reads made within the replica lag and the
+/// read-after-write delay of the last write of the request.
+pub fn route_read(
+    config: &ReplicaReadConfig,
+    preference: ReadPreference,
+    replica_lag: Option<Duration>,
+    last_write_at: Option<Instant>,
+    now: Instant,
+) -> RoutingDecision {
+    if !config.enabled {
+        return RoutingDecision::RoutingDisabled;
+    }
+    if preference == ReadPreference::Master {
+        return RoutingDecision::MasterPreferred;
+    }
+    let Some(replica_lag) = replica_lag else {
+        return RoutingDecision::ReplicaLagUnknown;
+    };
+    if replica_lag > Duration::from_millis(config.max_lag) {
+        return RoutingDecision::ReplicaLagExceeded;
+    }
+
+    let read_after_write = preference == ReadPreference::Consistent
+        && last_write_at.is_some_and(|last_write_at| {
+            now.saturating_duration_since(last_write_at)
+                < replica_lag.saturating_add(Duration::from_millis(config.read_after_write_delay))
+        });
+    if read_after_write {
+        RoutingDecision::ReadAfterWrite
+    } else {
+        RoutingDecision::Replica
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct LagSample {
+    /// `None` if the lag could not be measured
+    lag: Option<Duration>,
+    measured_at: Instant,
+}
+
+/// The replica lag as last measured. The lag is measured again by a single read once the
+/// measurement is older than the probe interval, while the other reads use the previous one.
+#[derive(Debug, Default)]
+pub struct ReplicaLag {
+    sample: Mutex<Option<LagSample>>,
+    probe: futures::lock::Mutex<()>,
+}
+
+impl ReplicaLag {
+    pub async fn get(&self, replica_pool: &PgPool, probe_interval: Duration) -> Option<Duration> {
+        let sample = *self.sample.lock().unwrap_or_else(PoisonError::into_inner);
+        if sample.is_some_and(|sample| sample.measured_at.elapsed() < probe_interval) {
+            return sample.and_then(|sample| sample.lag);
+        }
+        let Some(_probe) = self.probe.try_lock() else {
+            return sample.and_then(|sample| sample.lag);
+        };
+
+        let lag = measure_replica_lag(replica_pool)
+            .await
+            .map_err(|error| {
+                logger::error!(?error, "Failed to measure the replica lag");
+                metrics::REPLICA_LAG_PROBE_FAILED.add(&metrics::CONTEXT, 1, &[]);
+            })
+            .ok();
+        if let Some(lag) = lag {
+            metrics::REPLICA_LAG.observe(
+                &metrics::CONTEXT,
+                u64::try_from(lag.as_millis()).unwrap_or(u64::MAX),
+                &[],
+            );
+        }
+        *self.sample.lock().unwrap_or_else(PoisonError::into_inner) = Some(LagSample {
+            lag,
+            measured_at: Instant::now(),
+        });
+        lag
+    }
+}
+
+#[derive(diesel::QueryableByName)]
+struct ReplicaLagRow {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    lag: i64,
+}
+
+async fn measure_replica_lag(replica_pool: &PgPool) -> StorageResult<Duration> {
+    let conn = replica_pool
+        .get()
+        .await
+        .change_context(StorageError::DatabaseConnectionError)?;
+    let row = diesel::sql_query(REPLICA_LAG_QUERY)
+        .get_result_async::<ReplicaLagRow>(&*conn)
+        .await
+        .change_context(StorageError::DatabaseConnectionError)
+        .attach_printable("Failed to query the replica lag")?;
+
+    Ok(Duration::from_millis(
+        u64::try_from(row.lag).unwrap_or_default(),
+    ))
+}
+
+/// Picks the pool to read `table` from, as per the read routing of the store and the writes made
+/// by the request
+pub async fn get_read_pool<'a, T: DatabaseStore>(store: &'a T, table: Option<&str>) -> &'a PgPool {
+    let last_write_at = store
+        .get_consistency_token()
+        .and_then(ConsistencyToken::last_write_at);
+    let decision = store.route_read(table, last_write_at).await;
+
+    if decision != RoutingDecision::RoutingDisabled {
+        metrics::DATABASE_READS_ROUTED.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([
+                ("table", table.unwrap_or("unknown").to_owned()),
+                ("reason", decision.reason().to_owned()),
+            ]),
+        );
+    }
+
+    match decision.target() {
+        ReadTarget::Master => store.get_master_pool(),
+        ReadTarget::Replica => store.get_replica_pool(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::borrow::Cow;
+
+    use super::*;
+
+    fn config() -> ReplicaReadConfig {
+        ReplicaReadConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    const LAG: Option<Duration> = Some(Duration::from_millis(200));
+
+    #[test]
+    fn test_reads_use_master_when_disabled() {
+        let decision = route_read(
+            &ReplicaReadConfig::default(),
+            ReadPreference::Consistent,
+            LAG,
+            None,
+            Instant::now(),
+        );
+
+        assert_eq!(decision, RoutingDecision::RoutingDisabled);
+        assert_eq!(decision.target(), ReadTarget::Master);
+    }
+
+    #[test]
+    fn test_reads_without_writes_use_replica() {
+        let decision = route_read(
+            &config(),
+            ReadPreference::Consistent,
+            LAG,
+            None,
+            Instant::now(),
+        );
+
+        assert_eq!(decision, RoutingDecision::Replica);
+    }
+
+    #[test]
+    fn test_read_after_write_uses_master_until_replica_catches_up() {
+        let config = config();
+        let written_at = Instant::now();
+
+        // Within the replica lag and the read-after-write delay of the write
+        let decision = route_read(
+            &config,
+            ReadPreference::Consistent,
+            LAG,
+            Some(written_at),
+            written_at + Duration::from_millis(1100),
+        );
+        assert_eq!(decision, RoutingDecision::ReadAfterWrite);
+
+        let decision = route_read(
+            &config,
+            ReadPreference::Consistent,
+            LAG,
+            Some(written_at),
+            written_at + Duration::from_millis(1300),
+        );
+        assert_eq!(decision, RoutingDecision::Replica);
+
+        // Tables tolerating stale reads use the replica right after writes
+        let decision = route_read(
+            &config,
+            ReadPreference::Replica,
+            LAG,
+            Some(written_at),
+            written_at,
+        );
+        assert_eq!(decision, RoutingDecision::Replica);
+    }
+
+    #[test]
+    fn test_lagging_replica_falls_back_to_master() {
+        let config = config();
+
+        let decision = route_read(
+            &config,
+            ReadPreference::Replica,
+            Some(Duration::from_millis(config.max_lag + 1)),
+            None,
+            Instant::now(),
+        );
+        assert_eq!(decision, RoutingDecision::ReplicaLagExceeded);
+
+        let decision = route_read(&config, ReadPreference::Replica, None, None, Instant::now());
+        assert_eq!(decision, RoutingDecision::ReplicaLagUnknown);
+    }
+
+    #[test]
+    fn test_table_preference() {
+        let mut config = config();
+        config
+            .tables
+            .insert("payment_intent".to_owned(), ReadPreference::Master);
+
+        assert_eq!(
+            config.get_preference(Some("payment_intent")),
+            ReadPreference::Master
+        );
+        assert_eq!(
+            config.get_preference(Some("payment_methods")),
+            ReadPreference::Consistent
+        );
+
+        let decision = route_read(
+            &config,
+            config.get_preference(Some("payment_intent")),
+            LAG,
+            None,
+            Instant::now(),
+        );
+        assert_eq!(decision, RoutingDecision::MasterPreferred);
+    }
+
+    #[test]
+    fn test_payment_tables_and_unspecified_tables_use_master_by_default() {
+        let mut config = config();
+
+        assert_eq!(config.get_preference(None), ReadPreference::Master);
+        assert_eq!(
+            config.get_preference(Some("payment_attempt")),
+            ReadPreference::Master
+        );
+
+        // Payment tables may be read from the replica if configured explicitly
+        config
+            .tables
+            .insert("refund".to_owned(), ReadPreference::Consistent);
+        assert_eq!(
+            config.get_preference(Some("refund")),
+            ReadPreference::Consistent
+        );
+    }
+
+    #[test]
+    fn test_expired_consistency_tokens_are_evicted() {
+        let consistency_tokens = ConsistencyTokens::default();
+        let merchant_1 =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_1")).unwrap();
+        let merchant_2 =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_2")).unwrap();
+        let merchant_3 =
+            common_utils::id_type::MerchantId::try_from(Cow::from("merchant_3")).unwrap();
+        let now = Instant::now();
+
+        consistency_tokens.get_at(&merchant_1, now).record_write();
+        // A token still held by a request is retained, however old its last write is
+        let held_token = consistency_tokens.get_at(&merchant_2, now);
+        held_token.record_write();
+        assert_eq!(consistency_tokens.len(), 2);
+
+        let later = now + CONSISTENCY_TOKEN_RETENTION + Duration::from_secs(1);
+        let token = consistency_tokens.get_at(&merchant_3, later);
+        assert_eq!(consistency_tokens.len(), 2);
+        assert!(token.last_write_at().is_none());
+        assert!(consistency_tokens
+            .get_at(&merchant_2, later)
+            .last_write_at()
+            .is_some());
+        // Tokens of merchants whose writes were evicted start afresh
+        assert!(consistency_tokens
+            .get_at(&merchant_1, later)
+            .last_write_at()
+            .is_none());
+    }
+}
@@ -1,3 +1,8 @@
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use async_bb8_diesel::{AsyncConnection, ConnectionError};
 use bb8::CustomizeConnection;
 use common_utils::DbConnectionParams;
@@ -5,7 +10,10 @@ use diesel::PgConnection;
 use error_stack::ResultExt;
 use hyperswitch_domain_models::errors::{StorageError, StorageResult};
 
-use crate::config::{Database, TenantConfig};
+use crate::{
+    config::{Database, ReadPreference, ReplicaReadConfig, TenantConfig},
+    database::routing::{self, ConsistencyToken, ReplicaLag, RoutingDecision},
+};
 
 pub type PgPool = bb8::Pool<async_bb8_diesel::ConnectionManager<PgConnection>>;
 pub type PgPooledConn = async_bb8_diesel::Connection<PgConnection>;
@@ -20,6 +28,21 @@ pub trait DatabaseStore: Clone + Send + Sync {
     ) -> StorageResult<Self>;
     fn get_master_pool(&self) -> &PgPool;
     fn get_replica_pool(&self) -> &PgPool;
+
+    /// Picks the database to read `table` from, given the time of the last write made by the
+    /// request. Reads use the master database unless the store routes reads to a replica.
+    async fn route_read(
+        &self,
+        _table: Option<&str>,
+        _last_write_at: Option<Instant>,
+    ) -> RoutingDecision {
+        RoutingDecision::RoutingDisabled
+    }
+
+    /// The consistency token of the request served by the store, if the store tracks writes
+    fn get_consistency_token(&self) -> Option<&ConsistencyToken> {
+        None
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -54,17 +77,19 @@ impl DatabaseStore for Store {
 pub struct ReplicaStore {
     pub master_pool: PgPool,
     pub replica_pool: PgPool,
+    pub read_config: ReplicaReadConfig,
+    replica_lag: Arc<ReplicaLag>,
 }
 
 #[async_trait::async_trait]
 impl DatabaseStore for ReplicaStore {
-    type Config = (Database, Database);
+    type Config = (Database, Database, ReplicaReadConfig);
     async fn new(
-        config: (Database, Database),
+        config: (Database, Database, ReplicaReadConfig),
         tenant_config: &dyn TenantConfig,
         test_transaction: bool,
     ) -> StorageResult<Self> {
-        let (master_config, replica_config) = config;
+        let (master_config, replica_config, read_config) = config;
         let master_pool =
             diesel_make_pg_pool(&master_config, tenant_config.get_schema(), test_transaction)
                 .await
@@ -79,6 +104,8 @@ impl DatabaseStore for ReplicaStore {
         Ok(Self {
             master_pool,
             replica_pool,
+            read_config,
+            replica_lag: Arc::new(ReplicaLag::default()),
         })
     }
 
@@ -89,6 +116,33 @@ impl DatabaseStore for ReplicaStore {
     fn get_replica_pool(&self) -> &PgPool {
         &self.replica_pool
     }
+
+    async fn route_read(
+        &self,
+        table: Option<&str>,
+        last_write_at: Option<Instant>,
+    ) -> RoutingDecision {
+        let preference = self.read_config.get_preference(table);
+        // The lag is only measured when reads may be routed to the replica
+        let replica_lag = if self.read_config.enabled && preference != ReadPreference::Master {
+            self.replica_lag
+                .get(
+                    &self.replica_pool,
+                    Duration::from_secs(self.read_config.lag_probe_interval),
+                )
+                .await
+        } else {
+            None
+        };
+
+        routing::route_read(
+            &self.read_config,
+            preference,
+            replica_lag,
+            last_write_at,
+            Instant::now(),
+        )
+    }
 }
 
 pub async fn diesel_make_pg_pool(
@@ -10,6 +10,7 @@ pub mod config;
 pub mod connection;
 pub mod customers;
 pub mod database;
+pub mod dispute;
 pub mod errors;
 mod lookup;
 pub mod mandate;
@@ -25,7 +26,10 @@ mod reverse_lookup;
 mod utils;
 
 use common_utils::errors::CustomResult;
-use database::store::PgPool;
+use database::{
+    routing::{ConsistencyToken, ConsistencyTokens, RoutingDecision},
+    store::PgPool,
+};
 #[cfg(not(feature = "payouts"))]
 use hyperswitch_domain_models::{PayoutAttemptInterface, PayoutsInterface};
 pub use mock_db::MockDb;
@@ -42,6 +46,8 @@ pub struct RouterStore<T: DatabaseStore> {
     cache_store: Arc<RedisStore>,
     master_encryption_key: StrongSecret<Vec<u8>>,
     pub request_id: Option<String>,
+    consistency_tokens: Arc<ConsistencyTokens>,
+    consistency_token: Option<ConsistencyToken>,
 }
 
 #[async_trait::async_trait]
@@ -85,6 +91,16 @@ where
     fn get_replica_pool(&self) -> &PgPool {
         self.db_store.get_replica_pool()
     }
+    async fn route_read(
+        &self,
+        table: Option<&str>,
+        last_write_at: Option<std::time::Instant>,
+    ) -> RoutingDecision {
+        self.db_store.route_read(table, last_write_at).await
+    }
+    fn get_consistency_token(&self) -> Option<&ConsistencyToken> {
+        self.consistency_token.as_ref()
+    }
 }
 
 impl<T: DatabaseStore> RedisConnInterface for RouterStore<T> {
@@ -121,6 +137,8 @@ impl<T: DatabaseStore> RouterStore<T> {
             cache_store,
             master_encryption_key: encryption_key,
             request_id: None,
+            consistency_tokens: Arc::new(ConsistencyTokens::default()),
+            consistency_token: Some(ConsistencyToken::default()),
         })
     }
 
@@ -140,6 +158,18 @@ impl<T: DatabaseStore> RouterStore<T> {
         &self.master_encryption_key
     }
 
+    /// Starts tracking the writes of a new request, so that the reads of a request made on behalf
+    /// of no merchant are only affected by its own writes
+    pub fn reset_consistency_token(&mut self) {
+        self.consistency_token = Some(ConsistencyToken::default());
+    }
+
+    /// Tracks the writes of the request for the merchant, so that the reads of the request are
+    /// affected by the writes made for the merchant by any request
+    pub fn set_consistency_token(&mut self, merchant_id: &common_utils::id_type::MerchantId) {
+        self.consistency_token = Some(self.consistency_tokens.get(merchant_id));
+    }
+
     /// # Panics
     ///
     /// Will panic if `CONNECTOR_AUTH_FILE_PATH` is not set
@@ -160,6 +190,8 @@ impl<T: DatabaseStore> RouterStore<T> {
             cache_store: Arc::new(cache_store),
             master_encryption_key: encryption_key,
             request_id: None,
+            consistency_tokens: Arc::new(ConsistencyTokens::default()),
+            consistency_token: Some(ConsistencyToken::default()),
         })
     }
 }
@@ -202,6 +234,16 @@ where
     fn get_replica_pool(&self) -> &PgPool {
         self.router_store.get_replica_pool()
     }
+    async fn route_read(
+        &self,
+        table: Option<&str>,
+        last_write_at: Option<std::time::Instant>,
+    ) -> RoutingDecision {
+        self.router_store.route_read(table, last_write_at).await
+    }
+    fn get_consistency_token(&self) -> Option<&ConsistencyToken> {
+        self.router_store.get_consistency_token()
+    }
 }
 
 impl<T: DatabaseStore> RedisConnInterface for KVRouterStore<T> {
@@ -234,10 +276,71 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         self.router_store.master_key()
     }
 
+    /// Starts tracking the writes of a new request, so that the reads of a request made on behalf
+    /// of no merchant are only affected by its own writes
+    pub fn reset_consistency_token(&mut self) {
+        self.router_store.reset_consistency_token()
+    }
+
+    /// Tracks the writes of the request for the merchant, so that the reads of the request are
+    /// affected by the writes made for the merchant by any request
+    pub fn set_consistency_token(&mut self, merchant_id: &common_utils::id_type::MerchantId) {
+        self.router_store.set_consistency_token(merchant_id)
+    }
+
     pub fn get_drainer_stream_name(&self, shard_key: &str) -> String {
         format!("{{{}}}_{}", shard_key, self.drainer_stream_name)
     }
 
+    /// Fetches the partition map of the drainer streams. The map is cached in memory, and is read
+    /// again whenever the version of the current layout stored by the drainer differs from that
+    /// of the cached map, so that entries are not pushed to a layout the drainer has moved on
+    /// from. The configured number of partitions applies until the drainer stores the map.
+    pub async fn get_partition_map(
+        &self,
+    ) -> error_stack::Result<diesel_models::kv::partition::PartitionMap, RedisError> {
+        let redis_conn = &self.router_store.cache_store.redis_conn;
+        let cache_key = redis::cache::CacheKey {
+            key: diesel_models::kv::partition::PARTITION_MAP_KEY.to_string(),
+            prefix: redis_conn.key_prefix.clone(),
+        };
+        let live_version = redis_conn
+            .get_key::<Option<u32>>(diesel_models::kv::partition::PARTITION_MAP_VERSION_KEY)
+            .await?;
+        if let Some(partition_map) = redis::cache::PARTITION_MAP_CACHE
+            .get_val::<diesel_models::kv::partition::PartitionMap>(cache_key.clone())
+            .await
+        {
+            if !partition_map.is_stale(live_version) {
+                return Ok(partition_map);
+            }
+            logger::info!(
+                cached_version = partition_map.current.version,
+                ?live_version,
+                "Partition map changed, reading it again"
+            );
+        }
+
+        let partition_map = match redis_conn
+            .get_and_deserialize_key::<diesel_models::kv::partition::PartitionMap>(
+                diesel_models::kv::partition::PARTITION_MAP_KEY,
+                "PartitionMap",
+            )
+            .await
+        {
+            Ok(partition_map) => partition_map,
+            Err(error) if matches!(error.current_context(), RedisError::NotFound) => {
+                diesel_models::kv::partition::PartitionMap::new(self.drainer_num_partitions)
+            }
+            Err(error) => return Err(error),
+        };
+        redis::cache::PARTITION_MAP_CACHE
+            .push(cache_key, partition_map.clone())
+            .await;
+
+        Ok(partition_map)
+    }
+
     pub async fn push_to_drainer_stream<R>(
         &self,
         redis_entry: diesel_models::kv::TypedSql,
@@ -249,7 +352,8 @@ impl<T: DatabaseStore> KVRouterStore<T> {
         let global_id = format!("{}", partition_key);
         let request_id = self.request_id.clone().unwrap_or_default();
 
-        let shard_key = R::shard_key(partition_key, self.drainer_num_partitions);
+        let partition_map = self.get_partition_map().await?;
+        let shard_key = R::shard_key(partition_key, &partition_map.current);
         let stream_name = self.get_drainer_stream_name(&shard_key);
         self.router_store
             .cache_store
@@ -258,7 +362,7 @@ impl<T: DatabaseStore> KVRouterStore<T> {
                 &stream_name,
                 &redis_interface::RedisEntryId::AutoGeneratedID,
                 redis_entry
-                    .to_field_value_pairs(request_id, global_id)
+                    .to_field_value_pairs(request_id, global_id, partition_map.current.version)
                     .change_context(RedisError::JsonSerializationFailed)?,
             )
             .await
@@ -428,6 +532,54 @@ impl UniqueConstraints for diesel_models::Mandate {
     }
 }
 
+impl UniqueConstraints for diesel_models::Dispute {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "dispute_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.dispute_id
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "Dispute"
+    }
+}
+
+impl UniqueConstraints for diesel_models::capture::Capture {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!("capture_{}", self.capture_id)]
+    }
+    fn table_name(&self) -> &str {
+        "Capture"
+    }
+}
+
+impl UniqueConstraints for diesel_models::authentication::Authentication {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "authentication_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.authentication_id
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "Authentication"
+    }
+}
+
+impl UniqueConstraints for diesel_models::fraud_check::FraudCheck {
+    fn unique_constraints(&self) -> Vec<String> {
+        vec![format!(
+            "frm_{}_{}",
+            self.merchant_id.get_string_repr(),
+            self.attempt_id
+        )]
+    }
+    fn table_name(&self) -> &str {
+        "FraudCheck"
+    }
+}
+
 #[cfg(all(any(feature = "v1", feature = "v2"), not(feature = "customer_v2")))]
 impl UniqueConstraints for diesel_models::Customer {
     fn unique_constraints(&self) -> Vec<String> {
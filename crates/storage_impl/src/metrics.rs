@@ -17,3 +17,16 @@ gauge_metric!(IN_MEMORY_CACHE_ENTRY_COUNT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_HIT, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_MISS, GLOBAL_METER);
 counter_metric!(IN_MEMORY_CACHE_EVICTION_COUNT, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_COALESCED, GLOBAL_METER); // No. of lookups served by a concurrent population
+counter_metric!(IN_MEMORY_CACHE_STALE_SERVED, GLOBAL_METER);
+counter_metric!(IN_MEMORY_CACHE_EARLY_REFRESH, GLOBAL_METER);
+
+// Metrics for Redis cache
+counter_metric!(REDIS_CACHE_HIT, GLOBAL_METER);
+counter_metric!(REDIS_CACHE_MISS, GLOBAL_METER);
+counter_metric!(REDIS_CACHE_COALESCED, GLOBAL_METER);
+
+// Metrics for read routing
+gauge_metric!(REPLICA_LAG, GLOBAL_METER); // Replica lag in milliseconds
+counter_metric!(REPLICA_LAG_PROBE_FAILED, GLOBAL_METER);
+counter_metric!(DATABASE_READS_ROUTED, GLOBAL_METER);
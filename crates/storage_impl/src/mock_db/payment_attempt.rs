@@ -90,6 +90,28 @@ impl PaymentAttemptInterface for MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn find_attempts_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+        _storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, StorageError> {
+        let payment_attempts = self.payment_attempts.lock().await;
+        let limit = limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(payment_attempts
+            .iter()
+            .filter(|payment_attempt| {
+                payment_attempt.merchant_id == *merchant_id
+                    && payment_attempt.fingerprint_id.as_deref() == Some(fingerprint_id)
+            })
+            .take(limit)
+            .cloned()
+            .collect())
+    }
+
     #[allow(clippy::panic)]
     async fn insert_payment_attempt(
         &self,
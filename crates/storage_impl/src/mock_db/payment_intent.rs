@@ -71,6 +71,40 @@ impl PaymentIntentInterface for MockDb {
         // [#172]: Implement function for `MockDb`
         Err(StorageError::MockDbError)?
     }
+    #[cfg(feature = "olap")]
+    async fn find_payment_intents_with_expiring_authorization(
+        &self,
+        _state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        expiring_before: time::PrimitiveDateTime,
+        limit: u32,
+        _key_store: &MerchantKeyStore,
+    ) -> CustomResult<Vec<PaymentIntent>, StorageError> {
+        let payment_intents = self.payment_intents.lock().await;
+
+        let mut expiring_payment_intents = payment_intents
+            .iter()
+            .filter(|payment_intent| {
+                payment_intent.merchant_id == *merchant_id
+                    && (profile_id.is_none() || payment_intent.profile_id.as_ref() == profile_id)
+                    && matches!(
+                        payment_intent.status,
+                        storage_enums::IntentStatus::RequiresCapture
+                            | storage_enums::IntentStatus::PartiallyCapturedAndCapturable
+                    )
+                    && payment_intent
+                        .authorization_expires_at
+                        .is_some_and(|expires_at| expires_at <= expiring_before)
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        expiring_payment_intents
+            .sort_by_key(|payment_intent| payment_intent.authorization_expires_at);
+        expiring_payment_intents.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+
+        Ok(expiring_payment_intents)
+    }
 
     #[allow(clippy::panic)]
     async fn insert_payment_intent(
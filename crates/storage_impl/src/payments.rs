@@ -1,9 +1,15 @@
 pub mod payment_attempt;
 pub mod payment_intent;
 
-use diesel_models::{payment_attempt::PaymentAttempt, PaymentIntent};
+use diesel_models::{
+    authentication::Authentication, capture::Capture, fraud_check::FraudCheck,
+    payment_attempt::PaymentAttempt, PaymentIntent,
+};
 
 use crate::redis::kv_store::KvStorePartition;
 
 impl KvStorePartition for PaymentIntent {}
 impl KvStorePartition for PaymentAttempt {}
+impl KvStorePartition for Capture {}
+impl KvStorePartition for Authentication {}
+impl KvStorePartition for FraudCheck {}
@@ -33,7 +33,7 @@ use crate::{
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
-    utils::{pg_connection_read, pg_connection_write, try_redis_get_else_try_database_get},
+    utils::{pg_connection_read_table, pg_connection_write, try_redis_get_else_try_database_get},
     DataModelExt, DatabaseStore, KVRouterStore, RouterStore,
 };
 
@@ -83,7 +83,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
         DieselPaymentAttempt::find_by_connector_transaction_id_payment_id_merchant_id(
             &conn,
             connector_transaction_id,
@@ -105,7 +105,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
         DieselPaymentAttempt::find_last_successful_attempt_by_payment_id_merchant_id(
             &conn,
             payment_id,
@@ -126,7 +126,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
         DieselPaymentAttempt::find_last_successful_or_partially_captured_attempt_by_payment_id_merchant_id(
             &conn,
             payment_id,
@@ -147,7 +147,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         connector_txn_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
         DieselPaymentAttempt::find_by_merchant_id_connector_txn_id(
             &conn,
             merchant_id,
@@ -169,7 +169,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         attempt_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
 
         DieselPaymentAttempt::find_by_payment_id_merchant_id_attempt_id(
             &conn,
@@ -185,6 +185,33 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         .map(PaymentAttempt::from_storage_model)
     }
 
+    #[instrument(skip_all)]
+    async fn find_attempts_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
+        DieselPaymentAttempt::find_by_merchant_id_fingerprint_id(
+            &conn,
+            merchant_id,
+            fingerprint_id,
+            limit,
+        )
+        .await
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+        .map(|a| {
+            a.into_iter()
+                .map(PaymentAttempt::from_storage_model)
+                .collect()
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payments(
         &self,
@@ -192,7 +219,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentListFilters, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
         let intents = futures::future::try_join_all(pi.iter().cloned().map(|pi| async {
             pi.convert()
                 .await
@@ -232,7 +259,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
 
         DieselPaymentAttempt::find_by_merchant_id_preprocessing_id(
             &conn,
@@ -254,7 +281,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         payment_id: &common_utils::id_type::PaymentId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<Vec<PaymentAttempt>, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
         DieselPaymentAttempt::find_by_merchant_id_payment_id(&conn, merchant_id, payment_id)
             .await
             .map_err(|er| {
@@ -275,7 +302,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PaymentAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_attempt").await?;
 
         DieselPaymentAttempt::find_by_merchant_id_attempt_id(&conn, merchant_id, attempt_id)
             .await
@@ -517,6 +544,7 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                             kv::PaymentAttemptUpdateMems {
                                 orig: this.clone().to_storage_model(),
                                 update_data: payment_attempt.to_storage_model(),
+                                coalesced_updates: Vec::new(),
                             },
                         ),
                     },
@@ -1045,6 +1073,25 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
         }
     }
 
+    // Merchant wide scans are served from the database alone
+    #[instrument(skip_all)]
+    async fn find_attempts_by_merchant_id_fingerprint_id(
+        &self,
+        merchant_id: &common_utils::id_type::MerchantId,
+        fingerprint_id: &str,
+        limit: Option<i64>,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<Vec<PaymentAttempt>, errors::StorageError> {
+        self.router_store
+            .find_attempts_by_merchant_id_fingerprint_id(
+                merchant_id,
+                fingerprint_id,
+                limit,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[instrument(skip_all)]
     async fn get_filters_for_payments(
         &self,
@@ -1969,6 +2016,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
             },
+            Self::CustomerDataRedactionUpdate {
+                payment_method_data,
+                browser_info,
+                updated_by,
+            } => DieselPaymentAttemptUpdate::CustomerDataRedactionUpdate {
+                payment_method_data,
+                browser_info,
+                updated_by,
+            },
         }
     }
 
@@ -2321,6 +2377,15 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_message,
                 connector_transaction_id,
             },
+            DieselPaymentAttemptUpdate::CustomerDataRedactionUpdate {
+                payment_method_data,
+                browser_info,
+                updated_by,
+            } => Self::CustomerDataRedactionUpdate {
+                payment_method_data,
+                browser_info,
+                updated_by,
+            },
         }
     }
 }
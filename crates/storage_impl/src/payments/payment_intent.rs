@@ -59,7 +59,7 @@ use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
-    utils::{self, pg_connection_read, pg_connection_write},
+    utils::{self, pg_connection_read_table, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
 
@@ -196,6 +196,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
                             kv::PaymentIntentUpdateMems {
                                 orig: origin_diesel_intent,
                                 update_data: diesel_intent_update,
+                                coalesced_updates: Vec::new(),
                             },
                         ),
                     },
@@ -235,7 +236,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, StorageError> {
         let database_call = || async {
-            let conn = pg_connection_read(self).await?;
+            let conn = pg_connection_read_table(self, "payment_intent").await?;
             DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
                 .await
                 .map_err(|er| {
@@ -287,7 +288,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
     ) -> error_stack::Result<PaymentAttempt, StorageError> {
         match payment.active_attempt.clone() {
             RemoteStorageObject::ForeignID(attempt_id) => {
-                let conn = pg_connection_read(self).await?;
+                let conn = pg_connection_read_table(self, "payment_intent").await?;
 
                 let pa = DieselPaymentAttempt::find_by_merchant_id_attempt_id(
                     &conn,
@@ -392,6 +393,28 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
             )
             .await
     }
+
+    #[cfg(feature = "olap")]
+    async fn find_payment_intents_with_expiring_authorization(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        expiring_before: time::PrimitiveDateTime,
+        limit: u32,
+        merchant_key_store: &MerchantKeyStore,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        self.router_store
+            .find_payment_intents_with_expiring_authorization(
+                state,
+                merchant_id,
+                profile_id,
+                expiring_before,
+                limit,
+                merchant_key_store,
+            )
+            .await
+    }
 }
 
 #[async_trait::async_trait]
@@ -468,7 +491,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         merchant_key_store: &MerchantKeyStore,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payment_intent").await?;
 
         DieselPaymentIntent::find_by_payment_id_merchant_id(&conn, payment_id, merchant_id)
             .await
@@ -497,7 +520,7 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<PaymentAttempt, StorageError> {
         match &payment.active_attempt {
             RemoteStorageObject::ForeignID(attempt_id) => {
-                let conn = pg_connection_read(self).await?;
+                let conn = pg_connection_read_table(self, "payment_intent").await?;
 
                 let pa = DieselPaymentAttempt::find_by_merchant_id_attempt_id(
                     &conn,
@@ -530,7 +553,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         use common_utils::errors::ReportSwitchExt;
         use futures::{future::try_join_all, FutureExt};
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payment_intent")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
@@ -672,7 +697,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         time_range: &api_models::payments::TimeRange,
     ) -> error_stack::Result<Vec<(common_enums::IntentStatus, i64)>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payment_intent")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         let mut query = <DieselPaymentIntent as HasTable>::table()
@@ -716,7 +743,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
     ) -> error_stack::Result<Vec<(PaymentIntent, PaymentAttempt)>, StorageError> {
         use futures::{future::try_join_all, FutureExt};
 
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payment_intent")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .inner_join(
@@ -905,7 +934,9 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
         constraints: &PaymentIntentFetchConstraints,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payment_intent")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPaymentIntent::table()
             .select(pi_dsl::active_attempt_id)
@@ -978,4 +1009,64 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             .into()
         })
     }
+
+    #[cfg(feature = "olap")]
+    #[instrument(skip_all)]
+    async fn find_payment_intents_with_expiring_authorization(
+        &self,
+        state: &KeyManagerState,
+        merchant_id: &common_utils::id_type::MerchantId,
+        profile_id: Option<&common_utils::id_type::ProfileId>,
+        expiring_before: time::PrimitiveDateTime,
+        limit: u32,
+        merchant_key_store: &MerchantKeyStore,
+    ) -> error_stack::Result<Vec<PaymentIntent>, StorageError> {
+        use futures::future::try_join_all;
+
+        let conn = connection::pg_connection_read_table(self, "payment_intent")
+            .await
+            .switch()?;
+        let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
+
+        let mut query = <DieselPaymentIntent as HasTable>::table()
+            .filter(pi_dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(pi_dsl::status.eq_any(vec![
+                common_enums::IntentStatus::RequiresCapture,
+                common_enums::IntentStatus::PartiallyCapturedAndCapturable,
+            ]))
+            .filter(pi_dsl::authorization_expires_at.le(expiring_before))
+            .order(pi_dsl::authorization_expires_at.asc())
+            .limit(limit.into())
+            .into_boxed();
+
+        if let Some(profile_id) = profile_id {
+            query = query.filter(pi_dsl::profile_id.eq(profile_id.to_owned()));
+        }
+
+        logger::debug!(query = %diesel::debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+        let payment_intents =
+            db_metrics::track_database_call::<<DieselPaymentIntent as HasTable>::Table, _, _>(
+                query.get_results_async::<DieselPaymentIntent>(conn),
+                db_metrics::DatabaseOperation::Filter,
+            )
+            .await
+            .map_err(|er| {
+                StorageError::DatabaseError(
+                    error_stack::report!(diesel_models::errors::DatabaseError::from(er))
+                        .attach_printable("Error filtering payments with expiring authorization"),
+                )
+            })?;
+
+        try_join_all(payment_intents.into_iter().map(|diesel_payment_intent| {
+            PaymentIntent::convert_back(
+                state,
+                diesel_payment_intent,
+                merchant_key_store.key.get_inner(),
+                merchant_key_store.merchant_id.clone().into(),
+            )
+        }))
+        .await
+        .change_context(StorageError::DecryptionError)
+    }
 }
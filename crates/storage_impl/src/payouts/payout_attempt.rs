@@ -31,7 +31,7 @@ use crate::{
     errors::RedisErrorExt,
     lookup::ReverseLookupInterface,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
-    utils::{self, pg_connection_read, pg_connection_write},
+    utils::{self, pg_connection_read_table, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
 
@@ -415,7 +415,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         payout_attempt_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payout_attempt").await?;
         DieselPayoutAttempt::find_by_merchant_id_payout_attempt_id(
             &conn,
             merchant_id,
@@ -436,7 +436,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         connector_payout_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<PayoutAttempt, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payout_attempt").await?;
         DieselPayoutAttempt::find_by_merchant_id_connector_payout_id(
             &conn,
             merchant_id,
@@ -457,7 +457,7 @@ impl<T: DatabaseStore> PayoutAttemptInterface for crate::RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         _storage_scheme: MerchantStorageScheme,
     ) -> CustomResult<PayoutListFilters, errors::StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payout_attempt").await?;
         let payouts = payouts
             .iter()
             .cloned()
@@ -64,7 +64,7 @@ use crate::{
     diesel_error_to_data_error,
     errors::RedisErrorExt,
     redis::kv_store::{decide_storage_scheme, kv_wrapper, KvOperation, Op, PartitionKey},
-    utils::{self, pg_connection_read, pg_connection_write},
+    utils::{self, pg_connection_read_table, pg_connection_write},
     DataModelExt, DatabaseStore, KVRouterStore,
 };
 
@@ -116,6 +116,8 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                     payout_link_id: new.payout_link_id.clone(),
                     client_secret: new.client_secret.clone(),
                     priority: new.priority,
+                    scheduled_at: new.scheduled_at,
+                    recurrence: new.recurrence.clone(),
                 };
 
                 let redis_entry = kv::TypedSql {
@@ -194,6 +196,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
                         updatable: kv::Updateable::PayoutsUpdate(kv::PayoutsUpdateMems {
                             orig: origin_diesel_payout,
                             update_data: diesel_payout_update,
+                            coalesced_updates: Vec::new(),
                         }),
                     },
                 };
@@ -221,7 +224,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Payouts, StorageError> {
         let database_call = || async {
-            let conn = pg_connection_read(self).await?;
+            let conn = pg_connection_read_table(self, "payouts").await?;
             DieselPayouts::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
                 .await
                 .map_err(|er| {
@@ -265,7 +268,7 @@ impl<T: DatabaseStore> PayoutsInterface for KVRouterStore<T> {
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Option<Payouts>, StorageError> {
         let database_call = || async {
-            let conn = pg_connection_read(self).await?;
+            let conn = pg_connection_read_table(self, "payouts").await?;
             DieselPayouts::find_optional_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
                 .await
                 .map_err(|er| {
@@ -431,7 +434,7 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         payout_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Payouts, StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payouts").await?;
         DieselPayouts::find_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
             .await
             .map(Payouts::from_storage_model)
@@ -448,7 +451,7 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         payout_id: &str,
         _storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Option<Payouts>, StorageError> {
-        let conn = pg_connection_read(self).await?;
+        let conn = pg_connection_read_table(self, "payouts").await?;
         DieselPayouts::find_optional_by_merchant_id_payout_id(&conn, merchant_id, payout_id)
             .await
             .map(|x| x.map(Payouts::from_storage_model))
@@ -466,7 +469,9 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         filters: &PayoutFetchConstraints,
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<Payouts>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payouts")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
 
         //[#350]: Replace this with Boxable Expression and pass it into generic filter
@@ -573,7 +578,9 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         storage_scheme: MerchantStorageScheme,
     ) -> error_stack::Result<Vec<(Payouts, PayoutAttempt, Option<DieselCustomer>)>, StorageError>
     {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payouts")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPayouts::table()
             .inner_join(
@@ -773,7 +780,9 @@ impl<T: DatabaseStore> PayoutsInterface for crate::RouterStore<T> {
         merchant_id: &common_utils::id_type::MerchantId,
         constraints: &PayoutFetchConstraints,
     ) -> error_stack::Result<Vec<String>, StorageError> {
-        let conn = connection::pg_connection_read(self).await.switch()?;
+        let conn = connection::pg_connection_read_table(self, "payouts")
+            .await
+            .switch()?;
         let conn = async_bb8_diesel::Connection::as_async_conn(&conn);
         let mut query = DieselPayouts::table()
             .inner_join(
@@ -883,6 +892,8 @@ impl DataModelExt for Payouts {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            scheduled_at: self.scheduled_at,
+            recurrence: self.recurrence,
         }
     }
 
@@ -912,6 +923,8 @@ impl DataModelExt for Payouts {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            scheduled_at: storage_model.scheduled_at,
+            recurrence: storage_model.recurrence,
         }
     }
 }
@@ -944,6 +957,8 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: self.payout_link_id,
             client_secret: self.client_secret,
             priority: self.priority,
+            scheduled_at: self.scheduled_at,
+            recurrence: self.recurrence,
         }
     }
 
@@ -973,6 +988,8 @@ impl DataModelExt for PayoutsNew {
             payout_link_id: storage_model.payout_link_id,
             client_secret: storage_model.client_secret,
             priority: storage_model.priority,
+            scheduled_at: storage_model.scheduled_at,
+            recurrence: storage_model.recurrence,
         }
     }
 }
@@ -1,4 +1,11 @@
-use std::{any::Any, borrow::Cow, fmt::Debug, sync::Arc};
+use std::{
+    any::Any,
+    borrow::Cow,
+    collections::HashMap,
+    fmt::Debug,
+    sync::{Arc, PoisonError},
+    time::{Duration, Instant},
+};
 
 use common_utils::{
     errors::{self, CustomResult},
@@ -6,11 +13,14 @@ use common_utils::{
 };
 use dyn_clone::DynClone;
 use error_stack::{Report, ResultExt};
+use futures::lock::{Mutex as AsyncMutex, OwnedMutexGuard};
 use moka::future::Cache as MokaCache;
 use once_cell::sync::Lazy;
 use redis_interface::{errors::RedisError, RedisConnectionPool, RedisValue};
 use router_env::{
+    logger,
     metrics::add_attributes,
+    opentelemetry::metrics::Counter,
     tracing::{self, instrument},
 };
 
@@ -32,13 +42,44 @@ const CACHE_TTI: u64 = 10 * 60;
 /// Max Capacity of Cache in MB
 const MAX_CAPACITY: u64 = 30;
 
+/// Time for which expired entries of frequently read caches are served while being refreshed, 1 min
+const CACHE_STALE_WHILE_REVALIDATE: u64 = 60;
+
+/// Refresh entries of frequently read caches ahead of expiry, proportional to their compute time
+const CACHE_EARLY_REFRESH_BETA: f64 = 1.0;
+
 /// Config Cache with time_to_live as 30 mins and time_to_idle as 10 mins.
-pub static CONFIG_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("CONFIG_CACHE", CACHE_TTL, CACHE_TTI, None));
+/// Expired entries are served for a minute while being refreshed.
+pub static CONFIG_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new_with_revalidation(
+        "CONFIG_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        None,
+        RevalidationPolicy {
+            stale_while_revalidate: CACHE_STALE_WHILE_REVALIDATE,
+            early_refresh_beta: Some(CACHE_EARLY_REFRESH_BETA),
+        },
+    )
+});
+
+/// Accounts cache with time_to_live as 30 mins and size limit.
+/// Expired entries are served for a minute while being refreshed.
+pub static ACCOUNTS_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new_with_revalidation(
+        "ACCOUNTS_CACHE",
+        CACHE_TTL,
+        CACHE_TTI,
+        Some(MAX_CAPACITY),
+        RevalidationPolicy {
+            stale_while_revalidate: CACHE_STALE_WHILE_REVALIDATE,
+            early_refresh_beta: Some(CACHE_EARLY_REFRESH_BETA),
+        },
+    )
+});
 
-/// Accounts cache with time_to_live as 30 mins and size limit
-pub static ACCOUNTS_CACHE: Lazy<Cache> =
-    Lazy::new(|| Cache::new("ACCOUNTS_CACHE", CACHE_TTL, CACHE_TTI, Some(MAX_CAPACITY)));
+/// Populations of keys in redis which are in progress in this process
+static REDIS_POPULATIONS: Lazy<Populations> = Lazy::new(Populations::default);
 
 /// Routing Cache
 pub static ROUTING_CACHE: Lazy<Cache> =
@@ -72,6 +113,16 @@ pub static PM_FILTERS_CGRAPH_CACHE: Lazy<Cache> = Lazy::new(|| {
     )
 });
 
+/// Partition map cache, whose entries are replaced once the version of the partition map changes
+pub static PARTITION_MAP_CACHE: Lazy<Cache> = Lazy::new(|| {
+    Cache::new(
+        "PARTITION_MAP_CACHE",
+        diesel_models::kv::partition::PARTITION_MAP_CACHE_TTL_IN_SECS,
+        diesel_models::kv::partition::PARTITION_MAP_CACHE_TTL_IN_SECS,
+        None,
+    )
+});
+
 /// Trait which defines the behaviour of types that's gonna be stored in Cache
 pub trait Cacheable: Any + Send + Sync + DynClone {
     fn as_any(&self) -> &dyn Any;
@@ -135,7 +186,123 @@ dyn_clone::clone_trait_object!(Cacheable);
 
 pub struct Cache {
     name: &'static str,
-    inner: MokaCache<String, Arc<dyn Cacheable>>,
+    inner: MokaCache<String, CacheEntry>,
+    time_to_live: Duration,
+    revalidation: Option<RevalidationPolicy>,
+    populations: Populations,
+}
+
+/// Determines how the entries of a cache are refreshed around their expiry
+#[derive(Debug, Clone, Copy)]
+pub struct RevalidationPolicy {
+    /// Time in seconds after expiry for which an entry is served while one lookup refreshes it.
+    /// The entry is deleted once this time elapses.
+    pub stale_while_revalidate: u64,
+    /// Refreshes entries ahead of expiry with a probability that increases as the expiry
+    /// approaches and with the time taken to compute the entry, higher values refresh earlier.
+    /// Early refresh is disabled if not provided.
+    pub early_refresh_beta: Option<f64>,
+}
+
+#[derive(Clone)]
+struct CacheEntry {
+    value: Arc<dyn Cacheable>,
+    inserted_at: Instant,
+    /// Time taken to compute the value, used to refresh expensive values earlier
+    compute_time: Duration,
+}
+
+impl CacheEntry {
+    fn new(value: Arc<dyn Cacheable>, compute_time: Duration) -> Self {
+        Self {
+            value,
+            inserted_at: Instant::now(),
+            compute_time,
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Freshness {
+    Fresh,
+    /// The entry is yet to expire, but is picked to be refreshed ahead of its expiry
+    RefreshAhead,
+    /// The entry has expired, and is within the stale-while-revalidate window
+    Stale,
+}
+
+/// Coalesces concurrent populations of a key, so that the value is computed once while the other
+/// lookups of the key wait for it
+#[derive(Default)]
+struct Populations {
+    in_flight: std::sync::Mutex<HashMap<String, Arc<AsyncMutex<()>>>>,
+}
+
+impl Populations {
+    fn lock_for(&self, key: &str) -> Arc<AsyncMutex<()>> {
+        self.in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .entry(key.to_owned())
+            .or_default()
+            .clone()
+    }
+
+    /// Waits for the population of the key in progress, if any, and starts a new one
+    async fn lock(&self, key: &str) -> PopulationGuard<'_> {
+        let lock = self.lock_for(key);
+        let (guard, waited) = match lock.try_lock_owned() {
+            Some(guard) => (guard, false),
+            None => (lock.lock_owned().await, true),
+        };
+        PopulationGuard {
+            populations: self,
+            key: key.to_owned(),
+            guard: Some(guard),
+            waited,
+        }
+    }
+
+    /// Starts a population of the key, unless one is in progress already
+    fn try_lock(&self, key: &str) -> Option<PopulationGuard<'_>> {
+        let guard = self.lock_for(key).try_lock_owned()?;
+        Some(PopulationGuard {
+            populations: self,
+            key: key.to_owned(),
+            guard: Some(guard),
+            waited: false,
+        })
+    }
+
+    /// Forgets the lock of the key once no population holds or awaits it
+    fn release(&self, key: &str) {
+        let mut in_flight = self
+            .in_flight
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if in_flight
+            .get(key)
+            .is_some_and(|lock| Arc::strong_count(lock) == 1)
+        {
+            in_flight.remove(key);
+        }
+    }
+}
+
+/// Held while a key is populated, concurrent populations of the key wait until it is dropped
+struct PopulationGuard<'a> {
+    populations: &'a Populations,
+    key: String,
+    guard: Option<OwnedMutexGuard<()>>,
+    /// Whether another population of the key was in progress when the guard was requested
+    waited: bool,
+}
+
+impl Drop for PopulationGuard<'_> {
+    fn drop(&mut self) {
+        drop(self.guard.take());
+        self.populations.release(&self.key);
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -167,6 +334,35 @@ impl Cache {
         time_to_live: u64,
         time_to_idle: u64,
         max_capacity: Option<u64>,
+    ) -> Self {
+        Self::build(name, time_to_live, time_to_idle, max_capacity, None)
+    }
+
+    /// Creates a moka cache whose entries are refreshed around their expiry as per the
+    /// `revalidation` policy. Expired entries are retained for the stale-while-revalidate window
+    /// on top of `time_to_live`.
+    pub fn new_with_revalidation(
+        name: &'static str,
+        time_to_live: u64,
+        time_to_idle: u64,
+        max_capacity: Option<u64>,
+        revalidation: RevalidationPolicy,
+    ) -> Self {
+        Self::build(
+            name,
+            time_to_live,
+            time_to_idle,
+            max_capacity,
+            Some(revalidation),
+        )
+    }
+
+    fn build(
+        name: &'static str,
+        time_to_live: u64,
+        time_to_idle: u64,
+        max_capacity: Option<u64>,
+        revalidation: Option<RevalidationPolicy>,
     ) -> Self {
         // Record the metrics of manual invalidation of cache entry by the application
         let eviction_listener = move |_, _, cause| {
@@ -179,8 +375,13 @@ impl Cache {
                 ]),
             );
         };
+        let retention = time_to_live.saturating_add(
+            revalidation
+                .map(|policy| policy.stale_while_revalidate)
+                .unwrap_or_default(),
+        );
         let mut cache_builder = MokaCache::builder()
-            .time_to_live(std::time::Duration::from_secs(time_to_live))
+            .time_to_live(Duration::from_secs(retention))
             .time_to_idle(std::time::Duration::from_secs(time_to_idle))
             .eviction_listener(eviction_listener);
 
@@ -191,36 +392,163 @@ impl Cache {
         Self {
             name,
             inner: cache_builder.build(),
+            time_to_live: Duration::from_secs(time_to_live),
+            revalidation,
+            populations: Populations::default(),
         }
     }
 
     pub async fn push<T: Cacheable>(&self, key: CacheKey, val: T) {
-        self.inner.insert(key.into(), Arc::new(val)).await;
+        self.inner
+            .insert(key.into(), CacheEntry::new(Arc::new(val), Duration::ZERO))
+            .await;
     }
 
+    /// Fetches the value of the key, expired entries are not returned
     pub async fn get_val<T: Clone + Cacheable>(&self, key: CacheKey) -> Option<T> {
-        let val = self.inner.get::<String>(&key.into()).await;
+        let val = match self.lookup::<T>(&key.into()).await {
+            Some((val, Freshness::Fresh | Freshness::RefreshAhead)) => Some(val),
+            Some((_, Freshness::Stale)) | None => None,
+        };
 
         // Add cache hit and cache miss metrics
         if val.is_some() {
-            metrics::IN_MEMORY_CACHE_HIT.add(
-                &metrics::CONTEXT,
-                1,
-                &add_attributes([("cache_type", self.name)]),
-            );
+            self.record(&metrics::IN_MEMORY_CACHE_HIT);
         } else {
-            metrics::IN_MEMORY_CACHE_MISS.add(
-                &metrics::CONTEXT,
-                1,
-                &add_attributes([("cache_type", self.name)]),
-            );
+            self.record(&metrics::IN_MEMORY_CACHE_MISS);
         }
 
-        let val = (*val?).as_any().downcast_ref::<T>().cloned();
-
         val
     }
 
+    /// Fetches the value of the key, populating it with `fun` if it is missing. Concurrent
+    /// lookups of a missing key wait for a single population instead of calling `fun` each.
+    ///
+    /// With a revalidation policy, expired entries are served while a single lookup refreshes
+    /// them, and entries may be refreshed ahead of their expiry. The cached value is served if
+    /// the refresh fails.
+    pub async fn get_or_populate<T, F, Fut>(
+        &self,
+        key: CacheKey,
+        fun: F,
+    ) -> CustomResult<T, StorageError>
+    where
+        T: Cacheable + Clone,
+        F: FnOnce() -> Fut + Send,
+        Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
+    {
+        let key: String = key.into();
+
+        match self.lookup::<T>(&key).await {
+            Some((val, Freshness::Fresh)) => {
+                self.record(&metrics::IN_MEMORY_CACHE_HIT);
+                Ok(val)
+            }
+            Some((val, freshness)) => {
+                // Other lookups serve the cached value while the entry is being refreshed
+                let Some(_population) = self.populations.try_lock(&key) else {
+                    match freshness {
+                        Freshness::Stale => self.record(&metrics::IN_MEMORY_CACHE_STALE_SERVED),
+                        Freshness::Fresh | Freshness::RefreshAhead => {
+                            self.record(&metrics::IN_MEMORY_CACHE_HIT)
+                        }
+                    }
+                    return Ok(val);
+                };
+                match freshness {
+                    Freshness::Stale => self.record(&metrics::IN_MEMORY_CACHE_MISS),
+                    Freshness::Fresh | Freshness::RefreshAhead => {
+                        self.record(&metrics::IN_MEMORY_CACHE_EARLY_REFRESH)
+                    }
+                }
+
+                match self.populate(key, fun).await {
+                    Ok(refreshed) => Ok(refreshed),
+                    Err(error) => {
+                        logger::warn!(
+                            cache_type = self.name,
+                            ?error,
+                            "Failed to refresh cache entry, serving the cached value"
+                        );
+                        if matches!(freshness, Freshness::Stale) {
+                            self.record(&metrics::IN_MEMORY_CACHE_STALE_SERVED);
+                        }
+                        Ok(val)
+                    }
+                }
+            }
+            None => {
+                let population = self.populations.lock(&key).await;
+                if population.waited {
+                    // The entry was populated while waiting for the concurrent population
+                    if let Some((val, _)) = self.lookup::<T>(&key).await {
+                        self.record(&metrics::IN_MEMORY_CACHE_COALESCED);
+                        return Ok(val);
+                    }
+                }
+                self.record(&metrics::IN_MEMORY_CACHE_MISS);
+                self.populate(key, fun).await
+            }
+        }
+    }
+
+    async fn populate<T, F, Fut>(&self, key: String, fun: F) -> CustomResult<T, StorageError>
+    where
+        T: Cacheable + Clone,
+        F: FnOnce() -> Fut + Send,
+        Fut: futures::Future<Output = CustomResult<T, StorageError>> + Send,
+    {
+        let started_at = Instant::now();
+        let val = fun().await?;
+        self.inner
+            .insert(
+                key,
+                CacheEntry::new(Arc::new(val.clone()), started_at.elapsed()),
+            )
+            .await;
+        Ok(val)
+    }
+
+    /// Fetches the value of the key along with its freshness. Expired entries are only returned
+    /// within the stale-while-revalidate window.
+    async fn lookup<T: Clone + Cacheable>(&self, key: &str) -> Option<(T, Freshness)> {
+        let entry = self.inner.get(key).await?;
+        let freshness = self.freshness(&entry)?;
+        let val = (*entry.value).as_any().downcast_ref::<T>().cloned()?;
+        Some((val, freshness))
+    }
+
+    fn freshness(&self, entry: &CacheEntry) -> Option<Freshness> {
+        let age = entry.inserted_at.elapsed();
+        if age >= self.time_to_live {
+            return self.revalidation.map(|_| Freshness::Stale);
+        }
+
+        // Probabilistic early expiration (XFetch), the entry is refreshed earlier the longer it
+        // takes to compute, so that a single lookup refreshes it before it expires
+        let Some(beta) = self
+            .revalidation
+            .and_then(|policy| policy.early_refresh_beta)
+        else {
+            return Some(Freshness::Fresh);
+        };
+        let random: f64 = 1.0 - rand::random::<f64>();
+        let gap = entry.compute_time.as_secs_f64() * beta * -random.ln();
+        if age.as_secs_f64() + gap >= self.time_to_live.as_secs_f64() {
+            Some(Freshness::RefreshAhead)
+        } else {
+            Some(Freshness::Fresh)
+        }
+    }
+
+    fn record(&self, counter: &Counter<u64>) {
+        counter.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("cache_type", self.name)]),
+        );
+    }
+
     /// Check if a key exists in cache
     pub async fn exists(&self, key: CacheKey) -> bool {
         self.inner.contains_key::<String>(&key.into())
@@ -268,26 +596,58 @@ where
 {
     let type_name = std::any::type_name::<T>();
     let key = key.as_ref();
-    let redis_val = redis.get_and_deserialize_key::<T>(key, type_name).await;
-    let get_data_set_redis = || async {
-        let data = fun().await?;
-        redis
-            .serialize_and_set_key(key, &data)
-            .await
-            .change_context(StorageError::KVError)?;
-        Ok::<_, Report<StorageError>>(data)
+    let record = |counter: &Counter<u64>| {
+        counter.add(
+            &metrics::CONTEXT,
+            1,
+            &add_attributes([("data_type", type_name)]),
+        )
+    };
+    let is_missing = |err: &Report<RedisError>| {
+        matches!(
+            err.current_context(),
+            RedisError::NotFound | RedisError::JsonDeserializationFailed
+        )
+    };
+    let fetch_error = |err: Report<RedisError>| {
+        err.change_context(StorageError::KVError)
+            .attach_printable(format!("Error while fetching cache for {type_name}"))
     };
-    match redis_val {
-        Err(err) => match err.current_context() {
-            RedisError::NotFound | RedisError::JsonDeserializationFailed => {
-                get_data_set_redis().await
+
+    match redis.get_and_deserialize_key::<T>(key, type_name).await {
+        Ok(val) => {
+            record(&metrics::REDIS_CACHE_HIT);
+            return Ok(val);
+        }
+        Err(err) if !is_missing(&err) => return Err(fetch_error(err)),
+        Err(_) => {}
+    }
+
+    // Concurrent lookups of the key in this process wait for a single population
+    let population = REDIS_POPULATIONS
+        .lock(&String::from(CacheKey {
+            key: key.to_owned(),
+            prefix: redis.key_prefix.clone(),
+        }))
+        .await;
+    if population.waited {
+        match redis.get_and_deserialize_key::<T>(key, type_name).await {
+            Ok(val) => {
+                record(&metrics::REDIS_CACHE_COALESCED);
+                return Ok(val);
             }
-            _ => Err(err
-                .change_context(StorageError::KVError)
-                .attach_printable(format!("Error while fetching cache for {type_name}"))),
-        },
-        Ok(val) => Ok(val),
+            Err(err) if !is_missing(&err) => return Err(fetch_error(err)),
+            Err(_) => {}
+        }
     }
+    record(&metrics::REDIS_CACHE_MISS);
+
+    let data = fun().await?;
+    redis
+        .serialize_and_set_key(key, &data)
+        .await
+        .change_context(StorageError::KVError)?;
+    Ok(data)
 }
 
 #[instrument(skip_all)]
@@ -308,27 +668,15 @@ where
             RedisError::RedisConnectionError.into(),
         ))
         .attach_printable("Failed to get redis connection")?;
-    let cache_val = cache
-        .get_val::<T>(CacheKey {
-            key: key.to_string(),
-            prefix: redis.key_prefix.clone(),
-        })
-        .await;
-    if let Some(val) = cache_val {
-        Ok(val)
-    } else {
-        let val = get_or_populate_redis(redis, key, fun).await?;
-        cache
-            .push(
-                CacheKey {
-                    key: key.to_string(),
-                    prefix: redis.key_prefix.clone(),
-                },
-                val.clone(),
-            )
-            .await;
-        Ok(val)
-    }
+    cache
+        .get_or_populate(
+            CacheKey {
+                key: key.to_string(),
+                prefix: redis.key_prefix.clone(),
+            },
+            || get_or_populate_redis(redis, key, fun),
+        )
+        .await
 }
 
 #[instrument(skip_all)]
@@ -422,6 +770,7 @@ where
 
 #[cfg(test)]
 mod cache_tests {
+    #![allow(clippy::unwrap_used)]
     use super::*;
 
     #[tokio::test]
@@ -524,4 +873,120 @@ mod cache_tests {
             None
         );
     }
+
+    fn key() -> CacheKey {
+        CacheKey {
+            key: "key".to_string(),
+            prefix: "prefix".to_string(),
+        }
+    }
+
+    fn revalidated_cache(time_to_live: u64, early_refresh_beta: Option<f64>) -> Cache {
+        Cache::new_with_revalidation(
+            "test",
+            time_to_live,
+            1800,
+            None,
+            RevalidationPolicy {
+                stale_while_revalidate: 1800,
+                early_refresh_beta,
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn concurrent_population_is_coalesced() {
+        let cache = Cache::new("test", 1800, 1800, None);
+        let populations = std::sync::atomic::AtomicUsize::new(0);
+
+        let lookups = (0..10).map(|_| {
+            cache.get_or_populate(key(), || async {
+                populations.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+                // Lets the other lookups run while the value is being computed
+                for _ in 0..10 {
+                    tokio::task::yield_now().await;
+                }
+                Ok("val".to_string())
+            })
+        });
+        let values = futures::future::try_join_all(lookups).await.unwrap();
+
+        assert!(values.iter().all(|val| val == "val"));
+        assert_eq!(populations.load(std::sync::atomic::Ordering::SeqCst), 1);
+        assert!(cache.populations.in_flight.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn stale_entry_is_served_while_refreshing() {
+        // Entries expire as soon as they are inserted
+        let cache = revalidated_cache(0, None);
+        cache.push(key(), "stale".to_string()).await;
+
+        // Another lookup is refreshing the entry
+        let key_str = String::from(key());
+        let refresh = cache.populations.try_lock(&key_str).unwrap();
+        let val = cache
+            .get_or_populate(key(), || async { Ok("fresh".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(val, "stale");
+
+        drop(refresh);
+        let val = cache
+            .get_or_populate(key(), || async { Ok("fresh".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(val, "fresh");
+    }
+
+    #[tokio::test]
+    async fn stale_entry_is_served_if_refresh_fails() {
+        let cache = revalidated_cache(0, None);
+        cache.push(key(), "stale".to_string()).await;
+
+        let val = cache
+            .get_or_populate::<String, _, _>(key(), || async { Err(StorageError::KVError.into()) })
+            .await
+            .unwrap();
+
+        assert_eq!(val, "stale");
+    }
+
+    #[tokio::test]
+    async fn expired_entry_is_not_served_without_revalidation() {
+        let cache = Cache::new("test", 0, 1800, None);
+        cache.push(key(), "stale".to_string()).await;
+
+        assert_eq!(cache.get_val::<String>(key()).await, None);
+        let val = cache
+            .get_or_populate(key(), || async { Ok("fresh".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(val, "fresh");
+    }
+
+    #[tokio::test]
+    async fn expensive_entry_is_refreshed_ahead_of_expiry() {
+        let cache = revalidated_cache(1800, Some(1e9));
+
+        let val = cache
+            .get_or_populate(key(), || async {
+                std::thread::sleep(Duration::from_millis(1));
+                Ok("first".to_string())
+            })
+            .await
+            .unwrap();
+        assert_eq!(val, "first");
+
+        // The entry is refreshed long before its expiry, as it is expensive to compute
+        let val = cache
+            .get_or_populate(key(), || async { Ok("second".to_string()) })
+            .await
+            .unwrap();
+        assert_eq!(val, "second");
+        assert_eq!(
+            cache.get_val::<String>(key()).await,
+            Some("second".to_string())
+        );
+    }
 }
@@ -1,7 +1,7 @@
 use std::{fmt::Debug, sync::Arc};
 
 use common_utils::errors::CustomResult;
-use diesel_models::enums::MerchantStorageScheme;
+use diesel_models::{enums::MerchantStorageScheme, kv::partition::PartitionLayout};
 use error_stack::report;
 use redis_interface::errors::RedisError;
 use router_derive::TryGetEnumVariant;
@@ -15,8 +15,8 @@ pub trait KvStorePartition {
         crc32fast::hash(key.to_string().as_bytes()) % u32::from(num_partitions)
     }
 
-    fn shard_key(key: PartitionKey<'_>, num_partitions: u8) -> String {
-        format!("shard_{}", Self::partition_number(key, num_partitions))
+    fn shard_key(key: PartitionKey<'_>, layout: &PartitionLayout) -> String {
+        layout.shard_key(Self::partition_number(key, layout.num_partitions))
     }
 }
 
@@ -51,6 +51,10 @@ pub enum PartitionKey<'a> {
         merchant_id: &'a common_utils::id_type::MerchantId,
         mandate_id: &'a str,
     },
+    MerchantIdAuthenticationId {
+        merchant_id: &'a common_utils::id_type::MerchantId,
+        authentication_id: &'a str,
+    },
     #[cfg(all(feature = "v2", feature = "customer_v2"))]
     GlobalId {
         id: &'a str,
@@ -105,6 +109,13 @@ impl<'a> std::fmt::Display for PartitionKey<'a> {
                 "mid_{}_mandate_{mandate_id}",
                 merchant_id.get_string_repr()
             )),
+            PartitionKey::MerchantIdAuthenticationId {
+                merchant_id,
+                authentication_id,
+            } => f.write_str(&format!(
+                "mid_{}_auth_{authentication_id}",
+                merchant_id.get_string_repr()
+            )),
 
             #[cfg(all(feature = "v2", feature = "customer_v2"))]
             PartitionKey::GlobalId { id } => f.write_str(&format!("cust_{id}",)),
@@ -334,3 +345,260 @@ where
         storage_scheme
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use std::collections::{HashMap, VecDeque};
+
+    use diesel_models::kv::partition::PartitionMap;
+
+    use super::*;
+
+    const GRACE_PERIOD_IN_SECS: i64 = 30;
+
+    struct Entry;
+
+    impl KvStorePartition for Entry {}
+
+    /// Streams of the drainer, holding the key and the sequence number of each pushed entry
+    #[derive(Default)]
+    struct MockStreams {
+        streams: HashMap<String, VecDeque<(String, u32)>>,
+    }
+
+    impl MockStreams {
+        fn push(&mut self, layout: &PartitionLayout, key: &str, sequence: u32) {
+            let shard_key =
+                Entry::shard_key(PartitionKey::CombinationKey { combination: key }, layout);
+            self.streams
+                .entry(shard_key)
+                .or_default()
+                .push_back((key.to_string(), sequence));
+        }
+
+        fn pending_entries(&self, layout: &PartitionLayout) -> usize {
+            (0..u32::from(layout.num_partitions))
+                .filter_map(|partition| self.streams.get(&layout.shard_key(partition)))
+                .map(VecDeque::len)
+                .sum()
+        }
+
+        /// Reads at most `max_read_count` entries from every stream of the layout being drained,
+        /// as the drainer does in a cycle, and retires the previous layout as the drainer does
+        /// once it is drained
+        fn drain_cycle(
+            &mut self,
+            partition_map: &mut PartitionMap,
+            now: i64,
+            max_read_count: usize,
+            applied: &mut Vec<(String, u32)>,
+        ) {
+            let layout = *partition_map.draining_layout();
+            for partition in 0..u32::from(layout.num_partitions) {
+                if let Some(stream) = self.streams.get_mut(&layout.shard_key(partition)) {
+                    let read_count = max_read_count.min(stream.len());
+                    applied.extend(stream.drain(..read_count));
+                }
+            }
+
+            if let Some(previous) = partition_map.previous {
+                if let Some(completed) = partition_map.complete_reshard_if_drained(
+                    &previous,
+                    self.pending_entries(&previous),
+                    now,
+                    GRACE_PERIOD_IN_SECS,
+                ) {
+                    *partition_map = completed;
+                }
+            }
+        }
+    }
+
+    fn keys() -> Vec<String> {
+        (0..20).map(|key| format!("pay_{key}")).collect()
+    }
+
+    fn assert_applied_in_order(applied: &[(String, u32)], expected_sequences: u32) {
+        let mut sequences = HashMap::<&str, Vec<u32>>::new();
+        for (key, sequence) in applied {
+            sequences.entry(key).or_default().push(*sequence);
+        }
+
+        assert_eq!(sequences.len(), keys().len());
+        for (key, sequences) in sequences {
+            assert_eq!(
+                sequences,
+                (0..expected_sequences).collect::<Vec<_>>(),
+                "entries of {key} were lost or applied out of order"
+            );
+        }
+    }
+
+    #[test]
+    fn test_authentication_partition_key_is_scoped_to_merchant() {
+        let merchant_id =
+            common_utils::id_type::MerchantId::try_from(std::borrow::Cow::from("merchant_1"))
+                .unwrap();
+        let key = PartitionKey::MerchantIdAuthenticationId {
+            merchant_id: &merchant_id,
+            authentication_id: "authn_1",
+        };
+
+        assert_eq!(key.to_string(), "mid_merchant_1_auth_authn_1");
+    }
+
+    #[test]
+    fn test_initial_layout_retains_legacy_shard_keys() {
+        let partition_map = PartitionMap::new(64);
+        let key = PartitionKey::CombinationKey {
+            combination: "pay_1",
+        };
+
+        assert_eq!(
+            Entry::shard_key(key.clone(), &partition_map.current),
+            format!("shard_{}", Entry::partition_number(key, 64))
+        );
+    }
+
+    #[test]
+    fn test_reshard_is_rejected_while_in_progress() {
+        let partition_map = PartitionMap::new(4);
+        assert!(partition_map.reshard(4, 100).is_none());
+
+        let resharded = partition_map.reshard(8, 100).unwrap();
+        assert_eq!(resharded.current.version, 1);
+        assert_eq!(resharded.previous, Some(partition_map.current));
+        assert!(resharded.reshard(16, 110).is_none());
+
+        let completed = resharded.complete_reshard();
+        assert!(!completed.is_resharding());
+        // A drainer holding a map read before the reshard was completed does not complete it again
+        assert!(completed
+            .complete_reshard_if_drained(&partition_map.current, 0, 200, GRACE_PERIOD_IN_SECS)
+            .is_none());
+        assert_eq!(completed.find_layout(0), Some(&partition_map.current));
+        assert!(completed.reshard(16, 120).is_some());
+    }
+
+    #[test]
+    fn test_reshard_completes_once_drained_after_grace_period() {
+        let partition_map = PartitionMap::new(4).reshard(8, 100).unwrap();
+        let previous_layout = partition_map.previous.unwrap();
+
+        assert!(partition_map
+            .complete_reshard_if_drained(&previous_layout, 0, 110, GRACE_PERIOD_IN_SECS)
+            .is_none());
+        assert!(partition_map
+            .complete_reshard_if_drained(&previous_layout, 1, 130, GRACE_PERIOD_IN_SECS)
+            .is_none());
+
+        let completed = partition_map
+            .complete_reshard_if_drained(&previous_layout, 0, 130, GRACE_PERIOD_IN_SECS)
+            .unwrap();
+        assert_eq!(completed.retired, vec![previous_layout]);
+    }
+
+    #[test]
+    fn test_no_lost_writes_during_reshard() {
+        let mut streams = MockStreams::default();
+        let mut applied = Vec::new();
+        let mut partition_map = PartitionMap::new(4);
+        let mut now = 0;
+
+        for key in keys() {
+            streams.push(&partition_map.current, &key, 0);
+        }
+        streams.drain_cycle(&mut partition_map, now, 2, &mut applied);
+
+        let stale_partition_map = partition_map.clone();
+        partition_map = partition_map.reshard(8, now).unwrap();
+
+        for (index, key) in keys().iter().enumerate() {
+            // Routers which read the partition map just before the reshard push to the previous
+            // layout, the others push to the new layout
+            if index % 2 == 0 {
+                streams.push(&stale_partition_map.current, key, 1);
+            } else {
+                streams.push(&partition_map.current, key, 1);
+            }
+            streams.push(&partition_map.current, key, 2);
+        }
+
+        while partition_map.is_resharding() || streams.pending_entries(&partition_map.current) != 0
+        {
+            now += 10;
+            streams.drain_cycle(&mut partition_map, now, 2, &mut applied);
+        }
+
+        assert_eq!(partition_map.current.num_partitions, 8);
+        assert_eq!(partition_map.retired, vec![stale_partition_map.current]);
+        assert_applied_in_order(&applied, 3);
+    }
+
+    #[test]
+    fn test_previous_layout_is_drained_until_grace_period_elapses() {
+        let mut streams = MockStreams::default();
+        let mut applied = Vec::new();
+        let mut partition_map = PartitionMap::new(4).reshard(2, 0).unwrap();
+        let previous_layout = partition_map.previous.unwrap();
+
+        for key in keys() {
+            streams.push(&previous_layout, &key, 0);
+        }
+        streams.drain_cycle(&mut partition_map, 10, 100, &mut applied);
+        assert!(partition_map.is_resharding());
+
+        // A router with a stale partition map pushes to the previous layout after it was drained
+        for key in keys() {
+            streams.push(&previous_layout, &key, 1);
+        }
+        streams.drain_cycle(&mut partition_map, GRACE_PERIOD_IN_SECS, 100, &mut applied);
+
+        assert!(!partition_map.is_resharding());
+        assert_eq!(streams.pending_entries(&previous_layout), 0);
+        assert_applied_in_order(&applied, 2);
+    }
+
+    /// Returns the partition map a router pushes entries with, reading the map again if the
+    /// cached map is older than the live version, as the router does before pushing an entry
+    fn writer_partition_map(cached: &PartitionMap, live: &PartitionMap) -> PartitionMap {
+        if cached.is_stale(Some(live.current.version)) {
+            live.clone()
+        } else {
+            cached.clone()
+        }
+    }
+
+    #[test]
+    fn test_stale_writer_reads_partition_map_again_after_reshard_completes() {
+        let mut streams = MockStreams::default();
+        let mut applied = Vec::new();
+        let stale_partition_map = PartitionMap::new(4);
+        let mut partition_map = stale_partition_map.reshard(8, 0).unwrap();
+        let mut now = 0;
+
+        while partition_map.is_resharding() {
+            now += 10;
+            streams.drain_cycle(&mut partition_map, now, 2, &mut applied);
+        }
+        assert!(stale_partition_map.is_stale(Some(partition_map.current.version)));
+        assert!(!partition_map.is_stale(Some(partition_map.current.version)));
+        // Maps stored before the version was recorded are not considered stale
+        assert!(!stale_partition_map.is_stale(None));
+
+        // A router which cached the map before the reshard pushes entries long after the previous
+        // layout was retired
+        for sequence in 0..2 {
+            for key in keys() {
+                let writer_map = writer_partition_map(&stale_partition_map, &partition_map);
+                streams.push(&writer_map.current, &key, sequence);
+            }
+        }
+        streams.drain_cycle(&mut partition_map, now + 10, 100, &mut applied);
+
+        assert_eq!(streams.pending_entries(&stale_partition_map.current), 0);
+        assert_eq!(streams.pending_entries(&partition_map.current), 0);
+        assert_applied_in_order(&applied, 2);
+    }
+}
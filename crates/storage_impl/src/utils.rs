@@ -10,12 +10,36 @@ pub async fn pg_connection_read<T: DatabaseStore>(
 ) -> error_stack::Result<
     PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
     StorageError,
+> {
+    get_read_connection(store, None).await
+}
+
+/// Fetches a connection to read `table` from, as per the read preference configured for the table
+pub async fn pg_connection_read_table<T: DatabaseStore>(
+    store: &T,
+    table: &str,
+) -> error_stack::Result<
+    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    StorageError,
+> {
+    get_read_connection(store, Some(table)).await
+}
+
+async fn get_read_connection<'a, T: DatabaseStore>(
+    store: &'a T,
+    table: Option<&str>,
+) -> error_stack::Result<
+    PooledConnection<'a, async_bb8_diesel::ConnectionManager<PgConnection>>,
+    StorageError,
 > {
     // If only OLAP is enabled get replica pool.
     #[cfg(all(feature = "olap", not(feature = "oltp")))]
-    let pool = store.get_replica_pool();
+    let pool = {
+        let _ = table;
+        store.get_replica_pool()
+    };
 
-    // If either one of these are true we need to get master pool.
+    // If either one of these are true reads are routed between the master and replica pools.
     //  1. Only OLTP is enabled.
     //  2. Both OLAP and OLTP is enabled.
     //  3. Both OLAP and OLTP is disabled.
@@ -24,7 +48,7 @@ pub async fn pg_connection_read<T: DatabaseStore>(
         all(feature = "olap", feature = "oltp"),
         all(not(feature = "olap"), not(feature = "oltp"))
     ))]
-    let pool = store.get_master_pool();
+    let pool = crate::database::routing::get_read_pool(store, table).await;
 
     pool.get()
         .await
@@ -33,16 +57,20 @@ pub async fn pg_connection_read<T: DatabaseStore>(
 
 pub async fn pg_connection_write<T: DatabaseStore>(
     store: &T,
-) -> error_stack::Result<
-    PooledConnection<'_, async_bb8_diesel::ConnectionManager<PgConnection>>,
-    StorageError,
-> {
+) -> error_stack::Result<crate::database::routing::WriteConnection<'_>, StorageError> {
     // Since all writes should happen to master DB only choose master DB.
     let pool = store.get_master_pool();
 
+    // Reads made for the merchant after the write are not routed to a lagging replica
     pool.get()
         .await
         .change_context(StorageError::DatabaseConnectionError)
+        .map(|conn| {
+            crate::database::routing::WriteConnection::new(
+                conn,
+                store.get_consistency_token().cloned(),
+            )
+        })
 }
 
 pub async fn try_redis_get_else_try_database_get<F, RFut, DFut, T>(
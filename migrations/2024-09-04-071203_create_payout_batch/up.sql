@@ -0,0 +1,28 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payout_batch (
+    batch_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    profile_id VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64) NOT NULL,
+    file_format VARCHAR(16) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    confirm BOOLEAN NOT NULL DEFAULT TRUE,
+    auto_fulfill BOOLEAN NOT NULL DEFAULT TRUE,
+    total_count INTEGER NOT NULL,
+    processed_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, batch_id)
+);
+
+CREATE TABLE IF NOT EXISTS payout_batch_item (
+    merchant_id VARCHAR(64) NOT NULL,
+    batch_id VARCHAR(64) NOT NULL,
+    row_number INTEGER NOT NULL,
+    payout_id VARCHAR(64),
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, batch_id, row_number)
+);
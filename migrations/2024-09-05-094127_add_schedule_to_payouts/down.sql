@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payouts
+DROP COLUMN IF EXISTS scheduled_at,
+DROP COLUMN IF EXISTS recurrence;
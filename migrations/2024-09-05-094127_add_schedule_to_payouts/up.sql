@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payouts
+ADD COLUMN IF NOT EXISTS scheduled_at TIMESTAMP,
+ADD COLUMN IF NOT EXISTS recurrence JSONB;
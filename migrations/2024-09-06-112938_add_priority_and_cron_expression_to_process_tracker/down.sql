@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE process_tracker
+DROP COLUMN IF EXISTS priority,
+DROP COLUMN IF EXISTS cron_expression;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE process_tracker
+ADD COLUMN IF NOT EXISTS priority SMALLINT NOT NULL DEFAULT 0,
+ADD COLUMN IF NOT EXISTS cron_expression VARCHAR(255);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile DROP COLUMN IF EXISTS auto_capture_policy;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS auto_capture_policy;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS auto_capture_policy JSONB DEFAULT NULL;
+
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS auto_capture_policy JSONB DEFAULT NULL;
@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_intent_merchant_id_authorization_expires_at_index;
+
+ALTER TABLE business_profile DROP COLUMN IF EXISTS authorization_expiry_action;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS authorization_expires_at;
@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS authorization_expires_at TIMESTAMP DEFAULT NULL;
+
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS authorization_expiry_action VARCHAR(32) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_intent_merchant_id_authorization_expires_at_index ON payment_intent (merchant_id, authorization_expires_at)
+WHERE authorization_expires_at IS NOT NULL;
@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS card_vault.access_logs;
+
+DROP TABLE IF EXISTS card_vault.entries;
+
+DROP TABLE IF EXISTS card_vault.data_keys;
+
+DROP SCHEMA IF EXISTS card_vault;
@@ -0,0 +1,45 @@
+-- Your SQL goes here
+CREATE SCHEMA IF NOT EXISTS card_vault;
+
+CREATE TABLE IF NOT EXISTS card_vault.data_keys (
+    key_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    version INTEGER NOT NULL,
+    encrypted_key BYTEA NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    retired_at TIMESTAMP
+);
+
+-- A merchant has a single active data key at a time
+CREATE UNIQUE INDEX IF NOT EXISTS data_keys_merchant_id_active_index ON card_vault.data_keys (merchant_id)
+WHERE
+    status = 'active';
+
+CREATE TABLE IF NOT EXISTS card_vault.entries (
+    entry_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    key_id VARCHAR(64) NOT NULL,
+    encrypted_data BYTEA NOT NULL,
+    fingerprint VARCHAR(64),
+    requestor_reference VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS entries_merchant_id_customer_id_fingerprint_index ON card_vault.entries (merchant_id, customer_id, fingerprint);
+
+CREATE INDEX IF NOT EXISTS entries_key_id_index ON card_vault.entries (key_id);
+
+CREATE TABLE IF NOT EXISTS card_vault.access_logs (
+    log_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    entry_id VARCHAR(64),
+    key_id VARCHAR(64),
+    action VARCHAR(32) NOT NULL,
+    request_id VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS access_logs_merchant_id_created_at_index ON card_vault.access_logs (merchant_id, created_at);
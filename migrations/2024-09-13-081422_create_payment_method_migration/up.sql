@@ -0,0 +1,29 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS payment_method_migration (
+    migration_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    file_id VARCHAR(64) NOT NULL,
+    file_format VARCHAR(16) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    parallelism INTEGER NOT NULL,
+    total_count INTEGER NOT NULL,
+    processed_count INTEGER NOT NULL DEFAULT 0,
+    failed_count INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, migration_id)
+);
+
+CREATE TABLE IF NOT EXISTS payment_method_migration_item (
+    merchant_id VARCHAR(64) NOT NULL,
+    migration_id VARCHAR(64) NOT NULL,
+    line_number INTEGER NOT NULL,
+    customer_id VARCHAR(64),
+    payment_method_id VARCHAR(64),
+    status VARCHAR(32) NOT NULL,
+    error_code VARCHAR(64),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, migration_id, line_number)
+);
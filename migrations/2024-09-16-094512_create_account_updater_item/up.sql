@@ -0,0 +1,32 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS account_updater_item (
+    item_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    payment_method_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    reason VARCHAR(32) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    batch_id VARCHAR(64),
+    outcome VARCHAR(32),
+    error_message TEXT,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, item_id)
+);
+
+-- A saved card is submitted to the account updater at most once at a time
+CREATE UNIQUE INDEX IF NOT EXISTS account_updater_item_open_payment_method_index
+ON account_updater_item (merchant_id, payment_method_id)
+WHERE status IN ('pending', 'submitted');
+
+CREATE INDEX IF NOT EXISTS account_updater_item_merchant_id_batch_id_index
+ON account_updater_item (merchant_id, batch_id);
+
+CREATE INDEX IF NOT EXISTS account_updater_item_merchant_id_payment_method_id_index
+ON account_updater_item (merchant_id, payment_method_id);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'payment_methods';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'payment_method_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_method_updated';
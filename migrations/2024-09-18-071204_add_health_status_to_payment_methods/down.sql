@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS health_status;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS consecutive_mit_declines;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS health_status VARCHAR(32) DEFAULT NULL;
+
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS consecutive_mit_declines INTEGER DEFAULT NULL;
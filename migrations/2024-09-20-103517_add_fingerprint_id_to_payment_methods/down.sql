@@ -0,0 +1,6 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_attempt_merchant_id_fingerprint_id_index;
+
+DROP INDEX IF EXISTS payment_methods_merchant_id_fingerprint_id_index;
+
+ALTER TABLE payment_methods DROP COLUMN IF EXISTS fingerprint_id;
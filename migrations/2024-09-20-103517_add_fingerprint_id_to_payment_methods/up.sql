@@ -0,0 +1,7 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS fingerprint_id VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_methods_merchant_id_fingerprint_id_index ON payment_methods (merchant_id, fingerprint_id);
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_fingerprint_id_index ON payment_attempt (merchant_id, fingerprint_id);
@@ -0,0 +1,2 @@
+-- Your SQL goes here
+ALTER TYPE "GenericLinkType" ADD VALUE IF NOT EXISTS 'payment_method_manage';
@@ -0,0 +1,15 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS data_subject_request (
+    request_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    request_type VARCHAR(16) NOT NULL,
+    status VARCHAR(32) NOT NULL,
+    report JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    completed_at TIMESTAMP,
+    PRIMARY KEY (merchant_id, request_id)
+);
+
+CREATE INDEX IF NOT EXISTS data_subject_request_merchant_id_customer_id_index ON data_subject_request (merchant_id, customer_id);
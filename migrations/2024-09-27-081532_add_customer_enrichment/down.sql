@@ -0,0 +1,8 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS customer_address_label_merchant_id_customer_id_index;
+
+DROP TABLE IF EXISTS customer_address_label;
+
+ALTER TABLE customers DROP COLUMN IF EXISTS segments;
+
+ALTER TABLE customers DROP COLUMN IF EXISTS tax_ids;
@@ -0,0 +1,17 @@
+-- Your SQL goes here
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS tax_ids BYTEA DEFAULT NULL;
+
+ALTER TABLE customers ADD COLUMN IF NOT EXISTS segments TEXT[] DEFAULT NULL;
+
+CREATE TABLE IF NOT EXISTS customer_address_label (
+    address_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    customer_id VARCHAR(64) NOT NULL,
+    address_type VARCHAR(16) NOT NULL,
+    label VARCHAR(64),
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    PRIMARY KEY (merchant_id, address_id)
+);
+
+CREATE INDEX IF NOT EXISTS customer_address_label_merchant_id_customer_id_index ON customer_address_label (merchant_id, customer_id);
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE business_profile
+DROP COLUMN IF EXISTS is_pre_debit_balance_check_enabled,
+DROP COLUMN IF EXISTS is_account_ownership_check_enabled;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS is_pre_debit_balance_check_enabled BOOLEAN DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS is_account_ownership_check_enabled BOOLEAN DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund DROP COLUMN IF EXISTS created_by;
+
+ALTER TABLE payment_intent DROP COLUMN IF EXISTS captured_at;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TABLE payment_intent
+ADD COLUMN IF NOT EXISTS captured_at TIMESTAMP DEFAULT NULL;
+
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS created_by VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_method_migration DROP COLUMN IF EXISTS processed_offset;
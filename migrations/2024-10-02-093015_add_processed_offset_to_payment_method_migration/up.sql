@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_method_migration
+ADD COLUMN IF NOT EXISTS processed_offset BIGINT NOT NULL DEFAULT 0;
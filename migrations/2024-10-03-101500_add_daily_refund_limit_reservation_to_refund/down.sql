@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund DROP COLUMN IF EXISTS daily_refund_limit_reservation;
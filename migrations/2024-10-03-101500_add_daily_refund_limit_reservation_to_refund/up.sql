@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS daily_refund_limit_reservation JSONB DEFAULT NULL;
@@ -0,0 +1,2 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS process_tracker_tracking_data_merchant_id_schedule_time_index;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+-- Tasks are searched by the merchant recorded in their tracking data
+CREATE INDEX IF NOT EXISTS process_tracker_tracking_data_merchant_id_schedule_time_index
+ON process_tracker ((tracking_data ->> 'merchant_id'), schedule_time DESC);